@@ -41,7 +41,8 @@ impl App for NumberInputApp {
         let quantity = NumberInputState::integer(1)
             .with_min(0.0)
             .with_max(100.0)
-            .with_label("Quantity");
+            .with_label("Quantity")
+            .with_steppers(true);
 
         let price = NumberInputState::new(9.99)
             .with_min(0.0)
@@ -0,0 +1,154 @@
+//! Runtime clock for time-dependent application logic.
+//!
+//! [`Clock`] is a cheaply cloneable handle that reads either wall time or a
+//! controllable virtual time. A runtime created with
+//! [`Runtime::with_virtual_clock`](super::Runtime::with_virtual_clock) only
+//! advances when [`Runtime::advance_time`](super::Runtime::advance_time) is
+//! called, which makes time-dependent behavior fully deterministic in tests.
+//!
+//! Apps that need the current time should hold a clone of the runtime's
+//! clock (for example, passed in via [`App::Args`](super::App::Args)) and call
+//! [`Clock::now`] instead of [`Instant::now`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use envision::app::Clock;
+//!
+//! let clock = Clock::new_virtual();
+//! let start = clock.now();
+//!
+//! clock.advance(Duration::from_secs(5));
+//! assert_eq!(clock.now() - start, Duration::from_secs(5));
+//! ```
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A source of the current time, either wall time or virtual time.
+///
+/// Clones share the same underlying time source, so advancing a virtual
+/// clock is visible through every clone.
+#[derive(Clone, Debug, Default)]
+pub struct Clock {
+    /// Virtual time, or `None` for the system clock.
+    virtual_now: Option<Arc<Mutex<Instant>>>,
+}
+
+impl Clock {
+    /// Creates a clock that reads wall time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::Clock;
+    ///
+    /// let clock = Clock::system();
+    /// assert!(!clock.is_virtual());
+    /// ```
+    pub fn system() -> Self {
+        Self { virtual_now: None }
+    }
+
+    /// Creates a virtual clock starting at the current instant.
+    ///
+    /// The clock only moves when [`advance`](Self::advance) is called.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::Clock;
+    ///
+    /// let clock = Clock::new_virtual();
+    /// assert!(clock.is_virtual());
+    /// assert_eq!(clock.now(), clock.now());
+    /// ```
+    pub fn new_virtual() -> Self {
+        Self {
+            virtual_now: Some(Arc::new(Mutex::new(Instant::now()))),
+        }
+    }
+
+    /// Returns true if this is a virtual clock.
+    pub fn is_virtual(&self) -> bool {
+        self.virtual_now.is_some()
+    }
+
+    /// Returns the current time according to this clock.
+    pub fn now(&self) -> Instant {
+        match &self.virtual_now {
+            Some(now) => *now.lock().unwrap_or_else(|e| e.into_inner()),
+            None => Instant::now(),
+        }
+    }
+
+    /// Advances a virtual clock by `duration`.
+    ///
+    /// Has no effect on a system clock.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use envision::app::Clock;
+    ///
+    /// let clock = Clock::new_virtual();
+    /// let handle = clock.clone();
+    /// let start = clock.now();
+    ///
+    /// handle.advance(Duration::from_millis(250));
+    /// assert_eq!(clock.now() - start, Duration::from_millis(250));
+    /// ```
+    pub fn advance(&self, duration: Duration) {
+        if let Some(now) = &self.virtual_now {
+            let mut now = now.lock().unwrap_or_else(|e| e.into_inner());
+            *now += duration;
+        }
+    }
+
+    /// Returns the time elapsed on this clock since `earlier`.
+    ///
+    /// Returns zero if `earlier` is later than the clock's current time.
+    pub fn elapsed_since(&self, earlier: Instant) -> Duration {
+        self.now().saturating_duration_since(earlier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_clock_advance_is_noop() {
+        let clock = Clock::system();
+        let before = clock.now();
+        clock.advance(Duration::from_secs(3600));
+        assert!(clock.elapsed_since(before) < Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn test_virtual_clock_is_frozen_until_advanced() {
+        let clock = Clock::new_virtual();
+        let start = clock.now();
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(clock.elapsed_since(start), Duration::ZERO);
+
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(clock.elapsed_since(start), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_virtual_clock_clones_share_time() {
+        let clock = Clock::new_virtual();
+        let clone = clock.clone();
+        let start = clock.now();
+        clone.advance(Duration::from_secs(1));
+        assert_eq!(clock.now() - start, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_default_is_system() {
+        assert!(!Clock::default().is_virtual());
+    }
+}
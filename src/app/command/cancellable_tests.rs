@@ -0,0 +1,146 @@
+//! Tests for `Command::cancellable` and `Command::cancel`.
+
+use super::*;
+use tokio::sync::mpsc;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Msg {
+    Done(u32),
+    Cancelled(u32),
+}
+
+/// A cancellable future that finishes after `secs` unless its token fires.
+fn job(id: &str, n: u32, secs: u64) -> Command<Msg> {
+    Command::cancellable(id, move |token| async move {
+        tokio::select! {
+            _ = token.cancelled() => Some(Msg::Cancelled(n)),
+            _ = tokio::time::sleep(Duration::from_secs(secs)) => Some(Msg::Done(n)),
+        }
+    })
+}
+
+struct Harness {
+    handler: CommandHandler<Msg>,
+    msg_tx: mpsc::Sender<Msg>,
+    msg_rx: mpsc::Receiver<Msg>,
+    err_tx: mpsc::Sender<BoxedError>,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let (msg_tx, msg_rx) = mpsc::channel(10);
+        let (err_tx, _err_rx) = mpsc::channel(10);
+        Self {
+            handler: CommandHandler::new(),
+            msg_tx,
+            msg_rx,
+            err_tx,
+        }
+    }
+
+    fn run(&mut self, command: Command<Msg>) {
+        self.handler.execute(command);
+        self.handler.spawn_pending(
+            self.msg_tx.clone(),
+            self.err_tx.clone(),
+            CancellationToken::new(),
+        );
+    }
+}
+
+#[test]
+fn test_cancellable_is_async() {
+    assert!(job("export", 1, 1).is_async());
+    assert!(!Command::<Msg>::cancel("export").is_async());
+    assert_eq!(Command::<Msg>::cancel("export").action_count(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cancellable_runs_to_completion() {
+    let mut h = Harness::new();
+    h.run(job("export", 1, 1));
+
+    assert_eq!(h.msg_rx.recv().await, Some(Msg::Done(1)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cancel_triggers_token() {
+    let mut h = Harness::new();
+    h.run(job("export", 1, 60));
+    h.run(Command::cancel("export"));
+
+    assert_eq!(h.msg_rx.recv().await, Some(Msg::Cancelled(1)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cancel_in_same_command_as_start() {
+    let mut h = Harness::new();
+    h.run(job("export", 1, 60).and(Command::cancel("export")));
+
+    assert_eq!(h.msg_rx.recv().await, Some(Msg::Cancelled(1)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cancel_only_affects_matching_id() {
+    let mut h = Harness::new();
+    h.run(job("export", 1, 60));
+    h.run(job("import", 2, 1));
+    h.run(Command::cancel("import"));
+
+    assert_eq!(h.msg_rx.recv().await, Some(Msg::Cancelled(2)));
+    assert_eq!(h.msg_rx.recv().await, Some(Msg::Done(1)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_reissuing_id_cancels_previous() {
+    let mut h = Harness::new();
+    h.run(job("export", 1, 60));
+    h.run(job("export", 2, 1));
+
+    assert_eq!(h.msg_rx.recv().await, Some(Msg::Cancelled(1)));
+    assert_eq!(h.msg_rx.recv().await, Some(Msg::Done(2)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cancel_unknown_id_is_noop() {
+    let mut h = Harness::new();
+    h.run(Command::cancel("missing"));
+    h.run(job("export", 1, 1));
+
+    assert_eq!(h.msg_rx.recv().await, Some(Msg::Done(1)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cancellable_map() {
+    let (msg_tx, mut msg_rx) = mpsc::channel(10);
+    let (err_tx, _err_rx) = mpsc::channel(10);
+    let mut handler: CommandHandler<String> = CommandHandler::new();
+
+    let cmd = job("export", 7, 60).map(|m| format!("{m:?}"));
+    handler.execute(cmd.and(Command::cancel("export")));
+    handler.spawn_pending(msg_tx, err_tx, CancellationToken::new());
+
+    assert_eq!(msg_rx.recv().await, Some("Cancelled(7)".to_string()));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_parent_token_cancels_named_commands() {
+    let mut h = Harness::new();
+    let parent = CancellationToken::new();
+    h.handler.set_parent_token(parent.clone());
+    h.run(job("export", 1, 60));
+    parent.cancel();
+
+    assert_eq!(h.msg_rx.recv().await, Some(Msg::Cancelled(1)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_finished_command_releases_its_id() {
+    let mut h = Harness::new();
+    h.run(job("export", 1, 1));
+    assert_eq!(h.handler.named_tokens.len(), 1);
+
+    assert_eq!(h.msg_rx.recv().await, Some(Msg::Done(1)));
+    h.run(Command::none());
+    assert!(h.handler.named_tokens.is_empty());
+}
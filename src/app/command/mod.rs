@@ -4,13 +4,21 @@
 //! an update. They're the bridge between pure state updates and
 //! the outside world (IO, network, etc.).
 
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
 use std::future::Future;
 use std::pin::Pin;
+use std::time::Duration;
 
 use crate::app::subscription::BoxedSubscription;
+use crate::backend::CursorShape;
 use crate::overlay::Overlay;
 use tokio_util::sync::CancellationToken;
 
+mod sequence;
+
+pub(crate) use sequence::Continuation;
+
 /// A command that can produce messages or perform side effects.
 ///
 /// Commands are returned from `update` functions to trigger
@@ -53,11 +61,32 @@ pub(crate) enum CommandAction<M> {
     /// Pop the topmost overlay
     PopOverlay,
 
+    /// Remove the topmost overlay matching a type predicate
+    RemoveOverlay(fn(&dyn Any) -> bool),
+
     /// Request the runtime's cancellation token
     RequestCancelToken(Box<dyn FnOnce(CancellationToken) -> M + Send + 'static>),
 
+    /// An async future registered under an id so it can be cancelled later
+    Cancellable(String, CancellableFuture<M>),
+
+    /// Cancel the cancellable future registered under an id
+    Cancel(String),
+
     /// Register a subscription dynamically from within update()
     Subscribe(BoxedSubscription<M>),
+
+    /// Ask the runtime to draw the next frame
+    RequestRedraw,
+
+    /// Set the terminal window title
+    SetTitle(String),
+
+    /// Set the terminal cursor shape, or restore the default with `None`
+    SetCursorShape(Option<CursorShape>),
+
+    /// Commands run one after another, each after the previous completes
+    Sequence(VecDeque<Command<M>>),
 }
 
 impl<M> CommandAction<M> {
@@ -76,8 +105,15 @@ impl<M> CommandAction<M> {
             CommandAction::AsyncFallible(_) => "async_fallible",
             CommandAction::PushOverlay(_) => "push_overlay",
             CommandAction::PopOverlay => "pop_overlay",
+            CommandAction::RequestRedraw => "request_redraw",
+            CommandAction::SetTitle(_) => "set_title",
+            CommandAction::SetCursorShape(_) => "set_cursor_shape",
+            CommandAction::RemoveOverlay(_) => "remove_overlay",
             CommandAction::RequestCancelToken(_) => "request_cancel_token",
+            CommandAction::Cancellable(..) => "cancellable",
+            CommandAction::Cancel(_) => "cancel",
             CommandAction::Subscribe(_) => "subscribe",
+            CommandAction::Sequence(_) => "sequence",
         }
     }
 }
@@ -199,9 +235,14 @@ impl<M> Command<M> {
     /// assert!(!cmd.is_async());
     /// ```
     pub fn is_async(&self) -> bool {
-        self.actions
-            .iter()
-            .any(|a| matches!(a, CommandAction::Async(_) | CommandAction::AsyncFallible(_)))
+        self.actions.iter().any(|a| {
+            matches!(
+                a,
+                CommandAction::Async(_)
+                    | CommandAction::AsyncFallible(_)
+                    | CommandAction::Cancellable(..)
+            ) || matches!(a, CommandAction::Sequence(steps) if steps.iter().any(Command::is_async))
+        })
     }
 
     /// Returns true if this command contains a push overlay action.
@@ -249,6 +290,46 @@ impl<M> Command<M> {
             .any(|a| matches!(a, CommandAction::PopOverlay))
     }
 
+    /// Returns true if this command requests a redraw.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::Command;
+    ///
+    /// let cmd: Command<String> = Command::request_redraw();
+    /// assert!(cmd.is_redraw_request());
+    ///
+    /// let cmd: Command<String> = Command::none();
+    /// assert!(!cmd.is_redraw_request());
+    /// ```
+    pub fn is_redraw_request(&self) -> bool {
+        self.actions
+            .iter()
+            .any(|a| matches!(a, CommandAction::RequestRedraw))
+    }
+
+    /// Returns the title this command sets, if any.
+    ///
+    /// When several titles are set, the last one wins, as it does when the
+    /// runtime executes the command.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::Command;
+    ///
+    /// let cmd: Command<String> = Command::set_title("a").and(Command::set_title("b"));
+    /// assert_eq!(cmd.title(), Some("b"));
+    /// assert_eq!(Command::<String>::none().title(), None);
+    /// ```
+    pub fn title(&self) -> Option<&str> {
+        self.actions.iter().rev().find_map(|a| match a {
+            CommandAction::SetTitle(title) => Some(title.as_str()),
+            _ => None,
+        })
+    }
+
     /// Returns the number of actions in this command.
     ///
     /// A command can contain multiple actions when created with
@@ -411,6 +492,38 @@ impl<M> Command<M> {
         })
     }
 
+    /// Creates a command from an async operation with a deadline.
+    ///
+    /// The future races against a timer. If it completes within `duration`,
+    /// its message (if any) is dispatched as with
+    /// [`perform_async`](Command::perform_async). Otherwise the future is
+    /// dropped, cancelling it, and `on_timeout` is dispatched instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use envision::app::Command;
+    ///
+    /// let cmd: Command<String> = Command::with_timeout(
+    ///     Duration::from_secs(5),
+    ///     async { Some("response".to_string()) },
+    ///     "request timed out".to_string(),
+    /// );
+    /// assert!(cmd.is_async());
+    /// ```
+    pub fn with_timeout<Fut>(duration: Duration, future: Fut, on_timeout: M) -> Self
+    where
+        Fut: Future<Output = Option<M>> + Send + 'static,
+        M: Send + 'static,
+    {
+        Self::perform_async(async move {
+            tokio::time::timeout(duration, future)
+                .await
+                .unwrap_or(Some(on_timeout))
+        })
+    }
+
     /// Creates a command from an async operation that can fail.
     ///
     /// On success, the future returns `Ok(Some(message))` or `Ok(None)`.
@@ -487,6 +600,78 @@ impl<M> Command<M> {
         }
     }
 
+    /// Creates a command that shows an auto-dismissing toast notification.
+    ///
+    /// The toast is hosted by a [`ToastOverlay`](crate::overlay::ToastOverlay)
+    /// on the runtime's overlay stack. If a toast overlay is already active,
+    /// the toast is added to it; otherwise a new one is pushed. The overlay
+    /// dismisses itself once its last toast has expired.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use envision::app::Command;
+    /// use envision::component::ToastLevel;
+    ///
+    /// let cmd: Command<String> = Command::toast("Saved", ToastLevel::Success, Duration::from_secs(3));
+    /// assert!(cmd.is_overlay_push());
+    /// ```
+    #[cfg(feature = "display-components")]
+    pub fn toast(
+        text: impl Into<String>,
+        level: crate::component::ToastLevel,
+        duration: std::time::Duration,
+    ) -> Self
+    where
+        M: 'static,
+    {
+        Self::push_overlay(crate::overlay::ToastOverlay::new().with_toast(text, level, duration))
+    }
+
+    /// Creates a command that shows a modal loading spinner with a caption.
+    ///
+    /// The spinner is a [`SpinnerOverlay`](crate::overlay::SpinnerOverlay)
+    /// that blocks input until [`Command::hide_spinner`] removes it. If a
+    /// spinner is already showing, only its caption is replaced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::Command;
+    ///
+    /// let cmd: Command<String> = Command::show_spinner("Loading…");
+    /// assert!(cmd.is_overlay_push());
+    /// ```
+    #[cfg(feature = "display-components")]
+    pub fn show_spinner(message: impl Into<String>) -> Self
+    where
+        M: 'static,
+    {
+        Self::push_overlay(crate::overlay::SpinnerOverlay::new(message))
+    }
+
+    /// Creates a command that removes the spinner shown by [`Command::show_spinner`].
+    ///
+    /// Unlike [`Command::pop_overlay`], this removes the spinner even when
+    /// other overlays (such as toasts) sit above it. It does nothing if no
+    /// spinner is showing.
+    #[cfg(feature = "display-components")]
+    pub fn hide_spinner() -> Self {
+        Self::remove_overlay::<crate::overlay::SpinnerOverlay>()
+    }
+
+    /// Creates a command that removes the topmost overlay of concrete type `T`.
+    ///
+    /// The overlay is removed even when it is not the topmost layer. Only
+    /// overlays that override [`Overlay::as_any_mut`](crate::overlay::Overlay::as_any_mut)
+    /// can be matched; if none matches, the command does nothing.
+    pub fn remove_overlay<T: 'static>() -> Self {
+        Self {
+            actions: vec![CommandAction::RemoveOverlay(|any| any.is::<T>())],
+        }
+    }
+
     /// Creates a command that pops the topmost overlay from the runtime's overlay stack.
     pub fn pop_overlay() -> Self {
         Self {
@@ -494,6 +679,78 @@ impl<M> Command<M> {
         }
     }
 
+    /// Creates a command that asks the runtime to redraw the screen.
+    ///
+    /// Only needed with [`RuntimeConfig::render_on_demand`](crate::app::RuntimeConfig::render_on_demand),
+    /// where the runtime skips scheduled renders until a redraw is
+    /// requested. Otherwise every render interval redraws anyway and this
+    /// does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::Command;
+    ///
+    /// let cmd: Command<String> = Command::request_redraw();
+    /// assert!(cmd.is_redraw_request());
+    /// ```
+    pub fn request_redraw() -> Self {
+        Self {
+            actions: vec![CommandAction::RequestRedraw],
+        }
+    }
+
+    /// Creates a command that sets the terminal window or tab title.
+    ///
+    /// In a real terminal the runtime emits the OSC title sequence. A
+    /// virtual runtime records the title on its
+    /// [`CaptureBackend`](crate::backend::CaptureBackend), where
+    /// [`title`](crate::backend::CaptureBackend::title) returns it. The
+    /// latest title is also available from
+    /// [`Runtime::title`](crate::app::Runtime::title), which is the only
+    /// place it is recorded for runtimes created with
+    /// [`Runtime::builder`](crate::app::Runtime::builder).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::Command;
+    ///
+    /// let cmd: Command<String> = Command::set_title("notes.md - editor");
+    /// assert_eq!(cmd.title(), Some("notes.md - editor"));
+    /// ```
+    pub fn set_title(title: impl Into<String>) -> Self {
+        Self {
+            actions: vec![CommandAction::SetTitle(title.into())],
+        }
+    }
+
+    /// Creates a command that sets the terminal cursor shape, or restores
+    /// the terminal's default shape with `None`.
+    ///
+    /// In a real terminal the runtime emits crossterm's
+    /// [`SetCursorStyle`](crossterm::cursor::SetCursorStyle), and restores
+    /// the default shape on exit. A virtual runtime records the shape on
+    /// its [`CaptureBackend`](crate::backend::CaptureBackend), where
+    /// [`cursor_shape`](crate::backend::CaptureBackend::cursor_shape)
+    /// returns it. The latest shape is also available from
+    /// [`Runtime::cursor_shape`](crate::app::Runtime::cursor_shape).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::Command;
+    /// use envision::backend::CursorShape;
+    ///
+    /// let cmd: Command<String> = Command::set_cursor_shape(Some(CursorShape::SteadyBar));
+    /// assert_eq!(cmd.action_count(), 1);
+    /// ```
+    pub fn set_cursor_shape(shape: Option<CursorShape>) -> Self {
+        Self {
+            actions: vec![CommandAction::SetCursorShape(shape)],
+        }
+    }
+
     /// Creates a command that requests the runtime's cancellation token.
     ///
     /// When processed, the runtime calls the provided function with its
@@ -528,6 +785,71 @@ impl<M> Command<M> {
         }
     }
 
+    /// Creates an async command that can be cancelled later by id.
+    ///
+    /// `future` is called with a [`CancellationToken`] and returns the future
+    /// to run. Cancellation is cooperative: [`Command::cancel`] with the same
+    /// id cancels the token, and the future should watch it (for example
+    /// with `token.cancelled()` in a `tokio::select!`) and return early,
+    /// optionally with a message reporting the cancellation.
+    ///
+    /// Issuing another cancellable command with an id that is still
+    /// registered cancels the earlier one first. Like every async command,
+    /// the task is also dropped when the runtime shuts down.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::Command;
+    ///
+    /// #[derive(Clone)]
+    /// enum Msg {
+    ///     ExportDone,
+    ///     ExportCancelled,
+    /// }
+    ///
+    /// let start: Command<Msg> = Command::cancellable("export", |token| async move {
+    ///     tokio::select! {
+    ///         _ = token.cancelled() => Some(Msg::ExportCancelled),
+    ///         _ = tokio::time::sleep(std::time::Duration::from_secs(60)) => Some(Msg::ExportDone),
+    ///     }
+    /// });
+    /// assert!(start.is_async());
+    ///
+    /// // Later, e.g. when Esc is pressed:
+    /// let stop: Command<Msg> = Command::cancel("export");
+    /// ```
+    pub fn cancellable<F, Fut>(id: impl Into<String>, future: F) -> Self
+    where
+        F: FnOnce(CancellationToken) -> Fut + Send + 'static,
+        Fut: Future<Output = Option<M>> + Send + 'static,
+    {
+        let future: CancellableFuture<M> = Box::new(move |token| Box::pin(future(token)));
+        Self {
+            actions: vec![CommandAction::Cancellable(id.into(), future)],
+        }
+    }
+
+    /// Creates a command that cancels the [`cancellable`](Command::cancellable)
+    /// command registered under `id`.
+    ///
+    /// Cancelling an id that is not registered, or whose future has already
+    /// finished, does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::Command;
+    ///
+    /// let cmd: Command<String> = Command::cancel("export");
+    /// assert!(!cmd.is_none());
+    /// ```
+    pub fn cancel(id: impl Into<String>) -> Self {
+        Self {
+            actions: vec![CommandAction::Cancel(id.into())],
+        }
+    }
+
     /// Creates a command that dynamically registers a subscription.
     ///
     /// Use this to add subscriptions from within `update()` — for example,
@@ -625,6 +947,61 @@ impl<M> Command<M> {
         Self { actions }
     }
 
+    /// Creates a command that runs `commands` one after another.
+    ///
+    /// Unlike [`combine`](Command::combine), which starts every command at
+    /// once, each command of a sequence starts only after the async work of
+    /// the previous one has finished and its message has been dispatched.
+    /// This expresses dependent workflows such as save, then reload, then
+    /// notify, without intermediate messages.
+    ///
+    /// Commands without async work run immediately and the sequence moves
+    /// on. A fallible command that fails, or a [`cancellable`](Command::cancellable)
+    /// command that is cancelled, stops the sequence; the commands after it
+    /// never run.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::Command;
+    ///
+    /// #[derive(Clone)]
+    /// enum Msg {
+    ///     Saved,
+    ///     Reloaded,
+    ///     Notified,
+    /// }
+    ///
+    /// let cmd: Command<Msg> = Command::sequence(vec![
+    ///     Command::perform_async(async { Some(Msg::Saved) }),
+    ///     Command::perform_async(async { Some(Msg::Reloaded) }),
+    ///     Command::message(Msg::Notified),
+    /// ]);
+    /// assert!(cmd.is_async());
+    /// ```
+    pub fn sequence(commands: impl IntoIterator<Item = Command<M>>) -> Self {
+        let mut steps = VecDeque::new();
+        for mut cmd in commands {
+            if cmd.is_none() {
+                continue;
+            }
+            // Splice nested sequences in so their steps are awaited too
+            if let [CommandAction::Sequence(_)] = cmd.actions.as_slice() {
+                if let Some(CommandAction::Sequence(inner)) = cmd.actions.pop() {
+                    steps.extend(inner);
+                }
+                continue;
+            }
+            steps.push_back(cmd);
+        }
+        if steps.is_empty() {
+            return Self::none();
+        }
+        Self {
+            actions: vec![CommandAction::Sequence(steps)],
+        }
+    }
+
     /// Appends another command to this one.
     ///
     /// # Example
@@ -691,15 +1068,35 @@ impl<M> Command<M> {
                 }
                 CommandAction::PushOverlay(_) => None,
                 CommandAction::PopOverlay => Some(CommandAction::PopOverlay),
+                CommandAction::RequestRedraw => Some(CommandAction::RequestRedraw),
+                CommandAction::SetTitle(title) => Some(CommandAction::SetTitle(title)),
+                CommandAction::SetCursorShape(shape) => Some(CommandAction::SetCursorShape(shape)),
+                CommandAction::RemoveOverlay(predicate) => {
+                    Some(CommandAction::RemoveOverlay(predicate))
+                }
                 CommandAction::RequestCancelToken(cb) => {
                     let f = f.clone();
                     Some(CommandAction::RequestCancelToken(Box::new(move |token| {
                         f(cb(token))
                     })))
                 }
+                CommandAction::Cancellable(id, make_future) => {
+                    let f = f.clone();
+                    Some(CommandAction::Cancellable(
+                        id,
+                        Box::new(move |token| {
+                            let fut = make_future(token);
+                            Box::pin(async move { fut.await.map(&f) })
+                        }),
+                    ))
+                }
+                CommandAction::Cancel(id) => Some(CommandAction::Cancel(id)),
                 // Subscriptions can't be remapped after boxing — map them
                 // before creating the Command::subscribe.
                 CommandAction::Subscribe(_) => None,
+                CommandAction::Sequence(steps) => Some(CommandAction::Sequence(
+                    steps.into_iter().map(|cmd| cmd.map(f.clone())).collect(),
+                )),
             })
             .collect();
 
@@ -725,6 +1122,10 @@ pub type BoxedFallibleFuture<M> =
 /// A boxed callback that accepts a cancellation token and produces a message.
 pub(crate) type CancelTokenCallback<M> = Box<dyn FnOnce(CancellationToken) -> M + Send + 'static>;
 
+/// A boxed callback that accepts a cancellation token and produces a future.
+pub(crate) type CancellableFuture<M> =
+    Box<dyn FnOnce(CancellationToken) -> BoxedFuture<M> + Send + 'static>;
+
 /// Handles execution of commands.
 ///
 /// This handler processes sync actions immediately and collects async futures
@@ -734,19 +1135,41 @@ pub struct CommandHandler<M> {
     pending_futures: Vec<BoxedFuture<M>>,
     pending_fallible_futures: Vec<BoxedFallibleFuture<M>>,
     pending_cancel_token_requests: Vec<CancelTokenCallback<M>>,
+    parent_token: CancellationToken,
+    named_tokens: HashMap<String, CancellationToken>,
+    in_flight: Vec<tokio::task::JoinHandle<()>>,
+    /// Id given to the next spawned task, to correlate its trace events.
+    #[cfg(feature = "tracing")]
+    next_task_id: u64,
+    continuation_tx: tokio::sync::mpsc::UnboundedSender<Continuation<M>>,
+    continuation_rx: tokio::sync::mpsc::UnboundedReceiver<Continuation<M>>,
 }
 
 impl<M: Send + 'static> CommandHandler<M> {
     /// Creates a new command handler.
     pub fn new() -> Self {
+        let (continuation_tx, continuation_rx) = tokio::sync::mpsc::unbounded_channel();
         Self {
             core: super::command_core::CommandHandlerCore::new(),
             pending_futures: Vec::new(),
             pending_fallible_futures: Vec::new(),
             pending_cancel_token_requests: Vec::new(),
+            parent_token: CancellationToken::new(),
+            named_tokens: HashMap::new(),
+            in_flight: Vec::new(),
+            #[cfg(feature = "tracing")]
+            next_task_id: 0,
+            continuation_tx,
+            continuation_rx,
         }
     }
 
+    /// Sets the token that [`cancellable`](Command::cancellable) tokens are
+    /// derived from, so cancelling it cancels every named command.
+    pub(crate) fn set_parent_token(&mut self, token: CancellationToken) {
+        self.parent_token = token;
+    }
+
     /// Executes a command, collecting sync messages and async futures.
     ///
     /// Sync actions (Message, Batch, Quit, Callback) are processed immediately.
@@ -767,7 +1190,29 @@ impl<M: Send + 'static> CommandHandler<M> {
                     CommandAction::RequestCancelToken(cb) => {
                         self.pending_cancel_token_requests.push(cb);
                     }
-                    _ => unreachable!("execute_action only returns async or cancel-token actions"),
+                    CommandAction::Cancellable(id, make_future) => {
+                        let token = self.parent_token.child_token();
+                        if let Some(previous) = self.named_tokens.insert(id, token.clone()) {
+                            previous.cancel();
+                        }
+                        // Cancel the token once the future finishes or is
+                        // dropped, which marks its entry for removal
+                        let future = make_future(token.clone());
+                        let guard = token.drop_guard();
+                        self.pending_futures.push(Box::pin(async move {
+                            let _guard = guard;
+                            future.await
+                        }));
+                    }
+                    CommandAction::Cancel(id) => {
+                        if let Some(token) = self.named_tokens.remove(&id) {
+                            token.cancel();
+                        }
+                    }
+                    CommandAction::Sequence(steps) => self.execute_sequence(steps),
+                    _ => unreachable!(
+                        "execute_action only returns async, cancel-token, cancellable, or sequence actions"
+                    ),
                 }
             }
         }
@@ -794,14 +1239,23 @@ impl<M: Send + 'static> CommandHandler<M> {
             }
         }
 
+        // Forget tasks that have already finished
+        self.in_flight.retain(|task| !task.is_finished());
+        self.named_tokens.retain(|_, token| !token.is_cancelled());
+
         // Spawn regular async futures
-        for fut in self.pending_futures.drain(..) {
+        for fut in std::mem::take(&mut self.pending_futures) {
             let tx = msg_tx.clone();
             let cancel = cancel.clone();
+            #[cfg(feature = "tracing")]
+            let id = self.spawn_id("async");
 
-            tokio::spawn(async move {
+            let task = tokio::spawn(async move {
                 tokio::select! {
                     result = fut => {
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(id, produced_message = result.is_some(), "async command completed");
+
                         if let Some(msg) = result {
                             // Ignore send errors - the runtime may have shut down
                             let _ = tx.send(msg).await;
@@ -809,20 +1263,28 @@ impl<M: Send + 'static> CommandHandler<M> {
                     }
                     _ = cancel.cancelled() => {
                         // Task was cancelled, exit gracefully
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(id, "async command cancelled");
                     }
                 }
             });
+            self.in_flight.push(task);
         }
 
         // Spawn fallible async futures
-        for fut in self.pending_fallible_futures.drain(..) {
+        for fut in std::mem::take(&mut self.pending_fallible_futures) {
             let msg_tx = msg_tx.clone();
             let err_tx = err_tx.clone();
             let cancel = cancel.clone();
+            #[cfg(feature = "tracing")]
+            let id = self.spawn_id("fallible");
 
-            tokio::spawn(async move {
+            let task = tokio::spawn(async move {
                 tokio::select! {
                     result = fut => {
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(id, failed = result.is_err(), "fallible async command completed");
+
                         match result {
                             Ok(Some(msg)) => {
                                 // Send message on success
@@ -839,12 +1301,64 @@ impl<M: Send + 'static> CommandHandler<M> {
                     }
                     _ = cancel.cancelled() => {
                         // Task was cancelled, exit gracefully
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(id, "fallible async command cancelled");
                     }
                 }
             });
+            self.in_flight.push(task);
         }
     }
 
+    /// Assigns the next task id and traces the spawn of a `kind` command.
+    #[cfg(feature = "tracing")]
+    fn spawn_id(&mut self, kind: &'static str) -> u64 {
+        let id = self.next_task_id;
+        self.next_task_id += 1;
+        tracing::debug!(id, kind, "spawning async command");
+        id
+    }
+
+    /// Takes the queued async futures without spawning them, for callers
+    /// that drive them on the current thread.
+    pub(crate) fn take_pending_futures(
+        &mut self,
+    ) -> (Vec<BoxedFuture<M>>, Vec<BoxedFallibleFuture<M>>) {
+        (
+            std::mem::take(&mut self.pending_futures),
+            std::mem::take(&mut self.pending_fallible_futures),
+        )
+    }
+
+    /// Returns the number of async futures that are queued or still running.
+    pub(crate) fn outstanding_count(&self) -> usize {
+        let running = self
+            .in_flight
+            .iter()
+            .filter(|task| !task.is_finished())
+            .count();
+        running
+            + self.pending_futures.len()
+            + self.pending_fallible_futures.len()
+            + self.continuation_rx.len()
+    }
+
+    /// Takes the next finished step of a [`sequence`](Command::sequence),
+    /// if one is ready.
+    pub(crate) fn try_next_continuation(&mut self) -> Option<Continuation<M>> {
+        self.continuation_rx.try_recv().ok()
+    }
+
+    /// Waits for the next finished step of a [`sequence`](Command::sequence).
+    pub(crate) async fn next_continuation(&mut self) -> Option<Continuation<M>> {
+        self.continuation_rx.recv().await
+    }
+
+    /// Takes the handles of spawned async tasks that may still be running.
+    pub(crate) fn take_in_flight(&mut self) -> Vec<tokio::task::JoinHandle<()>> {
+        std::mem::take(&mut self.in_flight)
+    }
+
     /// Takes all pending messages.
     pub fn take_messages(&mut self) -> Vec<M> {
         self.core.take_messages()
@@ -860,6 +1374,11 @@ impl<M: Send + 'static> CommandHandler<M> {
         self.core.take_overlay_pops()
     }
 
+    /// Takes all pending overlay operations, in the order they were issued.
+    pub(crate) fn take_overlay_ops(&mut self) -> Vec<super::command_core::OverlayOp<M>> {
+        self.core.take_overlay_ops()
+    }
+
     /// Takes all pending dynamic subscription registrations.
     pub(crate) fn take_subscriptions(&mut self) -> Vec<BoxedSubscription<M>> {
         self.core.take_subscriptions()
@@ -885,6 +1404,22 @@ impl<M: Send + 'static> CommandHandler<M> {
         self.core.should_quit()
     }
 
+    /// Returns true if a redraw was requested since the last call, and
+    /// clears the request.
+    pub(crate) fn take_redraw_request(&mut self) -> bool {
+        self.core.take_redraw_request()
+    }
+
+    /// Takes the most recent title set since the last call, if any.
+    pub(crate) fn take_title(&mut self) -> Option<String> {
+        self.core.take_title()
+    }
+
+    /// Takes the most recent cursor shape set since the last call, if any.
+    pub(crate) fn take_cursor_shape(&mut self) -> Option<Option<CursorShape>> {
+        self.core.take_cursor_shape()
+    }
+
     /// Resets the quit flag.
     pub fn reset_quit(&mut self) {
         self.core.reset_quit()
@@ -897,5 +1432,9 @@ impl<M: Send + 'static> Default for CommandHandler<M> {
     }
 }
 
+#[cfg(test)]
+mod cancellable_tests;
+#[cfg(test)]
+mod sequence_tests;
 #[cfg(test)]
 mod tests;
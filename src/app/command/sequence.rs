@@ -0,0 +1,143 @@
+//! Continuations for [`Command::sequence`].
+//!
+//! Each step of a sequence that does async work has its futures wrapped so
+//! that, instead of sending their messages straight to the runtime, they
+//! report a [`Continuation`] through the handler's continuation channel.
+//! The last future of a step to finish carries the remaining steps, which
+//! the runtime executes only after dispatching that future's message.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::{Command, CommandAction, CommandHandler};
+
+/// The outcome of one future of a sequence step.
+pub(crate) struct Continuation<M> {
+    /// The message produced by the future, dispatched first.
+    pub(crate) message: Option<M>,
+    /// The remaining steps, present once the whole step has finished.
+    pub(crate) rest: Option<Command<M>>,
+}
+
+/// Tracks the unfinished futures of a step and the steps that follow it.
+struct StepProgress<M> {
+    remaining: usize,
+    rest: Option<Command<M>>,
+}
+
+/// A handle shared by the wrapped futures of one step.
+struct StepHandle<M> {
+    progress: Arc<Mutex<StepProgress<M>>>,
+    tx: UnboundedSender<Continuation<M>>,
+}
+
+impl<M> Clone for StepHandle<M> {
+    fn clone(&self) -> Self {
+        Self {
+            progress: Arc::clone(&self.progress),
+            tx: self.tx.clone(),
+        }
+    }
+}
+
+impl<M> StepHandle<M> {
+    /// Records a finished future, passing on the rest of the sequence if it
+    /// was the last one. A stopped step dispatches its message but drops
+    /// the rest.
+    fn finish(&self, message: Option<M>, stop: bool) {
+        let rest = {
+            let mut progress = self.progress.lock().unwrap_or_else(|e| e.into_inner());
+            progress.remaining = progress.remaining.saturating_sub(1);
+            if stop {
+                progress.rest = None;
+            }
+            if progress.remaining == 0 {
+                progress.rest.take()
+            } else {
+                None
+            }
+        };
+        if message.is_some() || rest.is_some() {
+            // Ignore send errors - the handler may have been dropped
+            let _ = self.tx.send(Continuation { message, rest });
+        }
+    }
+}
+
+impl<M: Send + 'static> CommandHandler<M> {
+    /// Executes the leading steps of a sequence up to and including the
+    /// first one with async work, deferring the rest until it completes.
+    pub(super) fn execute_sequence(&mut self, mut steps: VecDeque<Command<M>>) {
+        while let Some(step) = steps.pop_front() {
+            if !step.actions.iter().any(is_async_action) {
+                self.execute(step);
+                continue;
+            }
+            let rest = (!steps.is_empty()).then(|| Command::sequence(steps));
+            let step = self.wire_step(step, rest);
+            self.execute(step);
+            return;
+        }
+    }
+
+    /// Wraps the async actions of `step` so they report to the continuation
+    /// channel instead of the message channel.
+    fn wire_step(&self, step: Command<M>, rest: Option<Command<M>>) -> Command<M> {
+        let actions = step.into_actions();
+        let remaining = actions.iter().filter(|a| is_async_action(a)).count();
+        let handle = StepHandle {
+            progress: Arc::new(Mutex::new(StepProgress { remaining, rest })),
+            tx: self.continuation_tx.clone(),
+        };
+
+        let actions = actions
+            .into_iter()
+            .map(|action| match action {
+                CommandAction::Async(fut) => {
+                    let handle = handle.clone();
+                    CommandAction::Async(Box::pin(async move {
+                        handle.finish(fut.await, false);
+                        None
+                    }))
+                }
+                CommandAction::AsyncFallible(fut) => {
+                    let handle = handle.clone();
+                    CommandAction::AsyncFallible(Box::pin(async move {
+                        // A failed step never finishes, so the rest is dropped
+                        let msg = fut.await?;
+                        handle.finish(msg, false);
+                        Ok(None)
+                    }))
+                }
+                CommandAction::Cancellable(id, make_future) => {
+                    let handle = handle.clone();
+                    CommandAction::Cancellable(
+                        id,
+                        Box::new(move |token| {
+                            let fut = make_future(token.clone());
+                            Box::pin(async move {
+                                let msg = fut.await;
+                                handle.finish(msg, token.is_cancelled());
+                                None
+                            })
+                        }),
+                    )
+                }
+                // Nested sequences start here but do not hold up this step
+                other => other,
+            })
+            .collect();
+
+        Command { actions }
+    }
+}
+
+/// Returns true for the actions that hold up a sequence step.
+fn is_async_action<M>(action: &CommandAction<M>) -> bool {
+    matches!(
+        action,
+        CommandAction::Async(_) | CommandAction::AsyncFallible(_) | CommandAction::Cancellable(..)
+    )
+}
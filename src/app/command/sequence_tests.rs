@@ -0,0 +1,218 @@
+//! Tests for `Command::sequence`.
+
+use super::*;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::mpsc;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Msg {
+    Step(u32),
+}
+
+/// An async command that finishes after `millis` with `Msg::Step(n)`.
+fn step(n: u32, millis: u64) -> Command<Msg> {
+    Command::perform_async(async move {
+        tokio::time::sleep(Duration::from_millis(millis)).await;
+        Some(Msg::Step(n))
+    })
+}
+
+/// An async command that records when it starts running.
+fn flagged(n: u32, started: Arc<AtomicBool>) -> Command<Msg> {
+    Command::perform_async(async move {
+        started.store(true, Ordering::SeqCst);
+        Some(Msg::Step(n))
+    })
+}
+
+struct Harness {
+    handler: CommandHandler<Msg>,
+    msg_tx: mpsc::Sender<Msg>,
+    msg_rx: mpsc::Receiver<Msg>,
+    err_tx: mpsc::Sender<BoxedError>,
+    err_rx: mpsc::Receiver<BoxedError>,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let (msg_tx, msg_rx) = mpsc::channel(10);
+        let (err_tx, err_rx) = mpsc::channel(10);
+        Self {
+            handler: CommandHandler::new(),
+            msg_tx,
+            msg_rx,
+            err_tx,
+            err_rx,
+        }
+    }
+
+    fn run(&mut self, command: Command<Msg>) {
+        self.handler.execute(command);
+        self.handler.spawn_pending(
+            self.msg_tx.clone(),
+            self.err_tx.clone(),
+            CancellationToken::new(),
+        );
+    }
+
+    /// Waits for the next finished step, starts the rest of the sequence,
+    /// and returns the step's message.
+    async fn resume(&mut self) -> Option<Msg> {
+        let continuation = self.handler.next_continuation().await?;
+        if let Some(rest) = continuation.rest {
+            self.run(rest);
+        }
+        continuation.message
+    }
+}
+
+#[test]
+fn test_sequence_is_async() {
+    let cmd = Command::sequence(vec![Command::message(Msg::Step(1)), step(2, 10)]);
+    assert!(cmd.is_async());
+    assert_eq!(cmd.action_count(), 1);
+
+    let sync = Command::sequence(vec![Command::message(Msg::Step(1))]);
+    assert!(!sync.is_async());
+}
+
+#[test]
+fn test_empty_sequence_is_none() {
+    assert!(Command::<Msg>::sequence(vec![]).is_none());
+    assert!(Command::<Msg>::sequence(vec![Command::none(), Command::none()]).is_none());
+}
+
+#[test]
+fn test_sync_steps_run_immediately() {
+    let mut handler: CommandHandler<Msg> = CommandHandler::new();
+    handler.execute(Command::sequence(vec![
+        Command::message(Msg::Step(1)),
+        Command::batch(vec![Msg::Step(2), Msg::Step(3)]),
+    ]));
+
+    assert_eq!(
+        handler.take_messages(),
+        vec![Msg::Step(1), Msg::Step(2), Msg::Step(3)]
+    );
+    assert!(handler.try_next_continuation().is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_steps_run_in_order() {
+    let mut h = Harness::new();
+    // The later steps are faster, so only sequencing keeps them in order
+    h.run(Command::sequence(vec![
+        step(1, 300),
+        step(2, 200),
+        step(3, 100),
+    ]));
+
+    assert_eq!(h.resume().await, Some(Msg::Step(1)));
+    assert_eq!(h.resume().await, Some(Msg::Step(2)));
+    assert_eq!(h.resume().await, Some(Msg::Step(3)));
+    assert_eq!(h.handler.outstanding_count(), 0);
+    assert!(h.msg_rx.try_recv().is_err());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_next_step_waits_for_continuation() {
+    let mut h = Harness::new();
+    let started = Arc::new(AtomicBool::new(false));
+    h.run(Command::sequence(vec![
+        step(1, 100),
+        flagged(2, started.clone()),
+    ]));
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    // The first step finished, but its message has not been handled yet
+    assert!(!started.load(Ordering::SeqCst));
+    assert_eq!(h.handler.outstanding_count(), 1);
+
+    assert_eq!(h.resume().await, Some(Msg::Step(1)));
+    assert_eq!(h.resume().await, Some(Msg::Step(2)));
+    assert!(started.load(Ordering::SeqCst));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_step_waits_for_all_its_futures() {
+    let mut h = Harness::new();
+    let started = Arc::new(AtomicBool::new(false));
+    h.run(Command::sequence(vec![
+        step(1, 100).and(step(2, 300)),
+        flagged(3, started.clone()),
+    ]));
+
+    let first = h.handler.next_continuation().await.unwrap();
+    assert_eq!(first.message, Some(Msg::Step(1)));
+    assert!(first.rest.is_none());
+
+    assert_eq!(h.resume().await, Some(Msg::Step(2)));
+    assert_eq!(h.resume().await, Some(Msg::Step(3)));
+    assert!(started.load(Ordering::SeqCst));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_failed_step_stops_sequence() {
+    let mut h = Harness::new();
+    let started = Arc::new(AtomicBool::new(false));
+    h.run(Command::sequence(vec![
+        Command::try_perform_async(
+            async { Err::<u32, _>(std::io::Error::other("save failed")) },
+            |n| Some(Msg::Step(n)),
+        ),
+        flagged(2, started.clone()),
+    ]));
+
+    let err = h.err_rx.recv().await.unwrap();
+    assert!(err.to_string().contains("save failed"));
+    tokio::task::yield_now().await;
+    assert!(h.handler.try_next_continuation().is_none());
+    assert!(!started.load(Ordering::SeqCst));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cancelled_step_stops_sequence() {
+    let mut h = Harness::new();
+    let started = Arc::new(AtomicBool::new(false));
+    h.run(Command::sequence(vec![
+        Command::cancellable("save", |token| async move {
+            token.cancelled().await;
+            Some(Msg::Step(1))
+        }),
+        flagged(2, started.clone()),
+    ]));
+    h.run(Command::cancel("save"));
+
+    let continuation = h.handler.next_continuation().await.unwrap();
+    assert_eq!(continuation.message, Some(Msg::Step(1)));
+    assert!(continuation.rest.is_none());
+    assert!(!started.load(Ordering::SeqCst));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_nested_sequences_are_flattened() {
+    let mut h = Harness::new();
+    h.run(Command::sequence(vec![
+        Command::sequence(vec![step(1, 300), step(2, 200)]),
+        step(3, 100),
+    ]));
+
+    assert_eq!(h.resume().await, Some(Msg::Step(1)));
+    assert_eq!(h.resume().await, Some(Msg::Step(2)));
+    assert_eq!(h.resume().await, Some(Msg::Step(3)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_sequence_map() {
+    let (msg_tx, _msg_rx) = mpsc::channel(10);
+    let (err_tx, _err_rx) = mpsc::channel(10);
+    let mut handler: CommandHandler<String> = CommandHandler::new();
+
+    let cmd = Command::sequence(vec![step(1, 100)]).map(|m| format!("{m:?}"));
+    handler.execute(cmd);
+    handler.spawn_pending(msg_tx, err_tx, CancellationToken::new());
+
+    let continuation = handler.next_continuation().await.unwrap();
+    assert_eq!(continuation.message, Some("Step(1)".to_string()));
+}
@@ -527,6 +527,54 @@ fn test_command_spawn_collects_as_async() {
     assert_eq!(handler.pending_future_count(), 1);
 }
 
+#[tokio::test(start_paused = true)]
+async fn test_with_timeout_completes_in_time() {
+    let mut handler: CommandHandler<TestMsg> = CommandHandler::new();
+    let (msg_tx, mut msg_rx) = mpsc::channel(10);
+    let (err_tx, _err_rx) = mpsc::channel(10);
+
+    handler.execute(Command::with_timeout(
+        Duration::from_secs(1),
+        async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Some(TestMsg::AsyncResult(1))
+        },
+        TestMsg::A,
+    ));
+    handler.spawn_pending(msg_tx, err_tx, CancellationToken::new());
+
+    assert_eq!(msg_rx.recv().await, Some(TestMsg::AsyncResult(1)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_with_timeout_dispatches_on_timeout_and_cancels_future() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let finished = Arc::new(AtomicBool::new(false));
+    let finished_clone = finished.clone();
+
+    let mut handler: CommandHandler<TestMsg> = CommandHandler::new();
+    let (msg_tx, mut msg_rx) = mpsc::channel(10);
+    let (err_tx, _err_rx) = mpsc::channel(10);
+
+    handler.execute(Command::with_timeout(
+        Duration::from_millis(50),
+        async move {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            finished_clone.store(true, Ordering::SeqCst);
+            Some(TestMsg::AsyncResult(1))
+        },
+        TestMsg::A,
+    ));
+    handler.spawn_pending(msg_tx, err_tx, CancellationToken::new());
+
+    assert_eq!(msg_rx.recv().await, Some(TestMsg::A));
+    tokio::time::sleep(Duration::from_secs(20)).await;
+    assert!(!finished.load(Ordering::SeqCst));
+    assert!(msg_rx.try_recv().is_err());
+}
+
 #[tokio::test]
 async fn test_command_spawn_produces_no_message() {
     let mut handler: CommandHandler<TestMsg> = CommandHandler::new();
@@ -705,6 +753,23 @@ mod overlay_tests {
         assert_eq!(pops, 1);
     }
 
+    #[cfg(feature = "display-components")]
+    #[test]
+    fn test_command_handler_show_and_hide_spinner() {
+        let mut handler: CommandHandler<TestMsg> = CommandHandler::new();
+        handler.execute(Command::show_spinner("Loading"));
+        handler.execute(Command::hide_spinner());
+
+        assert_eq!(handler.take_overlay_pushes().len(), 1);
+        assert_eq!(handler.take_overlay_pops(), 0);
+        let ops = handler.take_overlay_ops();
+        let [crate::app::command_core::OverlayOp::Remove(predicate)] = ops[..] else {
+            panic!("expected a single removal");
+        };
+        assert!(predicate(&crate::overlay::SpinnerOverlay::new("Loading")));
+        assert!(!predicate(&TestOverlay));
+    }
+
     #[test]
     fn test_command_handler_multiple_overlay_ops() {
         let mut handler = CommandHandler::new();
@@ -924,6 +989,30 @@ fn test_is_overlay_pop_detects_pop_in_combined_command() {
     assert!(cmd.is_overlay_pop());
 }
 
+#[test]
+fn test_is_redraw_request() {
+    let cmd: Command<TestMsg> = Command::request_redraw();
+    assert!(cmd.is_redraw_request());
+    assert!(!cmd.is_none());
+    assert!(!Command::<TestMsg>::none().is_redraw_request());
+}
+
+#[test]
+fn test_map_preserves_redraw_request() {
+    let cmd: Command<TestMsg> =
+        Command::combine([Command::message(TestMsg::A), Command::request_redraw()]);
+    let mapped: Command<String> = cmd.map(|m| format!("{:?}", m));
+    assert!(mapped.is_redraw_request());
+}
+
+#[test]
+fn test_map_preserves_title() {
+    let cmd: Command<TestMsg> =
+        Command::combine([Command::message(TestMsg::A), Command::set_title("doc")]);
+    let mapped: Command<String> = cmd.map(|m| format!("{:?}", m));
+    assert_eq!(mapped.title(), Some("doc"));
+}
+
 #[test]
 fn test_action_count_zero_for_none() {
     let cmd: Command<TestMsg> = Command::none();
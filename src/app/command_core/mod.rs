@@ -3,21 +3,40 @@
 //! This module provides `CommandHandlerCore`, a struct containing the fields
 //! and methods for managing sync command results used by `CommandHandler`.
 
+use std::any::Any;
+
 use crate::app::subscription::BoxedSubscription;
+use crate::backend::CursorShape;
 use crate::overlay::Overlay;
 
 use super::command::CommandAction;
 
+/// An overlay stack change requested by a command.
+///
+/// Changes are kept in one list so the runtime applies them in the order
+/// they were issued, e.g. a hide followed by a show within one batch.
+pub(crate) enum OverlayOp<M> {
+    /// Push an overlay onto the stack.
+    Push(Box<dyn Overlay<M> + Send>),
+    /// Pop the topmost overlay.
+    Pop,
+    /// Remove the topmost overlay matching the predicate.
+    Remove(fn(&dyn Any) -> bool),
+}
+
 /// Core command handler state.
 ///
 /// Contains the fields and methods for managing sync command results
-/// (messages, overlay operations, quit flag, dynamic subscriptions).
+/// (messages, overlay operations, quit and redraw flags, title and cursor
+/// shape changes, dynamic subscriptions).
 pub(crate) struct CommandHandlerCore<M> {
     pub(crate) pending_messages: Vec<M>,
-    pub(crate) pending_overlay_pushes: Vec<Box<dyn Overlay<M> + Send>>,
-    pub(crate) pending_overlay_pops: usize,
+    pub(crate) pending_overlay_ops: Vec<OverlayOp<M>>,
     pub(crate) pending_subscriptions: Vec<BoxedSubscription<M>>,
     pub(crate) should_quit: bool,
+    pub(crate) redraw_requested: bool,
+    pub(crate) pending_title: Option<String>,
+    pub(crate) pending_cursor_shape: Option<Option<CursorShape>>,
 }
 
 impl<M> CommandHandlerCore<M> {
@@ -25,10 +44,12 @@ impl<M> CommandHandlerCore<M> {
     pub(crate) fn new() -> Self {
         Self {
             pending_messages: Vec::new(),
-            pending_overlay_pushes: Vec::new(),
-            pending_overlay_pops: 0,
+            pending_overlay_ops: Vec::new(),
             pending_subscriptions: Vec::new(),
             should_quit: false,
+            redraw_requested: false,
+            pending_title: None,
+            pending_cursor_shape: None,
         }
     }
 
@@ -60,20 +81,39 @@ impl<M> CommandHandlerCore<M> {
                 None
             }
             CommandAction::PushOverlay(overlay) => {
-                self.pending_overlay_pushes.push(overlay);
+                self.pending_overlay_ops.push(OverlayOp::Push(overlay));
                 None
             }
             CommandAction::PopOverlay => {
-                self.pending_overlay_pops += 1;
+                self.pending_overlay_ops.push(OverlayOp::Pop);
+                None
+            }
+            CommandAction::RemoveOverlay(predicate) => {
+                self.pending_overlay_ops.push(OverlayOp::Remove(predicate));
                 None
             }
             CommandAction::Subscribe(sub) => {
                 self.pending_subscriptions.push(sub);
                 None
             }
+            CommandAction::RequestRedraw => {
+                self.redraw_requested = true;
+                None
+            }
+            CommandAction::SetTitle(title) => {
+                self.pending_title = Some(title);
+                None
+            }
+            CommandAction::SetCursorShape(shape) => {
+                self.pending_cursor_shape = Some(shape);
+                None
+            }
             async_action @ (CommandAction::Async(_)
             | CommandAction::AsyncFallible(_)
-            | CommandAction::RequestCancelToken(_)) => Some(async_action),
+            | CommandAction::RequestCancelToken(_)
+            | CommandAction::Cancellable(..)
+            | CommandAction::Cancel(_)
+            | CommandAction::Sequence(_)) => Some(async_action),
         }
     }
 
@@ -82,14 +122,31 @@ impl<M> CommandHandlerCore<M> {
         std::mem::take(&mut self.pending_messages)
     }
 
-    /// Takes all pending overlay pushes.
+    /// Takes all pending overlay pushes, leaving other overlay operations
+    /// queued.
     pub(crate) fn take_overlay_pushes(&mut self) -> Vec<Box<dyn Overlay<M> + Send>> {
-        std::mem::take(&mut self.pending_overlay_pushes)
+        let mut pushes = Vec::new();
+        for op in std::mem::take(&mut self.pending_overlay_ops) {
+            match op {
+                OverlayOp::Push(overlay) => pushes.push(overlay),
+                op => self.pending_overlay_ops.push(op),
+            }
+        }
+        pushes
     }
 
-    /// Takes the count of pending overlay pops and resets the counter.
+    /// Takes the count of pending overlay pops, leaving other overlay
+    /// operations queued.
     pub(crate) fn take_overlay_pops(&mut self) -> usize {
-        std::mem::replace(&mut self.pending_overlay_pops, 0)
+        let before = self.pending_overlay_ops.len();
+        self.pending_overlay_ops
+            .retain(|op| !matches!(op, OverlayOp::Pop));
+        before - self.pending_overlay_ops.len()
+    }
+
+    /// Takes all pending overlay operations, in the order they were issued.
+    pub(crate) fn take_overlay_ops(&mut self) -> Vec<OverlayOp<M>> {
+        std::mem::take(&mut self.pending_overlay_ops)
     }
 
     /// Takes all pending dynamic subscription registrations.
@@ -106,6 +163,21 @@ impl<M> CommandHandlerCore<M> {
     pub(crate) fn reset_quit(&mut self) {
         self.should_quit = false;
     }
+
+    /// Returns true if a redraw was requested, and clears the request.
+    pub(crate) fn take_redraw_request(&mut self) -> bool {
+        std::mem::replace(&mut self.redraw_requested, false)
+    }
+
+    /// Takes the most recently set title, if any.
+    pub(crate) fn take_title(&mut self) -> Option<String> {
+        self.pending_title.take()
+    }
+
+    /// Takes the most recently set cursor shape, if any.
+    pub(crate) fn take_cursor_shape(&mut self) -> Option<Option<CursorShape>> {
+        self.pending_cursor_shape.take()
+    }
 }
 
 #[cfg(test)]
@@ -24,8 +24,7 @@ impl Overlay<TestMsg> for TestOverlay {
 fn test_new_initializes_empty() {
     let core: CommandHandlerCore<TestMsg> = CommandHandlerCore::new();
     assert!(core.pending_messages.is_empty());
-    assert!(core.pending_overlay_pushes.is_empty());
-    assert_eq!(core.pending_overlay_pops, 0);
+    assert!(core.pending_overlay_ops.is_empty());
     assert!(!core.should_quit());
 }
 
@@ -77,7 +76,7 @@ fn test_execute_action_push_overlay() {
     let overlay = Box::new(TestOverlay);
     let result = core.execute_action(CommandAction::PushOverlay(overlay));
     assert!(result.is_none());
-    assert_eq!(core.pending_overlay_pushes.len(), 1);
+    assert!(matches!(core.pending_overlay_ops[..], [OverlayOp::Push(_)]));
 }
 
 #[test]
@@ -85,10 +84,13 @@ fn test_execute_action_pop_overlay() {
     let mut core: CommandHandlerCore<TestMsg> = CommandHandlerCore::new();
     let result = core.execute_action(CommandAction::PopOverlay);
     assert!(result.is_none());
-    assert_eq!(core.pending_overlay_pops, 1);
+    assert!(matches!(core.pending_overlay_ops[..], [OverlayOp::Pop]));
 
     core.execute_action(CommandAction::PopOverlay);
-    assert_eq!(core.pending_overlay_pops, 2);
+    assert!(matches!(
+        core.pending_overlay_ops[..],
+        [OverlayOp::Pop, OverlayOp::Pop]
+    ));
 }
 
 #[test]
@@ -149,6 +151,21 @@ fn test_take_overlay_pops_returns_count_and_resets() {
     assert_eq!(second, 0);
 }
 
+#[test]
+fn test_take_overlay_ops_keeps_issue_order() {
+    let mut core: CommandHandlerCore<TestMsg> = CommandHandlerCore::new();
+    core.execute_action(CommandAction::RemoveOverlay(|_| true));
+    core.execute_action(CommandAction::PushOverlay(Box::new(TestOverlay)));
+    core.execute_action(CommandAction::PopOverlay);
+
+    assert_eq!(core.take_overlay_pushes().len(), 1);
+    assert!(matches!(
+        core.take_overlay_ops()[..],
+        [OverlayOp::Remove(_), OverlayOp::Pop]
+    ));
+    assert!(core.take_overlay_ops().is_empty());
+}
+
 #[test]
 fn test_should_quit_and_reset_quit() {
     let mut core: CommandHandlerCore<TestMsg> = CommandHandlerCore::new();
@@ -160,3 +177,31 @@ fn test_should_quit_and_reset_quit() {
     core.reset_quit();
     assert!(!core.should_quit());
 }
+
+#[test]
+fn test_execute_action_request_redraw() {
+    let mut core: CommandHandlerCore<TestMsg> = CommandHandlerCore::new();
+    assert!(!core.take_redraw_request());
+
+    let result = core.execute_action(CommandAction::RequestRedraw);
+    assert!(result.is_none());
+    assert!(core.take_redraw_request());
+    assert!(!core.take_redraw_request());
+}
+
+#[test]
+fn test_execute_action_set_title_keeps_latest() {
+    let mut core: CommandHandlerCore<TestMsg> = CommandHandlerCore::new();
+    assert_eq!(core.take_title(), None);
+
+    assert!(
+        core.execute_action(CommandAction::SetTitle("a".into()))
+            .is_none()
+    );
+    assert!(
+        core.execute_action(CommandAction::SetTitle("b".into()))
+            .is_none()
+    );
+    assert_eq!(core.take_title().as_deref(), Some("b"));
+    assert_eq!(core.take_title(), None);
+}
@@ -104,6 +104,7 @@
 //! }
 //! ```
 
+mod clock;
 mod command;
 mod command_core;
 mod model;
@@ -115,21 +116,25 @@ mod subscription;
 mod update;
 pub mod worker;
 
+pub use clock::Clock;
 pub use command::{BoxedError, Command, CommandHandler};
 pub use model::{App, OptionalArgs};
 #[cfg(feature = "serialization")]
 pub use persistence::load_state;
 pub use runtime::terminal::restore_terminal;
 pub use runtime::{
-    ConfiguredRuntimeBuilder, Runtime, RuntimeBuilder, RuntimeConfig, TerminalHook,
-    TerminalRuntime, VirtualRuntime,
+    ConfiguredRuntimeBuilder, DynamicUpdate, Runtime, RuntimeBuilder, RuntimeConfig,
+    RuntimeDiagnostics, StepOutcome, TerminalHook, TerminalRuntime, TickOutcome, VirtualRuntime,
 };
 pub use subscription::{
-    BatchSubscription, BoxedSubscription, ChannelSubscription, DebounceSubscription,
-    FilterSubscription, IntervalImmediateBuilder, IntervalImmediateSubscription,
-    MappedSubscription, StreamSubscription, Subscription, SubscriptionExt, TakeSubscription,
-    TerminalEventSubscription, ThrottleSubscription, TickSubscription, TickSubscriptionBuilder,
-    TimerSubscription, UnboundedChannelSubscription, batch, interval_immediate, terminal_events,
-    tick,
+    AdaptiveIntervalSubscription, BatchSubscription, BoxedSubscription, ChannelSubscription,
+    CombineLatestSubscription, DebounceSubscription, FilterSubscription, IntervalImmediateBuilder,
+    IntervalImmediateSubscription, MappedSubscription, SkipWhileSubscription,
+    StopwatchSubscription, StreamSubscription, Subscription, SubscriptionExt, TakeSubscription,
+    TakeWhileSubscription, TerminalEventSubscription, ThrottleSubscription, TickSubscription,
+    TickSubscriptionBuilder, TimeoutSubscription, TimerSubscription, UnboundedChannelSubscription,
+    UnfoldSubscription, batch, interval_immediate, terminal_events, tick,
 };
+#[cfg(feature = "cron")]
+pub use subscription::{CronSchedule, CronSubscription};
 pub use update::{FnUpdate, StateExt, Update, UpdateResult};
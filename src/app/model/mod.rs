@@ -274,6 +274,26 @@ pub trait App: Sized {
     fn on_tick(_state: &Self::State) -> Option<Self::Message> {
         None
     }
+
+    /// Handle a terminal resize.
+    ///
+    /// Called with the new size whenever an [`Event::Resize`] is processed,
+    /// before the event itself is routed to overlays and
+    /// `handle_event_with_state`. Works the same in terminal and virtual mode.
+    /// Return a message to process, or None to skip.
+    fn on_resize(_state: &Self::State, _width: u16, _height: u16) -> Option<Self::Message> {
+        None
+    }
+
+    /// Describes a message for tracing instrumentation.
+    ///
+    /// With the `tracing` feature enabled, the runtime records this as the
+    /// `message` field of each `dispatch` span. It is only called when that
+    /// span is enabled. Defaults to the message type name; override with
+    /// `format!("{msg:?}")` to record the full message.
+    fn describe_message(_msg: &Self::Message) -> String {
+        std::any::type_name::<Self::Message>().to_string()
+    }
 }
 
 #[cfg(test)]
@@ -93,6 +93,12 @@ fn test_default_on_exit() {
     TestApp::on_exit(&state);
 }
 
+#[test]
+fn test_default_describe_message() {
+    let description = TestApp::describe_message(&TestMsg::Increment);
+    assert!(description.ends_with("TestMsg"));
+}
+
 #[test]
 fn test_app_view() {
     use crate::backend::CaptureBackend;
@@ -171,6 +177,14 @@ impl App for CustomApp {
     fn on_exit(_state: &Self::State) {
         // Could save state or cleanup here
     }
+
+    fn describe_message(msg: &Self::Message) -> String {
+        match msg {
+            CustomMsg::Tick => "Tick".to_string(),
+            CustomMsg::Quit => "Quit".to_string(),
+            CustomMsg::KeyPressed(c) => format!("KeyPressed({c})"),
+        }
+    }
 }
 
 #[test]
@@ -328,3 +342,12 @@ fn test_args_unit_default_works_with_build() {
         .unwrap();
     // No with_args needed — () is OptionalArgs.
 }
+
+#[test]
+fn test_custom_describe_message() {
+    assert_eq!(CustomApp::describe_message(&CustomMsg::Tick), "Tick");
+    assert_eq!(
+        CustomApp::describe_message(&CustomMsg::KeyPressed('x')),
+        "KeyPressed(x)"
+    );
+}
@@ -0,0 +1,151 @@
+//! Driving async commands on the current thread, without tokio.
+//!
+//! Outside a tokio runtime the async futures of a [`Command`] cannot be
+//! spawned, so they stay queued. [`Runtime::run_commands_blocking`] polls
+//! them to completion one by one with a minimal executor that parks the
+//! thread between polls, so update logic that returns async commands can
+//! be tested from a plain `#[test]`.
+//!
+//! [`Command`]: crate::app::Command
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use ratatui::backend::Backend;
+
+use super::Runtime;
+use crate::app::model::App;
+
+impl<A: App, B: Backend> Runtime<A, B> {
+    /// Runs queued commands to completion on the current thread.
+    ///
+    /// Processes queued messages, then drives each pending async command
+    /// future to completion in turn and dispatches the message it
+    /// produces. Errors from fallible commands are collected for
+    /// [`take_errors`](Self::take_errors). Commands started by those
+    /// messages, including the later steps of a
+    /// [`Command::sequence`](crate::app::Command::sequence), are run too,
+    /// so when this returns the app has reached a settled state.
+    ///
+    /// This is meant for synchronous unit tests of update logic. Outside a
+    /// tokio runtime, async commands are queued rather than spawned, and
+    /// nothing runs them until this is called. Inside a tokio runtime they
+    /// are spawned as usual, and this only processes what has already
+    /// arrived.
+    ///
+    /// The executor has no reactor: futures that compute their result, or
+    /// await other such futures, resolve. Futures that need tokio — timers,
+    /// sockets, `spawn_blocking` — panic, and futures that wait on
+    /// something nothing will ever deliver block forever. After the
+    /// runtime quits, queued futures are dropped without running.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::prelude::*;
+    ///
+    /// struct Counter;
+    ///
+    /// #[derive(Clone)]
+    /// enum Msg {
+    ///     Fetch,
+    ///     Loaded(u32),
+    /// }
+    ///
+    /// impl App for Counter {
+    ///     type State = u32;
+    ///     type Message = Msg;
+    ///     type Args = ();
+    ///
+    ///     fn init(_args: ()) -> (u32, Command<Msg>) {
+    ///         (0, Command::none())
+    ///     }
+    ///
+    ///     fn update(state: &mut u32, msg: Msg) -> Command<Msg> {
+    ///         match msg {
+    ///             Msg::Fetch => Command::perform_async(async { Some(Msg::Loaded(42)) }),
+    ///             Msg::Loaded(value) => {
+    ///                 *state = value;
+    ///                 Command::none()
+    ///             }
+    ///         }
+    ///     }
+    ///
+    ///     fn view(_state: &u32, _frame: &mut Frame) {}
+    /// }
+    ///
+    /// // No tokio runtime here.
+    /// let mut vt = Runtime::<Counter, _>::virtual_builder(10, 1).build()?;
+    /// vt.dispatch(Msg::Fetch);
+    /// assert_eq!(*vt.state(), 0);
+    ///
+    /// vt.run_commands_blocking();
+    /// assert_eq!(*vt.state(), 42);
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn run_commands_blocking(&mut self) {
+        loop {
+            let dispatched = self.dispatched;
+            self.process_commands();
+            self.process_async_messages();
+
+            let (futures, fallible) = self.commands.take_pending_futures();
+            if self.core.should_quit || self.cancel_token.is_cancelled() {
+                break;
+            }
+            if futures.is_empty() && fallible.is_empty() {
+                if self.dispatched == dispatched {
+                    break;
+                }
+                continue;
+            }
+
+            for fut in futures {
+                if let Some(msg) = block_on(fut) {
+                    self.dispatch(msg);
+                }
+            }
+            for fut in fallible {
+                match block_on(fut) {
+                    Ok(Some(msg)) => self.dispatch(msg),
+                    Ok(None) => {}
+                    Err(err) => {
+                        // A full error queue drops the error, as a spawned
+                        // command would if the runtime stopped reading.
+                        let _ = self.error_tx.try_send(err);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Wakes a parked thread.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Polls `future` to completion, parking the current thread until it is
+/// woken between polls.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::park();
+    }
+}
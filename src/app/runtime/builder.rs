@@ -117,14 +117,18 @@ use std::io::Stdout;
 use std::marker::PhantomData;
 use std::time::Duration;
 
+use crossterm::ExecutableCommand;
+use crossterm::cursor::SetCursorStyle;
+use crossterm::terminal::SetTitle;
 use ratatui::backend::{Backend, CrosstermBackend};
 
-use super::Runtime;
 use super::builder_configured::ConfiguredRuntimeBuilder;
 use super::config::RuntimeConfig;
+use super::{CursorShapeWriter, Runtime, TitleWriter};
 use crate::app::model::App;
 use crate::backend::CaptureBackend;
 use crate::error;
+use crate::input::Event;
 
 /// A builder for constructing [`Runtime`] instances.
 ///
@@ -168,6 +172,9 @@ use crate::error;
 pub struct RuntimeBuilder<A: App, B: Backend> {
     backend: B,
     config: Option<RuntimeConfig>,
+    initial_events: Vec<Event>,
+    title_writer: Option<TitleWriter<B>>,
+    cursor_shape_writer: Option<CursorShapeWriter<B>>,
     _phantom: PhantomData<A>,
 }
 
@@ -181,6 +188,9 @@ impl<A: App, B: Backend> RuntimeBuilder<A, B> {
         Self {
             backend,
             config: None,
+            initial_events: Vec::new(),
+            title_writer: None,
+            cursor_shape_writer: None,
             _phantom: PhantomData,
         }
     }
@@ -227,6 +237,9 @@ impl<A: App, B: Backend> RuntimeBuilder<A, B> {
         ConfiguredRuntimeBuilder {
             backend: self.backend,
             config: self.config,
+            initial_events: self.initial_events,
+            title_writer: self.title_writer,
+            cursor_shape_writer: self.cursor_shape_writer,
             args,
         }
     }
@@ -332,6 +345,14 @@ impl<A: App, B: Backend> RuntimeBuilder<A, B> {
         self
     }
 
+    /// Sets whether to render only when a redraw is requested.
+    ///
+    /// See [`RuntimeConfig::render_on_demand`]. Default: false.
+    pub fn render_on_demand(mut self, enabled: bool) -> Self {
+        self.config_mut().render_on_demand = enabled;
+        self
+    }
+
     /// Sets the maximum number of messages to process per tick.
     ///
     /// This prevents infinite loops when messages trigger other messages.
@@ -349,6 +370,47 @@ impl<A: App, B: Backend> RuntimeBuilder<A, B> {
         self
     }
 
+    /// Queues events to process on the first tick (or first loop iteration
+    /// of [`run`](Runtime::run)).
+    ///
+    /// Use this to start an app already in a given interaction state, for
+    /// demos or scripted tests. Events are processed after the init command,
+    /// in order. Calling this again appends more events.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Clone)]
+    /// # enum MyMsg { Typed(char) }
+    /// # impl App for MyApp {
+    /// #     type State = String;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_: ()) -> (String, Command<MyMsg>) { (String::new(), Command::none()) }
+    /// #     fn update(state: &mut String, msg: MyMsg) -> Command<MyMsg> {
+    /// #         let MyMsg::Typed(c) = msg;
+    /// #         state.push(c);
+    /// #         Command::none()
+    /// #     }
+    /// #     fn view(state: &String, frame: &mut Frame) {}
+    /// #     fn handle_event(event: &Event) -> Option<MyMsg> {
+    /// #         event.as_key().and_then(|key| key.raw_char).map(MyMsg::Typed)
+    /// #     }
+    /// # }
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(80, 24)
+    ///     .initial_events([Event::char('h'), Event::char('i')])
+    ///     .build()?;
+    /// vt.tick()?;
+    /// assert_eq!(vt.state(), "hi");
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn initial_events(mut self, events: impl IntoIterator<Item = Event>) -> Self {
+        self.initial_events.extend(events);
+        self
+    }
+
     /// Returns a mutable reference to the config, creating a default if needed.
     fn config_mut(&mut self) -> &mut RuntimeConfig {
         self.config.get_or_insert_with(RuntimeConfig::default)
@@ -487,7 +549,13 @@ impl<A: App> Runtime<A, CrosstermBackend<Stdout>> {
     pub fn terminal_builder() -> error::Result<RuntimeBuilder<A, CrosstermBackend<Stdout>>> {
         let config = RuntimeConfig::default();
         let backend = Self::setup_terminal(&config)?;
-        Ok(RuntimeBuilder::new(backend))
+        let mut builder = RuntimeBuilder::new(backend);
+        builder.title_writer = Some(|backend, title| backend.execute(SetTitle(title)).map(|_| ()));
+        builder.cursor_shape_writer = Some(|backend, shape| {
+            let style = shape.map_or(SetCursorStyle::DefaultUserShape, SetCursorStyle::from);
+            backend.execute(style).map(|_| ())
+        });
+        Ok(builder)
     }
 }
 
@@ -524,7 +592,16 @@ impl<A: App> Runtime<A, CaptureBackend> {
     /// ```
     pub fn virtual_builder(width: u16, height: u16) -> RuntimeBuilder<A, CaptureBackend> {
         let backend = CaptureBackend::new(width, height);
-        RuntimeBuilder::new(backend)
+        let mut builder = RuntimeBuilder::new(backend);
+        builder.title_writer = Some(|backend, title| {
+            backend.set_title(title);
+            Ok(())
+        });
+        builder.cursor_shape_writer = Some(|backend, shape| {
+            backend.set_cursor_shape(shape);
+            Ok(())
+        });
+        builder
     }
 }
 
@@ -10,16 +10,18 @@ use std::time::Duration;
 
 use ratatui::backend::Backend;
 
-use super::Runtime;
 use super::config::RuntimeConfig;
+use super::{CursorShapeWriter, Runtime, TitleWriter};
 use crate::app::model::App;
 use crate::error;
+use crate::input::Event;
 
 /// A `RuntimeBuilder` after [`with_args`](super::RuntimeBuilder::with_args) has been called.
 ///
 /// Carries the args that will be passed to [`App::init`] plus all
 /// configuration set so far. Has its own fluent config-shaping methods
-/// (`config`, `tick_rate`, `frame_rate`, `max_messages`, `channel_capacity`)
+/// (`config`, `tick_rate`, `frame_rate`, `max_messages`, `channel_capacity`,
+/// `initial_events`)
 /// and an unconditionally-available `build()`.
 ///
 /// Most users never name this type — the typestate transition happens
@@ -27,6 +29,9 @@ use crate::error;
 pub struct ConfiguredRuntimeBuilder<A: App, B: Backend> {
     pub(super) backend: B,
     pub(super) config: Option<RuntimeConfig>,
+    pub(super) initial_events: Vec<Event>,
+    pub(super) title_writer: Option<TitleWriter<B>>,
+    pub(super) cursor_shape_writer: Option<CursorShapeWriter<B>>,
     pub(super) args: A::Args,
 }
 
@@ -49,6 +54,12 @@ impl<A: App, B: Backend> ConfiguredRuntimeBuilder<A, B> {
         self
     }
 
+    /// Sets whether to render only when a redraw is requested. Default: false.
+    pub fn render_on_demand(mut self, enabled: bool) -> Self {
+        self.config_mut().render_on_demand = enabled;
+        self
+    }
+
     /// Sets the max messages per tick. Default: 100.
     pub fn max_messages(mut self, max: usize) -> Self {
         self.config_mut().max_messages_per_tick = max;
@@ -61,6 +72,13 @@ impl<A: App, B: Backend> ConfiguredRuntimeBuilder<A, B> {
         self
     }
 
+    /// Queues events to process on the first tick. See
+    /// [`RuntimeBuilder::initial_events`](super::RuntimeBuilder::initial_events).
+    pub fn initial_events(mut self, events: impl IntoIterator<Item = Event>) -> Self {
+        self.initial_events.extend(events);
+        self
+    }
+
     fn config_mut(&mut self) -> &mut RuntimeConfig {
         self.config.get_or_insert_with(RuntimeConfig::default)
     }
@@ -78,6 +96,11 @@ impl<A: App, B: Backend> ConfiguredRuntimeBuilder<A, B> {
     pub fn build(self) -> error::Result<Runtime<A, B>> {
         let (state, init_cmd) = A::init(self.args);
         let config = self.config.unwrap_or_default();
-        Runtime::with_backend_state_and_config(self.backend, state, init_cmd, config)
+        let mut runtime =
+            Runtime::with_backend_state_and_config(self.backend, state, init_cmd, config)?;
+        runtime.set_title_writer(self.title_writer);
+        runtime.set_cursor_shape_writer(self.cursor_shape_writer);
+        runtime.preload(self.initial_events);
+        Ok(runtime)
     }
 }
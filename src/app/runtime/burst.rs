@@ -0,0 +1,91 @@
+//! Coalescing of input event bursts read in terminal mode.
+//!
+//! The terminal loop drains every immediately-available crossterm event
+//! before yielding, so a paste or a fast mouse sweep is handled in one pass
+//! and covered by a single render. Events that only matter in their latest
+//! form are collapsed first. Resizes are additionally throttled across
+//! bursts by [`ResizeDebouncer`], since a window drag delivers them over
+//! many reads.
+
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::input::{Event, MouseEventKind};
+
+/// Collapses redundant events in a burst read from the terminal.
+///
+/// A run of consecutive `Resize` events keeps only the last, since only the
+/// final size matters. A run of consecutive mouse moves likewise keeps only
+/// the last position. All other events pass through in order.
+pub(crate) fn coalesce_events(events: Vec<Event>) -> Vec<Event> {
+    let mut coalesced: Vec<Event> = Vec::with_capacity(events.len());
+    for event in events {
+        match coalesced.last_mut() {
+            Some(last) if supersedes(last, &event) => *last = event,
+            _ => coalesced.push(event),
+        }
+    }
+    coalesced
+}
+
+/// Returns true if `next` makes `prev` redundant when they are adjacent.
+fn supersedes(prev: &Event, next: &Event) -> bool {
+    match (prev, next) {
+        (Event::Resize(..), Event::Resize(..)) => true,
+        (Event::Mouse(prev), Event::Mouse(next)) => {
+            prev.kind == MouseEventKind::Moved && next.kind == MouseEventKind::Moved
+        }
+        _ => false,
+    }
+}
+
+/// Holds back terminal resizes so a window drag re-lays out at most once
+/// per window.
+///
+/// The first resize starts the window; later ones only replace the held
+/// size. The held resize is released when the window ends, or earlier if
+/// any other event arrives, so events keep their order relative to it.
+#[derive(Debug)]
+pub(crate) struct ResizeDebouncer {
+    window: Duration,
+    pending: Option<Event>,
+    deadline: Option<Instant>,
+}
+
+impl ResizeDebouncer {
+    /// Creates a debouncer; a zero `window` passes resizes straight through.
+    pub(crate) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: None,
+            deadline: None,
+        }
+    }
+
+    /// Takes an event read at `now`, returning the events to handle now.
+    pub(crate) fn offer(&mut self, event: Event, now: Instant) -> Vec<Event> {
+        if self.window.is_zero() {
+            return vec![event];
+        }
+        if let Event::Resize(..) = event {
+            self.pending = Some(event);
+            self.deadline.get_or_insert(now + self.window);
+            return Vec::new();
+        }
+        let mut ready: Vec<Event> = self.take().into_iter().collect();
+        ready.push(event);
+        ready
+    }
+
+    /// Returns when the held resize is due, if one is held.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Releases the held resize, if any.
+    pub(crate) fn take(&mut self) -> Option<Event> {
+        self.deadline = None;
+        self.pending.take()
+    }
+}
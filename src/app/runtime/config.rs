@@ -28,10 +28,23 @@ pub struct RuntimeConfig {
     /// How often to render (default: 16ms for ~60fps)
     pub frame_rate: Duration,
 
+    /// Whether scheduled renders only draw when a redraw was requested
+    /// (default: false). See [`render_on_demand`](Self::render_on_demand).
+    pub render_on_demand: bool,
+
+    /// Whether the terminal loop forwards key release and repeat events
+    /// (default: false). See [`forward_key_kinds`](Self::forward_key_kinds).
+    pub forward_key_kinds: bool,
+
+    /// How long the terminal loop holds a resize to coalesce it with the
+    /// ones that follow (default: 16ms). See
+    /// [`resize_debounce`](Self::resize_debounce).
+    pub resize_debounce: Duration,
+
     /// Maximum number of messages to process per tick (prevents infinite loops)
     pub max_messages_per_tick: usize,
 
-    /// Whether to capture frame history
+    /// Whether to capture frame history (see [`Runtime::frame_history`](super::Runtime::frame_history))
     pub capture_history: bool,
 
     /// Number of frames to keep in history
@@ -58,6 +71,9 @@ impl fmt::Debug for RuntimeConfig {
         f.debug_struct("RuntimeConfig")
             .field("tick_rate", &self.tick_rate)
             .field("frame_rate", &self.frame_rate)
+            .field("render_on_demand", &self.render_on_demand)
+            .field("forward_key_kinds", &self.forward_key_kinds)
+            .field("resize_debounce", &self.resize_debounce)
             .field("max_messages_per_tick", &self.max_messages_per_tick)
             .field("capture_history", &self.capture_history)
             .field("history_capacity", &self.history_capacity)
@@ -73,6 +89,9 @@ impl Default for RuntimeConfig {
         Self {
             tick_rate: Duration::from_millis(50),
             frame_rate: Duration::from_millis(16),
+            render_on_demand: false,
+            forward_key_kinds: false,
+            resize_debounce: Duration::from_millis(16),
             max_messages_per_tick: 100,
             capture_history: false,
             history_capacity: 10,
@@ -101,7 +120,89 @@ impl RuntimeConfig {
         self
     }
 
+    /// Sets whether the runtime renders only on demand.
+    ///
+    /// When enabled, the render interval (and [`Runtime::tick`](super::Runtime::tick))
+    /// skips drawing unless a redraw was requested since the last frame,
+    /// via [`Command::request_redraw`](crate::app::Command::request_redraw)
+    /// or [`Runtime::request_redraw`](super::Runtime::request_redraw). Apps
+    /// with expensive views can use this to avoid re-rendering identical
+    /// frames.
+    ///
+    /// The runtime still redraws on its own after a resize, while overlays
+    /// are showing, and when the last overlay closes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::RuntimeConfig;
+    ///
+    /// let config = RuntimeConfig::new().render_on_demand(true);
+    /// assert!(config.render_on_demand);
+    /// ```
+    pub fn render_on_demand(mut self, enabled: bool) -> Self {
+        self.render_on_demand = enabled;
+        self
+    }
+
+    /// Sets whether the terminal loop forwards key release and repeat events.
+    ///
+    /// By default only key presses reach the app. When enabled, the
+    /// runtime also asks the terminal to report releases and auto-repeats
+    /// and delivers them as [`Event::Key`](crate::input::Event::Key) with
+    /// the matching [`KeyEventKind`](crate::input::KeyEventKind), for apps
+    /// that react to keys being held. Only terminals that support the
+    /// kitty keyboard protocol (and Windows consoles) report them; others
+    /// keep sending presses alone.
+    ///
+    /// Releases bypass overlays and go straight to
+    /// [`App::handle_event`](crate::App::handle_event), and built-in
+    /// components ignore them, so a key still acts once per press. Repeats
+    /// are handled like presses everywhere. An app that matches keys itself
+    /// should check [`KeyEvent::kind`](crate::input::KeyEvent::kind), or
+    /// read keys with [`Event::as_key_press`](crate::input::Event::as_key_press).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::RuntimeConfig;
+    ///
+    /// let config = RuntimeConfig::new().forward_key_kinds(true);
+    /// assert!(config.forward_key_kinds);
+    /// ```
+    pub fn forward_key_kinds(mut self, enabled: bool) -> Self {
+        self.forward_key_kinds = enabled;
+        self
+    }
+
+    /// Sets how long the terminal loop holds a resize before handling it.
+    ///
+    /// Dragging a window edge makes the terminal report a stream of
+    /// resizes. Rather than re-laying out for each, the terminal loop
+    /// handles at most one per `window`, with the latest size; any other
+    /// input releases the held resize first, so event order is kept.
+    /// `Duration::ZERO` handles every resize as soon as it is read.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::RuntimeConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = RuntimeConfig::new().resize_debounce(Duration::from_millis(50));
+    /// assert_eq!(config.resize_debounce, Duration::from_millis(50));
+    /// ```
+    pub fn resize_debounce(mut self, window: Duration) -> Self {
+        self.resize_debounce = window;
+        self
+    }
+
     /// Enables frame history capture.
+    ///
+    /// The runtime keeps the last `capacity` rendered frames, retrievable
+    /// with [`Runtime::frame_history`](super::Runtime::frame_history). Each
+    /// frame is rendered twice, once to the terminal and once into a
+    /// capture buffer.
     pub fn with_history(mut self, capacity: usize) -> Self {
         self.capture_history = true;
         self.history_capacity = capacity;
@@ -243,6 +344,30 @@ mod tests {
         assert!(config.on_teardown.is_none());
     }
 
+    #[test]
+    fn test_forward_key_kinds_off_by_default() {
+        assert!(!RuntimeConfig::default().forward_key_kinds);
+        assert!(
+            RuntimeConfig::new()
+                .forward_key_kinds(true)
+                .forward_key_kinds
+        );
+    }
+
+    #[test]
+    fn test_resize_debounce_defaults_to_one_frame() {
+        assert_eq!(
+            RuntimeConfig::default().resize_debounce,
+            Duration::from_millis(16)
+        );
+        assert_eq!(
+            RuntimeConfig::new()
+                .resize_debounce(Duration::ZERO)
+                .resize_debounce,
+            Duration::ZERO
+        );
+    }
+
     #[test]
     fn test_on_setup_hook_stored() {
         let config = RuntimeConfig::new().on_setup(Arc::new(|| Ok(())));
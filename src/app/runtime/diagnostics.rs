@@ -0,0 +1,97 @@
+//! Runtime diagnostics snapshot.
+
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A point-in-time snapshot of the runtime's outstanding work.
+///
+/// Returned by [`Runtime::diagnostics`](super::Runtime::diagnostics). Use it
+/// to see why an app hasn't settled — for example, a subscription that is
+/// still running or a command that never completed. The [`Display`]
+/// impl renders a one-line summary suitable for a debug overlay.
+///
+/// [`Display`]: fmt::Display
+///
+/// # Example
+///
+/// ```rust
+/// use envision::app::RuntimeDiagnostics;
+///
+/// let diagnostics = RuntimeDiagnostics {
+///     active_subscriptions: 2,
+///     pending_commands: 1,
+///     queued_events: 0,
+///     pending_messages: 3,
+///     pending_errors: 0,
+/// };
+/// assert_eq!(
+///     diagnostics.to_string(),
+///     "subscriptions: 2, commands: 1, events: 0, messages: 3, errors: 0",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RuntimeDiagnostics {
+    /// Subscriptions whose streams are still running.
+    pub active_subscriptions: usize,
+
+    /// Async commands that are queued or still running.
+    pub pending_commands: usize,
+
+    /// Events waiting in the event queue.
+    pub queued_events: usize,
+
+    /// Messages from async tasks waiting in the message channel.
+    pub pending_messages: usize,
+
+    /// Errors from async tasks not yet collected with
+    /// [`take_errors`](super::Runtime::take_errors).
+    pub pending_errors: usize,
+}
+
+impl RuntimeDiagnostics {
+    /// Returns true if the runtime has no outstanding work.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::RuntimeDiagnostics;
+    ///
+    /// assert!(RuntimeDiagnostics::default().is_idle());
+    /// ```
+    pub fn is_idle(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl fmt::Display for RuntimeDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "subscriptions: {}, commands: {}, events: {}, messages: {}, errors: {}",
+            self.active_subscriptions,
+            self.pending_commands,
+            self.queued_events,
+            self.pending_messages,
+            self.pending_errors,
+        )
+    }
+}
+
+/// Decrements the active subscription count when a subscription task ends,
+/// including when its stream panics.
+pub(crate) struct ActiveGuard(Arc<AtomicUsize>);
+
+impl ActiveGuard {
+    /// Increments `count` and returns a guard that decrements it on drop.
+    pub(crate) fn new(count: Arc<AtomicUsize>) -> Self {
+        count.fetch_add(1, Ordering::SeqCst);
+        Self(count)
+    }
+}
+
+impl Drop for ActiveGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
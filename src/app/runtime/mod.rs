@@ -69,16 +69,27 @@
 //!
 //! Events are injected programmatically and the display can be inspected.
 
+mod blocking;
 mod builder;
 mod builder_configured;
+mod burst;
 mod config;
+mod diagnostics;
+mod step;
 pub(crate) mod terminal;
+mod tick_outcome;
 mod virtual_terminal;
 pub use builder::RuntimeBuilder;
 pub use builder_configured::ConfiguredRuntimeBuilder;
 pub use config::{RuntimeConfig, TerminalHook};
+pub use diagnostics::RuntimeDiagnostics;
+pub use step::StepOutcome;
+pub use tick_outcome::TickOutcome;
 
 use std::io::Stdout;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 use crate::error;
 use std::pin::Pin;
@@ -89,12 +100,14 @@ use tokio::sync::mpsc;
 use tokio_stream::StreamExt;
 use tokio_util::sync::CancellationToken;
 
-use super::command::{BoxedError, Command, CommandHandler};
+use super::clock::Clock;
+use super::command::{BoxedError, Command, CommandHandler, Continuation};
+use super::command_core::OverlayOp;
 use super::model::App;
 use super::runtime_core::{ProcessEventResult, RuntimeCore};
 use super::subscription::{BoxedSubscription, Subscription};
-use crate::backend::CaptureBackend;
-use crate::input::EventQueue;
+use crate::backend::{CaptureBackend, CursorShape, FrameSnapshot};
+use crate::input::{Event, EventQueue, KeyMap};
 use crate::overlay::{Overlay, OverlayStack};
 use crate::theme::Theme;
 
@@ -126,8 +139,64 @@ pub struct Runtime<A: App, B: Backend> {
 
     /// Cancellation token for graceful shutdown
     cancel_token: CancellationToken,
+
+    /// Time source for tick timing
+    clock: Clock,
+
+    /// Clock reading at the previous tick
+    last_tick: Instant,
+
+    /// Number of messages dispatched to `App::update` so far
+    dispatched: usize,
+
+    /// Number of frames rendered so far
+    rendered: u64,
+
+    /// Whether the screen needs drawing when rendering on demand
+    redraw_requested: bool,
+
+    /// Most recent frames, oldest first (when history capture is enabled)
+    frame_history: Vec<FrameSnapshot>,
+
+    /// Number of subscription tasks still running
+    active_subscriptions: Arc<AtomicUsize>,
+
+    /// Event loop timers kept between calls to `step`
+    step_timers: Option<step::LoopTimers>,
+
+    /// Most recent window title set with `Command::set_title`
+    title: Option<String>,
+
+    /// Applies window titles to the backend, if it supports them
+    title_writer: Option<TitleWriter<B>>,
+
+    /// Most recent cursor shape set with `Command::set_cursor_shape`
+    cursor_shape: Option<CursorShape>,
+
+    /// Applies cursor shapes to the backend, if it supports them
+    cursor_shape_writer: Option<CursorShapeWriter<B>>,
+
+    /// Replacement for `App::update`, if one was installed
+    dynamic_update: Option<DynamicUpdate<A>>,
 }
 
+/// Applies a window title to a backend.
+///
+/// Set by the backend-specific builder entry points; runtimes on other
+/// backends only record the title.
+pub(crate) type TitleWriter<B> = fn(&mut B, &str) -> std::io::Result<()>;
+
+/// Applies a cursor shape to a backend, with `None` for the default shape.
+///
+/// Set by the backend-specific builder entry points; runtimes on other
+/// backends only record the shape.
+pub(crate) type CursorShapeWriter<B> = fn(&mut B, Option<CursorShape>) -> std::io::Result<()>;
+
+/// An update function installed with [`Runtime::with_dynamic_update`].
+pub type DynamicUpdate<A> = Box<
+    dyn FnMut(&mut <A as App>::State, <A as App>::Message) -> Command<<A as App>::Message> + Send,
+>;
+
 /// Alias for a runtime using the crossterm terminal backend (production).
 ///
 /// This is the type returned by [`Runtime::terminal_builder()`] followed by
@@ -215,6 +284,7 @@ impl<A: App, B: Backend> Runtime<A, B> {
         let cancel_token = CancellationToken::new();
 
         let mut commands = CommandHandler::new();
+        commands.set_parent_token(cancel_token.clone());
         commands.execute(init_cmd);
 
         let mut runtime = Self {
@@ -224,6 +294,7 @@ impl<A: App, B: Backend> Runtime<A, B> {
                 events: EventQueue::new(),
                 overlay_stack: OverlayStack::new(),
                 theme: Theme::default(),
+                keymap: KeyMap::new(),
                 should_quit: false,
                 max_messages_per_tick: config.max_messages_per_tick,
             },
@@ -234,7 +305,26 @@ impl<A: App, B: Backend> Runtime<A, B> {
             error_tx,
             error_rx,
             cancel_token,
+            clock: Clock::system(),
+            last_tick: Instant::now(),
+            dispatched: 0,
+            rendered: 0,
+            redraw_requested: true,
+            frame_history: Vec::new(),
+            active_subscriptions: Arc::new(AtomicUsize::new(0)),
+            step_timers: None,
+            title: None,
+            title_writer: None,
+            cursor_shape: None,
+            cursor_shape_writer: None,
+            dynamic_update: None,
         };
+        if let Some(title) = runtime.commands.take_title() {
+            runtime.apply_title(title);
+        }
+        if let Some(shape) = runtime.commands.take_cursor_shape() {
+            runtime.apply_cursor_shape(shape);
+        }
 
         // Spawn any async commands from init
         runtime.spawn_pending_commands();
@@ -317,6 +407,41 @@ impl<A: App, B: Backend> Runtime<A, B> {
         &mut self.core.terminal
     }
 
+    /// Returns the current terminal size as `(width, height)`.
+    ///
+    /// Delegates to the backend, so in virtual mode this is the size the
+    /// runtime was created with. Returns `(0, 0)` if the backend cannot
+    /// report its size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState;
+    /// # #[derive(Clone)]
+    /// # enum MyMsg {}
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState, Command::none()) }
+    /// #     fn update(_state: &mut MyState, _msg: MyMsg) -> Command<MyMsg> { Command::none() }
+    /// #     fn view(_state: &MyState, _frame: &mut Frame) {}
+    /// # }
+    /// let vt = Runtime::<MyApp, _>::virtual_builder(80, 24).build()?;
+    /// assert_eq!(vt.size(), (80, 24));
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn size(&self) -> (u16, u16) {
+        self.core
+            .terminal
+            .size()
+            .map(|size| (size.width, size.height))
+            .unwrap_or_default()
+    }
+
     /// Returns a reference to the backend.
     pub fn backend(&self) -> &B {
         self.core.terminal.backend()
@@ -332,6 +457,17 @@ impl<A: App, B: Backend> Runtime<A, B> {
         &mut self.core.events
     }
 
+    /// Appends events to the queue, to be processed on the next tick.
+    ///
+    /// Unlike [`send`](Runtime::send), this works with any backend, so
+    /// events can be queued before [`run`](Runtime::run) or
+    /// [`run_terminal`](Runtime::run_terminal) starts the event loop. To
+    /// queue events at construction, use
+    /// [`RuntimeBuilder::initial_events`](crate::app::RuntimeBuilder::initial_events).
+    pub fn preload(&mut self, events: impl IntoIterator<Item = Event>) {
+        self.core.events.extend(events);
+    }
+
     /// Returns a clone of the cancellation token.
     ///
     /// Tasks can use this token to detect shutdown and cancel gracefully.
@@ -420,6 +556,46 @@ impl<A: App, B: Backend> Runtime<A, B> {
         !self.error_rx.is_empty()
     }
 
+    /// Returns a snapshot of the runtime's outstanding work.
+    ///
+    /// Reports running subscriptions, queued or running async commands,
+    /// queued events, undelivered async messages, and uncollected errors.
+    /// Useful for diagnosing why an app hasn't quit or settled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState;
+    /// # #[derive(Clone)]
+    /// # enum MyMsg {}
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState, Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> { Command::none() }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(80, 24).build()?;
+    /// vt.send(Event::key(Key::Char('j')));
+    /// let diagnostics = vt.diagnostics();
+    /// assert_eq!(diagnostics.queued_events, 1);
+    /// assert_eq!(diagnostics.active_subscriptions, 0);
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn diagnostics(&self) -> RuntimeDiagnostics {
+        RuntimeDiagnostics {
+            active_subscriptions: self.active_subscriptions.load(Ordering::SeqCst),
+            pending_commands: self.commands.outstanding_count(),
+            queued_events: self.core.events.len(),
+            pending_messages: self.message_rx.len(),
+            pending_errors: self.error_rx.len(),
+        }
+    }
+
     /// Adds a subscription to the runtime.
     ///
     /// The subscription is converted to a stream and spawned as a tokio task
@@ -433,7 +609,7 @@ impl<A: App, B: Backend> Runtime<A, B> {
         tracing::info!("registering subscription");
 
         let stream = Box::new(subscription).into_stream(self.cancel_token.clone());
-        Self::spawn_subscription(stream, self.message_tx.clone(), self.cancel_token.clone());
+        self.spawn_subscription(stream);
     }
 
     /// Adds multiple subscriptions to the runtime.
@@ -443,24 +619,30 @@ impl<A: App, B: Backend> Runtime<A, B> {
 
         for sub in subscriptions {
             let stream = sub.into_stream(self.cancel_token.clone());
-            Self::spawn_subscription(stream, self.message_tx.clone(), self.cancel_token.clone());
+            self.spawn_subscription(stream);
         }
     }
 
     /// Spawns a tokio task that reads from a subscription stream and forwards
     /// messages through the message channel.
     fn spawn_subscription(
+        &self,
         stream: Pin<Box<dyn tokio_stream::Stream<Item = A::Message> + Send>>,
-        msg_tx: mpsc::Sender<A::Message>,
-        cancel: CancellationToken,
     ) {
+        let msg_tx = self.message_tx.clone();
+        let cancel = self.cancel_token.clone();
+        let active = diagnostics::ActiveGuard::new(self.active_subscriptions.clone());
         tokio::spawn(async move {
+            let _active = active;
             let mut stream = stream;
             loop {
                 tokio::select! {
                     item = stream.next() => {
                         match item {
                             Some(msg) => {
+                                #[cfg(feature = "tracing")]
+                                tracing::trace!("subscription message received");
+
                                 if msg_tx.send(msg).await.is_err() {
                                     break;
                                 }
@@ -503,18 +685,148 @@ impl<A: App, B: Backend> Runtime<A, B> {
     /// ```
     pub fn dispatch(&mut self, msg: A::Message) {
         #[cfg(feature = "tracing")]
-        let _span = tracing::debug_span!("dispatch").entered();
+        let _span =
+            tracing::debug_span!("dispatch", message = %A::describe_message(&msg)).entered();
 
-        let cmd = A::update(&mut self.core.state, msg);
+        self.dispatched = self.dispatched.wrapping_add(1);
+        let cmd = match self.dynamic_update.as_mut() {
+            Some(update) => update(&mut self.core.state, msg),
+            None => A::update(&mut self.core.state, msg),
+        };
+        self.execute_command(cmd);
+    }
+
+    /// Executes a command and spawns any async work it started.
+    fn execute_command(&mut self, cmd: Command<A::Message>) {
         self.commands.execute(cmd);
 
         if self.commands.should_quit() {
             self.core.should_quit = true;
         }
+        if self.commands.take_redraw_request() {
+            self.redraw_requested = true;
+        }
+        if let Some(title) = self.commands.take_title() {
+            self.apply_title(title);
+        }
+        if let Some(shape) = self.commands.take_cursor_shape() {
+            self.apply_cursor_shape(shape);
+        }
 
         self.spawn_pending_commands();
     }
 
+    /// Records a window title and applies it to the backend.
+    ///
+    /// Failing to write the title is not fatal: the title is cosmetic, so
+    /// the error is only logged.
+    fn apply_title(&mut self, title: String) {
+        if let Some(write) = self.title_writer {
+            if let Err(_err) = write(self.core.terminal.backend_mut(), &title) {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %_err, "failed to set terminal title");
+            }
+        }
+        self.title = Some(title);
+    }
+
+    /// Sets how window titles reach the backend, applying any title set
+    /// by the init command.
+    pub(crate) fn set_title_writer(&mut self, writer: Option<TitleWriter<B>>) {
+        self.title_writer = writer;
+        if let Some(title) = self.title.take() {
+            self.apply_title(title);
+        }
+    }
+
+    /// Records a cursor shape and applies it to the backend.
+    ///
+    /// Like titles, failing to write the shape is only logged.
+    fn apply_cursor_shape(&mut self, shape: Option<CursorShape>) {
+        if let Some(write) = self.cursor_shape_writer {
+            if let Err(_err) = write(self.core.terminal.backend_mut(), shape) {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %_err, "failed to set cursor shape");
+            }
+        }
+        self.cursor_shape = shape;
+    }
+
+    /// Sets how cursor shapes reach the backend, applying any shape set by
+    /// the init command.
+    pub(crate) fn set_cursor_shape_writer(&mut self, writer: Option<CursorShapeWriter<B>>) {
+        self.cursor_shape_writer = writer;
+        if self.cursor_shape.is_some() {
+            self.apply_cursor_shape(self.cursor_shape);
+        }
+    }
+
+    /// Returns the cursor shape most recently set with
+    /// [`Command::set_cursor_shape`], or `None` if the app has not set one
+    /// or restored the default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// use envision::backend::CursorShape;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState;
+    /// # #[derive(Clone)]
+    /// # enum MyMsg {}
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// fn init(_args: ()) -> (MyState, Command<MyMsg>) {
+    ///     (MyState, Command::set_cursor_shape(Some(CursorShape::SteadyBar)))
+    /// }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> { Command::none() }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    ///
+    /// let vt = Runtime::<MyApp, _>::virtual_builder(20, 2).build()?;
+    /// assert_eq!(vt.cursor_shape(), Some(CursorShape::SteadyBar));
+    /// assert_eq!(vt.backend().cursor_shape(), Some(CursorShape::SteadyBar));
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn cursor_shape(&self) -> Option<CursorShape> {
+        self.cursor_shape
+    }
+
+    /// Returns the window title most recently set with
+    /// [`Command::set_title`], or `None` if the app has not set one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState;
+    /// # #[derive(Clone)]
+    /// # enum MyMsg {}
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// fn init(_args: ()) -> (MyState, Command<MyMsg>) {
+    ///     (MyState, Command::set_title("untitled"))
+    /// }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> { Command::none() }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    ///
+    /// let vt = Runtime::<MyApp, _>::virtual_builder(20, 2).build()?;
+    /// assert_eq!(vt.title(), Some("untitled"));
+    /// assert_eq!(vt.backend().title(), Some("untitled"));
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
     /// Dispatches multiple messages.
     pub fn dispatch_all(&mut self, messages: impl IntoIterator<Item = A::Message>) {
         for msg in messages {
@@ -523,7 +835,13 @@ impl<A: App, B: Backend> Runtime<A, B> {
     }
 
     /// Spawns any pending async commands.
+    ///
+    /// Outside a tokio runtime there is nothing to spawn onto, so the
+    /// commands stay queued for [`run_commands_blocking`](Self::run_commands_blocking).
     fn spawn_pending_commands(&mut self) {
+        if tokio::runtime::Handle::try_current().is_err() {
+            return;
+        }
         self.commands.spawn_pending(
             self.message_tx.clone(),
             self.error_tx.clone(),
@@ -538,12 +856,19 @@ impl<A: App, B: Backend> Runtime<A, B> {
             self.dispatch(msg);
         }
 
-        // Process overlay commands
-        for overlay in self.commands.take_overlay_pushes() {
-            self.core.overlay_stack.push(overlay);
-        }
-        for _ in 0..self.commands.take_overlay_pops() {
-            self.core.overlay_stack.pop();
+        // Process overlay commands in the order they were issued
+        for op in self.commands.take_overlay_ops() {
+            match op {
+                OverlayOp::Push(overlay) => self.core.overlay_stack.push(overlay),
+                OverlayOp::Pop => {
+                    self.core.overlay_stack.pop();
+                    self.redraw_requested = true;
+                }
+                OverlayOp::Remove(predicate) => {
+                    self.core.overlay_stack.remove_where(predicate);
+                    self.redraw_requested = true;
+                }
+            }
         }
 
         // Process dynamic subscription registrations
@@ -557,11 +882,7 @@ impl<A: App, B: Backend> Runtime<A, B> {
 
             for sub in subscriptions {
                 let stream = sub.into_stream(self.cancel_token.clone());
-                Self::spawn_subscription(
-                    stream,
-                    self.message_tx.clone(),
-                    self.cancel_token.clone(),
-                );
+                self.spawn_subscription(stream);
             }
         }
 
@@ -580,17 +901,155 @@ impl<A: App, B: Backend> Runtime<A, B> {
         while let Ok(msg) = self.message_rx.try_recv() {
             self.dispatch(msg);
         }
+        while let Some(continuation) = self.commands.try_next_continuation() {
+            self.resume_sequence(continuation);
+        }
+    }
+
+    /// Dispatches the message of a finished sequence step, then starts the
+    /// rest of the sequence.
+    fn resume_sequence(&mut self, continuation: Continuation<A::Message>) {
+        if let Some(msg) = continuation.message {
+            self.dispatch(msg);
+        }
+        if let Some(rest) = continuation.rest {
+            self.execute_command(rest);
+        }
     }
 
     /// Renders the current state to the terminal.
     ///
     /// Renders the main app view first, then any active overlays on top.
     ///
+    /// When frame history is enabled with [`RuntimeConfig::with_history`],
+    /// the frame is also drawn into a side [`CaptureBackend`] and kept in
+    /// [`frame_history`](Runtime::frame_history).
+    ///
     /// # Errors
     ///
     /// Returns an error if drawing to the terminal backend fails.
     pub fn render(&mut self) -> error::Result<()> {
-        self.core.render()
+        self.core.render()?;
+        self.rendered += 1;
+        self.redraw_requested = false;
+        if self.config.capture_history && self.config.history_capacity > 0 {
+            self.record_frame()?;
+        }
+        Ok(())
+    }
+
+    /// Flags the screen as needing a redraw.
+    ///
+    /// With [`RuntimeConfig::render_on_demand`], the next scheduled render
+    /// draws a frame instead of being skipped. Use this when state changed
+    /// outside of `update`, e.g. through [`state_mut`](Runtime::state_mut).
+    /// From `update`, return [`Command::request_redraw`] instead.
+    pub fn request_redraw(&mut self) {
+        self.redraw_requested = true;
+    }
+
+    /// Returns true if the next scheduled render will draw a frame.
+    ///
+    /// Always true unless [`RuntimeConfig::render_on_demand`] is enabled.
+    /// Otherwise true when a redraw was requested since the last frame or
+    /// an overlay is showing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState;
+    /// # #[derive(Clone)]
+    /// # enum MyMsg {}
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState, Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> { Command::none() }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(20, 2)
+    ///     .render_on_demand(true)
+    ///     .build()?;
+    /// vt.render()?;
+    /// assert!(!vt.needs_redraw());
+    ///
+    /// vt.request_redraw();
+    /// assert!(vt.needs_redraw());
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn needs_redraw(&self) -> bool {
+        !self.config.render_on_demand || self.redraw_requested || self.core.has_overlays()
+    }
+
+    /// Renders only if [`needs_redraw`](Runtime::needs_redraw) is true.
+    fn render_if_needed(&mut self) -> error::Result<()> {
+        if self.needs_redraw() {
+            self.render()
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Redraws the current frame into a capture backend and appends it to
+    /// the history, dropping the oldest frame once the capacity is reached.
+    fn record_frame(&mut self) -> error::Result<()> {
+        let size = self.core.terminal.size()?;
+        let capture = self
+            .core
+            .render_detached(CaptureBackend::new(size.width, size.height))?;
+        let mut snapshot = capture.backend().snapshot();
+        snapshot.frame = self.rendered;
+
+        if self.frame_history.len() >= self.config.history_capacity {
+            let excess = self.frame_history.len() + 1 - self.config.history_capacity;
+            self.frame_history.drain(..excess);
+        }
+        self.frame_history.push(snapshot);
+        Ok(())
+    }
+
+    /// Returns the most recently rendered frames, oldest first.
+    ///
+    /// Frames are only recorded when the runtime was configured with
+    /// [`RuntimeConfig::with_history`]; otherwise this is empty. Each frame
+    /// is drawn a second time into a [`CaptureBackend`], so this works for
+    /// terminal-mode runtimes too.
+    /// [`run_terminal`](Runtime::run_terminal) consumes the runtime, so use
+    /// [`run_terminal_with_history`](Runtime::run_terminal_with_history) to
+    /// keep the frames once the app exits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # use envision::app::RuntimeConfig;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState;
+    /// # #[derive(Clone)]
+    /// # enum MyMsg {}
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState, Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> { Command::none() }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(20, 2)
+    ///     .config(RuntimeConfig::new().with_history(3))
+    ///     .build()?;
+    /// vt.render()?;
+    /// vt.render()?;
+    /// assert_eq!(vt.frame_history().len(), 2);
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn frame_history(&self) -> &[FrameSnapshot] {
+        &self.frame_history
     }
 
     /// Processes the next event from the queue.
@@ -599,8 +1058,21 @@ impl<A: App, B: Backend> Runtime<A, B> {
     /// Only if the overlay propagates the event will it reach the app's
     /// `handle_event_with_state`.
     ///
+    /// A [`Resize`](Event::Resize) event first gives the app a chance to react
+    /// through [`App::on_resize`].
+    ///
     /// Returns true if an event was processed.
     pub fn process_event(&mut self) -> bool {
+        if let Some(&Event::Resize(width, height)) = self.core.events.peek() {
+            self.redraw_requested = true;
+            if let Some(msg) = A::on_resize(&self.core.state, width, height) {
+                self.dispatch(msg);
+            }
+        }
+        // An overlay may change or close in response to the event
+        if self.core.has_overlays() && self.core.events.peek().is_some() {
+            self.redraw_requested = true;
+        }
         match self.core.process_event() {
             ProcessEventResult::NoEvent => false,
             ProcessEventResult::Consumed => true,
@@ -611,6 +1083,29 @@ impl<A: App, B: Backend> Runtime<A, B> {
         }
     }
 
+    /// Routes one terminal event through `on_resize`, the overlay stack, the
+    /// keymap, and the app's `handle_event_with_state`.
+    fn handle_terminal_event(&mut self, event: Event) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(event = ?event, "terminal received event");
+
+        if let Event::Resize(width, height) = event {
+            self.redraw_requested = true;
+            if let Some(msg) = A::on_resize(&self.core.state, width, height) {
+                self.dispatch(msg);
+            }
+        }
+
+        if self.core.has_overlays() {
+            // An overlay may change or close in response to the event
+            self.redraw_requested = true;
+        }
+
+        if let ProcessEventResult::Dispatch(msg) = self.core.route_event(&event) {
+            self.dispatch(msg);
+        }
+    }
+
     /// Processes all pending events.
     pub fn process_all_events(&mut self) {
         while self.process_event() {}
@@ -622,10 +1117,14 @@ impl<A: App, B: Backend> Runtime<A, B> {
     /// a full cycle: process commands, drain events, call on_tick, check quit,
     /// and render.
     ///
+    /// With [`RuntimeConfig::render_on_demand`], the render step only draws
+    /// when [`needs_redraw`](Runtime::needs_redraw) is true.
+    ///
     /// For more granular control:
     /// - [`process_all_events`](Runtime::process_all_events) — Drain the event queue only
     /// - [`process_event`](Runtime::process_event) — Process exactly one event
     /// - [`run_ticks`](Runtime::run_ticks) — Convenience: run N full tick cycles
+    /// - [`tick_with_outcome`](Runtime::tick_with_outcome) — Tick and report what changed
     ///
     /// # Errors
     ///
@@ -654,9 +1153,51 @@ impl<A: App, B: Backend> Runtime<A, B> {
     /// # Ok::<(), envision::EnvisionError>(())
     /// ```
     pub fn tick(&mut self) -> error::Result<()> {
+        self.tick_with_outcome().map(|_| ())
+    }
+
+    /// Runs a single tick like [`tick`](Runtime::tick) and reports what it
+    /// did.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering to the terminal backend fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState { count: u32 }
+    /// # #[derive(Clone)]
+    /// # enum MyMsg { Bump }
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState::default(), Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> {
+    /// #         state.count += 1;
+    /// #         if state.count < 3 { Command::message(MyMsg::Bump) } else { Command::none() }
+    /// #     }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(80, 24).build()?;
+    /// vt.dispatch(MyMsg::Bump);
+    ///
+    /// // Each message queues the next; tick until nothing happens
+    /// while !vt.tick_with_outcome()?.is_idle() {}
+    /// assert_eq!(vt.state().count, 3);
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn tick_with_outcome(&mut self) -> error::Result<TickOutcome> {
         #[cfg(feature = "tracing")]
         let _span = tracing::debug_span!("tick").entered();
 
+        let dispatched = self.dispatched;
+        let rendered = self.rendered;
+
         // Process pending commands
         self.process_commands();
 
@@ -664,20 +1205,24 @@ impl<A: App, B: Backend> Runtime<A, B> {
         self.process_async_messages();
 
         // Process events
-        let mut messages_processed = 0;
-        while self.process_event() && messages_processed < self.core.max_messages_per_tick {
-            messages_processed += 1;
+        let mut events_processed = 0;
+        while self.process_event() {
+            events_processed += 1;
+            if events_processed > self.core.max_messages_per_tick {
+                break;
+            }
         }
 
         #[cfg(feature = "tracing")]
-        if messages_processed > 0 {
-            tracing::debug!(messages_processed, "tick: processed events");
+        if events_processed > 0 {
+            tracing::debug!(events_processed, "tick: processed events");
         }
 
         // Handle tick
         if let Some(msg) = A::on_tick(&self.core.state) {
             self.dispatch(msg);
         }
+        self.tick_overlays();
 
         // Check if we should quit
         if A::should_quit(&self.core.state) {
@@ -685,9 +1230,14 @@ impl<A: App, B: Backend> Runtime<A, B> {
         }
 
         // Render
-        self.render()?;
-
-        Ok(())
+        self.render_if_needed()?;
+
+        Ok(TickOutcome {
+            messages_dispatched: self.dispatched.wrapping_sub(dispatched),
+            events_processed,
+            rendered: self.rendered != rendered,
+            quit_requested: self.core.should_quit,
+        })
     }
 
     /// Returns true if the runtime should quit.
@@ -704,6 +1254,129 @@ impl<A: App, B: Backend> Runtime<A, B> {
         self.cancel_token.cancel();
     }
 
+    /// Quits the runtime after giving in-flight async commands time to finish.
+    ///
+    /// Waits up to `timeout` for spawned command futures to complete,
+    /// dispatching the messages they send as they arrive. It then cancels
+    /// the cancellation token, which stops subscriptions and any commands
+    /// still running, and dispatches messages that were already queued.
+    /// Errors reported by async commands stay available through
+    /// [`take_errors`](Runtime::take_errors).
+    ///
+    /// Commands are only awaited if the token hasn't been cancelled yet,
+    /// since cancellation aborts them. Call this instead of
+    /// [`quit`](Runtime::quit) to avoid losing completion messages, for
+    /// example from a save that is still running.
+    ///
+    /// Sync follow-ups such as [`Command::message`] chains run for at most
+    /// [`max_messages_per_tick`](RuntimeConfig::max_messages_per_tick)
+    /// rounds, so a chain that never ends can't keep the app from quitting.
+    ///
+    /// Returns `true` if every command finished before the timeout and its
+    /// follow-ups settled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # use std::time::Duration;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState { saved: bool }
+    /// #[derive(Clone)]
+    /// enum MyMsg { Save, Saved }
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState::default(), Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> {
+    /// #         match msg {
+    /// #             MyMsg::Save => Command::perform_async(async { Some(MyMsg::Saved) }),
+    /// #             MyMsg::Saved => { state.saved = true; Command::none() }
+    /// #         }
+    /// #     }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> envision::Result<()> {
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(80, 24).build()?;
+    /// vt.dispatch(MyMsg::Save);
+    /// assert!(vt.shutdown(Duration::from_secs(1)).await);
+    /// assert!(vt.state().saved);
+    /// assert!(vt.should_quit());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(&mut self, timeout: Duration) -> bool {
+        #[cfg(feature = "tracing")]
+        tracing::info!(?timeout, "runtime shutdown requested");
+
+        self.core.should_quit = true;
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut completed = true;
+
+        'wait: loop {
+            // Messages dispatched while draining may have queued follow-ups
+            if !self.settle_commands() {
+                completed = false;
+                break;
+            }
+            // Finished sequence steps may start more tasks
+            while let Some(continuation) = self.commands.try_next_continuation() {
+                self.resume_sequence(continuation);
+            }
+            let tasks = self.commands.take_in_flight();
+            if tasks.is_empty() {
+                break;
+            }
+            for mut task in tasks {
+                loop {
+                    tokio::select! {
+                        _ = &mut task => break,
+                        Some(msg) = self.message_rx.recv() => self.dispatch(msg),
+                        Some(continuation) = self.commands.next_continuation() => {
+                            self.resume_sequence(continuation);
+                        }
+                        _ = tokio::time::sleep_until(deadline) => {
+                            completed = false;
+                            break 'wait;
+                        }
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        if !completed {
+            tracing::warn!("shutdown timed out waiting for async commands");
+        }
+
+        self.cancel_token.cancel();
+        self.process_async_messages();
+        let settled = self.settle_commands();
+        completed && settled
+    }
+
+    /// Processes queued commands until they stop dispatching messages, so
+    /// chains of sync follow-ups (`Command::message` and the like) run to
+    /// the end.
+    ///
+    /// Gives up after `max_messages_per_tick` rounds, so an `update` that
+    /// keeps answering with another message can't hang shutdown. Returns
+    /// false if the commands didn't settle.
+    fn settle_commands(&mut self) -> bool {
+        for _ in 0..self.core.max_messages_per_tick {
+            let dispatched = self.dispatched;
+            self.process_commands();
+            if self.dispatched == dispatched {
+                return true;
+            }
+        }
+        false
+    }
+
     /// Runs the async event loop until the application quits.
     ///
     /// This is the main entry point for running a virtual terminal async loop.
@@ -716,57 +1389,13 @@ impl<A: App, B: Backend> Runtime<A, B> {
         #[cfg(feature = "tracing")]
         tracing::info!("starting runtime event loop");
 
-        let mut tick_interval = tokio::time::interval(self.config.tick_rate);
-        let mut render_interval = tokio::time::interval(self.config.frame_rate);
+        let mut timers = step::LoopTimers::new(&self.config);
 
         // Initial render
         self.render()?;
 
         loop {
-            tokio::select! {
-                // Handle async messages from spawned tasks
-                Some(msg) = self.message_rx.recv() => {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("runtime received async message");
-
-                    self.dispatch(msg);
-                }
-
-                // Handle tick interval
-                _ = tick_interval.tick() => {
-                    // Process sync commands
-                    self.process_commands();
-
-                    // Process events
-                    let mut messages_processed = 0;
-                    while self.process_event() && messages_processed < self.core.max_messages_per_tick {
-                        messages_processed += 1;
-                    }
-
-                    // Handle tick
-                    if let Some(msg) = A::on_tick(&self.core.state) {
-                        self.dispatch(msg);
-                    }
-
-                    // Check if we should quit
-                    if A::should_quit(&self.core.state) {
-                        self.core.should_quit = true;
-                    }
-                }
-
-                // Handle render interval
-                _ = render_interval.tick() => {
-                    self.render()?;
-                }
-
-                // Handle cancellation
-                _ = self.cancel_token.cancelled() => {
-                    #[cfg(feature = "tracing")]
-                    tracing::info!("runtime received cancellation");
-
-                    self.core.should_quit = true;
-                }
-            }
+            self.step_with(&mut timers, false).await?;
 
             if self.core.should_quit {
                 #[cfg(feature = "tracing")]
@@ -860,6 +1489,100 @@ impl<A: App, B: Backend> Runtime<A, B> {
         self.core.overlay_count()
     }
 
+    /// Replaces the runtime's clock with a virtual clock.
+    ///
+    /// With a virtual clock, time only moves when
+    /// [`advance_time`](Runtime::advance_time) is called. Overlay
+    /// [`on_tick`](crate::overlay::Overlay::on_tick) receives the virtual
+    /// time elapsed since the previous tick instead of the configured tick
+    /// rate, so time-dependent behavior is fully deterministic in tests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState;
+    /// # #[derive(Clone)]
+    /// # enum MyMsg {}
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState, Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> { Command::none() }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    /// use std::time::Duration;
+    ///
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(80, 24)
+    ///     .build()?
+    ///     .with_virtual_clock();
+    /// let start = vt.now();
+    /// vt.advance_time(Duration::from_secs(2));
+    /// assert_eq!(vt.now() - start, Duration::from_secs(2));
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn with_virtual_clock(self) -> Self {
+        self.with_clock(Clock::new_virtual())
+    }
+
+    /// Replaces the runtime's clock.
+    ///
+    /// Use this to share a [`Clock`] handle with the app (for example via
+    /// [`App::Args`]) so app logic reads the same time as the runtime.
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.last_tick = clock.now();
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the runtime's clock.
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns the current time according to the runtime's clock.
+    pub fn now(&self) -> Instant {
+        self.clock.now()
+    }
+
+    /// Advances the runtime's virtual clock by `duration`.
+    ///
+    /// Has no effect when the runtime uses the system clock.
+    pub fn advance_time(&mut self, duration: Duration) {
+        self.clock.advance(duration);
+    }
+
+    /// Advances time-based overlays and dispatches the messages they
+    /// produce.
+    fn tick_overlays(&mut self) {
+        if self.core.has_overlays() {
+            // Overlays may animate or expire
+            self.redraw_requested = true;
+        }
+        let elapsed = self.tick_elapsed();
+        for msg in self.core.tick_overlays(elapsed) {
+            self.dispatch(msg);
+        }
+    }
+
+    /// Returns the time to report for the current tick.
+    ///
+    /// A virtual clock reports the time elapsed since the previous tick;
+    /// the system clock reports the configured tick rate.
+    fn tick_elapsed(&mut self) -> Duration {
+        if self.clock.is_virtual() {
+            let now = self.clock.now();
+            let elapsed = now.saturating_duration_since(self.last_tick);
+            self.last_tick = now;
+            elapsed
+        } else {
+            self.config.tick_rate
+        }
+    }
+
     /// Sets the theme.
     pub fn set_theme(&mut self, theme: Theme) {
         self.core.theme = theme;
@@ -869,6 +1592,119 @@ impl<A: App, B: Backend> Runtime<A, B> {
     pub fn theme(&self) -> &Theme {
         &self.core.theme
     }
+
+    /// Sets the key bindings checked before the app's `handle_event`.
+    ///
+    /// An event that an overlay propagates is looked up in the keymap
+    /// first; only keys without a binding reach
+    /// [`App::handle_event_with_state`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState { quit: bool }
+    /// #[derive(Clone)]
+    /// enum MyMsg { Quit }
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState::default(), Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> {
+    /// #         match msg { MyMsg::Quit => state.quit = true }
+    /// #         Command::none()
+    /// #     }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(20, 2).build()?;
+    /// vt.set_keymap(KeyMap::new().bind(KeyEvent::char('q'), MyMsg::Quit));
+    ///
+    /// vt.send(Event::char('q'));
+    /// vt.tick()?;
+    /// assert!(vt.state().quit);
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn set_keymap(&mut self, keymap: KeyMap<A::Message>) {
+        self.core.keymap = keymap;
+    }
+
+    /// Returns the key bindings checked before the app's `handle_event`.
+    pub fn keymap(&self) -> &KeyMap<A::Message> {
+        &self.core.keymap
+    }
+
+    /// Replaces [`App::update`] with `update` for every message dispatched
+    /// from now on.
+    ///
+    /// This is an advanced escape hatch for REPLs, plugin hosts and
+    /// hot-reload-style experiments, where the update rules are loaded or
+    /// changed while the program runs. Everything else about the app stays
+    /// static: [`App::init`], [`App::view`], event handling and
+    /// subscriptions still come from the `App` implementation, and the
+    /// commands `update` returns are executed as usual. Prefer a normal
+    /// `App::update` that matches on state when the set of behaviors is
+    /// known up front.
+    ///
+    /// Install a different function with
+    /// [`set_dynamic_update`](Self::set_dynamic_update), or pass `None` to
+    /// go back to `App::update`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState { count: i32 }
+    /// #[derive(Clone)]
+    /// enum MyMsg { Increment }
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState::default(), Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> {
+    /// #         match msg { MyMsg::Increment => state.count += 1 }
+    /// #         Command::none()
+    /// #     }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(20, 2)
+    ///     .build()?
+    ///     .with_dynamic_update(Box::new(|state: &mut MyState, msg| {
+    ///         match msg { MyMsg::Increment => state.count += 10 }
+    ///         Command::none()
+    ///     }));
+    /// vt.dispatch(MyMsg::Increment);
+    /// assert_eq!(vt.state().count, 10);
+    ///
+    /// // Back to `MyApp::update`
+    /// vt.set_dynamic_update(None);
+    /// vt.dispatch(MyMsg::Increment);
+    /// assert_eq!(vt.state().count, 11);
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn with_dynamic_update(mut self, update: DynamicUpdate<A>) -> Self {
+        self.dynamic_update = Some(update);
+        self
+    }
+
+    /// Installs or removes the function used instead of [`App::update`].
+    ///
+    /// See [`with_dynamic_update`](Self::with_dynamic_update). The new
+    /// function applies from the next dispatched message; messages already
+    /// queued are not replayed.
+    pub fn set_dynamic_update(&mut self, update: Option<DynamicUpdate<A>>) {
+        self.dynamic_update = update;
+    }
+
+    /// Returns true if a dynamic update function replaces [`App::update`].
+    pub fn has_dynamic_update(&self) -> bool {
+        self.dynamic_update.is_some()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,206 @@
+//! One iteration of the async event loop.
+//!
+//! [`Runtime::run`] and [`Runtime::step`] share one iteration: it waits for
+//! the first ready source — cancellation, the tick timer, the render timer,
+//! an async message, or a finished sequence step — and handles it. Tests can
+//! call `step` directly to drive subscriptions one emission at a time; it
+//! takes ready sources in a fixed order, while `run` picks among them
+//! fairly.
+
+use ratatui::backend::Backend;
+use tokio::time::Interval;
+
+use super::{Runtime, RuntimeConfig};
+use crate::app::command::Continuation;
+use crate::app::model::App;
+use crate::error;
+
+/// The source handled by one call to [`Runtime::step`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// The cancellation token was cancelled; the runtime will quit.
+    Cancelled,
+    /// The tick timer fired: queued commands and events were processed and
+    /// `on_tick` ran.
+    Tick,
+    /// The render timer fired and the screen was drawn if needed.
+    Render,
+    /// A message from an async command or subscription was dispatched.
+    Message,
+    /// A step of a [`Command::sequence`](crate::app::Command::sequence)
+    /// finished and the rest of the sequence started.
+    Sequence,
+}
+
+/// A source that became ready in [`Runtime::step_with`], with what it
+/// produced.
+enum Source<M> {
+    Cancelled,
+    Tick,
+    Render,
+    Message(M),
+    Sequence(Continuation<M>),
+}
+
+/// The tick and render timers of the event loop.
+pub(super) struct LoopTimers {
+    tick: Interval,
+    render: Interval,
+}
+
+impl LoopTimers {
+    /// Creates timers whose first tick and render are due immediately.
+    pub(super) fn new(config: &RuntimeConfig) -> Self {
+        Self {
+            tick: tokio::time::interval(config.tick_rate),
+            render: tokio::time::interval(config.frame_rate),
+        }
+    }
+}
+
+impl<A: App, B: Backend> Runtime<A, B> {
+    /// Waits for the next source of work in the event loop and handles it.
+    ///
+    /// This is one iteration of [`run`](Runtime::run). When several sources
+    /// are ready at once they are taken in the order of the [`StepOutcome`]
+    /// variants, so under `tokio::time::pause` a sequence of steps is
+    /// deterministic; `run` instead picks among them at random, like any
+    /// unbiased `tokio::select!`. The tick and render timers start on the
+    /// first call and keep their schedule across calls; both are due
+    /// immediately, so the first two steps report
+    /// [`Tick`](StepOutcome::Tick) and [`Render`](StepOutcome::Render).
+    ///
+    /// Unlike `run`, `step` does not render before the first iteration or
+    /// call `on_exit`, and it keeps working after the app asks to quit;
+    /// check [`should_quit`](Runtime::should_quit) to stop.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering to the terminal backend fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # use envision::app::StepOutcome;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState;
+    /// # #[derive(Clone)]
+    /// # enum MyMsg {}
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState, Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> { Command::none() }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> envision::Result<()> {
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(80, 24).build()?;
+    /// assert_eq!(vt.step().await?, StepOutcome::Tick);
+    /// assert_eq!(vt.step().await?, StepOutcome::Render);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn step(&mut self) -> error::Result<StepOutcome> {
+        let mut timers = self
+            .step_timers
+            .take()
+            .unwrap_or_else(|| LoopTimers::new(&self.config));
+        let outcome = self.step_with(&mut timers, true).await;
+        self.step_timers = Some(timers);
+        outcome
+    }
+
+    /// Handles the first ready source, using the given timers.
+    ///
+    /// With `biased`, sources that are ready together are taken in the
+    /// order of the [`StepOutcome`] variants, as [`step`](Runtime::step)
+    /// documents. Otherwise tokio picks among them at random, which keeps
+    /// [`run`](Runtime::run) fair: a busy message channel can't starve the
+    /// tick or render timers.
+    pub(super) async fn step_with(
+        &mut self,
+        timers: &mut LoopTimers,
+        biased: bool,
+    ) -> error::Result<StepOutcome> {
+        let source = if biased {
+            tokio::select! {
+                biased;
+                _ = self.cancel_token.cancelled() => Source::Cancelled,
+                _ = timers.tick.tick() => Source::Tick,
+                _ = timers.render.tick() => Source::Render,
+                Some(msg) = self.message_rx.recv() => Source::Message(msg),
+                Some(continuation) = self.commands.next_continuation() => {
+                    Source::Sequence(continuation)
+                }
+            }
+        } else {
+            tokio::select! {
+                _ = self.cancel_token.cancelled() => Source::Cancelled,
+                _ = timers.tick.tick() => Source::Tick,
+                _ = timers.render.tick() => Source::Render,
+                Some(msg) = self.message_rx.recv() => Source::Message(msg),
+                Some(continuation) = self.commands.next_continuation() => {
+                    Source::Sequence(continuation)
+                }
+            }
+        };
+
+        let outcome = match source {
+            Source::Cancelled => {
+                #[cfg(feature = "tracing")]
+                tracing::info!("runtime received cancellation");
+
+                self.core.should_quit = true;
+                StepOutcome::Cancelled
+            }
+
+            Source::Tick => {
+                // Process sync commands
+                self.process_commands();
+
+                // Process events
+                let mut messages_processed = 0;
+                while self.process_event() && messages_processed < self.core.max_messages_per_tick {
+                    messages_processed += 1;
+                }
+
+                // Handle tick
+                if let Some(msg) = A::on_tick(&self.core.state) {
+                    self.dispatch(msg);
+                }
+                self.tick_overlays();
+
+                // Check if we should quit
+                if A::should_quit(&self.core.state) {
+                    self.core.should_quit = true;
+                }
+                StepOutcome::Tick
+            }
+
+            Source::Render => {
+                self.render_if_needed()?;
+                StepOutcome::Render
+            }
+
+            // Handle async messages from spawned tasks and subscriptions
+            Source::Message(msg) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("runtime received async message");
+
+                self.dispatch(msg);
+                StepOutcome::Message
+            }
+
+            // Continue command sequences whose current step finished
+            Source::Sequence(continuation) => {
+                self.resume_sequence(continuation);
+                StepOutcome::Sequence
+            }
+        };
+        Ok(outcome)
+    }
+}
@@ -8,7 +8,11 @@ use std::io::{self, Stdout};
 use crate::error;
 
 use crossterm::ExecutableCommand;
-use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::cursor::SetCursorStyle;
+use crossterm::event::{
+    DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+    KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+};
 use crossterm::terminal::{
     EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
 };
@@ -17,11 +21,12 @@ use ratatui::backend::CrosstermBackend;
 use super::Runtime;
 use super::config::RuntimeConfig;
 use crate::app::model::App;
+use crate::backend::FrameSnapshot;
 
 /// Restores the terminal to its normal state.
 ///
-/// Disables raw mode, leaves the alternate screen, disables mouse capture,
-/// and shows the cursor. Call this in panic handlers or cleanup code to
+/// Disables raw mode, leaves the alternate screen, disables mouse capture
+/// and bracketed paste, and shows the cursor. Call this in panic handlers or cleanup code to
 /// ensure the terminal is left in a usable state.
 ///
 /// This is a standalone function that does not require a [`Runtime`]
@@ -48,11 +53,11 @@ pub fn restore_terminal() -> crate::error::Result<()> {
     disable_raw_mode()?;
     io::stdout().execute(LeaveAlternateScreen)?;
     io::stdout().execute(DisableMouseCapture)?;
+    io::stdout().execute(DisableBracketedPaste)?;
     // Show cursor using crossterm directly (no Terminal instance needed)
     crossterm::execute!(io::stdout(), crossterm::cursor::Show)?;
     Ok(())
 }
-use crate::overlay::OverlayAction;
 
 // =============================================================================
 // Terminal Mode - for real terminal applications
@@ -66,12 +71,18 @@ impl<A: App> Runtime<A, CrosstermBackend<Stdout>> {
     /// and `tokio::select!` to multiplex between terminal events,
     /// async messages, tick intervals, and render intervals.
     ///
+    /// When input arrives, every event already available is read in one
+    /// pass (up to `max_messages_per_tick`), with consecutive resizes and
+    /// mouse moves collapsed to the latest, before the loop yields. Resizes
+    /// are further throttled to one per
+    /// [`resize_debounce`](RuntimeConfig::resize_debounce) window.
+    ///
     /// # Errors
     ///
     /// Returns an error if reading from the crossterm event stream fails,
     /// if rendering to the terminal fails, or if terminal cleanup
     /// (disabling raw mode, leaving alternate screen, disabling mouse
-    /// capture) fails on shutdown.
+    /// capture and bracketed paste) fails on shutdown.
     ///
     /// # Example
     ///
@@ -101,7 +112,64 @@ impl<A: App> Runtime<A, CrosstermBackend<Stdout>> {
     /// }
     /// ```
     pub async fn run_terminal(mut self) -> error::Result<A::State> {
+        self.run_terminal_loop().await?;
+        Ok(self.core.state)
+    }
+
+    /// Runs the interactive event loop like
+    /// [`run_terminal`](Runtime::run_terminal), and also returns the frames
+    /// recorded by [`RuntimeConfig::with_history`], oldest first.
+    ///
+    /// Use it to inspect the last screens an app showed after it exits.
+    /// The history is empty unless history capture was enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`run_terminal`](Runtime::run_terminal).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use envision::prelude::*;
+    /// # use envision::app::RuntimeConfig;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState;
+    /// # #[derive(Clone)]
+    /// # enum MyMsg {}
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState, Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> { Command::none() }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    /// #[tokio::main]
+    /// async fn main() -> envision::Result<()> {
+    ///     let (_state, history) = Runtime::<MyApp, _>::terminal_builder()?
+    ///         .config(RuntimeConfig::new().with_history(5))
+    ///         .build()?
+    ///         .run_terminal_with_history()
+    ///         .await?;
+    ///     if let Some(last) = history.last() {
+    ///         println!("{}", last.to_plain());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn run_terminal_with_history(
+        mut self,
+    ) -> error::Result<(A::State, Vec<FrameSnapshot>)> {
+        self.run_terminal_loop().await?;
+        Ok((self.core.state, self.frame_history))
+    }
+
+    /// Runs the event loop until the app quits, then cleans up the terminal
+    /// and calls `on_exit`.
+    async fn run_terminal_loop(&mut self) -> error::Result<()> {
         use futures_util::StreamExt;
+        use std::task::Poll;
 
         #[cfg(feature = "tracing")]
         tracing::info!("starting terminal runtime loop");
@@ -109,48 +177,74 @@ impl<A: App> Runtime<A, CrosstermBackend<Stdout>> {
         let mut tick_interval = tokio::time::interval(self.config.tick_rate);
         let mut render_interval = tokio::time::interval(self.config.frame_rate);
         let mut event_stream = crossterm::event::EventStream::new();
+        let forward_key_kinds = self.config.forward_key_kinds;
+        let mut resizes = super::burst::ResizeDebouncer::new(self.config.resize_debounce);
 
         // Initial render
         self.render()?;
 
         let result = loop {
+            let resize_deadline = resizes.deadline();
             tokio::select! {
                 // Handle terminal events from crossterm
                 maybe_event = event_stream.next() => {
-                    match maybe_event {
-                        Some(Ok(event)) => {
-                            if let Some(envision_event) = crate::input::convert::from_crossterm_event(event) {
-                                #[cfg(feature = "tracing")]
-                                tracing::debug!(event = ?envision_event, "terminal received event");
-
-                                match self.core.overlay_stack.handle_event(&envision_event) {
-                                    OverlayAction::Consumed => {}
-                                    OverlayAction::KeepAndMessage(msg) => self.dispatch(msg),
-                                    OverlayAction::Dismiss => {
-                                        self.core.overlay_stack.pop();
-                                    }
-                                    OverlayAction::DismissWithMessage(msg) => {
-                                        self.core.overlay_stack.pop();
-                                        self.dispatch(msg);
-                                    }
-                                    OverlayAction::Propagate => {
-                                        if let Some(msg) =
-                                            A::handle_event_with_state(&self.core.state, &envision_event)
-                                        {
-                                            self.dispatch(msg);
-                                        }
-                                    }
-                                }
+                    // Drain the rest of the burst without waiting, so one
+                    // render covers it
+                    let mut burst = Vec::new();
+                    let mut next = Some(maybe_event);
+                    let mut ended = None;
+                    while let Some(maybe_event) = next.take() {
+                        match maybe_event {
+                            Some(Ok(event)) => {
+                                burst.extend(crate::input::convert::from_crossterm_event_with_kinds(
+                                    event,
+                                    forward_key_kinds,
+                                ));
+                            }
+                            Some(Err(e)) => {
+                                ended = Some(Err(e.into()));
+                                break;
+                            }
+                            None => {
+                                // Event stream ended
+                                ended = Some(Ok(()));
+                                break;
                             }
                         }
-                        Some(Err(e)) => {
-                            break Err(e.into());
+                        if burst.len() < self.core.max_messages_per_tick {
+                            // Poll with this task's waker rather than
+                            // `now_or_never`: crossterm's `EventStream` keeps
+                            // the first waker it is handed while pending, so a
+                            // no-op waker would leave the next event unnoticed
+                            // until the tick or render interval fires.
+                            next = std::future::poll_fn(|cx| {
+                                Poll::Ready(match event_stream.poll_next_unpin(cx) {
+                                    Poll::Ready(event) => Some(event),
+                                    Poll::Pending => None,
+                                })
+                            })
+                            .await;
                         }
-                        None => {
-                            // Event stream ended
-                            break Ok(());
+                    }
+
+                    let now = tokio::time::Instant::now();
+                    for event in super::burst::coalesce_events(burst) {
+                        for event in resizes.offer(event, now) {
+                            self.handle_terminal_event(event);
                         }
                     }
+
+                    if let Some(result) = ended {
+                        break result;
+                    }
+                }
+
+                // Apply the latest size once the resize window ends
+                _ = tokio::time::sleep_until(resize_deadline.unwrap_or_else(tokio::time::Instant::now)),
+                    if resize_deadline.is_some() => {
+                    if let Some(event) = resizes.take() {
+                        self.handle_terminal_event(event);
+                    }
                 }
 
                 // Handle async messages from spawned tasks
@@ -161,6 +255,11 @@ impl<A: App> Runtime<A, CrosstermBackend<Stdout>> {
                     self.dispatch(msg);
                 }
 
+                // Continue command sequences whose current step finished
+                Some(continuation) = self.commands.next_continuation() => {
+                    self.resume_sequence(continuation);
+                }
+
                 // Handle tick interval
                 _ = tick_interval.tick() => {
                     // Process sync commands
@@ -176,6 +275,7 @@ impl<A: App> Runtime<A, CrosstermBackend<Stdout>> {
                     if let Some(msg) = A::on_tick(&self.core.state) {
                         self.dispatch(msg);
                     }
+                    self.tick_overlays();
 
                     // Check if we should quit
                     if A::should_quit(&self.core.state) {
@@ -185,7 +285,7 @@ impl<A: App> Runtime<A, CrosstermBackend<Stdout>> {
 
                 // Handle render interval
                 _ = render_interval.tick() => {
-                    if let Err(e) = self.render() {
+                    if let Err(e) = self.render_if_needed() {
                         break Err(e);
                     }
                 }
@@ -210,9 +310,8 @@ impl<A: App> Runtime<A, CrosstermBackend<Stdout>> {
         // Call on_exit
         A::on_exit(&self.core.state);
 
-        // Return the first error if any, otherwise return the final state
-        result.and(cleanup_result)?;
-        Ok(self.core.state)
+        // Return the first error if any
+        result.and(cleanup_result)
     }
 
     /// Runs the interactive terminal event loop, blocking the current thread.
@@ -263,6 +362,10 @@ impl<A: App> Runtime<A, CrosstermBackend<Stdout>> {
     /// - Enables raw mode
     /// - Enters alternate screen
     /// - Enables mouse capture
+    /// - Enables bracketed paste, so pasted text arrives as a single
+    ///   [`Event::Paste`](crate::input::Event::Paste)
+    /// - Asks for key release and repeat reports if
+    ///   [`forward_key_kinds`](RuntimeConfig::forward_key_kinds) is set
     /// - Runs the `on_setup` hook if configured
     pub(super) fn setup_terminal(
         config: &RuntimeConfig,
@@ -271,6 +374,15 @@ impl<A: App> Runtime<A, CrosstermBackend<Stdout>> {
         let mut stdout = io::stdout();
         stdout.execute(EnterAlternateScreen)?;
         stdout.execute(EnableMouseCapture)?;
+        stdout.execute(EnableBracketedPaste)?;
+        if config.forward_key_kinds {
+            // Not every terminal supports this (crossterm refuses on legacy
+            // Windows consoles, which report key kinds anyway), so failure
+            // just means fewer events.
+            let _ = stdout.execute(PushKeyboardEnhancementFlags(
+                KeyboardEnhancementFlags::REPORT_EVENT_TYPES,
+            ));
+        }
 
         // Run the on_setup hook if configured
         if let Some(ref hook) = config.on_setup {
@@ -288,6 +400,13 @@ impl<A: App> Runtime<A, CrosstermBackend<Stdout>> {
         }
 
         disable_raw_mode()?;
+        if self.config.forward_key_kinds {
+            let _ = self
+                .core
+                .terminal
+                .backend_mut()
+                .execute(PopKeyboardEnhancementFlags);
+        }
         self.core
             .terminal
             .backend_mut()
@@ -296,6 +415,17 @@ impl<A: App> Runtime<A, CrosstermBackend<Stdout>> {
             .terminal
             .backend_mut()
             .execute(DisableMouseCapture)?;
+        self.core
+            .terminal
+            .backend_mut()
+            .execute(DisableBracketedPaste)?;
+        if self.cursor_shape.is_some() {
+            let _ = self
+                .core
+                .terminal
+                .backend_mut()
+                .execute(SetCursorStyle::DefaultUserShape);
+        }
         self.core.terminal.show_cursor()?;
         Ok(())
     }
@@ -448,3 +448,161 @@ async fn test_runtime_run_with_on_tick() {
     assert!(runtime.should_quit());
     assert!(runtime.state().ticks >= 3);
 }
+
+// =========================================================================
+// Shutdown Tests
+// =========================================================================
+
+#[tokio::test(start_paused = true)]
+async fn test_runtime_shutdown_waits_for_in_flight_commands() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    runtime.commands.execute(Command::perform_async(async {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        Some(CounterMsg::IncrementBy(3))
+    }));
+    runtime.spawn_pending_commands();
+
+    assert!(runtime.shutdown(Duration::from_secs(1)).await);
+    assert_eq!(runtime.state().count, 3);
+    assert!(runtime.should_quit());
+    assert!(runtime.cancellation_token().is_cancelled());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_runtime_shutdown_times_out_and_cancels() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    runtime.commands.execute(Command::perform_async(async {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        Some(CounterMsg::IncrementBy(3))
+    }));
+    runtime.commands.execute(Command::perform_async(async {
+        Some(CounterMsg::Increment)
+    }));
+    runtime.spawn_pending_commands();
+
+    let start = tokio::time::Instant::now();
+    assert!(!runtime.shutdown(Duration::from_millis(100)).await);
+    assert_eq!(start.elapsed(), Duration::from_millis(100));
+    assert_eq!(runtime.state().count, 1);
+    assert!(runtime.cancellation_token().is_cancelled());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_runtime_shutdown_runs_sync_follow_ups() {
+    // The async result chains IncrementBy(1) -> 2 -> 3 through
+    // Command::message, queued while shutdown drains.
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24)
+        .build()
+        .unwrap()
+        .with_dynamic_update(Box::new(|state: &mut CounterState, msg| match msg {
+            CounterMsg::Increment => Command::perform_async(async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Some(CounterMsg::IncrementBy(1))
+            }),
+            CounterMsg::IncrementBy(n) => {
+                state.count += n;
+                if n < 3 {
+                    Command::message(CounterMsg::IncrementBy(n + 1))
+                } else {
+                    Command::none()
+                }
+            }
+            _ => Command::none(),
+        }));
+    runtime.dispatch(CounterMsg::Increment);
+    runtime.process_commands();
+
+    assert!(runtime.shutdown(Duration::from_secs(1)).await);
+    assert_eq!(runtime.state().count, 6);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_runtime_shutdown_stops_endless_message_chain() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24)
+        .config(RuntimeConfig::new().max_messages(10))
+        .build()
+        .unwrap()
+        .with_dynamic_update(Box::new(|state: &mut CounterState, msg| {
+            state.count += 1;
+            Command::message(msg)
+        }));
+    runtime.dispatch(CounterMsg::Increment);
+
+    assert!(!runtime.shutdown(Duration::from_secs(1)).await);
+    assert!(runtime.should_quit());
+    assert!(runtime.cancellation_token().is_cancelled());
+}
+
+#[tokio::test]
+async fn test_runtime_shutdown_without_commands() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    assert!(runtime.shutdown(Duration::from_millis(100)).await);
+    assert!(runtime.should_quit());
+}
+
+// =========================================================================
+// Sequence Tests
+// =========================================================================
+
+#[tokio::test(start_paused = true)]
+async fn test_runtime_sequence_dispatches_in_order() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    let started = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let flag = started.clone();
+    runtime.commands.execute(Command::sequence(vec![
+        Command::perform_async(async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Some(CounterMsg::IncrementBy(10))
+        }),
+        Command::perform_async(async move {
+            flag.store(true, std::sync::atomic::Ordering::SeqCst);
+            Some(CounterMsg::Decrement)
+        }),
+    ]));
+    runtime.spawn_pending_commands();
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert!(!started.load(std::sync::atomic::Ordering::SeqCst));
+
+    // Dispatches the first step's message and starts the second step
+    runtime.process_pending();
+    assert_eq!(runtime.state().count, 10);
+
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    runtime.process_pending();
+    assert!(started.load(std::sync::atomic::Ordering::SeqCst));
+    assert_eq!(runtime.state().count, 9);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_runtime_shutdown_waits_for_sequence() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    runtime.commands.execute(Command::sequence(vec![
+        Command::perform_async(async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Some(CounterMsg::IncrementBy(3))
+        }),
+        Command::perform_async(async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Some(CounterMsg::IncrementBy(4))
+        }),
+    ]));
+    runtime.spawn_pending_commands();
+
+    assert!(runtime.shutdown(Duration::from_secs(1)).await);
+    assert_eq!(runtime.state().count, 7);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_runtime_run_resumes_sequence() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    runtime.commands.execute(Command::sequence(vec![
+        Command::perform_async(async { Some(CounterMsg::IncrementBy(2)) }),
+        Command::perform_async(async { Some(CounterMsg::Quit) }),
+    ]));
+    runtime.spawn_pending_commands();
+
+    runtime.run().await.unwrap();
+    assert_eq!(runtime.state().count, 2);
+    assert!(runtime.should_quit());
+}
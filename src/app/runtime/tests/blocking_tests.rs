@@ -0,0 +1,115 @@
+//! Tests for driving async commands with `Runtime::run_commands_blocking`.
+
+use super::*;
+use crate::app::Command;
+
+fn blocking_runtime(
+    update: impl FnMut(&mut CounterState, CounterMsg) -> Command<CounterMsg> + Send + 'static,
+) -> Runtime<CounterApp, CaptureBackend> {
+    Runtime::virtual_builder(20, 2)
+        .build()
+        .unwrap()
+        .with_dynamic_update(Box::new(update))
+}
+
+#[test]
+fn test_run_commands_blocking_resolves_chained_async_commands() {
+    let mut vt = blocking_runtime(|state, msg| match msg {
+        CounterMsg::IncrementBy(n) => {
+            state.count += n;
+            if state.count < 30 {
+                Command::perform_async(async { Some(CounterMsg::IncrementBy(10)) })
+            } else {
+                Command::none()
+            }
+        }
+        _ => Command::perform_async(async { Some(CounterMsg::IncrementBy(10)) }),
+    });
+
+    vt.dispatch(CounterMsg::Increment);
+    assert_eq!(vt.state().count, 0);
+    assert_eq!(vt.diagnostics().pending_commands, 1);
+
+    vt.run_commands_blocking();
+    assert_eq!(vt.state().count, 30);
+    assert_eq!(vt.diagnostics().pending_commands, 0);
+}
+
+#[test]
+fn test_run_commands_blocking_collects_errors() {
+    let mut vt = blocking_runtime(|_state, _msg| {
+        Command::try_perform_async(
+            async { Err::<(), _>(std::io::Error::other("offline")) },
+            |_| Some(CounterMsg::Increment),
+        )
+    });
+
+    vt.dispatch(CounterMsg::Increment);
+    vt.run_commands_blocking();
+    let errors = vt.take_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), "offline");
+}
+
+#[test]
+fn test_run_commands_blocking_runs_sequences_in_order() {
+    let mut vt = blocking_runtime(|state, msg| match msg {
+        CounterMsg::Quit => Command::sequence([
+            Command::perform_async(async { Some(CounterMsg::IncrementBy(5)) }),
+            Command::message(CounterMsg::Decrement),
+            Command::perform_async(async { Some(CounterMsg::IncrementBy(10)) }),
+        ]),
+        CounterMsg::IncrementBy(n) => {
+            // Doubling first shows the decrement ran between the steps.
+            state.count = state.count * 2 + n;
+            Command::none()
+        }
+        CounterMsg::Decrement => {
+            state.count -= 1;
+            Command::none()
+        }
+        CounterMsg::Increment => Command::none(),
+    });
+
+    vt.dispatch(CounterMsg::Quit);
+    vt.run_commands_blocking();
+    assert_eq!(vt.state().count, 18);
+}
+
+#[test]
+fn test_run_commands_blocking_waits_for_wakeups_from_other_threads() {
+    let mut vt = blocking_runtime(|state, msg| match msg {
+        CounterMsg::IncrementBy(n) => {
+            state.count += n;
+            Command::none()
+        }
+        _ => {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(10));
+                let _ = tx.send(7);
+            });
+            Command::perform_async(async move { rx.await.ok().map(CounterMsg::IncrementBy) })
+        }
+    });
+
+    vt.dispatch(CounterMsg::Increment);
+    vt.run_commands_blocking();
+    assert_eq!(vt.state().count, 7);
+}
+
+#[test]
+fn test_run_commands_blocking_drops_futures_after_quit() {
+    let mut vt = blocking_runtime(|state, _msg| {
+        state.count += 1;
+        Command::combine([
+            Command::quit(),
+            Command::perform_async(async { Some(CounterMsg::Increment) }),
+        ])
+    });
+
+    vt.dispatch(CounterMsg::Increment);
+    vt.run_commands_blocking();
+    assert!(vt.should_quit());
+    assert_eq!(vt.state().count, 1);
+}
@@ -0,0 +1,121 @@
+//! Tests for coalescing terminal input bursts.
+
+use std::time::Duration;
+
+use super::super::burst::{ResizeDebouncer, coalesce_events};
+use crate::input::{Event, Key, MouseButton};
+
+#[test]
+fn test_coalesce_empty() {
+    assert!(coalesce_events(Vec::new()).is_empty());
+}
+
+#[test]
+fn test_coalesce_keeps_last_of_consecutive_resizes() {
+    let events = vec![
+        Event::Resize(80, 24),
+        Event::Resize(90, 30),
+        Event::Resize(100, 40),
+    ];
+    assert_eq!(coalesce_events(events), vec![Event::Resize(100, 40)]);
+}
+
+#[test]
+fn test_coalesce_keeps_last_of_consecutive_mouse_moves() {
+    let events = vec![
+        Event::mouse_move(1, 1),
+        Event::mouse_move(2, 1),
+        Event::mouse_move(3, 2),
+    ];
+    assert_eq!(coalesce_events(events), vec![Event::mouse_move(3, 2)]);
+}
+
+#[test]
+fn test_coalesce_preserves_keys_and_order() {
+    let events = vec![
+        Event::char('a'),
+        Event::char('a'),
+        Event::Resize(80, 24),
+        Event::key(Key::Enter),
+        Event::Resize(100, 40),
+    ];
+    assert_eq!(coalesce_events(events.clone()), events);
+}
+
+#[test]
+fn test_coalesce_does_not_merge_across_other_events() {
+    let events = vec![
+        Event::mouse_move(1, 1),
+        Event::click(1, 1),
+        Event::mouse_move(2, 2),
+        Event::mouse_move(3, 3),
+        Event::Resize(80, 24),
+        Event::Resize(120, 40),
+        Event::char('x'),
+    ];
+    assert_eq!(
+        coalesce_events(events),
+        vec![
+            Event::mouse_move(1, 1),
+            Event::click(1, 1),
+            Event::mouse_move(3, 3),
+            Event::Resize(120, 40),
+            Event::char('x'),
+        ]
+    );
+}
+
+#[test]
+fn test_coalesce_keeps_drags() {
+    let events = vec![
+        Event::mouse_drag(1, 1, MouseButton::Left),
+        Event::mouse_drag(2, 1, MouseButton::Left),
+    ];
+    assert_eq!(coalesce_events(events.clone()), events);
+}
+
+#[test]
+fn test_resize_debouncer_holds_latest_resize_until_taken() {
+    let start = tokio::time::Instant::now();
+    let window = Duration::from_millis(16);
+    let mut debouncer = ResizeDebouncer::new(window);
+
+    assert!(debouncer.offer(Event::Resize(80, 24), start).is_empty());
+    // Later resizes replace the held size without extending the window.
+    let later = start + Duration::from_millis(10);
+    assert!(debouncer.offer(Event::Resize(100, 30), later).is_empty());
+    assert_eq!(debouncer.deadline(), Some(start + window));
+
+    assert_eq!(debouncer.take(), Some(Event::Resize(100, 30)));
+    assert_eq!(debouncer.deadline(), None);
+    assert_eq!(debouncer.take(), None);
+}
+
+#[test]
+fn test_resize_debouncer_flushes_before_other_events() {
+    let now = tokio::time::Instant::now();
+    let mut debouncer = ResizeDebouncer::new(Duration::from_millis(16));
+
+    assert!(debouncer.offer(Event::Resize(80, 24), now).is_empty());
+    assert_eq!(
+        debouncer.offer(Event::char('x'), now),
+        vec![Event::Resize(80, 24), Event::char('x')]
+    );
+    assert_eq!(debouncer.deadline(), None);
+    assert_eq!(
+        debouncer.offer(Event::char('y'), now),
+        vec![Event::char('y')]
+    );
+}
+
+#[test]
+fn test_resize_debouncer_zero_window_passes_through() {
+    let now = tokio::time::Instant::now();
+    let mut debouncer = ResizeDebouncer::new(Duration::ZERO);
+
+    assert_eq!(
+        debouncer.offer(Event::Resize(80, 24), now),
+        vec![Event::Resize(80, 24)]
+    );
+    assert_eq!(debouncer.deadline(), None);
+}
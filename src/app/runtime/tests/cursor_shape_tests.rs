@@ -0,0 +1,58 @@
+//! Tests for cursor shapes set with `Command::set_cursor_shape`.
+
+use super::*;
+use crate::app::Command;
+use crate::backend::CursorShape;
+
+struct ShapeApp;
+
+impl App for ShapeApp {
+    type State = ();
+    type Message = Option<CursorShape>;
+    type Args = ();
+
+    fn init(_args: ()) -> (Self::State, Command<Self::Message>) {
+        ((), Command::set_cursor_shape(Some(CursorShape::SteadyBar)))
+    }
+
+    fn update(_state: &mut Self::State, shape: Self::Message) -> Command<Self::Message> {
+        Command::set_cursor_shape(shape)
+    }
+
+    fn view(_state: &Self::State, _frame: &mut ratatui::Frame) {}
+}
+
+#[test]
+fn test_init_cursor_shape_reaches_capture_backend() {
+    let vt: Runtime<ShapeApp, _> = Runtime::virtual_builder(20, 2).build().unwrap();
+    assert_eq!(vt.cursor_shape(), Some(CursorShape::SteadyBar));
+    assert_eq!(vt.backend().cursor_shape(), Some(CursorShape::SteadyBar));
+}
+
+#[test]
+fn test_update_cursor_shape_replaces_previous() {
+    let mut vt: Runtime<ShapeApp, _> = Runtime::virtual_builder(20, 2).build().unwrap();
+    vt.dispatch(Some(CursorShape::BlinkingBlock));
+    assert_eq!(vt.cursor_shape(), Some(CursorShape::BlinkingBlock));
+    assert_eq!(
+        vt.backend().cursor_shape(),
+        Some(CursorShape::BlinkingBlock)
+    );
+}
+
+#[test]
+fn test_cursor_shape_none_restores_default() {
+    let mut vt: Runtime<ShapeApp, _> = Runtime::virtual_builder(20, 2).build().unwrap();
+    vt.dispatch(None);
+    assert_eq!(vt.cursor_shape(), None);
+    assert_eq!(vt.backend().cursor_shape(), None);
+}
+
+#[test]
+fn test_cursor_shape_without_writer_is_only_recorded() {
+    let backend = CaptureBackend::new(20, 2);
+    let mut vt: Runtime<ShapeApp, _> = Runtime::builder(backend).build().unwrap();
+    vt.dispatch(Some(CursorShape::SteadyUnderline));
+    assert_eq!(vt.cursor_shape(), Some(CursorShape::SteadyUnderline));
+    assert_eq!(vt.backend().cursor_shape(), None);
+}
@@ -0,0 +1,81 @@
+use super::*;
+use crate::app::Command;
+use crate::app::command::BoxedError;
+use crate::app::subscription::{ChannelSubscription, TimerSubscription};
+
+#[test]
+fn test_diagnostics_idle_runtime() {
+    let runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    let diagnostics = runtime.diagnostics();
+    assert!(diagnostics.is_idle());
+    assert_eq!(diagnostics, RuntimeDiagnostics::default());
+}
+
+#[test]
+fn test_diagnostics_counts_queued_events() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    runtime.send(Event::char('a'));
+    runtime.send(Event::char('b'));
+    assert_eq!(runtime.diagnostics().queued_events, 2);
+
+    runtime.process_all_events();
+    assert_eq!(runtime.diagnostics().queued_events, 0);
+}
+
+#[tokio::test]
+async fn test_diagnostics_counts_pending_messages_and_errors() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    runtime
+        .message_sender()
+        .send(CounterMsg::Increment)
+        .await
+        .unwrap();
+    let err: BoxedError = Box::new(std::io::Error::other("boom"));
+    runtime.error_sender().send(err).await.unwrap();
+
+    let diagnostics = runtime.diagnostics();
+    assert_eq!(diagnostics.pending_messages, 1);
+    assert_eq!(diagnostics.pending_errors, 1);
+
+    runtime.process_pending();
+    let _ = runtime.take_errors();
+    assert!(runtime.diagnostics().is_idle());
+}
+
+#[tokio::test]
+async fn test_diagnostics_counts_pending_commands() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+    runtime.commands.execute(Command::perform_async(async move {
+        let _ = rx.await;
+        Some(CounterMsg::Increment)
+    }));
+    assert_eq!(runtime.diagnostics().pending_commands, 1);
+
+    runtime.spawn_pending_commands();
+    assert_eq!(runtime.diagnostics().pending_commands, 1);
+
+    tx.send(()).unwrap();
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert_eq!(runtime.diagnostics().pending_commands, 0);
+}
+
+#[tokio::test]
+async fn test_diagnostics_counts_active_subscriptions() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    let (_tx, rx) = tokio::sync::mpsc::channel::<CounterMsg>(1);
+    runtime.subscribe(ChannelSubscription::new(rx));
+    runtime.subscribe(TimerSubscription::after(
+        Duration::from_millis(1),
+        CounterMsg::Increment,
+    ));
+    assert_eq!(runtime.diagnostics().active_subscriptions, 2);
+
+    // The timer ends after firing; the channel stays open.
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(runtime.diagnostics().active_subscriptions, 1);
+
+    runtime.quit();
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert_eq!(runtime.diagnostics().active_subscriptions, 0);
+}
@@ -0,0 +1,68 @@
+//! Tests for replacing `App::update` with `Runtime::with_dynamic_update`.
+
+use super::*;
+use crate::app::Command;
+
+#[test]
+fn test_dynamic_update_replaces_app_update() {
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(20, 2)
+        .build()
+        .unwrap()
+        .with_dynamic_update(Box::new(|state: &mut CounterState, msg| {
+            match msg {
+                CounterMsg::Increment => state.count += 100,
+                _ => state.count -= 1,
+            }
+            Command::none()
+        }));
+    assert!(vt.has_dynamic_update());
+
+    vt.dispatch(CounterMsg::Increment);
+    vt.dispatch(CounterMsg::Quit);
+    assert_eq!(vt.state().count, 99);
+    assert!(!vt.should_quit());
+}
+
+#[test]
+fn test_dynamic_update_can_be_swapped_and_removed() {
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(20, 2).build().unwrap();
+    assert!(!vt.has_dynamic_update());
+
+    let mut step = 2;
+    vt.set_dynamic_update(Some(Box::new(move |state: &mut CounterState, _msg| {
+        // Captured state persists between calls
+        state.count += step;
+        step *= 2;
+        Command::none()
+    })));
+    vt.dispatch(CounterMsg::Increment);
+    vt.dispatch(CounterMsg::Increment);
+    assert_eq!(vt.state().count, 6);
+
+    vt.set_dynamic_update(None);
+    vt.dispatch(CounterMsg::Increment);
+    assert_eq!(vt.state().count, 7);
+}
+
+#[test]
+fn test_dynamic_update_commands_are_executed() {
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(20, 2)
+        .build()
+        .unwrap()
+        .with_dynamic_update(Box::new(|state: &mut CounterState, msg| match msg {
+            CounterMsg::IncrementBy(n) => {
+                state.count += n;
+                Command::message(CounterMsg::Quit)
+            }
+            CounterMsg::Quit => {
+                state.quit = true;
+                Command::none()
+            }
+            _ => Command::none(),
+        }));
+
+    vt.dispatch(CounterMsg::IncrementBy(5));
+    vt.tick().unwrap();
+    assert_eq!(vt.state().count, 5);
+    assert!(vt.should_quit());
+}
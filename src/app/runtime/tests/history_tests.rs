@@ -0,0 +1,48 @@
+//! Tests for `Runtime::frame_history`.
+
+use super::*;
+use crate::input::Event;
+
+#[test]
+fn test_frame_history_disabled_by_default() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 3).build().unwrap();
+    vt.render().unwrap();
+    vt.render().unwrap();
+    assert!(vt.frame_history().is_empty());
+}
+
+#[test]
+fn test_frame_history_records_rendered_frames() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 3)
+        .config(RuntimeConfig::new().with_history(5))
+        .build()
+        .unwrap();
+    vt.render().unwrap();
+    vt.send(Event::char('a'));
+    vt.tick().unwrap();
+
+    let history = vt.frame_history();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].size, (40, 3));
+    assert!(history[0].to_plain().contains("Events: 0"));
+    assert!(history[1].to_plain().contains("Events: 1"));
+    assert_eq!(history[1].to_plain(), vt.display());
+}
+
+#[test]
+fn test_frame_history_keeps_last_frames() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 3)
+        .config(RuntimeConfig::new().with_history(2))
+        .build()
+        .unwrap();
+    for c in ['a', 'b', 'c'] {
+        vt.send(Event::char(c));
+        vt.tick().unwrap();
+    }
+
+    let history = vt.frame_history();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].frame + 1, history[1].frame);
+    assert!(history[0].to_plain().contains("Events: 2"));
+    assert!(history[1].to_plain().contains("Events: 3"));
+}
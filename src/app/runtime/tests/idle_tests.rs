@@ -0,0 +1,113 @@
+//! Tests for `Runtime::run_until_idle`.
+
+use super::*;
+use crate::app::Command;
+use crate::input::{Event, Key};
+
+/// An app whose init kicks off a fetch → parse → display chain.
+struct ChainApp;
+
+#[derive(Clone, Default)]
+struct ChainState {
+    steps: Vec<&'static str>,
+    looping: bool,
+}
+
+#[derive(Clone, Debug)]
+enum ChainMsg {
+    Fetch,
+    Parse,
+    Display,
+    Loop,
+}
+
+impl App for ChainApp {
+    type State = ChainState;
+    type Message = ChainMsg;
+    type Args = ();
+
+    fn init(_args: ()) -> (Self::State, Command<Self::Message>) {
+        (ChainState::default(), Command::message(ChainMsg::Fetch))
+    }
+
+    fn update(state: &mut Self::State, msg: Self::Message) -> Command<Self::Message> {
+        match msg {
+            ChainMsg::Fetch => {
+                state.steps.push("fetch");
+                Command::message(ChainMsg::Parse)
+            }
+            ChainMsg::Parse => {
+                state.steps.push("parse");
+                Command::message(ChainMsg::Display)
+            }
+            ChainMsg::Display => {
+                state.steps.push("display");
+                Command::none()
+            }
+            ChainMsg::Loop => {
+                state.looping = true;
+                Command::message(ChainMsg::Loop)
+            }
+        }
+    }
+
+    fn view(state: &Self::State, frame: &mut ratatui::Frame) {
+        let text = state.steps.join(" > ");
+        frame.render_widget(Paragraph::new(text), frame.area());
+    }
+
+    fn handle_event(event: &Event) -> Option<Self::Message> {
+        match event.as_key()?.code {
+            Key::Char('l') => Some(ChainMsg::Loop),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_run_until_idle_follows_command_chain() {
+    let mut vt: Runtime<ChainApp, _> = Runtime::virtual_builder(40, 3).build().unwrap();
+
+    let iterations = vt.run_until_idle(10).unwrap();
+    assert_eq!(iterations, 3);
+    assert_eq!(vt.state().steps, vec!["fetch", "parse", "display"]);
+    assert!(vt.contains_text("fetch > parse > display"));
+}
+
+#[test]
+fn test_run_until_idle_when_already_idle() {
+    let mut vt: Runtime<ChainApp, _> = Runtime::virtual_builder(40, 3).build().unwrap();
+    vt.run_until_idle(10).unwrap();
+
+    assert_eq!(vt.run_until_idle(10).unwrap(), 0);
+}
+
+#[test]
+fn test_run_until_idle_stops_at_max_iters() {
+    let mut vt: Runtime<ChainApp, _> = Runtime::virtual_builder(40, 3).build().unwrap();
+    vt.run_until_idle(10).unwrap();
+
+    vt.send(Event::char('l'));
+    assert_eq!(vt.run_until_idle(5).unwrap(), 5);
+    assert!(vt.state().looping);
+}
+
+#[test]
+fn test_run_until_idle_processes_async_messages() {
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(40, 3).build().unwrap();
+    let sender = vt.message_sender();
+    sender.try_send(CounterMsg::IncrementBy(5)).unwrap();
+
+    assert_eq!(vt.run_until_idle(10).unwrap(), 1);
+    assert_eq!(vt.state().count, 5);
+    assert!(vt.contains_text("Count: 5"));
+}
+
+#[test]
+fn test_run_until_idle_stops_on_quit() {
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(40, 3).build().unwrap();
+    vt.dispatch(CounterMsg::Quit);
+
+    assert_eq!(vt.run_until_idle(10).unwrap(), 0);
+    assert!(vt.should_quit());
+}
@@ -0,0 +1,76 @@
+use super::*;
+use crate::component::RenderContext;
+use crate::input::{Key, KeyEvent, KeyMap};
+use crate::overlay::{Overlay, OverlayAction};
+
+/// An overlay that consumes every event.
+struct Swallow;
+
+impl Overlay<EventMsg> for Swallow {
+    fn handle_event(&mut self, _event: &Event) -> OverlayAction<EventMsg> {
+        OverlayAction::Consumed
+    }
+
+    fn view(&self, _ctx: &mut RenderContext<'_, '_>) {}
+}
+
+#[test]
+fn test_keymap_checked_before_handle_event() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 5).build().unwrap();
+    vt.set_keymap(KeyMap::new().bind(KeyEvent::char('x'), EventMsg::Quit));
+
+    vt.send(Event::char('x'));
+    vt.process_all_events();
+
+    assert!(vt.state().quit);
+    assert_eq!(vt.state().events_received, 0);
+}
+
+#[test]
+fn test_unbound_keys_reach_handle_event() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 5).build().unwrap();
+    vt.set_keymap(KeyMap::new().bind(KeyEvent::ctrl('c'), EventMsg::Quit));
+
+    vt.send(Event::char('c'));
+    vt.process_all_events();
+
+    assert!(!vt.state().quit);
+    assert_eq!(vt.state().last_key, Some('c'));
+
+    vt.send(Event::ctrl('c'));
+    vt.process_all_events();
+    assert!(vt.state().quit);
+}
+
+#[test]
+fn test_keymap_handles_keys_the_app_ignores() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 5).build().unwrap();
+    vt.set_keymap(KeyMap::new().bind(KeyEvent::new(Key::Esc), EventMsg::Quit));
+
+    vt.send(Event::key(Key::Esc));
+    vt.tick().unwrap();
+
+    assert!(vt.should_quit());
+}
+
+#[test]
+fn test_overlay_consumes_before_keymap() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 5).build().unwrap();
+    vt.set_keymap(KeyMap::new().bind(KeyEvent::char('x'), EventMsg::Quit));
+    vt.push_overlay(Box::new(Swallow));
+
+    vt.send(Event::char('x'));
+    vt.process_all_events();
+
+    assert!(!vt.state().quit);
+}
+
+#[test]
+fn test_keymap_accessor() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 5).build().unwrap();
+    assert!(vt.keymap().is_empty());
+
+    vt.set_keymap(KeyMap::new().bind_described(KeyEvent::char('x'), "Quit", EventMsg::Quit));
+    let descriptions: Vec<_> = vt.keymap().bindings().map(|b| b.description()).collect();
+    assert_eq!(descriptions, [Some("Quit")]);
+}
@@ -84,6 +84,37 @@ fn test_runtime_render() {
     assert!(runtime.contains_text("Count: 2"));
 }
 
+#[test]
+fn test_runtime_render_to_string_skips_tick_pipeline() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(40, 10).build().unwrap();
+    runtime.send(crate::input::Event::char('x'));
+    runtime.dispatch(CounterMsg::Quit);
+    runtime.dispatch(CounterMsg::Increment);
+
+    let screen = runtime.render_to_string().unwrap();
+    assert!(screen.contains("Count: 1"));
+    assert_eq!(screen, runtime.display());
+    assert!(!runtime.should_quit());
+    assert_eq!(runtime.events().len(), 1);
+}
+
+#[test]
+fn test_runtime_headless_render_is_independent_of_terminal() {
+    let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(40, 10).build().unwrap();
+    runtime.dispatch(CounterMsg::Increment);
+
+    let screen = runtime.headless_render().unwrap();
+    assert!(screen.contains("Count: 1"));
+    assert!(!runtime.contains_text("Count: 1"));
+
+    runtime.render().unwrap();
+    runtime.dispatch(CounterMsg::Increment);
+    assert_eq!(
+        runtime.headless_render().unwrap(),
+        runtime.render_to_string().unwrap()
+    );
+}
+
 #[test]
 fn test_runtime_quit() {
     let mut runtime: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
@@ -550,6 +581,49 @@ fn test_virtual_terminal_send_and_tick() {
     assert_eq!(vt.state().events_received, 2);
 }
 
+#[test]
+fn test_builder_initial_events() {
+    use crate::input::Event;
+
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(80, 24)
+        .initial_events([Event::char('a'), Event::char('b')])
+        .initial_events([Event::char('c')])
+        .build()
+        .unwrap();
+    assert_eq!(vt.state().events_received, 0);
+
+    vt.tick().unwrap();
+    assert_eq!(vt.state().events_received, 3);
+}
+
+#[test]
+fn test_configured_builder_initial_events() {
+    use crate::input::Event;
+
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(80, 24)
+        .initial_events([Event::char('a')])
+        .with_args(())
+        .initial_events([Event::char('b')])
+        .build()
+        .unwrap();
+
+    vt.tick().unwrap();
+    assert_eq!(vt.state().events_received, 2);
+}
+
+#[test]
+fn test_preload_queues_events() {
+    use crate::input::Event;
+
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    vt.preload(vec![Event::char('a'), Event::char('b')]);
+    assert_eq!(vt.events().len(), 2);
+
+    vt.tick().unwrap();
+    assert_eq!(vt.state().events_received, 2);
+    assert!(vt.events().is_empty());
+}
+
 #[test]
 fn test_virtual_terminal_display() {
     let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(40, 10).build().unwrap();
@@ -560,6 +634,18 @@ fn test_virtual_terminal_display() {
     assert!(display.contains("Count: 1"));
 }
 
+#[test]
+fn test_virtual_terminal_lines() {
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(40, 3).build().unwrap();
+    vt.dispatch(CounterMsg::Increment);
+    vt.tick().unwrap();
+
+    assert_eq!(vt.lines(), vec!["Count: 1", "", ""]);
+    assert_eq!(vt.line(0).as_deref(), Some("Count: 1"));
+    assert_eq!(vt.line(2).as_deref(), Some(""));
+    assert_eq!(vt.line(3), None);
+}
+
 #[test]
 fn test_virtual_terminal_display_ansi() {
     let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(40, 10).build().unwrap();
@@ -650,6 +736,102 @@ fn test_run_terminal_blocking_exists() {
 
 mod args_tests;
 
+// =========================================================================
+// Tick-driven overlay and clock tests — in tick_tests.rs
+// =========================================================================
+
+mod tick_tests;
+
+// =========================================================================
+// run_until_idle tests — in idle_tests.rs
+// =========================================================================
+
+mod idle_tests;
+
+// =========================================================================
+// replay_session tests — in replay_tests.rs
+// =========================================================================
+
+mod replay_tests;
+
+// =========================================================================
+// frame_history tests — in history_tests.rs
+// =========================================================================
+
+mod history_tests;
+
+// =========================================================================
+// size and on_resize tests — in resize_tests.rs
+// =========================================================================
+
+mod resize_tests;
+
+// =========================================================================
+// Terminal input burst coalescing tests — in burst_tests.rs
+// =========================================================================
+
+mod burst_tests;
+
+// =========================================================================
+// Runtime diagnostics tests — in diagnostics_tests.rs
+// =========================================================================
+
+mod diagnostics_tests;
+
+// =========================================================================
+// Blocking overlay tests — in modal_tests.rs
+// =========================================================================
+
+mod modal_tests;
+
+// =========================================================================
+// Render-on-demand tests — in redraw_tests.rs
+// =========================================================================
+
+mod redraw_tests;
+
+// =========================================================================
+// Event loop stepping tests — in step_tests.rs
+// =========================================================================
+
+mod step_tests;
+
+// =========================================================================
+// Keymap tests — in keymap_tests.rs
+// =========================================================================
+
+mod keymap_tests;
+
+// =========================================================================
+// Tick outcome tests — in tick_outcome_tests.rs
+// =========================================================================
+
+mod tick_outcome_tests;
+
+// =========================================================================
+// Title tests — in title_tests.rs
+// =========================================================================
+
+mod title_tests;
+
+// =========================================================================
+// Cursor shape tests — in cursor_shape_tests.rs
+// =========================================================================
+
+mod cursor_shape_tests;
+
+// =========================================================================
+// Dynamic update tests — in dynamic_update_tests.rs
+// =========================================================================
+
+mod dynamic_update_tests;
+
+// =========================================================================
+// Blocking command tests — in blocking_tests.rs
+// =========================================================================
+
+mod blocking_tests;
+
 // =========================================================================
 // Overlay Tests
 // =========================================================================
@@ -773,6 +955,54 @@ mod overlay_tests {
         assert_eq!(vt.overlay_count(), 0);
     }
 
+    #[test]
+    fn test_terminal_event_release_skips_overlay_beneath() {
+        use crate::input::{KeyEventBuilder, KeyEventKind};
+
+        let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+        vt.push_overlay(Box::new(DialogOverlay));
+        vt.push_overlay(Box::new(DialogOverlay));
+
+        vt.handle_terminal_event(Event::key(Key::Esc));
+        assert_eq!(vt.overlay_count(), 1);
+
+        // The release of the dismissing Esc must not close the next dialog
+        let release = KeyEventBuilder::new()
+            .code(Key::Esc)
+            .kind(KeyEventKind::Release)
+            .into_event();
+        vt.handle_terminal_event(release);
+        assert_eq!(vt.overlay_count(), 1);
+    }
+
+    #[cfg(feature = "display-components")]
+    #[test]
+    fn test_runtime_overlay_commands_apply_in_issue_order() {
+        use crate::overlay::SpinnerOverlay;
+
+        let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+        vt.commands.execute(Command::show_spinner("Loading"));
+        vt.process_commands();
+
+        // Hide then show in one batch leaves the new spinner up
+        vt.commands.execute(Command::combine([
+            Command::hide_spinner(),
+            Command::show_spinner("Saving"),
+        ]));
+        vt.process_commands();
+        assert_eq!(vt.overlay_count(), 1);
+        let spinner = vt.core.overlay_stack.find_mut::<SpinnerOverlay>().unwrap();
+        assert_eq!(spinner.message(), "Saving");
+
+        // Show then hide removes it
+        vt.commands.execute(Command::combine([
+            Command::show_spinner("Again"),
+            Command::hide_spinner(),
+        ]));
+        vt.process_commands();
+        assert_eq!(vt.overlay_count(), 0);
+    }
+
     #[test]
     fn test_runtime_overlay_dismiss_with_message() {
         let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
@@ -0,0 +1,87 @@
+use super::*;
+use crate::component::RenderContext;
+use crate::input::Key;
+use crate::overlay::{Overlay, OverlayAction};
+
+/// A prompt that resolves to the first digit typed, propagates letters,
+/// and dismisses without a value on Esc.
+struct DigitPrompt;
+
+impl Overlay<u32> for DigitPrompt {
+    fn handle_event(&mut self, event: &Event) -> OverlayAction<u32> {
+        match event.as_key().map(|key| key.code) {
+            Some(Key::Char(c)) if c.is_ascii_digit() => {
+                OverlayAction::DismissWithMessage(c.to_digit(10).unwrap())
+            }
+            Some(Key::Esc) => OverlayAction::Dismiss,
+            _ => OverlayAction::Propagate,
+        }
+    }
+
+    fn view(&self, ctx: &mut RenderContext<'_, '_>) {
+        ctx.frame
+            .render_widget(Paragraph::new("Digit?"), ctx.area.rows().nth(2).unwrap());
+    }
+}
+
+#[test]
+fn test_run_overlay_until_returns_value() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 5).build().unwrap();
+    vt.send(Event::char('7'));
+
+    assert_eq!(vt.run_overlay_until(DigitPrompt).unwrap(), Some(7));
+    assert!(!vt.has_overlays());
+    assert!(!vt.contains_text("Digit?"));
+}
+
+#[test]
+fn test_run_overlay_until_propagates_to_app() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 5).build().unwrap();
+    vt.send(Event::char('a'));
+    vt.send(Event::char('b'));
+    vt.send(Event::char('3'));
+    vt.send(Event::char('c'));
+
+    assert_eq!(vt.run_overlay_until(DigitPrompt).unwrap(), Some(3));
+    // The resolving digit is taken by the overlay; letters before and
+    // after it reach the app
+    assert_eq!(vt.state().events_received, 3);
+    assert_eq!(vt.state().last_key, Some('c'));
+    assert!(vt.state().ticks > 0);
+}
+
+#[test]
+fn test_run_overlay_until_renders_overlay() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 5)
+        .config(RuntimeConfig::new().with_history(8))
+        .build()
+        .unwrap();
+    vt.send(Event::char('a'));
+    vt.send(Event::char('1'));
+
+    assert_eq!(vt.run_overlay_until(DigitPrompt).unwrap(), Some(1));
+    let frames = vt.frame_history();
+    assert!(frames[0].to_plain().contains("Digit?"));
+    assert!(!frames.last().unwrap().to_plain().contains("Digit?"));
+}
+
+#[test]
+fn test_run_overlay_until_dismiss_returns_none() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 5).build().unwrap();
+    vt.send(Event::key(Key::Esc));
+
+    assert_eq!(vt.run_overlay_until(DigitPrompt).unwrap(), None);
+    assert!(!vt.has_overlays());
+}
+
+#[test]
+fn test_run_overlay_until_exhausted_queue_removes_overlay() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 5).build().unwrap();
+    vt.send(Event::char('a'));
+
+    assert_eq!(vt.run_overlay_until(DigitPrompt).unwrap(), None);
+    assert_eq!(vt.diagnostics().queued_events, 0);
+    assert_eq!(vt.state().last_key, Some('a'));
+    assert!(!vt.has_overlays());
+    assert!(!vt.contains_text("Digit?"));
+}
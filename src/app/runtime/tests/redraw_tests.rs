@@ -0,0 +1,173 @@
+use super::*;
+use crate::app::Command;
+use crate::component::RenderContext;
+use crate::overlay::{Overlay, OverlayAction};
+use ratatui::layout::Rect;
+
+struct RedrawApp;
+
+#[derive(Clone, Debug)]
+enum RedrawMsg {
+    /// Changes state without asking for a redraw.
+    Quiet,
+    /// Changes state and asks for a redraw.
+    Loud,
+    /// Closes the topmost overlay.
+    Close,
+}
+
+impl App for RedrawApp {
+    type State = i32;
+    type Message = RedrawMsg;
+    type Args = ();
+
+    fn init(_args: ()) -> (Self::State, Command<Self::Message>) {
+        (0, Command::none())
+    }
+
+    fn update(state: &mut Self::State, msg: Self::Message) -> Command<Self::Message> {
+        match msg {
+            RedrawMsg::Quiet => {
+                *state += 1;
+                Command::none()
+            }
+            RedrawMsg::Loud => {
+                *state += 1;
+                Command::request_redraw()
+            }
+            RedrawMsg::Close => Command::pop_overlay(),
+        }
+    }
+
+    fn view(state: &Self::State, frame: &mut ratatui::Frame) {
+        let text = format!("Count: {}", state);
+        frame.render_widget(Paragraph::new(text), frame.area());
+    }
+}
+
+/// An overlay that draws a marker and consumes all events.
+struct MarkerOverlay;
+
+impl Overlay<RedrawMsg> for MarkerOverlay {
+    fn handle_event(&mut self, _event: &Event) -> OverlayAction<RedrawMsg> {
+        OverlayAction::Consumed
+    }
+
+    fn view(&self, ctx: &mut RenderContext<'_, '_>) {
+        ctx.frame
+            .render_widget(Paragraph::new("OVERLAY"), Rect::new(0, 1, 7, 1));
+    }
+}
+
+fn on_demand_runtime() -> Runtime<RedrawApp, CaptureBackend> {
+    Runtime::virtual_builder(20, 3)
+        .render_on_demand(true)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_render_on_demand_disabled_by_default() {
+    let mut runtime: Runtime<RedrawApp, _> = Runtime::virtual_builder(20, 3).build().unwrap();
+    runtime.tick().unwrap();
+    assert!(runtime.needs_redraw());
+
+    runtime.dispatch(RedrawMsg::Quiet);
+    runtime.tick().unwrap();
+    assert!(runtime.contains_text("Count: 1"));
+    assert_eq!(runtime.rendered, 2);
+}
+
+#[test]
+fn test_render_on_demand_first_tick_renders() {
+    let mut runtime = on_demand_runtime();
+    assert!(runtime.needs_redraw());
+
+    runtime.tick().unwrap();
+    assert!(runtime.contains_text("Count: 0"));
+    assert!(!runtime.needs_redraw());
+}
+
+#[test]
+fn test_render_on_demand_skips_unrequested_frames() {
+    let mut runtime = on_demand_runtime();
+    runtime.tick().unwrap();
+
+    runtime.dispatch(RedrawMsg::Quiet);
+    runtime.tick().unwrap();
+    runtime.tick().unwrap();
+
+    assert!(runtime.contains_text("Count: 0"));
+    assert_eq!(runtime.rendered, 1);
+}
+
+#[test]
+fn test_render_on_demand_command_requests_redraw() {
+    let mut runtime = on_demand_runtime();
+    runtime.tick().unwrap();
+
+    runtime.dispatch(RedrawMsg::Quiet);
+    runtime.dispatch(RedrawMsg::Loud);
+    assert!(runtime.needs_redraw());
+
+    runtime.tick().unwrap();
+    assert!(runtime.contains_text("Count: 2"));
+    assert!(!runtime.needs_redraw());
+}
+
+#[test]
+fn test_render_on_demand_runtime_request_redraw() {
+    let mut runtime = on_demand_runtime();
+    runtime.tick().unwrap();
+
+    *runtime.state_mut() = 7;
+    runtime.request_redraw();
+    runtime.tick().unwrap();
+
+    assert!(runtime.contains_text("Count: 7"));
+}
+
+#[test]
+fn test_render_on_demand_explicit_render_always_draws() {
+    let mut runtime = on_demand_runtime();
+    runtime.tick().unwrap();
+
+    runtime.dispatch(RedrawMsg::Quiet);
+    runtime.render().unwrap();
+    assert!(runtime.contains_text("Count: 1"));
+}
+
+#[test]
+fn test_render_on_demand_resize_requests_redraw() {
+    let mut runtime = on_demand_runtime();
+    runtime.tick().unwrap();
+
+    runtime.send(Event::Resize(20, 3));
+    runtime.process_all_events();
+    assert!(runtime.needs_redraw());
+}
+
+#[test]
+fn test_render_on_demand_redraws_while_overlay_shows() {
+    let mut runtime = on_demand_runtime();
+    runtime.tick().unwrap();
+
+    runtime.push_overlay(Box::new(MarkerOverlay));
+    runtime.dispatch(RedrawMsg::Quiet);
+    runtime.tick().unwrap();
+    assert!(runtime.contains_text("Count: 1"));
+    assert!(runtime.contains_text("OVERLAY"));
+}
+
+#[test]
+fn test_render_on_demand_redraws_after_overlay_closes() {
+    let mut runtime = on_demand_runtime();
+    runtime.push_overlay(Box::new(MarkerOverlay));
+    runtime.tick().unwrap();
+    assert!(runtime.contains_text("OVERLAY"));
+
+    runtime.dispatch(RedrawMsg::Close);
+    runtime.tick().unwrap();
+    assert!(!runtime.has_overlays());
+    assert!(!runtime.contains_text("OVERLAY"));
+}
@@ -0,0 +1,50 @@
+//! Tests for `Runtime::replay_session`.
+
+use super::*;
+use crate::input::Event;
+
+#[test]
+fn test_replay_session_matches_live_session() {
+    let events = [Event::char('a'), Event::char('b'), Event::char('c')];
+
+    let mut live: Runtime<EventApp, _> = Runtime::virtual_builder(40, 3).build().unwrap();
+    for event in &events {
+        live.send(event.clone());
+        live.tick().unwrap();
+    }
+
+    let fresh: Runtime<EventApp, _> = Runtime::virtual_builder(40, 3).build().unwrap();
+    let replayed = fresh.replay_session(&events).unwrap();
+    assert_eq!(replayed, live.display());
+    assert!(replayed.contains("Events: 3, Ticks: 3"));
+}
+
+#[test]
+fn test_replay_session_leaves_runtime_untouched() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 3).build().unwrap();
+    vt.send(Event::char('x'));
+    vt.tick().unwrap();
+    let before = vt.display();
+
+    let replayed = vt.replay_session(&[]).unwrap();
+    assert!(replayed.contains("Events: 0, Ticks: 0"));
+    assert_eq!(vt.display(), before);
+    assert_eq!(vt.state().events_received, 1);
+}
+
+#[test]
+fn test_replay_session_stops_after_quit() {
+    let vt: Runtime<EventApp, _> = Runtime::virtual_builder(40, 3).build().unwrap();
+    let events = [Event::char('a'), Event::char('q'), Event::char('b')];
+
+    let replayed = vt.replay_session(&events).unwrap();
+    assert!(replayed.contains("Events: 1, Ticks: 2"));
+}
+
+#[test]
+fn test_replay_session_uses_runtime_size() {
+    let vt: Runtime<CounterApp, _> = Runtime::virtual_builder(12, 2).build().unwrap();
+    let replayed = vt.replay_session(&[]).unwrap();
+    assert_eq!(replayed.lines().count(), 2);
+    assert!(replayed.lines().all(|line| line.chars().count() == 12));
+}
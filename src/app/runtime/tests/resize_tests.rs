@@ -0,0 +1,83 @@
+//! Tests for `Runtime::size` and the `App::on_resize` hook.
+
+use super::*;
+
+struct ResizeApp;
+
+#[derive(Clone, Default)]
+struct ResizeState {
+    sizes: Vec<(u16, u16)>,
+    events: usize,
+}
+
+#[derive(Clone, Debug)]
+enum ResizeMsg {
+    Resized(u16, u16),
+    Event,
+}
+
+impl App for ResizeApp {
+    type State = ResizeState;
+    type Message = ResizeMsg;
+    type Args = ();
+
+    fn init(_args: ()) -> (Self::State, super::super::Command<Self::Message>) {
+        (ResizeState::default(), super::super::Command::none())
+    }
+
+    fn update(state: &mut Self::State, msg: Self::Message) -> super::super::Command<Self::Message> {
+        match msg {
+            ResizeMsg::Resized(w, h) => state.sizes.push((w, h)),
+            ResizeMsg::Event => state.events += 1,
+        }
+        super::super::Command::none()
+    }
+
+    fn view(_state: &Self::State, _frame: &mut ratatui::Frame) {}
+
+    fn handle_event(_event: &Event) -> Option<Self::Message> {
+        Some(ResizeMsg::Event)
+    }
+
+    fn on_resize(_state: &Self::State, width: u16, height: u16) -> Option<Self::Message> {
+        Some(ResizeMsg::Resized(width, height))
+    }
+}
+
+#[test]
+fn test_runtime_size_reports_virtual_dimensions() {
+    let vt: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    assert_eq!(vt.size(), (80, 24));
+
+    let vt: Runtime<CounterApp, _> = Runtime::virtual_builder(40, 10).build().unwrap();
+    assert_eq!(vt.size(), (40, 10));
+}
+
+#[test]
+fn test_runtime_on_resize_called_for_resize_event() {
+    let mut vt: Runtime<ResizeApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    vt.send(Event::Resize(120, 40));
+    vt.process_all_events();
+
+    assert_eq!(vt.state().sizes, vec![(120, 40)]);
+    // The resize event is still routed to handle_event
+    assert_eq!(vt.state().events, 1);
+}
+
+#[test]
+fn test_runtime_on_resize_not_called_for_other_events() {
+    let mut vt: Runtime<ResizeApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    vt.send(Event::char('a'));
+    vt.process_all_events();
+
+    assert!(vt.state().sizes.is_empty());
+    assert_eq!(vt.state().events, 1);
+}
+
+#[test]
+fn test_runtime_on_resize_default_is_noop() {
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    vt.send(Event::Resize(100, 30));
+    assert!(vt.process_event());
+    assert_eq!(vt.state().count, 0);
+}
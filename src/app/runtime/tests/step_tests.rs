@@ -0,0 +1,89 @@
+//! Tests for stepping the async event loop one iteration at a time.
+
+use super::*;
+use crate::app::{Command, StepOutcome, TickSubscription};
+
+/// A runtime whose own timers stay out of the way after the first step.
+fn slow_runtime() -> Runtime<CounterApp, CaptureBackend> {
+    Runtime::virtual_builder(80, 24)
+        .tick_rate(Duration::from_secs(60))
+        .frame_rate(Duration::from_secs(60))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_step_starts_with_tick_then_render() {
+    let mut vt = slow_runtime();
+    assert_eq!(vt.step().await.unwrap(), StepOutcome::Tick);
+    assert_eq!(vt.step().await.unwrap(), StepOutcome::Render);
+    assert!(vt.contains_text("Count: 0"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_step_processes_queued_events_on_tick() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    vt.send(Event::char('k'));
+    assert_eq!(vt.step().await.unwrap(), StepOutcome::Tick);
+    assert_eq!(vt.state().last_key, Some('k'));
+    assert_eq!(vt.state().ticks, 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_step_drives_subscription_deterministically() {
+    let mut vt = slow_runtime();
+    vt.step().await.unwrap();
+    vt.step().await.unwrap();
+
+    let start = tokio::time::Instant::now();
+    vt.subscribe(TickSubscription::new(Duration::from_millis(30), || {
+        CounterMsg::Increment
+    }));
+
+    for expected in 1..=3 {
+        assert_eq!(vt.step().await.unwrap(), StepOutcome::Message);
+        assert_eq!(vt.state().count, expected);
+    }
+    assert_eq!(start.elapsed(), Duration::from_millis(60));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_step_timers_keep_schedule_between_calls() {
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24)
+        .tick_rate(Duration::from_millis(100))
+        .frame_rate(Duration::from_secs(60))
+        .build()
+        .unwrap();
+    vt.step().await.unwrap();
+    vt.step().await.unwrap();
+
+    let start = tokio::time::Instant::now();
+    assert_eq!(vt.step().await.unwrap(), StepOutcome::Tick);
+    assert_eq!(start.elapsed(), Duration::from_millis(100));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_step_reports_sequence() {
+    let mut vt = slow_runtime();
+    vt.step().await.unwrap();
+    vt.step().await.unwrap();
+
+    vt.commands.execute(Command::sequence(vec![
+        Command::perform_async(async { Some(CounterMsg::IncrementBy(5)) }),
+        Command::perform_async(async { Some(CounterMsg::Decrement) }),
+    ]));
+    vt.spawn_pending_commands();
+
+    assert_eq!(vt.step().await.unwrap(), StepOutcome::Sequence);
+    assert_eq!(vt.state().count, 5);
+    assert_eq!(vt.step().await.unwrap(), StepOutcome::Sequence);
+    assert_eq!(vt.state().count, 4);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_step_reports_cancellation() {
+    let mut vt = slow_runtime();
+    vt.cancellation_token().cancel();
+    assert_eq!(vt.step().await.unwrap(), StepOutcome::Cancelled);
+    assert!(vt.should_quit());
+}
@@ -0,0 +1,85 @@
+//! Tests for the outcome reported by `tick_with_outcome`.
+
+use super::*;
+use crate::input::Event;
+
+#[test]
+fn test_tick_outcome_idle_app() {
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(20, 2).build().unwrap();
+    let outcome = vt.tick_with_outcome().unwrap();
+    assert!(outcome.is_idle());
+    assert!(outcome.rendered);
+    assert!(!outcome.quit_requested);
+}
+
+#[test]
+fn test_tick_outcome_counts_events_and_messages() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(20, 2).build().unwrap();
+    vt.send(Event::char('a'));
+    vt.send(Event::char('b'));
+    vt.send(Event::Resize(30, 3));
+
+    let outcome = vt.tick_with_outcome().unwrap();
+    assert_eq!(outcome.events_processed, 3);
+    // Two key presses and on_tick
+    assert_eq!(outcome.messages_dispatched, 3);
+    assert!(!outcome.is_idle());
+}
+
+#[test]
+fn test_tick_outcome_respects_max_messages() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(20, 2)
+        .config(RuntimeConfig::new().max_messages(2))
+        .build()
+        .unwrap();
+    for _ in 0..5 {
+        vt.send(Event::char('x'));
+    }
+
+    let outcome = vt.tick_with_outcome().unwrap();
+    assert_eq!(outcome.events_processed, 3);
+    assert_eq!(vt.state().events_received, 3);
+    assert_eq!(vt.tick_with_outcome().unwrap().events_processed, 2);
+}
+
+#[test]
+fn test_tick_outcome_excludes_earlier_dispatches() {
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(20, 2).build().unwrap();
+    vt.dispatch(CounterMsg::Increment);
+    // Dispatches before the tick are not counted
+    assert!(vt.tick_with_outcome().unwrap().is_idle());
+}
+
+#[test]
+fn test_tick_outcome_reports_quit() {
+    let mut vt: Runtime<EventApp, _> = Runtime::virtual_builder(20, 2).build().unwrap();
+    vt.send(Event::char('q'));
+    assert!(vt.tick_with_outcome().unwrap().quit_requested);
+}
+
+#[test]
+fn test_tick_outcome_skipped_render() {
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(20, 2)
+        .render_on_demand(true)
+        .build()
+        .unwrap();
+    vt.render().unwrap();
+    assert!(!vt.tick_with_outcome().unwrap().rendered);
+
+    vt.request_redraw();
+    assert!(vt.tick_with_outcome().unwrap().rendered);
+}
+
+#[test]
+fn test_tick_until_idle() {
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(20, 2).build().unwrap();
+    for _ in 0..3 {
+        vt.send(Event::char('x'));
+    }
+
+    let mut ticks = 0;
+    while !vt.tick_with_outcome().unwrap().is_idle() {
+        ticks += 1;
+    }
+    assert_eq!(ticks, 1);
+}
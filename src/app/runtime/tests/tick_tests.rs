@@ -0,0 +1,85 @@
+//! Tests for tick-driven overlays and the runtime clock.
+
+use super::*;
+
+#[cfg(feature = "display-components")]
+#[test]
+fn test_runtime_toast_overlay_expires_on_tick() {
+    use crate::component::ToastLevel;
+    use std::time::Duration;
+
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24)
+        .tick_rate(Duration::from_millis(100))
+        .build()
+        .unwrap();
+    vt.push_overlay(Box::new(crate::overlay::ToastOverlay::new().with_toast(
+        "Saved",
+        ToastLevel::Success,
+        Duration::from_millis(200),
+    )));
+
+    vt.tick().unwrap();
+    assert_eq!(vt.overlay_count(), 1);
+    assert!(vt.contains_text("Saved"));
+
+    vt.tick().unwrap();
+    assert!(!vt.has_overlays());
+    assert!(!vt.contains_text("Saved"));
+}
+
+#[cfg(feature = "display-components")]
+#[test]
+fn test_runtime_virtual_clock_drives_overlay_ticks() {
+    use crate::component::ToastLevel;
+    use std::time::Duration;
+
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24)
+        .build()
+        .unwrap()
+        .with_virtual_clock();
+    vt.push_overlay(Box::new(crate::overlay::ToastOverlay::new().with_toast(
+        "Saved",
+        ToastLevel::Success,
+        Duration::from_secs(1),
+    )));
+
+    // No virtual time passes, so the toast never expires
+    vt.run_ticks(100).unwrap();
+    assert_eq!(vt.overlay_count(), 1);
+
+    vt.advance_time(Duration::from_secs(1));
+    vt.tick().unwrap();
+    assert!(!vt.has_overlays());
+}
+
+#[test]
+fn test_runtime_virtual_clock_now_and_advance() {
+    use std::time::Duration;
+
+    let vt: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24).build().unwrap();
+    assert!(!vt.clock().is_virtual());
+
+    let mut vt = vt.with_virtual_clock();
+    assert!(vt.clock().is_virtual());
+    let start = vt.now();
+    vt.tick().unwrap();
+    assert_eq!(vt.now(), start);
+
+    vt.advance_time(Duration::from_millis(1500));
+    assert_eq!(vt.now() - start, Duration::from_millis(1500));
+}
+
+#[test]
+fn test_runtime_with_clock_shares_handle() {
+    use crate::app::Clock;
+    use std::time::Duration;
+
+    let clock = Clock::new_virtual();
+    let mut vt: Runtime<CounterApp, _> = Runtime::virtual_builder(80, 24)
+        .build()
+        .unwrap()
+        .with_clock(clock.clone());
+    let start = clock.now();
+    vt.advance_time(Duration::from_secs(3));
+    assert_eq!(clock.now() - start, Duration::from_secs(3));
+}
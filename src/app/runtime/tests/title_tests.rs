@@ -0,0 +1,53 @@
+//! Tests for window titles set with `Command::set_title`.
+
+use super::*;
+use crate::app::Command;
+
+struct TitleApp;
+
+impl App for TitleApp {
+    type State = ();
+    type Message = String;
+    type Args = ();
+
+    fn init(_args: ()) -> (Self::State, Command<Self::Message>) {
+        ((), Command::set_title("untitled"))
+    }
+
+    fn update(_state: &mut Self::State, file: Self::Message) -> Command<Self::Message> {
+        Command::set_title(format!("{file} - editor"))
+    }
+
+    fn view(_state: &Self::State, _frame: &mut ratatui::Frame) {}
+}
+
+#[test]
+fn test_init_title_reaches_capture_backend() {
+    let vt: Runtime<TitleApp, _> = Runtime::virtual_builder(20, 2).build().unwrap();
+    assert_eq!(vt.title(), Some("untitled"));
+    assert_eq!(vt.backend().title(), Some("untitled"));
+}
+
+#[test]
+fn test_update_title_replaces_previous() {
+    let mut vt: Runtime<TitleApp, _> = Runtime::virtual_builder(20, 2).build().unwrap();
+    vt.dispatch("notes.md".to_string());
+    assert_eq!(vt.title(), Some("notes.md - editor"));
+    assert_eq!(vt.backend().title(), Some("notes.md - editor"));
+}
+
+#[test]
+fn test_title_without_writer_is_only_recorded() {
+    let backend = CaptureBackend::new(20, 2);
+    let mut vt: Runtime<TitleApp, _> = Runtime::builder(backend).build().unwrap();
+    vt.dispatch("notes.md".to_string());
+    assert_eq!(vt.title(), Some("notes.md - editor"));
+    assert_eq!(vt.backend().title(), None);
+}
+
+#[test]
+fn test_app_without_title_has_none() {
+    let vt: Runtime<CounterApp, _> = Runtime::virtual_builder(20, 2).build().unwrap();
+    assert_eq!(vt.title(), None);
+    assert_eq!(vt.backend().title(), None);
+}
@@ -0,0 +1,58 @@
+//! What a single runtime tick did.
+
+/// A summary of the work done by one call to
+/// [`Runtime::tick_with_outcome`](super::Runtime::tick_with_outcome).
+///
+/// Use it to drive an app until it settles, or to skip work when a tick
+/// had nothing to do.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::app::TickOutcome;
+///
+/// let outcome = TickOutcome {
+///     messages_dispatched: 0,
+///     events_processed: 0,
+///     rendered: true,
+///     quit_requested: false,
+/// };
+/// assert!(outcome.is_idle());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TickOutcome {
+    /// Messages passed to `App::update` during the tick, including those
+    /// produced by events, commands, async tasks and `on_tick`.
+    pub messages_dispatched: usize,
+
+    /// Events taken from the event queue, whether or not they produced a
+    /// message.
+    pub events_processed: usize,
+
+    /// Whether a frame was drawn at the end of the tick.
+    pub rendered: bool,
+
+    /// Whether the runtime should quit after the tick.
+    pub quit_requested: bool,
+}
+
+impl TickOutcome {
+    /// Returns true if the tick dispatched no messages and processed no
+    /// events.
+    ///
+    /// Rendering does not count as work: a runtime draws every tick unless
+    /// it renders on demand. An app whose `on_tick` always returns a
+    /// message is never idle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::TickOutcome;
+    ///
+    /// let busy = TickOutcome { events_processed: 1, ..TickOutcome::default() };
+    /// assert!(!busy.is_idle());
+    /// ```
+    pub fn is_idle(&self) -> bool {
+        self.messages_dispatched == 0 && self.events_processed == 0
+    }
+}
@@ -4,10 +4,83 @@
 //! capture backend, useful for programmatic control (AI agents, automation,
 //! testing).
 
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
 use super::Runtime;
 use crate::app::model::App;
 use crate::backend::CaptureBackend;
+use crate::component::RenderContext;
+use crate::error;
 use crate::input::Event;
+use crate::overlay::{Overlay, OverlayAction};
+
+/// How a [`ModalOverlay`] ended: `Some(value)` if it resolved, `None` if
+/// it dismissed itself without a value.
+type Resolution<R> = Arc<Mutex<Option<Option<R>>>>;
+
+/// Runs an `Overlay<R>` on the app's overlay stack for
+/// [`run_overlay_until`](Runtime::run_overlay_until).
+///
+/// Values the overlay produces are stored in `resolution` instead of being
+/// dispatched, and resolve it, so the overlay is dismissed with the first
+/// one. Propagated events reach the app as usual.
+struct ModalOverlay<O, R> {
+    inner: O,
+    resolution: Resolution<R>,
+}
+
+impl<O, R> ModalOverlay<O, R> {
+    fn resolve<M>(&mut self, action: OverlayAction<R>) -> OverlayAction<M> {
+        let resolved = match action {
+            OverlayAction::Consumed => return OverlayAction::Consumed,
+            OverlayAction::Propagate => return OverlayAction::Propagate,
+            OverlayAction::Dismiss => None,
+            OverlayAction::KeepAndMessage(value) | OverlayAction::DismissWithMessage(value) => {
+                Some(value)
+            }
+        };
+        *self.resolution.lock().unwrap() = Some(resolved);
+        OverlayAction::Dismiss
+    }
+}
+
+impl<O, R, M> Overlay<M> for ModalOverlay<O, R>
+where
+    O: Overlay<R> + 'static,
+    R: Send + 'static,
+{
+    fn handle_event(&mut self, event: &Event) -> OverlayAction<M> {
+        let action = self.inner.handle_event(event);
+        self.resolve(action)
+    }
+
+    fn view(&self, ctx: &mut RenderContext<'_, '_>) {
+        self.inner.view(ctx);
+    }
+
+    fn on_tick(&mut self, elapsed: Duration) -> OverlayAction<M> {
+        let action = self.inner.on_tick(elapsed);
+        self.resolve(action)
+    }
+
+    fn is_opaque(&self) -> bool {
+        self.inner.is_opaque()
+    }
+
+    fn dims_backdrop(&self) -> bool {
+        self.inner.dims_backdrop()
+    }
+
+    fn blocks_input(&self) -> bool {
+        self.inner.blocks_input()
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        Some(self)
+    }
+}
 
 // =============================================================================
 // Virtual Terminal Mode - for programmatic control (agents, testing)
@@ -44,6 +117,181 @@ impl<A: App> Runtime<A, CaptureBackend> {
         self.core.events.push(event);
     }
 
+    /// Processes commands, async messages, and events until no new
+    /// messages are produced, then renders once.
+    ///
+    /// Each iteration processes pending commands, async messages that have
+    /// already arrived, and every queued event. Iteration stops when an
+    /// iteration dispatches no messages, when the app quits, or after
+    /// `max_iters` iterations. Unlike [`tick`](Runtime::tick), this never
+    /// calls [`App::on_tick`], so time-driven messages don't keep the
+    /// runtime busy. Async commands that are still running are not awaited.
+    ///
+    /// Returns the number of iterations that dispatched at least one
+    /// message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering to the terminal backend fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState { loaded: bool }
+    /// #[derive(Clone)]
+    /// enum MyMsg { Fetch, Loaded }
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) {
+    /// #         (MyState::default(), Command::message(MyMsg::Fetch))
+    /// #     }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> {
+    /// #         match msg {
+    /// #             MyMsg::Fetch => Command::message(MyMsg::Loaded),
+    /// #             MyMsg::Loaded => { state.loaded = true; Command::none() }
+    /// #         }
+    /// #     }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    ///
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(80, 24).build()?;
+    /// let iterations = vt.run_until_idle(10)?;
+    /// assert_eq!(iterations, 2); // Fetch, then Loaded
+    /// assert!(vt.state().loaded);
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn run_until_idle(&mut self, max_iters: usize) -> error::Result<usize> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("run_until_idle", max_iters).entered();
+
+        let mut iterations = 0;
+        while iterations < max_iters && !self.core.should_quit {
+            let before = self.dispatched;
+
+            self.process_commands();
+            self.process_async_messages();
+            self.process_all_events();
+
+            if A::should_quit(&self.core.state) {
+                self.core.should_quit = true;
+            }
+
+            if self.dispatched == before {
+                break;
+            }
+            iterations += 1;
+        }
+
+        self.render()?;
+        Ok(iterations)
+    }
+
+    /// Pushes a modal overlay and ticks the runtime against queued events
+    /// until it resolves with a value, and returns that value.
+    ///
+    /// The overlay is typed on its own result `R`: returning
+    /// [`OverlayAction::DismissWithMessage`] or
+    /// [`OverlayAction::KeepAndMessage`] resolves it with that value, and
+    /// the value is returned instead of being dispatched. The overlay sits
+    /// on top of the overlay stack, so it is rendered into the display and
+    /// frame history like any other. Each iteration is a normal
+    /// [`tick`](Runtime::tick): events the overlay propagates reach the app,
+    /// and commands, async messages and `on_tick` are processed. Events
+    /// queued after the resolving one are handled in the same tick, so they
+    /// reach the app.
+    ///
+    /// This gives scripted and agent flows a request/response style for
+    /// prompts. It only makes sense in virtual (headless) mode: events must
+    /// already be queued with [`send`](Runtime::send), since nothing else
+    /// can deliver them while this call blocks.
+    ///
+    /// Returns `None` if the overlay dismisses itself without a value, or if
+    /// the event queue runs out or the app quits before it resolves; the
+    /// overlay is then removed from the stack.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering to the terminal backend fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// use envision::overlay::{Overlay, OverlayAction};
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState;
+    /// # #[derive(Clone)]
+    /// # enum MyMsg {}
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState, Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> { Command::none() }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {}
+    /// # }
+    ///
+    /// struct Confirm;
+    ///
+    /// impl Overlay<bool> for Confirm {
+    ///     fn handle_event(&mut self, event: &Event) -> OverlayAction<bool> {
+    ///         match event.as_key().map(|key| key.code) {
+    ///             Some(Key::Char('y')) => OverlayAction::DismissWithMessage(true),
+    ///             Some(Key::Char('n')) => OverlayAction::DismissWithMessage(false),
+    ///             _ => OverlayAction::Consumed,
+    ///         }
+    ///     }
+    ///
+    ///     fn view(&self, _ctx: &mut RenderContext<'_, '_>) {}
+    /// }
+    ///
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(80, 24).build()?;
+    /// vt.send(Event::char('x'));
+    /// vt.send(Event::char('y'));
+    /// assert_eq!(vt.run_overlay_until(Confirm)?, Some(true));
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn run_overlay_until<O, R>(&mut self, overlay: O) -> error::Result<Option<R>>
+    where
+        O: Overlay<R> + 'static,
+        R: Send + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("run_overlay_until").entered();
+
+        let resolution: Resolution<R> = Arc::new(Mutex::new(None));
+        self.core.overlay_stack.push(Box::new(ModalOverlay {
+            inner: overlay,
+            resolution: Arc::clone(&resolution),
+        }));
+        self.redraw_requested = true;
+        self.render()?;
+
+        loop {
+            if let Some(result) = resolution.lock().unwrap().take() {
+                return Ok(result);
+            }
+            if self.core.events.is_empty() || self.core.should_quit {
+                break;
+            }
+            self.tick()?;
+        }
+
+        // Never resolved: take the overlay back off the stack
+        self.core
+            .overlay_stack
+            .remove_where(|any| any.is::<ModalOverlay<O, R>>());
+        self.redraw_requested = true;
+        self.render()?;
+        Ok(None)
+    }
+
     /// Returns the current display content as plain text.
     ///
     /// This is what would be shown on a terminal screen.
@@ -74,12 +322,230 @@ impl<A: App> Runtime<A, CaptureBackend> {
         self.core.terminal.backend().to_string()
     }
 
+    /// Returns each display row with trailing spaces trimmed.
+    ///
+    /// See [`CaptureBackend::lines`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState;
+    /// # #[derive(Clone)]
+    /// # enum MyMsg {}
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState, Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> { Command::none() }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {
+    /// #         frame.render_widget(ratatui::widgets::Paragraph::new("Hello"), frame.area());
+    /// #     }
+    /// # }
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(20, 2).build()?;
+    /// vt.tick()?;
+    /// assert_eq!(vt.lines(), vec!["Hello", ""]);
+    /// assert_eq!(vt.line(0).as_deref(), Some("Hello"));
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn lines(&self) -> Vec<String> {
+        self.core.terminal.backend().lines()
+    }
+
+    /// Returns display row `y` with trailing spaces trimmed, or `None` if
+    /// `y` is outside the screen.
+    ///
+    /// See [`CaptureBackend::line`].
+    pub fn line(&self, y: u16) -> Option<String> {
+        self.core.terminal.backend().line(y)
+    }
+
+    /// Renders the current state and returns the display as plain text.
+    ///
+    /// Unlike [`tick`](Runtime::tick), this does not process commands,
+    /// events, or async messages, and does not call [`App::on_tick`] or
+    /// advance overlays. Use it to snapshot the screen between specific
+    /// dispatches without perturbing the runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering to the terminal backend fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState { count: u32 }
+    /// #[derive(Clone)]
+    /// enum MyMsg { Increment }
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState::default(), Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> {
+    /// #         state.count += 1;
+    /// #         Command::none()
+    /// #     }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {
+    /// #         let text = format!("Count: {}", state.count);
+    /// #         frame.render_widget(ratatui::widgets::Paragraph::new(text), frame.area());
+    /// #     }
+    /// # }
+    ///
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(20, 1).build()?;
+    /// vt.dispatch(MyMsg::Increment);
+    /// assert!(vt.render_to_string()?.contains("Count: 1"));
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn render_to_string(&mut self) -> error::Result<String> {
+        self.render()?;
+        Ok(self.display())
+    }
+
+    /// Renders the current state into a fresh, blank buffer and returns it
+    /// as plain text.
+    ///
+    /// Unlike [`render_to_string`](Self::render_to_string), this doesn't
+    /// draw through the runtime's terminal: every call starts from an empty
+    /// screen of the same size, so cells from earlier frames can never leak
+    /// into the result. The runtime's own display is left unchanged. This
+    /// makes it suitable for calling in a tight loop, e.g. in fuzz-style
+    /// tests that render many unrelated states.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState { label: String }
+    /// # #[derive(Clone)]
+    /// # enum MyMsg { Set(String) }
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState::default(), Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> {
+    /// #         let MyMsg::Set(label) = msg;
+    /// #         state.label = label;
+    /// #         Command::none()
+    /// #     }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {
+    /// #         frame.render_widget(ratatui::widgets::Paragraph::new(state.label.as_str()), frame.area());
+    /// #     }
+    /// # }
+    /// let mut vt = Runtime::<MyApp, _>::virtual_builder(20, 1).build()?;
+    /// vt.dispatch(MyMsg::Set("a long label".into()));
+    /// assert!(vt.headless_render()?.starts_with("a long label"));
+    ///
+    /// vt.dispatch(MyMsg::Set("short".into()));
+    /// assert_eq!(vt.headless_render()?.trim_end(), "short");
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn headless_render(&self) -> error::Result<String> {
+        let backend = self.core.terminal.backend();
+        let terminal = self
+            .core
+            .render_detached(CaptureBackend::new(backend.width(), backend.height()))?;
+        Ok(terminal.backend().to_string())
+    }
+
     /// Returns the display content with ANSI color codes.
     pub fn display_ansi(&self) -> String {
         self.core.terminal.backend().to_ansi()
     }
 }
 
+impl<A: App> Runtime<A, CaptureBackend>
+where
+    A::Args: crate::app::OptionalArgs,
+{
+    /// Replays a recorded sequence of events in a fresh virtual terminal and
+    /// returns the final display.
+    ///
+    /// A new runtime is built with the same size, configuration and theme as
+    /// this one, with a virtual clock. Like
+    /// [`RuntimeBuilder::build`](super::RuntimeBuilder::build), this is only
+    /// available when `A::Args = ()`. The initial frame is rendered before
+    /// any events are replayed.
+    /// Each event is then sent and followed by one [`tick`](Runtime::tick),
+    /// so it passes through the same pipeline as a live session. This
+    /// runtime's state and display are left unchanged.
+    ///
+    /// Paired with an event log, this reproduces a captured frame with one
+    /// call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if building the runtime or rendering fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use envision::prelude::*;
+    /// # struct MyApp;
+    /// # #[derive(Default, Clone)]
+    /// # struct MyState { typed: String }
+    /// # #[derive(Clone)]
+    /// # enum MyMsg { Type(char) }
+    /// # impl App for MyApp {
+    /// #     type State = MyState;
+    /// #     type Message = MyMsg;
+    /// #     type Args = ();
+    /// #     fn init(_args: ()) -> (MyState, Command<MyMsg>) { (MyState::default(), Command::none()) }
+    /// #     fn update(state: &mut MyState, msg: MyMsg) -> Command<MyMsg> {
+    /// #         let MyMsg::Type(c) = msg;
+    /// #         state.typed.push(c);
+    /// #         Command::none()
+    /// #     }
+    /// #     fn view(state: &MyState, frame: &mut Frame) {
+    /// #         frame.render_widget(ratatui::widgets::Paragraph::new(state.typed.as_str()), frame.area());
+    /// #     }
+    /// #     fn handle_event(event: &Event) -> Option<MyMsg> {
+    /// #         match event.as_key()?.code {
+    /// #             Key::Char(c) => Some(MyMsg::Type(c)),
+    /// #             _ => None,
+    /// #         }
+    /// #     }
+    /// # }
+    /// let vt = Runtime::<MyApp, _>::virtual_builder(20, 1).build()?;
+    /// let events = [Event::char('h'), Event::char('i')];
+    /// assert_eq!(vt.replay_session(&events)?.trim_end(), "hi");
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    pub fn replay_session(&self, events: &[Event]) -> error::Result<String> {
+        let backend = self.core.terminal.backend();
+        let mut replay =
+            Runtime::<A, CaptureBackend>::virtual_builder(backend.width(), backend.height())
+                .config(self.config.clone())
+                .build()?
+                .with_virtual_clock();
+        replay.set_theme(self.core.theme.clone());
+        replay.render()?;
+
+        for event in events {
+            if replay.should_quit() {
+                break;
+            }
+            replay.send(event.clone());
+            replay.tick()?;
+        }
+
+        Ok(replay.display())
+    }
+}
+
 // =============================================================================
 // Additional convenience methods for CaptureBackend (virtual terminal)
 // =============================================================================
@@ -4,14 +4,30 @@
 //! and methods used by `Runtime`. It manages terminal, state, event queue,
 //! overlays, theme, and rendering.
 
-use ratatui::Terminal;
 use ratatui::backend::Backend;
+use ratatui::{Frame, Terminal};
 
 use super::model::App;
-use crate::input::EventQueue;
+use crate::input::{Event, EventQueue, KeyEvent, KeyMap};
 use crate::overlay::{Overlay, OverlayAction, OverlayStack};
 use crate::theme::Theme;
 
+/// Draws the app view, unless an opaque overlay hides it, followed by any
+/// active overlays.
+fn draw_frame<A: App>(
+    state: &A::State,
+    overlay_stack: &OverlayStack<A::Message>,
+    theme: &Theme,
+    frame: &mut Frame,
+) {
+    if !overlay_stack.hides_view() {
+        A::view(state, frame);
+    }
+    let area = frame.area();
+    let mut ctx = crate::component::RenderContext::new(frame, area, theme);
+    overlay_stack.render(&mut ctx);
+}
+
 /// Core runtime state shared between sync and async runtimes.
 ///
 /// Contains the fields and methods that are identical across both runtime
@@ -23,6 +39,7 @@ pub(crate) struct RuntimeCore<A: App, B: Backend> {
     pub(crate) events: EventQueue,
     pub(crate) overlay_stack: OverlayStack<A::Message>,
     pub(crate) theme: Theme,
+    pub(crate) keymap: KeyMap<A::Message>,
     pub(crate) should_quit: bool,
     pub(crate) max_messages_per_tick: usize,
 }
@@ -34,18 +51,38 @@ impl<A: App, B: Backend> RuntimeCore<A, B> {
     pub(crate) fn render(&mut self) -> crate::error::Result<()> {
         #[cfg(feature = "tracing")]
         let _span = tracing::debug_span!("render").entered();
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        let Self {
+            state,
+            terminal,
+            overlay_stack,
+            theme,
+            ..
+        } = self;
+        terminal.draw(|frame| draw_frame::<A>(state, overlay_stack, theme, frame))?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(elapsed = ?started.elapsed(), "frame rendered");
 
-        let theme = &self.theme;
-        let overlay_stack = &self.overlay_stack;
-        self.terminal.draw(|frame| {
-            A::view(&self.state, frame);
-            let area = frame.area();
-            let mut ctx = crate::component::RenderContext::new(frame, area, theme);
-            overlay_stack.render(&mut ctx);
-        })?;
         Ok(())
     }
 
+    /// Renders the current state into a fresh terminal with the given backend.
+    ///
+    /// The runtime's own terminal is left untouched, so the result never
+    /// depends on what was drawn before.
+    pub(crate) fn render_detached<B2: Backend>(
+        &self,
+        backend: B2,
+    ) -> crate::error::Result<Terminal<B2>> {
+        let mut terminal = Terminal::new(backend)?;
+        terminal
+            .draw(|frame| draw_frame::<A>(&self.state, &self.overlay_stack, &self.theme, frame))?;
+        Ok(terminal)
+    }
+
     /// Processes the next event from the queue.
     ///
     /// If the overlay stack is active, events are routed through it first.
@@ -62,30 +99,65 @@ impl<A: App, B: Backend> RuntimeCore<A, B> {
             #[cfg(feature = "tracing")]
             tracing::debug!(event = ?event, "processing event from queue");
 
-            match self.overlay_stack.handle_event(&event) {
-                OverlayAction::Consumed => ProcessEventResult::Consumed,
-                OverlayAction::KeepAndMessage(msg) => ProcessEventResult::Dispatch(msg),
-                OverlayAction::Dismiss => {
-                    self.overlay_stack.pop();
-                    ProcessEventResult::Consumed
-                }
-                OverlayAction::DismissWithMessage(msg) => {
-                    self.overlay_stack.pop();
-                    ProcessEventResult::Dispatch(msg)
-                }
-                OverlayAction::Propagate => {
-                    if let Some(msg) = A::handle_event_with_state(&self.state, &event) {
-                        ProcessEventResult::Dispatch(msg)
-                    } else {
-                        ProcessEventResult::Consumed
-                    }
-                }
-            }
+            self.route_event(&event)
         } else {
             ProcessEventResult::NoEvent
         }
     }
 
+    /// Routes one event through the overlay stack and then the app.
+    ///
+    /// Shared by the queued event path and the terminal event loop, so both
+    /// deliver events the same way. Never returns
+    /// [`ProcessEventResult::NoEvent`].
+    pub(crate) fn route_event(&mut self, event: &Event) -> ProcessEventResult<A::Message> {
+        // Key releases go straight to the app: overlays act on presses,
+        // and the release of a key that dismissed one must not reach
+        // the overlay beneath it. An overlay that blocks input drops them.
+        if event.as_key().is_some_and(KeyEvent::is_release) {
+            if self.overlay_stack.blocks_input() {
+                return ProcessEventResult::Consumed;
+            }
+            return match self.app_message(event) {
+                Some(msg) => ProcessEventResult::Dispatch(msg),
+                None => ProcessEventResult::Consumed,
+            };
+        }
+
+        match self.overlay_stack.handle_event(event) {
+            OverlayAction::Consumed => ProcessEventResult::Consumed,
+            OverlayAction::KeepAndMessage(msg) => ProcessEventResult::Dispatch(msg),
+            OverlayAction::Dismiss => {
+                self.overlay_stack.pop();
+                ProcessEventResult::Consumed
+            }
+            OverlayAction::DismissWithMessage(msg) => {
+                self.overlay_stack.pop();
+                ProcessEventResult::Dispatch(msg)
+            }
+            OverlayAction::Propagate => match self.app_message(event) {
+                Some(msg) => ProcessEventResult::Dispatch(msg),
+                None => ProcessEventResult::Consumed,
+            },
+        }
+    }
+
+    /// Maps an event that reached the app to a message: a keymap binding
+    /// if one matches, otherwise the app's `handle_event_with_state`.
+    pub(crate) fn app_message(&self, event: &Event) -> Option<A::Message> {
+        self.keymap
+            .message_for(event)
+            .or_else(|| A::handle_event_with_state(&self.state, event))
+    }
+
+    /// Advances time-based overlays by `elapsed`.
+    ///
+    /// Expired overlays are removed from the stack. Returns any messages the
+    /// overlays produced; the caller must dispatch them.
+    pub(crate) fn tick_overlays(&mut self, elapsed: std::time::Duration) -> Vec<A::Message> {
+        self.overlay_stack.tick(elapsed)
+    }
+
     /// Pushes an overlay onto the stack.
     pub(crate) fn push_overlay(&mut self, overlay: Box<dyn Overlay<A::Message>>) {
         self.overlay_stack.push(overlay);
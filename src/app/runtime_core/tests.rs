@@ -130,6 +130,7 @@ fn new_core() -> RuntimeCore<TestApp, CaptureBackend> {
         events: EventQueue::new(),
         overlay_stack: OverlayStack::new(),
         theme: Theme::default(),
+        keymap: KeyMap::new(),
         should_quit: false,
         max_messages_per_tick: 100,
     }
@@ -146,6 +147,32 @@ fn test_render_succeeds() {
     assert!(output.contains("Hello"));
 }
 
+#[test]
+fn test_render_skips_view_under_opaque_overlay() {
+    struct OpaqueOverlay;
+
+    impl Overlay<TestMsg> for OpaqueOverlay {
+        fn handle_event(&mut self, _event: &Event) -> OverlayAction<TestMsg> {
+            OverlayAction::Consumed
+        }
+
+        fn view(&self, _ctx: &mut crate::component::RenderContext<'_, '_>) {}
+
+        fn is_opaque(&self) -> bool {
+            true
+        }
+    }
+
+    let mut core = new_core();
+    core.overlay_stack.push(Box::new(ConsumingOverlay));
+    core.render().unwrap();
+    assert!(core.terminal.backend().to_string().contains("Hello"));
+
+    core.overlay_stack.push(Box::new(OpaqueOverlay));
+    core.render().unwrap();
+    assert!(!core.terminal.backend().to_string().contains("Hello"));
+}
+
 #[test]
 fn test_process_event_no_event() {
     let mut core = new_core();
@@ -181,6 +208,28 @@ fn test_process_event_consuming_overlay() {
     assert!(matches!(result, ProcessEventResult::Consumed));
 }
 
+#[test]
+fn test_process_event_key_release_skips_overlays() {
+    let mut core = new_core();
+    core.push_overlay(Box::new(ConsumingOverlay));
+    core.events.release(Key::Char('x'));
+
+    let result = core.process_event();
+    assert!(matches!(result, ProcessEventResult::Dispatch(TestMsg::Set(ref s)) if s == "x"));
+    assert_eq!(core.overlay_stack.len(), 1);
+}
+
+#[cfg(feature = "display-components")]
+#[test]
+fn test_process_event_key_release_dropped_while_input_blocked() {
+    let mut core = new_core();
+    core.push_overlay(Box::new(crate::overlay::SpinnerOverlay::new("Loading")));
+    core.events.release(Key::Char('x'));
+
+    let result = core.process_event();
+    assert!(matches!(result, ProcessEventResult::Consumed));
+}
+
 #[test]
 fn test_process_event_message_overlay() {
     let mut core = new_core();
@@ -185,6 +185,140 @@ where
     }
 }
 
+/// A subscription that emits messages while a predicate holds.
+///
+/// The subscription ends at the first message for which the predicate
+/// returns `false`; that message is not emitted.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::app::{SubscriptionExt, tick};
+/// use std::time::Duration;
+///
+/// let sub = tick(Duration::from_secs(1))
+///     .with_message(|| 42i32)
+///     .take_while(|n| *n < 100);
+/// ```
+pub struct TakeWhileSubscription<M, S, P>
+where
+    S: Subscription<M>,
+    P: Fn(&M) -> bool + Send + 'static,
+{
+    inner: Box<S>,
+    predicate: P,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M, S, P> TakeWhileSubscription<M, S, P>
+where
+    S: Subscription<M>,
+    P: Fn(&M) -> bool + Send + 'static,
+{
+    /// Creates a take-while subscription.
+    pub fn new(inner: S, predicate: P) -> Self {
+        Self {
+            inner: Box::new(inner),
+            predicate,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<M, S, P> Subscription<M> for TakeWhileSubscription<M, S, P>
+where
+    M: Send + 'static,
+    S: Subscription<M>,
+    P: Fn(&M) -> bool + Send + 'static,
+{
+    fn into_stream(
+        self: Box<Self>,
+        cancel: CancellationToken,
+    ) -> Pin<Box<dyn Stream<Item = M> + Send>> {
+        use tokio_stream::StreamExt;
+
+        let predicate = self.predicate;
+        let mut inner = self.inner.into_stream(cancel);
+
+        Box::pin(async_stream::stream! {
+            while let Some(msg) = inner.next().await {
+                if !(predicate)(&msg) {
+                    break;
+                }
+                yield msg;
+            }
+        })
+    }
+}
+
+/// A subscription that drops leading messages while a predicate holds.
+///
+/// Once the predicate returns `false`, that message and every message after
+/// it are emitted without further checks.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::app::{SubscriptionExt, tick};
+/// use std::time::Duration;
+///
+/// let sub = tick(Duration::from_secs(1))
+///     .with_message(|| 42i32)
+///     .skip_while(|n| *n < 10);
+/// ```
+pub struct SkipWhileSubscription<M, S, P>
+where
+    S: Subscription<M>,
+    P: Fn(&M) -> bool + Send + 'static,
+{
+    inner: Box<S>,
+    predicate: P,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M, S, P> SkipWhileSubscription<M, S, P>
+where
+    S: Subscription<M>,
+    P: Fn(&M) -> bool + Send + 'static,
+{
+    /// Creates a skip-while subscription.
+    pub fn new(inner: S, predicate: P) -> Self {
+        Self {
+            inner: Box::new(inner),
+            predicate,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<M, S, P> Subscription<M> for SkipWhileSubscription<M, S, P>
+where
+    M: Send + 'static,
+    S: Subscription<M>,
+    P: Fn(&M) -> bool + Send + 'static,
+{
+    fn into_stream(
+        self: Box<Self>,
+        cancel: CancellationToken,
+    ) -> Pin<Box<dyn Stream<Item = M> + Send>> {
+        use tokio_stream::StreamExt;
+
+        let predicate = self.predicate;
+        let mut inner = self.inner.into_stream(cancel);
+
+        Box::pin(async_stream::stream! {
+            let mut skipping = true;
+            while let Some(msg) = inner.next().await {
+                if skipping && (predicate)(&msg) {
+                    continue;
+                }
+                skipping = false;
+                yield msg;
+            }
+        })
+    }
+}
+
 /// A subscription that debounces messages from an inner subscription.
 ///
 /// Debouncing delays message emission until a quiet period has passed.
@@ -364,3 +498,215 @@ where
         })
     }
 }
+
+/// A subscription that emits a message when an inner subscription goes quiet.
+///
+/// Messages from the inner subscription pass through unchanged. If no
+/// message arrives for `duration`, the `on_timeout` message is emitted once;
+/// the timer restarts with the next inner message. The timer also runs from
+/// the start, so a subscription that never produces anything times out
+/// after `duration`.
+///
+/// Unlike [`DebounceSubscription`], which delays the latest message, this
+/// injects a new message on inactivity — for example to show "connection
+/// lost" when a stream stops delivering.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::app::{SubscriptionExt, tick};
+/// use std::time::Duration;
+///
+/// #[derive(Clone)]
+/// enum Msg {
+///     Heartbeat,
+///     ConnectionLost,
+/// }
+///
+/// let sub = tick(Duration::from_secs(1))
+///     .with_message(|| Msg::Heartbeat)
+///     .timeout_with(Duration::from_secs(5), || Msg::ConnectionLost);
+/// ```
+pub struct TimeoutSubscription<M, S, F>
+where
+    S: Subscription<M>,
+    F: Fn() -> M + Send + 'static,
+{
+    inner: Box<S>,
+    pub(crate) duration: Duration,
+    on_timeout: F,
+    _phantom: std::marker::PhantomData<M>,
+}
+
+impl<M, S, F> TimeoutSubscription<M, S, F>
+where
+    S: Subscription<M>,
+    F: Fn() -> M + Send + 'static,
+{
+    /// Creates a timeout subscription.
+    pub fn new(inner: S, duration: Duration, on_timeout: F) -> Self {
+        Self {
+            inner: Box::new(inner),
+            duration,
+            on_timeout,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<M, S, F> Subscription<M> for TimeoutSubscription<M, S, F>
+where
+    M: Send + 'static,
+    S: Subscription<M>,
+    F: Fn() -> M + Send + 'static,
+{
+    fn into_stream(
+        self: Box<Self>,
+        cancel: CancellationToken,
+    ) -> Pin<Box<dyn Stream<Item = M> + Send>> {
+        use tokio_stream::StreamExt;
+
+        let duration = self.duration;
+        let on_timeout = self.on_timeout;
+        let mut inner = self.inner.into_stream(cancel.clone());
+
+        Box::pin(async_stream::stream! {
+            let mut deadline = tokio::time::Instant::now() + duration;
+            let mut timed_out = false;
+
+            loop {
+                tokio::select! {
+                    biased;
+
+                    _ = cancel.cancelled() => {
+                        break;
+                    }
+
+                    // Activity counts even if it lands on the deadline
+                    msg = inner.next() => {
+                        match msg {
+                            Some(m) => {
+                                deadline = tokio::time::Instant::now() + duration;
+                                timed_out = false;
+                                yield m;
+                            }
+                            None => break,
+                        }
+                    }
+
+                    _ = tokio::time::sleep_until(deadline), if !timed_out => {
+                        timed_out = true;
+                        yield on_timeout();
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// A subscription that combines the latest messages of two subscriptions.
+///
+/// Emits a `(A, B)` tuple whenever either side produces a message, carrying
+/// the most recent message from each. The first tuple is emitted once both
+/// sides have produced at least one message. The combined subscription ends
+/// when both sides have ended, and cancellation stops both.
+///
+/// Use [`map`](super::SubscriptionExt::map) to turn the tuple into an app
+/// message.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::app::{SubscriptionExt, tick};
+/// use std::time::Duration;
+///
+/// let cpu = tick(Duration::from_secs(1)).with_message(|| 42u8);
+/// let mem = tick(Duration::from_secs(5)).with_message(|| 1024u64);
+///
+/// let sub = cpu
+///     .combine_latest(mem)
+///     .map(|(cpu, mem)| format!("cpu {}% mem {}MB", cpu, mem));
+/// ```
+pub struct CombineLatestSubscription<A, B, SA, SB>
+where
+    SA: Subscription<A>,
+    SB: Subscription<B>,
+{
+    first: Box<SA>,
+    second: Box<SB>,
+    _phantom: std::marker::PhantomData<(A, B)>,
+}
+
+impl<A, B, SA, SB> CombineLatestSubscription<A, B, SA, SB>
+where
+    SA: Subscription<A>,
+    SB: Subscription<B>,
+{
+    /// Creates a subscription combining the latest messages of `first` and `second`.
+    pub fn new(first: SA, second: SB) -> Self {
+        Self {
+            first: Box::new(first),
+            second: Box::new(second),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<A, B, SA, SB> Subscription<(A, B)> for CombineLatestSubscription<A, B, SA, SB>
+where
+    A: Clone + Send + 'static,
+    B: Clone + Send + 'static,
+    SA: Subscription<A>,
+    SB: Subscription<B>,
+{
+    fn into_stream(
+        self: Box<Self>,
+        cancel: CancellationToken,
+    ) -> Pin<Box<dyn Stream<Item = (A, B)> + Send>> {
+        use tokio_stream::StreamExt;
+
+        let mut first = self.first.into_stream(cancel.clone());
+        let mut second = self.second.into_stream(cancel.clone());
+
+        Box::pin(async_stream::stream! {
+            let mut latest_first: Option<A> = None;
+            let mut latest_second: Option<B> = None;
+            let mut first_done = false;
+            let mut second_done = false;
+
+            while !(first_done && second_done) {
+                tokio::select! {
+                    biased;
+
+                    _ = cancel.cancelled() => {
+                        break;
+                    }
+
+                    msg = first.next(), if !first_done => {
+                        match msg {
+                            Some(a) => latest_first = Some(a),
+                            None => {
+                                first_done = true;
+                                continue;
+                            }
+                        }
+                    }
+
+                    msg = second.next(), if !second_done => {
+                        match msg {
+                            Some(b) => latest_second = Some(b),
+                            None => {
+                                second_done = true;
+                                continue;
+                            }
+                        }
+                    }
+                }
+
+                if let (Some(a), Some(b)) = (&latest_first, &latest_second) {
+                    yield (a.clone(), b.clone());
+                }
+            }
+        })
+    }
+}
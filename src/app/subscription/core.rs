@@ -2,6 +2,7 @@ use std::pin::Pin;
 use std::time::Duration;
 
 use tokio::sync::mpsc;
+use tokio::time::MissedTickBehavior;
 use tokio_stream::Stream;
 use tokio_util::sync::CancellationToken;
 
@@ -25,7 +26,10 @@ pub type BoxedSubscription<M> = Box<dyn Subscription<M>>;
 
 /// A subscription that fires at regular intervals.
 ///
-/// Each tick produces a message using the provided function.
+/// Each tick produces a message using the provided function. Ticks missed
+/// while the app was busy or the machine was asleep are skipped by default,
+/// so a long pause produces one tick rather than a burst of catch-up ticks;
+/// see [`with_missed_behavior`](Self::with_missed_behavior).
 ///
 /// # Example
 ///
@@ -40,6 +44,7 @@ where
     F: Fn() -> M + Send + 'static,
 {
     pub(crate) interval: Duration,
+    pub(crate) missed_behavior: MissedTickBehavior,
     message_fn: F,
 }
 
@@ -51,9 +56,21 @@ where
     pub fn new(interval: Duration, message_fn: F) -> Self {
         Self {
             interval,
+            missed_behavior: MissedTickBehavior::Skip,
             message_fn,
         }
     }
+
+    /// Sets how ticks missed after a lag are handled.
+    ///
+    /// Defaults to [`MissedTickBehavior::Skip`]. Use
+    /// [`MissedTickBehavior::Burst`] to deliver every missed tick, or
+    /// [`MissedTickBehavior::Delay`] to restart the schedule from the late
+    /// tick.
+    pub fn with_missed_behavior(mut self, behavior: MissedTickBehavior) -> Self {
+        self.missed_behavior = behavior;
+        self
+    }
 }
 
 impl<M: Send + 'static, F: Fn() -> M + Send + 'static> Subscription<M> for TickSubscription<M, F> {
@@ -62,10 +79,12 @@ impl<M: Send + 'static, F: Fn() -> M + Send + 'static> Subscription<M> for TickS
         cancel: CancellationToken,
     ) -> Pin<Box<dyn Stream<Item = M> + Send>> {
         let interval_duration = self.interval;
+        let missed_behavior = self.missed_behavior;
         let message_fn = self.message_fn;
 
         Box::pin(async_stream::stream! {
             let mut interval = tokio::time::interval(interval_duration);
+            interval.set_missed_tick_behavior(missed_behavior);
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
@@ -83,12 +102,36 @@ impl<M: Send + 'static, F: Fn() -> M + Send + 'static> Subscription<M> for TickS
 /// Builder for tick subscriptions with a fluent API.
 pub struct TickSubscriptionBuilder {
     interval: Duration,
+    missed_behavior: MissedTickBehavior,
 }
 
 impl TickSubscriptionBuilder {
     /// Creates a tick subscription builder with the given interval.
     pub fn every(interval: Duration) -> Self {
-        Self { interval }
+        Self {
+            interval,
+            missed_behavior: MissedTickBehavior::Skip,
+        }
+    }
+
+    /// Sets how ticks missed after a lag are handled.
+    ///
+    /// Defaults to [`MissedTickBehavior::Skip`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::tick;
+    /// use std::time::Duration;
+    /// use tokio::time::MissedTickBehavior;
+    ///
+    /// let sub = tick(Duration::from_secs(1))
+    ///     .missed_behavior(MissedTickBehavior::Delay)
+    ///     .with_message(|| "tick");
+    /// ```
+    pub fn missed_behavior(mut self, behavior: MissedTickBehavior) -> Self {
+        self.missed_behavior = behavior;
+        self
     }
 
     /// Sets the message to produce on each tick.
@@ -96,7 +139,7 @@ impl TickSubscriptionBuilder {
     where
         F: Fn() -> M + Send + 'static,
     {
-        TickSubscription::new(self.interval, message_fn)
+        TickSubscription::new(self.interval, message_fn).with_missed_behavior(self.missed_behavior)
     }
 }
 
@@ -306,3 +349,67 @@ where
         })
     }
 }
+
+/// A subscription that unfolds a state value into a sequence of messages.
+///
+/// The closure receives the current state and returns a future resolving to
+/// `Some((message, next_state))` to emit a message and continue, or `None`
+/// to end the subscription. This expresses stateful producers such as
+/// paginated fetches or countdowns without a hand-written `Stream` impl.
+///
+/// The subscription also ends when it is cancelled, including while a call
+/// to the closure is still pending.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::app::UnfoldSubscription;
+///
+/// // Count down from 3 to 1, then stop.
+/// let countdown = UnfoldSubscription::new(3u32, |n: u32| async move {
+///     if n == 0 { None } else { Some((n, n - 1)) }
+/// });
+/// ```
+pub struct UnfoldSubscription<T, F> {
+    seed: T,
+    unfold_fn: F,
+}
+
+impl<T, F> UnfoldSubscription<T, F> {
+    /// Creates a subscription that unfolds `seed` with `unfold_fn`.
+    pub fn new(seed: T, unfold_fn: F) -> Self {
+        Self { seed, unfold_fn }
+    }
+}
+
+impl<M, T, F, Fut> Subscription<M> for UnfoldSubscription<T, F>
+where
+    M: Send + 'static,
+    T: Send + 'static,
+    F: FnMut(T) -> Fut + Send + 'static,
+    Fut: Future<Output = Option<(M, T)>> + Send + 'static,
+{
+    fn into_stream(
+        self: Box<Self>,
+        cancel: CancellationToken,
+    ) -> Pin<Box<dyn Stream<Item = M> + Send>> {
+        let mut state = self.seed;
+        let mut unfold_fn = self.unfold_fn;
+
+        Box::pin(async_stream::stream! {
+            loop {
+                let next = tokio::select! {
+                    next = (unfold_fn)(state) => next,
+                    _ = cancel.cancelled() => break,
+                };
+                match next {
+                    Some((message, next_state)) => {
+                        state = next_state;
+                        yield message;
+                    }
+                    None => break,
+                }
+            }
+        })
+    }
+}
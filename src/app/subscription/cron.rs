@@ -0,0 +1,355 @@
+use std::fmt;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime, Timelike, Utc};
+use tokio_stream::Stream;
+use tokio_util::sync::CancellationToken;
+
+use super::Subscription;
+use crate::error::EnvisionError;
+
+const MONTH_NAMES: &[&str] = &[
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: &[&str] = &["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// How many years ahead [`CronSchedule::next_after`] searches before giving up.
+const SEARCH_YEARS: i32 = 5;
+
+/// A parsed five-field cron schedule.
+///
+/// The expression has the usual `minute hour day-of-month month
+/// day-of-week` fields. Each field accepts `*`, single values, ranges
+/// (`1-5`), steps (`*/15`, `0-30/10`) and comma-separated lists of these.
+/// Months and weekdays also accept three-letter English names (`jan`,
+/// `mon`), and both `0` and `7` mean Sunday. The shorthands `@yearly`,
+/// `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight` and
+/// `@hourly` are accepted as well.
+///
+/// As in classic cron, when both the day-of-month and day-of-week fields
+/// are restricted a day matches if either of them does.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use envision::app::CronSchedule;
+///
+/// let schedule = CronSchedule::parse("0 9 * * mon-fri").unwrap();
+/// // Saturday 2024-06-01 at noon: the next fire is Monday at 09:00.
+/// let now = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// let next = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap().and_hms_opt(9, 0, 0).unwrap();
+/// assert_eq!(schedule.next_after(now), Some(next));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    any_day: bool,
+    any_weekday: bool,
+}
+
+impl CronSchedule {
+    /// Parses a cron expression.
+    ///
+    /// Returns [`EnvisionError::Config`] describing the offending field if
+    /// the expression is malformed.
+    pub fn parse(expression: &str) -> crate::Result<Self> {
+        let expanded = match expression.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other => other,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(EnvisionError::config(
+                "cron schedule",
+                format!("expected 5 fields, found {}", fields.len()),
+            ));
+        };
+
+        let mut weekdays = parse_field(weekday, "day of week", 0, 7, WEEKDAY_NAMES)?;
+        // 7 is an alias for Sunday.
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays & !(1 << 7)) | 1;
+        }
+        Ok(Self {
+            expression: expression.trim().to_string(),
+            minutes: parse_field(minute, "minute", 0, 59, &[])?,
+            hours: parse_field(hour, "hour", 0, 23, &[])?,
+            days: parse_field(day, "day of month", 1, 31, &[])?,
+            months: parse_field(month, "month", 1, 12, MONTH_NAMES)?,
+            weekdays,
+            any_day: day.starts_with('*'),
+            any_weekday: weekday.starts_with('*'),
+        })
+    }
+
+    /// Returns the expression this schedule was parsed from.
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Returns true if the schedule fires during the minute containing `time`.
+    pub fn matches(&self, time: NaiveDateTime) -> bool {
+        self.matches_date(time.date())
+            && bit(self.hours, time.hour())
+            && bit(self.minutes, time.minute())
+    }
+
+    /// Returns the first fire time strictly after `time`, at minute resolution.
+    ///
+    /// Returns `None` if the schedule can never fire, such as `0 0 31 2 *`.
+    pub fn next_after(&self, time: NaiveDateTime) -> Option<NaiveDateTime> {
+        let start = time.with_second(0)?.with_nanosecond(0)? + chrono::Duration::minutes(1);
+        let last_year = start.year() + SEARCH_YEARS;
+
+        let mut date = start.date();
+        let mut from_hour = start.hour();
+        let mut from_minute = start.minute();
+        while date.year() <= last_year {
+            if !bit(self.months, date.month()) {
+                date = first_of_next_month(date)?;
+            } else if self.matches_date(date) {
+                if let Some(found) = self.time_on(date, from_hour, from_minute) {
+                    return Some(found);
+                }
+                date = date.succ_opt()?;
+            } else {
+                date = date.succ_opt()?;
+            }
+            from_hour = 0;
+            from_minute = 0;
+        }
+        None
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !bit(self.months, date.month()) {
+            return false;
+        }
+        let day = bit(self.days, date.day());
+        let weekday = bit(self.weekdays, date.weekday().num_days_from_sunday());
+        match (self.any_day, self.any_weekday) {
+            (true, true) => true,
+            (true, false) => weekday,
+            (false, true) => day,
+            (false, false) => day || weekday,
+        }
+    }
+
+    /// Returns the first matching time on `date` at or after `hour:minute`.
+    fn time_on(&self, date: NaiveDate, hour: u32, minute: u32) -> Option<NaiveDateTime> {
+        (hour..24).filter(|&h| bit(self.hours, h)).find_map(|h| {
+            let from = if h == hour { minute } else { 0 };
+            (from..60)
+                .find(|&m| bit(self.minutes, m))
+                .and_then(|m| date.and_hms_opt(h, m, 0))
+        })
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = EnvisionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+fn bit(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+fn first_of_next_month(date: NaiveDate) -> Option<NaiveDate> {
+    if date.month() == 12 {
+        NaiveDate::from_ymd_opt(date.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(date.year(), date.month() + 1, 1)
+    }
+}
+
+/// Parses one cron field into a bitmask of the values it allows.
+fn parse_field(field: &str, name: &str, min: u32, max: u32, names: &[&str]) -> crate::Result<u64> {
+    let invalid =
+        |reason: String| EnvisionError::config("cron schedule", format!("{name}: {reason}"));
+    let value = |text: &str| -> crate::Result<u32> {
+        let lower = text.to_ascii_lowercase();
+        let parsed = match names.iter().position(|n| *n == lower) {
+            Some(index) => min + index as u32,
+            None => text
+                .parse()
+                .map_err(|_| invalid(format!("invalid value `{text}`")))?,
+        };
+        if parsed < min || parsed > max {
+            return Err(invalid(format!("{parsed} is outside {min}-{max}")));
+        }
+        Ok(parsed)
+    };
+
+    let mut mask = 0;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| invalid(format!("invalid step `{step}`")))?;
+                if step == 0 {
+                    return Err(invalid("step must be positive".to_string()));
+                }
+                (range, Some(step))
+            }
+            None => (item, None),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (value(start)?, value(end)?)
+        } else {
+            let start = value(range)?;
+            // `5/10` means every tenth value starting at 5.
+            (start, if step.is_some() { max } else { start })
+        };
+        if start > end {
+            return Err(invalid(format!("range `{range}` is reversed")));
+        }
+        for v in (start..=end).step_by(step.unwrap_or(1) as usize) {
+            mask |= 1 << v;
+        }
+    }
+    Ok(mask)
+}
+
+/// A subscription that fires on a cron schedule.
+///
+/// Each time the wall clock reaches the next time matched by the
+/// [`CronSchedule`], the subscription produces a message, then waits for
+/// the following match. The schedule is evaluated in local time unless
+/// [`utc`](Self::utc) is called.
+///
+/// Rather than sleeping until the next fire time in one go, the
+/// subscription re-reads the wall clock at least once per
+/// [`recheck interval`](Self::with_recheck_interval) (one second by
+/// default), so it follows system clock changes and daylight saving
+/// transitions. If the clock jumps forward past one or more fire times, a
+/// single message is produced for them; if it jumps backward, times that
+/// already fired are not repeated. The stream ends when cancelled, or if
+/// the schedule can never fire.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::app::{CronSchedule, CronSubscription};
+///
+/// let schedule = CronSchedule::parse("0 * * * *").unwrap();
+/// let sub = CronSubscription::new(schedule, || "hourly");
+/// ```
+pub struct CronSubscription<M, F>
+where
+    F: Fn() -> M + Send + 'static,
+{
+    schedule: CronSchedule,
+    message_fn: F,
+    wall_clock: Arc<dyn Fn() -> NaiveDateTime + Send + Sync>,
+    recheck_interval: Duration,
+}
+
+impl<M, F> CronSubscription<M, F>
+where
+    F: Fn() -> M + Send + 'static,
+{
+    /// Creates a subscription that fires on `schedule` in local time.
+    pub fn new(schedule: CronSchedule, message_fn: F) -> Self {
+        Self {
+            schedule,
+            message_fn,
+            wall_clock: Arc::new(|| Local::now().naive_local()),
+            recheck_interval: Duration::from_secs(1),
+        }
+    }
+
+    /// Evaluates the schedule in UTC instead of local time.
+    pub fn utc(self) -> Self {
+        self.with_wall_clock(|| Utc::now().naive_utc())
+    }
+
+    /// Reads the wall clock from `now` instead of the system clock.
+    ///
+    /// This is mainly useful for driving the schedule from a fake clock in
+    /// tests.
+    pub fn with_wall_clock(
+        mut self,
+        now: impl Fn() -> NaiveDateTime + Send + Sync + 'static,
+    ) -> Self {
+        self.wall_clock = Arc::new(now);
+        self
+    }
+
+    /// Sets the longest time the subscription sleeps before re-reading the
+    /// wall clock.
+    ///
+    /// Defaults to one second. Longer intervals wake less often but can
+    /// fire up to that much late after a clock change.
+    pub fn with_recheck_interval(mut self, interval: Duration) -> Self {
+        self.recheck_interval = interval.max(Duration::from_millis(1));
+        self
+    }
+
+    /// Returns the schedule.
+    pub fn schedule(&self) -> &CronSchedule {
+        &self.schedule
+    }
+}
+
+impl<M: Send + 'static, F: Fn() -> M + Send + 'static> Subscription<M> for CronSubscription<M, F> {
+    fn into_stream(
+        self: Box<Self>,
+        cancel: CancellationToken,
+    ) -> Pin<Box<dyn Stream<Item = M> + Send>> {
+        let CronSubscription {
+            schedule,
+            message_fn,
+            wall_clock,
+            recheck_interval,
+        } = *self;
+
+        Box::pin(async_stream::stream! {
+            let Some(mut next) = schedule.next_after(wall_clock()) else {
+                return;
+            };
+            loop {
+                let now = wall_clock();
+                if now >= next {
+                    yield (message_fn)();
+                    match schedule.next_after(now) {
+                        Some(after) => next = after,
+                        None => break,
+                    }
+                    continue;
+                }
+
+                let remaining = (next - now).to_std().unwrap_or_default();
+                tokio::select! {
+                    _ = tokio::time::sleep(remaining.min(recheck_interval)) => {}
+                    _ = cancel.cancelled() => break,
+                }
+            }
+        })
+    }
+}
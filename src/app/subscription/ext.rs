@@ -2,8 +2,9 @@ use std::time::Duration;
 
 use super::Subscription;
 use super::combinators::{
-    DebounceSubscription, FilterSubscription, MappedSubscription, TakeSubscription,
-    ThrottleSubscription,
+    CombineLatestSubscription, DebounceSubscription, FilterSubscription, MappedSubscription,
+    SkipWhileSubscription, TakeSubscription, TakeWhileSubscription, ThrottleSubscription,
+    TimeoutSubscription,
 };
 
 /// Extension trait for subscriptions.
@@ -82,6 +83,54 @@ pub trait SubscriptionExt<M>: Subscription<M> + Sized {
         TakeSubscription::new(self, count)
     }
 
+    /// Emits messages from this subscription while the predicate holds.
+    ///
+    /// The subscription ends at the first message for which the predicate
+    /// returns `false`, without emitting it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::{SubscriptionExt, tick};
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Clone, PartialEq)]
+    /// enum Job { Running, Done }
+    ///
+    /// // Stream ticks until the job reports Done.
+    /// let sub = tick(Duration::from_secs(1))
+    ///     .with_message(|| Job::Running)
+    ///     .take_while(|job| *job != Job::Done);
+    /// ```
+    fn take_while<P>(self, predicate: P) -> TakeWhileSubscription<M, Self, P>
+    where
+        P: Fn(&M) -> bool + Send + 'static,
+    {
+        TakeWhileSubscription::new(self, predicate)
+    }
+
+    /// Drops leading messages from this subscription while the predicate holds.
+    ///
+    /// Once the predicate returns `false`, that message and all later ones
+    /// are emitted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::{SubscriptionExt, tick};
+    /// use std::time::Duration;
+    ///
+    /// let sub = tick(Duration::from_secs(1))
+    ///     .with_message(|| 42i32)
+    ///     .skip_while(|n| *n < 10);
+    /// ```
+    fn skip_while<P>(self, predicate: P) -> SkipWhileSubscription<M, Self, P>
+    where
+        P: Fn(&M) -> bool + Send + 'static,
+    {
+        SkipWhileSubscription::new(self, predicate)
+    }
+
     /// Debounces messages from this subscription.
     ///
     /// Only emits a message after a quiet period has passed. If a new message
@@ -123,6 +172,55 @@ pub trait SubscriptionExt<M>: Subscription<M> + Sized {
     fn throttle(self, duration: Duration) -> ThrottleSubscription<M, Self> {
         ThrottleSubscription::new(self, duration)
     }
+
+    /// Emits `on_timeout()` when this subscription produces nothing for
+    /// `duration`.
+    ///
+    /// Fires once per quiet period; the timer restarts with the next
+    /// message. Unlike [`debounce`](Self::debounce), the original messages
+    /// pass through undelayed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::{SubscriptionExt, tick};
+    /// use std::time::Duration;
+    ///
+    /// // Report "stale" if no tick arrives for 2 seconds
+    /// let sub = tick(Duration::from_secs(1))
+    ///     .with_message(|| "tick")
+    ///     .timeout_with(Duration::from_secs(2), || "stale");
+    /// ```
+    fn timeout_with<F>(self, duration: Duration, on_timeout: F) -> TimeoutSubscription<M, Self, F>
+    where
+        F: Fn() -> M + Send + 'static,
+    {
+        TimeoutSubscription::new(self, duration, on_timeout)
+    }
+
+    /// Combines this subscription with another, emitting the latest message
+    /// from each as a tuple.
+    ///
+    /// A tuple is emitted whenever either subscription produces a message,
+    /// once both have produced at least one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::app::{SubscriptionExt, tick};
+    /// use std::time::Duration;
+    ///
+    /// let sub = tick(Duration::from_secs(1))
+    ///     .with_message(|| 1u32)
+    ///     .combine_latest(tick(Duration::from_secs(2)).with_message(|| "status"))
+    ///     .map(|(count, status)| format!("{}: {}", status, count));
+    /// ```
+    fn combine_latest<N, T>(self, other: T) -> CombineLatestSubscription<M, N, Self, T>
+    where
+        T: Subscription<N>,
+    {
+        CombineLatestSubscription::new(self, other)
+    }
 }
 
 impl<M, S: Subscription<M>> SubscriptionExt<M> for S {}
@@ -1,6 +1,7 @@
 use std::pin::Pin;
 use std::time::Duration;
 
+use tokio::time::MissedTickBehavior;
 use tokio_stream::Stream;
 use tokio_util::sync::CancellationToken;
 
@@ -9,7 +10,8 @@ use super::Subscription;
 /// A subscription that fires immediately, then at regular intervals.
 ///
 /// Unlike [`TickSubscription`](super::TickSubscription), this fires the first message immediately
-/// without waiting for the interval.
+/// without waiting for the interval. Missed ticks are skipped by default, as
+/// for `TickSubscription`.
 ///
 /// # Example
 ///
@@ -24,6 +26,7 @@ where
     F: Fn() -> M + Send + 'static,
 {
     pub(crate) interval: Duration,
+    pub(crate) missed_behavior: MissedTickBehavior,
     message_fn: F,
 }
 
@@ -35,9 +38,18 @@ where
     pub fn new(interval: Duration, message_fn: F) -> Self {
         Self {
             interval,
+            missed_behavior: MissedTickBehavior::Skip,
             message_fn,
         }
     }
+
+    /// Sets how ticks missed after a lag are handled.
+    ///
+    /// Defaults to [`MissedTickBehavior::Skip`].
+    pub fn with_missed_behavior(mut self, behavior: MissedTickBehavior) -> Self {
+        self.missed_behavior = behavior;
+        self
+    }
 }
 
 impl<M: Send + 'static, F: Fn() -> M + Send + 'static> Subscription<M>
@@ -48,6 +60,7 @@ impl<M: Send + 'static, F: Fn() -> M + Send + 'static> Subscription<M>
         cancel: CancellationToken,
     ) -> Pin<Box<dyn Stream<Item = M> + Send>> {
         let interval_duration = self.interval;
+        let missed_behavior = self.missed_behavior;
         let message_fn = self.message_fn;
 
         Box::pin(async_stream::stream! {
@@ -55,6 +68,7 @@ impl<M: Send + 'static, F: Fn() -> M + Send + 'static> Subscription<M>
             yield (message_fn)();
 
             let mut interval = tokio::time::interval(interval_duration);
+            interval.set_missed_tick_behavior(missed_behavior);
             // Skip the first tick since we already fired
             interval.tick().await;
 
@@ -75,12 +89,24 @@ impl<M: Send + 'static, F: Fn() -> M + Send + 'static> Subscription<M>
 /// Builder for interval immediate subscriptions with a fluent API.
 pub struct IntervalImmediateBuilder {
     interval: Duration,
+    missed_behavior: MissedTickBehavior,
 }
 
 impl IntervalImmediateBuilder {
     /// Creates an interval immediate subscription builder.
     pub fn every(interval: Duration) -> Self {
-        Self { interval }
+        Self {
+            interval,
+            missed_behavior: MissedTickBehavior::Skip,
+        }
+    }
+
+    /// Sets how ticks missed after a lag are handled.
+    ///
+    /// Defaults to [`MissedTickBehavior::Skip`].
+    pub fn missed_behavior(mut self, behavior: MissedTickBehavior) -> Self {
+        self.missed_behavior = behavior;
+        self
     }
 
     /// Sets the message to produce on each tick.
@@ -89,6 +115,7 @@ impl IntervalImmediateBuilder {
         F: Fn() -> M + Send + 'static,
     {
         IntervalImmediateSubscription::new(self.interval, message_fn)
+            .with_missed_behavior(self.missed_behavior)
     }
 }
 
@@ -105,3 +132,169 @@ impl IntervalImmediateBuilder {
 pub fn interval_immediate(interval: Duration) -> IntervalImmediateBuilder {
     IntervalImmediateBuilder::every(interval)
 }
+
+/// A subscription whose message function also chooses the next delay.
+///
+/// The first message fires after `initial` delay. Each call to the message
+/// function returns the message together with the delay before the next
+/// call, so the subscription can poll quickly while there is activity and
+/// back off when idle, without being recreated from `update`.
+///
+/// Delays shorter than the minimum interval (1ms by default, see
+/// [`with_min_interval`](Self::with_min_interval)) are raised to it, so a
+/// zero duration never turns into a busy loop.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::app::AdaptiveIntervalSubscription;
+/// use std::time::Duration;
+///
+/// // Poll every 100ms, doubling the delay up to 5s.
+/// let mut delay = Duration::from_millis(100);
+/// let sub = AdaptiveIntervalSubscription::new(delay, move || {
+///     delay = (delay * 2).min(Duration::from_secs(5));
+///     ("poll", delay)
+/// });
+/// ```
+pub struct AdaptiveIntervalSubscription<M, F>
+where
+    F: FnMut() -> (M, Duration) + Send + 'static,
+{
+    pub(crate) initial: Duration,
+    pub(crate) min_interval: Duration,
+    message_fn: F,
+}
+
+impl<M, F> AdaptiveIntervalSubscription<M, F>
+where
+    F: FnMut() -> (M, Duration) + Send + 'static,
+{
+    /// Creates a new adaptive interval subscription.
+    pub fn new(initial: Duration, message_fn: F) -> Self {
+        Self {
+            initial,
+            min_interval: Duration::from_millis(1),
+            message_fn,
+        }
+    }
+
+    /// Sets the shortest delay allowed between messages.
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+}
+
+impl<M: Send + 'static, F: FnMut() -> (M, Duration) + Send + 'static> Subscription<M>
+    for AdaptiveIntervalSubscription<M, F>
+{
+    fn into_stream(
+        self: Box<Self>,
+        cancel: CancellationToken,
+    ) -> Pin<Box<dyn Stream<Item = M> + Send>> {
+        let min_interval = self.min_interval;
+        let mut delay = self.initial.max(min_interval);
+        let mut message_fn = self.message_fn;
+
+        Box::pin(async_stream::stream! {
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep(delay) => {
+                        let (msg, next) = (message_fn)();
+                        delay = next.max(min_interval);
+                        yield msg;
+                    }
+                    _ = cancel.cancelled() => {
+                        break;
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// A subscription that reports the total time elapsed on each tick.
+///
+/// The clock starts when the subscription starts running. On every tick
+/// the message function receives the time measured since then, rather
+/// than the nominal interval, so a display built from it stays accurate
+/// even when ticks arrive late or are skipped. Summing fixed deltas in
+/// `update` instead drifts by every delay.
+///
+/// The first message fires after one interval. Missed ticks are skipped
+/// by default, as for [`TickSubscription`](super::TickSubscription).
+///
+/// # Example
+///
+/// ```rust
+/// use envision::app::StopwatchSubscription;
+/// use std::time::Duration;
+///
+/// enum Msg {
+///     Elapsed(Duration),
+/// }
+///
+/// let sub = StopwatchSubscription::new(Duration::from_millis(100), Msg::Elapsed);
+/// ```
+pub struct StopwatchSubscription<M, F>
+where
+    F: Fn(Duration) -> M + Send + 'static,
+{
+    pub(crate) interval: Duration,
+    pub(crate) missed_behavior: MissedTickBehavior,
+    message_fn: F,
+}
+
+impl<M, F> StopwatchSubscription<M, F>
+where
+    F: Fn(Duration) -> M + Send + 'static,
+{
+    /// Creates a stopwatch that ticks every `interval`.
+    pub fn new(interval: Duration, message_fn: F) -> Self {
+        Self {
+            interval,
+            missed_behavior: MissedTickBehavior::Skip,
+            message_fn,
+        }
+    }
+
+    /// Sets how ticks missed after a lag are handled.
+    ///
+    /// Defaults to [`MissedTickBehavior::Skip`]. The reported elapsed time
+    /// is measured either way.
+    pub fn with_missed_behavior(mut self, behavior: MissedTickBehavior) -> Self {
+        self.missed_behavior = behavior;
+        self
+    }
+}
+
+impl<M: Send + 'static, F: Fn(Duration) -> M + Send + 'static> Subscription<M>
+    for StopwatchSubscription<M, F>
+{
+    fn into_stream(
+        self: Box<Self>,
+        cancel: CancellationToken,
+    ) -> Pin<Box<dyn Stream<Item = M> + Send>> {
+        let interval_duration = self.interval;
+        let missed_behavior = self.missed_behavior;
+        let message_fn = self.message_fn;
+
+        Box::pin(async_stream::stream! {
+            let start = tokio::time::Instant::now();
+            let mut interval = tokio::time::interval_at(start + interval_duration, interval_duration);
+            interval.set_missed_tick_behavior(missed_behavior);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        yield (message_fn)(start.elapsed());
+                    }
+                    _ = cancel.cancelled() => {
+                        break;
+                    }
+                }
+            }
+        })
+    }
+}
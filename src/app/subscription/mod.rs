@@ -16,21 +16,30 @@
 mod batch;
 mod combinators;
 mod core;
+#[cfg(feature = "cron")]
+mod cron;
 mod ext;
 mod interval;
 mod terminal;
 
 pub use batch::{BatchSubscription, batch};
 pub use combinators::{
-    DebounceSubscription, FilterSubscription, MappedSubscription, TakeSubscription,
-    ThrottleSubscription,
+    CombineLatestSubscription, DebounceSubscription, FilterSubscription, MappedSubscription,
+    SkipWhileSubscription, TakeSubscription, TakeWhileSubscription, ThrottleSubscription,
+    TimeoutSubscription,
 };
 pub use core::{
     BoxedSubscription, ChannelSubscription, StreamSubscription, Subscription, TickSubscription,
-    TickSubscriptionBuilder, TimerSubscription, UnboundedChannelSubscription, tick,
+    TickSubscriptionBuilder, TimerSubscription, UnboundedChannelSubscription, UnfoldSubscription,
+    tick,
 };
+#[cfg(feature = "cron")]
+pub use cron::{CronSchedule, CronSubscription};
 pub use ext::SubscriptionExt;
-pub use interval::{IntervalImmediateBuilder, IntervalImmediateSubscription, interval_immediate};
+pub use interval::{
+    AdaptiveIntervalSubscription, IntervalImmediateBuilder, IntervalImmediateSubscription,
+    StopwatchSubscription, interval_immediate,
+};
 pub use terminal::{TerminalEventSubscription, terminal_events};
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use super::*;
+use tokio::time::MissedTickBehavior;
 
 #[tokio::test]
 async fn test_tick_subscription() {
@@ -216,6 +217,85 @@ fn test_tick_builder_every() {
     assert_eq!(sub.interval, Duration::from_secs(1));
 }
 
+#[test]
+fn test_tick_builder_missed_behavior() {
+    let sub = tick(Duration::from_secs(1)).with_message(|| TestMsg::Tick);
+    assert_eq!(sub.missed_behavior, MissedTickBehavior::Skip);
+
+    let sub = tick(Duration::from_secs(1))
+        .missed_behavior(MissedTickBehavior::Delay)
+        .with_message(|| TestMsg::Tick);
+    assert_eq!(sub.missed_behavior, MissedTickBehavior::Delay);
+}
+
+/// Takes the first two ticks, stalls for 550ms without polling, then returns
+/// the times of the next three ticks relative to the start.
+async fn ticks_after_lag(sub: BoxedSubscription<TestMsg>) -> Vec<Duration> {
+    let cancel = CancellationToken::new();
+    let mut stream = sub.into_stream(cancel.clone());
+    let start = tokio::time::Instant::now();
+
+    for _ in 0..2 {
+        assert_eq!(stream.next().await, Some(TestMsg::Tick));
+    }
+    tokio::time::advance(Duration::from_millis(550)).await;
+
+    let mut elapsed = Vec::new();
+    for _ in 0..3 {
+        assert_eq!(stream.next().await, Some(TestMsg::Tick));
+        elapsed.push(start.elapsed());
+    }
+    cancel.cancel();
+    elapsed
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_tick_skips_missed_ticks_by_default() {
+    let sub = Box::new(tick(Duration::from_millis(100)).with_message(|| TestMsg::Tick));
+    assert_eq!(
+        ticks_after_lag(sub).await,
+        vec![
+            Duration::from_millis(650),
+            Duration::from_millis(700),
+            Duration::from_millis(800),
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_tick_burst_catches_up_missed_ticks() {
+    let sub = Box::new(
+        tick(Duration::from_millis(100))
+            .missed_behavior(MissedTickBehavior::Burst)
+            .with_message(|| TestMsg::Tick),
+    );
+    assert_eq!(
+        ticks_after_lag(sub).await,
+        vec![
+            Duration::from_millis(650),
+            Duration::from_millis(650),
+            Duration::from_millis(650),
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_tick_delay_restarts_after_lag() {
+    let sub = Box::new(
+        tick(Duration::from_millis(100))
+            .missed_behavior(MissedTickBehavior::Delay)
+            .with_message(|| TestMsg::Tick),
+    );
+    assert_eq!(
+        ticks_after_lag(sub).await,
+        vec![
+            Duration::from_millis(650),
+            Duration::from_millis(750),
+            Duration::from_millis(850),
+        ]
+    );
+}
+
 #[test]
 fn test_timer_after() {
     let timer = TimerSubscription::after(Duration::from_secs(5), TestMsg::Timer);
@@ -307,6 +387,75 @@ async fn test_interval_immediate_vs_tick() {
     cancel2.cancel();
 }
 
+#[tokio::test(start_paused = true)]
+async fn test_adaptive_interval_uses_returned_delays() {
+    let cancel = CancellationToken::new();
+    let mut delay = Duration::from_millis(100);
+    let sub = Box::new(AdaptiveIntervalSubscription::new(delay, move || {
+        delay *= 2;
+        (TestMsg::Tick, delay)
+    }));
+
+    let mut stream = sub.into_stream(cancel.clone());
+    let start = tokio::time::Instant::now();
+
+    let mut elapsed = Vec::new();
+    for _ in 0..3 {
+        assert_eq!(stream.next().await, Some(TestMsg::Tick));
+        elapsed.push(start.elapsed());
+    }
+    assert_eq!(
+        elapsed,
+        vec![
+            Duration::from_millis(100),
+            Duration::from_millis(300),
+            Duration::from_millis(700),
+        ]
+    );
+
+    cancel.cancel();
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_adaptive_interval_clamps_zero_delay() {
+    let cancel = CancellationToken::new();
+    let sub = Box::new(
+        AdaptiveIntervalSubscription::new(Duration::ZERO, || (TestMsg::Tick, Duration::ZERO))
+            .with_min_interval(Duration::from_millis(10)),
+    );
+
+    let mut stream = sub.into_stream(cancel.clone());
+    let start = tokio::time::Instant::now();
+
+    stream.next().await;
+    stream.next().await;
+    assert_eq!(start.elapsed(), Duration::from_millis(20));
+
+    cancel.cancel();
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_stopwatch_reports_measured_elapsed_time() {
+    let cancel = CancellationToken::new();
+    let sub = Box::new(StopwatchSubscription::new(
+        Duration::from_millis(100),
+        |elapsed| elapsed,
+    ));
+
+    let mut stream = sub.into_stream(cancel.clone());
+    assert_eq!(stream.next().await, Some(Duration::from_millis(100)));
+    assert_eq!(stream.next().await, Some(Duration::from_millis(200)));
+
+    // A slow consumer: the late tick reports the true elapsed time, not
+    // a multiple of the interval.
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    assert_eq!(stream.next().await, Some(Duration::from_millis(450)));
+
+    cancel.cancel();
+    assert_eq!(stream.next().await, None);
+}
+
 #[tokio::test]
 async fn test_empty_batch_subscription() {
     let cancel = CancellationToken::new();
@@ -432,6 +581,24 @@ fn test_interval_immediate_builder_every() {
     let builder = IntervalImmediateBuilder::every(Duration::from_secs(2));
     let sub = builder.with_message(|| TestMsg::Tick);
     assert_eq!(sub.interval, Duration::from_secs(2));
+    assert_eq!(sub.missed_behavior, MissedTickBehavior::Skip);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_interval_immediate_missed_behavior() {
+    let sub = Box::new(
+        interval_immediate(Duration::from_millis(100))
+            .missed_behavior(MissedTickBehavior::Burst)
+            .with_message(|| TestMsg::Tick),
+    );
+    assert_eq!(
+        ticks_after_lag(sub).await,
+        vec![
+            Duration::from_millis(650),
+            Duration::from_millis(650),
+            Duration::from_millis(650),
+        ]
+    );
 }
 
 #[tokio::test]
@@ -464,3 +631,58 @@ async fn test_tick_cancellation() {
     }
     assert!(ended, "Stream should have ended after cancellation");
 }
+
+#[tokio::test]
+async fn test_unfold_subscription_until_none() {
+    let cancel = CancellationToken::new();
+    let sub = Box::new(UnfoldSubscription::new(3, |n| async move {
+        if n == 0 {
+            None
+        } else {
+            Some((TestMsg::Value(n), n - 1))
+        }
+    }));
+
+    let stream = sub.into_stream(cancel);
+    let msgs: Vec<TestMsg> = stream.collect().await;
+    assert_eq!(
+        msgs,
+        vec![TestMsg::Value(3), TestMsg::Value(2), TestMsg::Value(1)]
+    );
+}
+
+#[tokio::test]
+async fn test_unfold_subscription_threads_state() {
+    let cancel = CancellationToken::new();
+    // Simulated pagination: state is the next page cursor.
+    let sub = Box::new(UnfoldSubscription::new(Some(0), |cursor| async move {
+        let page = cursor?;
+        let next = if page < 2 { Some(page + 1) } else { None };
+        Some((TestMsg::Value(page * 10), next))
+    }));
+
+    let stream = sub.into_stream(cancel);
+    let msgs: Vec<TestMsg> = stream.collect().await;
+    assert_eq!(
+        msgs,
+        vec![TestMsg::Value(0), TestMsg::Value(10), TestMsg::Value(20)]
+    );
+}
+
+#[tokio::test]
+async fn test_unfold_subscription_cancellation() {
+    let cancel = CancellationToken::new();
+    let sub = Box::new(UnfoldSubscription::new(0, |n| async move {
+        if n > 0 {
+            std::future::pending::<()>().await;
+        }
+        Some((TestMsg::Value(n), n + 1))
+    }));
+
+    let mut stream = sub.into_stream(cancel.clone());
+    assert_eq!(stream.next().await, Some(TestMsg::Value(0)));
+
+    // The second call never resolves; cancellation must still end the stream.
+    cancel.cancel();
+    assert_eq!(stream.next().await, None);
+}
@@ -0,0 +1,175 @@
+use super::*;
+use chrono::{NaiveDate, NaiveDateTime};
+use std::sync::{Arc, Mutex};
+
+fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2024, 6, day)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
+
+/// A fake wall clock that follows tokio's (paused) time plus an adjustable
+/// offset, so tests can simulate system clock changes.
+#[derive(Clone)]
+struct FakeWallClock {
+    base: NaiveDateTime,
+    start: tokio::time::Instant,
+    offset: Arc<Mutex<chrono::Duration>>,
+}
+
+impl FakeWallClock {
+    fn new(base: NaiveDateTime) -> Self {
+        Self {
+            base,
+            start: tokio::time::Instant::now(),
+            offset: Arc::new(Mutex::new(chrono::Duration::zero())),
+        }
+    }
+
+    fn now(&self) -> NaiveDateTime {
+        let elapsed = chrono::Duration::from_std(self.start.elapsed()).unwrap();
+        self.base + elapsed + *self.offset.lock().unwrap()
+    }
+
+    fn jump(&self, by: chrono::Duration) {
+        *self.offset.lock().unwrap() += by;
+    }
+}
+
+#[test]
+fn test_cron_parse_rejects_malformed_expressions() {
+    for expr in [
+        "",
+        "* * * *",
+        "60 * * * *",
+        "* 24 * * *",
+        "*/0 * * * *",
+        "5-1 * * * *",
+        "x * * * *",
+    ] {
+        let err = CronSchedule::parse(expr).unwrap_err();
+        assert!(matches!(err, crate::EnvisionError::Config { .. }), "{expr}");
+    }
+    assert_eq!(
+        CronSchedule::parse("61 * * * *").unwrap_err().to_string(),
+        "configuration error: field `cron schedule`: minute: 61 is outside 0-59"
+    );
+}
+
+#[test]
+fn test_cron_next_after_steps_ranges_and_lists() {
+    let every_15 = CronSchedule::parse("*/15 * * * *").unwrap();
+    assert_eq!(every_15.next_after(at(1, 10, 7)), Some(at(1, 10, 15)));
+    assert_eq!(every_15.next_after(at(1, 23, 45)), Some(at(2, 0, 0)));
+
+    let list = CronSchedule::parse("0,30 9-10 * * *").unwrap();
+    assert_eq!(list.next_after(at(1, 9, 30)), Some(at(1, 10, 0)));
+    assert_eq!(list.next_after(at(1, 10, 30)), Some(at(2, 9, 0)));
+
+    // Strictly after: a time exactly on a match moves to the next one.
+    let hourly: CronSchedule = "@hourly".parse().unwrap();
+    assert_eq!(hourly.next_after(at(1, 9, 0)), Some(at(1, 10, 0)));
+    assert!(hourly.matches(at(1, 9, 0)));
+    assert_eq!(hourly.to_string(), "@hourly");
+}
+
+#[test]
+fn test_cron_next_after_names_and_month_rollover() {
+    let new_year = CronSchedule::parse("0 0 1 jan *").unwrap();
+    assert_eq!(
+        new_year.next_after(at(15, 12, 0)),
+        NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+    );
+
+    // 2024-06-01 is a Saturday; 7 and `sun` both mean Sunday.
+    let sunday = CronSchedule::parse("30 8 * * 7").unwrap();
+    assert_eq!(sunday.next_after(at(1, 0, 0)), Some(at(2, 8, 30)));
+    assert_eq!(
+        CronSchedule::parse("30 8 * * SUN")
+            .unwrap()
+            .next_after(at(1, 0, 0)),
+        Some(at(2, 8, 30))
+    );
+}
+
+#[test]
+fn test_cron_restricted_day_and_weekday_match_either() {
+    // The 10th, or any Monday.
+    let schedule = CronSchedule::parse("0 0 10 * mon").unwrap();
+    assert_eq!(schedule.next_after(at(1, 0, 0)), Some(at(3, 0, 0)));
+    assert_eq!(schedule.next_after(at(8, 0, 0)), Some(at(10, 0, 0)));
+}
+
+#[test]
+fn test_cron_impossible_schedule_never_fires() {
+    let schedule = CronSchedule::parse("0 0 31 2 *").unwrap();
+    assert_eq!(schedule.next_after(at(1, 0, 0)), None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cron_subscription_fires_on_schedule() {
+    let clock = FakeWallClock::new(at(1, 9, 58));
+    let wall = clock.clone();
+    let sub = Box::new(
+        CronSubscription::new(CronSchedule::parse("0 * * * *").unwrap(), || TestMsg::Tick)
+            .with_wall_clock(move || wall.now()),
+    );
+    let mut stream = sub.into_stream(CancellationToken::new());
+
+    assert_eq!(stream.next().await, Some(TestMsg::Tick));
+    assert_eq!(clock.now(), at(1, 10, 0));
+    assert_eq!(stream.next().await, Some(TestMsg::Tick));
+    assert_eq!(clock.now(), at(1, 11, 0));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cron_subscription_follows_clock_jumps() {
+    let clock = FakeWallClock::new(at(1, 9, 0));
+    let wall = clock.clone();
+    let sub = Box::new(
+        CronSubscription::new(CronSchedule::parse("0 12 * * *").unwrap(), || TestMsg::Tick)
+            .with_wall_clock(move || wall.now()),
+    );
+    let mut stream = sub.into_stream(CancellationToken::new());
+
+    let pending = tokio::time::timeout(Duration::from_secs(10), stream.next()).await;
+    assert!(pending.is_err());
+
+    // The clock is set forward past noon: one message, within a recheck.
+    let start = tokio::time::Instant::now();
+    clock.jump(chrono::Duration::hours(4));
+    assert_eq!(stream.next().await, Some(TestMsg::Tick));
+    assert!(start.elapsed() <= Duration::from_secs(1));
+
+    // Set back before noon: today's noon already fired, so the next
+    // message is tomorrow's.
+    clock.jump(chrono::Duration::hours(-4));
+    assert_eq!(stream.next().await, Some(TestMsg::Tick));
+    assert_eq!(clock.now(), at(2, 12, 0));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cron_subscription_cancellation() {
+    let cancel = CancellationToken::new();
+    let sub = Box::new(
+        CronSubscription::new(CronSchedule::parse("@yearly").unwrap(), || TestMsg::Tick)
+            .with_wall_clock(|| at(1, 0, 0)),
+    );
+    let mut stream = sub.into_stream(cancel.clone());
+    cancel.cancel();
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn test_cron_subscription_impossible_schedule_ends() {
+    let sub = Box::new(CronSubscription::new(
+        CronSchedule::parse("0 0 30 feb *").unwrap(),
+        || TestMsg::Tick,
+    ));
+    assert_eq!(sub.schedule().expression(), "0 0 30 feb *");
+    let mut stream = sub.into_stream(CancellationToken::new());
+    assert_eq!(stream.next().await, None);
+}
@@ -240,3 +240,101 @@ async fn test_throttle_empty_stream() {
     let msg = stream.next().await;
     assert_eq!(msg, None);
 }
+
+#[tokio::test(start_paused = true)]
+async fn test_timeout_fires_once_when_quiet() {
+    let cancel = CancellationToken::new();
+    let (_tx, rx) = mpsc::channel::<TestMsg>(10);
+    let inner = ChannelSubscription::new(rx);
+    let sub = Box::new(TimeoutSubscription::new(
+        inner,
+        Duration::from_millis(100),
+        || TestMsg::Quit,
+    ));
+
+    let mut stream = sub.into_stream(cancel.clone());
+
+    let msg = tokio::time::timeout(Duration::from_millis(150), stream.next()).await;
+    assert_eq!(msg.unwrap(), Some(TestMsg::Quit));
+
+    // Still quiet: no second timeout message
+    let msg = tokio::time::timeout(Duration::from_millis(500), stream.next()).await;
+    assert!(msg.is_err());
+
+    cancel.cancel();
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_timeout_passes_messages_and_rearms() {
+    let cancel = CancellationToken::new();
+    let (tx, rx) = mpsc::channel(10);
+    let inner = ChannelSubscription::new(rx);
+    let sub = Box::new(TimeoutSubscription::new(
+        inner,
+        Duration::from_millis(100),
+        || TestMsg::Quit,
+    ));
+
+    let mut stream = sub.into_stream(cancel.clone());
+
+    // Activity within the window keeps the timer from firing
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    tx.send(TestMsg::Value(1)).await.unwrap();
+    assert_eq!(stream.next().await, Some(TestMsg::Value(1)));
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    tx.send(TestMsg::Value(2)).await.unwrap();
+    assert_eq!(stream.next().await, Some(TestMsg::Value(2)));
+
+    // Then silence times out
+    assert_eq!(stream.next().await, Some(TestMsg::Quit));
+
+    // Activity resumes, and the next silence times out again
+    tx.send(TestMsg::Value(3)).await.unwrap();
+    assert_eq!(stream.next().await, Some(TestMsg::Value(3)));
+    let start = tokio::time::Instant::now();
+    assert_eq!(stream.next().await, Some(TestMsg::Quit));
+    assert_eq!(start.elapsed(), Duration::from_millis(100));
+
+    cancel.cancel();
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_timeout_ends_with_inner_stream() {
+    let cancel = CancellationToken::new();
+    let values = vec![TestMsg::Value(1)];
+    let inner = StreamSubscription::new(tokio_stream::iter(values));
+    let sub = Box::new(TimeoutSubscription::new(
+        inner,
+        Duration::from_millis(100),
+        || TestMsg::Quit,
+    ));
+
+    let mut stream = sub.into_stream(cancel);
+    assert_eq!(stream.next().await, Some(TestMsg::Value(1)));
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_timeout_cancellation() {
+    let cancel = CancellationToken::new();
+    let (_tx, rx) = mpsc::channel::<TestMsg>(10);
+    let inner = ChannelSubscription::new(rx);
+    let sub = Box::new(TimeoutSubscription::new(
+        inner,
+        Duration::from_millis(100),
+        || TestMsg::Quit,
+    ));
+
+    let mut stream = sub.into_stream(cancel.clone());
+    cancel.cancel();
+    assert_eq!(stream.next().await, None);
+}
+
+#[test]
+fn test_timeout_subscription_new() {
+    let values = vec![TestMsg::Value(1)];
+    let inner = StreamSubscription::new(tokio_stream::iter(values));
+    let sub = TimeoutSubscription::new(inner, Duration::from_millis(250), || TestMsg::Quit);
+    assert_eq!(sub.duration, Duration::from_millis(250));
+}
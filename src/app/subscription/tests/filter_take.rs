@@ -190,3 +190,81 @@ async fn test_take_empty_stream() {
     let msg = stream.next().await;
     assert_eq!(msg, None);
 }
+
+#[tokio::test]
+async fn test_take_while_subscription() {
+    let cancel = CancellationToken::new();
+    let values = vec![
+        TestMsg::Value(1),
+        TestMsg::Value(2),
+        TestMsg::Quit,
+        TestMsg::Value(3),
+    ];
+    let inner = StreamSubscription::new(tokio_stream::iter(values));
+    let sub = Box::new(inner.take_while(|msg| *msg != TestMsg::Quit));
+
+    let stream = sub.into_stream(cancel);
+    let collected: Vec<TestMsg> = stream.collect().await;
+
+    // Ends at Quit without emitting it or anything after it
+    assert_eq!(collected, vec![TestMsg::Value(1), TestMsg::Value(2)]);
+}
+
+#[tokio::test]
+async fn test_take_while_first_message_fails() {
+    let cancel = CancellationToken::new();
+    let values = vec![TestMsg::Quit, TestMsg::Value(1)];
+    let inner = StreamSubscription::new(tokio_stream::iter(values));
+    let sub = Box::new(TakeWhileSubscription::new(inner, |msg| {
+        *msg != TestMsg::Quit
+    }));
+
+    let mut stream = sub.into_stream(cancel);
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn test_skip_while_subscription() {
+    let cancel = CancellationToken::new();
+    let values = vec![
+        TestMsg::Value(1),
+        TestMsg::Value(2),
+        TestMsg::Value(5),
+        TestMsg::Value(1),
+    ];
+    let inner = StreamSubscription::new(tokio_stream::iter(values));
+    let sub = Box::new(inner.skip_while(|msg| matches!(msg, TestMsg::Value(n) if *n < 3)));
+
+    let stream = sub.into_stream(cancel);
+    let collected: Vec<TestMsg> = stream.collect().await;
+
+    // Later messages that match the predicate are no longer dropped
+    assert_eq!(collected, vec![TestMsg::Value(5), TestMsg::Value(1)]);
+}
+
+#[tokio::test]
+async fn test_skip_while_all_skipped() {
+    let cancel = CancellationToken::new();
+    let values = vec![TestMsg::Tick, TestMsg::Tick];
+    let inner = StreamSubscription::new(tokio_stream::iter(values));
+    let sub = Box::new(SkipWhileSubscription::new(inner, |_| true));
+
+    let mut stream = sub.into_stream(cancel);
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn test_take_while_respects_cancellation() {
+    let cancel = CancellationToken::new();
+    let sub = Box::new(
+        tick(Duration::from_millis(10))
+            .with_message(|| TestMsg::Tick)
+            .take_while(|_| true),
+    );
+
+    let mut stream = sub.into_stream(cancel.clone());
+    assert_eq!(stream.next().await, Some(TestMsg::Tick));
+
+    cancel.cancel();
+    assert_eq!(stream.next().await, None);
+}
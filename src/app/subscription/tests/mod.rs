@@ -11,6 +11,8 @@ enum TestMsg {
 }
 
 mod core;
+#[cfg(feature = "cron")]
+mod cron;
 mod debounce_throttle;
 mod filter_take;
 mod subscription_ext;
@@ -172,3 +172,66 @@ async fn test_subscription_ext_filter_map_take() {
     let msg = stream.next().await;
     assert_eq!(msg, None);
 }
+
+#[tokio::test]
+async fn test_subscription_ext_combine_latest() {
+    let cancel = CancellationToken::new();
+    let (tx_a, rx_a) = mpsc::channel(10);
+    let (tx_b, rx_b) = mpsc::channel(10);
+
+    let sub =
+        Box::new(ChannelSubscription::new(rx_a).combine_latest(ChannelSubscription::new(rx_b)));
+    let mut stream = sub.into_stream(cancel);
+
+    // Nothing is emitted until both sides have produced a value
+    tx_a.send(1).await.unwrap();
+    tx_a.send(2).await.unwrap();
+    tx_b.send("a").await.unwrap();
+    assert_eq!(stream.next().await, Some((2, "a")));
+
+    tx_b.send("b").await.unwrap();
+    assert_eq!(stream.next().await, Some((2, "b")));
+
+    tx_a.send(3).await.unwrap();
+    assert_eq!(stream.next().await, Some((3, "b")));
+
+    // Ends only after both sides have ended
+    drop(tx_a);
+    tx_b.send("c").await.unwrap();
+    assert_eq!(stream.next().await, Some((3, "c")));
+    drop(tx_b);
+    assert_eq!(stream.next().await, None);
+}
+
+#[tokio::test]
+async fn test_subscription_ext_combine_latest_cancellation() {
+    let cancel = CancellationToken::new();
+    let first = tick(Duration::from_millis(10)).with_message(|| 1);
+    let second = tick(Duration::from_millis(10)).with_message(|| 2);
+
+    let sub = Box::new(first.combine_latest(second));
+    let mut stream = sub.into_stream(cancel.clone());
+    assert_eq!(stream.next().await, Some((1, 2)));
+
+    cancel.cancel();
+    let result = tokio::time::timeout(Duration::from_millis(200), stream.next()).await;
+    assert_eq!(result, Ok(None));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_subscription_ext_timeout_with() {
+    let cancel = CancellationToken::new();
+    let (tx, rx) = tokio::sync::mpsc::channel(10);
+    let inner = ChannelSubscription::new(rx);
+
+    // Use fluent timeout_with method
+    let sub = Box::new(inner.timeout_with(Duration::from_millis(100), || TestMsg::Quit));
+
+    let mut stream = sub.into_stream(cancel.clone());
+
+    tx.send(TestMsg::Value(1)).await.unwrap();
+    assert_eq!(stream.next().await, Some(TestMsg::Value(1)));
+    assert_eq!(stream.next().await, Some(TestMsg::Quit));
+
+    cancel.cancel();
+}
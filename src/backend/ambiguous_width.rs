@@ -0,0 +1,53 @@
+//! Measuring East Asian Ambiguous-width characters.
+//!
+//! Unicode leaves the width of some characters — arrows, box-drawing
+//! lines, circled digits, symbols like `±` and `§` — to context: terminals in
+//! CJK locales usually draw them two columns wide, others one.
+//! [`AmbiguousWidth`] picks which, so [`CaptureBackend`](super::CaptureBackend)
+//! can measure a frame the way the user's terminal will.
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// How many columns East Asian Ambiguous-width characters occupy.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::backend::AmbiguousWidth;
+///
+/// assert_eq!(AmbiguousWidth::default(), AmbiguousWidth::Narrow);
+/// assert_eq!(AmbiguousWidth::Narrow.str_width("→○"), 2);
+/// assert_eq!(AmbiguousWidth::Wide.str_width("→○"), 4);
+/// // Characters with a fixed width are unaffected.
+/// assert_eq!(AmbiguousWidth::Wide.str_width("ab日"), 4);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum AmbiguousWidth {
+    /// One column, as in most Western locales.
+    #[default]
+    Narrow,
+    /// Two columns, as in most CJK locales.
+    Wide,
+}
+
+impl AmbiguousWidth {
+    /// Returns the display width of `ch`, or `None` for control characters.
+    pub fn char_width(self, ch: char) -> Option<usize> {
+        match self {
+            AmbiguousWidth::Narrow => ch.width(),
+            AmbiguousWidth::Wide => ch.width_cjk(),
+        }
+    }
+
+    /// Returns the display width of `text`.
+    pub fn str_width(self, text: &str) -> usize {
+        match self {
+            AmbiguousWidth::Narrow => text.width(),
+            AmbiguousWidth::Wide => text.width_cjk(),
+        }
+    }
+}
@@ -5,15 +5,19 @@
 
 use std::collections::VecDeque;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::io;
 use std::sync::Arc;
+use std::time::Duration;
 
 use ratatui::backend::{Backend, ClearType, WindowSize};
 use ratatui::buffer::Cell;
-use ratatui::layout::{Position, Size};
+use ratatui::layout::{Position, Rect, Size};
 
-use super::cell::EnhancedCell;
-use super::output::OutputFormat;
+use super::ambiguous_width::AmbiguousWidth;
+use super::cell::{CapturedStyle, EnhancedCell};
+use super::cursor_shape::CursorShape;
+use super::output::{self, AnsiOptions, OutputFormat, TrimMode};
 
 /// A backend that captures rendered frames for inspection and testing.
 ///
@@ -65,6 +69,9 @@ pub struct CaptureBackend {
     /// Whether the cursor is visible
     cursor_visible: bool,
 
+    /// Cursor shape requested by the app, if any
+    cursor_shape: Option<CursorShape>,
+
     /// Current frame number (incremented on each flush)
     current_frame: u64,
 
@@ -73,6 +80,18 @@ pub struct CaptureBackend {
 
     /// Maximum history size (0 = disabled)
     history_capacity: usize,
+
+    /// Named regions marked with `mark_region`
+    regions: Vec<(String, Rect)>,
+
+    /// Bounding rectangle of the cells written by the most recent `draw`
+    last_damage: Option<Rect>,
+
+    /// Window title set by the app, if any
+    title: Option<String>,
+
+    /// How East Asian Ambiguous-width characters are measured
+    ambiguous_width: AmbiguousWidth,
 }
 
 /// A snapshot of a single frame's state.
@@ -143,6 +162,9 @@ where
 pub struct CursorSnapshot {
     pub position: (u16, u16),
     pub visible: bool,
+    /// The requested cursor shape, or `None` for the terminal default.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub shape: Option<CursorShape>,
 }
 
 impl FrameSnapshot {
@@ -242,9 +264,14 @@ impl CaptureBackend {
             height,
             cursor_position: Position::new(0, 0),
             cursor_visible: true,
+            cursor_shape: None,
             current_frame: 0,
             history: VecDeque::new(),
             history_capacity: 0,
+            regions: Vec::new(),
+            last_damage: None,
+            title: None,
+            ambiguous_width: AmbiguousWidth::Narrow,
         }
     }
 
@@ -262,6 +289,39 @@ impl CaptureBackend {
         backend
     }
 
+    /// Sets how wide East Asian Ambiguous-width characters are
+    /// (builder pattern).
+    ///
+    /// The default, [`AmbiguousWidth::Narrow`], matches most Western
+    /// terminals. Apps for CJK locales, whose terminals usually draw
+    /// characters like `→`, `○` and `─` two columns wide, can use
+    /// [`AmbiguousWidth::Wide`] so that drawing, [`validate`](Self::validate)
+    /// and [`visual_diff`](Self::visual_diff) measure them the same way.
+    ///
+    /// ratatui itself always lays text out with narrow ambiguous
+    /// characters, so with `Wide` such a character followed by more text
+    /// shows up as a [`CellError::TornWideChar`]: the places where the
+    /// frame would be garbled on a wide terminal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::{AmbiguousWidth, CaptureBackend};
+    /// use ratatui::Terminal;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let backend = CaptureBackend::new(4, 1).with_ambiguous_width(AmbiguousWidth::Wide);
+    /// let mut terminal = Terminal::new(backend).unwrap();
+    /// terminal.draw(|frame| frame.render_widget(Paragraph::new("→"), frame.area())).unwrap();
+    ///
+    /// // On a wide terminal "→" covers the next column too.
+    /// assert!(terminal.backend().cell(1, 0).unwrap().is_continuation());
+    /// ```
+    pub fn with_ambiguous_width(mut self, ambiguous_width: AmbiguousWidth) -> Self {
+        self.ambiguous_width = ambiguous_width;
+        self
+    }
+
     /// Returns the current frame number.
     pub fn current_frame(&self) -> u64 {
         self.current_frame
@@ -307,23 +367,313 @@ impl CaptureBackend {
         (0..self.height).map(|y| self.row_content(y)).collect()
     }
 
+    /// Returns each row as a string with trailing spaces trimmed.
+    ///
+    /// Unlike [`content_lines`](Self::content_lines), rows are not padded to
+    /// the full width, so they compare directly against expected text. There
+    /// is always one entry per row; use [`lines_with`](Self::lines_with) to
+    /// also drop trailing blank rows or to keep the padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(10, 3)).unwrap();
+    /// terminal
+    ///     .draw(|frame| frame.render_widget(Paragraph::new("one\ntwo"), frame.area()))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(terminal.backend().lines(), vec!["one", "two", ""]);
+    /// ```
+    pub fn lines(&self) -> Vec<String> {
+        self.lines_with(TrimMode::TrailingSpaces)
+    }
+
+    /// Returns each row as a string, trimmed according to `mode`.
+    ///
+    /// Joining the lines with `\n` gives the same text as
+    /// [`to_string_with`](Self::to_string_with).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::{CaptureBackend, TrimMode};
+    ///
+    /// let mut backend = CaptureBackend::new(4, 3);
+    /// if let Some(cell) = backend.cell_mut(0, 0) {
+    ///     cell.set_char('A');
+    /// }
+    /// assert_eq!(backend.lines_with(TrimMode::None), vec!["A   ", "    ", "    "]);
+    /// assert_eq!(backend.lines_with(TrimMode::TrailingBlankLines), vec!["A"]);
+    /// ```
+    pub fn lines_with(&self, mode: TrimMode) -> Vec<String> {
+        let mut lines = self.content_lines();
+        if mode == TrimMode::None {
+            return lines;
+        }
+
+        for line in &mut lines {
+            line.truncate(line.trim_end().len());
+        }
+        if mode == TrimMode::TrailingBlankLines {
+            while lines.last().is_some_and(|l| l.is_empty()) {
+                lines.pop();
+            }
+        }
+        lines
+    }
+
+    /// Returns row `y` with trailing spaces trimmed, or `None` if `y` is
+    /// outside the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    ///
+    /// let mut backend = CaptureBackend::new(5, 2);
+    /// if let Some(cell) = backend.cell_mut(1, 1) {
+    ///     cell.set_char('x');
+    /// }
+    /// assert_eq!(backend.line(0), Some(String::new()));
+    /// assert_eq!(backend.line(1), Some(" x".to_string()));
+    /// assert_eq!(backend.line(2), None);
+    /// ```
+    pub fn line(&self, y: u16) -> Option<String> {
+        if y >= self.height {
+            return None;
+        }
+        Some(self.row_content(y).trim_end().to_string())
+    }
+
+    /// Returns the style of every cell as a grid of rows, without symbols.
+    ///
+    /// `style_grid()[y][x]` is the style of the cell at `(x, y)`. Use it to
+    /// analyze coloring without parsing text, for example to assert that
+    /// red only appears inside an error region.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    /// use ratatui::style::{Color, Style};
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(6, 2)).unwrap();
+    /// terminal
+    ///     .draw(|frame| {
+    ///         let error = Paragraph::new("err").style(Style::default().fg(Color::Red));
+    ///         frame.render_widget(error, ratatui::layout::Rect::new(0, 0, 3, 1));
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let grid = terminal.backend().style_grid();
+    /// assert_eq!(grid.len(), 2);
+    /// assert_eq!(grid[0].len(), 6);
+    /// assert_eq!(grid[0][0].style().fg, Some(Color::Red));
+    /// assert_ne!(grid[1][0].style().fg, Some(Color::Red));
+    /// ```
+    pub fn style_grid(&self) -> Vec<Vec<CapturedStyle>> {
+        if self.width == 0 {
+            return vec![Vec::new(); self.height as usize];
+        }
+        self.cells
+            .chunks(self.width as usize)
+            .map(|row| row.iter().map(CapturedStyle::from).collect())
+            .collect()
+    }
+
+    /// Returns the style of the cell under the visible cursor.
+    ///
+    /// Returns `None` when the cursor is hidden or outside the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(10, 3)).unwrap();
+    /// terminal.draw(|frame| frame.set_cursor_position((4, 1))).unwrap();
+    /// assert!(terminal.backend().style_at_cursor().is_some());
+    ///
+    /// terminal.draw(|_frame| {}).unwrap();
+    /// assert_eq!(terminal.backend().style_at_cursor(), None);
+    /// ```
+    pub fn style_at_cursor(&self) -> Option<CapturedStyle> {
+        let position = self.visible_cursor()?;
+        self.cell(position.x, position.y).map(CapturedStyle::from)
+    }
+
     /// Searches for text in the buffer and returns positions where it appears.
+    ///
+    /// Positions are cell coordinates, so a match after a wide character
+    /// reports the column it is drawn at rather than its byte offset.
     pub fn find_text(&self, needle: &str) -> Vec<Position> {
+        self.find_text_in(Rect::new(0, 0, self.width, self.height), needle)
+    }
+
+    /// Searches for text within `area` and returns positions where it
+    /// appears.
+    ///
+    /// The area is clipped to the screen. Only text that lies entirely
+    /// inside the area matches, so a pane can be searched without picking
+    /// up the same text in a neighbouring pane.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    /// use ratatui::layout::{Position, Rect};
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(20, 1)).unwrap();
+    /// terminal
+    ///     .draw(|frame| frame.render_widget(Paragraph::new("ok      ok"), frame.area()))
+    ///     .unwrap();
+    ///
+    /// let right = Rect::new(8, 0, 12, 1);
+    /// assert_eq!(terminal.backend().find_text_in(right, "ok"), vec![Position::new(8, 0)]);
+    /// ```
+    pub fn find_text_in(&self, area: Rect, needle: &str) -> Vec<Position> {
+        let area = self.clip(area);
         let mut positions = Vec::new();
-        for y in 0..self.height {
-            let row = self.row_content(y);
-            for (x, _) in row.match_indices(needle) {
-                positions.push(Position::new(x as u16, y));
+        for y in area.top()..area.bottom() {
+            let (row, columns) = self.row_with_columns(y, area.left(), area.right());
+            for (offset, _) in row.match_indices(needle) {
+                if let Some(&x) = columns.get(offset) {
+                    positions.push(Position::new(x, y));
+                }
             }
         }
         positions
     }
 
+    /// Searches each rendered row for matches of a regular expression.
+    ///
+    /// Rows are searched independently, including their trailing padding,
+    /// so a pattern never matches across a line break. Matches report the
+    /// cell column they start at and how many columns they span, so wide
+    /// characters are accounted for.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` is not a valid regular expression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    /// use ratatui::layout::Position;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(20, 2)).unwrap();
+    /// terminal
+    ///     .draw(|frame| frame.render_widget(Paragraph::new("\nElapsed: 12s"), frame.area()))
+    ///     .unwrap();
+    ///
+    /// let matches = terminal.backend().find_regex(r"\d+s").unwrap();
+    /// assert_eq!(matches.len(), 1);
+    /// assert_eq!(matches[0].position, Position::new(9, 1));
+    /// assert_eq!(matches[0].width, 3);
+    /// assert_eq!(matches[0].text, "12s");
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn find_regex(&self, pattern: &str) -> Result<Vec<TextMatch>, regex::Error> {
+        let regex = regex::Regex::new(pattern)?;
+        let mut matches = Vec::new();
+        for y in 0..self.height {
+            let (row, columns) = self.row_with_columns(y, 0, self.width);
+            for found in regex.find_iter(&row) {
+                let Some(&start) = columns.get(found.start()) else {
+                    continue;
+                };
+                let end = columns.get(found.end()).copied().unwrap_or(self.width);
+                matches.push(TextMatch {
+                    position: Position::new(start, y),
+                    width: end - start,
+                    text: found.as_str().to_string(),
+                });
+            }
+        }
+        Ok(matches)
+    }
+
+    /// Returns the text of row `y` between columns `left` and `right`,
+    /// alongside the column each byte starts in.
+    fn row_with_columns(&self, y: u16, left: u16, right: u16) -> (String, Vec<u16>) {
+        let mut row = String::new();
+        let mut columns = Vec::new();
+        for x in left..right {
+            let symbol = self.cells[self.index_of(x, y)].symbol();
+            row.push_str(symbol);
+            columns.extend(std::iter::repeat_n(x, symbol.len()));
+        }
+        (row, columns)
+    }
+
     /// Returns true if the buffer contains the given text.
     pub fn contains_text(&self, needle: &str) -> bool {
         !self.find_text(needle).is_empty()
     }
 
+    /// Returns true if `area` contains the given text.
+    ///
+    /// See [`find_text_in`](Self::find_text_in) for how the area is searched.
+    pub fn contains_text_in(&self, area: Rect, needle: &str) -> bool {
+        !self.find_text_in(area, needle).is_empty()
+    }
+
+    /// Returns a hash of the visible content, for cheap change detection.
+    ///
+    /// The hash covers the buffer size and the symbol and style (colors,
+    /// modifiers and underline color) of every cell, so a color-only change
+    /// yields a different hash. Frame counters and the cursor are not
+    /// included. The value is stable across runs and platforms.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    /// use ratatui::style::{Color, Style};
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(10, 1)).unwrap();
+    /// terminal.draw(|frame| frame.render_widget(Paragraph::new("hi"), frame.area())).unwrap();
+    /// let before = terminal.backend().content_hash();
+    ///
+    /// terminal.draw(|frame| frame.render_widget(Paragraph::new("hi"), frame.area())).unwrap();
+    /// assert_eq!(terminal.backend().content_hash(), before);
+    ///
+    /// terminal
+    ///     .draw(|frame| {
+    ///         let red = Paragraph::new("hi").style(Style::default().fg(Color::Red));
+    ///         frame.render_widget(red, frame.area());
+    ///     })
+    ///     .unwrap();
+    /// assert_ne!(terminal.backend().content_hash(), before);
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = StableHasher::default();
+        self.width.hash(&mut hasher);
+        self.height.hash(&mut hasher);
+        for cell in &self.cells {
+            cell.symbol().hash(&mut hasher);
+            cell.fg.hash(&mut hasher);
+            cell.bg.hash(&mut hasher);
+            cell.modifiers.hash(&mut hasher);
+            cell.underline_color.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
     /// Creates a snapshot of the current state.
     pub fn snapshot(&self) -> FrameSnapshot {
         FrameSnapshot {
@@ -332,6 +682,7 @@ impl CaptureBackend {
             cursor: CursorSnapshot {
                 position: (self.cursor_position.x, self.cursor_position.y),
                 visible: self.cursor_visible,
+                shape: self.cursor_shape,
             },
             cells: Arc::from(self.cells.as_slice()),
         }
@@ -381,16 +732,240 @@ impl CaptureBackend {
         }
     }
 
+    /// Compares the current display against `expected` and describes any
+    /// differences.
+    ///
+    /// The expected text is aligned with the screen contents line by line,
+    /// with wide characters occupying their full width and zero-width
+    /// characters none. Trailing whitespace is ignored on both sides, and
+    /// missing lines compare as empty, so `expected` doesn't need to be
+    /// padded to the screen size. Returns `None` when every line matches.
+    ///
+    /// Otherwise each differing row is reported with its expected (`-`)
+    /// and actual (`+`) text, followed by a line with a `^` under every
+    /// changed column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    ///
+    /// let mut backend = CaptureBackend::new(10, 2);
+    /// for (x, ch) in "Count: 2".chars().enumerate() {
+    ///     backend.cell_mut(x as u16, 0).unwrap().set_char(ch);
+    /// }
+    ///
+    /// assert_eq!(backend.visual_diff("Count: 2"), None);
+    /// assert_eq!(
+    ///     backend.visual_diff("Count: 1").unwrap(),
+    ///     "row 0:\n- Count: 1\n+ Count: 2\n         ^\n",
+    /// );
+    /// ```
+    pub fn visual_diff(&self, expected: &str) -> Option<String> {
+        let expected_lines: Vec<&str> = expected.lines().map(str::trim_end).collect();
+        let actual_lines: Vec<String> = (0..self.height).map(|y| self.display_row(y)).collect();
+
+        let mut output = String::new();
+        for row in 0..expected_lines.len().max(actual_lines.len()) {
+            let expected_line = expected_lines.get(row).copied().unwrap_or("");
+            let actual_line = actual_lines.get(row).map(String::as_str).unwrap_or("");
+            if expected_line == actual_line {
+                continue;
+            }
+
+            let expected_columns = display_columns(expected_line, self.ambiguous_width);
+            let actual_columns = display_columns(actual_line, self.ambiguous_width);
+            let markers: String = (0..expected_columns.len().max(actual_columns.len()))
+                .map(|col| {
+                    if expected_columns.get(col) == actual_columns.get(col) {
+                        ' '
+                    } else {
+                        '^'
+                    }
+                })
+                .collect();
+
+            output.push_str(&format!("row {}:\n", row));
+            output.push_str(&format!("- {}\n", expected_line));
+            output.push_str(&format!("+ {}\n", actual_line));
+            output.push_str(&format!("  {}\n", markers.trim_end()));
+        }
+
+        if output.is_empty() {
+            None
+        } else {
+            Some(output)
+        }
+    }
+
+    /// Returns a row as it appears on screen, without the continuation
+    /// cells covered by wide characters, and with trailing whitespace
+    /// trimmed.
+    ///
+    /// Only cells marked as continuations are skipped, so a glyph drawn
+    /// right after an ambiguous-width character that ratatui laid out one
+    /// column wide is kept.
+    fn display_row(&self, y: u16) -> String {
+        let start = self.index_of(0, y);
+        let end = start + self.width as usize;
+        let line: String = self.cells[start..end]
+            .iter()
+            .filter(|cell| !cell.is_continuation())
+            .map(EnhancedCell::symbol)
+            .collect();
+        line.trim_end().to_string()
+    }
+
     /// Renders the buffer to a string using the specified format.
     pub fn render(&self, format: OutputFormat) -> String {
         format.render(self)
     }
 
+    /// Renders the buffer as plain text, trimmed according to `mode`.
+    ///
+    /// [`TrimMode::None`] gives the same output as `to_string()`. Trimming
+    /// only affects the returned text; [`contains_text`](Self::contains_text)
+    /// and [`find_text`](Self::find_text) still search the full grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::{CaptureBackend, TrimMode};
+    ///
+    /// let mut backend = CaptureBackend::new(10, 3);
+    /// if let Some(cell) = backend.cell_mut(0, 0) {
+    ///     cell.set_char('A');
+    /// }
+    /// assert_eq!(backend.to_string_with(TrimMode::TrailingBlankLines), "A");
+    /// ```
+    pub fn to_string_with(&self, mode: TrimMode) -> String {
+        output::render_with_trim(self, mode)
+    }
+
     /// Renders the buffer with ANSI color codes.
     pub fn to_ansi(&self) -> String {
         self.render(OutputFormat::Ansi)
     }
 
+    /// Renders the buffer as the escape sequences that reproduce it on a
+    /// real terminal.
+    ///
+    /// Where [`to_ansi`](Self::to_ansi) aims at readable output, this aims
+    /// at faithful replay in as few bytes as possible: the cursor is sent
+    /// home, then each row is written in full with an SGR sequence only
+    /// where the style changes from the previous cell, even across rows.
+    /// Rows are separated by `\n`. The result can be written verbatim to
+    /// another terminal to show the captured frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::style::Color;
+    ///
+    /// let mut backend = CaptureBackend::new(2, 2);
+    /// for (x, y) in [(0, 0), (1, 0), (0, 1)] {
+    ///     if let Some(cell) = backend.cell_mut(x, y) {
+    ///         cell.set_char('#');
+    ///         cell.fg = Color::Red.into();
+    ///     }
+    /// }
+    /// assert_eq!(
+    ///     backend.to_terminal_sequence(),
+    ///     "\x1b[H\x1b[0m\x1b[31m##\n#\x1b[0m "
+    /// );
+    /// ```
+    pub fn to_terminal_sequence(&self) -> String {
+        output::render_terminal_sequence(self)
+    }
+
+    /// Renders the frame history as an asciinema (asciicast v2) recording.
+    ///
+    /// Each recorded frame is shown for its entry in `frame_durations`;
+    /// see [`render_asciicast`](output::render_asciicast) for the details.
+    /// History must be enabled with [`with_history`](Self::with_history);
+    /// without it, the recording holds just the current frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::with_history(10, 1, 16)).unwrap();
+    /// for text in ["one", "two"] {
+    ///     terminal
+    ///         .draw(|frame| frame.render_widget(Paragraph::new(text), frame.area()))
+    ///         .unwrap();
+    /// }
+    ///
+    /// let cast = terminal.backend().to_asciicast(&[Duration::from_millis(500)]);
+    /// // A header, one event per frame and a final event at 1s
+    /// assert_eq!(cast.lines().count(), 4);
+    /// assert!(cast.ends_with("[1.000000, \"o\", \"\"]\n"));
+    /// ```
+    pub fn to_asciicast(&self, frame_durations: &[Duration]) -> String {
+        if self.history.is_empty() {
+            output::render_asciicast([&self.snapshot()], frame_durations)
+        } else {
+            output::render_asciicast(&self.history, frame_durations)
+        }
+    }
+
+    /// Renders the buffer with ANSI color codes using the given options.
+    ///
+    /// Use this to produce stable ANSI golden files, or to check how a frame
+    /// looks on a terminal with limited color support.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::{AnsiOptions, CaptureBackend, ColorDepth};
+    ///
+    /// let backend = CaptureBackend::new(10, 2);
+    /// let options = AnsiOptions::new()
+    ///     .with_color_depth(ColorDepth::Ansi16)
+    ///     .with_trailing_reset(true);
+    /// assert!(backend.to_ansi_with(&options).ends_with("\x1b[0m"));
+    /// ```
+    pub fn to_ansi_with(&self, options: &AnsiOptions) -> String {
+        output::render_ansi_with(self, options)
+    }
+
+    /// Renders the buffer as a GitHub-flavored markdown code block.
+    ///
+    /// The block holds the same text as `to_string()`, so a frame can be
+    /// pasted into an issue without a screenshot. Since code blocks are
+    /// monochrome, if the frame uses color a legend follows the block,
+    /// listing each foreground and background combination with the rows,
+    /// columns and text of the cells drawn in it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::style::Color;
+    ///
+    /// let mut backend = CaptureBackend::new(6, 1);
+    /// for (x, c) in "failed".chars().enumerate() {
+    ///     if let Some(cell) = backend.cell_mut(x as u16, 0) {
+    ///         cell.set_char(c);
+    ///         cell.fg = Color::Red.into();
+    ///     }
+    /// }
+    /// assert_eq!(
+    ///     backend.to_markdown(),
+    ///     "```text\nfailed\n```\n\n\
+    ///      Colors (rows and columns from 0):\n\n\
+    ///      - `Red` text: row 0, cols 0-5 `failed`\n"
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        output::render_markdown(self)
+    }
+
     /// Creates an [`AnnotatedOutput`] combining the visual text and structured annotations.
     ///
     /// This pairs the plain text representation of the current buffer with
@@ -495,6 +1070,38 @@ impl CaptureBackend {
         self.render(OutputFormat::JsonPretty)
     }
 
+    /// Renders the buffer as a PNG image.
+    ///
+    /// Each cell becomes a rectangle `cell_px` pixels wide and `2 * cell_px`
+    /// tall, painted from its foreground and background colors. Block
+    /// elements such as `▀`, `▄` and the eighth blocks used by charts paint
+    /// exactly the part of the cell they cover; other symbols are
+    /// approximated by filling the cell with the foreground color. Reset
+    /// colors use a light gray foreground on black.
+    ///
+    /// Unlike SVG, the result can be embedded where only bitmaps are shown.
+    /// Returns an empty vector if the buffer or `cell_px` is zero-sized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the image dimensions overflow, i.e. `cell_px` is
+    /// too large for the buffer size, or if PNG encoding fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    ///
+    /// let backend = CaptureBackend::new(4, 2);
+    /// let png = backend.to_half_block_png(4)?;
+    /// assert!(png.starts_with(b"\x89PNG"));
+    /// # Ok::<(), envision::EnvisionError>(())
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn to_half_block_png(&self, cell_px: u32) -> crate::error::Result<Vec<u8>> {
+        output::png::render(self, cell_px)
+    }
+
     /// Converts (x, y) coordinates to a linear index.
     fn index_of(&self, x: u16, y: u16) -> usize {
         (y as usize) * (self.width as usize) + (x as usize)
@@ -510,6 +1117,37 @@ impl CaptureBackend {
         }
     }
 
+    /// Returns the bounding rectangle of the cells written by the most
+    /// recent [`Backend::draw`] call.
+    ///
+    /// ratatui only passes cells that changed since the previous frame, so
+    /// this is the area a frame actually updated. Returns `None` before the
+    /// first draw and after a draw that wrote no cells.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(20, 5)).unwrap();
+    /// terminal.draw(|frame| {
+    ///     frame.render_widget(Paragraph::new("Hi"), Rect::new(3, 2, 5, 1));
+    /// }).unwrap();
+    /// assert_eq!(terminal.backend().last_damage(), Some(Rect::new(3, 2, 2, 1)));
+    ///
+    /// // Drawing the same frame again changes nothing.
+    /// terminal.draw(|frame| {
+    ///     frame.render_widget(Paragraph::new("Hi"), Rect::new(3, 2, 5, 1));
+    /// }).unwrap();
+    /// assert_eq!(terminal.backend().last_damage(), None);
+    /// ```
+    pub fn last_damage(&self) -> Option<Rect> {
+        self.last_damage
+    }
+
     /// Returns the width of the terminal.
     pub fn width(&self) -> u16 {
         self.width
@@ -520,6 +1158,23 @@ impl CaptureBackend {
         self.height
     }
 
+    /// Returns how wide East Asian Ambiguous-width characters are taken
+    /// to be.
+    pub fn ambiguous_width(&self) -> AmbiguousWidth {
+        self.ambiguous_width
+    }
+
+    /// Sets how wide East Asian Ambiguous-width characters are taken to
+    /// be. Cells already drawn are not re-measured.
+    pub fn set_ambiguous_width(&mut self, ambiguous_width: AmbiguousWidth) {
+        self.ambiguous_width = ambiguous_width;
+    }
+
+    /// Returns the number of columns `cell`'s symbol covers.
+    fn symbol_width(&self, cell: &EnhancedCell) -> usize {
+        self.ambiguous_width.str_width(cell.symbol())
+    }
+
     /// Returns whether the cursor is currently visible.
     pub fn is_cursor_visible(&self) -> bool {
         self.cursor_visible
@@ -529,6 +1184,333 @@ impl CaptureBackend {
     pub fn cursor_position(&self) -> Position {
         self.cursor_position
     }
+
+    /// Returns the cursor shape set with
+    /// [`set_cursor_shape`](Self::set_cursor_shape), or `None` for the
+    /// terminal's default shape.
+    pub fn cursor_shape(&self) -> Option<CursorShape> {
+        self.cursor_shape
+    }
+
+    /// Records the cursor shape, or `None` to restore the terminal default.
+    ///
+    /// ratatui has no cursor shape command, so the shape is set here
+    /// directly, mirroring a crossterm [`SetCursorStyle`] on a real
+    /// terminal. It is kept across frames and included in snapshots.
+    ///
+    /// [`SetCursorStyle`]: crossterm::cursor::SetCursorStyle
+    pub fn set_cursor_shape(&mut self, shape: Option<CursorShape>) {
+        self.cursor_shape = shape;
+    }
+
+    /// Returns the window title set with [`set_title`](Self::set_title),
+    /// or `None` if no title was set.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Records the window title.
+    ///
+    /// This mirrors the OSC title sequence a real terminal receives, so
+    /// tests can assert on titles set with
+    /// [`Command::set_title`](crate::app::Command::set_title).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    ///
+    /// let mut backend = CaptureBackend::new(20, 2);
+    /// assert_eq!(backend.title(), None);
+    /// backend.set_title("notes.md");
+    /// assert_eq!(backend.title(), Some("notes.md"));
+    /// ```
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = Some(title.into());
+    }
+
+    /// Returns the cursor position if the cursor is visible.
+    ///
+    /// After a `Terminal::draw`, this is the position passed to
+    /// `Frame::set_cursor_position`, or `None` if the frame did not place
+    /// the cursor (ratatui hides it in that case).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(10, 3)).unwrap();
+    /// terminal.draw(|frame| frame.set_cursor_position((4, 1))).unwrap();
+    /// assert_eq!(
+    ///     terminal.backend().visible_cursor(),
+    ///     Some(ratatui::layout::Position::new(4, 1))
+    /// );
+    ///
+    /// terminal.draw(|_frame| {}).unwrap();
+    /// assert_eq!(terminal.backend().visible_cursor(), None);
+    /// ```
+    pub fn visible_cursor(&self) -> Option<Position> {
+        self.cursor_visible.then_some(self.cursor_position)
+    }
+
+    /// Checks that the buffer is internally consistent.
+    ///
+    /// This is an invariant check for tests: call it after each `draw` to
+    /// catch custom widgets that leave the screen in a state a real
+    /// terminal would render differently. It reports:
+    ///
+    /// - continuation cells not covered by a wide glyph to their left
+    ///   ([`CellError::OrphanContinuation`]),
+    /// - wide glyphs whose covered cells are not continuations, or that
+    ///   run past the right edge ([`CellError::TornWideChar`]),
+    /// - zero-width symbols, such as a lone combining mark, in a cell of
+    ///   their own ([`CellError::ZeroWidthSymbol`]),
+    /// - a visible cursor outside the buffer
+    ///   ([`CellError::CursorOutOfBounds`]). A hidden cursor may be
+    ///   anywhere.
+    ///
+    /// Cells marked as skipped are ignored. Errors are listed in row-major
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::{CaptureBackend, CellError};
+    /// use ratatui::Terminal;
+    /// use ratatui::layout::Position;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(10, 1)).unwrap();
+    /// terminal.draw(|frame| frame.render_widget(Paragraph::new("日本"), frame.area())).unwrap();
+    /// assert_eq!(terminal.backend().validate(), Ok(()));
+    ///
+    /// // Overwrite the right half of "日" without touching its left half.
+    /// terminal.backend_mut().cell_mut(1, 0).unwrap().set_char('x');
+    /// assert_eq!(
+    ///     terminal.backend().validate(),
+    ///     Err(vec![CellError::TornWideChar { position: Position::new(0, 0) }])
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<CellError>> {
+        let mut errors = Vec::new();
+        for y in 0..self.height {
+            // Columns still covered by the last wide glyph on this row.
+            let mut covered = 0;
+            for x in 0..self.width {
+                let cell = &self.cells[self.index_of(x, y)];
+                let position = Position::new(x, y);
+                if cell.skip {
+                    covered = 0;
+                    continue;
+                }
+                if cell.is_continuation() {
+                    if covered == 0 {
+                        errors.push(CellError::OrphanContinuation { position });
+                    } else {
+                        covered -= 1;
+                    }
+                    continue;
+                }
+                if covered > 0 {
+                    // The previous wide glyph's tail was overwritten; it
+                    // has already been reported.
+                    covered = 0;
+                }
+
+                let width = self.symbol_width(cell);
+                if width == 0 {
+                    errors.push(CellError::ZeroWidthSymbol {
+                        position,
+                        symbol: cell.symbol().to_string(),
+                    });
+                    continue;
+                }
+                let tail = width - 1;
+                let fits = usize::from(x) + width <= usize::from(self.width);
+                let paired = (1..=tail).all(|offset| {
+                    let next = usize::from(x) + offset;
+                    next < usize::from(self.width)
+                        && self.cells[self.index_of(next as u16, y)].is_continuation()
+                });
+                if !fits || !paired {
+                    errors.push(CellError::TornWideChar { position });
+                }
+                covered = tail;
+            }
+        }
+
+        if self.cursor_visible
+            && (self.cursor_position.x >= self.width || self.cursor_position.y >= self.height)
+        {
+            errors.push(CellError::CursorOutOfBounds {
+                position: self.cursor_position,
+            });
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Marks a named region of the screen.
+    ///
+    /// Marking a name that already exists moves that region. Regions
+    /// persist across frames until [`clear_regions`](Self::clear_regions)
+    /// is called.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(20, 2)).unwrap();
+    /// terminal.draw(|frame| {
+    ///     frame.render_widget(Paragraph::new("Body"), Rect::new(0, 0, 20, 1));
+    ///     frame.render_widget(Paragraph::new("Ready"), Rect::new(0, 1, 20, 1));
+    /// }).unwrap();
+    ///
+    /// let backend = terminal.backend_mut();
+    /// backend.mark_region("body", Rect::new(0, 0, 20, 1));
+    /// backend.mark_region("status", Rect::new(0, 1, 20, 1));
+    /// assert_eq!(
+    ///     backend.regions(),
+    ///     vec![
+    ///         ("body".to_string(), "Body".to_string()),
+    ///         ("status".to_string(), "Ready".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn mark_region(&mut self, name: impl Into<String>, area: Rect) {
+        let name = name.into();
+        match self.regions.iter_mut().find(|(n, _)| *n == name) {
+            Some(region) => region.1 = area,
+            None => self.regions.push((name, area)),
+        }
+    }
+
+    /// Marks a region for every annotation in `registry` that has an ID.
+    ///
+    /// Use this with [`with_annotations`](crate::annotation::with_annotations)
+    /// so widgets rendered through [`Annotate`](crate::annotation::Annotate)
+    /// mark their own regions during `view`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::annotation::{Annotate, Annotation, with_annotations};
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(20, 1)).unwrap();
+    /// let registry = with_annotations(|| {
+    ///     terminal.draw(|frame| {
+    ///         let widget = Annotate::new(Paragraph::new("Ready"), Annotation::container("status"));
+    ///         frame.render_widget(widget, frame.area());
+    ///     }).unwrap();
+    /// });
+    ///
+    /// let backend = terminal.backend_mut();
+    /// backend.mark_annotated_regions(&registry);
+    /// assert_eq!(backend.regions(), vec![("status".to_string(), "Ready".to_string())]);
+    /// ```
+    pub fn mark_annotated_regions(&mut self, registry: &crate::annotation::AnnotationRegistry) {
+        for region in registry.regions() {
+            if let Some(id) = &region.annotation.id {
+                self.mark_region(id.clone(), region.area.into());
+            }
+        }
+    }
+
+    /// Removes all marked regions.
+    pub fn clear_regions(&mut self) {
+        self.regions.clear();
+    }
+
+    /// Returns the names and areas of all marked regions, in marking order.
+    pub fn marked_regions(&self) -> &[(String, Rect)] {
+        &self.regions
+    }
+
+    /// Returns the name and current text of every marked region, in
+    /// marking order.
+    ///
+    /// See [`region_text`](Self::region_text) for how the text is extracted.
+    pub fn regions(&self) -> Vec<(String, String)> {
+        self.regions
+            .iter()
+            .map(|(name, area)| (name.clone(), self.region_text(*area)))
+            .collect()
+    }
+
+    /// Returns the text within `area`, one line per row.
+    ///
+    /// The area is clipped to the screen, and trailing whitespace is
+    /// trimmed from each line.
+    pub fn region_text(&self, area: Rect) -> String {
+        self.region_lines(area).join("\n")
+    }
+
+    /// Returns the rows within `area` as strings with trailing whitespace
+    /// trimmed.
+    ///
+    /// The area is clipped to the screen, so an area partly off screen
+    /// yields only its visible rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(12, 2)).unwrap();
+    /// terminal
+    ///     .draw(|frame| {
+    ///         frame.render_widget(Paragraph::new("menu  body\n      more"), frame.area())
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let body = Rect::new(6, 0, 6, 10);
+    /// assert_eq!(terminal.backend().region_lines(body), vec!["body", "more"]);
+    /// ```
+    pub fn region_lines(&self, area: Rect) -> Vec<String> {
+        let area = self.clip(area);
+        if area.is_empty() {
+            return Vec::new();
+        }
+        (area.top()..area.bottom())
+            .map(|y| {
+                let start = self.index_of(area.x, y);
+                let end = start + area.width as usize;
+                let line: String = self.cells[start..end].iter().map(|c| c.symbol()).collect();
+                line.trim_end().to_string()
+            })
+            .collect()
+    }
+
+    /// Clips `area` to the screen, or returns an empty rect if they don't
+    /// overlap.
+    ///
+    /// `Rect::intersection` keeps the offset of an area past the right or
+    /// bottom edge and only zeroes its size, which would index past the
+    /// buffer.
+    fn clip(&self, area: Rect) -> Rect {
+        let clipped = area.intersection(Rect::new(0, 0, self.width, self.height));
+        if clipped.is_empty() {
+            Rect::default()
+        } else {
+            clipped
+        }
+    }
 }
 
 impl Backend for CaptureBackend {
@@ -536,12 +1518,27 @@ impl Backend for CaptureBackend {
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
+        let mut damage: Option<Rect> = None;
         for (x, y, cell) in content {
             if x < self.width && y < self.height {
                 let idx = self.index_of(x, y);
-                self.cells[idx] = EnhancedCell::from_ratatui_cell(cell, self.current_frame);
+                let cell = EnhancedCell::from_ratatui_cell(cell, self.current_frame);
+
+                // ratatui doesn't send the cells hidden behind a wide glyph,
+                // so mark them as continuations rather than keep stale content.
+                let span = (self.symbol_width(&cell) as u16).clamp(1, self.width - x);
+                for offset in 1..span as usize {
+                    let mut continuation = cell.clone();
+                    continuation.set_symbol("");
+                    self.cells[idx + offset] = continuation;
+                }
+                self.cells[idx] = cell;
+
+                let touched = Rect::new(x, y, span, 1);
+                damage = Some(damage.map_or(touched, |area| area.union(touched)));
             }
         }
+        self.last_damage = damage;
         Ok(())
     }
 
@@ -628,6 +1625,22 @@ impl fmt::Display for CaptureBackend {
     }
 }
 
+/// Splits a line into display columns, so wide characters occupy one
+/// entry per column they cover and zero-width characters none.
+fn display_columns(line: &str, ambiguous_width: AmbiguousWidth) -> Vec<Option<char>> {
+    let mut columns = Vec::new();
+    for ch in line.chars() {
+        if ambiguous_width.char_width(ch) == Some(0) {
+            continue;
+        }
+        columns.push(Some(ch));
+        for _ in 1..ambiguous_width.char_width(ch).unwrap_or(0) {
+            columns.push(None);
+        }
+    }
+    columns
+}
+
 /// Represents the difference between two frames.
 #[derive(Clone, Debug)]
 #[cfg_attr(
@@ -689,6 +1702,119 @@ impl fmt::Display for FrameDiff {
     }
 }
 
+/// FNV-1a hasher with platform-independent integer encoding.
+///
+/// `std`'s default hasher is not guaranteed to be stable across releases,
+/// and the default `Hasher` integer methods use native endianness.
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as u64);
+    }
+}
+
+/// A regular expression match found by [`CaptureBackend::find_regex`].
+#[cfg(feature = "regex")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextMatch {
+    /// Cell position where the match starts.
+    pub position: Position,
+    /// Number of columns the match spans.
+    pub width: u16,
+    /// The matched text.
+    pub text: String,
+}
+
+/// An inconsistency found by [`CaptureBackend::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CellError {
+    /// A continuation cell that no wide glyph to its left covers.
+    OrphanContinuation {
+        /// The continuation cell.
+        position: Position,
+    },
+    /// A wide glyph whose covered cells are not all continuations, or
+    /// that runs past the right edge of the buffer.
+    TornWideChar {
+        /// The cell holding the wide glyph.
+        position: Position,
+    },
+    /// A cell whose symbol has no display width, such as a lone
+    /// combining mark.
+    ZeroWidthSymbol {
+        /// The offending cell.
+        position: Position,
+        /// The zero-width symbol.
+        symbol: String,
+    },
+    /// The cursor is visible but outside the buffer.
+    CursorOutOfBounds {
+        /// The cursor position.
+        position: Position,
+    },
+}
+
+impl fmt::Display for CellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OrphanContinuation { position } => write!(
+                f,
+                "continuation cell at ({}, {}) has no wide glyph",
+                position.x, position.y
+            ),
+            Self::TornWideChar { position } => write!(
+                f,
+                "wide glyph at ({}, {}) is missing its continuation cells",
+                position.x, position.y
+            ),
+            Self::ZeroWidthSymbol { position, symbol } => write!(
+                f,
+                "zero-width symbol {symbol:?} at ({}, {})",
+                position.x, position.y
+            ),
+            Self::CursorOutOfBounds { position } => write!(
+                f,
+                "cursor at ({}, {}) is outside the buffer",
+                position.x, position.y
+            ),
+        }
+    }
+}
+
 /// A single cell change in a diff.
 #[derive(Clone, Debug)]
 #[cfg_attr(
@@ -309,6 +309,109 @@ fn test_content_lines() {
     assert!(lines[0].starts_with("AAA"));
 }
 
+#[test]
+fn test_lines_trims_trailing_spaces() {
+    let mut backend = CaptureBackend::new(6, 3);
+    for (i, c) in "A B".chars().enumerate() {
+        if let Some(cell) = backend.cell_mut(i as u16, 1) {
+            cell.set_char(c);
+        }
+    }
+
+    assert_eq!(backend.lines(), vec!["", "A B", ""]);
+    assert_eq!(
+        backend.lines().join("\n"),
+        backend.to_string_with(TrimMode::TrailingSpaces)
+    );
+}
+
+#[test]
+fn test_lines_with_modes() {
+    let mut backend = CaptureBackend::new(3, 3);
+    if let Some(cell) = backend.cell_mut(0, 0) {
+        cell.set_char('x');
+    }
+
+    assert_eq!(backend.lines_with(TrimMode::None), backend.content_lines());
+    assert_eq!(
+        backend.lines_with(TrimMode::TrailingSpaces),
+        vec!["x", "", ""]
+    );
+    assert_eq!(backend.lines_with(TrimMode::TrailingBlankLines), vec!["x"]);
+    assert!(
+        CaptureBackend::new(3, 2)
+            .lines_with(TrimMode::TrailingBlankLines)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_line() {
+    let mut backend = CaptureBackend::new(5, 2);
+    for (i, c) in "Hi".chars().enumerate() {
+        if let Some(cell) = backend.cell_mut(i as u16 + 1, 0) {
+            cell.set_char(c);
+        }
+    }
+
+    assert_eq!(backend.line(0).as_deref(), Some(" Hi"));
+    assert_eq!(backend.line(1).as_deref(), Some(""));
+    assert_eq!(backend.line(2), None);
+}
+
+#[test]
+fn test_content_hash_equal_for_equal_content() {
+    let mut a = CaptureBackend::new(4, 2);
+    let mut b = CaptureBackend::new(4, 2);
+    assert_eq!(a.content_hash(), b.content_hash());
+
+    a.cell_mut(1, 1).unwrap().set_char('x');
+    assert_ne!(a.content_hash(), b.content_hash());
+
+    b.cell_mut(1, 1).unwrap().set_char('x');
+    b.cell_mut(1, 1).unwrap().last_modified_frame = 7;
+    assert_eq!(a.content_hash(), b.content_hash());
+}
+
+#[test]
+fn test_content_hash_detects_style_only_change() {
+    let mut backend = CaptureBackend::new(4, 2);
+    backend.cell_mut(0, 0).unwrap().set_char('x');
+    let before = backend.content_hash();
+
+    backend.cell_mut(0, 0).unwrap().fg = crate::backend::cell::SerializableColor::Red;
+    let fg_changed = backend.content_hash();
+    assert_ne!(fg_changed, before);
+
+    backend.cell_mut(0, 0).unwrap().modifiers.bold = true;
+    assert_ne!(backend.content_hash(), fg_changed);
+}
+
+#[test]
+fn test_content_hash_includes_size() {
+    assert_ne!(
+        CaptureBackend::new(4, 2).content_hash(),
+        CaptureBackend::new(2, 4).content_hash()
+    );
+}
+
+#[test]
+fn test_content_hash_ignores_cursor() {
+    let mut backend = CaptureBackend::new(4, 2);
+    let before = backend.content_hash();
+    backend.set_cursor_position(Position::new(2, 1)).unwrap();
+    backend.show_cursor().unwrap();
+    assert_eq!(backend.content_hash(), before);
+}
+
+#[test]
+fn test_content_hash_is_stable() {
+    assert_eq!(
+        CaptureBackend::new(2, 1).content_hash(),
+        14528504812091190776
+    );
+}
+
 #[cfg(feature = "serialization")]
 #[test]
 fn test_to_json() {
@@ -495,6 +598,38 @@ fn test_is_cursor_visible() {
     assert!(backend.is_cursor_visible());
 }
 
+#[test]
+fn test_cursor_shape() {
+    let mut backend = CaptureBackend::new(80, 24);
+    assert_eq!(backend.cursor_shape(), None);
+    assert_eq!(backend.snapshot().cursor.shape, None);
+
+    backend.set_cursor_shape(Some(CursorShape::BlinkingBar));
+    backend.flush().unwrap();
+    assert_eq!(backend.cursor_shape(), Some(CursorShape::BlinkingBar));
+    assert_eq!(
+        backend.snapshot().cursor.shape,
+        Some(CursorShape::BlinkingBar)
+    );
+
+    backend.set_cursor_shape(None);
+    assert_eq!(backend.cursor_shape(), None);
+}
+
+#[test]
+fn test_cursor_shape_into_crossterm() {
+    use crossterm::cursor::SetCursorStyle;
+
+    assert!(matches!(
+        SetCursorStyle::from(CursorShape::SteadyUnderline),
+        SetCursorStyle::SteadyUnderScore
+    ));
+    assert!(matches!(
+        SetCursorStyle::from(CursorShape::BlinkingBlock),
+        SetCursorStyle::BlinkingBlock
+    ));
+}
+
 #[test]
 fn test_cursor_position_accessor() {
     let mut backend = CaptureBackend::new(80, 24);
@@ -628,3 +763,668 @@ fn test_to_ansi_method() {
     let ansi = backend.to_ansi();
     assert!(ansi.contains("T"));
 }
+
+#[test]
+fn test_region_text_clips_and_trims() {
+    let mut backend = CaptureBackend::new(6, 2);
+    for (x, ch) in "ab cd".chars().enumerate() {
+        backend.cell_mut(x as u16, 1).unwrap().set_char(ch);
+    }
+
+    assert_eq!(backend.region_text(Rect::new(0, 0, 6, 2)), "\nab cd");
+    assert_eq!(backend.region_text(Rect::new(3, 1, 10, 10)), "cd");
+    assert_eq!(backend.region_text(Rect::new(10, 10, 2, 2)), "");
+}
+
+#[test]
+fn test_region_text_off_screen() {
+    let backend = CaptureBackend::new(10, 2);
+
+    // Right of the screen, within its rows
+    assert_eq!(backend.region_text(Rect::new(15, 1, 5, 1)), "");
+    // Below the screen, within its columns
+    assert_eq!(backend.region_text(Rect::new(2, 5, 5, 1)), "");
+}
+
+#[test]
+fn test_regions_with_area_off_screen() {
+    let mut backend = CaptureBackend::new(10, 2);
+    backend.mark_region("right", Rect::new(15, 1, 5, 1));
+    backend.mark_region("below", Rect::new(2, 5, 5, 1));
+
+    assert_eq!(
+        backend.regions(),
+        vec![
+            ("right".to_string(), String::new()),
+            ("below".to_string(), String::new()),
+        ]
+    );
+}
+
+#[test]
+fn test_region_lines_clips_and_trims() {
+    let mut backend = CaptureBackend::new(6, 2);
+    for (x, ch) in "ab cd".chars().enumerate() {
+        backend.cell_mut(x as u16, 1).unwrap().set_char(ch);
+    }
+
+    assert_eq!(
+        backend.region_lines(Rect::new(0, 0, 6, 2)),
+        vec!["", "ab cd"]
+    );
+    assert_eq!(backend.region_lines(Rect::new(1, 1, 2, 5)), vec!["b"]);
+    assert!(backend.region_lines(Rect::new(10, 10, 2, 2)).is_empty());
+    assert!(backend.region_lines(Rect::new(8, 1, 3, 1)).is_empty());
+    assert!(backend.region_lines(Rect::new(1, 4, 3, 1)).is_empty());
+}
+
+#[test]
+fn test_find_text_in_region() {
+    let mut backend = CaptureBackend::new(10, 2);
+    for (x, ch) in "ok  ok".chars().enumerate() {
+        backend.cell_mut(x as u16, 0).unwrap().set_char(ch);
+    }
+    backend.cell_mut(4, 1).unwrap().set_char('o');
+    backend.cell_mut(5, 1).unwrap().set_char('k');
+
+    assert_eq!(
+        backend.find_text_in(Rect::new(3, 0, 7, 2), "ok"),
+        vec![Position::new(4, 0), Position::new(4, 1)]
+    );
+    // Text straddling the edge of the region does not match
+    assert_eq!(
+        backend.find_text_in(Rect::new(0, 0, 5, 1), "ok"),
+        vec![Position::new(0, 0)]
+    );
+    assert!(backend.contains_text_in(Rect::new(4, 1, 20, 20), "ok"));
+    assert!(!backend.contains_text_in(Rect::new(0, 1, 4, 1), "ok"));
+    assert!(!backend.contains_text_in(Rect::new(20, 20, 2, 2), "ok"));
+    assert!(!backend.contains_text_in(Rect::new(12, 1, 4, 1), "ok"));
+}
+
+#[test]
+fn test_mark_region_replaces_existing_name() {
+    let mut backend = CaptureBackend::new(4, 2);
+    backend.cell_mut(0, 0).unwrap().set_char('A');
+    backend.cell_mut(0, 1).unwrap().set_char('B');
+
+    backend.mark_region("top", Rect::new(0, 0, 4, 1));
+    backend.mark_region("other", Rect::new(0, 1, 4, 1));
+    backend.mark_region("top", Rect::new(0, 1, 4, 1));
+
+    assert_eq!(backend.marked_regions().len(), 2);
+    assert_eq!(
+        backend.regions(),
+        vec![
+            ("top".to_string(), "B".to_string()),
+            ("other".to_string(), "B".to_string()),
+        ]
+    );
+
+    backend.clear_regions();
+    assert!(backend.regions().is_empty());
+}
+
+#[test]
+fn test_mark_annotated_regions_skips_annotations_without_id() {
+    use crate::annotation::{Annotate, Annotation, with_annotations};
+    use ratatui::Terminal;
+    use ratatui::widgets::Paragraph;
+
+    let mut terminal = Terminal::new(CaptureBackend::new(10, 2)).unwrap();
+    let registry = with_annotations(|| {
+        terminal
+            .draw(|frame| {
+                frame.render_widget(
+                    Annotate::new(Paragraph::new("List"), Annotation::list("items")),
+                    Rect::new(0, 0, 10, 1),
+                );
+                frame.render_widget(
+                    Annotate::new(Paragraph::new("Hint"), Annotation::label("hint")),
+                    Rect::new(0, 1, 10, 1),
+                );
+            })
+            .unwrap();
+    });
+
+    let backend = terminal.backend_mut();
+    backend.mark_annotated_regions(&registry);
+    assert_eq!(
+        backend.regions(),
+        vec![("items".to_string(), "List".to_string())]
+    );
+}
+
+#[test]
+fn test_visual_diff_marks_changed_columns() {
+    let mut backend = CaptureBackend::new(8, 3);
+    for (x, ch) in "abcd".chars().enumerate() {
+        backend.cell_mut(x as u16, 0).unwrap().set_char(ch);
+    }
+    for (x, ch) in "xyz".chars().enumerate() {
+        backend.cell_mut(x as u16, 1).unwrap().set_char(ch);
+    }
+
+    assert_eq!(backend.visual_diff("abcd\nxyz"), None);
+    assert_eq!(backend.visual_diff("abcd   \nxyz\n\n"), None);
+
+    let diff = backend.visual_diff("aXcd\nxyz!").unwrap();
+    assert_eq!(
+        diff,
+        "row 0:\n- aXcd\n+ abcd\n   ^\nrow 1:\n- xyz!\n+ xyz\n     ^\n"
+    );
+}
+
+#[test]
+fn test_visual_diff_reports_extra_expected_rows() {
+    let backend = CaptureBackend::new(4, 1);
+    let diff = backend.visual_diff("\nab").unwrap();
+    assert_eq!(diff, "row 1:\n- ab\n+ \n  ^^\n");
+}
+
+#[test]
+fn test_visual_diff_aligns_wide_characters() {
+    let mut terminal = ratatui::Terminal::new(CaptureBackend::new(6, 1)).unwrap();
+    terminal
+        .draw(|frame| {
+            frame.render_widget(ratatui::widgets::Paragraph::new("日本x"), frame.area());
+        })
+        .unwrap();
+
+    assert_eq!(terminal.backend().visual_diff("日本x"), None);
+    let diff = terminal.backend().visual_diff("日本y").unwrap();
+    assert_eq!(diff, "row 0:\n- 日本y\n+ 日本x\n      ^\n");
+}
+
+#[test]
+fn test_visual_diff_skips_zero_width_characters() {
+    let mut backend = CaptureBackend::new(6, 1);
+    for (x, ch) in "abc".chars().enumerate() {
+        backend.cell_mut(x as u16, 0).unwrap().set_char(ch);
+    }
+
+    // The combining accent takes no column, so only the last one differs
+    let diff = backend.visual_diff("a\u{301}bd").unwrap();
+    assert!(diff.ends_with("\n    ^\n"), "{diff:?}");
+}
+
+#[test]
+fn test_last_damage_tracks_most_recent_draw() {
+    let mut backend = CaptureBackend::new(10, 5);
+    assert_eq!(backend.last_damage(), None);
+
+    let cell = Cell::new("x");
+    backend
+        .draw([(2, 1, &cell), (6, 3, &cell), (4, 2, &cell)].into_iter())
+        .unwrap();
+    assert_eq!(backend.last_damage(), Some(Rect::new(2, 1, 5, 3)));
+
+    backend.draw([(9, 4, &cell)].into_iter()).unwrap();
+    assert_eq!(backend.last_damage(), Some(Rect::new(9, 4, 1, 1)));
+
+    backend.draw(std::iter::empty()).unwrap();
+    assert_eq!(backend.last_damage(), None);
+}
+
+#[test]
+fn test_last_damage_ignores_out_of_bounds_cells() {
+    let mut backend = CaptureBackend::new(4, 2);
+    let cell = Cell::new("x");
+    backend
+        .draw([(10, 10, &cell), (1, 1, &cell)].into_iter())
+        .unwrap();
+    assert_eq!(backend.last_damage(), Some(Rect::new(1, 1, 1, 1)));
+}
+
+fn draw_text(backend: CaptureBackend, text: &str) -> ratatui::Terminal<CaptureBackend> {
+    let mut terminal = ratatui::Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| {
+            frame.render_widget(ratatui::widgets::Paragraph::new(text), frame.area());
+        })
+        .unwrap();
+    terminal
+}
+
+#[test]
+fn test_wide_characters_occupy_continuation_cells() {
+    let terminal = draw_text(CaptureBackend::new(6, 1), "日本x");
+    let backend = terminal.backend();
+
+    assert_eq!(backend.cell(0, 0).unwrap().symbol(), "日");
+    assert!(backend.cell(1, 0).unwrap().is_continuation());
+    assert_eq!(backend.cell(2, 0).unwrap().symbol(), "本");
+    assert!(backend.cell(3, 0).unwrap().is_continuation());
+    assert_eq!(backend.cell(4, 0).unwrap().symbol(), "x");
+    assert!(!backend.cell(4, 0).unwrap().is_continuation());
+}
+
+#[test]
+fn test_wide_characters_not_double_printed() {
+    let terminal = draw_text(CaptureBackend::new(6, 2), "日本x\n🦀ok");
+    assert_eq!(terminal.backend().to_string(), "日本x \n🦀ok  ");
+}
+
+#[test]
+fn test_wide_character_replaces_stale_content() {
+    let mut terminal = draw_text(CaptureBackend::new(4, 1), "abcd");
+    terminal
+        .draw(|frame| {
+            frame.render_widget(ratatui::widgets::Paragraph::new("世cd"), frame.area());
+        })
+        .unwrap();
+    assert_eq!(terminal.backend().to_string(), "世cd");
+
+    terminal
+        .draw(|frame| {
+            frame.render_widget(ratatui::widgets::Paragraph::new("abcd"), frame.area());
+        })
+        .unwrap();
+    assert_eq!(terminal.backend().to_string(), "abcd");
+    assert!(!terminal.backend().cell(1, 0).unwrap().is_continuation());
+}
+
+#[test]
+fn test_find_text_reports_columns_after_wide_characters() {
+    let terminal = draw_text(CaptureBackend::new(12, 2), "日本 hello\nhéllo");
+    let backend = terminal.backend();
+
+    assert_eq!(backend.find_text("hello"), vec![Position::new(5, 0)]);
+    assert_eq!(backend.find_text("本"), vec![Position::new(2, 0)]);
+    assert_eq!(
+        backend.find_text("llo"),
+        vec![Position::new(7, 0), Position::new(2, 1)]
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_find_regex_reports_positions_and_text() {
+    let terminal = draw_text(CaptureBackend::new(16, 2), "took 12ms\nretry 3 of 5");
+    let backend = terminal.backend();
+
+    let matches = backend.find_regex(r"\d+").unwrap();
+    let found: Vec<(Position, u16, &str)> = matches
+        .iter()
+        .map(|m| (m.position, m.width, m.text.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (Position::new(5, 0), 2, "12"),
+            (Position::new(6, 1), 1, "3"),
+            (Position::new(11, 1), 1, "5"),
+        ]
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_find_regex_counts_wide_character_columns() {
+    let terminal = draw_text(CaptureBackend::new(12, 1), "日本 hello");
+    let backend = terminal.backend();
+
+    let matches = backend.find_regex("本 h").unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].position, Position::new(2, 0));
+    assert_eq!(matches[0].width, 4);
+
+    let matches = backend.find_regex("hel+o").unwrap();
+    assert_eq!(matches[0].position, Position::new(5, 0));
+    assert_eq!(matches[0].width, 5);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_find_regex_does_not_span_rows() {
+    let terminal = draw_text(CaptureBackend::new(5, 2), "ab\ncd");
+    let backend = terminal.backend();
+
+    assert!(backend.find_regex(r"b\s+c").unwrap().is_empty());
+    // Trailing padding belongs to the row
+    let matches = backend.find_regex(r"b +$").unwrap();
+    assert_eq!(matches[0].width, 4);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_find_regex_invalid_pattern() {
+    let backend = CaptureBackend::new(5, 1);
+    assert!(backend.find_regex("(unclosed").is_err());
+}
+
+#[test]
+fn test_style_grid_projects_cell_styles() {
+    use crate::backend::cell::{SerializableColor, SerializableModifier};
+
+    let mut backend = CaptureBackend::new(3, 2);
+    if let Some(cell) = backend.cell_mut(1, 0) {
+        cell.set_char('E');
+        cell.fg = SerializableColor::Red;
+        cell.modifiers = SerializableModifier {
+            bold: true,
+            ..Default::default()
+        };
+    }
+    if let Some(cell) = backend.cell_mut(2, 1) {
+        cell.bg = SerializableColor::Blue;
+    }
+
+    let grid = backend.style_grid();
+    assert_eq!(grid.len(), 2);
+    assert!(grid.iter().all(|row| row.len() == 3));
+
+    assert_eq!(grid[1][2].bg, SerializableColor::Blue);
+    assert_eq!(grid[0][1].fg, SerializableColor::Red);
+    assert!(grid[0][1].modifiers.bold);
+
+    // Red appears only at (1, 0).
+    let red: Vec<(usize, usize)> = grid
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, style)| style.fg == SerializableColor::Red)
+                .map(move |(x, _)| (x, y))
+        })
+        .collect();
+    assert_eq!(red, vec![(1, 0)]);
+}
+
+#[test]
+fn test_style_grid_zero_size() {
+    assert!(CaptureBackend::new(0, 0).style_grid().is_empty());
+    assert_eq!(CaptureBackend::new(0, 2).style_grid(), vec![vec![], vec![]]);
+}
+
+#[test]
+fn test_style_at_cursor() {
+    use crate::backend::cell::SerializableColor;
+
+    let mut backend = CaptureBackend::new(5, 2);
+    if let Some(cell) = backend.cell_mut(2, 1) {
+        cell.bg = SerializableColor::Yellow;
+    }
+    backend.hide_cursor().unwrap();
+    assert_eq!(backend.style_at_cursor(), None);
+
+    backend.set_cursor_position(Position::new(2, 1)).unwrap();
+    backend.show_cursor().unwrap();
+    let style = backend.style_at_cursor().unwrap();
+    assert_eq!(style.bg, SerializableColor::Yellow);
+    assert_eq!(style.fg, SerializableColor::Reset);
+}
+
+#[cfg(feature = "image")]
+mod png_tests {
+    use super::*;
+    use ratatui::style::{Color, Modifier, Style};
+
+    /// Decodes a PNG into its dimensions and RGB pixel data.
+    fn decode(bytes: &[u8]) -> (u32, u32, Vec<u8>) {
+        let decoder = png::Decoder::new(bytes);
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(info.color_type, png::ColorType::Rgb);
+        pixels.truncate(info.buffer_size());
+        (info.width, info.height, pixels)
+    }
+
+    fn pixel(image: &(u32, u32, Vec<u8>), x: u32, y: u32) -> (u8, u8, u8) {
+        let i = ((y * image.0 + x) * 3) as usize;
+        (image.2[i], image.2[i + 1], image.2[i + 2])
+    }
+
+    fn set(backend: &mut CaptureBackend, x: u16, symbol: &str, style: Style) {
+        let cell = backend.cell_mut(x, 0).unwrap();
+        cell.set_symbol(symbol);
+        cell.set_style(style);
+    }
+
+    #[test]
+    fn test_png_dimensions() {
+        let image = decode(&CaptureBackend::new(5, 3).to_half_block_png(4).unwrap());
+        assert_eq!((image.0, image.1), (20, 24));
+    }
+
+    #[test]
+    fn test_png_empty_when_zero_sized() {
+        assert!(
+            CaptureBackend::new(0, 3)
+                .to_half_block_png(4)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            CaptureBackend::new(5, 3)
+                .to_half_block_png(0)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_png_errors_when_dimensions_overflow() {
+        let backend = CaptureBackend::new(80, 24);
+        assert!(backend.to_half_block_png(u32::MAX).is_err());
+        assert!(backend.to_half_block_png(u32::MAX / 80).is_err());
+        // Each side fits in u32, but the pixel buffer size doesn't fit in usize
+        assert!(backend.to_half_block_png(u32::MAX / 48).is_err());
+    }
+
+    #[test]
+    fn test_png_blank_cells_use_background() {
+        let mut backend = CaptureBackend::new(2, 1);
+        set(
+            &mut backend,
+            0,
+            " ",
+            Style::default().fg(Color::Red).bg(Color::Blue),
+        );
+        let image = decode(&backend.to_half_block_png(2).unwrap());
+
+        assert_eq!(pixel(&image, 0, 0), (0, 0, 128));
+        // Reset background
+        assert_eq!(pixel(&image, 3, 3), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_png_symbols_fill_with_foreground() {
+        let mut backend = CaptureBackend::new(1, 1);
+        set(
+            &mut backend,
+            0,
+            "A",
+            Style::default().fg(Color::Rgb(10, 20, 30)),
+        );
+        let image = decode(&backend.to_half_block_png(2).unwrap());
+
+        for (x, y) in [(0, 0), (1, 3)] {
+            assert_eq!(pixel(&image, x, y), (10, 20, 30));
+        }
+    }
+
+    #[test]
+    fn test_png_half_blocks() {
+        let mut backend = CaptureBackend::new(2, 1);
+        let style = Style::default().fg(Color::White).bg(Color::Black);
+        set(&mut backend, 0, "▀", style);
+        set(&mut backend, 1, "▄", style);
+        let image = decode(&backend.to_half_block_png(2).unwrap());
+
+        let white = (255, 255, 255);
+        let black = (0, 0, 0);
+        // Upper half block: top painted, bottom not
+        assert_eq!(pixel(&image, 0, 0), white);
+        assert_eq!(pixel(&image, 1, 1), white);
+        assert_eq!(pixel(&image, 0, 2), black);
+        assert_eq!(pixel(&image, 1, 3), black);
+        // Lower half block: the reverse
+        assert_eq!(pixel(&image, 2, 0), black);
+        assert_eq!(pixel(&image, 3, 3), white);
+    }
+
+    #[test]
+    fn test_png_eighth_blocks() {
+        let mut backend = CaptureBackend::new(2, 1);
+        let style = Style::default().fg(Color::White).bg(Color::Black);
+        set(&mut backend, 0, "▂", style);
+        set(&mut backend, 1, "▎", style);
+        let image = decode(&backend.to_half_block_png(4).unwrap());
+
+        // Lower quarter of an 8px-tall cell is rows 6..8
+        assert_eq!(pixel(&image, 0, 5), (0, 0, 0));
+        assert_eq!(pixel(&image, 0, 6), (255, 255, 255));
+        // Left quarter of a 4px-wide cell is column 0
+        assert_eq!(pixel(&image, 4, 0), (255, 255, 255));
+        assert_eq!(pixel(&image, 5, 0), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_png_honors_reversed() {
+        let mut backend = CaptureBackend::new(1, 1);
+        let style = Style::default()
+            .fg(Color::Red)
+            .bg(Color::Green)
+            .add_modifier(Modifier::REVERSED);
+        set(&mut backend, 0, " ", style);
+        let image = decode(&backend.to_half_block_png(1).unwrap());
+
+        assert_eq!(pixel(&image, 0, 0), (128, 0, 0));
+    }
+
+    #[test]
+    fn test_png_indexed_color() {
+        let mut backend = CaptureBackend::new(1, 1);
+        set(
+            &mut backend,
+            0,
+            "█",
+            Style::default().fg(Color::Indexed(196)),
+        );
+        let image = decode(&backend.to_half_block_png(1).unwrap());
+
+        assert_eq!(pixel(&image, 0, 1), (255, 0, 0));
+    }
+}
+
+#[test]
+fn test_validate_accepts_rendered_wide_text() {
+    let mut terminal = ratatui::Terminal::new(CaptureBackend::new(8, 2)).unwrap();
+    terminal
+        .draw(|frame| {
+            frame.render_widget(ratatui::widgets::Paragraph::new("a日本\n🎉"), frame.area());
+            frame.set_cursor_position((7, 1));
+        })
+        .unwrap();
+    assert_eq!(terminal.backend().validate(), Ok(()));
+}
+
+#[test]
+fn test_validate_reports_orphan_continuation_and_torn_glyph() {
+    let mut backend = CaptureBackend::new(6, 1);
+    backend.cell_mut(0, 0).unwrap().set_symbol("");
+    backend.cell_mut(2, 0).unwrap().set_symbol("日");
+    // The glyph at the right edge has nowhere for its second half.
+    backend.cell_mut(5, 0).unwrap().set_symbol("本");
+    assert_eq!(
+        backend.validate(),
+        Err(vec![
+            CellError::OrphanContinuation {
+                position: Position::new(0, 0)
+            },
+            CellError::TornWideChar {
+                position: Position::new(2, 0)
+            },
+            CellError::TornWideChar {
+                position: Position::new(5, 0)
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_validate_reports_zero_width_symbol() {
+    let mut backend = CaptureBackend::new(3, 1);
+    backend.cell_mut(1, 0).unwrap().set_symbol("\u{301}");
+    let errors = backend.validate().unwrap_err();
+    assert_eq!(
+        errors,
+        vec![CellError::ZeroWidthSymbol {
+            position: Position::new(1, 0),
+            symbol: "\u{301}".to_string(),
+        }]
+    );
+    assert_eq!(
+        errors[0].to_string(),
+        "zero-width symbol \"\\u{301}\" at (1, 0)"
+    );
+}
+
+#[test]
+fn test_validate_checks_only_visible_cursor() {
+    let mut backend = CaptureBackend::new(4, 2);
+    backend.set_cursor_position(Position::new(4, 0)).unwrap();
+    backend.hide_cursor().unwrap();
+    assert_eq!(backend.validate(), Ok(()));
+
+    backend.show_cursor().unwrap();
+    assert_eq!(
+        backend.validate(),
+        Err(vec![CellError::CursorOutOfBounds {
+            position: Position::new(4, 0)
+        }])
+    );
+}
+
+#[test]
+fn test_ambiguous_width_controls_wide_glyph_layout() {
+    let draw = |backend: CaptureBackend| {
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                frame.render_widget(ratatui::widgets::Paragraph::new("○→b"), frame.area())
+            })
+            .unwrap();
+        terminal.backend().clone()
+    };
+
+    let narrow = draw(CaptureBackend::new(6, 1));
+    assert_eq!(narrow.ambiguous_width(), AmbiguousWidth::Narrow);
+    assert_eq!(narrow.validate(), Ok(()));
+
+    // ratatui lays "○→" out one column each, which a wide terminal garbles.
+    let wide = draw(CaptureBackend::new(6, 1).with_ambiguous_width(AmbiguousWidth::Wide));
+    assert_eq!(
+        wide.validate(),
+        Err(vec![
+            CellError::TornWideChar {
+                position: Position::new(0, 0)
+            },
+            CellError::TornWideChar {
+                position: Position::new(1, 0)
+            },
+        ])
+    );
+
+    let mut backend = CaptureBackend::new(4, 1);
+    backend.set_ambiguous_width(AmbiguousWidth::Wide);
+    backend.cell_mut(0, 0).unwrap().set_symbol("○");
+    backend.cell_mut(1, 0).unwrap().set_symbol("");
+    assert_eq!(backend.validate(), Ok(()));
+}
+
+#[test]
+fn test_visual_diff_keeps_content_after_ambiguous_glyph() {
+    let mut backend = CaptureBackend::new(6, 1).with_ambiguous_width(AmbiguousWidth::Wide);
+    for (x, ch) in "○→b".chars().enumerate() {
+        backend.cell_mut(x as u16, 0).unwrap().set_char(ch);
+    }
+    assert_eq!(backend.visual_diff("○→b"), None);
+
+    backend.cell_mut(1, 0).unwrap().set_symbol("");
+    backend.cell_mut(2, 0).unwrap().set_char('→');
+    assert_eq!(backend.visual_diff("○→"), None);
+}
@@ -106,6 +106,33 @@ impl EnhancedCell {
         self.symbol.width()
     }
 
+    /// Returns true if this cell is covered by a wide character to its left.
+    ///
+    /// A double-width glyph (CJK, most emoji) occupies its own cell plus the
+    /// next one. The covered cell is a continuation with an empty symbol, so
+    /// text output doesn't print anything extra for it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CaptureBackend;
+    /// use ratatui::Terminal;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let mut terminal = Terminal::new(CaptureBackend::new(4, 1)).unwrap();
+    /// terminal
+    ///     .draw(|frame| frame.render_widget(Paragraph::new("世a"), frame.area()))
+    ///     .unwrap();
+    ///
+    /// let backend = terminal.backend();
+    /// assert_eq!(backend.cell(0, 0).unwrap().symbol(), "世");
+    /// assert!(backend.cell(1, 0).unwrap().is_continuation());
+    /// assert_eq!(backend.cell(2, 0).unwrap().symbol(), "a");
+    /// ```
+    pub fn is_continuation(&self) -> bool {
+        self.symbol.is_empty()
+    }
+
     /// Sets the style from a ratatui Style
     pub fn set_style(&mut self, style: Style) {
         if let Some(fg) = style.fg {
@@ -158,12 +185,77 @@ impl Default for EnhancedCell {
     }
 }
 
-/// A serializable version of ratatui's Color enum
+/// The style of a captured cell, without its symbol.
+///
+/// A cheap, `Copy` projection of an [`EnhancedCell`] used by
+/// [`CaptureBackend::style_grid`](crate::backend::CaptureBackend::style_grid)
+/// to analyze coloring separately from text.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::backend::{CapturedStyle, EnhancedCell};
+///
+/// let cell = EnhancedCell::with_symbol("x");
+/// assert_eq!(CapturedStyle::from(&cell), CapturedStyle::default());
+/// ```
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(
     feature = "serialization",
     derive(serde::Serialize, serde::Deserialize)
 )]
+pub struct CapturedStyle {
+    /// Foreground color
+    pub fg: SerializableColor,
+
+    /// Background color
+    pub bg: SerializableColor,
+
+    /// Text modifiers (bold, italic, etc.)
+    pub modifiers: SerializableModifier,
+
+    /// Underline color (if different from foreground)
+    pub underline_color: Option<SerializableColor>,
+}
+
+impl Default for CapturedStyle {
+    fn default() -> Self {
+        Self {
+            fg: SerializableColor::Reset,
+            bg: SerializableColor::Reset,
+            modifiers: SerializableModifier::empty(),
+            underline_color: None,
+        }
+    }
+}
+
+impl CapturedStyle {
+    /// Returns the style as a ratatui Style
+    pub fn style(&self) -> Style {
+        Style::new()
+            .fg(self.fg.into())
+            .bg(self.bg.into())
+            .add_modifier(self.modifiers.into())
+    }
+}
+
+impl From<&EnhancedCell> for CapturedStyle {
+    fn from(cell: &EnhancedCell) -> Self {
+        Self {
+            fg: cell.fg,
+            bg: cell.bg,
+            modifiers: cell.modifiers,
+            underline_color: cell.underline_color,
+        }
+    }
+}
+
+/// A serializable version of ratatui's Color enum
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 #[cfg_attr(feature = "serialization", serde(rename_all = "snake_case"))]
 pub enum SerializableColor {
     Reset,
@@ -292,7 +384,7 @@ impl SerializableColor {
 }
 
 /// A serializable version of ratatui's Modifier flags
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(
     feature = "serialization",
     derive(serde::Serialize, serde::Deserialize)
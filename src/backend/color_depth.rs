@@ -0,0 +1,338 @@
+//! Terminal color depth and color downsampling.
+//!
+//! Terminals support 16, 256 or 16 million colors. [`ColorDepth`] describes
+//! which, and maps colors the terminal can't display to the nearest color it
+//! can rather than leaving the choice to the terminal emulator.
+
+use ratatui::style::Color;
+
+/// The number of colors a terminal can display.
+///
+/// Colors the depth can't represent are mapped to the nearest color it can.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::backend::ColorDepth;
+/// use ratatui::style::Color;
+///
+/// assert_eq!(ColorDepth::default(), ColorDepth::TrueColor);
+/// assert_eq!(ColorDepth::Ansi16.downsample(Color::Rgb(250, 10, 5)), Color::LightRed);
+/// assert_eq!(ColorDepth::Ansi256.downsample(Color::Rgb(255, 0, 0)), Color::Indexed(196));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorDepth {
+    /// The 16 standard colors (`30`-`37`, `90`-`97`).
+    ///
+    /// RGB and indexed colors are mapped to the standard color of the
+    /// nearest hue, or to the nearest gray for desaturated colors.
+    Ansi16,
+    /// The 256-color palette (`38;5;N`).
+    ///
+    /// RGB colors are mapped to the perceptually nearest entry of the 6x6x6
+    /// color cube or the grayscale ramp. Named and indexed colors are unchanged.
+    Ansi256,
+    /// 24-bit color (`38;2;R;G;B`). No colors are changed.
+    #[default]
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detects the color depth from the `COLORTERM` and `TERM` environment
+    /// variables.
+    ///
+    /// `COLORTERM=truecolor` or `COLORTERM=24bit` means [`ColorDepth::TrueColor`],
+    /// a `TERM` containing `256color` means [`ColorDepth::Ansi256`], and
+    /// anything else falls back to [`ColorDepth::Ansi16`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::ColorDepth;
+    ///
+    /// let depth = ColorDepth::detect();
+    /// # let _ = depth;
+    /// ```
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").ok();
+        let term = std::env::var("TERM").ok();
+        Self::from_env_values(colorterm.as_deref(), term.as_deref())
+    }
+
+    fn from_env_values(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        let colorterm = colorterm.unwrap_or_default().to_ascii_lowercase();
+        let term = term.unwrap_or_default().to_ascii_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" || term.ends_with("-direct") {
+            Self::TrueColor
+        } else if term.contains("256color") {
+            Self::Ansi256
+        } else {
+            Self::Ansi16
+        }
+    }
+
+    /// Maps `color` to the nearest color representable at this depth.
+    ///
+    /// [`Color::Reset`] is never changed.
+    pub fn downsample(self, color: Color) -> Color {
+        match (self, color) {
+            (ColorDepth::TrueColor, _) => color,
+            (ColorDepth::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(nearest_indexed(r, g, b)),
+            (ColorDepth::Ansi16, Color::Rgb(r, g, b)) => nearest_named(r, g, b),
+            (ColorDepth::Ansi16, Color::Indexed(i)) => match NAMED_COLORS.get(i as usize) {
+                Some(&(named, _)) => named,
+                None => {
+                    let (r, g, b) = indexed_to_rgb(i);
+                    nearest_named(r, g, b)
+                }
+            },
+            _ => color,
+        }
+    }
+}
+
+/// The 16 standard colors in palette order, with their xterm default RGB values.
+pub(crate) const NAMED_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (128, 0, 0)),
+    (Color::Green, (0, 128, 0)),
+    (Color::Yellow, (128, 128, 0)),
+    (Color::Blue, (0, 0, 128)),
+    (Color::Magenta, (128, 0, 128)),
+    (Color::Cyan, (0, 128, 128)),
+    (Color::Gray, (192, 192, 192)),
+    (Color::DarkGray, (128, 128, 128)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (0, 0, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Channel values of the 6x6x6 color cube in the 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Squared "redmean" distance, which weights the channels by how sensitive
+/// the eye is to them at the given red level.
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let mean_r = (i32::from(r1) + i32::from(r2)) / 2;
+    let dr = i32::from(r1) - i32::from(r2);
+    let dg = i32::from(g1) - i32::from(g2);
+    let db = i32::from(b1) - i32::from(b2);
+    (((512 + mean_r) * dr * dr) / 256 + 4 * dg * dg + ((767 - mean_r) * db * db) / 256) as u32
+}
+
+/// Colors whose channels differ by less than this are matched against the
+/// grays rather than by hue.
+const MIN_CHROMA: u8 = 40;
+
+/// Saturated colors brighter than this use the light variant of their hue.
+const LIGHT_THRESHOLD: u8 = 191;
+
+/// Returns the standard color that best represents the given RGB value.
+///
+/// The terminal's 16 colors are fully saturated, so pastel and muted theme
+/// colors are closer by distance to a gray than to their own hue. Saturated
+/// colors are instead matched to the nearest of the six hues, picking the
+/// light variant for bright colors; only desaturated colors are matched by
+/// distance against black, the grays and white.
+fn nearest_named(r: u8, g: u8, b: u8) -> Color {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+
+    if chroma < MIN_CHROMA {
+        return [Color::Black, Color::DarkGray, Color::Gray, Color::White]
+            .into_iter()
+            .zip([0, 8, 7, 15])
+            .min_by_key(|&(_, index)| distance(NAMED_COLORS[index].1, (r, g, b)))
+            .map_or(Color::White, |(color, _)| color);
+    }
+
+    let (r, g, b, c) = (f64::from(r), f64::from(g), f64::from(b), f64::from(chroma));
+    let max_f = f64::from(max);
+    let hue = if max_f == r {
+        60.0 * ((g - b) / c).rem_euclid(6.0)
+    } else if max_f == g {
+        60.0 * ((b - r) / c + 2.0)
+    } else {
+        60.0 * ((r - g) / c + 4.0)
+    };
+
+    let light = max > LIGHT_THRESHOLD;
+    match ((hue + 30.0) / 60.0) as u32 % 6 {
+        0 if light => Color::LightRed,
+        0 => Color::Red,
+        1 if light => Color::LightYellow,
+        1 => Color::Yellow,
+        2 if light => Color::LightGreen,
+        2 => Color::Green,
+        3 if light => Color::LightCyan,
+        3 => Color::Cyan,
+        4 if light => Color::LightBlue,
+        4 => Color::Blue,
+        _ if light => Color::LightMagenta,
+        _ => Color::Magenta,
+    }
+}
+
+/// Returns the RGB value of a color, using the xterm defaults for the
+/// standard colors. Returns `None` for [`Color::Reset`], whose value is up to
+/// the terminal.
+pub(crate) fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Reset => None,
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        Color::Indexed(i) => Some(indexed_to_rgb(i)),
+        named => NAMED_COLORS
+            .iter()
+            .find(|&&(c, _)| c == named)
+            .map(|&(_, rgb)| rgb),
+    }
+}
+
+/// Returns the RGB value of an entry in the 256-color palette.
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => NAMED_COLORS[index as usize].1,
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        232..=255 => {
+            let level = 8 + 10 * (index - 232);
+            (level, level, level)
+        }
+    }
+}
+
+/// Returns the color cube or grayscale ramp index closest to the given RGB value.
+///
+/// The first 16 palette entries are skipped because terminals commonly
+/// remap them.
+fn nearest_indexed(r: u8, g: u8, b: u8) -> u8 {
+    let cube_step = |v: u8| -> u8 {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| (i32::from(**level) - i32::from(v)).unsigned_abs())
+            .map_or(0, |(i, _)| i as u8)
+    };
+    let cube = 16 + 36 * cube_step(r) + 6 * cube_step(g) + cube_step(b);
+
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    if distance(indexed_to_rgb(gray), (r, g, b)) < distance(indexed_to_rgb(cube), (r, g, b)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truecolor_keeps_colors() {
+        let color = Color::Rgb(1, 2, 3);
+        assert_eq!(ColorDepth::TrueColor.downsample(color), color);
+    }
+
+    #[test]
+    fn test_reset_is_unchanged() {
+        for depth in [
+            ColorDepth::Ansi16,
+            ColorDepth::Ansi256,
+            ColorDepth::TrueColor,
+        ] {
+            assert_eq!(depth.downsample(Color::Reset), Color::Reset);
+        }
+    }
+
+    #[test]
+    fn test_ansi256_maps_rgb_to_cube_or_gray() {
+        let depth = ColorDepth::Ansi256;
+        assert_eq!(depth.downsample(Color::Rgb(0, 0, 0)), Color::Indexed(16));
+        assert_eq!(
+            depth.downsample(Color::Rgb(255, 255, 255)),
+            Color::Indexed(231)
+        );
+        assert_eq!(
+            depth.downsample(Color::Rgb(128, 128, 128)),
+            Color::Indexed(244)
+        );
+        assert_eq!(
+            depth.downsample(Color::Rgb(95, 135, 175)),
+            Color::Indexed(67)
+        );
+        assert_eq!(depth.downsample(Color::Indexed(3)), Color::Indexed(3));
+        assert_eq!(depth.downsample(Color::Blue), Color::Blue);
+    }
+
+    #[test]
+    fn test_ansi16_maps_rgb_and_indexed_to_named() {
+        let depth = ColorDepth::Ansi16;
+        assert_eq!(depth.downsample(Color::Rgb(250, 10, 5)), Color::LightRed);
+        assert_eq!(depth.downsample(Color::Rgb(20, 20, 120)), Color::Blue);
+        assert_eq!(depth.downsample(Color::Indexed(4)), Color::Blue);
+        assert_eq!(depth.downsample(Color::Indexed(231)), Color::White);
+        assert_eq!(depth.downsample(Color::Indexed(244)), Color::DarkGray);
+        assert_eq!(depth.downsample(Color::Cyan), Color::Cyan);
+    }
+
+    #[test]
+    fn test_ansi16_keeps_hue_of_muted_colors() {
+        let depth = ColorDepth::Ansi16;
+        // Nord red, green, yellow and frost
+        assert_eq!(depth.downsample(Color::Rgb(0xBF, 0x61, 0x6A)), Color::Red);
+        assert_eq!(depth.downsample(Color::Rgb(0xA3, 0xBE, 0x8C)), Color::Green);
+        assert_eq!(
+            depth.downsample(Color::Rgb(0xEB, 0xCB, 0x8B)),
+            Color::LightYellow
+        );
+        assert_eq!(
+            depth.downsample(Color::Rgb(0x88, 0xC0, 0xD0)),
+            Color::LightCyan
+        );
+        // Catppuccin pink
+        assert_eq!(
+            depth.downsample(Color::Rgb(0xF5, 0xC2, 0xE7)),
+            Color::LightMagenta
+        );
+    }
+
+    #[test]
+    fn test_ansi16_maps_desaturated_colors_to_grays() {
+        let depth = ColorDepth::Ansi16;
+        assert_eq!(depth.downsample(Color::Rgb(0x2E, 0x34, 0x40)), Color::Black);
+        assert_eq!(
+            depth.downsample(Color::Rgb(0x6C, 0x70, 0x86)),
+            Color::DarkGray
+        );
+        assert_eq!(depth.downsample(Color::Rgb(0xD8, 0xDE, 0xE9)), Color::Gray);
+        assert_eq!(depth.downsample(Color::Rgb(0xFA, 0xFA, 0xFA)), Color::White);
+    }
+
+    #[test]
+    fn test_from_env_values() {
+        let detect = ColorDepth::from_env_values;
+        assert_eq!(
+            detect(Some("truecolor"), Some("xterm")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(detect(Some("24bit"), None), ColorDepth::TrueColor);
+        assert_eq!(detect(None, Some("xterm-direct")), ColorDepth::TrueColor);
+        assert_eq!(detect(None, Some("xterm-256color")), ColorDepth::Ansi256);
+        assert_eq!(detect(None, Some("screen-256color")), ColorDepth::Ansi256);
+        assert_eq!(detect(None, Some("xterm")), ColorDepth::Ansi16);
+        assert_eq!(detect(None, None), ColorDepth::Ansi16);
+    }
+}
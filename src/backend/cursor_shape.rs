@@ -0,0 +1,77 @@
+//! Terminal cursor shapes.
+//!
+//! ratatui's `Backend` trait only shows, hides and moves the cursor; the
+//! shape is set with a separate terminal command. [`CursorShape`] names the
+//! shapes terminals support, so [`CaptureBackend`](super::CaptureBackend)
+//! can record the shape an app asked for and a real terminal can apply it
+//! through crossterm.
+
+use crossterm::cursor::SetCursorStyle;
+
+/// The shape of the terminal cursor.
+///
+/// Converts into crossterm's [`SetCursorStyle`] command for use with a real
+/// terminal.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::backend::{CaptureBackend, CursorShape};
+///
+/// let mut backend = CaptureBackend::new(10, 3);
+/// assert_eq!(backend.cursor_shape(), None);
+///
+/// backend.set_cursor_shape(Some(CursorShape::SteadyBar));
+/// assert_eq!(backend.cursor_shape(), Some(CursorShape::SteadyBar));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum CursorShape {
+    /// A blinking block.
+    BlinkingBlock,
+    /// A steady block.
+    SteadyBlock,
+    /// A blinking underline.
+    BlinkingUnderline,
+    /// A steady underline.
+    SteadyUnderline,
+    /// A blinking vertical bar, as used for text insertion.
+    BlinkingBar,
+    /// A steady vertical bar.
+    SteadyBar,
+}
+
+impl CursorShape {
+    /// Returns true for the blinking shapes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::backend::CursorShape;
+    ///
+    /// assert!(CursorShape::BlinkingBar.is_blinking());
+    /// assert!(!CursorShape::SteadyBar.is_blinking());
+    /// ```
+    pub fn is_blinking(self) -> bool {
+        matches!(
+            self,
+            CursorShape::BlinkingBlock | CursorShape::BlinkingUnderline | CursorShape::BlinkingBar
+        )
+    }
+}
+
+impl From<CursorShape> for SetCursorStyle {
+    fn from(shape: CursorShape) -> Self {
+        match shape {
+            CursorShape::BlinkingBlock => SetCursorStyle::BlinkingBlock,
+            CursorShape::SteadyBlock => SetCursorStyle::SteadyBlock,
+            CursorShape::BlinkingUnderline => SetCursorStyle::BlinkingUnderScore,
+            CursorShape::SteadyUnderline => SetCursorStyle::SteadyUnderScore,
+            CursorShape::BlinkingBar => SetCursorStyle::BlinkingBar,
+            CursorShape::SteadyBar => SetCursorStyle::SteadyBar,
+        }
+    }
+}
@@ -3,10 +3,19 @@
 //! This module contains our custom ratatui backend that captures rendered frames
 //! for inspection, testing, and headless operation.
 
+mod ambiguous_width;
 mod capture;
 mod cell;
+mod color_depth;
+mod cursor_shape;
 pub mod output;
 
-pub use capture::{CaptureBackend, FrameSnapshot};
-pub use cell::EnhancedCell;
-pub use output::OutputFormat;
+pub use ambiguous_width::AmbiguousWidth;
+#[cfg(feature = "regex")]
+pub use capture::TextMatch;
+pub use capture::{CaptureBackend, CellError, FrameSnapshot};
+pub use cell::{CapturedStyle, EnhancedCell};
+pub use color_depth::ColorDepth;
+pub(crate) use color_depth::{NAMED_COLORS, to_rgb};
+pub use cursor_shape::CursorShape;
+pub use output::{AnsiOptions, OutputFormat, TrimMode};
@@ -3,20 +3,108 @@
 //! Renders the captured buffer with full ANSI escape codes for colors
 //! and text modifiers. The output can be displayed in any terminal
 //! that supports ANSI escape sequences.
+//!
+//! [`render`] produces the default form. [`render_with`] takes an
+//! [`AnsiOptions`] to control how escapes are emitted, which makes the
+//! output suitable for golden files and for checking how a frame looks on
+//! terminals with limited color support.
+
+use ratatui::style::Color;
 
-use crate::backend::CaptureBackend;
-use crate::backend::cell::SerializableColor;
+use crate::backend::cell::{EnhancedCell, SerializableColor, SerializableModifier};
+use crate::backend::{CaptureBackend, ColorDepth};
 
 /// ANSI reset sequence
-const RESET: &str = "\x1b[0m";
+pub(super) const RESET: &str = "\x1b[0m";
+
+/// Options controlling how
+/// [`CaptureBackend::to_ansi_with`](crate::backend::CaptureBackend::to_ansi_with)
+/// emits escape sequences.
+///
+/// The default options produce the same output as
+/// [`CaptureBackend::to_ansi`](crate::backend::CaptureBackend::to_ansi): escapes are
+/// written only when the style changes, colors are written at full depth,
+/// and each styled line ends with a reset.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::backend::{AnsiOptions, ColorDepth};
+///
+/// let options = AnsiOptions::new()
+///     .with_color_depth(ColorDepth::Ansi256)
+///     .with_trailing_reset(true);
+/// assert_eq!(options.color_depth(), ColorDepth::Ansi256);
+/// assert!(!options.per_cell_reset());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AnsiOptions {
+    per_cell_reset: bool,
+    color_depth: ColorDepth,
+    trailing_reset: bool,
+}
+
+impl AnsiOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether every cell is preceded by a reset and its full style.
+    ///
+    /// When false (the default), escapes are only written when the style
+    /// differs from the previous cell on the same line.
+    pub fn with_per_cell_reset(mut self, per_cell_reset: bool) -> Self {
+        self.per_cell_reset = per_cell_reset;
+        self
+    }
+
+    /// Sets the color depth colors are downsampled to.
+    pub fn with_color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = color_depth;
+        self
+    }
+
+    /// Sets whether the output always ends with a reset sequence.
+    pub fn with_trailing_reset(mut self, trailing_reset: bool) -> Self {
+        self.trailing_reset = trailing_reset;
+        self
+    }
+
+    /// Returns whether every cell is preceded by a reset.
+    pub fn per_cell_reset(&self) -> bool {
+        self.per_cell_reset
+    }
+
+    /// Returns the color depth colors are downsampled to.
+    pub fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Returns whether the output always ends with a reset sequence.
+    pub fn trailing_reset(&self) -> bool {
+        self.trailing_reset
+    }
+}
 
 /// Renders the backend with ANSI escape codes for colors and styling.
 ///
 /// This output can be printed directly to a terminal that supports ANSI
 /// codes to see the full visual representation of the captured frame.
 pub fn render(backend: &CaptureBackend) -> String {
+    render_with(backend, &AnsiOptions::default())
+}
+
+/// Renders the backend with ANSI escape codes using the given options.
+///
+/// Each style change is written as a reset followed by the modifiers, the
+/// foreground and the background, so the output is deterministic for a
+/// given buffer and set of options. Lines are separated by `\n`, and every
+/// line that ends styled is closed with a reset.
+pub fn render_with(backend: &CaptureBackend, options: &AnsiOptions) -> String {
     let width = backend.width();
     let height = backend.height();
+    let depth = options.color_depth;
     let mut output = String::new();
 
     for y in 0..height {
@@ -26,38 +114,28 @@ pub fn render(backend: &CaptureBackend) -> String {
 
         let mut last_fg = SerializableColor::Reset;
         let mut last_bg = SerializableColor::Reset;
-        let mut last_modifiers = crate::backend::cell::SerializableModifier::empty();
+        let mut last_modifiers = SerializableModifier::empty();
+        let mut last_underline = None;
 
         for x in 0..width {
             let Some(cell) = backend.cell(x, y) else {
                 continue;
             };
 
-            // Check if we need to change styling
-            let need_fg_change = cell.fg != last_fg;
-            let need_bg_change = cell.bg != last_bg;
-            let need_mod_change = cell.modifiers != last_modifiers;
-
-            if need_fg_change || need_bg_change || need_mod_change {
-                // Reset and apply new styles
-                output.push_str(RESET);
-
-                // Apply modifiers first
-                if !cell.modifiers.is_empty() {
-                    output.push_str(&cell.modifiers.to_ansi());
-                }
-
-                // Apply colors
-                if cell.fg != SerializableColor::Reset {
-                    output.push_str(&cell.fg.to_ansi_fg());
-                }
-                if cell.bg != SerializableColor::Reset {
-                    output.push_str(&cell.bg.to_ansi_bg());
-                }
-
-                last_fg = cell.fg;
-                last_bg = cell.bg;
+            let fg = downsample(depth, cell.fg);
+            let bg = downsample(depth, cell.bg);
+
+            if options.per_cell_reset
+                || fg != last_fg
+                || bg != last_bg
+                || cell.modifiers != last_modifiers
+                || underline_color(cell) != last_underline
+            {
+                push_style(&mut output, cell, fg, bg);
+                last_fg = fg;
+                last_bg = bg;
                 last_modifiers = cell.modifiers;
+                last_underline = underline_color(cell);
             }
 
             output.push_str(cell.symbol());
@@ -67,14 +145,183 @@ pub fn render(backend: &CaptureBackend) -> String {
         if last_fg != SerializableColor::Reset
             || last_bg != SerializableColor::Reset
             || !last_modifiers.is_empty()
+            || last_underline.is_some()
         {
             output.push_str(RESET);
         }
     }
 
+    if options.trailing_reset && !output.ends_with(RESET) {
+        output.push_str(RESET);
+    }
+
     output
 }
 
+/// The style of the last cell written to a terminal, so an SGR sequence is
+/// only written when the next cell's style differs from it.
+///
+/// Shared by [`render_terminal_sequence`] and the asciicast writer, which
+/// both write cells in the order a terminal would draw them.
+pub(super) struct SgrRun {
+    last: Option<CellStyle>,
+}
+
+type CellStyle = (
+    SerializableColor,
+    SerializableColor,
+    SerializableModifier,
+    Option<SerializableColor>,
+);
+
+const RESET_STYLE: CellStyle = (
+    SerializableColor::Reset,
+    SerializableColor::Reset,
+    SerializableModifier::empty(),
+    None,
+);
+
+impl SgrRun {
+    /// Starts a run on a terminal whose style is known to be reset.
+    pub(super) fn after_reset() -> Self {
+        Self {
+            last: Some(RESET_STYLE),
+        }
+    }
+
+    /// Starts a run on a terminal whose style is unknown, so the first cell
+    /// always writes its style.
+    pub(super) fn unknown() -> Self {
+        Self { last: None }
+    }
+
+    /// Writes the cell's style if it differs from the last one written.
+    pub(super) fn push_cell_style(&mut self, output: &mut String, cell: &EnhancedCell) {
+        let style = (cell.fg, cell.bg, cell.modifiers, underline_color(cell));
+        if self.last != Some(style) {
+            push_style(output, cell, cell.fg, cell.bg);
+            self.last = Some(style);
+        }
+    }
+
+    /// Writes a reset if the last style written was not already reset.
+    pub(super) fn finish(&self, output: &mut String) {
+        if self.last.is_some_and(|style| style != RESET_STYLE) {
+            output.push_str(RESET);
+        }
+    }
+}
+
+/// Renders the backend as the bytes a terminal needs to redraw the frame.
+///
+/// The output starts with a cursor-home sequence and writes every row in
+/// full, separated by `\n`, so it assumes the receiving terminal
+/// translates newlines to carriage return plus line feed, as a terminal in
+/// its normal mode does. Unlike [`render_with`], the current style carries
+/// across rows: an SGR sequence is written only when a cell's style differs
+/// from the cell written before it. The trailing halves of wide characters
+/// are not written, cells ratatui marks as skipped are stepped over with a
+/// cursor movement, and the output ends with a reset if the last cell was
+/// styled.
+pub(crate) fn render_terminal_sequence(backend: &CaptureBackend) -> String {
+    let mut output = String::from("\x1b[H");
+    let mut run = SgrRun::after_reset();
+
+    for y in 0..backend.height() {
+        if y > 0 {
+            output.push('\n');
+        }
+        for x in 0..backend.width() {
+            let Some(cell) = backend.cell(x, y) else {
+                continue;
+            };
+            if cell.skip {
+                output.push_str("\x1b[C");
+                continue;
+            }
+            if cell.is_continuation() {
+                continue;
+            }
+
+            run.push_cell_style(&mut output, cell);
+            output.push_str(cell.symbol());
+        }
+    }
+
+    run.finish(&mut output);
+    output
+}
+
+fn downsample(depth: ColorDepth, color: SerializableColor) -> SerializableColor {
+    SerializableColor::from(depth.downsample(Color::from(color)))
+}
+
+/// Writes a reset followed by the cell's modifiers, colors and underline
+/// color.
+pub(super) fn push_style(
+    output: &mut String,
+    cell: &EnhancedCell,
+    fg: SerializableColor,
+    bg: SerializableColor,
+) {
+    output.push_str(RESET);
+
+    // Apply modifiers first
+    if !cell.modifiers.is_empty() {
+        output.push_str(&cell.modifiers.to_ansi());
+    }
+
+    // Apply colors
+    if fg != SerializableColor::Reset {
+        output.push_str(&fg.to_ansi_fg());
+    }
+    if bg != SerializableColor::Reset {
+        output.push_str(&bg.to_ansi_bg());
+    }
+    if let Some(underline) = underline_color(cell) {
+        push_underline_color(output, underline);
+    }
+}
+
+/// Returns the cell's underline color, or `None` if it uses the default,
+/// which ratatui records as either `None` or `Some(Reset)`.
+fn underline_color(cell: &EnhancedCell) -> Option<SerializableColor> {
+    cell.underline_color
+        .filter(|color| *color != SerializableColor::Reset)
+}
+
+/// Writes the SGR 58 sequence that sets the underline color.
+///
+/// Named colors are written as their palette index, as crossterm does,
+/// since SGR 58 has no short form for them.
+fn push_underline_color(output: &mut String, color: SerializableColor) {
+    let index = match color {
+        SerializableColor::Reset => return,
+        SerializableColor::Rgb { r, g, b } => {
+            output.push_str(&format!("\x1b[58;2;{r};{g};{b}m"));
+            return;
+        }
+        SerializableColor::Indexed(i) => i,
+        SerializableColor::Black => 0,
+        SerializableColor::Red => 1,
+        SerializableColor::Green => 2,
+        SerializableColor::Yellow => 3,
+        SerializableColor::Blue => 4,
+        SerializableColor::Magenta => 5,
+        SerializableColor::Cyan => 6,
+        SerializableColor::Gray => 7,
+        SerializableColor::DarkGray => 8,
+        SerializableColor::LightRed => 9,
+        SerializableColor::LightGreen => 10,
+        SerializableColor::LightYellow => 11,
+        SerializableColor::LightBlue => 12,
+        SerializableColor::LightMagenta => 13,
+        SerializableColor::LightCyan => 14,
+        SerializableColor::White => 15,
+    };
+    output.push_str(&format!("\x1b[58;5;{index}m"));
+}
+
 /// Renders the backend with ANSI codes and includes a legend explaining the styling.
 ///
 /// This is useful for debugging, as it shows what colors and modifiers
@@ -320,3 +320,201 @@ fn test_ansi_render_bright_colors() {
     assert!(output.contains("\x1b[91m")); // Light red FG
     assert!(output.contains("\x1b[104m")); // Light blue BG
 }
+
+fn red_run_backend() -> CaptureBackend {
+    let mut backend = CaptureBackend::new(3, 1);
+    for (i, c) in "RRx".chars().enumerate() {
+        if let Some(cell) = backend.cell_mut(i as u16, 0) {
+            cell.set_char(c);
+            if c == 'R' {
+                cell.fg = SerializableColor::Red;
+            }
+        }
+    }
+    backend
+}
+
+#[test]
+fn test_render_with_default_matches_render() {
+    let backend = red_run_backend();
+    assert_eq!(
+        render_with(&backend, &AnsiOptions::default()),
+        render(&backend)
+    );
+}
+
+#[test]
+fn test_render_with_compact_golden() {
+    let output = render_with(&red_run_backend(), &AnsiOptions::new());
+    assert_eq!(output, "\x1b[0m\x1b[31mRR\x1b[0mx");
+}
+
+#[test]
+fn test_render_with_per_cell_reset_golden() {
+    let options = AnsiOptions::new().with_per_cell_reset(true);
+    let output = render_with(&red_run_backend(), &options);
+    assert_eq!(output, "\x1b[0m\x1b[31mR\x1b[0m\x1b[31mR\x1b[0mx");
+}
+
+#[test]
+fn test_render_with_trailing_reset() {
+    let mut backend = CaptureBackend::new(2, 1);
+    for (i, c) in "ok".chars().enumerate() {
+        if let Some(cell) = backend.cell_mut(i as u16, 0) {
+            cell.set_char(c);
+        }
+    }
+
+    assert_eq!(render_with(&backend, &AnsiOptions::new()), "ok");
+    let options = AnsiOptions::new().with_trailing_reset(true);
+    assert_eq!(render_with(&backend, &options), "ok\x1b[0m");
+
+    // A line that already ends with a reset doesn't get a second one
+    let mut styled = CaptureBackend::new(1, 1);
+    if let Some(cell) = styled.cell_mut(0, 0) {
+        cell.set_char('R');
+        cell.fg = SerializableColor::Red;
+    }
+    assert_eq!(render_with(&styled, &options), "\x1b[0m\x1b[31mR\x1b[0m");
+}
+
+#[test]
+fn test_render_with_ansi256_downsamples_rgb() {
+    let mut backend = CaptureBackend::new(1, 1);
+    if let Some(cell) = backend.cell_mut(0, 0) {
+        cell.set_char('C');
+        cell.fg = SerializableColor::Rgb { r: 255, g: 0, b: 0 };
+        cell.bg = SerializableColor::Rgb {
+            r: 128,
+            g: 128,
+            b: 130,
+        };
+    }
+
+    let options = AnsiOptions::new().with_color_depth(ColorDepth::Ansi256);
+    let output = render_with(&backend, &options);
+    assert_eq!(output, "\x1b[0m\x1b[38;5;196m\x1b[48;5;244mC\x1b[0m");
+}
+
+#[test]
+fn test_render_with_ansi16_downsamples_rgb_and_indexed() {
+    let mut backend = CaptureBackend::new(3, 1);
+    let colors = [
+        SerializableColor::Rgb {
+            r: 250,
+            g: 10,
+            b: 5,
+        },
+        SerializableColor::Indexed(4),
+        SerializableColor::Indexed(231),
+    ];
+    for (i, color) in colors.into_iter().enumerate() {
+        if let Some(cell) = backend.cell_mut(i as u16, 0) {
+            cell.set_char('#');
+            cell.fg = color;
+        }
+    }
+
+    let options = AnsiOptions::new().with_color_depth(ColorDepth::Ansi16);
+    let output = render_with(&backend, &options);
+    assert_eq!(
+        output,
+        "\x1b[0m\x1b[91m#\x1b[0m\x1b[34m#\x1b[0m\x1b[97m#\x1b[0m"
+    );
+}
+
+#[test]
+fn test_render_with_truecolor_keeps_colors() {
+    let mut backend = CaptureBackend::new(1, 1);
+    if let Some(cell) = backend.cell_mut(0, 0) {
+        cell.set_char('T');
+        cell.fg = SerializableColor::Rgb { r: 1, g: 2, b: 3 };
+    }
+
+    let output = render_with(&backend, &AnsiOptions::new());
+    assert!(output.contains("\x1b[38;2;1;2;3m"));
+}
+
+#[test]
+fn test_downsampling_merges_equal_styles() {
+    let mut backend = CaptureBackend::new(2, 1);
+    let colors = [
+        SerializableColor::Rgb { r: 255, g: 0, b: 0 },
+        SerializableColor::Rgb { r: 250, g: 5, b: 5 },
+    ];
+    for (i, color) in colors.into_iter().enumerate() {
+        if let Some(cell) = backend.cell_mut(i as u16, 0) {
+            cell.set_char('#');
+            cell.fg = color;
+        }
+    }
+
+    let options = AnsiOptions::new().with_color_depth(ColorDepth::Ansi16);
+    assert_eq!(render_with(&backend, &options), "\x1b[0m\x1b[91m##\x1b[0m");
+}
+
+#[test]
+fn test_terminal_sequence_carries_style_across_rows() {
+    let mut backend = CaptureBackend::new(2, 2);
+    for y in 0..2 {
+        for x in 0..2 {
+            if let Some(cell) = backend.cell_mut(x, y) {
+                cell.set_char('x');
+                cell.fg = SerializableColor::Green;
+            }
+        }
+    }
+
+    assert_eq!(
+        render_terminal_sequence(&backend),
+        "\x1b[H\x1b[0m\x1b[32mxx\nxx\x1b[0m"
+    );
+}
+
+#[test]
+fn test_terminal_sequence_unstyled_frame_has_no_sgr() {
+    let mut backend = CaptureBackend::new(3, 2);
+    for (i, c) in "abc".chars().enumerate() {
+        if let Some(cell) = backend.cell_mut(i as u16, 1) {
+            cell.set_char(c);
+        }
+    }
+
+    assert_eq!(render_terminal_sequence(&backend), "\x1b[H   \nabc");
+}
+
+#[test]
+fn test_terminal_sequence_skips_wide_char_continuations() {
+    let mut backend = CaptureBackend::new(4, 1);
+    if let Some(cell) = backend.cell_mut(0, 0) {
+        cell.set_symbol("世");
+    }
+    if let Some(cell) = backend.cell_mut(1, 0) {
+        cell.set_symbol("");
+    }
+    if let Some(cell) = backend.cell_mut(2, 0) {
+        cell.skip = true;
+    }
+
+    assert_eq!(render_terminal_sequence(&backend), "\x1b[H世\x1b[C ");
+}
+
+#[test]
+fn test_terminal_sequence_rewrites_style_when_only_underline_color_changes() {
+    let mut backend = CaptureBackend::new(2, 1);
+    for (x, color) in [
+        (0, SerializableColor::Red),
+        (1, SerializableColor::Rgb { r: 1, g: 2, b: 3 }),
+    ] {
+        if let Some(cell) = backend.cell_mut(x, 0) {
+            cell.set_char('u');
+            cell.modifiers.underlined = true;
+            cell.underline_color = Some(color);
+        }
+    }
+
+    assert_eq!(
+        render_terminal_sequence(&backend),
+        "\x1b[H\x1b[0m\x1b[4m\x1b[58;5;1mu\x1b[0m\x1b[4m\x1b[58;2;1;2;3mu\x1b[0m"
+    );
+}
@@ -0,0 +1,185 @@
+//! asciinema recording output.
+//!
+//! [`render_asciicast`] turns a sequence of captured frames into an
+//! asciicast v2 recording that asciinema can replay in a terminal or a
+//! browser. The first frame is drawn in full; each later frame writes only
+//! the cells that changed, so recordings stay small.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use super::ansi::SgrRun;
+use crate::backend::FrameSnapshot;
+use crate::backend::cell::EnhancedCell;
+
+/// How long a frame is shown when no duration is given.
+const DEFAULT_FRAME_DURATION: Duration = Duration::from_millis(100);
+
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+
+/// Renders frames as an asciicast v2 recording.
+///
+/// Frame `i` is shown for `frame_durations[i]`; frames past the end of the
+/// slice reuse its last duration, or 100ms if it is empty. The recording
+/// ends with an empty event once the last frame's time is up, so players
+/// hold the final frame. A frame with a different size from the one before
+/// it emits a resize event and is redrawn in full.
+///
+/// Use this directly with
+/// [`Runtime::frame_history`](crate::app::Runtime::frame_history), or call
+/// [`CaptureBackend::to_asciicast`](crate::backend::CaptureBackend::to_asciicast).
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use envision::backend::CaptureBackend;
+/// use envision::backend::output::render_asciicast;
+///
+/// let backend = CaptureBackend::new(4, 1);
+/// let cast = render_asciicast([&backend.snapshot()], &[Duration::from_secs(1)]);
+///
+/// let mut lines = cast.lines();
+/// assert_eq!(lines.next(), Some(r#"{"version": 2, "width": 4, "height": 1}"#));
+/// assert!(lines.next().unwrap().starts_with("[0.000000, \"o\", "));
+/// assert_eq!(lines.next(), Some(r#"[1.000000, "o", ""]"#));
+/// ```
+pub fn render_asciicast<'a>(
+    frames: impl IntoIterator<Item = &'a FrameSnapshot>,
+    frame_durations: &[Duration],
+) -> String {
+    let frames: Vec<&FrameSnapshot> = frames.into_iter().collect();
+    let (width, height) = frames.first().map_or((0, 0), |frame| frame.size);
+    let mut cast = format!("{{\"version\": 2, \"width\": {width}, \"height\": {height}}}\n");
+
+    let mut time = Duration::ZERO;
+    let mut previous: Option<&FrameSnapshot> = None;
+    for (i, frame) in frames.iter().enumerate() {
+        let resized = previous.is_some_and(|prev| prev.size != frame.size);
+        if resized {
+            let size = format!("{}x{}", frame.size.0, frame.size.1);
+            push_event(&mut cast, time, "r", &size);
+        }
+        let data = frame_output(previous.filter(|_| !resized), frame);
+        if !data.is_empty() {
+            push_event(&mut cast, time, "o", &data);
+        }
+
+        time += frame_durations
+            .get(i)
+            .or(frame_durations.last())
+            .copied()
+            .unwrap_or(DEFAULT_FRAME_DURATION);
+        previous = Some(frame);
+    }
+    if !frames.is_empty() {
+        push_event(&mut cast, time, "o", "");
+    }
+    cast
+}
+
+/// Returns the terminal output that turns `previous` into `frame`, or draws
+/// `frame` from a cleared screen when there is no previous frame.
+fn frame_output(previous: Option<&FrameSnapshot>, frame: &FrameSnapshot) -> String {
+    let mut output = String::new();
+    let cursor_was_visible = previous.is_none_or(|prev| prev.cursor.visible);
+    if previous.is_none() {
+        output.push_str("\x1b[0m\x1b[H\x1b[2J");
+    }
+
+    let (width, height) = frame.size;
+    let cells = frame.cells();
+    let blank = EnhancedCell::new();
+    let mut drawn = false;
+    let mut at: Option<(u16, u16)> = None;
+    let mut run = SgrRun::unknown();
+
+    for y in 0..height {
+        for x in 0..width {
+            let idx = usize::from(y) * usize::from(width) + usize::from(x);
+            let Some(cell) = cells.get(idx) else {
+                continue;
+            };
+            if cell.skip || cell.is_continuation() {
+                continue;
+            }
+            let unchanged = match previous {
+                Some(prev) => prev
+                    .cells()
+                    .get(idx)
+                    .is_some_and(|old| looks_same(old, cell)),
+                // The screen was just cleared
+                None => looks_same(&blank, cell),
+            };
+            if unchanged {
+                continue;
+            }
+
+            if !drawn && cursor_was_visible {
+                output.push_str(HIDE_CURSOR);
+            }
+            drawn = true;
+            if at != Some((x, y)) {
+                move_to(&mut output, x, y);
+            }
+            run.push_cell_style(&mut output, cell);
+            output.push_str(cell.symbol());
+            let advance = u16::try_from(cell.symbol_width().max(1)).unwrap_or(1);
+            at = Some((x.saturating_add(advance), y));
+        }
+    }
+    run.finish(&mut output);
+
+    let cursor = &frame.cursor;
+    let cursor_changed = previous.is_none_or(|prev| {
+        prev.cursor.position != cursor.position || prev.cursor.visible != cursor.visible
+    });
+    if cursor.visible {
+        if drawn || cursor_changed {
+            move_to(&mut output, cursor.position.0, cursor.position.1);
+        }
+        if drawn || !cursor_was_visible {
+            output.push_str(SHOW_CURSOR);
+        }
+    } else if cursor_was_visible && !drawn {
+        output.push_str(HIDE_CURSOR);
+    }
+    output
+}
+
+/// Returns true if two cells look the same on screen.
+fn looks_same(a: &EnhancedCell, b: &EnhancedCell) -> bool {
+    a.symbol() == b.symbol()
+        && a.fg == b.fg
+        && a.bg == b.bg
+        && a.modifiers == b.modifiers
+        && a.underline_color == b.underline_color
+}
+
+/// Writes a cursor movement to the zero-based cell `(x, y)`.
+fn move_to(output: &mut String, x: u16, y: u16) {
+    let _ = write!(output, "\x1b[{};{}H", u32::from(y) + 1, u32::from(x) + 1);
+}
+
+/// Appends an event line: `[time, code, "data"]`.
+fn push_event(cast: &mut String, time: Duration, code: &str, data: &str) {
+    let _ = write!(cast, "[{:.6}, \"{code}\", \"", time.as_secs_f64());
+    for c in data.chars() {
+        match c {
+            '"' => cast.push_str("\\\""),
+            '\\' => cast.push_str("\\\\"),
+            '\n' => cast.push_str("\\n"),
+            '\r' => cast.push_str("\\r"),
+            '\t' => cast.push_str("\\t"),
+            c if u32::from(c) < 0x20 || c == '\u{7f}' => {
+                let _ = write!(cast, "\\u{:04x}", u32::from(c));
+            }
+            c => cast.push(c),
+        }
+    }
+    cast.push_str("\"]\n");
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,186 @@
+use super::*;
+use ratatui::backend::Backend;
+use ratatui::layout::Position;
+
+use crate::backend::CaptureBackend;
+use crate::backend::cell::SerializableColor;
+
+/// Splits a cast into its header and event lines.
+fn events(cast: &str) -> Vec<&str> {
+    cast.lines().skip(1).collect()
+}
+
+fn write(backend: &mut CaptureBackend, x: u16, y: u16, text: &str) {
+    for (i, c) in text.chars().enumerate() {
+        backend.cell_mut(x + i as u16, y).unwrap().set_char(c);
+    }
+}
+
+#[test]
+fn test_header_uses_first_frame_size() {
+    let backend = CaptureBackend::new(12, 3);
+    let cast = render_asciicast([&backend.snapshot()], &[]);
+    assert_eq!(
+        cast.lines().next(),
+        Some(r#"{"version": 2, "width": 12, "height": 3}"#)
+    );
+}
+
+#[test]
+fn test_no_frames() {
+    let cast = render_asciicast([], &[]);
+    assert_eq!(cast, "{\"version\": 2, \"width\": 0, \"height\": 0}\n");
+}
+
+#[test]
+fn test_first_frame_clears_and_draws_text() {
+    let mut backend = CaptureBackend::new(6, 2);
+    backend.hide_cursor().unwrap();
+    write(&mut backend, 1, 1, "hi");
+    let cast = render_asciicast([&backend.snapshot()], &[]);
+    assert_eq!(
+        events(&cast)[0],
+        r#"[0.000000, "o", "\u001b[0m\u001b[H\u001b[2J\u001b[?25l\u001b[2;2H\u001b[0mhi"]"#
+    );
+}
+
+#[test]
+fn test_later_frames_write_only_changes() {
+    let mut backend = CaptureBackend::new(6, 1);
+    backend.hide_cursor().unwrap();
+    write(&mut backend, 0, 0, "abc");
+    let first = backend.snapshot();
+    write(&mut backend, 2, 0, "Z");
+    let second = backend.snapshot();
+
+    let cast = render_asciicast([&first, &second], &[]);
+    assert_eq!(
+        events(&cast)[1],
+        r#"[0.100000, "o", "\u001b[1;3H\u001b[0mZ"]"#
+    );
+}
+
+#[test]
+fn test_unchanged_frame_emits_no_output() {
+    let backend = CaptureBackend::new(4, 1);
+    let frame = backend.snapshot();
+    let cast = render_asciicast([&frame, &frame], &[]);
+    // The first frame, then the closing event
+    assert_eq!(events(&cast).len(), 2);
+}
+
+#[test]
+fn test_durations_accumulate_and_repeat_last() {
+    let mut backend = CaptureBackend::new(4, 1);
+    let mut frames = Vec::new();
+    for c in ['a', 'b', 'c'] {
+        backend.cell_mut(0, 0).unwrap().set_char(c);
+        frames.push(backend.snapshot());
+    }
+    let cast = render_asciicast(
+        &frames,
+        &[Duration::from_millis(250), Duration::from_millis(500)],
+    );
+    let times: Vec<&str> = events(&cast)
+        .iter()
+        .map(|line| &line[1..line.find(',').unwrap()])
+        .collect();
+    assert_eq!(times, ["0.000000", "0.250000", "0.750000", "1.250000"]);
+}
+
+#[test]
+fn test_styles_are_written_and_reset() {
+    let mut backend = CaptureBackend::new(3, 1);
+    backend.hide_cursor().unwrap();
+    let cell = backend.cell_mut(0, 0).unwrap();
+    cell.set_char('R');
+    cell.fg = SerializableColor::Red;
+    let cast = render_asciicast([&backend.snapshot()], &[]);
+    assert!(events(&cast)[0].contains(r#"\u001b[0m\u001b[31mR\u001b[0m"#));
+}
+
+#[test]
+fn test_cursor_is_restored_after_drawing() {
+    let mut backend = CaptureBackend::new(4, 2);
+    write(&mut backend, 0, 0, "x");
+    backend.set_cursor_position(Position::new(2, 1)).unwrap();
+    let cast = render_asciicast([&backend.snapshot()], &[]);
+    assert!(events(&cast)[0].ends_with(r#"x\u001b[2;3H\u001b[?25h"]"#));
+}
+
+#[test]
+fn test_cursor_only_change() {
+    let mut backend = CaptureBackend::new(4, 2);
+    let first = backend.snapshot();
+    backend.hide_cursor().unwrap();
+    let second = backend.snapshot();
+    let cast = render_asciicast([&first, &second], &[]);
+    assert_eq!(events(&cast)[1], r#"[0.100000, "o", "\u001b[?25l"]"#);
+}
+
+#[test]
+fn test_resize_emits_event_and_redraws() {
+    let mut small = CaptureBackend::new(2, 1);
+    small.hide_cursor().unwrap();
+    write(&mut small, 0, 0, "ab");
+    let mut large = CaptureBackend::new(3, 1);
+    large.hide_cursor().unwrap();
+    write(&mut large, 0, 0, "ab");
+
+    let cast = render_asciicast([&small.snapshot(), &large.snapshot()], &[]);
+    let events = events(&cast);
+    assert_eq!(events[1], r#"[0.100000, "r", "3x1"]"#);
+    assert!(events[2].contains(r#"\u001b[2J"#));
+    assert!(events[2].contains("ab"));
+}
+
+#[test]
+fn test_wide_characters_advance_two_columns() {
+    let mut backend = CaptureBackend::new(4, 1);
+    backend.hide_cursor().unwrap();
+    backend.cell_mut(0, 0).unwrap().set_symbol("日");
+    backend.cell_mut(1, 0).unwrap().set_symbol("");
+    backend.cell_mut(2, 0).unwrap().set_char('x');
+    let cast = render_asciicast([&backend.snapshot()], &[]);
+    assert!(events(&cast)[0].ends_with(r#"\u001b[1;1H\u001b[0m日x"]"#));
+}
+
+#[test]
+fn test_data_is_json_escaped() {
+    let mut backend = CaptureBackend::new(4, 1);
+    backend.hide_cursor().unwrap();
+    write(&mut backend, 0, 0, r#""\"#);
+    let cast = render_asciicast([&backend.snapshot()], &[]);
+    assert!(events(&cast)[0].ends_with(r#"\u001b[0m\"\\"]"#));
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn test_events_are_valid_json() {
+    let mut backend = CaptureBackend::new(5, 2);
+    write(&mut backend, 0, 0, "a\"b");
+    backend.cell_mut(0, 1).unwrap().fg = SerializableColor::Blue;
+    let first = backend.snapshot();
+    write(&mut backend, 1, 1, "zz");
+    let cast = render_asciicast([&first, &backend.snapshot()], &[]);
+
+    for line in cast.lines() {
+        serde_json::from_str::<serde_json::Value>(line).unwrap();
+    }
+}
+
+#[test]
+fn test_underline_color_change_rewrites_style() {
+    let mut backend = CaptureBackend::new(2, 1);
+    backend.hide_cursor().unwrap();
+    write(&mut backend, 0, 0, "ab");
+    let first = backend.snapshot();
+    backend.cell_mut(1, 0).unwrap().underline_color = Some(SerializableColor::Green);
+    let second = backend.snapshot();
+
+    let cast = render_asciicast([&first, &second], &[]);
+    assert_eq!(
+        events(&cast)[1],
+        r#"[0.100000, "o", "\u001b[1;2H\u001b[0m\u001b[58;5;2mb\u001b[0m"]"#
+    );
+}
@@ -75,7 +75,7 @@ pub fn render(backend: &CaptureBackend, pretty: bool) -> String {
                     || cell.bg != crate::backend::cell::SerializableColor::Reset
                     || !cell.modifiers.is_empty();
 
-                if has_styling && cell.symbol() != " " {
+                if has_styling && cell.symbol() != " " && !cell.is_continuation() {
                     styled_cells.push(JsonStyledCell {
                         x,
                         y,
@@ -0,0 +1,189 @@
+//! Markdown output formatter.
+//!
+//! Renders the captured buffer as a fenced code block for pasting into
+//! GitHub issues and other markdown documents. Code blocks are
+//! monochrome, so a legend after the block lists where each color is used.
+
+use crate::backend::CaptureBackend;
+use crate::backend::cell::SerializableColor;
+
+/// A colored stretch of one row.
+struct Run {
+    y: u16,
+    start: u16,
+    end: u16,
+    text: String,
+}
+
+/// Renders the backend as a GitHub-flavored markdown code block.
+///
+/// The block holds the plain text of the frame, fenced with enough
+/// backticks that none in the frame can close it early. If any cell has a
+/// foreground or background color, a legend follows listing each color
+/// combination with the rows, columns (both counted from 0) and text of
+/// the runs of cells drawn in it.
+pub fn render(backend: &CaptureBackend) -> String {
+    let text = backend.to_string();
+    let fence = "`".repeat(longest_backtick_run(&text).max(2) + 1);
+    let mut output = format!("{fence}text\n{text}\n{fence}\n");
+
+    let legend = color_runs(backend);
+    if !legend.is_empty() {
+        output.push_str("\nColors (rows and columns from 0):\n\n");
+        for (colors, runs) in legend {
+            let places: Vec<String> = runs.iter().map(describe_run).collect();
+            output.push_str(&format!("- {}: {}\n", colors, places.join("; ")));
+        }
+    }
+    output
+}
+
+/// Groups the colored runs of every row by color, in order of first use.
+fn color_runs(backend: &CaptureBackend) -> Vec<(String, Vec<Run>)> {
+    let mut legend: Vec<((SerializableColor, SerializableColor), Vec<Run>)> = Vec::new();
+    for y in 0..backend.height() {
+        let mut x = 0;
+        while x < backend.width() {
+            let Some(cell) = backend.cell(x, y) else {
+                break;
+            };
+            let colors = (cell.fg, cell.bg);
+            let start = x;
+            let mut text = String::new();
+            while let Some(cell) = backend.cell(x, y).filter(|c| (c.fg, c.bg) == colors) {
+                if !cell.skip {
+                    text.push_str(cell.symbol());
+                }
+                x += 1;
+            }
+            if colors == (SerializableColor::Reset, SerializableColor::Reset) {
+                continue;
+            }
+            let run = Run {
+                y,
+                start,
+                end: x - 1,
+                text,
+            };
+            match legend.iter_mut().find(|(c, _)| *c == colors) {
+                Some((_, runs)) => runs.push(run),
+                None => legend.push((colors, vec![run])),
+            }
+        }
+    }
+    legend
+        .into_iter()
+        .map(|((fg, bg), runs)| (describe_colors(fg, bg), runs))
+        .collect()
+}
+
+fn describe_colors(fg: SerializableColor, bg: SerializableColor) -> String {
+    match (fg, bg) {
+        (fg, SerializableColor::Reset) => format!("`{}` text", color_name(fg)),
+        (SerializableColor::Reset, bg) => format!("`{}` background", color_name(bg)),
+        (fg, bg) => format!("`{}` on `{}`", color_name(fg), color_name(bg)),
+    }
+}
+
+fn describe_run(run: &Run) -> String {
+    let columns = if run.start == run.end {
+        format!("col {}", run.start)
+    } else {
+        format!("cols {}-{}", run.start, run.end)
+    };
+    let text = run.text.trim();
+    if text.is_empty() {
+        format!("row {}, {}", run.y, columns)
+    } else {
+        format!("row {}, {} {}", run.y, columns, inline_code(text))
+    }
+}
+
+fn color_name(color: SerializableColor) -> String {
+    match color {
+        SerializableColor::Rgb { r, g, b } => format!("#{r:02x}{g:02x}{b:02x}"),
+        SerializableColor::Indexed(index) => format!("color {index}"),
+        named => format!("{named:?}"),
+    }
+}
+
+/// Wraps `text` in an inline code span that its own backticks cannot end.
+fn inline_code(text: &str) -> String {
+    let ticks = "`".repeat(longest_backtick_run(text) + 1);
+    if ticks.len() > 1 {
+        format!("{ticks} {text} {ticks}")
+    } else {
+        format!("`{text}`")
+    }
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(backend: &mut CaptureBackend, x: u16, y: u16, text: &str, fg: SerializableColor) {
+        for (i, c) in text.chars().enumerate() {
+            if let Some(cell) = backend.cell_mut(x + i as u16, y) {
+                cell.set_char(c);
+                cell.fg = fg;
+            }
+        }
+    }
+
+    #[test]
+    fn test_markdown_render_without_color_has_no_legend() {
+        let mut backend = CaptureBackend::new(5, 2);
+        write(&mut backend, 0, 0, "hello", SerializableColor::Reset);
+
+        assert_eq!(render(&backend), "```text\nhello\n     \n```\n");
+    }
+
+    #[test]
+    fn test_markdown_render_lists_color_runs() {
+        let mut backend = CaptureBackend::new(8, 3);
+        write(&mut backend, 0, 0, "err", SerializableColor::Red);
+        write(&mut backend, 2, 2, "bad!", SerializableColor::Red);
+        write(&mut backend, 5, 0, "ok", SerializableColor::Green);
+        for x in 0..8 {
+            backend.cell_mut(x, 1).unwrap().bg = SerializableColor::Rgb {
+                r: 0,
+                g: 0x80,
+                b: 0xff,
+            };
+        }
+
+        let output = render(&backend);
+        assert!(output.starts_with("```text\nerr  ok \n"));
+        assert!(output.ends_with(
+            "Colors (rows and columns from 0):\n\n\
+             - `Red` text: row 0, cols 0-2 `err`; row 2, cols 2-5 `bad!`\n\
+             - `Green` text: row 0, cols 5-6 `ok`\n\
+             - `#0080ff` background: row 1, cols 0-7\n"
+        ));
+    }
+
+    #[test]
+    fn test_markdown_render_fences_around_backticks() {
+        let mut backend = CaptureBackend::new(5, 1);
+        write(&mut backend, 0, 0, "```", SerializableColor::Indexed(208));
+
+        let output = render(&backend);
+        assert!(output.starts_with("````text\n```  \n````\n"));
+        assert!(output.ends_with("- `color 208` text: row 0, cols 0-2 ```` ``` ````\n"));
+    }
+
+    #[test]
+    fn test_markdown_render_foreground_on_background() {
+        let mut backend = CaptureBackend::new(3, 1);
+        let cell = backend.cell_mut(1, 0).unwrap();
+        cell.set_char('x');
+        cell.fg = SerializableColor::White;
+        cell.bg = SerializableColor::Blue;
+
+        assert!(render(&backend).ends_with("- `White` on `Blue`: row 0, col 1 `x`\n"));
+    }
+}
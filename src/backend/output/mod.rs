@@ -3,21 +3,40 @@
 //! This module provides various output formats for rendering captured frames:
 //!
 //! - **Plain**: Simple text output without styling
-//! - **Ansi**: Full ANSI escape codes for colored terminal output
+//! - **WithCursor**: Plain text with the cursor cell in reverse video
+//! - **Ansi**: Full ANSI escape codes for colored terminal output (see
+//!   [`AnsiOptions`] for compact, per-cell and downsampled variants)
+//! - **Terminal sequence**: The minimal bytes that redraw a frame on a
+//!   real terminal (see `CaptureBackend::to_terminal_sequence`)
+//! - **asciicast**: An asciinema recording of a sequence of frames (see
+//!   [`render_asciicast`])
+//! - **Markdown**: A fenced code block with a color legend, for bug
+//!   reports (see `CaptureBackend::to_markdown`)
 //! - **Json**: Machine-readable JSON format
 //! - **JsonPretty**: Human-readable pretty-printed JSON
+//!
+//! With the `image` feature, `CaptureBackend::to_half_block_png` also
+//! rasterizes the buffer to a PNG.
 
 mod ansi;
+mod asciicast;
 #[cfg(feature = "serialization")]
 mod json;
+mod markdown;
 mod plain;
+#[cfg(feature = "image")]
+pub(crate) mod png;
 
 use crate::backend::CaptureBackend;
 
-pub use ansi::render_with_legend;
+pub(crate) use ansi::render_terminal_sequence;
+pub(crate) use ansi::render_with as render_ansi_with;
+pub use ansi::{AnsiOptions, render_with_legend};
+pub use asciicast::render_asciicast;
 #[cfg(feature = "serialization")]
 pub use json::render_lines_only;
-pub use plain::render_trimmed;
+pub(crate) use markdown::render as render_markdown;
+pub use plain::{TrimMode, render_trimmed, render_with_trim};
 
 /// Available output formats for rendering captured frames.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -27,6 +46,10 @@ pub enum OutputFormat {
     #[default]
     Plain,
 
+    /// Plain text with the visible cursor cell marked in reverse video.
+    /// Use this to verify cursor placement for text-entry components.
+    WithCursor,
+
     /// ANSI escape codes for full color and styling.
     /// Renders correctly in terminals that support ANSI codes.
     Ansi,
@@ -45,6 +68,7 @@ impl OutputFormat {
     pub fn render(self, backend: &CaptureBackend) -> String {
         match self {
             OutputFormat::Plain => plain::render(backend),
+            OutputFormat::WithCursor => plain::render_with_cursor(backend),
             OutputFormat::Ansi => ansi::render(backend),
             #[cfg(feature = "serialization")]
             OutputFormat::Json => json::render(backend, false),
@@ -20,26 +20,86 @@ pub fn render(backend: &CaptureBackend) -> String {
     lines.join("\n")
 }
 
-/// Renders the backend as plain text with trailing whitespace trimmed.
+/// Renders the backend as plain text with the cursor cell in reverse video.
 ///
-/// This is useful when you want a cleaner output for display,
-/// but note that it may not exactly match the buffer contents.
-pub fn render_trimmed(backend: &CaptureBackend) -> String {
+/// The cell under the visible cursor is wrapped in ANSI reverse-video
+/// escape codes (`ESC[7m` ... `ESC[27m`); all other cells are plain text.
+/// When the cursor is hidden the output is identical to [`render`].
+pub fn render_with_cursor(backend: &CaptureBackend) -> String {
+    let Some(cursor) = backend.visible_cursor() else {
+        return render(backend);
+    };
+
     let height = backend.height();
     let mut lines = Vec::with_capacity(height as usize);
 
     for y in 0..height {
-        lines.push(backend.row_content(y).trim_end().to_string());
-    }
+        if y != cursor.y {
+            lines.push(backend.row_content(y));
+            continue;
+        }
 
-    // Also trim trailing empty lines
-    while lines.last().map(|l| l.is_empty()).unwrap_or(false) {
-        lines.pop();
+        let mut line = String::new();
+        for x in 0..backend.width() {
+            let Some(cell) = backend.cell(x, y) else {
+                continue;
+            };
+            if cell.skip {
+                continue;
+            }
+            if x == cursor.x {
+                line.push_str("\x1b[7m");
+                line.push_str(cell.symbol());
+                line.push_str("\x1b[27m");
+            } else {
+                line.push_str(cell.symbol());
+            }
+        }
+        lines.push(line);
     }
 
     lines.join("\n")
 }
 
+/// How much trailing whitespace to trim from plain text output.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::backend::{CaptureBackend, TrimMode};
+///
+/// let backend = CaptureBackend::new(5, 3);
+/// assert_eq!(backend.to_string_with(TrimMode::None), "     \n     \n     ");
+/// assert_eq!(backend.to_string_with(TrimMode::TrailingSpaces), "\n\n");
+/// assert_eq!(backend.to_string_with(TrimMode::TrailingBlankLines), "");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TrimMode {
+    /// Keep every line padded to the full width. This matches
+    /// [`CaptureBackend`]'s `Display` output.
+    #[default]
+    None,
+    /// Trim trailing spaces from each line.
+    TrailingSpaces,
+    /// Trim trailing spaces from each line and drop the blank lines at the
+    /// end of the output.
+    TrailingBlankLines,
+}
+
+/// Renders the backend as plain text, trimmed according to `mode`.
+pub fn render_with_trim(backend: &CaptureBackend, mode: TrimMode) -> String {
+    backend.lines_with(mode).join("\n")
+}
+
+/// Renders the backend as plain text with trailing whitespace trimmed.
+///
+/// This is useful when you want a cleaner output for display,
+/// but note that it may not exactly match the buffer contents.
+/// Equivalent to [`render_with_trim`] with [`TrimMode::TrailingBlankLines`].
+pub fn render_trimmed(backend: &CaptureBackend) -> String {
+    render_with_trim(backend, TrimMode::TrailingBlankLines)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,6 +123,28 @@ mod tests {
         assert_eq!(lines[0].len(), 10); // Full width preserved
     }
 
+    #[test]
+    fn test_plain_render_with_cursor() {
+        use ratatui::backend::Backend;
+
+        let mut backend = CaptureBackend::new(5, 2);
+        for (i, c) in "abc".chars().enumerate() {
+            if let Some(cell) = backend.cell_mut(i as u16, 1) {
+                cell.set_char(c);
+            }
+        }
+        backend.set_cursor_position((1, 1)).unwrap();
+        backend.show_cursor().unwrap();
+
+        let output = render_with_cursor(&backend);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "     ");
+        assert_eq!(lines[1], "a\x1b[7mb\x1b[27mc  ");
+
+        backend.hide_cursor().unwrap();
+        assert_eq!(render_with_cursor(&backend), render(&backend));
+    }
+
     #[test]
     fn test_plain_render_trimmed() {
         let mut backend = CaptureBackend::new(10, 5);
@@ -81,4 +163,40 @@ mod tests {
         assert_eq!(lines.len(), 1);
         assert_eq!(lines[0], "Hi");
     }
+
+    #[test]
+    fn test_plain_render_with_trim_modes() {
+        let mut backend = CaptureBackend::new(6, 4);
+        for (i, c) in "ab".chars().enumerate() {
+            if let Some(cell) = backend.cell_mut(i as u16, 0) {
+                cell.set_char(c);
+            }
+        }
+        if let Some(cell) = backend.cell_mut(1, 1) {
+            cell.set_char('c');
+        }
+
+        assert_eq!(render_with_trim(&backend, TrimMode::None), render(&backend));
+        assert_eq!(
+            render_with_trim(&backend, TrimMode::TrailingSpaces),
+            "ab\n c\n\n"
+        );
+        assert_eq!(
+            render_with_trim(&backend, TrimMode::TrailingBlankLines),
+            "ab\n c"
+        );
+    }
+
+    #[test]
+    fn test_plain_render_with_trim_keeps_inner_blank_lines() {
+        let mut backend = CaptureBackend::new(4, 3);
+        if let Some(cell) = backend.cell_mut(0, 2) {
+            cell.set_char('z');
+        }
+
+        assert_eq!(
+            render_with_trim(&backend, TrimMode::TrailingBlankLines),
+            "\n\nz"
+        );
+    }
 }
@@ -0,0 +1,134 @@
+//! PNG output formatter.
+//!
+//! Rasterizes the captured buffer into a bitmap, one colored rectangle per
+//! cell, for sharing renders where SVG or ANSI text isn't displayed.
+
+use ratatui::style::Color;
+
+use crate::backend::CaptureBackend;
+use crate::backend::cell::EnhancedCell;
+use crate::backend::color_depth::to_rgb;
+use crate::error::{self, EnvisionError};
+
+/// RGB used for a [`Color::Reset`] foreground.
+const DEFAULT_FG: (u8, u8, u8) = (192, 192, 192);
+
+/// RGB used for a [`Color::Reset`] background.
+const DEFAULT_BG: (u8, u8, u8) = (0, 0, 0);
+
+/// The part of a cell painted in the foreground color, in eighths of the
+/// cell's width and height: `(left, right, top, bottom)`.
+type Coverage = (usize, usize, usize, usize);
+
+/// Renders the backend as a PNG, with each cell `cell_px` pixels wide and
+/// `2 * cell_px` pixels tall.
+///
+/// Block elements (`█`, `▀`, `▄`, `▌`, `▐` and the eighth blocks) paint
+/// exactly the part of the cell they cover. Blank cells are painted in the
+/// background color, and any other symbol fills its cell with the
+/// foreground color. Returns an empty vector if the image would be empty,
+/// and an error if its dimensions don't fit in memory or a PNG header.
+pub fn render(backend: &CaptureBackend, cell_px: u32) -> error::Result<Vec<u8>> {
+    let too_large = || EnvisionError::render("png", "image dimensions overflow");
+    let cell_w = cell_px;
+    let cell_h = cell_px.checked_mul(2).ok_or_else(too_large)?;
+    let width = u32::from(backend.width())
+        .checked_mul(cell_w)
+        .ok_or_else(too_large)?;
+    let height = u32::from(backend.height())
+        .checked_mul(cell_h)
+        .ok_or_else(too_large)?;
+    if width == 0 || height == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Pixel offsets are computed in usize, which the length check bounds
+    let (cell_w, cell_h, width) = (cell_w as usize, cell_h as usize, width as usize);
+    let len = width
+        .checked_mul(height as usize)
+        .and_then(|n| n.checked_mul(3))
+        .ok_or_else(too_large)?;
+
+    let mut pixels = vec![0u8; len];
+    for (y, row) in backend
+        .cells()
+        .chunks(usize::from(backend.width()))
+        .enumerate()
+    {
+        for (x, cell) in row.iter().enumerate() {
+            let (fg, bg) = cell_colors(cell);
+            let (left, right, top, bottom) = coverage(cell.symbol());
+            for py in 0..cell_h {
+                let row_start = ((y * cell_h + py) * width + x * cell_w) * 3;
+                let in_rows = py * 8 >= top * cell_h && py * 8 < bottom * cell_h;
+                for px in 0..cell_w {
+                    let in_cols = px * 8 >= left * cell_w && px * 8 < right * cell_w;
+                    let (r, g, b) = if in_rows && in_cols { fg } else { bg };
+                    let i = row_start + px * 3;
+                    pixels[i..i + 3].copy_from_slice(&[r, g, b]);
+                }
+            }
+        }
+    }
+
+    encode(width as u32, height, &pixels).map_err(EnvisionError::other)
+}
+
+/// Returns the RGB foreground and background of a cell, honoring reverse video.
+fn cell_colors(cell: &EnhancedCell) -> ((u8, u8, u8), (u8, u8, u8)) {
+    let fg = to_rgb(Color::from(cell.fg)).unwrap_or(DEFAULT_FG);
+    let bg = to_rgb(Color::from(cell.bg)).unwrap_or(DEFAULT_BG);
+    if cell.modifiers.reversed {
+        (bg, fg)
+    } else {
+        (fg, bg)
+    }
+}
+
+/// Returns which part of the cell a symbol paints in the foreground color.
+fn coverage(symbol: &str) -> Coverage {
+    const NONE: Coverage = (0, 0, 0, 0);
+    const FULL: Coverage = (0, 8, 0, 8);
+
+    let mut chars = symbol.chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else {
+        // Wide-glyph continuations take the glyph's fill; clusters are
+        // approximated like any other symbol
+        return if symbol.trim().is_empty() && !symbol.is_empty() {
+            NONE
+        } else {
+            FULL
+        };
+    };
+
+    match c {
+        '▀' => (0, 8, 0, 4),
+        '▔' => (0, 8, 0, 1),
+        // Lower one-eighth through seven-eighths blocks
+        '▁'..='▇' => {
+            let eighths = c as usize - '▁' as usize + 1;
+            (0, 8, 8 - eighths, 8)
+        }
+        // Left seven-eighths through one-eighth blocks
+        '▉'..='▏' => {
+            let eighths = '▏' as usize - c as usize + 1;
+            (0, eighths, 0, 8)
+        }
+        '▐' => (4, 8, 0, 8),
+        '▕' => (7, 8, 0, 8),
+        c if c.is_whitespace() || c == '\u{2800}' => NONE,
+        _ => FULL,
+    }
+}
+
+/// Encodes 8-bit RGB pixel data as a PNG.
+fn encode(width: u32, height: u32, pixels: &[u8]) -> Result<Vec<u8>, png::EncodingError> {
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(pixels)?;
+    writer.finish()?;
+    Ok(bytes)
+}
@@ -32,8 +32,11 @@
 //! // Now panels 0 and 1 are both expanded
 //! ```
 
+use std::cell::Cell;
+
 use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use super::{Component, EventContext, RenderContext};
 use crate::input::{Event, Key};
@@ -149,6 +152,86 @@ impl AccordionPanel {
     pub fn is_expanded(&self) -> bool {
         self.expanded
     }
+
+    /// Returns the content rows as rendered with the given layout.
+    ///
+    /// With `width`, long lines are word-wrapped to it. With `max_height`,
+    /// content is capped to that many rows, the last one replaced by "…"
+    /// when rows were cut.
+    fn content_rows(&self, width: Option<usize>, max_height: Option<usize>) -> Vec<String> {
+        let mut rows: Vec<String> = Vec::new();
+        for line in self.content.lines() {
+            match width {
+                Some(width) => wrap_line(line, width, &mut rows),
+                None => rows.push(line.to_string()),
+            }
+        }
+        if rows.is_empty() {
+            rows.push(String::new());
+        }
+
+        if let Some(max) = max_height.map(|max| max.max(1)) {
+            if rows.len() > max {
+                rows.truncate(max);
+                rows[max - 1] = "…".to_string();
+            }
+        }
+        rows
+    }
+
+    /// Returns how many content rows the panel occupies when rendered.
+    fn content_lines(&self, width: Option<usize>, max_height: Option<usize>) -> usize {
+        self.content_rows(width, max_height).len()
+    }
+
+    /// Returns the rendered height of the panel: its header plus its
+    /// content when expanded.
+    fn line_count(&self, width: Option<usize>, max_height: Option<usize>) -> usize {
+        if self.expanded {
+            1 + self.content_lines(width, max_height)
+        } else {
+            1
+        }
+    }
+}
+
+/// Word-wraps `line` to `width` columns, pushing each row onto `rows`.
+///
+/// Words longer than `width` are broken mid-word.
+fn wrap_line(line: &str, width: usize, rows: &mut Vec<String>) {
+    let width = width.max(1);
+    let start = rows.len();
+    let mut remaining = line;
+    while UnicodeWidthStr::width(remaining) > width {
+        let mut col = 0;
+        let mut break_at = remaining.len();
+        let mut last_space = None;
+        for (idx, ch) in remaining.char_indices() {
+            // A space right at the edge is still a break opportunity
+            if ch == ' ' {
+                last_space = Some(idx);
+            }
+            let ch_width = UnicodeWidthChar::width(ch).unwrap_or(0);
+            if col + ch_width > width {
+                break_at = idx;
+                break;
+            }
+            col += ch_width;
+        }
+        let (row, rest) = match last_space {
+            Some(space) if space > 0 => (&remaining[..space], &remaining[space + 1..]),
+            _ => {
+                // Take at least one character so wide glyphs make progress
+                let first_len = remaining.chars().next().map_or(0, char::len_utf8);
+                remaining.split_at(break_at.max(first_len))
+            }
+        };
+        rows.push(row.trim_end().to_string());
+        remaining = rest;
+    }
+    if !remaining.is_empty() || rows.len() == start {
+        rows.push(remaining.to_string());
+    }
 }
 
 /// Messages that can be sent to an Accordion.
@@ -188,7 +271,7 @@ pub enum AccordionOutput {
 }
 
 /// State for an Accordion component.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default)]
 #[cfg_attr(
     feature = "serialization",
     derive(serde::Serialize, serde::Deserialize)
@@ -198,6 +281,33 @@ pub struct AccordionState {
     panels: Vec<AccordionPanel>,
     /// Currently focused panel index.
     focused_index: usize,
+    /// Number of rendered lines scrolled out of view at the top.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    scroll_offset: usize,
+    /// Whether panel content is word-wrapped to the available width.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    content_wrap: bool,
+    /// Maximum number of content rows shown per expanded panel.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    max_content_height: Option<usize>,
+    /// The viewport height and content wrap width seen by the last
+    /// render, used to keep the focused panel visible as focus moves.
+    ///
+    /// Runtime-only state; not part of logical equality and not
+    /// serialized.
+    #[cfg_attr(feature = "serialization", serde(skip))]
+    viewport: Cell<Option<(usize, Option<usize>)>>,
+}
+
+impl PartialEq for AccordionState {
+    fn eq(&self, other: &Self) -> bool {
+        // `viewport` is excluded: it only caches the last render.
+        self.panels == other.panels
+            && self.focused_index == other.focused_index
+            && self.scroll_offset == other.scroll_offset
+            && self.content_wrap == other.content_wrap
+            && self.max_content_height == other.max_content_height
+    }
 }
 
 impl AccordionState {
@@ -220,6 +330,10 @@ impl AccordionState {
         Self {
             panels,
             focused_index: 0,
+            scroll_offset: 0,
+            content_wrap: false,
+            max_content_height: None,
+            viewport: Cell::new(None),
         }
     }
 
@@ -469,6 +583,156 @@ impl AccordionState {
         self
     }
 
+    /// Enables word-wrapping of panel content (builder method).
+    ///
+    /// Wrapped content is sized to the width the accordion is rendered
+    /// at, minus the two-column indent. Disabled by default, in which case
+    /// long lines are clipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::AccordionState;
+    ///
+    /// let state = AccordionState::from_pairs(vec![("A", "1")]).with_content_wrap(true);
+    /// assert!(state.content_wrap());
+    /// ```
+    pub fn with_content_wrap(mut self, wrap: bool) -> Self {
+        self.content_wrap = wrap;
+        self
+    }
+
+    /// Returns true if panel content is word-wrapped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::AccordionState;
+    ///
+    /// let state = AccordionState::from_pairs(vec![("A", "1")]);
+    /// assert!(!state.content_wrap());
+    /// ```
+    pub fn content_wrap(&self) -> bool {
+        self.content_wrap
+    }
+
+    /// Caps each expanded panel's content to `rows` rows (builder method).
+    ///
+    /// When content is cut, its last visible row shows "…". The cap
+    /// applies after wrapping and is at least one row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::AccordionState;
+    ///
+    /// let state = AccordionState::from_pairs(vec![("A", "1")]).with_max_content_height(5);
+    /// assert_eq!(state.max_content_height(), Some(5));
+    /// ```
+    pub fn with_max_content_height(mut self, rows: usize) -> Self {
+        self.max_content_height = Some(rows);
+        self
+    }
+
+    /// Returns the per-panel content row cap, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::AccordionState;
+    ///
+    /// let state = AccordionState::from_pairs(vec![("A", "1")]);
+    /// assert_eq!(state.max_content_height(), None);
+    /// ```
+    pub fn max_content_height(&self) -> Option<usize> {
+        self.max_content_height
+    }
+
+    /// Returns the number of rendered lines scrolled out of view.
+    ///
+    /// Each panel takes one line for its header plus one line per content
+    /// row when expanded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::AccordionState;
+    ///
+    /// let state = AccordionState::from_pairs(vec![("A", "1"), ("B", "2")]);
+    /// assert_eq!(state.scroll_offset(), 0);
+    /// ```
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll_offset
+    }
+
+    /// Scrolls so the focused panel is visible in a viewport of
+    /// `visible_lines` lines and `width` columns.
+    ///
+    /// The focused panel's header is always brought into view; its
+    /// expanded content is shown as far as it fits below the header.
+    /// Once the accordion has rendered, [`Accordion::update`] makes the
+    /// same adjustment as focus moves, so this is only needed to position
+    /// the offset before the first render. `width` is the width of the
+    /// render area, which decides how many rows wrapped content takes with
+    /// [`with_content_wrap`](Self::with_content_wrap).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Accordion, AccordionMessage, AccordionState, Component};
+    ///
+    /// let mut state = AccordionState::from_pairs(
+    ///     (0..10).map(|i| (format!("Panel {i}"), "content")).collect(),
+    /// );
+    /// Accordion::update(&mut state, AccordionMessage::Last);
+    /// state.ensure_focused_visible(4, 40);
+    /// assert_eq!(state.scroll_offset(), 6);
+    /// ```
+    pub fn ensure_focused_visible(&mut self, visible_lines: usize, width: u16) {
+        let width = self.wrap_width(width);
+        self.viewport.set(Some((visible_lines, width)));
+        self.scroll_offset = self.visible_scroll(visible_lines, width);
+    }
+
+    /// Re-scrolls for the viewport of the last render so focus changes
+    /// keep the focused panel visible.
+    fn follow_focus(&mut self) {
+        if let Some((visible_lines, width)) = self.viewport.get() {
+            self.scroll_offset = self.visible_scroll(visible_lines, width);
+        }
+    }
+
+    /// Returns the content wrap width for a render width, if wrapping.
+    fn wrap_width(&self, area_width: u16) -> Option<usize> {
+        self.content_wrap
+            .then(|| usize::from(area_width.saturating_sub(2)))
+    }
+
+    /// Returns the scroll offset that keeps the focused panel visible in
+    /// a viewport of `visible_lines` lines, with content wrapped to `width`.
+    fn visible_scroll(&self, visible_lines: usize, width: Option<usize>) -> usize {
+        if visible_lines == 0 || self.panels.is_empty() {
+            return self.scroll_offset;
+        }
+
+        let focused = self.focused_index.min(self.panels.len() - 1);
+        let max_height = self.max_content_height;
+        let line_count = |panel: &AccordionPanel| panel.line_count(width, max_height);
+        let top: usize = self.panels[..focused].iter().map(line_count).sum();
+        let bottom = top + line_count(&self.panels[focused]);
+        let total: usize = self.panels.iter().map(line_count).sum();
+
+        let mut scroll = self.scroll_offset.min(total.saturating_sub(visible_lines));
+        // Bring the content into view, but never push the header off the top.
+        if bottom > scroll + visible_lines {
+            scroll = (bottom - visible_lines).min(top);
+        }
+        if top < scroll {
+            scroll = top;
+        }
+        scroll
+    }
+
     /// Returns the count of expanded panels.
     ///
     /// # Examples
@@ -601,7 +865,7 @@ impl Component for Accordion {
     }
 
     fn update(state: &mut Self::State, msg: Self::Message) -> Option<Self::Output> {
-        match msg {
+        let output = match msg {
             AccordionMessage::Down => {
                 if !state.panels.is_empty() {
                     state.focused_index = (state.focused_index + 1) % state.panels.len();
@@ -727,7 +991,9 @@ impl Component for Accordion {
                     None
                 }
             }
-        }
+        };
+        state.follow_focus();
+        output
     }
 
     fn handle_event(
@@ -738,7 +1004,7 @@ impl Component for Accordion {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Up | Key::Char('k') => Some(AccordionMessage::Up),
                 Key::Down | Key::Char('j') => Some(AccordionMessage::Down),
@@ -766,55 +1032,64 @@ impl Component for Accordion {
             );
         });
 
-        let mut y = ctx.area.y;
+        let height = ctx.area.height as usize;
+        let width = state.wrap_width(ctx.area.width);
+        state.viewport.set(Some((height, width)));
+        let scroll = state.visible_scroll(height, width);
+        let mut line = 0;
 
         for (i, panel) in state.panels.iter().enumerate() {
-            if y >= ctx.area.bottom() {
+            if line >= scroll + height {
                 break;
             }
 
             // Header line
-            let is_focused_panel = ctx.focused && i == state.focused_index;
-            let icon = if panel.expanded { "▼" } else { "▶" };
-            let header = format!("{} {}", icon, panel.title);
-
-            let header_style = if ctx.disabled {
-                ctx.theme.disabled_style()
-            } else if is_focused_panel {
-                ctx.theme.focused_bold_style()
-            } else {
-                ctx.theme.normal_style()
-            };
-
-            let header_area = Rect::new(ctx.area.x, y, ctx.area.width, 1);
-            ctx.frame
-                .render_widget(Paragraph::new(header).style(header_style), header_area);
-            y += 1;
-
-            // Content (if expanded)
-            if panel.expanded && y < ctx.area.bottom() {
-                let content_lines = panel.content.lines().count().max(1) as u16;
-                let available_height = ctx.area.bottom().saturating_sub(y);
-                let content_height = content_lines.min(available_height);
-
-                if content_height > 0 {
+            if line >= scroll {
+                let is_focused_panel = ctx.focused && i == state.focused_index;
+                let icon = if panel.expanded { "▼" } else { "▶" };
+                let header = format!("{} {}", icon, panel.title);
+
+                let header_style = if ctx.disabled {
+                    ctx.theme.disabled_style()
+                } else if is_focused_panel {
+                    ctx.theme.focused_bold_style()
+                } else {
+                    ctx.theme.normal_style()
+                };
+
+                let y = ctx.area.y + (line - scroll) as u16;
+                let header_area = Rect::new(ctx.area.x, y, ctx.area.width, 1);
+                ctx.frame
+                    .render_widget(Paragraph::new(header).style(header_style), header_area);
+            }
+            line += 1;
+
+            // Content (if expanded), clipped to the visible window
+            if panel.expanded {
+                let rows = panel.content_rows(width, state.max_content_height);
+                let first = line.max(scroll);
+                let last = (line + rows.len()).min(scroll + height);
+
+                if first < last {
                     let content_area = Rect::new(
                         ctx.area.x + 2,
-                        y,
+                        ctx.area.y + (first - scroll) as u16,
                         ctx.area.width.saturating_sub(2),
-                        content_height,
+                        (last - first) as u16,
                     );
                     let content_style = if ctx.disabled {
                         ctx.theme.disabled_style()
                     } else {
                         ctx.theme.placeholder_style()
                     };
-                    ctx.frame.render_widget(
-                        Paragraph::new(panel.content.as_str()).style(content_style),
-                        content_area,
-                    );
-                    y += content_height;
+                    let visible: Vec<Line> = rows[first - line..last - line]
+                        .iter()
+                        .map(|row| Line::from(row.as_str()))
+                        .collect();
+                    ctx.frame
+                        .render_widget(Paragraph::new(visible).style(content_style), content_area);
                 }
+                line += rows.len();
             }
         }
     }
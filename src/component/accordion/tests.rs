@@ -816,3 +816,238 @@ fn test_selected_index_after_focus_change() {
     assert_eq!(state.selected_index(), Some(1));
     assert_eq!(state.selected(), Some(1));
 }
+
+// Scrolling tests
+
+fn render_accordion(state: &AccordionState, height: u16) -> String {
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, height);
+    terminal
+        .draw(|frame| {
+            Accordion::view(
+                state,
+                &mut RenderContext::new(frame, frame.area(), &theme).focused(true),
+            );
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+fn many_panels() -> AccordionState {
+    AccordionState::from_pairs((0..10).map(|i| (format!("Panel {i}"), "body")).collect())
+}
+
+#[test]
+fn test_scroll_offset_defaults_to_zero() {
+    assert_eq!(many_panels().scroll_offset(), 0);
+}
+
+#[test]
+fn test_view_scrolls_focused_panel_into_view() {
+    let mut state = many_panels();
+    Accordion::update(&mut state, AccordionMessage::Last);
+
+    let output = render_accordion(&state, 4);
+    assert!(output.contains("Panel 9"));
+    assert!(output.contains("Panel 6"));
+    assert!(!output.contains("Panel 5"));
+}
+
+#[test]
+fn test_view_shows_expanded_content_of_focused_panel() {
+    let mut state = AccordionState::new(
+        (0..6)
+            .map(|i| AccordionPanel::new(format!("Panel {i}"), "line one\nline two"))
+            .collect(),
+    );
+    Accordion::update(&mut state, AccordionMessage::Last);
+    Accordion::update(&mut state, AccordionMessage::Expand);
+
+    let output = render_accordion(&state, 4);
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines[1].contains("Panel 5"));
+    assert!(lines[2].contains("line one"));
+    assert!(lines[3].contains("line two"));
+}
+
+#[test]
+fn test_ensure_focused_visible_keeps_header_when_content_too_tall() {
+    let mut state = AccordionState::new(vec![
+        AccordionPanel::new("A", "1"),
+        AccordionPanel::new("B", "1\n2\n3\n4\n5\n6").expanded(),
+    ]);
+    Accordion::update(&mut state, AccordionMessage::Down);
+    state.ensure_focused_visible(3, 30);
+    assert_eq!(state.scroll_offset(), 1);
+
+    let output = render_accordion(&state, 3);
+    let first = output.lines().next().unwrap();
+    assert!(first.contains("B"));
+}
+
+#[test]
+fn test_ensure_focused_visible_scrolls_back_up() {
+    let mut state = many_panels();
+    Accordion::update(&mut state, AccordionMessage::Last);
+    state.ensure_focused_visible(3, 30);
+    assert_eq!(state.scroll_offset(), 7);
+
+    Accordion::update(&mut state, AccordionMessage::First);
+    state.ensure_focused_visible(3, 30);
+    assert_eq!(state.scroll_offset(), 0);
+    assert!(render_accordion(&state, 3).contains("Panel 0"));
+}
+
+#[test]
+fn test_update_keeps_offset_stable_after_render() {
+    let mut state = many_panels();
+    render_accordion(&state, 3);
+
+    for _ in 0..5 {
+        Accordion::update(&mut state, AccordionMessage::Down);
+    }
+    assert_eq!(state.scroll_offset(), 3);
+
+    // Moving up within the viewport doesn't scroll
+    Accordion::update(&mut state, AccordionMessage::Up);
+    assert_eq!(state.scroll_offset(), 3);
+    let output = render_accordion(&state, 3);
+    assert!(output.lines().next().unwrap().contains("Panel 3"));
+
+    Accordion::update(&mut state, AccordionMessage::Up);
+    Accordion::update(&mut state, AccordionMessage::Up);
+    assert_eq!(state.scroll_offset(), 2);
+}
+
+#[test]
+fn test_update_without_render_leaves_offset() {
+    let mut state = many_panels();
+    Accordion::update(&mut state, AccordionMessage::Last);
+    assert_eq!(state.scroll_offset(), 0);
+}
+
+// Content wrap and height cap tests
+
+#[test]
+fn test_content_wrap_and_max_height_default_off() {
+    let state = AccordionState::from_pairs(vec![("A", "1")]);
+    assert!(!state.content_wrap());
+    assert_eq!(state.max_content_height(), None);
+}
+
+#[test]
+fn test_long_line_clipped_without_wrap() {
+    let state = AccordionState::new(vec![
+        AccordionPanel::new("A", "alpha beta gamma delta epsilon zeta").expanded(),
+        AccordionPanel::new("B", "b"),
+    ]);
+    let output = render_accordion(&state, 4);
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines[1].contains("alpha beta"));
+    assert!(!output.contains("zeta"));
+    assert!(lines[2].contains("B"));
+}
+
+#[test]
+fn test_content_wrap_renders_wrapped_rows() {
+    let state = AccordionState::new(vec![
+        AccordionPanel::new("A", "alpha beta gamma delta epsilon zeta").expanded(),
+        AccordionPanel::new("B", "b"),
+    ])
+    .with_content_wrap(true);
+    let output = render_accordion(&state, 5);
+    let lines: Vec<&str> = output.lines().collect();
+    // 30 columns minus the 2-column indent leaves 28 for content
+    assert_eq!(lines[1].trim_end(), "  alpha beta gamma delta");
+    assert_eq!(lines[2].trim_end(), "  epsilon zeta");
+    assert!(lines[3].contains("B"));
+}
+
+#[test]
+fn test_max_content_height_truncates_with_ellipsis() {
+    let state = AccordionState::new(vec![
+        AccordionPanel::new("A", "1\n2\n3\n4\n5").expanded(),
+        AccordionPanel::new("B", "b"),
+    ])
+    .with_max_content_height(3);
+    let output = render_accordion(&state, 6);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[1].trim_end(), "  1");
+    assert_eq!(lines[2].trim_end(), "  2");
+    assert_eq!(lines[3].trim_end(), "  …");
+    assert!(lines[4].contains("B"));
+}
+
+#[test]
+fn test_max_content_height_leaves_short_content() {
+    let state = AccordionState::new(vec![
+        AccordionPanel::new("A", "1\n2").expanded(),
+        AccordionPanel::new("B", "b"),
+    ])
+    .with_max_content_height(3);
+    let output = render_accordion(&state, 5);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[2].trim_end(), "  2");
+    assert!(lines[3].contains("B"));
+}
+
+#[test]
+fn test_max_content_height_caps_scroll_math() {
+    let mut state = AccordionState::new(vec![
+        AccordionPanel::new("A", "1\n2\n3\n4\n5\n6\n7\n8").expanded(),
+        AccordionPanel::new("B", "b"),
+    ])
+    .with_max_content_height(2);
+    Accordion::update(&mut state, AccordionMessage::Down);
+    state.ensure_focused_visible(4, 30);
+    assert_eq!(state.scroll_offset(), 0);
+    assert!(render_accordion(&state, 4).contains("A"));
+}
+
+#[test]
+fn test_wrapped_content_scrolls_focused_panel_into_view() {
+    let mut state = AccordionState::new(vec![
+        AccordionPanel::new("A", "word ".repeat(30)).expanded(),
+        AccordionPanel::new("B", "b"),
+    ])
+    .with_content_wrap(true);
+    Accordion::update(&mut state, AccordionMessage::Down);
+
+    let output = render_accordion(&state, 3);
+    assert!(output.lines().any(|line| line.contains("▶ B")));
+}
+
+#[test]
+fn test_ensure_focused_visible_counts_wrapped_rows() {
+    let mut state = AccordionState::new(vec![
+        AccordionPanel::new("A", "alpha beta gamma delta epsilon zeta").expanded(),
+        AccordionPanel::new("B", "b").expanded(),
+    ])
+    .with_content_wrap(true);
+    Accordion::update(&mut state, AccordionMessage::Down);
+
+    // At 30 columns A's content wraps onto two rows, so B's content ends
+    // on line 5 and a 4-line viewport scrolls by one.
+    state.ensure_focused_visible(4, 30);
+    assert_eq!(state.scroll_offset(), 1);
+    let output = render_accordion(&state, 4);
+    assert!(output.lines().next().unwrap().contains("alpha beta"));
+}
+
+#[test]
+fn test_wrap_line() {
+    let mut rows = Vec::new();
+    wrap_line("hello world again", 5, &mut rows);
+    assert_eq!(rows, vec!["hello", "world", "again"]);
+
+    let mut rows = Vec::new();
+    wrap_line("abcdefgh", 3, &mut rows);
+    assert_eq!(rows, vec!["abc", "def", "gh"]);
+
+    let mut rows = Vec::new();
+    wrap_line("日本語", 1, &mut rows);
+    assert_eq!(rows, vec!["日", "本", "語"]);
+
+    let mut rows = Vec::new();
+    wrap_line("", 4, &mut rows);
+    assert_eq!(rows, vec![""]);
+}
@@ -711,7 +711,7 @@ impl Component for AlertPanel {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
 
         match key.code {
             Key::Left | Key::Char('h') => Some(AlertPanelMessage::SelectPrev),
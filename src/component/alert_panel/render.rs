@@ -7,7 +7,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph, Sparkline};
 
 use super::{AlertPanelState, AlertState};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// State indicator symbols for each alert level.
 const OK_INDICATOR: &str = "\u{25cf}"; // ●
@@ -24,7 +24,7 @@ pub(super) fn render_alert_panel(
     state: &AlertPanelState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -126,7 +126,7 @@ fn render_metric_card(
     state: &AlertPanelState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 ) {
@@ -246,7 +246,7 @@ fn state_indicator(state: &AlertState) -> &'static str {
 }
 
 /// Returns the appropriate style for a given alert state.
-fn state_color(state: &AlertState, theme: &Theme) -> Style {
+fn state_color(state: &AlertState, theme: &dyn ThemeProvider) -> Style {
     match state {
         AlertState::Ok => theme.success_style(),
         AlertState::Warning => theme.warning_style(),
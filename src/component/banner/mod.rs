@@ -0,0 +1,369 @@
+//! A persistent inline banner for pane-level notices.
+//!
+//! [`Banner`] renders a single full-width line such as "Unsaved changes" or
+//! "Offline" at the top of a pane. Unlike [`Toast`](super::Toast), it is
+//! not an overlay and never expires: it lives in the normal layout until it
+//! is dismissed or replaced. State is stored in [`BannerState`], updated via
+//! [`BannerMessage`], and produces [`BannerOutput`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use envision::component::{
+//!     Banner, BannerLevel, BannerMessage, BannerOutput, BannerState, Component,
+//! };
+//!
+//! let mut state = BannerState::new().with_dismissible(true);
+//! assert!(!state.is_visible());
+//!
+//! Banner::update(&mut state, BannerMessage::Show {
+//!     message: "Offline".to_string(),
+//!     level: BannerLevel::Warning,
+//! });
+//! assert!(state.is_visible());
+//! assert_eq!(state.message(), "Offline");
+//!
+//! let output = Banner::update(&mut state, BannerMessage::Dismiss);
+//! assert_eq!(output, Some(BannerOutput::Dismissed));
+//! assert!(!state.is_visible());
+//! ```
+
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use super::{Component, EventContext, RenderContext, Toggleable};
+use crate::input::{Event, Key};
+
+/// Severity level for a banner, selecting its theme style.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum BannerLevel {
+    /// General information (blue).
+    #[default]
+    Info,
+    /// Successful operation (green).
+    Success,
+    /// Warning message (yellow).
+    Warning,
+    /// Error message (red).
+    Error,
+}
+
+/// Messages that can be sent to a Banner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BannerMessage {
+    /// Show the banner with a message and level, replacing any current one.
+    Show {
+        /// The message to display.
+        message: String,
+        /// The severity level.
+        level: BannerLevel,
+    },
+    /// Hide the banner.
+    Dismiss,
+}
+
+/// Output messages from a Banner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BannerOutput {
+    /// The banner was dismissed.
+    Dismissed,
+}
+
+/// State for a Banner component.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct BannerState {
+    /// The message text.
+    message: String,
+    /// The severity level.
+    level: BannerLevel,
+    /// Whether the user can dismiss the banner.
+    dismissible: bool,
+    /// Whether the banner is shown.
+    visible: bool,
+}
+
+impl BannerState {
+    /// Creates a hidden banner with no message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::BannerState;
+    ///
+    /// let state = BannerState::new();
+    /// assert!(!state.is_visible());
+    /// assert_eq!(state.message(), "");
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a visible banner with the given message and level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{BannerLevel, BannerState};
+    ///
+    /// let state = BannerState::with_message("Unsaved changes", BannerLevel::Warning);
+    /// assert!(state.is_visible());
+    /// assert_eq!(state.level(), BannerLevel::Warning);
+    /// ```
+    pub fn with_message(message: impl Into<String>, level: BannerLevel) -> Self {
+        Self {
+            message: message.into(),
+            level,
+            dismissible: false,
+            visible: true,
+        }
+    }
+
+    /// Sets whether the user can dismiss the banner (builder pattern).
+    ///
+    /// A dismissible banner shows a `✕` on the right and is dismissed with
+    /// Esc when focused.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::BannerState;
+    ///
+    /// let state = BannerState::new().with_dismissible(true);
+    /// assert!(state.is_dismissible());
+    /// ```
+    pub fn with_dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+
+    /// Returns the message text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{BannerLevel, BannerState};
+    ///
+    /// let state = BannerState::with_message("Offline", BannerLevel::Error);
+    /// assert_eq!(state.message(), "Offline");
+    /// ```
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the severity level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{BannerLevel, BannerState};
+    ///
+    /// assert_eq!(BannerState::new().level(), BannerLevel::Info);
+    /// ```
+    pub fn level(&self) -> BannerLevel {
+        self.level
+    }
+
+    /// Returns whether the user can dismiss the banner.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::BannerState;
+    ///
+    /// assert!(!BannerState::new().is_dismissible());
+    /// ```
+    pub fn is_dismissible(&self) -> bool {
+        self.dismissible
+    }
+
+    /// Sets whether the user can dismiss the banner.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::BannerState;
+    ///
+    /// let mut state = BannerState::new();
+    /// state.set_dismissible(true);
+    /// assert!(state.is_dismissible());
+    /// ```
+    pub fn set_dismissible(&mut self, dismissible: bool) {
+        self.dismissible = dismissible;
+    }
+
+    /// Returns whether the banner is shown.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{BannerLevel, BannerState};
+    ///
+    /// assert!(!BannerState::new().is_visible());
+    /// assert!(BannerState::with_message("Offline", BannerLevel::Error).is_visible());
+    /// ```
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Updates the banner state with a message, returning any output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{BannerLevel, BannerMessage, BannerOutput, BannerState};
+    ///
+    /// let mut state = BannerState::with_message("Offline", BannerLevel::Error);
+    /// assert_eq!(state.update(BannerMessage::Dismiss), Some(BannerOutput::Dismissed));
+    /// ```
+    pub fn update(&mut self, msg: BannerMessage) -> Option<BannerOutput> {
+        Banner::update(self, msg)
+    }
+}
+
+/// A persistent, full-width inline banner.
+///
+/// The banner occupies one line and is styled with its level's theme style
+/// in reverse video, so it reads as a colored bar. When hidden it renders
+/// nothing, leaving its area blank.
+///
+/// # Keyboard Activation
+///
+/// When focused and dismissible, Esc dismisses the banner.
+///
+/// # Visual Layout
+///
+/// ```text
+/// ! Unsaved changes                          ✕
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use envision::component::{Banner, BannerLevel, BannerMessage, BannerState, Component};
+///
+/// let mut state = BannerState::new();
+/// Banner::update(&mut state, BannerMessage::Show {
+///     message: "Unsaved changes".to_string(),
+///     level: BannerLevel::Warning,
+/// });
+/// assert!(state.is_visible());
+/// ```
+pub struct Banner;
+
+impl Component for Banner {
+    type State = BannerState;
+    type Message = BannerMessage;
+    type Output = BannerOutput;
+
+    fn init() -> Self::State {
+        BannerState::default()
+    }
+
+    fn update(state: &mut Self::State, msg: Self::Message) -> Option<Self::Output> {
+        match msg {
+            BannerMessage::Show { message, level } => {
+                state.message = message;
+                state.level = level;
+                state.visible = true;
+                None
+            }
+            BannerMessage::Dismiss => {
+                if state.visible {
+                    state.visible = false;
+                    Some(BannerOutput::Dismissed)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn handle_event(
+        state: &Self::State,
+        event: &Event,
+        ctx: &EventContext,
+    ) -> Option<Self::Message> {
+        if !ctx.focused || ctx.disabled || !state.visible || !state.dismissible {
+            return None;
+        }
+        if let Some(key) = event.as_key_press() {
+            match key.code {
+                Key::Esc => Some(BannerMessage::Dismiss),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    fn view(state: &Self::State, ctx: &mut RenderContext<'_, '_>) {
+        if !state.visible || ctx.area.height == 0 || ctx.area.width == 0 {
+            return;
+        }
+
+        crate::annotation::with_registry(|reg| {
+            reg.register(
+                ctx.area,
+                crate::annotation::Annotation::new(crate::annotation::WidgetType::Custom(
+                    "Banner".to_string(),
+                ))
+                .with_id("banner")
+                .with_label(state.message.as_str())
+                .with_focus(ctx.focused)
+                .with_disabled(ctx.disabled),
+            );
+        });
+
+        let (level_style, prefix) = match state.level {
+            BannerLevel::Info => (ctx.theme.info_style(), "i"),
+            BannerLevel::Success => (ctx.theme.success_style(), "+"),
+            BannerLevel::Warning => (ctx.theme.warning_style(), "!"),
+            BannerLevel::Error => (ctx.theme.error_style(), "x"),
+        };
+        let style = if ctx.disabled {
+            ctx.theme.disabled_style()
+        } else {
+            level_style
+        }
+        .add_modifier(Modifier::REVERSED);
+
+        let area = Rect {
+            height: 1,
+            ..ctx.area
+        };
+        let text = format!(" {} {}", prefix, state.message);
+        ctx.frame
+            .render_widget(Paragraph::new(text).style(style), area);
+
+        if state.dismissible && area.width > 2 {
+            let close_area = Rect {
+                x: area.right() - 2,
+                width: 2,
+                ..area
+            };
+            ctx.frame
+                .render_widget(Paragraph::new("\u{2715} ").style(style), close_area);
+        }
+    }
+}
+
+impl Toggleable for Banner {
+    fn is_visible(state: &Self::State) -> bool {
+        state.visible
+    }
+
+    fn set_visible(state: &mut Self::State, visible: bool) {
+        state.visible = visible;
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,256 @@
+use super::*;
+use crate::input::{Event, Key};
+
+fn shown() -> BannerState {
+    BannerState::with_message("Unsaved changes", BannerLevel::Warning).with_dismissible(true)
+}
+
+// ========================================
+// Construction Tests
+// ========================================
+
+#[test]
+fn test_new_is_hidden() {
+    let state = BannerState::new();
+    assert!(!state.is_visible());
+    assert_eq!(state.message(), "");
+    assert_eq!(state.level(), BannerLevel::Info);
+    assert!(!state.is_dismissible());
+}
+
+#[test]
+fn test_init_matches_default() {
+    assert_eq!(Banner::init(), BannerState::default());
+}
+
+#[test]
+fn test_with_message_is_visible() {
+    let state = BannerState::with_message("Offline", BannerLevel::Error);
+    assert!(state.is_visible());
+    assert_eq!(state.message(), "Offline");
+    assert_eq!(state.level(), BannerLevel::Error);
+}
+
+#[test]
+fn test_set_dismissible() {
+    let mut state = BannerState::new();
+    state.set_dismissible(true);
+    assert!(state.is_dismissible());
+}
+
+// ========================================
+// Update Tests
+// ========================================
+
+#[test]
+fn test_show_replaces_message() {
+    let mut state = shown();
+    let output = state.update(BannerMessage::Show {
+        message: "Offline".to_string(),
+        level: BannerLevel::Error,
+    });
+    assert_eq!(output, None);
+    assert_eq!(state.message(), "Offline");
+    assert_eq!(state.level(), BannerLevel::Error);
+    assert!(state.is_visible());
+}
+
+#[test]
+fn test_dismiss_hides() {
+    let mut state = shown();
+    assert_eq!(
+        state.update(BannerMessage::Dismiss),
+        Some(BannerOutput::Dismissed)
+    );
+    assert!(!state.is_visible());
+}
+
+#[test]
+fn test_dismiss_when_hidden_is_noop() {
+    let mut state = BannerState::new();
+    assert_eq!(state.update(BannerMessage::Dismiss), None);
+}
+
+#[test]
+fn test_show_after_dismiss() {
+    let mut state = shown();
+    state.update(BannerMessage::Dismiss);
+    state.update(BannerMessage::Show {
+        message: "Back online".to_string(),
+        level: BannerLevel::Success,
+    });
+    assert!(state.is_visible());
+    assert_eq!(state.message(), "Back online");
+}
+
+#[test]
+fn test_toggleable() {
+    let mut state = BannerState::new();
+    Banner::show(&mut state);
+    assert!(Banner::is_visible(&state));
+    Banner::toggle(&mut state);
+    assert!(!state.is_visible());
+}
+
+// ========================================
+// Event Tests
+// ========================================
+
+#[test]
+fn test_esc_dismisses_when_focused() {
+    let msg = Banner::handle_event(
+        &shown(),
+        &Event::key(Key::Esc),
+        &EventContext::new().focused(true),
+    );
+    assert_eq!(msg, Some(BannerMessage::Dismiss));
+}
+
+#[test]
+fn test_esc_ignored_when_not_dismissible() {
+    let state = shown().with_dismissible(false);
+    let msg = Banner::handle_event(
+        &state,
+        &Event::key(Key::Esc),
+        &EventContext::new().focused(true),
+    );
+    assert_eq!(msg, None);
+}
+
+#[test]
+fn test_esc_ignored_when_unfocused_disabled_or_hidden() {
+    let esc = Event::key(Key::Esc);
+    assert_eq!(
+        Banner::handle_event(&shown(), &esc, &EventContext::default()),
+        None
+    );
+    assert_eq!(
+        Banner::handle_event(
+            &shown(),
+            &esc,
+            &EventContext::new().focused(true).disabled(true)
+        ),
+        None
+    );
+    let hidden = BannerState::new().with_dismissible(true);
+    assert_eq!(
+        Banner::handle_event(&hidden, &esc, &EventContext::new().focused(true)),
+        None
+    );
+}
+
+#[test]
+fn test_other_keys_ignored() {
+    let msg = Banner::handle_event(
+        &shown(),
+        &Event::key(Key::Enter),
+        &EventContext::new().focused(true),
+    );
+    assert_eq!(msg, None);
+}
+
+// ========================================
+// View Tests
+// ========================================
+
+#[test]
+fn test_view_dismissible() {
+    let state = shown();
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 3);
+
+    terminal
+        .draw(|frame| {
+            Banner::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    insta::assert_snapshot!(terminal.backend().to_string());
+}
+
+#[test]
+fn test_view_not_dismissible() {
+    let state = BannerState::with_message("Offline", BannerLevel::Error);
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 1);
+
+    terminal
+        .draw(|frame| {
+            Banner::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    let output = terminal.backend().to_string();
+    assert_eq!(output.trim_end(), " x Offline");
+    assert!(!output.contains('\u{2715}'));
+}
+
+#[test]
+fn test_view_hidden_renders_nothing() {
+    let state = BannerState::new().with_dismissible(true);
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 1);
+
+    terminal
+        .draw(|frame| {
+            Banner::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    assert_eq!(terminal.backend().to_string().trim(), "");
+}
+
+#[test]
+fn test_view_fills_width_with_level_style() {
+    let state = shown();
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 1);
+
+    terminal
+        .draw(|frame| {
+            Banner::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    let expected = theme.warning_style().add_modifier(Modifier::REVERSED);
+    for x in [0, 20, 29] {
+        let cell = terminal.backend().cell(x, 0).unwrap();
+        assert_eq!(cell.style().fg, expected.fg);
+        assert!(cell.style().add_modifier.contains(Modifier::REVERSED));
+    }
+}
+
+#[test]
+fn test_view_disabled_uses_disabled_style() {
+    let state = shown();
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 1);
+
+    terminal
+        .draw(|frame| {
+            Banner::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &theme).disabled(true),
+            );
+        })
+        .unwrap();
+
+    let cell = terminal.backend().cell(0, 0).unwrap();
+    assert_eq!(cell.style().fg, theme.disabled_style().fg);
+}
+
+#[test]
+fn test_annotation_emitted() {
+    use crate::annotation::with_annotations;
+
+    let state = shown();
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 1);
+    let registry = with_annotations(|| {
+        terminal
+            .draw(|frame| {
+                Banner::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+            })
+            .unwrap();
+    });
+    let regions = registry.regions();
+    assert_eq!(regions.len(), 1);
+    assert_eq!(
+        regions[0].annotation.label.as_deref(),
+        Some("Unsaved changes")
+    );
+}
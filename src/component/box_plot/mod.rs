@@ -520,7 +520,7 @@ impl Component for BoxPlot {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
 
         match key.code {
             Key::Right | Key::Char('l') => Some(BoxPlotMessage::NextDataset),
@@ -7,7 +7,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
 
 use super::BoxPlotState;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Maps a data value to a position within a pixel range.
 ///
@@ -31,7 +31,7 @@ pub(super) fn render_vertical(
     state: &BoxPlotState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 ) {
@@ -216,7 +216,7 @@ pub(super) fn render_horizontal(
     state: &BoxPlotState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 ) {
@@ -6,6 +6,9 @@
 //! [`BreadcrumbState`], updated via [`BreadcrumbMessage`], and produces
 //! [`BreadcrumbOutput`].
 //!
+//! Paths that are too wide for their area are truncated in the middle: the
+//! first and last segments stay visible and the hidden ones collapse into
+//! `…`. Clicking a segment navigates back to it.
 //!
 //! # Example
 //!
@@ -36,8 +39,10 @@
 use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
 
+use unicode_width::UnicodeWidthStr;
+
 use super::{Component, EventContext, RenderContext};
-use crate::input::{Event, Key};
+use crate::input::{Event, Key, MouseButton, MouseEventKind};
 
 /// A single breadcrumb segment.
 ///
@@ -146,6 +151,13 @@ pub enum BreadcrumbMessage {
     Select,
     /// Select a specific segment by index.
     SelectIndex(usize),
+    /// Append a segment with the given label and navigate to it.
+    Push(String),
+    /// Remove the last segment and navigate to its parent.
+    Pop,
+    /// Navigate to the segment at the given index, removing every segment
+    /// after it.
+    JumpTo(usize),
 }
 
 /// Output messages from a Breadcrumb component.
@@ -155,6 +167,9 @@ pub enum BreadcrumbOutput {
     Selected(usize),
     /// Focus moved to a segment.
     FocusChanged(usize),
+    /// The path changed and the segment at this index is now the current
+    /// location.
+    Navigated(usize),
 }
 
 /// State for a Breadcrumb component.
@@ -185,6 +200,9 @@ pub struct BreadcrumbState {
     separator: String,
     /// Maximum visible segments (None = show all).
     max_visible: Option<usize>,
+    /// The area the breadcrumb was last laid out in, used for mouse clicks.
+    #[cfg_attr(feature = "serialization", serde(skip))]
+    area: Rect,
 }
 
 impl Default for BreadcrumbState {
@@ -194,6 +212,7 @@ impl Default for BreadcrumbState {
             focused_index: 0,
             separator: " > ".to_string(),
             max_visible: None,
+            area: Rect::default(),
         }
     }
 }
@@ -219,6 +238,7 @@ impl BreadcrumbState {
             focused_index: 0,
             separator: " > ".to_string(),
             max_visible: None,
+            area: Rect::default(),
         }
     }
 
@@ -556,6 +576,137 @@ impl BreadcrumbState {
         &self.segments[start..end]
     }
 
+    /// Sets the area the breadcrumb is rendered in.
+    ///
+    /// Mouse clicks are mapped to segments using this area, so call it with
+    /// the same area passed to [`Breadcrumb::view`] before handling events.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::BreadcrumbState;
+    /// use ratatui::layout::Rect;
+    ///
+    /// let mut state = BreadcrumbState::from_labels(vec!["Home", "Docs"]);
+    /// state.set_area(Rect::new(0, 0, 40, 1));
+    /// assert_eq!(state.area(), Rect::new(0, 0, 40, 1));
+    /// ```
+    pub fn set_area(&mut self, area: Rect) {
+        self.area = area;
+    }
+
+    /// Returns the area set with [`set_area`](Self::set_area).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::BreadcrumbState;
+    /// use ratatui::layout::Rect;
+    ///
+    /// let state = BreadcrumbState::from_labels(vec!["Home"]);
+    /// assert_eq!(state.area(), Rect::default());
+    /// ```
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Returns the index of the segment rendered at the given screen
+    /// position, if any.
+    ///
+    /// Uses the area set with [`set_area`](Self::set_area). Separators and
+    /// the `…` marker do not belong to any segment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::BreadcrumbState;
+    /// use ratatui::layout::Rect;
+    ///
+    /// // Renders as "Home > Docs"
+    /// let mut state = BreadcrumbState::from_labels(vec!["Home", "Docs"]);
+    /// state.set_area(Rect::new(0, 0, 40, 1));
+    /// assert_eq!(state.segment_at(1, 0), Some(0));
+    /// assert_eq!(state.segment_at(5, 0), None);
+    /// assert_eq!(state.segment_at(8, 0), Some(1));
+    /// ```
+    pub fn segment_at(&self, column: u16, row: u16) -> Option<usize> {
+        let area = self.area;
+        if row < area.y || row >= area.bottom() || column < area.x || column >= area.right() {
+            return None;
+        }
+
+        let separator_width = self.separator.width();
+        let mut x = usize::from(area.x);
+        for (i, item) in self.layout(area.width).into_iter().enumerate() {
+            if i > 0 {
+                x += separator_width;
+            }
+            let width = self.item_width(item);
+            if let Some(index) = item {
+                if (x..x + width).contains(&usize::from(column)) {
+                    return Some(index);
+                }
+            }
+            x += width;
+        }
+        None
+    }
+
+    /// Returns the items to render in the given width, in order.
+    ///
+    /// `Some(index)` is a segment and `None` is the `…` marker. Segments
+    /// hidden by `max_visible` are replaced by a leading marker. If the
+    /// result is still wider than `width`, the middle is collapsed into a
+    /// marker, keeping the first and last segments and as many trailing
+    /// segments as fit.
+    fn layout(&self, width: u16) -> Vec<Option<usize>> {
+        let (start, end) = self.visible_range();
+        let mut items: Vec<Option<usize>> = Vec::new();
+        if self.is_truncated() {
+            items.push(None);
+        }
+        items.extend((start..end).map(Some));
+
+        let width = usize::from(width);
+        if items.len() <= 2 || self.items_width(&items) <= width {
+            return items;
+        }
+
+        // Keep the head (first segment, or the truncation marker) and the
+        // last segment, then add trailing segments while they fit.
+        let (mut head, first_tail) = if self.is_truncated() {
+            (vec![None], start)
+        } else {
+            (vec![Some(start), None], start + 1)
+        };
+        let mut tail = vec![end - 1];
+        for index in (first_tail..end - 1).rev() {
+            let mut candidate = head.clone();
+            candidate.push(Some(index));
+            candidate.extend(tail.iter().rev().map(|&i| Some(i)));
+            if self.items_width(&candidate) > width {
+                break;
+            }
+            tail.push(index);
+        }
+        head.extend(tail.into_iter().rev().map(Some));
+        head
+    }
+
+    /// Returns the display width of a layout item.
+    fn item_width(&self, item: Option<usize>) -> usize {
+        match item {
+            Some(index) => self.segments[index].label.width(),
+            None => "…".width(),
+        }
+    }
+
+    /// Returns the display width of a layout, including separators.
+    fn items_width(&self, items: &[Option<usize>]) -> usize {
+        let labels: usize = items.iter().map(|&item| self.item_width(item)).sum();
+        labels + items.len().saturating_sub(1) * self.separator.width()
+    }
+
     /// Updates the breadcrumb state with a message, returning any output.
     ///
     /// # Example
@@ -586,11 +737,19 @@ impl BreadcrumbState {
 /// - `Last` - Jump to the last segment
 /// - `Select` - Select the focused segment
 /// - `SelectIndex(index)` - Select a specific segment
+/// - `Push(label)` - Append a segment and navigate to it
+/// - `Pop` - Remove the last segment and navigate to its parent
+/// - `JumpTo(index)` - Navigate to a segment, removing the ones after it
+///
+/// Left-clicking a segment sends `JumpTo` for it. Clicks are mapped using
+/// the area given to [`BreadcrumbState::set_area`].
 ///
 /// # Output
 ///
 /// - `FocusChanged(index)` - Emitted when focus moves to a different segment
 /// - `Selected(index)` - Emitted when a segment is selected
+/// - `Navigated(index)` - Emitted when the path changes; `index` is the new
+///   current segment
 ///
 /// # Example
 ///
@@ -621,11 +780,13 @@ impl Component for Breadcrumb {
     }
 
     fn update(state: &mut Self::State, msg: Self::Message) -> Option<Self::Output> {
-        if state.segments.is_empty() {
-            return None;
-        }
-
         match msg {
+            BreadcrumbMessage::Push(label) => {
+                state.segments.push(BreadcrumbSegment::new(label));
+                state.focused_index = state.segments.len() - 1;
+                Some(BreadcrumbOutput::Navigated(state.focused_index))
+            }
+            _ if state.segments.is_empty() => None,
             BreadcrumbMessage::Left => {
                 if state.focused_index > 0 {
                     state.focused_index -= 1;
@@ -667,18 +828,48 @@ impl Component for Breadcrumb {
                     None
                 }
             }
+            BreadcrumbMessage::Pop => {
+                state.segments.pop();
+                if state.segments.is_empty() {
+                    state.focused_index = 0;
+                    None
+                } else {
+                    state.focused_index = state.segments.len() - 1;
+                    Some(BreadcrumbOutput::Navigated(state.focused_index))
+                }
+            }
+            BreadcrumbMessage::JumpTo(index) => {
+                if index < state.segments.len() {
+                    state.segments.truncate(index + 1);
+                    state.focused_index = index;
+                    Some(BreadcrumbOutput::Navigated(index))
+                } else {
+                    None
+                }
+            }
         }
     }
 
     fn handle_event(
-        _state: &Self::State,
+        state: &Self::State,
         event: &Event,
         ctx: &EventContext,
     ) -> Option<Self::Message> {
-        if !ctx.focused || ctx.disabled {
+        if ctx.disabled {
+            return None;
+        }
+        if let Some(mouse) = event.as_mouse() {
+            if mouse.kind == MouseEventKind::Down(MouseButton::Left) {
+                return state
+                    .segment_at(mouse.column, mouse.row)
+                    .map(BreadcrumbMessage::JumpTo);
+            }
+            return None;
+        }
+        if !ctx.focused {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Left | Key::Char('h') => Some(BreadcrumbMessage::Left),
                 Key::Right | Key::Char('l') => Some(BreadcrumbMessage::Right),
@@ -698,15 +889,16 @@ impl Component for Breadcrumb {
         }
 
         let mut spans: Vec<Span> = Vec::new();
-        let (start, end) = state.visible_range();
 
-        // Add ellipsis if truncated
-        if state.is_truncated() {
-            spans.push(Span::styled("…", ctx.theme.disabled_style()));
-            spans.push(Span::raw(&state.separator));
-        }
+        for (i, item) in state.layout(ctx.area.width).into_iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw(&state.separator));
+            }
+            let Some(seg_idx) = item else {
+                spans.push(Span::styled("…", ctx.theme.disabled_style()));
+                continue;
+            };
 
-        for seg_idx in start..end {
             let segment = &state.segments[seg_idx];
             let is_last = seg_idx == state.segments.len() - 1;
             let is_focused_segment = ctx.focused && seg_idx == state.focused_index;
@@ -726,10 +918,6 @@ impl Component for Breadcrumb {
             };
 
             spans.push(Span::styled(segment.label(), style));
-
-            if !is_last {
-                spans.push(Span::raw(&state.separator));
-            }
         }
 
         let line = Line::from(spans);
@@ -769,3 +769,194 @@ fn test_annotation_emitted() {
     let regions = registry.find_by_type(&WidgetType::Breadcrumb);
     assert_eq!(regions.len(), 1);
 }
+
+// ==================== Path Navigation Tests ====================
+
+#[test]
+fn test_push_message() {
+    let mut state = BreadcrumbState::from_labels(vec!["Home"]);
+    let output = Breadcrumb::update(&mut state, BreadcrumbMessage::Push("Docs".into()));
+    assert_eq!(output, Some(BreadcrumbOutput::Navigated(1)));
+    assert_eq!(state.current().unwrap().label(), "Docs");
+    assert_eq!(state.focused_index(), 1);
+}
+
+#[test]
+fn test_push_message_empty() {
+    let mut state = BreadcrumbState::default();
+    let output = Breadcrumb::update(&mut state, BreadcrumbMessage::Push("Home".into()));
+    assert_eq!(output, Some(BreadcrumbOutput::Navigated(0)));
+    assert_eq!(state.len(), 1);
+}
+
+#[test]
+fn test_pop_message() {
+    let mut state = BreadcrumbState::from_labels(vec!["Home", "Docs", "Guide"]);
+    let output = Breadcrumb::update(&mut state, BreadcrumbMessage::Pop);
+    assert_eq!(output, Some(BreadcrumbOutput::Navigated(1)));
+    assert_eq!(state.len(), 2);
+    assert_eq!(state.focused_index(), 1);
+}
+
+#[test]
+fn test_pop_message_last_segment() {
+    let mut state = BreadcrumbState::from_labels(vec!["Home"]);
+    let output = Breadcrumb::update(&mut state, BreadcrumbMessage::Pop);
+    assert_eq!(output, None);
+    assert!(state.is_empty());
+    assert_eq!(state.focused_index(), 0);
+
+    let output = Breadcrumb::update(&mut state, BreadcrumbMessage::Pop);
+    assert_eq!(output, None);
+}
+
+#[test]
+fn test_jump_to() {
+    let mut state = BreadcrumbState::from_labels(vec!["A", "B", "C", "D"]);
+    let output = Breadcrumb::update(&mut state, BreadcrumbMessage::JumpTo(1));
+    assert_eq!(output, Some(BreadcrumbOutput::Navigated(1)));
+    assert_eq!(state.len(), 2);
+    assert_eq!(state.current().unwrap().label(), "B");
+    assert_eq!(state.focused_index(), 1);
+}
+
+#[test]
+fn test_jump_to_out_of_bounds() {
+    let mut state = BreadcrumbState::from_labels(vec!["A", "B"]);
+    let output = Breadcrumb::update(&mut state, BreadcrumbMessage::JumpTo(2));
+    assert_eq!(output, None);
+    assert_eq!(state.len(), 2);
+}
+
+// ==================== Width Truncation Tests ====================
+
+fn render_line(state: &BreadcrumbState, width: u16) -> String {
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(width, 1);
+    terminal
+        .draw(|frame| {
+            Breadcrumb::view(state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+    terminal.backend().to_string().trim_end().to_string()
+}
+
+#[test]
+fn test_view_fits_without_truncation() {
+    let state = BreadcrumbState::from_labels(vec!["Home", "Docs", "Guide"]);
+    assert_eq!(render_line(&state, 20), "Home > Docs > Guide");
+}
+
+#[test]
+fn test_view_truncates_middle_when_too_wide() {
+    let state = BreadcrumbState::from_labels(vec!["Home", "Projects", "Envision", "Docs", "Guide"]);
+    // Full path is 41 columns wide
+    assert_eq!(
+        render_line(&state, 35),
+        "Home > … > Envision > Docs > Guide"
+    );
+    assert_eq!(render_line(&state, 25), "Home > … > Docs > Guide");
+    assert_eq!(render_line(&state, 18), "Home > … > Guide");
+}
+
+#[test]
+fn test_view_truncation_keeps_first_and_last() {
+    let state = BreadcrumbState::from_labels(vec!["Home", "Projects", "Guide"]);
+    // Too narrow even for the collapsed path; the first and last segments
+    // are still rendered and the rest is clipped.
+    assert_eq!(render_line(&state, 10), "Home > … >");
+}
+
+#[test]
+fn test_view_width_truncation_with_max_visible() {
+    let state = BreadcrumbState::from_labels(vec!["Root", "Projects", "Envision", "Docs", "Guide"])
+        .with_max_visible(Some(3));
+    assert_eq!(render_line(&state, 40), "… > Envision > Docs > Guide");
+    assert_eq!(render_line(&state, 20), "… > Docs > Guide");
+}
+
+// ==================== Mouse Tests ====================
+
+#[test]
+fn test_segment_at() {
+    // "Home > Docs > Guide" starting at column 2, row 3
+    let mut state = BreadcrumbState::from_labels(vec!["Home", "Docs", "Guide"]);
+    state.set_area(Rect::new(2, 3, 30, 1));
+
+    assert_eq!(state.segment_at(2, 3), Some(0));
+    assert_eq!(state.segment_at(5, 3), Some(0));
+    assert_eq!(state.segment_at(6, 3), None);
+    assert_eq!(state.segment_at(9, 3), Some(1));
+    assert_eq!(state.segment_at(16, 3), Some(2));
+    assert_eq!(state.segment_at(21, 3), None);
+    assert_eq!(state.segment_at(2, 4), None);
+    assert_eq!(state.segment_at(0, 3), None);
+}
+
+#[test]
+fn test_segment_at_truncated() {
+    // Renders as "Home > … > Guide"
+    let mut state = BreadcrumbState::from_labels(vec!["Home", "Projects", "Envision", "Guide"]);
+    state.set_area(Rect::new(0, 0, 18, 1));
+
+    assert_eq!(state.segment_at(0, 0), Some(0));
+    assert_eq!(state.segment_at(7, 0), None);
+    assert_eq!(state.segment_at(11, 0), Some(3));
+}
+
+#[test]
+fn test_segment_at_without_area() {
+    let state = BreadcrumbState::from_labels(vec!["Home"]);
+    assert_eq!(state.segment_at(0, 0), None);
+}
+
+#[test]
+fn test_handle_event_click_segment() {
+    let mut state = BreadcrumbState::from_labels(vec!["Home", "Docs", "Guide"]);
+    state.set_area(Rect::new(0, 0, 30, 1));
+
+    let msg = Breadcrumb::handle_event(&state, &Event::click(8, 0), &EventContext::default());
+    assert_eq!(msg, Some(BreadcrumbMessage::JumpTo(1)));
+
+    let msg = Breadcrumb::handle_event(&state, &Event::click(5, 0), &EventContext::default());
+    assert_eq!(msg, None);
+}
+
+#[test]
+fn test_handle_event_click_ignored_when_disabled() {
+    let mut state = BreadcrumbState::from_labels(vec!["Home", "Docs"]);
+    state.set_area(Rect::new(0, 0, 30, 1));
+
+    let msg = Breadcrumb::handle_event(
+        &state,
+        &Event::click(0, 0),
+        &EventContext::new().disabled(true),
+    );
+    assert_eq!(msg, None);
+}
+
+#[test]
+fn test_handle_event_other_mouse_ignored() {
+    let mut state = BreadcrumbState::from_labels(vec!["Home", "Docs"]);
+    state.set_area(Rect::new(0, 0, 30, 1));
+
+    let msg = Breadcrumb::handle_event(
+        &state,
+        &Event::click_button(0, 0, crate::input::MouseButton::Right),
+        &EventContext::default(),
+    );
+    assert_eq!(msg, None);
+
+    let msg = Breadcrumb::handle_event(&state, &Event::mouse_move(0, 0), &EventContext::default());
+    assert_eq!(msg, None);
+}
+
+#[test]
+fn test_dispatch_click_navigates() {
+    let mut state = BreadcrumbState::from_labels(vec!["Home", "Docs", "Guide"]);
+    state.set_area(Rect::new(0, 0, 30, 1));
+
+    let output =
+        Breadcrumb::dispatch_event(&mut state, &Event::click(0, 0), &EventContext::default());
+    assert_eq!(output, Some(BreadcrumbOutput::Navigated(0)));
+    assert_eq!(state.len(), 1);
+}
@@ -182,7 +182,7 @@ impl Component for Button {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Enter | Key::Char(' ') => Some(ButtonMessage::Press),
                 _ => None,
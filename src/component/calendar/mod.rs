@@ -36,6 +36,10 @@
 //! });
 //! assert!(state.has_event(2026, 4, 15));
 //! ```
+//!
+//! With the `chrono` feature, `CalendarState::from_date`,
+//! `CalendarState::selected_date`, and `CalendarOutput::date` convert to and
+//! from `chrono::NaiveDate`.
 
 use std::collections::HashMap;
 
@@ -484,7 +488,9 @@ impl CalendarState {
 ///
 /// The calendar renders a standard month grid with day-of-week headers,
 /// supports keyboard navigation between days and months, and can display
-/// colored event markers on specific dates.
+/// colored event markers on specific dates. Days from the adjacent months
+/// that fill out the first and last weeks are drawn in the theme's
+/// disabled style.
 ///
 /// # Keyboard Navigation
 ///
@@ -506,7 +512,7 @@ impl CalendarState {
 /// │  8   9  10  11  12  13  14   │
 /// │ 15  16  17  18  19 [20] 21   │
 /// │ 22  23  24• 25  26  27  28   │
-/// │ 29  30  31                   │
+/// │ 29  30  31   1   2   3   4   │
 /// │ ◀ PgUp          PgDn ▶      │
 /// └──────────────────────────────┘
 /// ```
@@ -706,7 +712,7 @@ impl Component for Calendar {
             return None;
         }
 
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Left | Key::Char('h') => Some(CalendarMessage::SelectPrevDay),
                 Key::Right | Key::Char('l') => Some(CalendarMessage::SelectNextDay),
@@ -749,7 +755,7 @@ impl Component for Calendar {
             ctx.theme.disabled_style()
         } else {
             Style::default()
-                .fg(ctx.theme.primary)
+                .fg(ctx.theme.base_theme().primary)
                 .add_modifier(Modifier::BOLD)
         };
 
@@ -770,7 +776,7 @@ impl Component for Calendar {
                 ctx.theme.focused_bold_style()
             } else {
                 Style::default()
-                    .fg(ctx.theme.foreground)
+                    .fg(ctx.theme.base_theme().foreground)
                     .add_modifier(Modifier::BOLD)
             };
 
@@ -814,6 +820,16 @@ impl Component for Calendar {
             ctx.theme.selected_highlight_style(ctx.focused)
         };
 
+        // Days from the previous and next months fill the edges of the grid
+        let adjacent_style = ctx.theme.disabled_style();
+        let (prev_year, prev_month) = if state.month == 1 {
+            (state.year - 1, 12)
+        } else {
+            (state.year, state.month - 1)
+        };
+        let prev_total_days = days_in_month(prev_year, prev_month);
+        let mut next_day = 1u32;
+
         // Build week rows
         let mut day = 1u32;
 
@@ -826,11 +842,13 @@ impl Component for Calendar {
 
             for dow in 0..7u32 {
                 if week == 0 && dow < first_dow {
-                    // Empty cell before month starts
-                    spans.push(Span::styled("    ", normal_style));
+                    // Trailing days of the previous month
+                    let prev_day = prev_total_days - (first_dow - dow) + 1;
+                    spans.push(Span::styled(format!("{prev_day:>3} "), adjacent_style));
                 } else if day > total_days {
-                    // Empty cell after month ends
-                    spans.push(Span::styled("    ", normal_style));
+                    // Leading days of the next month
+                    spans.push(Span::styled(format!("{next_day:>3} "), adjacent_style));
+                    next_day += 1;
                 } else {
                     let is_selected = state.selected_day == Some(day);
                     let has_event = state.events.contains_key(&(state.year, state.month, day));
@@ -874,6 +892,9 @@ impl Component for Calendar {
     }
 }
 
+#[cfg(feature = "chrono")]
+mod naive_date;
+
 #[cfg(test)]
 mod tests;
 #[cfg(test)]
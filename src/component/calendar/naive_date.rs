@@ -0,0 +1,87 @@
+//! [`chrono::NaiveDate`] conversions for the Calendar component.
+//!
+//! Available with the `chrono` feature.
+
+use chrono::{Datelike, NaiveDate};
+
+use super::{CalendarOutput, CalendarState};
+
+impl CalendarState {
+    /// Creates a calendar showing the month of `date`, with that day selected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use envision::component::CalendarState;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2026, 3, 20).unwrap();
+    /// let state = CalendarState::from_date(date);
+    /// assert_eq!(state.year(), 2026);
+    /// assert_eq!(state.month(), 3);
+    /// assert_eq!(state.selected_day(), Some(20));
+    /// ```
+    pub fn from_date(date: NaiveDate) -> Self {
+        Self::new(date.year(), date.month()).with_selected_day(date.day())
+    }
+
+    /// Returns the selected day as a [`NaiveDate`], if any.
+    ///
+    /// Returns `None` when no day is selected or the selection is not a
+    /// valid date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use envision::component::CalendarState;
+    ///
+    /// let state = CalendarState::new(2026, 3).with_selected_day(20);
+    /// assert_eq!(state.selected_date(), NaiveDate::from_ymd_opt(2026, 3, 20));
+    /// assert_eq!(CalendarState::new(2026, 3).selected_date(), None);
+    /// ```
+    pub fn selected_date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, self.month, self.selected_day?)
+    }
+
+    /// Navigates to the month of `date` and selects that day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use envision::component::CalendarState;
+    ///
+    /// let mut state = CalendarState::new(2026, 3);
+    /// let date = NaiveDate::from_ymd_opt(2027, 1, 5).unwrap();
+    /// state.set_selected_date(date);
+    /// assert_eq!(state.selected_date(), Some(date));
+    /// ```
+    pub fn set_selected_date(&mut self, date: NaiveDate) {
+        self.year = date.year();
+        self.month = date.month();
+        self.selected_day = Some(date.day());
+    }
+}
+
+impl CalendarOutput {
+    /// Returns the confirmed date for [`DateSelected`](Self::DateSelected)
+    /// outputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use envision::component::{CalendarMessage, CalendarState};
+    ///
+    /// let mut state = CalendarState::new(2026, 3).with_selected_day(20);
+    /// let output = state.update(CalendarMessage::ConfirmSelection).unwrap();
+    /// assert_eq!(output.date(), NaiveDate::from_ymd_opt(2026, 3, 20));
+    /// ```
+    pub fn date(&self) -> Option<NaiveDate> {
+        match *self {
+            Self::DateSelected(year, month, day) => NaiveDate::from_ymd_opt(year, month, day),
+            Self::MonthChanged(..) => None,
+        }
+    }
+}
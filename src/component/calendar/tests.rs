@@ -791,3 +791,62 @@ fn test_leap_year_february_navigation() {
     assert_eq!(state.month(), 3);
     assert_eq!(state.selected_day(), Some(1));
 }
+
+// ========== chrono Tests ==========
+
+#[cfg(feature = "chrono")]
+mod chrono_tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_from_date() {
+        let state = CalendarState::from_date(date(2024, 2, 29));
+        assert_eq!(state.year(), 2024);
+        assert_eq!(state.month(), 2);
+        assert_eq!(state.selected_day(), Some(29));
+    }
+
+    #[test]
+    fn test_selected_date_round_trip() {
+        let state = CalendarState::from_date(date(2026, 12, 31));
+        assert_eq!(state.selected_date(), Some(date(2026, 12, 31)));
+    }
+
+    #[test]
+    fn test_selected_date_none_without_selection() {
+        assert_eq!(CalendarState::new(2026, 3).selected_date(), None);
+    }
+
+    #[test]
+    fn test_selected_date_none_for_invalid_day() {
+        let state = CalendarState::new(2026, 2).with_selected_day(30);
+        assert_eq!(state.selected_date(), None);
+    }
+
+    #[test]
+    fn test_set_selected_date_changes_month() {
+        let mut state = CalendarState::new(2026, 3).with_selected_day(1);
+        state.set_selected_date(date(2025, 11, 15));
+        assert_eq!(state.year(), 2025);
+        assert_eq!(state.month(), 11);
+        assert_eq!(state.selected_day(), Some(15));
+    }
+
+    #[test]
+    fn test_output_date_after_navigation() {
+        let mut state = CalendarState::from_date(date(2026, 3, 31));
+        Calendar::update(&mut state, CalendarMessage::SelectNextDay);
+        let output = Calendar::update(&mut state, CalendarMessage::ConfirmSelection).unwrap();
+        assert_eq!(output.date(), Some(date(2026, 4, 1)));
+    }
+
+    #[test]
+    fn test_output_date_none_for_month_changed() {
+        assert_eq!(CalendarOutput::MonthChanged(2026, 4).date(), None);
+    }
+}
@@ -107,6 +107,32 @@ fn test_view_february_non_leap_year() {
     insta::assert_snapshot!(terminal.backend().to_string());
 }
 
+#[test]
+fn test_view_adjacent_month_days_use_disabled_style() {
+    // April 2026 starts on a Wednesday and ends on a Thursday
+    let state = CalendarState::new(2026, 4);
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(34, 12);
+    terminal
+        .draw(|frame| {
+            Calendar::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    let output = terminal.backend().to_string();
+    assert!(output.contains(" 29  30  31   1   2   3   4"));
+    assert!(output.contains(" 26  27  28  29  30   1   2"));
+
+    let disabled = theme.disabled_style().fg;
+    // "29" of March in the first week row
+    let prev = terminal.backend().cell(2, 2).unwrap();
+    assert_eq!(prev.symbol(), "2");
+    assert_eq!(prev.style().fg, disabled);
+    // "1" of April in the same row
+    let current = terminal.backend().cell(15, 2).unwrap();
+    assert_eq!(current.symbol(), "1");
+    assert_ne!(current.style().fg, disabled);
+}
+
 #[test]
 fn test_view_zero_area() {
     let state = CalendarState::new(2026, 3);
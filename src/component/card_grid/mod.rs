@@ -0,0 +1,534 @@
+//! A masonry layout of variable-height cards.
+//!
+//! [`CardGrid`] packs cards of different heights into columns, placing each
+//! card in whichever column is currently shortest, the way dashboards of
+//! mixed panels are usually laid out. It computes where every card goes
+//! and scrolls vertically when the cards overflow; the app renders each
+//! card's contents into the areas returned by
+//! [`CardGridState::visible_cards`]. State is stored in [`CardGridState`],
+//! updated via [`CardGridMessage`], and produces no output (Output = `()`).
+//!
+//! See also [`ScrollView`](super::ScrollView) for a single scrollable
+//! region and [`PaneLayout`](super::PaneLayout) for fixed panes.
+//!
+//! # Example
+//!
+//! ```rust
+//! use envision::component::CardGridState;
+//! use ratatui::layout::Rect;
+//!
+//! let mut state = CardGridState::new([4, 2, 3]).with_columns(2).with_gap(0);
+//! state.set_area(Rect::new(0, 0, 20, 10));
+//!
+//! // The third card goes under the second, the shorter column.
+//! assert_eq!(
+//!     state.card_rects(),
+//!     vec![Rect::new(0, 0, 10, 4), Rect::new(10, 0, 10, 2), Rect::new(10, 2, 10, 3)]
+//! );
+//! ```
+
+use ratatui::prelude::*;
+
+use super::{Component, EventContext, RenderContext};
+use crate::input::{Event, Key};
+use crate::scroll::ScrollState;
+
+/// Messages that can be sent to a CardGrid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum CardGridMessage {
+    /// Scroll up by one line.
+    ScrollUp,
+    /// Scroll down by one line.
+    ScrollDown,
+    /// Scroll up by one page.
+    PageUp,
+    /// Scroll down by one page.
+    PageDown,
+    /// Scroll to the top.
+    Home,
+    /// Scroll to the bottom.
+    End,
+    /// Scroll so the given line is at the top, clamped to the last page.
+    ScrollTo(usize),
+    /// Scroll the least distance that brings the given card fully into
+    /// view, or its top if it is taller than the viewport.
+    ScrollToCard(usize),
+    /// Replace the card heights.
+    SetCardHeights(Vec<u16>),
+}
+
+/// How the number of columns is chosen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+enum ColumnRule {
+    /// A fixed number of columns.
+    Fixed(u16),
+    /// As many columns as fit at this minimum width.
+    MinWidth(u16),
+}
+
+/// A card that is at least partly inside the viewport.
+///
+/// Returned by [`CardGridState::visible_cards`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VisibleCard {
+    /// Index of the card.
+    pub index: usize,
+    /// The on-screen area of the visible part of the card.
+    pub area: Rect,
+    /// How many of the card's rows are scrolled off above the viewport.
+    ///
+    /// The app should skip this many rows of the card's content so that
+    /// what it draws into `area` lines up with the rest of the card.
+    pub clipped_top: u16,
+}
+
+/// State for a CardGrid component.
+///
+/// Holds the content height of each card, the column rule, the gap
+/// between cards and the scroll position. The layout depends on the width
+/// of the area the grid is drawn in, so call [`set_area`](Self::set_area)
+/// with that area before querying it or handling scroll messages.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::component::CardGridState;
+/// use ratatui::layout::Rect;
+///
+/// let mut state = CardGridState::new([5, 5, 5]).with_min_card_width(20);
+/// state.set_area(Rect::new(0, 0, 62, 10));
+/// // 20-wide cards with a gap of 1 fit three across 62 columns.
+/// assert_eq!(state.column_count(), 3);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct CardGridState {
+    /// Content height of each card, in rows.
+    heights: Vec<u16>,
+    /// How the number of columns is chosen.
+    columns: ColumnRule,
+    /// Blank columns between cards and blank rows between stacked cards.
+    gap: u16,
+    /// The area the grid is drawn in.
+    area: Rect,
+    /// Scroll state over the content rows.
+    scroll: ScrollState,
+    /// Whether to show the scrollbar when cards overflow.
+    show_scrollbar: bool,
+}
+
+impl Default for CardGridState {
+    fn default() -> Self {
+        Self {
+            heights: Vec::new(),
+            columns: ColumnRule::Fixed(1),
+            gap: 1,
+            area: Rect::default(),
+            scroll: ScrollState::default(),
+            show_scrollbar: true,
+        }
+    }
+}
+
+impl CardGridState {
+    /// Creates a grid of cards with the given content heights, in one
+    /// column with a gap of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CardGridState;
+    ///
+    /// let state = CardGridState::new([3, 8, 5]);
+    /// assert_eq!(state.card_heights(), &[3, 8, 5]);
+    /// assert_eq!(state.gap(), 1);
+    /// ```
+    pub fn new(heights: impl IntoIterator<Item = u16>) -> Self {
+        Self {
+            heights: heights.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Lays the cards out in `columns` columns (builder pattern).
+    ///
+    /// Fewer columns are used if the area is too narrow to give each a
+    /// column of width.
+    pub fn with_columns(mut self, columns: u16) -> Self {
+        self.columns = ColumnRule::Fixed(columns.max(1));
+        self.sync_scroll();
+        self
+    }
+
+    /// Uses as many columns as fit with each at least `width` wide
+    /// (builder pattern).
+    ///
+    /// There is always at least one column, even if it is narrower than
+    /// `width`.
+    pub fn with_min_card_width(mut self, width: u16) -> Self {
+        self.columns = ColumnRule::MinWidth(width.max(1));
+        self.sync_scroll();
+        self
+    }
+
+    /// Sets the gap between columns and between stacked cards (builder
+    /// pattern).
+    pub fn with_gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self.sync_scroll();
+        self
+    }
+
+    /// Sets whether the scrollbar is shown (builder pattern).
+    pub fn with_show_scrollbar(mut self, show: bool) -> Self {
+        self.show_scrollbar = show;
+        self.sync_scroll();
+        self
+    }
+
+    /// Returns the content height of each card.
+    pub fn card_heights(&self) -> &[u16] {
+        &self.heights
+    }
+
+    /// Replaces the card heights, keeping the scroll offset where possible.
+    pub fn set_card_heights(&mut self, heights: Vec<u16>) {
+        self.heights = heights;
+        self.sync_scroll();
+    }
+
+    /// Returns the number of cards.
+    pub fn len(&self) -> usize {
+        self.heights.len()
+    }
+
+    /// Returns true if there are no cards.
+    pub fn is_empty(&self) -> bool {
+        self.heights.is_empty()
+    }
+
+    /// Returns the gap between columns and between stacked cards.
+    pub fn gap(&self) -> u16 {
+        self.gap
+    }
+
+    /// Returns whether the scrollbar is shown when cards overflow.
+    pub fn show_scrollbar(&self) -> bool {
+        self.show_scrollbar
+    }
+
+    /// Records the screen area the grid is rendered into.
+    ///
+    /// The layout and scroll limits are computed for this area, so set it
+    /// to the same area passed to [`CardGrid::view`] before handling
+    /// messages or querying the layout.
+    pub fn set_area(&mut self, area: Rect) {
+        self.area = area;
+        self.sync_scroll();
+    }
+
+    /// Returns the area set with [`set_area`](Self::set_area).
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Returns the current scroll offset, in rows.
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll.offset()
+    }
+
+    /// Sets the scroll offset, clamped so the last page is full.
+    pub fn set_scroll_offset(&mut self, offset: usize) {
+        self.scroll.set_offset(offset);
+    }
+
+    /// Returns the number of columns used for the current area.
+    pub fn column_count(&self) -> u16 {
+        self.columns_for(self.layout_width(self.area))
+    }
+
+    /// Returns the total height of the packed cards, in rows.
+    pub fn content_height(&self) -> u16 {
+        self.content_height_for(self.layout_width(self.area))
+    }
+
+    /// Returns the position of every card in content space: relative to
+    /// the left of the area and the top of the content, before scrolling.
+    ///
+    /// Each card is placed in the shortest column so far, the leftmost on
+    /// a tie. Columns share the width equally, with any leftover columns
+    /// of the area going to the leftmost columns.
+    pub fn card_rects(&self) -> Vec<Rect> {
+        self.layout(self.layout_width(self.area))
+    }
+
+    /// Returns the on-screen area of each card that is at least partly
+    /// visible, in card order, clipped to the viewport.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CardGridState;
+    /// use ratatui::layout::Rect;
+    ///
+    /// let mut state = CardGridState::new([4, 4, 4]).with_show_scrollbar(false);
+    /// state.set_area(Rect::new(0, 0, 10, 6));
+    /// state.set_scroll_offset(2);
+    ///
+    /// let visible = state.visible_cards();
+    /// // Card 0 has two rows scrolled off; card 1 starts below the gap.
+    /// assert_eq!(visible[0].area, Rect::new(0, 0, 10, 2));
+    /// assert_eq!(visible[0].clipped_top, 2);
+    /// assert_eq!(visible[1].area, Rect::new(0, 3, 10, 3));
+    /// assert_eq!(visible.len(), 2);
+    /// ```
+    pub fn visible_cards(&self) -> Vec<VisibleCard> {
+        let offset = self.scroll.offset();
+        let top = offset;
+        let bottom = offset + usize::from(self.area.height);
+        self.card_rects()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, rect)| {
+                let start = usize::from(rect.y).max(top);
+                let end = usize::from(rect.bottom()).min(bottom);
+                if start >= end {
+                    return None;
+                }
+                let y = self.area.y + (start - offset) as u16;
+                Some(VisibleCard {
+                    index,
+                    area: Rect::new(self.area.x + rect.x, y, rect.width, (end - start) as u16),
+                    clipped_top: (start - usize::from(rect.y)) as u16,
+                })
+            })
+            .collect()
+    }
+
+    /// Updates the state with a message, returning any output.
+    pub fn update(&mut self, msg: CardGridMessage) -> Option<()> {
+        CardGrid::update(self, msg)
+    }
+
+    /// Returns the width the cards are laid out in within `area`: all of
+    /// it, less a column for the scrollbar when the cards overflow.
+    fn layout_width(&self, area: Rect) -> u16 {
+        let width = area.width;
+        if self.show_scrollbar && self.content_height_for(width) > area.height {
+            width.saturating_sub(1)
+        } else {
+            width
+        }
+    }
+
+    fn columns_for(&self, width: u16) -> u16 {
+        // A column plus its gap fits `(width + gap) / (column + gap)` times.
+        let fit = |column: u16| {
+            let count = (u32::from(width) + u32::from(self.gap))
+                / (u32::from(column) + u32::from(self.gap));
+            u16::try_from(count).unwrap_or(u16::MAX)
+        };
+        let columns = match self.columns {
+            ColumnRule::Fixed(columns) => columns,
+            ColumnRule::MinWidth(min) => fit(min),
+        };
+        // Every column needs at least one cell of width.
+        columns.min(fit(1)).max(1)
+    }
+
+    fn content_height_for(&self, width: u16) -> u16 {
+        self.layout(width)
+            .iter()
+            .map(|rect| rect.bottom())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn layout(&self, width: u16) -> Vec<Rect> {
+        let columns = self.columns_for(width);
+        let gaps = self.gap.saturating_mul(columns - 1);
+        let usable = width.saturating_sub(gaps);
+        let base = usable / columns;
+        let extra = usable % columns;
+
+        let mut column_x = Vec::with_capacity(usize::from(columns));
+        let mut x = 0u16;
+        for column in 0..columns {
+            let column_width = base + u16::from(column < extra);
+            column_x.push((x, column_width));
+            x = x.saturating_add(column_width).saturating_add(self.gap);
+        }
+
+        // Next free row in each column
+        let mut tops = vec![0u16; usize::from(columns)];
+        self.heights
+            .iter()
+            .map(|&height| {
+                let column = (0..tops.len()).min_by_key(|&c| tops[c]).unwrap_or(0);
+                let (x, column_width) = column_x[column];
+                let rect = Rect::new(x, tops[column], column_width, height);
+                tops[column] = tops[column].saturating_add(height).saturating_add(self.gap);
+                rect
+            })
+            .collect()
+    }
+
+    fn sync_scroll(&mut self) {
+        self.scroll
+            .set_content_length(usize::from(self.content_height()));
+        self.scroll
+            .set_viewport_height(usize::from(self.area.height));
+    }
+}
+
+/// A masonry layout of variable-height cards.
+///
+/// `CardGrid` arranges cards in columns, each card going into the column
+/// that is shortest so far, and scrolls vertically when they overflow. It
+/// draws only a scrollbar; the app draws each card (usually a bordered
+/// block) into the areas from [`CardGridState::visible_cards`]. The number
+/// of columns is either fixed or derived from a minimum card width.
+///
+/// # Keyboard Navigation
+///
+/// When focused:
+/// - `Up` / `k` -- Scroll up one line
+/// - `Down` / `j` -- Scroll down one line
+/// - `PageUp` / `Ctrl+u` -- Scroll up one page
+/// - `PageDown` / `Ctrl+d` -- Scroll down one page
+/// - `Home` / `g` -- Scroll to top
+/// - `End` / `G` (Shift+g) -- Scroll to bottom
+///
+/// # Example
+///
+/// ```rust
+/// use envision::component::{CardGrid, CardGridState, Component, RenderContext};
+/// use envision::theme::Theme;
+/// use ratatui::widgets::{Block, Borders, Paragraph};
+/// use ratatui::{Terminal, backend::TestBackend};
+///
+/// let titles = ["CPU", "Memory", "Disk", "Network"];
+/// let mut state = CardGridState::new([5, 3, 4, 6]).with_min_card_width(16);
+/// let theme = Theme::default();
+/// let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+/// terminal
+///     .draw(|frame| {
+///         let area = frame.area();
+///         state.set_area(area);
+///         for card in state.visible_cards() {
+///             let block = Block::default().borders(Borders::ALL).title(titles[card.index]);
+///             frame.render_widget(Paragraph::new("").block(block), card.area);
+///         }
+///         CardGrid::view(&state, &mut RenderContext::new(frame, area, &theme));
+///     })
+///     .unwrap();
+/// ```
+pub struct CardGrid;
+
+impl Component for CardGrid {
+    type State = CardGridState;
+    type Message = CardGridMessage;
+    type Output = ();
+
+    fn init() -> Self::State {
+        CardGridState::default()
+    }
+
+    fn handle_event(
+        _state: &Self::State,
+        event: &Event,
+        ctx: &EventContext,
+    ) -> Option<Self::Message> {
+        if !ctx.focused || ctx.disabled {
+            return None;
+        }
+
+        let key = event.as_key_press()?;
+        let ctrl = key.modifiers.ctrl();
+
+        match key.code {
+            Key::Up | Key::Char('k') if !ctrl => Some(CardGridMessage::ScrollUp),
+            Key::Down | Key::Char('j') if !ctrl => Some(CardGridMessage::ScrollDown),
+            Key::PageUp => Some(CardGridMessage::PageUp),
+            Key::PageDown => Some(CardGridMessage::PageDown),
+            Key::Char('u') if ctrl => Some(CardGridMessage::PageUp),
+            Key::Char('d') if ctrl => Some(CardGridMessage::PageDown),
+            Key::Char('g') if key.modifiers.shift() => Some(CardGridMessage::End),
+            Key::Home | Key::Char('g') => Some(CardGridMessage::Home),
+            Key::End => Some(CardGridMessage::End),
+            _ => None,
+        }
+    }
+
+    fn update(state: &mut Self::State, msg: Self::Message) -> Option<Self::Output> {
+        let page = state.scroll.viewport_height().max(1);
+        let moved = match msg {
+            CardGridMessage::ScrollUp => state.scroll.scroll_up(),
+            CardGridMessage::ScrollDown => state.scroll.scroll_down(),
+            CardGridMessage::PageUp => state.scroll.page_up(page),
+            CardGridMessage::PageDown => state.scroll.page_down(page),
+            CardGridMessage::Home => state.scroll.scroll_to_start(),
+            CardGridMessage::End => state.scroll.scroll_to_end(),
+            CardGridMessage::ScrollTo(line) => {
+                let before = state.scroll.offset();
+                state.scroll.set_offset(line);
+                state.scroll.offset() != before
+            }
+            CardGridMessage::ScrollToCard(index) => {
+                let rect = state.card_rects().get(index).copied()?;
+                let before = state.scroll.offset();
+                let (top, bottom) = (usize::from(rect.y), usize::from(rect.bottom()));
+                let viewport = usize::from(state.area.height);
+                if top < before || bottom - top > viewport {
+                    state.scroll.set_offset(top);
+                } else if bottom > before + viewport {
+                    state.scroll.set_offset(bottom - viewport);
+                }
+                state.scroll.offset() != before
+            }
+            CardGridMessage::SetCardHeights(heights) => {
+                state.set_card_heights(heights);
+                return None;
+            }
+        };
+        moved.then_some(())
+    }
+
+    fn view(state: &Self::State, ctx: &mut RenderContext<'_, '_>) {
+        crate::annotation::with_registry(|reg| {
+            reg.register(
+                ctx.area,
+                crate::annotation::Annotation::custom("CardGrid", "card_grid")
+                    .with_focus(ctx.focused)
+                    .with_disabled(ctx.disabled)
+                    .with_value(format!("{} cards", state.heights.len())),
+            );
+        });
+
+        if !state.show_scrollbar || ctx.area.width == 0 || ctx.area.height == 0 {
+            return;
+        }
+
+        // Measure against the area being drawn, which may not be the one
+        // last passed to `set_area`.
+        let content_height = state.content_height_for(state.layout_width(ctx.area));
+        let mut bar = ScrollState::new(usize::from(content_height));
+        bar.set_viewport_height(usize::from(ctx.area.height));
+        bar.set_offset(state.scroll.offset());
+        crate::scroll::render_scrollbar(&bar, ctx.frame, ctx.area, ctx.theme);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,196 @@
+use super::*;
+use crate::component::test_utils;
+use ratatui::widgets::{Block, Borders};
+
+fn grid(heights: &[u16], columns: u16, width: u16, height: u16) -> CardGridState {
+    let mut state = CardGridState::new(heights.iter().copied()).with_columns(columns);
+    state.set_area(Rect::new(0, 0, width, height));
+    state
+}
+
+fn render(state: &CardGridState, width: u16, height: u16) -> String {
+    let (mut terminal, theme) = test_utils::setup_render(width, height);
+    terminal
+        .draw(|frame| {
+            let area = frame.area();
+            for card in state.visible_cards() {
+                let block = Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("#{}", card.index));
+                frame.render_widget(block, card.area);
+            }
+            CardGrid::view(state, &mut RenderContext::new(frame, area, &theme));
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+// =============================================================================
+// Layout
+// =============================================================================
+
+#[test]
+fn test_cards_go_to_shortest_column() {
+    let state = grid(&[6, 2, 2, 3, 1], 2, 21, 40);
+    assert_eq!(
+        state.card_rects(),
+        vec![
+            Rect::new(0, 0, 10, 6),
+            Rect::new(11, 0, 10, 2),
+            Rect::new(11, 3, 10, 2),
+            Rect::new(11, 6, 10, 3),
+            Rect::new(0, 7, 10, 1),
+        ]
+    );
+    assert_eq!(state.content_height(), 9);
+}
+
+#[test]
+fn test_tie_goes_to_leftmost_column() {
+    let state = grid(&[2, 2, 1], 2, 21, 40);
+    assert_eq!(state.card_rects()[2], Rect::new(0, 3, 10, 1));
+}
+
+#[test]
+fn test_leftover_width_goes_to_leftmost_columns() {
+    let state = grid(&[1, 1, 1], 3, 12, 10).with_gap(0);
+    let widths: Vec<u16> = state.card_rects().iter().map(|r| r.width).collect();
+    assert_eq!(widths, vec![4, 4, 4]);
+
+    let state = grid(&[1, 1, 1], 3, 14, 10).with_gap(0);
+    let rects = state.card_rects();
+    assert_eq!(
+        rects.iter().map(|r| (r.x, r.width)).collect::<Vec<_>>(),
+        vec![(0, 5), (5, 5), (10, 4)]
+    );
+}
+
+#[test]
+fn test_min_card_width_derives_columns() {
+    let mut state = CardGridState::new([1, 1, 1, 1]).with_min_card_width(20);
+    for (width, columns) in [(10, 1), (41, 2), (61, 2), (62, 3), (200, 9)] {
+        state.set_area(Rect::new(0, 0, width, 50));
+        assert_eq!(state.column_count(), columns, "width {width}");
+    }
+}
+
+#[test]
+fn test_columns_limited_by_width() {
+    let state = grid(&[1, 1], 10, 5, 10);
+    // Five cells fit three one-wide columns with gaps of one.
+    assert_eq!(state.column_count(), 3);
+    assert_eq!(grid(&[1], 4, 0, 10).column_count(), 1);
+}
+
+#[test]
+fn test_empty_grid() {
+    let state = grid(&[], 3, 30, 10);
+    assert!(state.is_empty());
+    assert_eq!(state.content_height(), 0);
+    assert!(state.card_rects().is_empty());
+    assert!(state.visible_cards().is_empty());
+}
+
+#[test]
+fn test_overflow_reserves_scrollbar_column() {
+    let fits = grid(&[3, 3], 2, 21, 10);
+    assert_eq!(fits.card_rects()[1], Rect::new(11, 0, 10, 3));
+
+    let overflows = grid(&[30, 3], 2, 21, 10);
+    assert_eq!(overflows.card_rects()[1], Rect::new(11, 0, 9, 3));
+
+    let no_bar = grid(&[30, 3], 2, 21, 10).with_show_scrollbar(false);
+    assert_eq!(no_bar.card_rects()[1], Rect::new(11, 0, 10, 3));
+}
+
+#[test]
+fn test_visible_cards_offset_by_area_origin() {
+    let mut state = CardGridState::new([2, 2]).with_columns(2);
+    state.set_area(Rect::new(5, 3, 21, 4));
+    let visible = state.visible_cards();
+    assert_eq!(visible[0].area, Rect::new(5, 3, 10, 2));
+    assert_eq!(visible[1].area, Rect::new(16, 3, 10, 2));
+    assert_eq!(visible[1].clipped_top, 0);
+}
+
+// =============================================================================
+// Scrolling
+// =============================================================================
+
+#[test]
+fn test_scroll_is_clamped_to_content() {
+    let mut state = grid(&[5, 5, 5], 1, 10, 6).with_show_scrollbar(false);
+    // Content is 17 rows tall; the last page starts at row 11.
+    assert_eq!(state.update(CardGridMessage::End), Some(()));
+    assert_eq!(state.scroll_offset(), 11);
+    assert_eq!(state.update(CardGridMessage::ScrollDown), None);
+    assert_eq!(state.update(CardGridMessage::PageUp), Some(()));
+    assert_eq!(state.scroll_offset(), 5);
+    assert_eq!(state.update(CardGridMessage::Home), Some(()));
+    assert_eq!(state.update(CardGridMessage::ScrollUp), None);
+    assert_eq!(state.update(CardGridMessage::ScrollTo(100)), Some(()));
+    assert_eq!(state.scroll_offset(), 11);
+}
+
+#[test]
+fn test_scroll_to_card_moves_least_distance() {
+    let mut state = grid(&[5, 5, 5], 1, 10, 6).with_show_scrollbar(false);
+    // Card 1 spans rows 6..11: scroll so it ends at the bottom.
+    assert_eq!(state.update(CardGridMessage::ScrollToCard(1)), Some(()));
+    assert_eq!(state.scroll_offset(), 5);
+    assert_eq!(state.update(CardGridMessage::ScrollToCard(1)), None);
+    assert_eq!(state.update(CardGridMessage::ScrollToCard(0)), Some(()));
+    assert_eq!(state.scroll_offset(), 0);
+    assert_eq!(state.update(CardGridMessage::ScrollToCard(9)), None);
+}
+
+#[test]
+fn test_set_card_heights_clamps_offset() {
+    let mut state = grid(&[5, 5, 5], 1, 10, 6).with_show_scrollbar(false);
+    state.update(CardGridMessage::End);
+    assert_eq!(
+        state.update(CardGridMessage::SetCardHeights(vec![4, 4])),
+        None
+    );
+    assert_eq!(state.card_heights(), &[4, 4]);
+    assert_eq!(state.scroll_offset(), 3);
+}
+
+#[test]
+fn test_handle_event_requires_focus() {
+    let state = grid(&[20], 1, 10, 5);
+    let focused = EventContext::new().focused(true);
+    assert_eq!(
+        CardGrid::handle_event(&state, &Event::key(Key::Down), &focused),
+        Some(CardGridMessage::ScrollDown)
+    );
+    assert_eq!(
+        CardGrid::handle_event(&state, &Event::char('G'), &focused),
+        Some(CardGridMessage::End)
+    );
+    assert_eq!(
+        CardGrid::handle_event(&state, &Event::ctrl('d'), &focused),
+        Some(CardGridMessage::PageDown)
+    );
+    assert_eq!(
+        CardGrid::handle_event(&state, &Event::key(Key::Down), &EventContext::new()),
+        None
+    );
+    let disabled = EventContext::new().focused(true).disabled(true);
+    assert_eq!(
+        CardGrid::handle_event(&state, &Event::key(Key::Down), &disabled),
+        None
+    );
+}
+
+// =============================================================================
+// Rendering
+// =============================================================================
+
+#[test]
+fn test_view_masonry_with_scrollbar() {
+    let mut state = CardGridState::new([4, 3, 3, 4]).with_columns(2);
+    state.set_area(Rect::new(0, 0, 24, 8));
+    state.update(CardGridMessage::ScrollDown);
+    insta::assert_snapshot!(render(&state, 24, 8));
+}
@@ -0,0 +1,374 @@
+//! A paged container with dot indicators.
+//!
+//! [`Carousel`] tracks which of a fixed number of pages is showing, for
+//! onboarding wizards, galleries and other content viewed one page at a
+//! time. It draws only the page indicators (`● ○ ○`) on the bottom row;
+//! the app renders the current page itself into
+//! [`Carousel::content_area`]. State is stored in [`CarouselState`],
+//! updated via [`CarouselMessage`], and produces [`CarouselOutput`].
+//!
+//! See also [`Tabs`](super::Tabs) for labelled, directly selectable pages
+//! and [`StepIndicator`](super::StepIndicator) for wizard progress.
+//!
+//! # Example
+//!
+//! ```rust
+//! use envision::component::{Carousel, CarouselMessage, CarouselOutput, CarouselState, Component};
+//!
+//! let mut state = CarouselState::new(3).with_wrap(true);
+//! assert_eq!(state.current(), 0);
+//!
+//! let output = Carousel::update(&mut state, CarouselMessage::Prev);
+//! assert_eq!(output, Some(CarouselOutput::PageChanged(2)));
+//! assert_eq!(state.current(), 2);
+//! ```
+
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use super::{Component, EventContext, RenderContext};
+use crate::input::{Event, Key};
+
+/// Messages that can be sent to a Carousel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CarouselMessage {
+    /// Show the next page.
+    Next,
+    /// Show the previous page.
+    Prev,
+    /// Show a specific page (0-indexed, clamped to the last page).
+    GoTo(usize),
+    /// Change the number of pages, clamping the current page.
+    SetCount(usize),
+}
+
+/// Output messages from a Carousel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CarouselOutput {
+    /// The current page changed. Contains the new page index (0-indexed).
+    PageChanged(usize),
+}
+
+/// State for a Carousel component.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::component::CarouselState;
+///
+/// let state = CarouselState::new(4).with_current(2);
+/// assert_eq!(state.current(), 2);
+/// assert_eq!(state.count(), 4);
+/// assert!(!state.is_wrapping());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct CarouselState {
+    /// Current page (0-indexed).
+    current: usize,
+    /// Number of pages.
+    count: usize,
+    /// Whether navigating past either end wraps around.
+    wrap: bool,
+}
+
+impl CarouselState {
+    /// Creates a carousel with `count` pages, showing the first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CarouselState;
+    ///
+    /// let state = CarouselState::new(3);
+    /// assert_eq!(state.current(), 0);
+    /// assert_eq!(state.count(), 3);
+    /// ```
+    pub fn new(count: usize) -> Self {
+        Self {
+            count,
+            ..Self::default()
+        }
+    }
+
+    /// Sets whether Next on the last page and Prev on the first wrap
+    /// around (builder pattern).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CarouselState;
+    ///
+    /// let state = CarouselState::new(3).with_wrap(true);
+    /// assert!(state.is_wrapping());
+    /// ```
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets the starting page, clamped to the last page (builder pattern).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CarouselState;
+    ///
+    /// let state = CarouselState::new(3).with_current(10);
+    /// assert_eq!(state.current(), 2);
+    /// ```
+    pub fn with_current(mut self, page: usize) -> Self {
+        self.set_current(page);
+        self
+    }
+
+    /// Returns the current page (0-indexed).
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the number of pages.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns true if navigation wraps around at either end.
+    pub fn is_wrapping(&self) -> bool {
+        self.wrap
+    }
+
+    /// Returns true if the current page is the first.
+    pub fn is_first(&self) -> bool {
+        self.current == 0
+    }
+
+    /// Returns true if the current page is the last, or there are no pages.
+    pub fn is_last(&self) -> bool {
+        self.current + 1 >= self.count
+    }
+
+    /// Sets the current page, clamped to the last page.
+    pub fn set_current(&mut self, page: usize) {
+        self.current = page.min(self.count.saturating_sub(1));
+    }
+
+    /// Sets whether navigation wraps around at either end.
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
+    /// Sets the number of pages, clamping the current page.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CarouselState;
+    ///
+    /// let mut state = CarouselState::new(5).with_current(4);
+    /// state.set_count(2);
+    /// assert_eq!(state.current(), 1);
+    /// ```
+    pub fn set_count(&mut self, count: usize) {
+        self.count = count;
+        self.set_current(self.current);
+    }
+
+    /// Updates the state with a message, returning any output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{CarouselMessage, CarouselOutput, CarouselState};
+    ///
+    /// let mut state = CarouselState::new(3);
+    /// assert_eq!(state.update(CarouselMessage::Next), Some(CarouselOutput::PageChanged(1)));
+    /// ```
+    pub fn update(&mut self, msg: CarouselMessage) -> Option<CarouselOutput> {
+        Carousel::update(self, msg)
+    }
+
+    /// Moves to `page` and reports the change, if it is a change.
+    fn go_to(&mut self, page: usize) -> Option<CarouselOutput> {
+        let previous = self.current;
+        self.set_current(page);
+        (self.current != previous).then_some(CarouselOutput::PageChanged(self.current))
+    }
+}
+
+/// A paged container with dot indicators.
+///
+/// `Carousel` renders a row of page indicators at the bottom of its area:
+/// `●` for the current page and `○` for the others, or `3 / 12` when the
+/// dots do not fit. The rest of the area is left to the app, which draws
+/// the current page into [`Carousel::content_area`].
+///
+/// # Key Bindings
+///
+/// - `Left` / `h` -- Previous page
+/// - `Right` / `l` -- Next page
+/// - `Home` -- First page
+/// - `End` -- Last page
+///
+/// # Example
+///
+/// ```rust
+/// use envision::component::{Carousel, CarouselState, Component, RenderContext};
+/// use envision::theme::Theme;
+/// use ratatui::widgets::Paragraph;
+/// use ratatui::{Terminal, backend::TestBackend};
+///
+/// let state = CarouselState::new(3).with_current(1);
+/// let pages = ["Welcome", "Setup", "Done"];
+/// let theme = Theme::default();
+/// let mut terminal = Terminal::new(TestBackend::new(20, 3)).unwrap();
+/// terminal
+///     .draw(|frame| {
+///         let area = frame.area();
+///         let page = Paragraph::new(pages[state.current()]);
+///         frame.render_widget(page, Carousel::content_area(area));
+///         Carousel::view(&state, &mut RenderContext::new(frame, area, &theme));
+///     })
+///     .unwrap();
+/// ```
+pub struct Carousel;
+
+impl Carousel {
+    /// Returns the part of `area` left for page content: everything above
+    /// the indicator row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::Carousel;
+    /// use ratatui::layout::Rect;
+    ///
+    /// assert_eq!(Carousel::content_area(Rect::new(0, 0, 20, 5)), Rect::new(0, 0, 20, 4));
+    /// ```
+    pub fn content_area(area: Rect) -> Rect {
+        Rect {
+            height: area.height.saturating_sub(1),
+            ..area
+        }
+    }
+
+    /// Returns the row of `area` where the page indicators are drawn.
+    pub fn indicator_area(area: Rect) -> Rect {
+        Rect {
+            y: area.bottom().saturating_sub(1).max(area.y),
+            height: area.height.min(1),
+            ..area
+        }
+    }
+}
+
+impl Component for Carousel {
+    type State = CarouselState;
+    type Message = CarouselMessage;
+    type Output = CarouselOutput;
+
+    fn init() -> Self::State {
+        CarouselState::default()
+    }
+
+    fn handle_event(
+        state: &Self::State,
+        event: &Event,
+        ctx: &EventContext,
+    ) -> Option<Self::Message> {
+        if !ctx.focused || ctx.disabled {
+            return None;
+        }
+
+        let key = event.as_key_press()?;
+
+        match key.code {
+            Key::Left | Key::Char('h') => Some(CarouselMessage::Prev),
+            Key::Right | Key::Char('l') => Some(CarouselMessage::Next),
+            Key::Home => Some(CarouselMessage::GoTo(0)),
+            Key::End => Some(CarouselMessage::GoTo(state.count.saturating_sub(1))),
+            _ => None,
+        }
+    }
+
+    fn update(state: &mut Self::State, msg: Self::Message) -> Option<Self::Output> {
+        match msg {
+            CarouselMessage::Next => {
+                if !state.is_last() {
+                    state.go_to(state.current + 1)
+                } else if state.wrap {
+                    state.go_to(0)
+                } else {
+                    None
+                }
+            }
+            CarouselMessage::Prev => {
+                if !state.is_first() {
+                    state.go_to(state.current - 1)
+                } else if state.wrap {
+                    state.go_to(state.count.saturating_sub(1))
+                } else {
+                    None
+                }
+            }
+            CarouselMessage::GoTo(page) => state.go_to(page),
+            CarouselMessage::SetCount(count) => {
+                state.set_count(count);
+                None
+            }
+        }
+    }
+
+    fn view(state: &Self::State, ctx: &mut RenderContext<'_, '_>) {
+        crate::annotation::with_registry(|reg| {
+            reg.register(
+                ctx.area,
+                crate::annotation::Annotation::custom("Carousel", "carousel")
+                    .with_focus(ctx.focused)
+                    .with_disabled(ctx.disabled)
+                    .with_value(format!("{}/{}", state.current + 1, state.count)),
+            );
+        });
+
+        if state.count == 0 {
+            return;
+        }
+        let area = Self::indicator_area(ctx.area);
+
+        let (current_style, other_style) = if ctx.disabled {
+            (ctx.theme.disabled_style(), ctx.theme.disabled_style())
+        } else if ctx.focused {
+            (ctx.theme.focused_style(), ctx.theme.border_style())
+        } else {
+            (ctx.theme.normal_style(), ctx.theme.border_style())
+        };
+
+        // One column per dot plus a space between each.
+        let line = if state.count * 2 - 1 <= usize::from(area.width) {
+            let mut spans = Vec::with_capacity(state.count * 2);
+            for page in 0..state.count {
+                if page > 0 {
+                    spans.push(Span::raw(" "));
+                }
+                spans.push(if page == state.current {
+                    Span::styled("●", current_style)
+                } else {
+                    Span::styled("○", other_style)
+                });
+            }
+            Line::from(spans)
+        } else {
+            Line::styled(
+                format!("{} / {}", state.current + 1, state.count),
+                current_style,
+            )
+        };
+        let paragraph = Paragraph::new(line).alignment(Alignment::Center);
+        ctx.frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,198 @@
+use super::*;
+use crate::component::test_utils;
+
+fn render(state: &CarouselState, width: u16, height: u16, focused: bool) -> String {
+    let (mut terminal, theme) = test_utils::setup_render(width, height);
+    terminal
+        .draw(|frame| {
+            let area = frame.area();
+            frame.render_widget(
+                Paragraph::new(format!("Page {}", state.current() + 1)),
+                Carousel::content_area(area),
+            );
+            Carousel::view(
+                state,
+                &mut RenderContext::new(frame, area, &theme).focused(focused),
+            );
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+// =============================================================================
+// State
+// =============================================================================
+
+#[test]
+fn test_new_and_default() {
+    let state = CarouselState::new(3);
+    assert_eq!(state.current(), 0);
+    assert_eq!(state.count(), 3);
+    assert!(!state.is_wrapping());
+    assert!(state.is_first());
+    assert!(!state.is_last());
+
+    let state = Carousel::init();
+    assert_eq!(state.count(), 0);
+    assert!(state.is_last());
+}
+
+#[test]
+fn test_set_count_clamps_current() {
+    let mut state = CarouselState::new(5).with_current(3);
+    Carousel::update(&mut state, CarouselMessage::SetCount(2));
+    assert_eq!(state.count(), 2);
+    assert_eq!(state.current(), 1);
+
+    state.set_count(0);
+    assert_eq!(state.current(), 0);
+}
+
+// =============================================================================
+// Navigation
+// =============================================================================
+
+#[test]
+fn test_next_and_prev_stop_at_ends_without_wrap() {
+    let mut state = CarouselState::new(2);
+    assert_eq!(Carousel::update(&mut state, CarouselMessage::Prev), None);
+    assert_eq!(
+        Carousel::update(&mut state, CarouselMessage::Next),
+        Some(CarouselOutput::PageChanged(1))
+    );
+    assert_eq!(Carousel::update(&mut state, CarouselMessage::Next), None);
+    assert_eq!(state.current(), 1);
+}
+
+#[test]
+fn test_next_and_prev_wrap_around() {
+    let mut state = CarouselState::new(3).with_wrap(true).with_current(2);
+    assert_eq!(
+        Carousel::update(&mut state, CarouselMessage::Next),
+        Some(CarouselOutput::PageChanged(0))
+    );
+    assert_eq!(
+        Carousel::update(&mut state, CarouselMessage::Prev),
+        Some(CarouselOutput::PageChanged(2))
+    );
+}
+
+#[test]
+fn test_wrap_with_single_page_reports_nothing() {
+    let mut state = CarouselState::new(1).with_wrap(true);
+    assert_eq!(Carousel::update(&mut state, CarouselMessage::Next), None);
+    assert_eq!(Carousel::update(&mut state, CarouselMessage::Prev), None);
+}
+
+#[test]
+fn test_go_to_clamps_and_ignores_current_page() {
+    let mut state = CarouselState::new(4);
+    assert_eq!(
+        Carousel::update(&mut state, CarouselMessage::GoTo(9)),
+        Some(CarouselOutput::PageChanged(3))
+    );
+    assert_eq!(Carousel::update(&mut state, CarouselMessage::GoTo(3)), None);
+}
+
+#[test]
+fn test_navigation_with_no_pages() {
+    let mut state = CarouselState::new(0).with_wrap(true);
+    assert_eq!(Carousel::update(&mut state, CarouselMessage::Next), None);
+    assert_eq!(Carousel::update(&mut state, CarouselMessage::Prev), None);
+    assert_eq!(Carousel::update(&mut state, CarouselMessage::GoTo(2)), None);
+}
+
+// =============================================================================
+// Events
+// =============================================================================
+
+#[test]
+fn test_handle_event_keys() {
+    let state = CarouselState::new(5);
+    let ctx = EventContext::new().focused(true);
+    let cases = [
+        (Event::key(Key::Left), CarouselMessage::Prev),
+        (Event::char('h'), CarouselMessage::Prev),
+        (Event::key(Key::Right), CarouselMessage::Next),
+        (Event::char('l'), CarouselMessage::Next),
+        (Event::key(Key::Home), CarouselMessage::GoTo(0)),
+        (Event::key(Key::End), CarouselMessage::GoTo(4)),
+    ];
+    for (event, expected) in cases {
+        assert_eq!(Carousel::handle_event(&state, &event, &ctx), Some(expected));
+    }
+    assert_eq!(
+        Carousel::handle_event(&state, &Event::key(Key::Up), &ctx),
+        None
+    );
+}
+
+#[test]
+fn test_handle_event_requires_focus_and_enabled() {
+    let state = CarouselState::new(3);
+    let event = Event::key(Key::Right);
+    assert_eq!(
+        Carousel::handle_event(&state, &event, &EventContext::default()),
+        None
+    );
+    assert_eq!(
+        Carousel::handle_event(
+            &state,
+            &event,
+            &EventContext::new().focused(true).disabled(true)
+        ),
+        None
+    );
+}
+
+// =============================================================================
+// Layout and rendering
+// =============================================================================
+
+#[test]
+fn test_content_and_indicator_areas() {
+    let area = Rect::new(2, 3, 20, 5);
+    assert_eq!(Carousel::content_area(area), Rect::new(2, 3, 20, 4));
+    assert_eq!(Carousel::indicator_area(area), Rect::new(2, 7, 20, 1));
+
+    let empty = Rect::new(2, 3, 20, 0);
+    assert_eq!(Carousel::content_area(empty), empty);
+    assert_eq!(Carousel::indicator_area(empty), empty);
+}
+
+#[test]
+fn test_view_dots() {
+    let state = CarouselState::new(3).with_current(1);
+    insta::assert_snapshot!(render(&state, 20, 3, true));
+}
+
+#[test]
+fn test_view_falls_back_to_counter_when_dots_do_not_fit() {
+    let state = CarouselState::new(12).with_current(2);
+    let output = render(&state, 12, 2, false);
+    assert_eq!(output.lines().nth(1).unwrap().trim(), "3 / 12");
+}
+
+#[test]
+fn test_view_no_pages_draws_nothing() {
+    let state = CarouselState::new(0);
+    let output = render(&state, 10, 2, false);
+    assert_eq!(output.lines().nth(1).unwrap().trim(), "");
+}
+
+#[test]
+fn test_view_annotation() {
+    use crate::annotation::{WidgetType, with_annotations};
+    let state = CarouselState::new(4).with_current(1);
+    let (mut terminal, theme) = test_utils::setup_render(20, 3);
+    let registry = with_annotations(|| {
+        terminal
+            .draw(|frame| {
+                Carousel::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+            })
+            .unwrap();
+    });
+    let regions = registry.find_by_type(&WidgetType::Custom("Carousel".into()));
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].annotation.value.as_deref(), Some("2/4"));
+}
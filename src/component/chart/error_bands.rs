@@ -5,7 +5,7 @@ use ratatui::prelude::*;
 use super::ChartState;
 use super::annotations::AxisBounds;
 use super::render::interpolate_y;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Fills the shaded region between upper and lower bounds for error bands.
 pub(super) fn fill_error_bands(
@@ -14,7 +14,7 @@ pub(super) fn fill_error_bands(
     graph_area: Rect,
     bounds: AxisBounds,
     disabled: bool,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 ) {
     let x_range = bounds.x_max - bounds.x_min;
     let y_range = bounds.y_max - bounds.y_min;
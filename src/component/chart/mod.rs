@@ -400,7 +400,7 @@ impl Component for Chart {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
 
         match key.code {
             Key::Tab if key.modifiers.shift() => Some(ChartMessage::PrevSeries),
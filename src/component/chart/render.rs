@@ -12,7 +12,7 @@ use ratatui::widgets::{
 
 use super::format::smart_format;
 use super::{BarMode, ChartKind, ChartState};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the legend showing series labels and colors.
 pub(super) fn render_legend(state: &ChartState, frame: &mut Frame, area: Rect) {
@@ -78,7 +78,7 @@ pub(super) fn render_bar_chart(
     state: &ChartState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     horizontal: bool,
     _focused: bool,
     disabled: bool,
@@ -105,7 +105,7 @@ fn render_bar_chart_single(
     state: &ChartState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     horizontal: bool,
     disabled: bool,
 ) {
@@ -148,7 +148,7 @@ fn render_bar_chart_grouped(
     state: &ChartState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     horizontal: bool,
     disabled: bool,
 ) {
@@ -200,7 +200,7 @@ fn render_bar_chart_stacked(
     state: &ChartState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     horizontal: bool,
     disabled: bool,
 ) {
@@ -412,7 +412,7 @@ pub(super) fn render_shared_axis_chart(
     state: &ChartState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     _focused: bool,
     disabled: bool,
 ) {
@@ -829,7 +829,7 @@ fn fill_area_below_curve(
     series_data: &[Vec<(f64, f64)>],
     bounds: super::annotations::AxisBounds,
     disabled: bool,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 ) {
     let x_range = bounds.x_max - bounds.x_min;
     let y_range = bounds.y_max - bounds.y_min;
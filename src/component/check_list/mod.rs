@@ -0,0 +1,522 @@
+//! An always-open multi-select list of checkable items.
+//!
+//! [`CheckList<T>`] renders every item with a `[x]` / `[ ]` prefix and lets
+//! the user move a cursor and toggle items independently. Unlike
+//! [`Dropdown`](super::Dropdown) it never collapses, which makes it a good
+//! fit for permission or feature toggles. State is stored in
+//! [`CheckListState<T>`], updated via [`CheckListMessage`], and produces
+//! [`CheckListOutput`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use envision::component::{CheckList, CheckListMessage, CheckListOutput, CheckListState, Component};
+//!
+//! let mut state = CheckListState::new(vec!["read", "write", "admin"]);
+//!
+//! // Toggle the item under the cursor
+//! let output = CheckList::<&str>::update(&mut state, CheckListMessage::Toggle);
+//! assert_eq!(output, Some(CheckListOutput::CheckedChanged(vec![0])));
+//!
+//! // Move down and toggle again
+//! CheckList::<&str>::update(&mut state, CheckListMessage::Down);
+//! CheckList::<&str>::update(&mut state, CheckListMessage::Toggle);
+//! assert_eq!(state.checked_indices(), vec![0, 1]);
+//! assert_eq!(state.checked_items(), vec![&"read", &"write"]);
+//! ```
+
+use std::marker::PhantomData;
+
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
+
+use super::{Component, EventContext, RenderContext};
+use crate::input::{Event, Key};
+
+/// Messages that can be sent to a CheckList.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckListMessage {
+    /// Move the cursor to the previous visible item.
+    Up,
+    /// Move the cursor to the next visible item.
+    Down,
+    /// Toggle the item under the cursor.
+    Toggle,
+    /// Check every visible item.
+    CheckAll,
+    /// Uncheck every visible item.
+    UncheckAll,
+    /// Show only items whose text contains the given string (case-insensitive).
+    ///
+    /// An empty string clears the filter.
+    SetFilter(String),
+}
+
+/// Output messages from a CheckList.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckListOutput {
+    /// The set of checked items changed (contains every checked item index,
+    /// in ascending order).
+    CheckedChanged(Vec<usize>),
+}
+
+/// State for a CheckList component.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct CheckListState<T: Clone> {
+    /// The available items.
+    items: Vec<T>,
+    /// Whether each item is checked, parallel to `items`.
+    checked: Vec<bool>,
+    /// The item index under the cursor, or `None` if nothing is visible.
+    cursor: Option<usize>,
+    /// The active filter text.
+    filter_text: String,
+    /// Indices of the items matching the filter.
+    filtered_indices: Vec<usize>,
+}
+
+impl<T: Clone + PartialEq> PartialEq for CheckListState<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+            && self.checked == other.checked
+            && self.cursor == other.cursor
+            && self.filter_text == other.filter_text
+    }
+}
+
+impl<T: Clone> Default for CheckListState<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            checked: Vec::new(),
+            cursor: None,
+            filter_text: String::new(),
+            filtered_indices: Vec::new(),
+        }
+    }
+}
+
+impl<T: Clone> CheckListState<T> {
+    /// Creates a new check list with every item unchecked.
+    ///
+    /// The cursor starts on the first item, or `None` if the list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let state = CheckListState::new(vec!["A", "B", "C"]);
+    /// assert_eq!(state.len(), 3);
+    /// assert_eq!(state.cursor(), Some(0));
+    /// assert!(state.checked_indices().is_empty());
+    /// ```
+    pub fn new(items: Vec<T>) -> Self {
+        let checked = vec![false; items.len()];
+        let filtered_indices = (0..items.len()).collect();
+        let cursor = if items.is_empty() { None } else { Some(0) };
+        Self {
+            items,
+            checked,
+            cursor,
+            filter_text: String::new(),
+            filtered_indices,
+        }
+    }
+
+    /// Sets which items start checked (builder pattern).
+    ///
+    /// Out-of-bounds indices are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let state = CheckListState::new(vec!["A", "B", "C"]).with_checked(&[0, 2, 9]);
+    /// assert_eq!(state.checked_indices(), vec![0, 2]);
+    /// ```
+    pub fn with_checked(mut self, indices: &[usize]) -> Self {
+        for &index in indices {
+            self.set_checked(index, true);
+        }
+        self
+    }
+
+    /// Returns the items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let state = CheckListState::new(vec!["A", "B"]);
+    /// assert_eq!(state.items(), &["A", "B"]);
+    /// ```
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns true if the item at `index` is checked.
+    ///
+    /// Returns `false` for out-of-bounds indices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let state = CheckListState::new(vec!["A", "B"]).with_checked(&[1]);
+    /// assert!(!state.is_checked(0));
+    /// assert!(state.is_checked(1));
+    /// ```
+    pub fn is_checked(&self, index: usize) -> bool {
+        self.checked.get(index).copied().unwrap_or(false)
+    }
+
+    /// Sets whether the item at `index` is checked.
+    ///
+    /// Out-of-bounds indices are ignored. No output is produced; use
+    /// [`CheckListMessage::Toggle`] for user-driven changes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let mut state = CheckListState::new(vec!["A", "B"]);
+    /// state.set_checked(1, true);
+    /// assert_eq!(state.checked_indices(), vec![1]);
+    /// ```
+    pub fn set_checked(&mut self, index: usize, checked: bool) {
+        if let Some(flag) = self.checked.get_mut(index) {
+            *flag = checked;
+        }
+    }
+
+    /// Returns the indices of all checked items in ascending order.
+    ///
+    /// Filtering does not affect the result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let state = CheckListState::new(vec!["A", "B", "C"]).with_checked(&[2, 0]);
+    /// assert_eq!(state.checked_indices(), vec![0, 2]);
+    /// ```
+    pub fn checked_indices(&self) -> Vec<usize> {
+        self.checked
+            .iter()
+            .enumerate()
+            .filter(|(_, checked)| **checked)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Returns references to all checked items in order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let state = CheckListState::new(vec!["A", "B", "C"]).with_checked(&[1]);
+    /// assert_eq!(state.checked_items(), vec![&"B"]);
+    /// ```
+    pub fn checked_items(&self) -> Vec<&T> {
+        self.items
+            .iter()
+            .zip(&self.checked)
+            .filter(|(_, checked)| **checked)
+            .map(|(item, _)| item)
+            .collect()
+    }
+
+    /// Returns the item index under the cursor.
+    ///
+    /// Returns `None` if no items are visible.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let state = CheckListState::new(vec!["A", "B"]);
+    /// assert_eq!(state.cursor(), Some(0));
+    /// ```
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// Returns the item under the cursor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let state = CheckListState::new(vec!["A", "B"]);
+    /// assert_eq!(state.cursor_item(), Some(&"A"));
+    /// ```
+    pub fn cursor_item(&self) -> Option<&T> {
+        self.items.get(self.cursor?)
+    }
+
+    /// Returns the current filter text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let state = CheckListState::new(vec!["A"]);
+    /// assert_eq!(state.filter_text(), "");
+    /// ```
+    pub fn filter_text(&self) -> &str {
+        &self.filter_text
+    }
+
+    /// Returns the indices of the items matching the current filter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let state = CheckListState::new(vec!["A", "B"]);
+    /// assert_eq!(state.visible_indices(), &[0, 1]);
+    /// ```
+    pub fn visible_indices(&self) -> &[usize] {
+        &self.filtered_indices
+    }
+
+    /// Returns true if the list has no items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let state: CheckListState<&str> = CheckListState::default();
+    /// assert!(state.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the number of items, ignoring the filter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::CheckListState;
+    ///
+    /// let state = CheckListState::new(vec!["A", "B", "C"]);
+    /// assert_eq!(state.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns the cursor's position within the visible items.
+    fn cursor_position(&self) -> Option<usize> {
+        let cursor = self.cursor?;
+        self.filtered_indices.iter().position(|&i| i == cursor)
+    }
+
+    /// Sets every visible item to `checked`, returning true if anything changed.
+    fn set_visible(&mut self, checked: bool) -> bool {
+        let mut changed = false;
+        for &index in &self.filtered_indices {
+            if self.checked[index] != checked {
+                self.checked[index] = checked;
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+impl<T: Clone + std::fmt::Display> CheckListState<T> {
+    /// Recomputes the visible items, keeping the cursor on the same item
+    /// when it is still visible.
+    fn apply_filter(&mut self) {
+        if self.filter_text.is_empty() {
+            self.filtered_indices = (0..self.items.len()).collect();
+        } else {
+            let filter_lower = self.filter_text.to_lowercase();
+            self.filtered_indices = self
+                .items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.to_string().to_lowercase().contains(&filter_lower))
+                .map(|(i, _)| i)
+                .collect();
+        }
+
+        if self.cursor_position().is_none() {
+            self.cursor = self.filtered_indices.first().copied();
+        }
+    }
+}
+
+impl<T: Clone + std::fmt::Display + 'static> CheckListState<T> {
+    /// Updates the check list state with a message, returning any output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{CheckListMessage, CheckListOutput, CheckListState};
+    ///
+    /// let mut state = CheckListState::new(vec!["A", "B"]);
+    /// let output = state.update(CheckListMessage::CheckAll);
+    /// assert_eq!(output, Some(CheckListOutput::CheckedChanged(vec![0, 1])));
+    /// ```
+    pub fn update(&mut self, msg: CheckListMessage) -> Option<CheckListOutput> {
+        CheckList::<T>::update(self, msg)
+    }
+}
+
+/// An always-open multi-select list of checkable items.
+///
+/// Each item is rendered as `[x] Label` or `[ ] Label`, with the cursor row
+/// highlighted. A substring filter narrows the visible items; `CheckAll` and
+/// `UncheckAll` only affect the visible items.
+///
+/// # Type Parameter
+///
+/// - `T`: The type of items. Must implement `Clone` and `Display` for rendering.
+///
+/// # Keyboard Navigation
+///
+/// - `Up` / `k` - Move the cursor up
+/// - `Down` / `j` - Move the cursor down
+/// - `Space` / `Enter` - Toggle the item under the cursor
+///
+/// # Example
+///
+/// ```rust
+/// use envision::component::{CheckList, CheckListMessage, CheckListOutput, CheckListState, Component};
+///
+/// let mut state = CheckListState::new(vec!["alpha", "beta", "gamma"]);
+///
+/// CheckList::<&str>::update(&mut state, CheckListMessage::SetFilter("a".into()));
+/// let output = CheckList::<&str>::update(&mut state, CheckListMessage::CheckAll);
+/// assert_eq!(output, Some(CheckListOutput::CheckedChanged(vec![0, 1, 2])));
+///
+/// CheckList::<&str>::update(&mut state, CheckListMessage::SetFilter("gam".into()));
+/// let output = CheckList::<&str>::update(&mut state, CheckListMessage::UncheckAll);
+/// assert_eq!(output, Some(CheckListOutput::CheckedChanged(vec![0, 1])));
+/// ```
+pub struct CheckList<T: Clone>(PhantomData<T>);
+
+impl<T: Clone + std::fmt::Display + 'static> Component for CheckList<T> {
+    type State = CheckListState<T>;
+    type Message = CheckListMessage;
+    type Output = CheckListOutput;
+
+    fn init() -> Self::State {
+        CheckListState::default()
+    }
+
+    fn handle_event(
+        _state: &Self::State,
+        event: &Event,
+        ctx: &EventContext,
+    ) -> Option<Self::Message> {
+        if !ctx.focused || ctx.disabled {
+            return None;
+        }
+        let key = event.as_key_press()?;
+        match key.code {
+            Key::Up | Key::Char('k') => Some(CheckListMessage::Up),
+            Key::Down | Key::Char('j') => Some(CheckListMessage::Down),
+            Key::Char(' ') | Key::Enter => Some(CheckListMessage::Toggle),
+            _ => None,
+        }
+    }
+
+    fn update(state: &mut Self::State, msg: Self::Message) -> Option<Self::Output> {
+        match msg {
+            CheckListMessage::Up => {
+                let position = state.cursor_position()?;
+                if position > 0 {
+                    state.cursor = Some(state.filtered_indices[position - 1]);
+                }
+                None
+            }
+            CheckListMessage::Down => {
+                let position = state.cursor_position()?;
+                if let Some(&next) = state.filtered_indices.get(position + 1) {
+                    state.cursor = Some(next);
+                }
+                None
+            }
+            CheckListMessage::Toggle => {
+                let cursor = state.cursor?;
+                state.checked[cursor] = !state.checked[cursor];
+                Some(CheckListOutput::CheckedChanged(state.checked_indices()))
+            }
+            CheckListMessage::CheckAll => state
+                .set_visible(true)
+                .then(|| CheckListOutput::CheckedChanged(state.checked_indices())),
+            CheckListMessage::UncheckAll => state
+                .set_visible(false)
+                .then(|| CheckListOutput::CheckedChanged(state.checked_indices())),
+            CheckListMessage::SetFilter(text) => {
+                state.filter_text = text;
+                state.apply_filter();
+                None
+            }
+        }
+    }
+
+    fn view(state: &Self::State, ctx: &mut RenderContext<'_, '_>) {
+        crate::annotation::with_registry(|reg| {
+            let mut ann = crate::annotation::Annotation::list("check_list")
+                .with_focus(ctx.focused)
+                .with_disabled(ctx.disabled);
+            if let Some(cursor) = state.cursor {
+                ann = ann.with_selected(true).with_value(cursor.to_string());
+            }
+            reg.register(ctx.area, ann);
+        });
+
+        let items: Vec<ListItem> = state
+            .filtered_indices
+            .iter()
+            .map(|&i| {
+                let indicator = if state.checked[i] { "[x]" } else { "[ ]" };
+                let style = if ctx.disabled {
+                    ctx.theme.disabled_style()
+                } else {
+                    ctx.theme.normal_style()
+                };
+                ListItem::new(format!("{} {}", indicator, state.items[i])).style(style)
+            })
+            .collect();
+
+        let highlight_style = if ctx.disabled {
+            ctx.theme.disabled_style()
+        } else {
+            ctx.theme.selected_highlight_style(ctx.focused)
+        };
+
+        let mut list = List::new(items).highlight_style(highlight_style);
+        if !ctx.chrome_owned {
+            list = list.block(Block::default().borders(Borders::ALL));
+        }
+
+        let mut list_state = ListState::default().with_selected(state.cursor_position());
+        ctx.frame
+            .render_stateful_widget(list, ctx.area, &mut list_state);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,259 @@
+use super::*;
+use crate::component::test_utils;
+
+fn permissions() -> CheckListState<&'static str> {
+    CheckListState::new(vec!["read", "write", "delete", "admin"])
+}
+
+#[test]
+fn test_new() {
+    let state = permissions();
+    assert_eq!(state.len(), 4);
+    assert_eq!(state.cursor(), Some(0));
+    assert_eq!(state.cursor_item(), Some(&"read"));
+    assert!(state.checked_indices().is_empty());
+}
+
+#[test]
+fn test_new_empty() {
+    let state = CheckListState::<String>::new(vec![]);
+    assert!(state.is_empty());
+    assert_eq!(state.cursor(), None);
+    assert_eq!(state.cursor_item(), None);
+}
+
+#[test]
+fn test_init() {
+    let state = CheckList::<String>::init();
+    assert!(state.is_empty());
+    assert_eq!(state.cursor(), None);
+}
+
+#[test]
+fn test_with_checked_and_set_checked() {
+    let mut state = permissions().with_checked(&[3, 1, 10]);
+    assert_eq!(state.checked_indices(), vec![1, 3]);
+    assert_eq!(state.checked_items(), vec![&"write", &"admin"]);
+
+    state.set_checked(1, false);
+    state.set_checked(99, true);
+    assert_eq!(state.checked_indices(), vec![3]);
+    assert!(state.is_checked(3));
+    assert!(!state.is_checked(99));
+}
+
+#[test]
+fn test_navigation() {
+    let mut state = permissions();
+    assert_eq!(CheckList::update(&mut state, CheckListMessage::Up), None);
+    assert_eq!(state.cursor(), Some(0));
+
+    for _ in 0..5 {
+        CheckList::update(&mut state, CheckListMessage::Down);
+    }
+    assert_eq!(state.cursor(), Some(3));
+
+    CheckList::update(&mut state, CheckListMessage::Up);
+    assert_eq!(state.cursor(), Some(2));
+}
+
+#[test]
+fn test_toggle() {
+    let mut state = permissions();
+    CheckList::update(&mut state, CheckListMessage::Down);
+
+    let output = CheckList::update(&mut state, CheckListMessage::Toggle);
+    assert_eq!(output, Some(CheckListOutput::CheckedChanged(vec![1])));
+
+    let output = CheckList::update(&mut state, CheckListMessage::Toggle);
+    assert_eq!(output, Some(CheckListOutput::CheckedChanged(vec![])));
+}
+
+#[test]
+fn test_toggle_empty() {
+    let mut state = CheckListState::<String>::default();
+    assert_eq!(
+        CheckList::update(&mut state, CheckListMessage::Toggle),
+        None
+    );
+}
+
+#[test]
+fn test_check_all_and_uncheck_all() {
+    let mut state = permissions();
+
+    let output = CheckList::update(&mut state, CheckListMessage::CheckAll);
+    assert_eq!(
+        output,
+        Some(CheckListOutput::CheckedChanged(vec![0, 1, 2, 3]))
+    );
+    assert_eq!(
+        CheckList::update(&mut state, CheckListMessage::CheckAll),
+        None
+    );
+
+    let output = CheckList::update(&mut state, CheckListMessage::UncheckAll);
+    assert_eq!(output, Some(CheckListOutput::CheckedChanged(vec![])));
+    assert_eq!(
+        CheckList::update(&mut state, CheckListMessage::UncheckAll),
+        None
+    );
+}
+
+#[test]
+fn test_filter_is_case_insensitive_substring() {
+    let mut state = permissions();
+    CheckList::update(&mut state, CheckListMessage::SetFilter("T".into()));
+    assert_eq!(state.filter_text(), "T");
+    assert_eq!(state.visible_indices(), &[1, 2]);
+    assert_eq!(state.cursor(), Some(1));
+
+    CheckList::update(&mut state, CheckListMessage::SetFilter(String::new()));
+    assert_eq!(state.visible_indices(), &[0, 1, 2, 3]);
+    assert_eq!(state.cursor(), Some(1));
+}
+
+#[test]
+fn test_filter_keeps_visible_cursor() {
+    let mut state = permissions();
+    CheckList::update(&mut state, CheckListMessage::Down);
+    CheckList::update(&mut state, CheckListMessage::Down);
+    CheckList::update(&mut state, CheckListMessage::SetFilter("t".into()));
+    assert_eq!(state.cursor(), Some(2));
+}
+
+#[test]
+fn test_filter_navigation_skips_hidden_items() {
+    let mut state = permissions();
+    CheckList::update(&mut state, CheckListMessage::SetFilter("t".into()));
+    CheckList::update(&mut state, CheckListMessage::Down);
+    assert_eq!(state.cursor(), Some(2));
+    CheckList::update(&mut state, CheckListMessage::Down);
+    assert_eq!(state.cursor(), Some(2));
+}
+
+#[test]
+fn test_filter_with_no_matches() {
+    let mut state = permissions();
+    CheckList::update(&mut state, CheckListMessage::SetFilter("zzz".into()));
+    assert!(state.visible_indices().is_empty());
+    assert_eq!(state.cursor(), None);
+    assert_eq!(
+        CheckList::update(&mut state, CheckListMessage::Toggle),
+        None
+    );
+    assert_eq!(CheckList::update(&mut state, CheckListMessage::Down), None);
+}
+
+#[test]
+fn test_check_all_only_affects_visible_items() {
+    let mut state = permissions().with_checked(&[0]);
+    CheckList::update(&mut state, CheckListMessage::SetFilter("t".into()));
+
+    let output = CheckList::update(&mut state, CheckListMessage::CheckAll);
+    assert_eq!(output, Some(CheckListOutput::CheckedChanged(vec![0, 1, 2])));
+
+    CheckList::update(&mut state, CheckListMessage::SetFilter("write".into()));
+    let output = CheckList::update(&mut state, CheckListMessage::UncheckAll);
+    assert_eq!(output, Some(CheckListOutput::CheckedChanged(vec![0, 2])));
+}
+
+#[test]
+fn test_handle_event() {
+    let state = permissions();
+    let ctx = EventContext::new().focused(true);
+    assert_eq!(
+        CheckList::handle_event(&state, &Event::key(Key::Up), &ctx),
+        Some(CheckListMessage::Up)
+    );
+    assert_eq!(
+        CheckList::handle_event(&state, &Event::char('j'), &ctx),
+        Some(CheckListMessage::Down)
+    );
+    assert_eq!(
+        CheckList::handle_event(&state, &Event::char(' '), &ctx),
+        Some(CheckListMessage::Toggle)
+    );
+    assert_eq!(
+        CheckList::handle_event(&state, &Event::key(Key::Enter), &ctx),
+        Some(CheckListMessage::Toggle)
+    );
+    assert_eq!(
+        CheckList::handle_event(&state, &Event::char('x'), &ctx),
+        None
+    );
+}
+
+#[test]
+fn test_handle_event_ignored_when_unfocused_or_disabled() {
+    let state = permissions();
+    assert_eq!(
+        CheckList::handle_event(&state, &Event::char(' '), &EventContext::default()),
+        None
+    );
+    let ctx = EventContext::new().focused(true).disabled(true);
+    assert_eq!(
+        CheckList::handle_event(&state, &Event::char(' '), &ctx),
+        None
+    );
+}
+
+#[test]
+fn test_instance_update() {
+    let mut state = permissions();
+    let output = state.update(CheckListMessage::Toggle);
+    assert_eq!(output, Some(CheckListOutput::CheckedChanged(vec![0])));
+}
+
+#[test]
+fn test_view_renders_checkboxes() {
+    let mut state = permissions().with_checked(&[1]);
+    CheckList::update(&mut state, CheckListMessage::Down);
+    let (mut terminal, theme) = test_utils::setup_render(30, 6);
+
+    terminal
+        .draw(|frame| {
+            CheckList::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &theme).focused(true),
+            );
+        })
+        .unwrap();
+
+    let output = terminal.backend().to_string();
+    assert!(output.contains("[ ] read"));
+    assert!(output.contains("[x] write"));
+    assert!(output.contains("[ ] admin"));
+}
+
+#[test]
+fn test_view_honors_filter() {
+    let mut state = permissions();
+    CheckList::update(&mut state, CheckListMessage::SetFilter("adm".into()));
+    let (mut terminal, theme) = test_utils::setup_render(30, 6);
+
+    terminal
+        .draw(|frame| {
+            CheckList::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    let output = terminal.backend().to_string();
+    assert!(output.contains("[ ] admin"));
+    assert!(!output.contains("read"));
+}
+
+#[test]
+fn test_annotation_emitted() {
+    use crate::annotation::{WidgetType, with_annotations};
+    let state = permissions();
+    let (mut terminal, theme) = test_utils::setup_render(30, 6);
+    let registry = with_annotations(|| {
+        terminal
+            .draw(|frame| {
+                CheckList::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+            })
+            .unwrap();
+    });
+    assert_eq!(registry.find_by_type(&WidgetType::List).len(), 1);
+}
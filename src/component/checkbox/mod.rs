@@ -261,7 +261,7 @@ impl Component for Checkbox {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Enter | Key::Char(' ') => Some(CheckboxMessage::Toggle),
                 _ => None,
@@ -561,7 +561,7 @@ impl Component for CodeBlock {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
         let ctrl = key.modifiers.ctrl();
 
         match key.code {
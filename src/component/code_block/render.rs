@@ -10,7 +10,7 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 use super::CodeBlockState;
 use super::highlight::highlight_line;
 use crate::scroll::ScrollState;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Width of the line-number gutter (digits + separator).
 ///
@@ -27,7 +27,7 @@ pub(super) fn render(
     state: &CodeBlockState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -163,7 +163,7 @@ fn render_gutter(
     _state: &CodeBlockState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 
     disabled: bool,
 ) {
@@ -225,7 +225,7 @@ fn render_code_line(
     state: &CodeBlockState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     let is_highlighted = config.is_highlighted;
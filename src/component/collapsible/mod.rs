@@ -407,7 +407,7 @@ impl Component for Collapsible {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Char(' ') | Key::Enter => Some(CollapsibleMessage::Toggle),
                 Key::Right => Some(CollapsibleMessage::Expand),
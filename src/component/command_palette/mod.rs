@@ -628,7 +628,7 @@ impl Component for CommandPalette {
             return None;
         }
 
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Esc => Some(CommandPaletteMessage::Dismiss),
                 Key::Enter => Some(CommandPaletteMessage::Confirm),
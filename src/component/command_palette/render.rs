@@ -5,14 +5,14 @@ use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph};
 
 use super::CommandPaletteState;
 use crate::scroll::ScrollState;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the command palette component as a centered overlay.
 pub(super) fn render_command_palette(
     state: &CommandPaletteState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 ) {
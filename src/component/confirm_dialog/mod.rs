@@ -33,7 +33,7 @@ use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 
 use super::{Component, EventContext, RenderContext, Toggleable};
 use crate::input::{Event, Key};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Preset button configurations for the confirm dialog.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -591,7 +591,7 @@ impl Component for ConfirmDialog {
         if !state.visible {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Tab if key.modifiers.shift() => Some(ConfirmDialogMessage::FocusPrev),
                 Key::Tab => Some(ConfirmDialogMessage::FocusNext),
@@ -688,7 +688,7 @@ fn render_confirm_buttons(
     state: &ConfirmDialogState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 ) {
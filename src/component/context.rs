@@ -6,7 +6,7 @@
 
 use ratatui::prelude::{Frame, Rect};
 
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Context passed to [`Component::handle_event`](crate::component::Component::handle_event).
 ///
@@ -95,7 +95,10 @@ pub struct RenderContext<'frame, 'buf> {
     /// The area within the frame to render to.
     pub area: Rect,
     /// The theme to use for styling.
-    pub theme: &'frame Theme,
+    ///
+    /// Any [`ThemeProvider`] works here; pass `&theme` for a plain
+    /// [`Theme`](crate::theme::Theme).
+    pub theme: &'frame dyn ThemeProvider,
     /// Whether the component currently has keyboard focus.
     pub focused: bool,
     /// Whether the component is currently disabled.
@@ -108,7 +111,11 @@ pub struct RenderContext<'frame, 'buf> {
 
 impl<'frame, 'buf> RenderContext<'frame, 'buf> {
     /// Constructs a new RenderContext with `focused` and `disabled` both `false`.
-    pub fn new(frame: &'frame mut Frame<'buf>, area: Rect, theme: &'frame Theme) -> Self {
+    pub fn new(
+        frame: &'frame mut Frame<'buf>,
+        area: Rect,
+        theme: &'frame dyn ThemeProvider,
+    ) -> Self {
         Self {
             frame,
             area,
@@ -246,14 +253,17 @@ mod render_context_tests {
             .draw(|frame| {
                 let parent_area = frame.area();
                 let mut ctx = RenderContext::new(frame, parent_area, &theme).focused(true);
-                let parent_theme_ptr = ctx.theme as *const Theme;
+                let parent_theme_ptr = ctx.theme as *const dyn ThemeProvider as *const ();
                 let child_area = ratatui::layout::Rect::new(5, 2, 20, 3);
                 {
                     let mut child_ctx = ctx.with_area(child_area);
                     assert_eq!(child_ctx.area, child_area);
                     assert!(child_ctx.focused);
                     // Verify child shares parent's theme reference (pointer equality)
-                    assert_eq!(child_ctx.theme as *const Theme, parent_theme_ptr);
+                    assert_eq!(
+                        child_ctx.theme as *const dyn ThemeProvider as *const (),
+                        parent_theme_ptr
+                    );
                     child_ctx.render_widget(Paragraph::new("child"));
                 }
                 // Critical: render through the parent ctx after child scope.
@@ -208,7 +208,7 @@ impl Component for ConversationView {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
 
         match key.code {
             Key::Up | Key::Char('k') => Some(ConversationViewMessage::ScrollUp),
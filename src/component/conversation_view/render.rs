@@ -13,7 +13,7 @@ use unicode_width::UnicodeWidthChar;
 use unicode_width::UnicodeWidthStr;
 
 use crate::component::RenderContext;
-use crate::theme::Theme;
+use crate::theme::{Theme, ThemeProvider};
 
 /// Renders the full conversation view using the messages stored in state.
 ///
@@ -87,7 +87,7 @@ fn render_messages_from(
     state: &ConversationViewState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 ) {
     let messages = source.source_messages();
 
@@ -143,7 +143,7 @@ pub(super) fn build_display_lines<'a>(
     messages: &[ConversationMessage],
     state: &ConversationViewState,
     width: usize,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 ) -> Vec<Line<'a>> {
     let mut lines = Vec::new();
 
@@ -161,7 +161,7 @@ fn format_message<'a>(
     msg: &ConversationMessage,
     state: &ConversationViewState,
     width: usize,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     lines: &mut Vec<Line<'a>>,
 ) {
     let role = msg.role();
@@ -211,7 +211,7 @@ fn format_block<'a>(
     width: usize,
     indent: &str,
     role_style: Style,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     lines: &mut Vec<Line<'a>>,
 ) {
     match block {
@@ -309,7 +309,7 @@ fn format_text_block<'a>(
     indent: &str,
     style: Style,
     markdown_enabled: bool,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     lines: &mut Vec<Line<'a>>,
 ) {
     // theme is used by the markdown rendering path below; suppress
@@ -332,7 +332,7 @@ fn format_text_block<'a>(
         );
         for mut md_line in md_lines {
             for span in md_line.spans.iter_mut() {
-                if span.style.fg.is_none() || span.style.fg == Some(theme.foreground) {
+                if span.style.fg.is_none() || span.style.fg == Some(theme.base_theme().foreground) {
                     span.style.fg = style.fg;
                 }
             }
@@ -219,7 +219,7 @@ impl<T: TableRow + 'static> Component for DataGrid<T> {
             return None;
         }
 
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             if state.editing {
                 // Editing mode key bindings
                 match key.code {
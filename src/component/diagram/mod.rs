@@ -349,6 +349,7 @@ impl Component for Diagram {
         use crate::input::Key;
 
         match event {
+            Event::Key(key) if key.is_release() => None,
             Event::Key(key) if state.search.active => {
                 // Search mode: capture typing
                 match key.code {
@@ -14,7 +14,7 @@ use super::layout::{EdgePath, LayoutResult, NodePosition, PathSegment};
 use super::types::{DiagramEdge, DiagramNode, NodeShape, NodeStatus};
 use super::viewport::Viewport2D;
 use crate::component::RenderContext;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Status indicator characters for each node status.
 fn status_indicator(status: &NodeStatus) -> &'static str {
@@ -149,7 +149,7 @@ struct RenderParams<'a> {
     viewport: &'a Viewport2D,
     clip: Rect,
     disabled: bool,
-    theme: &'a Theme,
+    theme: &'a dyn ThemeProvider,
 }
 
 /// Renders all edges using batch buffer writes.
@@ -463,7 +463,7 @@ fn render_info_bar(
     node: &DiagramNode,
     area: Rect,
     disabled: bool,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 ) {
     if area.height < 3 {
         return;
@@ -675,7 +675,7 @@ fn render_search_bar(
     frame: &mut ratatui::Frame,
     search: &super::search::SearchState,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 ) {
     if area.height < 2 {
         return;
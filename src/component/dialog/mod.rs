@@ -29,7 +29,7 @@ use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 
 use super::{Component, EventContext, RenderContext, Toggleable};
 use crate::input::{Event, Key};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// A button configuration for a dialog.
 ///
@@ -693,7 +693,7 @@ impl Component for Dialog {
         if !state.visible {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Tab if key.modifiers.shift() => Some(DialogMessage::FocusPrev),
                 Key::Tab => Some(DialogMessage::FocusNext),
@@ -789,7 +789,7 @@ fn render_buttons(
     state: &DialogState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 ) {
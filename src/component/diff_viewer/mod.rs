@@ -791,7 +791,7 @@ impl Component for DiffViewer {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
         let ctrl = key.modifiers.ctrl();
         let shift = key.modifiers.shift();
 
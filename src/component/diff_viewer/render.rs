@@ -7,7 +7,7 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 
 use super::{DiffLineType, DiffMode, DiffViewerState};
 use crate::scroll::ScrollState;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the DiffViewer in the given area.
 ///
@@ -18,7 +18,7 @@ pub(super) fn render(
     state: &DiffViewerState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -83,12 +83,12 @@ fn build_title(state: &DiffViewerState) -> String {
 }
 
 /// Returns the style for a hunk header line (blue + bold).
-fn header_style(theme: &Theme) -> Style {
+fn header_style(theme: &dyn ThemeProvider) -> Style {
     theme.info_style().add_modifier(Modifier::BOLD)
 }
 
 /// Returns the style for an added line.
-fn added_style(_state: &DiffViewerState, theme: &Theme, disabled: bool) -> Style {
+fn added_style(_state: &DiffViewerState, theme: &dyn ThemeProvider, disabled: bool) -> Style {
     if disabled {
         theme.disabled_style()
     } else {
@@ -97,7 +97,7 @@ fn added_style(_state: &DiffViewerState, theme: &Theme, disabled: bool) -> Style
 }
 
 /// Returns the style for a removed line.
-fn removed_style(_state: &DiffViewerState, theme: &Theme, disabled: bool) -> Style {
+fn removed_style(_state: &DiffViewerState, theme: &dyn ThemeProvider, disabled: bool) -> Style {
     if disabled {
         theme.disabled_style()
     } else {
@@ -106,7 +106,7 @@ fn removed_style(_state: &DiffViewerState, theme: &Theme, disabled: bool) -> Sty
 }
 
 /// Returns the style for a context line.
-fn context_style(_state: &DiffViewerState, theme: &Theme, disabled: bool) -> Style {
+fn context_style(_state: &DiffViewerState, theme: &dyn ThemeProvider, disabled: bool) -> Style {
     if disabled {
         theme.disabled_style()
     } else {
@@ -119,7 +119,7 @@ fn render_unified(
     state: &DiffViewerState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     let all_lines = state.collect_display_lines();
@@ -192,7 +192,7 @@ fn render_side_by_side(
     state: &DiffViewerState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     let pairs = state.collect_side_by_side_pairs();
@@ -263,7 +263,7 @@ fn render_side_line(
     frame: &mut Frame,
     line_area: Rect,
     state: &DiffViewerState,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     prefer_new: bool,
     disabled: bool,
 ) {
@@ -302,7 +302,12 @@ fn render_side_line(
 }
 
 /// Renders a vertical scrollbar within the given content area.
-fn render_scrollbar_in_area(scroll: &ScrollState, frame: &mut Frame, area: Rect, theme: &Theme) {
+fn render_scrollbar_in_area(
+    scroll: &ScrollState,
+    frame: &mut Frame,
+    area: Rect,
+    theme: &dyn ThemeProvider,
+) {
     use ratatui::widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState};
 
     if !scroll.can_scroll() {
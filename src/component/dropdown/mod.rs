@@ -30,10 +30,12 @@
 //! ```
 
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::widgets::{List, ListItem, Paragraph};
 
+use super::SpinnerStyle;
 use super::{Component, EventContext, RenderContext};
 use crate::input::{Event, Key};
+use crate::scroll::ScrollState;
 
 /// Messages that can be sent to a Dropdown.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -58,6 +60,16 @@ pub enum DropdownMessage {
     Confirm,
     /// Set the filter text directly.
     SetFilter(String),
+    /// Replace the options with asynchronously loaded results.
+    ///
+    /// Clears the loading state. The results are shown as-is, without
+    /// applying the local filter, since they typically come from a remote
+    /// search that already matched against the filter text.
+    SetOptionsAsync(Vec<String>),
+    /// Advance the loading spinner animation.
+    ///
+    /// Only advances while the dropdown is loading.
+    Tick,
 }
 
 /// Output messages from a Dropdown.
@@ -94,6 +106,10 @@ pub struct DropdownState {
     is_open: bool,
     /// Placeholder text when nothing selected and filter empty.
     placeholder: String,
+    /// Whether options are currently being loaded asynchronously.
+    loading: bool,
+    /// Current loading spinner animation frame.
+    spinner_frame: usize,
 }
 
 impl Default for DropdownState {
@@ -106,6 +122,8 @@ impl Default for DropdownState {
             highlighted_index: 0,
             is_open: false,
             placeholder: String::from("Search..."),
+            loading: false,
+            spinner_frame: 0,
         }
     }
 }
@@ -408,6 +426,71 @@ impl DropdownState {
         self
     }
 
+    /// Returns true if options are currently being loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use envision::prelude::*;
+    ///
+    /// let mut state = DropdownState::new(Vec::<String>::new());
+    /// assert!(!state.is_loading());
+    ///
+    /// state.set_loading(true);
+    /// assert!(state.is_loading());
+    /// ```
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// Sets whether options are currently being loaded.
+    ///
+    /// While loading, the open option list shows a spinner line instead of
+    /// the options. Send [`DropdownMessage::SetOptionsAsync`] with the results
+    /// to clear the loading state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use envision::prelude::*;
+    ///
+    /// let mut state = DropdownState::new(Vec::<String>::new());
+    /// state.set_loading(true);
+    /// state.update(DropdownMessage::SetOptionsAsync(vec!["Apple".to_string()]));
+    /// assert!(!state.is_loading());
+    /// assert_eq!(state.options(), &["Apple"]);
+    /// ```
+    pub fn set_loading(&mut self, loading: bool) {
+        self.loading = loading;
+        if !loading {
+            self.spinner_frame = 0;
+        }
+    }
+
+    /// Returns the current loading spinner frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use envision::prelude::*;
+    ///
+    /// let mut state = DropdownState::new(Vec::<String>::new());
+    /// state.set_loading(true);
+    /// state.update(DropdownMessage::Tick);
+    /// assert_eq!(state.spinner_frame(), 1);
+    /// ```
+    pub fn spinner_frame(&self) -> usize {
+        self.spinner_frame
+    }
+
+    /// Returns true if the dropdown can be opened.
+    ///
+    /// A loading dropdown can be opened even without options so the
+    /// spinner is visible.
+    fn can_open(&self) -> bool {
+        !self.options.is_empty() || self.loading
+    }
+
     /// Updates the dropdown state with a message, returning any output.
     ///
     /// # Examples
@@ -460,18 +543,21 @@ impl DropdownState {
 /// - Keyboard navigation through filtered results
 /// - Selection from existing options only
 /// - Filter clears on close/confirm
+/// - Optional async loading state with a spinner for remote options
 ///
 /// # Keyboard Navigation
 ///
-/// The dropdown itself doesn't handle keyboard events directly. Your application
-/// should map:
-/// - Characters to [`DropdownMessage::Insert`]
+/// When focused, [`Component::handle_event`] maps keys as follows:
+/// - Characters to [`DropdownMessage::Insert`] (typing opens a closed dropdown)
 /// - Backspace to [`DropdownMessage::Backspace`]
-/// - Down arrow to [`DropdownMessage::Down`]
-/// - Up arrow to [`DropdownMessage::Up`]
-/// - Enter to [`DropdownMessage::Confirm`]
+/// - Down arrow to [`DropdownMessage::Down`], or [`DropdownMessage::Open`] when closed
+/// - Up arrow to [`DropdownMessage::Up`], or [`DropdownMessage::Open`] when closed
+/// - Enter to [`DropdownMessage::Confirm`], or [`DropdownMessage::Toggle`] when closed
 /// - Escape to [`DropdownMessage::Close`]
 ///
+/// The messages remain public, so applications can also drive the dropdown
+/// directly with their own key bindings.
+///
 /// # Visual States
 ///
 /// **Closed (no selection):**
@@ -498,6 +584,9 @@ impl DropdownState {
 /// └──────────────────────┘
 /// ```
 ///
+/// When there are more matches than fit below the input, the option list
+/// scrolls to keep the highlighted option visible and shows a scrollbar.
+///
 /// # Example
 ///
 /// ```rust
@@ -528,7 +617,7 @@ impl Component for Dropdown {
     fn update(state: &mut Self::State, msg: Self::Message) -> Option<Self::Output> {
         match msg {
             DropdownMessage::Open => {
-                if !state.options.is_empty() {
+                if state.can_open() {
                     state.is_open = true;
                     // Reset filter and show all options
                     state.filter_text.clear();
@@ -555,7 +644,7 @@ impl Component for Dropdown {
                     state.is_open = false;
                     state.filter_text.clear();
                     state.update_filter();
-                } else if !state.options.is_empty() {
+                } else if state.can_open() {
                     state.is_open = true;
                     state.filter_text.clear();
                     state.update_filter();
@@ -573,7 +662,7 @@ impl Component for Dropdown {
                 state.filter_text.push(c);
                 state.update_filter();
                 // Auto-open when typing
-                if !state.is_open && !state.options.is_empty() {
+                if !state.is_open && state.can_open() {
                     state.is_open = true;
                 }
                 Some(DropdownOutput::FilterChanged(state.filter_text.clone()))
@@ -600,7 +689,7 @@ impl Component for Dropdown {
                     state.filter_text = text;
                     state.update_filter();
                     // Auto-open when setting filter
-                    if !state.is_open && !state.options.is_empty() {
+                    if !state.is_open && state.can_open() {
                         state.is_open = true;
                     }
                     Some(DropdownOutput::FilterChanged(state.filter_text.clone()))
@@ -651,6 +740,28 @@ impl Component for Dropdown {
                     None
                 }
             }
+            DropdownMessage::SetOptionsAsync(options) => {
+                state.options = options;
+                state.loading = false;
+                state.spinner_frame = 0;
+
+                if let Some(idx) = state.selected_index {
+                    if idx >= state.options.len() {
+                        state.selected_index = None;
+                    }
+                }
+
+                state.filtered_indices = (0..state.options.len()).collect();
+                state.highlighted_index = 0;
+                None
+            }
+            DropdownMessage::Tick => {
+                if state.loading {
+                    state.spinner_frame =
+                        (state.spinner_frame + 1) % SpinnerStyle::Dots.frame_count();
+                }
+                None
+            }
         }
     }
 
@@ -662,20 +773,24 @@ impl Component for Dropdown {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
+            let ctrl = key.modifiers.ctrl();
             if state.is_open {
                 match key.code {
                     Key::Enter => Some(DropdownMessage::Confirm),
                     Key::Esc => Some(DropdownMessage::Close),
                     Key::Up => Some(DropdownMessage::Up),
                     Key::Down => Some(DropdownMessage::Down),
-                    Key::Char(c) if key.modifiers.is_none() => Some(DropdownMessage::Insert(c)),
+                    Key::Char(_) if !ctrl => key.raw_char.map(DropdownMessage::Insert),
                     Key::Backspace => Some(DropdownMessage::Backspace),
                     _ => None,
                 }
             } else {
                 match key.code {
                     Key::Enter => Some(DropdownMessage::Toggle),
+                    Key::Up | Key::Down => Some(DropdownMessage::Open),
+                    // Insert auto-opens the dropdown
+                    Key::Char(_) if !ctrl => key.raw_char.map(DropdownMessage::Insert),
                     _ => None,
                 }
             }
@@ -704,11 +819,7 @@ impl Component for Dropdown {
             ctx.theme.normal_style()
         };
 
-        let border_style = if ctx.focused && !ctx.disabled {
-            ctx.theme.focused_border_style()
-        } else {
-            ctx.theme.border_style()
-        };
+        let block = ctx.theme.focused_block(ctx.focused && !ctx.disabled);
 
         // Determine what to show in the input ctx.area
         let display_text = if state.is_open {
@@ -735,11 +846,9 @@ impl Component for Dropdown {
             style
         };
 
-        let paragraph = Paragraph::new(display_text).style(text_style).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(border_style),
-        );
+        let paragraph = Paragraph::new(display_text)
+            .style(text_style)
+            .block(block.clone());
 
         if !state.is_open {
             ctx.frame.render_widget(paragraph, ctx.area);
@@ -763,22 +872,31 @@ impl Component for Dropdown {
                     height: ctx.area.height.saturating_sub(closed_height),
                 };
 
-                if state.filtered_indices.is_empty() {
+                if state.loading {
+                    let frame = SpinnerStyle::Dots.frame(state.spinner_frame);
+                    let loading = Paragraph::new(format!("  {} Loading...", frame))
+                        .style(ctx.theme.info_style())
+                        .block(block);
+                    ctx.frame.render_widget(loading, list_area);
+                } else if state.filtered_indices.is_empty() {
                     // Show "no matches" message
                     let no_match = Paragraph::new("  No matches")
                         .style(ctx.theme.placeholder_style())
-                        .block(
-                            Block::default()
-                                .borders(Borders::ALL)
-                                .border_style(border_style),
-                        );
+                        .block(block);
                     ctx.frame.render_widget(no_match, list_area);
                 } else {
-                    let items: Vec<ListItem> = state
-                        .filtered_indices
+                    // Scroll so the highlighted option stays within the
+                    // bordered list area
+                    let mut scroll = ScrollState::new(state.filtered_indices.len());
+                    scroll.set_viewport_height(list_area.height.saturating_sub(2) as usize);
+                    scroll.ensure_visible(state.highlighted_index);
+                    let range = scroll.visible_range();
+
+                    let items: Vec<ListItem> = state.filtered_indices[range.clone()]
                         .iter()
                         .enumerate()
-                        .map(|(i, &orig_idx)| {
+                        .map(|(offset, &orig_idx)| {
+                            let i = range.start + offset;
                             let opt = &state.options[orig_idx];
                             let prefix = if i == state.highlighted_index {
                                 "> "
@@ -795,13 +913,12 @@ impl Component for Dropdown {
                         })
                         .collect();
 
-                    let list = List::new(items).block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .border_style(border_style),
-                    );
+                    let list = List::new(items).block(block);
 
                     ctx.frame.render_widget(list, list_area);
+                    crate::scroll::render_scrollbar_inside_border(
+                        &scroll, ctx.frame, list_area, ctx.theme,
+                    );
                 }
             }
         }
@@ -467,6 +467,51 @@ fn test_view_open_no_filter() {
     insta::assert_snapshot!(terminal.backend().to_string());
 }
 
+#[test]
+fn test_view_open_scrolls_to_highlighted() {
+    let options: Vec<String> = (1..=20).map(|i| format!("Option {i}")).collect();
+    let mut state = DropdownState::new(options);
+    Dropdown::update(&mut state, DropdownMessage::Open);
+    for _ in 0..9 {
+        Dropdown::update(&mut state, DropdownMessage::Down);
+    }
+
+    // 3 input rows + 2 list borders leaves room for 5 options
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 10);
+
+    terminal
+        .draw(|frame| {
+            Dropdown::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    let output = terminal.backend().to_string();
+    assert!(output.contains("> Option 10"));
+    assert!(output.contains("  Option 6"));
+    assert!(!output.contains("Option 5 "));
+    assert!(!output.contains("Option 11"));
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn test_view_open_no_scrollbar_when_options_fit() {
+    let mut state = DropdownState::new(vec!["Apple", "Banana", "Cherry"]);
+    Dropdown::update(&mut state, DropdownMessage::Open);
+
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 10);
+
+    terminal
+        .draw(|frame| {
+            Dropdown::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    let output = terminal.backend().to_string();
+    // Only the input's own open indicator; no scrollbar arrows
+    assert_eq!(output.matches('▲').count(), 1);
+    assert!(!output.contains('▼'));
+}
+
 #[test]
 fn test_view_open_with_filter() {
     let mut state = DropdownState::new(vec!["Apple", "Banana", "Cherry"]);
@@ -711,6 +756,64 @@ fn test_handle_event_backspace_when_open() {
     assert_eq!(msg, Some(DropdownMessage::Backspace));
 }
 
+#[test]
+fn test_handle_event_uppercase_char_when_open() {
+    let mut state = DropdownState::new(vec!["A", "B", "C"]);
+    Dropdown::update(&mut state, DropdownMessage::Open);
+
+    let msg = Dropdown::handle_event(
+        &state,
+        &Event::char('A'),
+        &EventContext::new().focused(true),
+    );
+    assert_eq!(msg, Some(DropdownMessage::Insert('A')));
+}
+
+#[test]
+fn test_handle_event_ctrl_char_ignored() {
+    let mut state = DropdownState::new(vec!["A", "B", "C"]);
+    Dropdown::update(&mut state, DropdownMessage::Open);
+
+    let msg = Dropdown::handle_event(
+        &state,
+        &Event::ctrl('a'),
+        &EventContext::new().focused(true),
+    );
+    assert_eq!(msg, None);
+}
+
+#[test]
+fn test_handle_event_arrows_open_when_closed() {
+    let state = DropdownState::new(vec!["A", "B", "C"]);
+    let ctx = EventContext::new().focused(true);
+
+    assert_eq!(
+        Dropdown::handle_event(&state, &Event::key(Key::Down), &ctx),
+        Some(DropdownMessage::Open)
+    );
+    assert_eq!(
+        Dropdown::handle_event(&state, &Event::key(Key::Up), &ctx),
+        Some(DropdownMessage::Open)
+    );
+    assert_eq!(
+        Dropdown::handle_event(&state, &Event::key(Key::Esc), &ctx),
+        None
+    );
+}
+
+#[test]
+fn test_handle_event_typing_opens_when_closed() {
+    let mut state = DropdownState::new(vec!["Apple", "Banana", "Cherry"]);
+    let ctx = EventContext::new().focused(true);
+
+    let msg = Dropdown::handle_event(&state, &Event::char('b'), &ctx);
+    assert_eq!(msg, Some(DropdownMessage::Insert('b')));
+
+    Dropdown::dispatch_event(&mut state, &Event::char('b'), &ctx);
+    assert!(state.is_open());
+    assert_eq!(state.filter_text(), "b");
+}
+
 #[test]
 fn test_handle_event_ignored_when_unfocused() {
     let state = DropdownState::new(vec!["A", "B", "C"]);
@@ -811,3 +914,99 @@ fn test_annotation_emitted() {
     assert_eq!(regions.len(), 1);
     assert_eq!(regions[0].annotation.expanded, Some(false));
 }
+
+// ========================================
+// Async Loading Tests
+// ========================================
+
+#[test]
+fn test_set_loading() {
+    let mut state = DropdownState::new(vec!["A"]);
+    assert!(!state.is_loading());
+    state.set_loading(true);
+    assert!(state.is_loading());
+    state.set_loading(false);
+    assert!(!state.is_loading());
+}
+
+#[test]
+fn test_open_while_loading_without_options() {
+    let mut state = DropdownState::new(Vec::<String>::new());
+    state.set_loading(true);
+    Dropdown::update(&mut state, DropdownMessage::Open);
+    assert!(state.is_open());
+}
+
+#[test]
+fn test_insert_while_loading_opens_and_emits_filter_changed() {
+    let mut state = DropdownState::new(Vec::<String>::new());
+    state.set_loading(true);
+    let output = Dropdown::update(&mut state, DropdownMessage::Insert('a'));
+    assert_eq!(output, Some(DropdownOutput::FilterChanged("a".to_string())));
+    assert!(state.is_open());
+}
+
+#[test]
+fn test_set_options_async() {
+    let mut state = DropdownState::new(Vec::<String>::new());
+    state.set_loading(true);
+    Dropdown::update(&mut state, DropdownMessage::Insert('x'));
+
+    let output = Dropdown::update(
+        &mut state,
+        DropdownMessage::SetOptionsAsync(vec!["Apple".to_string(), "Banana".to_string()]),
+    );
+    assert_eq!(output, None);
+    assert!(!state.is_loading());
+    assert_eq!(state.filter_text(), "x");
+    // Remote results are shown unfiltered
+    assert_eq!(state.filtered_options(), vec!["Apple", "Banana"]);
+
+    Dropdown::update(&mut state, DropdownMessage::Down);
+    let output = Dropdown::update(&mut state, DropdownMessage::Confirm);
+    assert_eq!(output, Some(DropdownOutput::Selected("Banana".to_string())));
+}
+
+#[test]
+fn test_set_options_async_resets_invalid_selection() {
+    let mut state = DropdownState::with_selection(vec!["A", "B", "C"], 2);
+    Dropdown::update(
+        &mut state,
+        DropdownMessage::SetOptionsAsync(vec!["X".to_string()]),
+    );
+    assert_eq!(state.selected_index(), None);
+}
+
+#[test]
+fn test_tick_advances_only_while_loading() {
+    let mut state = DropdownState::new(vec!["A"]);
+    Dropdown::update(&mut state, DropdownMessage::Tick);
+    assert_eq!(state.spinner_frame(), 0);
+
+    state.set_loading(true);
+    Dropdown::update(&mut state, DropdownMessage::Tick);
+    Dropdown::update(&mut state, DropdownMessage::Tick);
+    assert_eq!(state.spinner_frame(), 2);
+
+    state.set_loading(false);
+    assert_eq!(state.spinner_frame(), 0);
+}
+
+#[test]
+fn test_view_loading() {
+    let mut state = DropdownState::new(Vec::<String>::new());
+    state.set_loading(true);
+    Dropdown::update(&mut state, DropdownMessage::Insert('a'));
+
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 10);
+
+    terminal
+        .draw(|frame| {
+            Dropdown::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    let output = terminal.backend().to_string();
+    assert!(output.contains("⠋ Loading..."));
+    assert!(!output.contains("No matches"));
+}
@@ -171,7 +171,7 @@ impl Component for EventStream {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
 
         match state.focus {
             Focus::List => match key.code {
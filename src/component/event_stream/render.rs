@@ -2,7 +2,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
 
 use super::EventStreamState;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the complete event stream component.
 ///
@@ -13,7 +13,7 @@ pub(super) fn render_event_stream(
     state: &EventStreamState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -63,7 +63,7 @@ fn render_event_list(
     state: &EventStreamState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -171,7 +171,7 @@ fn render_header(
     state: &EventStreamState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     let style = if disabled {
@@ -213,7 +213,7 @@ fn render_event_row<'a>(
     state: &EventStreamState,
     event: &super::StreamEvent,
     _max_width: usize,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) -> ListItem<'a> {
     let level_color = event.level.color();
@@ -284,7 +284,7 @@ fn render_status_bar(
     state: &EventStreamState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 ) {
@@ -732,7 +732,7 @@ impl Component for FileBrowser {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
         let ctrl = key.modifiers.ctrl();
 
         match state.internal_focus {
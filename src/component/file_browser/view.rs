@@ -5,7 +5,7 @@ use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
 
 use super::types::FileBrowserFocus;
 use super::{FileBrowserState, format_size};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the file browser component into the given frame area.
 ///
@@ -16,7 +16,7 @@ pub(super) fn render(
     state: &FileBrowserState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
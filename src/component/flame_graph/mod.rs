@@ -932,7 +932,7 @@ impl Component for FlameGraph {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Up | Key::Char('k') => Some(FlameGraphMessage::SelectUp),
                 Key::Down | Key::Char('j') => Some(FlameGraphMessage::SelectDown),
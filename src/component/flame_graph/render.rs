@@ -5,7 +5,7 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 
 use super::FlameGraphState;
 use super::node::FlameNode;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the full flame graph including border, depth rows, and detail bar.
 ///
@@ -16,7 +16,7 @@ pub(super) fn render_flame_graph(
     state: &FlameGraphState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -138,7 +138,7 @@ struct FlameRootInfo<'a> {
 
 /// Style context for rendering a flame graph row.
 struct RowStyleContext<'a> {
-    theme: &'a Theme,
+    theme: &'a dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 }
@@ -257,7 +257,7 @@ fn compute_frame_style(
     node: &FlameNode,
     is_selected: bool,
     matches_search: bool,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 
     focused: bool,
     disabled: bool,
@@ -293,7 +293,7 @@ fn render_detail_bar(
     frame: &mut Frame,
     root_total: u64,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 
     disabled: bool,
 ) {
@@ -329,6 +329,7 @@ fn render_detail_bar(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::theme::Theme;
 
     #[test]
     fn test_compute_frame_style_disabled() {
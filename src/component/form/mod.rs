@@ -47,7 +47,7 @@ use super::{
     InputFieldMessage, InputFieldState, RenderContext, Select, SelectMessage, SelectState,
 };
 use crate::input::{Event, Key};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Internal representation of a field's widget state.
 #[derive(Clone, Debug, PartialEq)]
@@ -458,7 +458,7 @@ impl Component for Form {
             return None;
         }
 
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             // Global keys (regardless of field type)
             if key.code == Key::Tab && key.modifiers.shift() {
                 return Some(FormMessage::FocusPrev);
@@ -728,7 +728,7 @@ fn render_text_field(
     state: &InputFieldState,
     is_focused: bool,
     disabled: bool,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 ) {
     let border_style = if disabled {
         theme.disabled_style()
@@ -770,7 +770,7 @@ fn render_checkbox(
     state: &CheckboxState,
     is_focused: bool,
     disabled: bool,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 ) {
     let check = if state.is_checked() { "[x]" } else { "[ ]" };
     let style = if disabled {
@@ -794,7 +794,7 @@ fn render_select_field(
     state: &SelectState,
     is_focused: bool,
     disabled: bool,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 ) {
     let border_style = if disabled {
         theme.disabled_style()
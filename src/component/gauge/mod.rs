@@ -7,13 +7,15 @@
 //! produces [`GaugeOutput`] (unit type `()`).
 //!
 //! Unlike [`ProgressBar`](super::ProgressBar) which tracks task completion,
-//! `Gauge` is designed for showing current measurements against a maximum.
-//! It supports configurable threshold zones that change the bar color based
-//! on the current value (e.g., green for normal, yellow for warning, red for
+//! `Gauge` is designed for showing current measurements within a range
+//! (`0` to a maximum by default; see [`GaugeState::with_min`]). It supports
+//! configurable threshold zones that change the bar color based on the
+//! current value (e.g., green for normal, yellow for warning, red for
 //! critical).
 //!
 //! Two visual variants are supported:
-//! - [`GaugeVariant::Full`]: A block-fill gauge with centered label (ratatui `Gauge`)
+//! - [`GaugeVariant::Full`]: A block-fill gauge with centered label, filled
+//!   to an eighth of a cell (ratatui `Gauge`)
 //! - [`GaugeVariant::Line`]: A compact single-line gauge (ratatui `LineGauge`)
 //!
 //! # Example
@@ -41,7 +43,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Gauge as RatatuiGauge, LineGauge};
 
 use super::{Component, EventContext, RenderContext};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// The visual variant of the gauge.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -95,6 +97,8 @@ pub enum GaugeMessage {
     SetValue(f64),
     /// Set the maximum value.
     SetMax(f64),
+    /// Set the minimum value.
+    SetMin(f64),
     /// Set the label.
     SetLabel(Option<String>),
     /// Set the units display string.
@@ -156,6 +160,9 @@ pub struct GaugeState {
     value: f64,
     /// The maximum value.
     max: f64,
+    /// The minimum value, where the bar is empty.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    min: f64,
     /// Optional custom label (overrides the default formatted label).
     label: Option<String>,
     /// Optional units display string (e.g., "MB", "ms", "%").
@@ -173,6 +180,7 @@ impl Default for GaugeState {
         Self {
             value: 0.0,
             max: 100.0,
+            min: 0.0,
             label: None,
             units: None,
             variant: GaugeVariant::default(),
@@ -203,6 +211,26 @@ impl GaugeState {
         }
     }
 
+    /// Sets the minimum value using builder pattern.
+    ///
+    /// The bar is empty at `min` and full at `max`, so a meter can start
+    /// somewhere other than zero, such as a temperature from 20 to 90.
+    /// Threshold zones are fractions of this range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::GaugeState;
+    ///
+    /// let state = GaugeState::new(55.0, 90.0).with_min(20.0);
+    /// assert_eq!(state.min(), 20.0);
+    /// assert_eq!(state.display_percentage(), 50);
+    /// ```
+    pub fn with_min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
     /// Sets the label using builder pattern.
     ///
     /// When a custom label is set, it replaces the default formatted label.
@@ -354,6 +382,19 @@ impl GaugeState {
         self.max
     }
 
+    /// Returns the minimum value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::GaugeState;
+    ///
+    /// assert_eq!(GaugeState::new(0.0, 100.0).min(), 0.0);
+    /// ```
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
     /// Sets the current value.
     ///
     /// # Example
@@ -384,9 +425,26 @@ impl GaugeState {
         self.max = max;
     }
 
-    /// Returns the percentage as a ratio clamped to 0.0..=1.0.
+    /// Sets the minimum value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::GaugeState;
+    ///
+    /// let mut state = GaugeState::new(50.0, 100.0);
+    /// state.set_min(-100.0);
+    /// assert_eq!(state.display_percentage(), 75);
+    /// ```
+    pub fn set_min(&mut self, min: f64) {
+        self.min = min;
+    }
+
+    /// Returns how far the value is from `min` to `max`, as a ratio
+    /// clamped to 0.0..=1.0.
     ///
-    /// If `max` is zero or negative, returns 0.0.
+    /// If `max` is not above `min`, or the ratio is not finite (such as for
+    /// a NaN value), returns 0.0.
     ///
     /// # Example
     ///
@@ -400,10 +458,12 @@ impl GaugeState {
     /// assert!((state.percentage() - 1.0).abs() < f64::EPSILON);
     /// ```
     pub fn percentage(&self) -> f64 {
-        if self.max <= 0.0 {
+        let range = self.max - self.min;
+        if range <= 0.0 {
             return 0.0;
         }
-        (self.value / self.max).clamp(0.0, 1.0)
+        let ratio = ((self.value - self.min) / range).clamp(0.0, 1.0);
+        if !ratio.is_finite() { 0.0 } else { ratio }
     }
 
     /// Returns the percentage as a u16 (0-100) for ratatui's `Gauge::percent`.
@@ -549,8 +609,10 @@ impl GaugeState {
 /// This is a display-only component that does not receive keyboard focus.
 ///
 /// Unlike [`ProgressBar`](super::ProgressBar) which tracks task completion
-/// with progress from 0% to 100%, `Gauge` shows a current value relative to
-/// a maximum, with configurable threshold zones that change the bar color.
+/// with progress from 0% to 100%, `Gauge` shows a current value within a
+/// range, with configurable threshold zones that change the bar color as the
+/// value crosses them. This makes it the meter for resource monitors and
+/// dashboards.
 ///
 /// # Visual Variants
 ///
@@ -574,6 +636,7 @@ impl GaugeState {
 ///
 /// - `SetValue(f64)` - Set the current value
 /// - `SetMax(f64)` - Set the maximum value
+/// - `SetMin(f64)` - Set the minimum value
 /// - `SetLabel(Option<String>)` - Set a custom label
 /// - `SetUnits(Option<String>)` - Set the units display string
 ///
@@ -609,6 +672,9 @@ impl Component for Gauge {
             GaugeMessage::SetMax(max) => {
                 state.max = max;
             }
+            GaugeMessage::SetMin(min) => {
+                state.min = min;
+            }
             GaugeMessage::SetLabel(label) => {
                 state.label = label;
             }
@@ -652,12 +718,12 @@ fn render_full_gauge(
     state: &GaugeState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     label_text: &str,
     disabled: bool,
 ) {
     let color = if disabled {
-        theme.disabled
+        theme.base_theme().disabled
     } else {
         state.current_color()
     };
@@ -666,9 +732,10 @@ fn render_full_gauge(
 
     let gauge = RatatuiGauge::default()
         .block(block)
-        .percent(state.display_percentage())
+        .ratio(state.percentage())
+        .use_unicode(true)
         .label(label_text.to_string())
-        .gauge_style(Style::default().fg(color).bg(theme.background));
+        .gauge_style(Style::default().fg(color).bg(theme.base_theme().background));
 
     let annotation =
         crate::annotation::Annotation::new(crate::annotation::WidgetType::Custom("Gauge".into()))
@@ -684,12 +751,12 @@ fn render_line_gauge(
     state: &GaugeState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     label_text: &str,
     disabled: bool,
 ) {
     let color = if disabled {
-        theme.disabled
+        theme.base_theme().disabled
     } else {
         state.current_color()
     };
@@ -718,7 +785,7 @@ fn render_line_gauge(
 }
 
 /// Builds the block for the full gauge variant.
-fn build_block(state: &GaugeState, theme: &Theme) -> Block<'static> {
+fn build_block(state: &GaugeState, theme: &dyn ThemeProvider) -> Block<'static> {
     let mut block = Block::default()
         .borders(Borders::ALL)
         .border_style(theme.border_style());
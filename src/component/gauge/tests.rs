@@ -131,6 +131,31 @@ fn test_percentage_clamped_below() {
     assert!((state.percentage() - 0.0).abs() < f64::EPSILON);
 }
 
+#[test]
+fn test_percentage_with_min() {
+    let mut state = GaugeState::new(55.0, 90.0).with_min(20.0);
+    assert!((state.percentage() - 0.5).abs() < f64::EPSILON);
+    state.set_value(10.0);
+    assert_eq!(state.percentage(), 0.0);
+    // Thresholds are fractions of the min..max range.
+    state.set_value(85.0);
+    assert_eq!(state.current_color(), Color::Red);
+}
+
+#[test]
+fn test_percentage_empty_range() {
+    let state = GaugeState::new(50.0, 20.0).with_min(20.0);
+    assert_eq!(state.percentage(), 0.0);
+}
+
+#[test]
+fn test_percentage_not_finite() {
+    let state = GaugeState::new(f64::NAN, 100.0);
+    assert_eq!(state.percentage(), 0.0);
+    let state = GaugeState::new(f64::INFINITY, f64::INFINITY);
+    assert_eq!(state.percentage(), 0.0);
+}
+
 #[test]
 fn test_display_percentage() {
     let state = GaugeState::new(75.0, 100.0);
@@ -306,6 +331,15 @@ fn test_update_set_max() {
     assert_eq!(state.max(), 200.0);
 }
 
+#[test]
+fn test_update_set_min() {
+    let mut state = GaugeState::new(50.0, 100.0);
+    let output = Gauge::update(&mut state, GaugeMessage::SetMin(25.0));
+    assert_eq!(output, None);
+    assert_eq!(state.min(), 25.0);
+    assert_eq!(state.display_percentage(), 33);
+}
+
 #[test]
 fn test_update_set_label() {
     let mut state = GaugeState::new(50.0, 100.0);
@@ -618,6 +652,37 @@ fn test_view_full_percent() {
     insta::assert_snapshot!(terminal.backend().to_string());
 }
 
+#[test]
+fn test_render_full_fills_partial_cells() {
+    // 25% of the 38 inner columns is 9.5: nine full cells and a half.
+    let state = GaugeState::new(25.0, 100.0);
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(40, 5);
+    terminal
+        .draw(|frame| {
+            Gauge::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+    let backend = terminal.backend();
+    assert_eq!(backend.cell(9, 1).unwrap().symbol(), "█");
+    assert_eq!(backend.cell(10, 1).unwrap().symbol(), "▌");
+    assert_eq!(backend.cell(11, 1).unwrap().symbol(), " ");
+}
+
+#[test]
+fn test_render_nan_value() {
+    for variant in [GaugeVariant::Full, GaugeVariant::Line] {
+        let mut state = GaugeState::new(50.0, 100.0).with_variant(variant);
+        state.update(GaugeMessage::SetValue(f64::NAN));
+        let (mut terminal, theme) = crate::component::test_utils::setup_render(40, 5);
+        terminal
+            .draw(|frame| {
+                Gauge::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+            })
+            .unwrap();
+        assert!(terminal.backend().to_string().contains("0%"));
+    }
+}
+
 // =========================================================================
 // Annotation tests
 // =========================================================================
@@ -2,6 +2,8 @@
 
 use ratatui::style::Color;
 
+use crate::theme::ramp::{INFERNO, PLASMA, VIRIDIS};
+
 /// Color scale for the heatmap.
 ///
 /// Determines how values are mapped to colors. Each variant defines a
@@ -231,78 +233,14 @@ pub fn value_to_color(value: f64, min: f64, max: f64, scale: &HeatmapColorScale)
             let b = (bb as f64 * factor) as u8;
             Color::Rgb(r, g, b)
         }
-        HeatmapColorScale::Viridis => lookup_color(&VIRIDIS_LUT, t),
-        HeatmapColorScale::Inferno => lookup_color(&INFERNO_LUT, t),
-        HeatmapColorScale::Plasma => lookup_color(&PLASMA_LUT, t),
+        HeatmapColorScale::Viridis => lookup_color(&VIRIDIS, t),
+        HeatmapColorScale::Inferno => lookup_color(&INFERNO, t),
+        HeatmapColorScale::Plasma => lookup_color(&PLASMA, t),
         HeatmapColorScale::BlueWhiteRed => diverging_color(t, 0, 0, 255, 255, 0, 0),
         HeatmapColorScale::RedWhiteBlue => diverging_color(t, 255, 0, 0, 0, 0, 255),
     }
 }
 
-// =============================================================================
-// Perceptually uniform color scale lookup tables
-// =============================================================================
-
-/// Viridis colormap: 16 evenly spaced samples from the matplotlib Viridis scale.
-const VIRIDIS_LUT: [(u8, u8, u8); 16] = [
-    (68, 1, 84),
-    (72, 26, 108),
-    (71, 47, 126),
-    (65, 68, 135),
-    (57, 86, 140),
-    (48, 103, 141),
-    (39, 119, 142),
-    (31, 135, 141),
-    (30, 150, 138),
-    (44, 166, 130),
-    (73, 181, 117),
-    (110, 196, 98),
-    (155, 208, 72),
-    (199, 217, 46),
-    (238, 224, 29),
-    (253, 231, 37),
-];
-
-/// Inferno colormap: 16 evenly spaced samples from the matplotlib Inferno scale.
-const INFERNO_LUT: [(u8, u8, u8); 16] = [
-    (0, 0, 4),
-    (11, 7, 36),
-    (32, 12, 74),
-    (59, 15, 99),
-    (87, 16, 110),
-    (114, 17, 112),
-    (140, 25, 101),
-    (165, 44, 81),
-    (187, 65, 58),
-    (205, 92, 35),
-    (219, 122, 12),
-    (230, 155, 0),
-    (237, 189, 12),
-    (239, 222, 52),
-    (237, 249, 121),
-    (252, 255, 164),
-];
-
-/// Plasma colormap: 16 evenly spaced samples from the matplotlib Plasma scale.
-const PLASMA_LUT: [(u8, u8, u8); 16] = [
-    (13, 8, 135),
-    (49, 4, 150),
-    (80, 2, 162),
-    (108, 1, 168),
-    (134, 2, 166),
-    (156, 23, 158),
-    (177, 42, 144),
-    (195, 63, 126),
-    (210, 84, 107),
-    (222, 107, 87),
-    (231, 131, 67),
-    (238, 157, 46),
-    (242, 183, 28),
-    (243, 210, 22),
-    (238, 236, 38),
-    (240, 249, 33),
-];
-
 /// Looks up a color from a lookup table with linear interpolation between entries.
 ///
 /// `t` is a normalized value in [0.0, 1.0]. Values outside this range are clamped.
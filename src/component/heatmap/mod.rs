@@ -684,7 +684,7 @@ impl Component for Heatmap {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
 
         match key.code {
             Key::Up | Key::Char('k') => Some(HeatmapMessage::SelectUp),
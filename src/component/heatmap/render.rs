@@ -5,14 +5,14 @@ use ratatui::widgets::Paragraph;
 
 use super::HeatmapState;
 use super::color::value_to_color;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the heatmap grid inside the border.
 pub(super) fn render_heatmap(
     state: &HeatmapState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 ) {
@@ -113,7 +113,7 @@ fn render_col_labels(
     area: Rect,
     grid_x: u16,
     cell_width: u16,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     for (ci, label) in state.col_labels().iter().enumerate() {
@@ -152,7 +152,7 @@ fn render_row_label(
     frame: &mut Frame,
     pos: LabelPosition,
     row_index: usize,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     if let Some(label) = state.row_labels().get(row_index) {
@@ -185,7 +185,7 @@ fn render_row_cells(
     frame: &mut Frame,
     ri: usize,
     params: CellRenderParams,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 ) {
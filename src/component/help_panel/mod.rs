@@ -40,7 +40,7 @@ use ratatui::widgets::{Block, Borders};
 use super::{Component, EventContext, RenderContext, Toggleable};
 use crate::input::{Event, Key};
 use crate::scroll::ScrollState;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// A single keybinding entry.
 ///
@@ -578,7 +578,7 @@ impl HelpPanelState {
     }
 
     /// Builds all display lines as styled [`Line`] values.
-    fn build_lines<'a>(&'a self, theme: &Theme) -> Vec<Line<'a>> {
+    fn build_lines<'a>(&'a self, theme: &dyn ThemeProvider) -> Vec<Line<'a>> {
         let key_width = self.max_key_width();
         let mut lines: Vec<Line<'a>> = Vec::new();
 
@@ -667,7 +667,7 @@ impl Component for HelpPanel {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
         let ctrl = key.modifiers.ctrl();
 
         match key.code {
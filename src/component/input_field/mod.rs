@@ -798,7 +798,7 @@ impl Component for InputField {
             return Some(InputFieldMessage::Paste(text.clone()));
         }
 
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             let ctrl = key.modifiers.ctrl();
             let shift = key.modifiers.shift();
             match key.code {
@@ -658,3 +658,28 @@ fn test_annotation_emitted() {
     assert_eq!(regions.len(), 1);
     assert_eq!(regions[0].annotation.value, Some("Hi".to_string()));
 }
+
+#[test]
+fn test_cursor_hidden_when_unfocused() {
+    let state = InputFieldState::with_value("Hello");
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(40, 10);
+
+    terminal
+        .draw(|frame| {
+            InputField::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+    assert!(!terminal.backend().is_cursor_visible());
+    assert_eq!(terminal.backend().visible_cursor(), None);
+
+    terminal
+        .draw(|frame| {
+            InputField::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &theme).focused(true),
+            );
+        })
+        .unwrap();
+    assert!(terminal.backend().is_cursor_visible());
+    assert!(terminal.backend().visible_cursor().is_some());
+}
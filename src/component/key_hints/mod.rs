@@ -26,6 +26,7 @@
 
 use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
+use unicode_width::UnicodeWidthStr;
 
 use super::{Component, RenderContext};
 
@@ -471,6 +472,27 @@ impl KeyHintsState {
         self
     }
 
+    /// Replaces all hints with `(key, action)` pairs using builder pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::KeyHintsState;
+    ///
+    /// let state = KeyHintsState::new()
+    ///     .with_keybindings(vec![("q", "Quit"), ("j/k", "Move"), ("Enter", "Select")]);
+    /// assert_eq!(state.len(), 3);
+    /// assert_eq!(state.hints()[1].action(), "Move");
+    /// ```
+    pub fn with_keybindings<K, A>(mut self, pairs: impl IntoIterator<Item = (K, A)>) -> Self
+    where
+        K: Into<String>,
+        A: Into<String>,
+    {
+        self.set_keybindings(pairs);
+        self
+    }
+
     /// Returns all hints.
     ///
     /// # Example
@@ -561,6 +583,29 @@ impl KeyHintsState {
         self.hints = hints;
     }
 
+    /// Replaces all hints with `(key, action)` pairs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::KeyHintsState;
+    ///
+    /// let mut state = KeyHintsState::new().hint("q", "Quit");
+    /// state.set_keybindings(vec![("Esc", "Back"), ("?", "Help")]);
+    /// assert_eq!(state.len(), 2);
+    /// assert_eq!(state.hints()[0].key(), "Esc");
+    /// ```
+    pub fn set_keybindings<K, A>(&mut self, pairs: impl IntoIterator<Item = (K, A)>)
+    where
+        K: Into<String>,
+        A: Into<String>,
+    {
+        self.hints = pairs
+            .into_iter()
+            .map(|(key, action)| KeyHint::new(key, action))
+            .collect();
+    }
+
     /// Adds a hint.
     ///
     /// # Example
@@ -778,6 +823,10 @@ impl KeyHintsState {
 /// Inline:  Enter Select  Esc Cancel  q Quit
 /// ```
 ///
+/// When the hints don't fit the width, they wrap onto further rows of the
+/// render area. Hints that still don't fit are dropped whole rather than
+/// being cut off mid-hint.
+///
 /// # Example
 ///
 /// ```rust
@@ -842,8 +891,6 @@ impl Component for KeyHints {
             return;
         }
 
-        let mut spans = Vec::new();
-
         // Use ctx.theme for key style (focused/success color for keys)
         let key_style = if state.key_style == Style::default().fg(Color::Green) {
             ctx.theme.success_style()
@@ -851,23 +898,34 @@ impl Component for KeyHints {
             state.key_style
         };
 
-        for (i, hint) in visible.iter().enumerate() {
-            if i > 0 {
-                spans.push(Span::raw(&state.hint_separator));
-            }
-
-            spans.push(Span::styled(&hint.key, key_style));
-            spans.push(Span::raw(&state.key_action_separator));
-            spans.push(Span::styled(&hint.action, state.action_style));
-        }
+        let rows = pack_rows(
+            state,
+            &visible,
+            ctx.area.width as usize,
+            ctx.area.height as usize,
+        );
+        let lines: Vec<Line> = rows
+            .into_iter()
+            .map(|row| {
+                let mut spans = Vec::new();
+                for (i, hint) in row.into_iter().enumerate() {
+                    if i > 0 {
+                        spans.push(Span::raw(&state.hint_separator));
+                    }
+                    spans.push(Span::styled(&hint.key, key_style));
+                    spans.push(Span::raw(&state.key_action_separator));
+                    spans.push(Span::styled(&hint.action, state.action_style));
+                }
+                Line::from(spans)
+            })
+            .collect();
 
-        let line = Line::from(spans);
         let alignment = match state.layout {
             KeyHintsLayout::Spaced => Alignment::Center,
             KeyHintsLayout::Inline => Alignment::Left,
         };
 
-        let paragraph = Paragraph::new(line).alignment(alignment);
+        let paragraph = Paragraph::new(lines).alignment(alignment);
 
         let annotation =
             crate::annotation::Annotation::new(crate::annotation::WidgetType::KeyHints)
@@ -877,5 +935,40 @@ impl Component for KeyHints {
     }
 }
 
+/// Packs whole hints into at most `max_rows` rows of `width` columns.
+///
+/// Hints that do not fit once every row is used are dropped rather than
+/// being cut off mid-hint. A single hint wider than `width` still gets a
+/// row of its own and is clipped when rendered.
+fn pack_rows<'a>(
+    state: &KeyHintsState,
+    hints: &[&'a KeyHint],
+    width: usize,
+    max_rows: usize,
+) -> Vec<Vec<&'a KeyHint>> {
+    let separator_width = state.hint_separator.width();
+    let mut rows: Vec<Vec<&KeyHint>> = vec![Vec::new()];
+    let mut used = 0;
+
+    for &hint in hints {
+        let hint_width =
+            hint.key.width() + state.key_action_separator.width() + hint.action.width();
+        let row = rows.last_mut().expect("rows is never empty");
+        if row.is_empty() {
+            row.push(hint);
+            used = hint_width;
+        } else if used + separator_width + hint_width <= width {
+            row.push(hint);
+            used += separator_width + hint_width;
+        } else if rows.len() < max_rows {
+            rows.push(vec![hint]);
+            used = hint_width;
+        } else {
+            break;
+        }
+    }
+    rows
+}
+
 #[cfg(test)]
 mod tests;
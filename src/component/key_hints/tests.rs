@@ -353,6 +353,36 @@ fn test_view_inline_layout() {
     insta::assert_snapshot!(terminal.backend().to_string());
 }
 
+#[test]
+fn test_view_wraps_to_second_row() {
+    let state = KeyHintsState::new()
+        .with_layout(KeyHintsLayout::Inline)
+        .with_keybindings(vec![("q", "Quit"), ("j/k", "Move"), ("Enter", "Select")]);
+
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(20, 2);
+
+    terminal
+        .draw(|frame| KeyHints::view(&state, &mut RenderContext::new(frame, frame.area(), &theme)))
+        .unwrap();
+
+    insta::assert_snapshot!(terminal.backend().to_string());
+}
+
+#[test]
+fn test_view_drops_hints_that_do_not_fit() {
+    let state = KeyHintsState::new()
+        .with_layout(KeyHintsLayout::Inline)
+        .with_keybindings(vec![("q", "Quit"), ("j/k", "Move"), ("Enter", "Select")]);
+
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(20, 1);
+
+    terminal
+        .draw(|frame| KeyHints::view(&state, &mut RenderContext::new(frame, frame.area(), &theme)))
+        .unwrap();
+
+    insta::assert_snapshot!(terminal.backend().to_string());
+}
+
 // ========================================
 // Disabled Tests
 // ========================================
@@ -425,6 +455,19 @@ fn test_default_matches_init() {
     assert_eq!(default_state.action_style(), init_state.action_style());
 }
 
+#[test]
+fn test_set_keybindings_replaces_hints() {
+    let mut state = KeyHintsState::new().hint("x", "Old");
+    state.set_keybindings(vec![
+        ("q".to_string(), "Quit".to_string()),
+        ("?".into(), "Help".into()),
+    ]);
+    assert_eq!(state.len(), 2);
+    assert_eq!(state.hints()[0].key(), "q");
+    assert_eq!(state.hints()[1].action(), "Help");
+    assert!(state.hints().iter().all(|h| h.is_enabled()));
+}
+
 // Annotation tests
 
 #[test]
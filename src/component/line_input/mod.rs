@@ -752,7 +752,7 @@ impl Component for LineInput {
             return Some(LineInputMessage::Paste(text.clone()));
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
         let ctrl = key.modifiers.ctrl();
         let shift = key.modifiers.shift();
 
@@ -4,7 +4,8 @@
 
 use ratatui::prelude::*;
 
-use crate::theme::Theme;
+use crate::component::SpinnerStyle;
+use crate::theme::ThemeProvider;
 
 /// Loading state of an individual item.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -106,10 +107,8 @@ impl ItemState {
         match self {
             Self::Ready => " ",
             Self::Loading => {
-                // Braille dots animation matching SpinnerStyle::Dots
-                const LOADING_FRAMES: [&str; 10] =
-                    ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
-                LOADING_FRAMES[spinner_frame % LOADING_FRAMES.len()]
+                let frames = SpinnerStyle::Dots.static_frames().unwrap_or(&[" "]);
+                frames[spinner_frame % frames.len()]
             }
             Self::Error(_) => "✗",
         }
@@ -129,7 +128,7 @@ impl ItemState {
     /// // Styles are non-equal for ready vs loading states
     /// assert_ne!(ready_style, loading_style);
     /// ```
-    pub fn style(&self, theme: &Theme) -> Style {
+    pub fn style(&self, theme: &dyn ThemeProvider) -> Style {
         match self {
             Self::Ready => theme.normal_style(),
             Self::Loading => theme.warning_style(),
@@ -151,11 +150,17 @@ pub struct LoadingListItem<T: Clone> {
     pub(super) label: String,
     /// Current loading state.
     pub(super) state: ItemState,
+    /// Milliseconds left before a loading item times out, if it has a deadline.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub(super) timeout_remaining_ms: Option<u64>,
 }
 
 impl<T: Clone + PartialEq> PartialEq for LoadingListItem<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.data == other.data && self.label == other.label && self.state == other.state
+        self.data == other.data
+            && self.label == other.label
+            && self.state == other.state
+            && self.timeout_remaining_ms == other.timeout_remaining_ms
     }
 }
 
@@ -176,6 +181,7 @@ impl<T: Clone> LoadingListItem<T> {
             data,
             label: label.into(),
             state: ItemState::Ready,
+            timeout_remaining_ms: None,
         }
     }
 
@@ -253,6 +259,9 @@ impl<T: Clone> LoadingListItem<T> {
 
     /// Sets the state.
     ///
+    /// Clears any loading timeout set with
+    /// [`LoadingListState::set_loading_with_timeout`](super::LoadingListState::set_loading_with_timeout).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -264,6 +273,25 @@ impl<T: Clone> LoadingListItem<T> {
     /// ```
     pub fn set_state(&mut self, state: ItemState) {
         self.state = state;
+        self.timeout_remaining_ms = None;
+    }
+
+    /// Returns the milliseconds left before this loading item times out.
+    ///
+    /// Returns `None` if the item has no loading timeout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::LoadingListState;
+    ///
+    /// let mut state = LoadingListState::with_items(vec!["a".to_string()], |s| s.clone());
+    /// assert_eq!(state.items()[0].timeout_remaining_ms(), None);
+    /// state.set_loading_with_timeout(0, 5000);
+    /// assert_eq!(state.items()[0].timeout_remaining_ms(), Some(5000));
+    /// ```
+    pub fn timeout_remaining_ms(&self) -> Option<u64> {
+        self.timeout_remaining_ms
     }
 
     /// Returns true if the item is loading.
@@ -35,7 +35,7 @@
 //! // Or: LoadingList::update(&mut state, LoadingListMessage::SetError { index: 0, message: "Failed".to_string() });
 //! ```
 
-use super::{Component, EventContext, RenderContext};
+use super::{Component, EventContext, RenderContext, SpinnerStyle};
 use crate::input::{Event, Key};
 use crate::scroll::ScrollState;
 
@@ -51,6 +51,14 @@ pub enum LoadingListMessage<T: Clone> {
     SetItems(Vec<T>),
     /// Set an item's state to loading.
     SetLoading(usize),
+    /// Set an item's state to loading, failing it if still loading after
+    /// `timeout_ms` milliseconds of ticks.
+    SetLoadingWithTimeout {
+        /// Item index.
+        index: usize,
+        /// Milliseconds before the item times out.
+        timeout_ms: u64,
+    },
     /// Set an item's state to ready.
     SetReady(usize),
     /// Set an item's state to error.
@@ -72,8 +80,12 @@ pub enum LoadingListMessage<T: Clone> {
     Last,
     /// Select the current item.
     Select,
-    /// Tick animation (advances spinner frame).
-    Tick,
+    /// Advance time by the given number of milliseconds.
+    ///
+    /// Advances the spinner frame and times out loading items whose
+    /// deadline has passed, reporting them with
+    /// [`LoadingListOutput::ItemsTimedOut`].
+    Tick(u64),
 }
 
 /// Output messages from LoadingList.
@@ -90,6 +102,9 @@ pub enum LoadingListOutput<T: Clone> {
         /// New state.
         state: ItemState,
     },
+    /// Loading items whose deadline passed on this tick, in index order.
+    /// Each is now in the `Error("timed out")` state.
+    ItemsTimedOut(Vec<usize>),
 }
 
 /// State for the LoadingList component.
@@ -475,7 +490,35 @@ impl<T: Clone> LoadingListState<T> {
     /// ```
     pub fn set_loading(&mut self, index: usize) {
         if let Some(item) = self.items.get_mut(index) {
-            item.state = ItemState::Loading;
+            item.set_state(ItemState::Loading);
+        }
+    }
+
+    /// Sets the loading state for an item with a timeout.
+    ///
+    /// If the item is still loading once [`LoadingListMessage::Tick`]
+    /// messages have advanced `timeout_ms` milliseconds, it transitions to
+    /// [`ItemState::Error`] with the message `"timed out"`. Any other state
+    /// change clears the timeout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{ItemState, LoadingList, LoadingListMessage, LoadingListState, Component};
+    ///
+    /// let mut state = LoadingListState::with_items(vec!["item".to_string()], |s| s.clone());
+    /// state.set_loading_with_timeout(0, 1000);
+    ///
+    /// LoadingList::update(&mut state, LoadingListMessage::Tick(600));
+    /// assert!(state.is_loading(0));
+    ///
+    /// LoadingList::update(&mut state, LoadingListMessage::Tick(600));
+    /// assert_eq!(state.items()[0].state(), &ItemState::Error("timed out".into()));
+    /// ```
+    pub fn set_loading_with_timeout(&mut self, index: usize, timeout_ms: u64) {
+        if let Some(item) = self.items.get_mut(index) {
+            item.set_state(ItemState::Loading);
+            item.timeout_remaining_ms = Some(timeout_ms);
         }
     }
 
@@ -496,7 +539,7 @@ impl<T: Clone> LoadingListState<T> {
     /// ```
     pub fn set_ready(&mut self, index: usize) {
         if let Some(item) = self.items.get_mut(index) {
-            item.state = ItemState::Ready;
+            item.set_state(ItemState::Ready);
         }
     }
 
@@ -516,7 +559,7 @@ impl<T: Clone> LoadingListState<T> {
     /// ```
     pub fn set_error(&mut self, index: usize, message: impl Into<String>) {
         if let Some(item) = self.items.get_mut(index) {
-            item.state = ItemState::Error(message.into());
+            item.set_state(ItemState::Error(message.into()));
         }
     }
 
@@ -837,7 +880,19 @@ impl<T: Clone> Component for LoadingList<T> {
 
             LoadingListMessage::SetLoading(index) => {
                 if let Some(item) = state.items.get_mut(index) {
-                    item.state = ItemState::Loading;
+                    item.set_state(ItemState::Loading);
+                    Some(LoadingListOutput::ItemStateChanged {
+                        index,
+                        state: ItemState::Loading,
+                    })
+                } else {
+                    None
+                }
+            }
+
+            LoadingListMessage::SetLoadingWithTimeout { index, timeout_ms } => {
+                if index < state.items.len() {
+                    state.set_loading_with_timeout(index, timeout_ms);
                     Some(LoadingListOutput::ItemStateChanged {
                         index,
                         state: ItemState::Loading,
@@ -849,7 +904,7 @@ impl<T: Clone> Component for LoadingList<T> {
 
             LoadingListMessage::SetReady(index) => {
                 if let Some(item) = state.items.get_mut(index) {
-                    item.state = ItemState::Ready;
+                    item.set_state(ItemState::Ready);
                     Some(LoadingListOutput::ItemStateChanged {
                         index,
                         state: ItemState::Ready,
@@ -862,7 +917,7 @@ impl<T: Clone> Component for LoadingList<T> {
             LoadingListMessage::SetError { index, message } => {
                 if let Some(item) = state.items.get_mut(index) {
                     let new_state = ItemState::Error(message.clone());
-                    item.state = new_state.clone();
+                    item.set_state(new_state.clone());
                     Some(LoadingListOutput::ItemStateChanged {
                         index,
                         state: new_state,
@@ -875,7 +930,7 @@ impl<T: Clone> Component for LoadingList<T> {
             LoadingListMessage::ClearError(index) => {
                 if let Some(item) = state.items.get_mut(index) {
                     if item.is_error() {
-                        item.state = ItemState::Ready;
+                        item.set_state(ItemState::Ready);
                         return Some(LoadingListOutput::ItemStateChanged {
                             index,
                             state: ItemState::Ready,
@@ -947,9 +1002,22 @@ impl<T: Clone> Component for LoadingList<T> {
                 None
             }
 
-            LoadingListMessage::Tick => {
-                state.spinner_frame = (state.spinner_frame + 1) % 4;
-                None
+            LoadingListMessage::Tick(elapsed_ms) => {
+                state.spinner_frame = (state.spinner_frame + 1) % SpinnerStyle::Dots.frame_count();
+
+                let mut timed_out = Vec::new();
+                for (index, item) in state.items.iter_mut().enumerate() {
+                    let Some(remaining) = item.timeout_remaining_ms.as_mut() else {
+                        continue;
+                    };
+                    *remaining = remaining.saturating_sub(elapsed_ms);
+                    if *remaining == 0 {
+                        item.set_state(ItemState::Error("timed out".to_string()));
+                        timed_out.push(index);
+                    }
+                }
+
+                (!timed_out.is_empty()).then_some(LoadingListOutput::ItemsTimedOut(timed_out))
             }
         }
     }
@@ -962,7 +1030,7 @@ impl<T: Clone> Component for LoadingList<T> {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Up | Key::Char('k') => Some(LoadingListMessage::Up),
                 Key::Down | Key::Char('j') => Some(LoadingListMessage::Down),
@@ -3,10 +3,10 @@
 //! Extracted from the main loading_list module to keep file sizes manageable.
 
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, List, ListItem};
+use ratatui::widgets::{List, ListItem};
 
 use super::*;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the loading list into the given frame area.
 ///
@@ -17,7 +17,7 @@ pub(super) fn render_loading_list<T: Clone>(
     state: &LoadingListState<T>,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -39,10 +39,11 @@ pub(super) fn render_loading_list<T: Clone>(
     let inner = if chrome_owned {
         area
     } else {
+        let focused = focused && !disabled;
         let block = if let Some(title) = &state.title {
-            Block::default().borders(Borders::ALL).title(title.as_str())
+            theme.titled_block(Line::from(title.as_str()), focused)
         } else {
-            Block::default().borders(Borders::ALL)
+            theme.block(focused)
         };
 
         let inner = block.inner(area);
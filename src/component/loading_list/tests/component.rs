@@ -184,7 +184,7 @@ fn test_update_tick() {
     let mut state = LoadingListState::with_items(items, |i| i.name.clone());
 
     assert_eq!(state.spinner_frame(), 0);
-    LoadingList::update(&mut state, LoadingListMessage::Tick);
+    LoadingList::update(&mut state, LoadingListMessage::Tick(100));
     assert_eq!(state.spinner_frame(), 1);
 }
 
@@ -480,22 +480,24 @@ fn test_large_loading_list_navigation() {
 // ========================================
 
 #[test]
-fn test_tick_wraps_at_four() {
+fn test_tick_wraps_after_last_frame() {
     let items = make_items();
     let mut state = LoadingListState::with_items(items, |i| i.name.clone());
     assert_eq!(state.spinner_frame(), 0);
 
-    LoadingList::update(&mut state, LoadingListMessage::Tick);
+    LoadingList::update(&mut state, LoadingListMessage::Tick(100));
     assert_eq!(state.spinner_frame(), 1);
 
-    LoadingList::update(&mut state, LoadingListMessage::Tick);
+    LoadingList::update(&mut state, LoadingListMessage::Tick(100));
     assert_eq!(state.spinner_frame(), 2);
 
-    LoadingList::update(&mut state, LoadingListMessage::Tick);
-    assert_eq!(state.spinner_frame(), 3);
+    for _ in 2..9 {
+        LoadingList::update(&mut state, LoadingListMessage::Tick(100));
+    }
+    assert_eq!(state.spinner_frame(), 9);
 
-    // Should wrap back to 0
-    LoadingList::update(&mut state, LoadingListMessage::Tick);
+    // Should wrap back to 0 after the last Dots frame
+    LoadingList::update(&mut state, LoadingListMessage::Tick(100));
     assert_eq!(state.spinner_frame(), 0);
 }
 
@@ -504,15 +506,15 @@ fn test_tick_cycles_continuously() {
     let items = make_items();
     let mut state = LoadingListState::with_items(items, |i| i.name.clone());
 
-    // Tick 12 times (3 full cycles)
+    // Tick 30 times (3 full cycles)
     for cycle in 0..3 {
-        for expected_frame in 0..4 {
+        for expected_frame in 0..10 {
             assert_eq!(
                 state.spinner_frame(),
                 expected_frame,
                 "Cycle {cycle}, expected frame {expected_frame}"
             );
-            LoadingList::update(&mut state, LoadingListMessage::Tick);
+            LoadingList::update(&mut state, LoadingListMessage::Tick(100));
         }
     }
     assert_eq!(state.spinner_frame(), 0);
@@ -523,13 +525,110 @@ fn test_tick_returns_none() {
     let items = make_items();
     let mut state = LoadingListState::with_items(items, |i| i.name.clone());
 
-    // Tick should never produce output
+    // Tick never produces output without loading timeouts
     for _ in 0..10 {
-        let output = LoadingList::update(&mut state, LoadingListMessage::Tick);
+        let output = LoadingList::update(&mut state, LoadingListMessage::Tick(100));
         assert!(output.is_none());
     }
 }
 
+// ========================================
+// Loading Timeout Tests
+// ========================================
+
+#[test]
+fn test_tick_times_out_loading_item() {
+    let items = make_items();
+    let mut state = LoadingListState::with_items(items, |i| i.name.clone());
+
+    let output = LoadingList::update(
+        &mut state,
+        LoadingListMessage::SetLoadingWithTimeout {
+            index: 1,
+            timeout_ms: 250,
+        },
+    );
+    assert_eq!(
+        output,
+        Some(LoadingListOutput::ItemStateChanged {
+            index: 1,
+            state: ItemState::Loading,
+        })
+    );
+
+    assert_eq!(
+        LoadingList::update(&mut state, LoadingListMessage::Tick(100)),
+        None
+    );
+    assert_eq!(
+        LoadingList::update(&mut state, LoadingListMessage::Tick(100)),
+        None
+    );
+    assert_eq!(state.items()[1].timeout_remaining_ms(), Some(50));
+
+    let output = LoadingList::update(&mut state, LoadingListMessage::Tick(100));
+    assert_eq!(output, Some(LoadingListOutput::ItemsTimedOut(vec![1])));
+    assert_eq!(
+        state.items()[1].state(),
+        &ItemState::Error("timed out".to_string())
+    );
+    assert_eq!(state.items()[1].timeout_remaining_ms(), None);
+
+    // The timeout fires only once
+    assert_eq!(
+        LoadingList::update(&mut state, LoadingListMessage::Tick(100)),
+        None
+    );
+}
+
+#[test]
+fn test_state_change_clears_loading_timeout() {
+    let items = make_items();
+    let mut state = LoadingListState::with_items(items, |i| i.name.clone());
+    state.set_loading_with_timeout(0, 100);
+
+    LoadingList::update(&mut state, LoadingListMessage::SetReady(0));
+    assert_eq!(state.items()[0].timeout_remaining_ms(), None);
+
+    let output = LoadingList::update(&mut state, LoadingListMessage::Tick(1000));
+    assert_eq!(output, None);
+    assert!(state.is_ready(0));
+
+    state.set_loading_with_timeout(0, 100);
+    state.set_loading(0);
+    LoadingList::update(&mut state, LoadingListMessage::Tick(1000));
+    assert!(state.is_loading(0));
+}
+
+#[test]
+fn test_tick_times_out_several_items_reports_all() {
+    let items = make_items();
+    let mut state = LoadingListState::with_items(items, |i| i.name.clone());
+    state.set_loading_with_timeout(2, 100);
+    state.set_loading_with_timeout(1, 50);
+
+    let output = LoadingList::update(&mut state, LoadingListMessage::Tick(100));
+    assert_eq!(output, Some(LoadingListOutput::ItemsTimedOut(vec![1, 2])));
+    assert!(state.is_error(1));
+    assert!(state.is_error(2));
+}
+
+#[test]
+fn test_set_loading_with_timeout_out_of_bounds() {
+    let items = make_items();
+    let mut state = LoadingListState::with_items(items, |i| i.name.clone());
+
+    let output = LoadingList::update(
+        &mut state,
+        LoadingListMessage::SetLoadingWithTimeout {
+            index: 99,
+            timeout_ms: 100,
+        },
+    );
+    assert_eq!(output, None);
+    assert!(!state.has_loading());
+}
+
 // ========================================
 // Navigation Output Value Tests
 // ========================================
@@ -150,7 +150,7 @@ fn test_disabled_allows_programmatic_state_changes() {
     assert!(state.get(0).unwrap().is_ready());
 
     // Tick should still work when disabled
-    let output = LoadingList::<TestItem>::update(&mut state, LoadingListMessage::Tick);
+    let output = LoadingList::<TestItem>::update(&mut state, LoadingListMessage::Tick(100));
     assert!(output.is_none());
 }
 
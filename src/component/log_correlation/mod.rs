@@ -753,7 +753,7 @@ impl Component for LogCorrelation {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
 
         match key.code {
             Key::Up | Key::Char('k') => Some(LogCorrelationMessage::ScrollUp),
@@ -2,7 +2,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 use super::LogCorrelationState;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the entire LogCorrelation component.
 ///
@@ -13,7 +13,7 @@ pub(super) fn render(
     state: &LogCorrelationState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -60,7 +60,7 @@ fn render_streams(
     state: &LogCorrelationState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -194,7 +194,7 @@ fn render_single_stream(
     data: StreamViewData<'_>,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 ) {
     let is_active = view_state.is_active;
     let focused = view_state.focused;
@@ -320,7 +320,7 @@ fn render_status_bar(
     state: &LogCorrelationState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     let style = if disabled {
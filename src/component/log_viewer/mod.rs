@@ -197,7 +197,7 @@ impl Component for LogViewer {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
 
         match state.focus {
             Focus::Log => match key.code {
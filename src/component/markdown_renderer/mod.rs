@@ -356,7 +356,7 @@ impl Component for MarkdownRenderer {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
         let ctrl = key.modifiers.ctrl();
         let shift = key.modifiers.shift();
 
@@ -18,7 +18,7 @@ use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, T
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Parses a markdown string and produces styled [`Line`]s for rendering.
 ///
@@ -37,7 +37,7 @@ use crate::theme::Theme;
 /// let lines = render_markdown("# Hello\n\nSome text.", 40, &theme);
 /// assert!(!lines.is_empty());
 /// ```
-pub fn render_markdown(source: &str, width: u16, theme: &Theme) -> Vec<Line<'static>> {
+pub fn render_markdown(source: &str, width: u16, theme: &dyn ThemeProvider) -> Vec<Line<'static>> {
     let mut options = Options::empty();
     options.insert(Options::ENABLE_STRIKETHROUGH);
     let parser = Parser::new_ext(source, options);
@@ -86,7 +86,7 @@ struct MarkdownLineRenderer<'t> {
     /// Available width for horizontal rules.
     width: u16,
     /// Theme for styling.
-    theme: &'t Theme,
+    theme: &'t dyn ThemeProvider,
 }
 
 struct CodeBlockAccumulator {
@@ -96,7 +96,7 @@ struct CodeBlockAccumulator {
 }
 
 impl<'t> MarkdownLineRenderer<'t> {
-    fn new(width: u16, theme: &'t Theme) -> Self {
+    fn new(width: u16, theme: &'t dyn ThemeProvider) -> Self {
         Self {
             lines: Vec::new(),
             style_stack: Vec::new(),
@@ -231,7 +231,7 @@ impl<'t> MarkdownLineRenderer<'t> {
             TagEnd::CodeBlock => {
                 if let Some(cb) = self.code_block.take() {
                     let code_style = self.code_block_style();
-                    let border_style = Style::default().fg(self.theme.border);
+                    let border_style = self.theme.border_style();
                     for line_text in cb.content.trim_end_matches('\n').split('\n') {
                         let spans = vec![
                             Span::styled("│ ", border_style),
@@ -262,7 +262,7 @@ impl<'t> MarkdownLineRenderer<'t> {
             }
             TagEnd::Link => {
                 if let Some(url) = self.link_url.take() {
-                    let link_style = Style::default().fg(self.theme.info);
+                    let link_style = self.theme.info_style();
                     self.current_spans
                         .push(Span::styled(format!(" ({})", url), link_style));
                 }
@@ -283,7 +283,7 @@ impl<'t> MarkdownLineRenderer<'t> {
             // Collect text inside blockquotes as spans; they get flushed
             // when the paragraph inside the blockquote ends.
             let style = Style::default()
-                .fg(self.theme.disabled)
+                .fg(self.theme.base_theme().disabled)
                 .add_modifier(Modifier::ITALIC);
             self.current_spans
                 .push(Span::styled(text.to_string(), style));
@@ -300,7 +300,7 @@ impl<'t> MarkdownLineRenderer<'t> {
             return;
         }
         let style = Style::default()
-            .fg(self.theme.warning)
+            .fg(self.theme.base_theme().warning)
             .add_modifier(Modifier::BOLD);
         self.current_spans
             .push(Span::styled(format!("`{}`", code), style));
@@ -331,7 +331,7 @@ impl<'t> MarkdownLineRenderer<'t> {
             1
         };
         let rule_text: String = std::iter::repeat_n(rule_char, rule_width).collect();
-        let style = Style::default().fg(self.theme.border);
+        let style = self.theme.border_style();
         self.lines.push(Line::from(Span::styled(rule_text, style)));
         self.lines.push(Line::from(""));
     }
@@ -359,12 +359,12 @@ impl<'t> MarkdownLineRenderer<'t> {
 
     fn heading_style(&self) -> Style {
         Style::default()
-            .fg(self.theme.primary)
+            .fg(self.theme.base_theme().primary)
             .add_modifier(Modifier::BOLD)
     }
 
     fn code_block_style(&self) -> Style {
-        Style::default().fg(self.theme.foreground)
+        Style::default().fg(self.theme.base_theme().foreground)
     }
 
     fn normal_style(&self) -> Style {
@@ -399,7 +399,7 @@ impl<'t> MarkdownLineRenderer<'t> {
 
     fn flush_blockquote_paragraph(&mut self) {
         if !self.current_spans.is_empty() {
-            let border_style = Style::default().fg(self.theme.border);
+            let border_style = self.theme.border_style();
             let prefix_span = Span::styled("▎ ", border_style);
             let mut spans = vec![prefix_span];
             spans.append(&mut self.current_spans);
@@ -567,6 +567,7 @@ fn heading_level_to_u8(level: HeadingLevel) -> u8 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::theme::Theme;
 
     fn default_theme() -> Theme {
         Theme::default()
@@ -514,7 +514,7 @@ impl Component for Menu {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Left => Some(MenuMessage::Left),
                 Key::Right => Some(MenuMessage::Right),
@@ -37,7 +37,7 @@ use ratatui::widgets::{Block, Borders, Paragraph, Sparkline};
 
 use super::{Component, EventContext, RenderContext};
 use crate::input::{Event, Key};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Messages that can be sent to a MetricsDashboard.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -530,7 +530,7 @@ impl Component for MetricsDashboard {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
 
         match key.code {
             Key::Left | Key::Char('h') => Some(MetricsDashboardMessage::Left),
@@ -722,7 +722,7 @@ fn render_widget(widget: &MetricWidget, is_selected: bool, ctx: &mut RenderConte
 }
 
 /// Returns the appropriate style for a widget's value.
-fn value_color(widget: &MetricWidget, theme: &Theme) -> Style {
+fn value_color(widget: &MetricWidget, theme: &dyn ThemeProvider) -> Style {
     match &widget.kind {
         MetricKind::Counter { .. } => theme.info_style(),
         MetricKind::Gauge { value, max } => {
@@ -92,6 +92,8 @@ use crate::input::Event;
 #[cfg(feature = "input-components")]
 mod button;
 #[cfg(feature = "input-components")]
+mod check_list;
+#[cfg(feature = "input-components")]
 mod checkbox;
 #[cfg(feature = "input-components")]
 mod dropdown;
@@ -104,6 +106,8 @@ mod number_input;
 #[cfg(feature = "input-components")]
 mod radio_group;
 #[cfg(feature = "input-components")]
+mod segmented_control;
+#[cfg(feature = "input-components")]
 mod select;
 #[cfg(feature = "input-components")]
 mod slider;
@@ -171,12 +175,16 @@ mod tree;
 
 // Display components
 #[cfg(feature = "display-components")]
+mod banner;
+#[cfg(feature = "display-components")]
 mod big_text;
 #[cfg(feature = "display-components")]
 mod calendar;
 #[cfg(feature = "display-components")]
 mod canvas;
 #[cfg(feature = "display-components")]
+mod card_grid;
+#[cfg(feature = "display-components")]
 pub mod code_block;
 #[cfg(feature = "display-components")]
 mod collapsible;
@@ -202,11 +210,17 @@ mod scroll_view;
 mod scrollable_text;
 #[cfg(feature = "display-components")]
 mod sparkline;
-#[cfg(feature = "display-components")]
+#[cfg(any(
+    feature = "input-components",
+    feature = "data-components",
+    feature = "display-components"
+))]
 mod spinner;
 #[cfg(feature = "display-components")]
 mod status_bar;
 #[cfg(feature = "display-components")]
+mod status_grid;
+#[cfg(feature = "display-components")]
 mod status_log;
 #[cfg(feature = "display-components")]
 pub mod styled_text;
@@ -225,6 +239,8 @@ mod accordion;
 #[cfg(feature = "navigation-components")]
 mod breadcrumb;
 #[cfg(feature = "navigation-components")]
+mod carousel;
+#[cfg(feature = "navigation-components")]
 pub mod command_palette;
 #[cfg(feature = "navigation-components")]
 mod menu;
@@ -258,6 +274,8 @@ mod focus_manager;
 #[cfg(feature = "input-components")]
 pub use button::{Button, ButtonMessage, ButtonOutput, ButtonState};
 #[cfg(feature = "input-components")]
+pub use check_list::{CheckList, CheckListMessage, CheckListOutput, CheckListState};
+#[cfg(feature = "input-components")]
 pub use checkbox::{Checkbox, CheckboxMessage, CheckboxOutput, CheckboxState};
 #[cfg(feature = "input-components")]
 pub use dropdown::{Dropdown, DropdownMessage, DropdownOutput, DropdownState};
@@ -270,13 +288,17 @@ pub use number_input::{NumberInput, NumberInputMessage, NumberInputOutput, Numbe
 #[cfg(feature = "input-components")]
 pub use radio_group::{RadioGroup, RadioGroupMessage, RadioGroupOutput, RadioGroupState};
 #[cfg(feature = "input-components")]
+pub use segmented_control::{
+    SegmentedControl, SegmentedControlMessage, SegmentedControlOutput, SegmentedControlState,
+};
+#[cfg(feature = "input-components")]
 pub use select::{Select, SelectMessage, SelectOutput, SelectState};
 #[cfg(feature = "input-components")]
 pub use slider::{Slider, SliderMessage, SliderOrientation, SliderOutput, SliderState};
 #[cfg(feature = "input-components")]
 pub use switch::{Switch, SwitchMessage, SwitchOutput, SwitchState};
 #[cfg(feature = "input-components")]
-pub use text_area::{TextArea, TextAreaMessage, TextAreaOutput, TextAreaState};
+pub use text_area::{LimitKind, TextArea, TextAreaMessage, TextAreaOutput, TextAreaState};
 
 // Data components
 #[cfg(feature = "data-components")]
@@ -299,12 +321,16 @@ pub use tree::{Tree, TreeMessage, TreeNode, TreeOutput, TreeState};
 
 // Display components
 #[cfg(feature = "display-components")]
+pub use banner::{Banner, BannerLevel, BannerMessage, BannerOutput, BannerState};
+#[cfg(feature = "display-components")]
 pub use big_text::{BigText, BigTextMessage, BigTextState, big_char, big_char_width};
 #[cfg(feature = "display-components")]
 pub use calendar::{Calendar, CalendarMessage, CalendarOutput, CalendarState};
 #[cfg(feature = "display-components")]
 pub use canvas::{Canvas, CanvasMarker, CanvasMessage, CanvasShape, CanvasState};
 #[cfg(feature = "display-components")]
+pub use card_grid::{CardGrid, CardGridMessage, CardGridState, VisibleCard};
+#[cfg(feature = "display-components")]
 pub use code_block::{CodeBlock, CodeBlockMessage, CodeBlockState, Language};
 #[cfg(feature = "display-components")]
 pub use collapsible::{Collapsible, CollapsibleMessage, CollapsibleOutput, CollapsibleState};
@@ -335,7 +361,11 @@ pub use resource_gauge::{
 pub use sparkline::{
     Sparkline, SparklineDirection, SparklineMessage, SparklineOutput, SparklineState,
 };
-#[cfg(feature = "display-components")]
+#[cfg(any(
+    feature = "input-components",
+    feature = "data-components",
+    feature = "display-components"
+))]
 pub use spinner::{Spinner, SpinnerMessage, SpinnerState, SpinnerStyle};
 
 // Compound components
@@ -435,6 +465,8 @@ pub use status_bar::{
     StatusBarStyle,
 };
 #[cfg(feature = "display-components")]
+pub use status_grid::{Health, StatusGrid, StatusGridMessage, StatusGridOutput, StatusGridState};
+#[cfg(feature = "display-components")]
 pub use status_log::{
     StatusLog, StatusLogEntry, StatusLogLevel, StatusLogMessage, StatusLogOutput, StatusLogState,
 };
@@ -462,6 +494,8 @@ pub use breadcrumb::{
     Breadcrumb, BreadcrumbMessage, BreadcrumbOutput, BreadcrumbSegment, BreadcrumbState,
 };
 #[cfg(feature = "navigation-components")]
+pub use carousel::{Carousel, CarouselMessage, CarouselOutput, CarouselState};
+#[cfg(feature = "navigation-components")]
 pub use command_palette::{
     CommandPalette, CommandPaletteMessage, CommandPaletteOutput, CommandPaletteState, PaletteItem,
 };
@@ -760,7 +760,7 @@ impl Component for MultiProgress {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Up | Key::Char('k') => Some(MultiProgressMessage::ScrollUp),
                 Key::Down | Key::Char('j') => Some(MultiProgressMessage::ScrollDown),
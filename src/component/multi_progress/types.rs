@@ -1,6 +1,6 @@
 use ratatui::prelude::*;
 
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Status of a progress item.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -35,7 +35,7 @@ impl ProgressItemStatus {
     /// // Different statuses produce different styles
     /// assert_ne!(pending_style, active_style);
     /// ```
-    pub fn style(&self, theme: &Theme) -> Style {
+    pub fn style(&self, theme: &dyn ThemeProvider) -> Style {
         match self {
             Self::Pending => theme.disabled_style(),
             Self::Active => theme.info_style(),
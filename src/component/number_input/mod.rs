@@ -95,6 +95,9 @@ pub struct NumberInputState {
     editing: bool,
     /// Text buffer used during edit mode.
     edit_buffer: String,
+    /// Whether to show ◀/▶ stepper arrows around the value.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    steppers: bool,
 }
 
 impl Default for NumberInputState {
@@ -109,6 +112,7 @@ impl Default for NumberInputState {
             placeholder: None,
             editing: false,
             edit_buffer: String::new(),
+            steppers: false,
         }
     }
 }
@@ -260,6 +264,37 @@ impl NumberInputState {
         self
     }
 
+    /// Shows ◀/▶ stepper arrows around the value (builder pattern).
+    ///
+    /// An arrow is dimmed when the value is at that bound. The arrows are
+    /// hidden while editing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::NumberInputState;
+    ///
+    /// let state = NumberInputState::new(5.0).with_steppers(true);
+    /// assert!(state.has_steppers());
+    /// ```
+    pub fn with_steppers(mut self, steppers: bool) -> Self {
+        self.steppers = steppers;
+        self
+    }
+
+    /// Returns whether ◀/▶ stepper arrows are shown around the value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::NumberInputState;
+    ///
+    /// assert!(!NumberInputState::new(5.0).has_steppers());
+    /// ```
+    pub fn has_steppers(&self) -> bool {
+        self.steppers
+    }
+
     /// Returns the current numeric value.
     ///
     /// # Example
@@ -510,6 +545,13 @@ impl NumberInputState {
 /// └──────────────────┘
 /// ```
 ///
+/// With [`with_steppers`](NumberInputState::with_steppers):
+/// ```text
+/// ┌──────────────────┐
+/// │ Label:    ◀ 42 ▶ │
+/// └──────────────────┘
+/// ```
+///
 /// # Example
 ///
 /// ```rust
@@ -612,7 +654,7 @@ impl Component for NumberInput {
             return None;
         }
 
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             if state.editing {
                 // Edit mode key handling
                 match key.code {
@@ -678,14 +720,29 @@ impl Component for NumberInput {
             state.format_value()
         };
 
-        // Build the full line with optional label
-        let full_text = if let Some(label) = &state.label {
-            format!("{label}: {display_text}")
+        // Build the full line with optional label and stepper arrows
+        let mut spans = Vec::new();
+        if let Some(label) = &state.label {
+            spans.push(Span::raw(format!("{label}: ")));
+        }
+        if state.steppers && !state.editing {
+            let arrow_style = |at_bound: bool| {
+                if at_bound {
+                    ctx.theme.disabled_style()
+                } else {
+                    content_style
+                }
+            };
+            let at_min = state.min.is_some_and(|min| state.value <= min);
+            let at_max = state.max.is_some_and(|max| state.value >= max);
+            spans.push(Span::styled("\u{25c0} ", arrow_style(at_min)));
+            spans.push(Span::raw(display_text));
+            spans.push(Span::styled(" \u{25b6}", arrow_style(at_max)));
         } else {
-            display_text
-        };
+            spans.push(Span::raw(display_text));
+        }
 
-        let paragraph = Paragraph::new(full_text)
+        let paragraph = Paragraph::new(Line::from(spans))
             .style(content_style)
             .block(block)
             .alignment(Alignment::Right);
@@ -130,6 +130,71 @@ fn test_view_float_precision() {
     insta::assert_snapshot!(terminal.backend().to_string());
 }
 
+#[test]
+fn test_view_steppers() {
+    let state = NumberInputState::new(42.0)
+        .with_label("Qty")
+        .with_steppers(true);
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 3);
+
+    terminal
+        .draw(|frame| {
+            NumberInput::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    insta::assert_snapshot!(terminal.backend().to_string());
+}
+
+#[test]
+fn test_view_steppers_dim_arrow_at_bound() {
+    let state = NumberInputState::new(10.0)
+        .with_range(0.0, 10.0)
+        .with_steppers(true);
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(20, 3);
+
+    terminal
+        .draw(|frame| {
+            NumberInput::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &theme).focused(true),
+            );
+        })
+        .unwrap();
+
+    let backend = terminal.backend();
+    let dec = backend.find_text("\u{25c0}")[0];
+    let inc = backend.find_text("\u{25b6}")[0];
+    assert_eq!(
+        Color::from(backend.cell(dec.x, dec.y).unwrap().fg),
+        theme.focused_style().fg.unwrap()
+    );
+    assert_eq!(
+        Color::from(backend.cell(inc.x, inc.y).unwrap().fg),
+        theme.disabled_style().fg.unwrap()
+    );
+}
+
+#[test]
+fn test_view_steppers_hidden_while_editing() {
+    let mut state = NumberInputState::new(42.0).with_steppers(true);
+    NumberInput::update(&mut state, NumberInputMessage::StartEdit);
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 3);
+
+    terminal
+        .draw(|frame| {
+            NumberInput::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &theme).focused(true),
+            );
+        })
+        .unwrap();
+
+    let output = terminal.backend().to_string();
+    assert!(output.contains("42_"));
+    assert!(!output.contains('\u{25c0}'));
+}
+
 #[test]
 fn test_view_zero_area() {
     let state = NumberInputState::new(42.0);
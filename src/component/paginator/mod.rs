@@ -41,7 +41,7 @@ use ratatui::widgets::Paragraph;
 
 use super::{Component, EventContext, RenderContext};
 use crate::input::{Event, Key};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Display style for the paginator.
 ///
@@ -525,7 +525,7 @@ impl Component for Paginator {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
 
         match key.code {
             Key::Left | Key::Char('h') => Some(PaginatorMessage::PrevPage),
@@ -734,7 +734,7 @@ fn render_dots(state: &PaginatorState) -> String {
 }
 
 /// Renders the compact style text (for annotation/non-span display).
-fn render_compact(state: &PaginatorState, _theme: &Theme) -> String {
+fn render_compact(state: &PaginatorState, _theme: &dyn ThemeProvider) -> String {
     let left = if state.is_first_page() { " " } else { "◀" };
     let right = if state.is_last_page() { " " } else { "▶" };
     format!(
@@ -749,7 +749,7 @@ fn render_compact(state: &PaginatorState, _theme: &Theme) -> String {
 /// Renders the compact style with styled spans for arrow dimming.
 fn render_compact_spans<'a>(
     state: &PaginatorState,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     ctx: &EventContext,
 ) -> Vec<Span<'a>> {
     let text_style = if ctx.disabled {
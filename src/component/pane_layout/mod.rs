@@ -854,7 +854,7 @@ impl Component for PaneLayout {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
         let ctrl = key.modifiers.ctrl();
 
         match key.code {
@@ -344,7 +344,7 @@ impl<T: Clone + std::fmt::Display + 'static> Component for RadioGroup<T> {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Up | Key::Char('k') => Some(RadioGroupMessage::Up),
                 Key::Down | Key::Char('j') => Some(RadioGroupMessage::Down),
@@ -41,7 +41,7 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 use crate::component::Component;
 use crate::component::context::{EventContext, RenderContext};
 use crate::input::Event;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 // ---------------------------------------------------------------------------
 // Types
@@ -806,7 +806,7 @@ impl Component for ResourceGauge {
 struct BarParams<'a> {
     state: &'a ResourceGaugeState,
     disabled: bool,
-    theme: &'a Theme,
+    theme: &'a dyn ThemeProvider,
 }
 
 /// Renders the triple-value bar.
@@ -29,6 +29,8 @@
 //! assert_eq!(state.scroll_offset(), 1);
 //! ```
 
+use std::ops::Range;
+
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders};
 
@@ -55,6 +57,8 @@ pub enum ScrollViewMessage {
     Home,
     /// Scroll to the bottom.
     End,
+    /// Scroll so the given line is at the top, clamped to the last page.
+    ScrollTo(usize),
     /// Set the total content height (in lines).
     SetContentHeight(u16),
 }
@@ -370,6 +374,29 @@ impl ScrollViewState {
         content.height
     }
 
+    /// Returns the range of content lines visible in the given render area.
+    ///
+    /// The app can use this to render only the rows that will be shown. The
+    /// offset is clamped so the last page is always full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::ScrollViewState;
+    /// use ratatui::prelude::Rect;
+    ///
+    /// let mut state = ScrollViewState::new().with_content_height(100);
+    /// state.set_scroll_offset(95);
+    /// let area = Rect::new(0, 0, 40, 12);
+    /// assert_eq!(state.visible_range(area), 90..100);
+    /// ```
+    pub fn visible_range(&self, area: Rect) -> Range<usize> {
+        let viewport = self.viewport_height(area) as usize;
+        let total = self.content_height as usize;
+        let start = self.scroll.offset().min(total.saturating_sub(viewport));
+        start..(start + viewport).min(total)
+    }
+
     // ---- Instance methods ----
 
     /// Updates the state with a message, returning any output.
@@ -431,6 +458,7 @@ impl ScrollViewState {
 /// ScrollView::update(&mut state, ScrollViewMessage::Home);
 /// assert_eq!(state.scroll_offset(), 0);
 /// ```
+#[doc(alias = "Viewport")]
 pub struct ScrollView;
 
 impl Component for ScrollView {
@@ -451,7 +479,7 @@ impl Component for ScrollView {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
         let ctrl = key.modifiers.ctrl();
 
         match key.code {
@@ -514,6 +542,15 @@ impl Component for ScrollView {
                     None
                 }
             }
+            ScrollViewMessage::ScrollTo(line) => {
+                let before = state.scroll.offset();
+                state.scroll.set_offset(line);
+                if state.scroll.offset() != before {
+                    Some(())
+                } else {
+                    None
+                }
+            }
             ScrollViewMessage::SetContentHeight(height) => {
                 state.content_height = height;
                 state.scroll.set_content_length(height as usize);
@@ -707,3 +707,49 @@ fn view_chrome_owned_no_outer_border() {
         .unwrap();
     insta::assert_snapshot!(terminal.backend().to_string());
 }
+
+// =============================================================================
+// ScrollTo and visible range
+// =============================================================================
+
+#[test]
+fn test_scroll_to() {
+    let mut state = scrollable_state();
+    assert_eq!(
+        ScrollView::update(&mut state, ScrollViewMessage::ScrollTo(40)),
+        Some(())
+    );
+    assert_eq!(state.scroll_offset(), 40);
+    assert_eq!(
+        ScrollView::update(&mut state, ScrollViewMessage::ScrollTo(40)),
+        None
+    );
+}
+
+#[test]
+fn test_scroll_to_clamped() {
+    let mut state = scrollable_state();
+    state.scroll.set_viewport_height(10);
+    ScrollView::update(&mut state, ScrollViewMessage::ScrollTo(500));
+    assert_eq!(state.scroll_offset(), 90);
+}
+
+#[test]
+fn test_visible_range() {
+    let mut state = scrollable_state();
+    let area = Rect::new(0, 0, 40, 12);
+    assert_eq!(state.visible_range(area), 0..10);
+
+    state.set_scroll_offset(25);
+    assert_eq!(state.visible_range(area), 25..35);
+
+    state.set_scroll_offset(99);
+    assert_eq!(state.visible_range(area), 90..100);
+}
+
+#[test]
+fn test_visible_range_content_fits() {
+    let state = ScrollViewState::new().with_content_height(4);
+    assert_eq!(state.visible_range(Rect::new(0, 0, 40, 12)), 0..4);
+    assert_eq!(state.visible_range(Rect::new(0, 0, 1, 1)), 0..0);
+}
@@ -319,7 +319,7 @@ impl Component for ScrollableText {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
         let ctrl = key.modifiers.ctrl();
 
         match key.code {
@@ -733,7 +733,7 @@ impl<T: Clone + Display + 'static> Component for SearchableList<T> {
             return None;
         }
 
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             // Tab always toggles focus between filter and list
             if key.code == Key::Tab {
                 return Some(SearchableListMessage::ToggleFocus);
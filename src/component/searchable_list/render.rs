@@ -7,7 +7,7 @@ use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
 
 use super::{Focus, SearchableListState};
 use crate::scroll::ScrollState;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the searchable list component.
 ///
@@ -19,7 +19,7 @@ pub(super) fn render_searchable_list<T: Clone + Display>(
     state: &SearchableListState<T>,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -0,0 +1,307 @@
+//! A horizontal group of mutually exclusive segments.
+//!
+//! [`SegmentedControl`] shows a small, fixed set of options side by side,
+//! like `[ Day | Week | Month ]`, with exactly one of them active. For a
+//! handful of short options it is quicker to read and operate than a
+//! [`Dropdown`](super::Dropdown). State is stored in
+//! [`SegmentedControlState`], updated via [`SegmentedControlMessage`], and
+//! produces [`SegmentedControlOutput`].
+//!
+//! See also [`RadioGroup`](super::RadioGroup) for a vertical list of
+//! options.
+//!
+//! # Example
+//!
+//! ```rust
+//! use envision::component::{
+//!     Component, SegmentedControl, SegmentedControlMessage, SegmentedControlOutput,
+//!     SegmentedControlState,
+//! };
+//!
+//! let mut state = SegmentedControlState::new(vec!["Day", "Week", "Month"]);
+//! assert_eq!(state.selected_option(), Some("Day"));
+//!
+//! let output = SegmentedControl::update(&mut state, SegmentedControlMessage::Next);
+//! assert_eq!(output, Some(SegmentedControlOutput::Changed(1)));
+//! assert_eq!(state.selected_option(), Some("Week"));
+//! ```
+
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use super::{Component, EventContext, RenderContext};
+use crate::input::{Event, Key};
+
+/// Messages that can be sent to a SegmentedControl.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SegmentedControlMessage {
+    /// Select the segment to the right of the current one.
+    Next,
+    /// Select the segment to the left of the current one.
+    Prev,
+    /// Select a specific segment (0-indexed, clamped to the last segment).
+    Select(usize),
+}
+
+/// Output messages from a SegmentedControl.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SegmentedControlOutput {
+    /// The selected segment changed. Contains the new index (0-indexed).
+    Changed(usize),
+}
+
+/// State for a SegmentedControl component.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::component::SegmentedControlState;
+///
+/// let state = SegmentedControlState::new(vec!["Low", "Medium", "High"]).with_selected(2);
+/// assert_eq!(state.selected(), 2);
+/// assert_eq!(state.len(), 3);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct SegmentedControlState {
+    /// Segment labels, left to right.
+    options: Vec<String>,
+    /// Selected segment (0-indexed).
+    selected: usize,
+}
+
+impl SegmentedControlState {
+    /// Creates a control with the given options, selecting the first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::SegmentedControlState;
+    ///
+    /// let state = SegmentedControlState::new(vec!["On", "Off"]);
+    /// assert_eq!(state.selected(), 0);
+    /// assert_eq!(state.options(), ["On", "Off"]);
+    /// ```
+    pub fn new<S: Into<String>>(options: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            selected: 0,
+        }
+    }
+
+    /// Sets the selected segment, clamped to the last one (builder
+    /// pattern).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::SegmentedControlState;
+    ///
+    /// let state = SegmentedControlState::new(vec!["A", "B"]).with_selected(5);
+    /// assert_eq!(state.selected(), 1);
+    /// ```
+    pub fn with_selected(mut self, index: usize) -> Self {
+        self.set_selected(index);
+        self
+    }
+
+    /// Returns the segment labels.
+    pub fn options(&self) -> &[String] {
+        &self.options
+    }
+
+    /// Returns the number of segments.
+    pub fn len(&self) -> usize {
+        self.options.len()
+    }
+
+    /// Returns true if there are no segments.
+    pub fn is_empty(&self) -> bool {
+        self.options.is_empty()
+    }
+
+    /// Returns the selected segment index (0-indexed).
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Returns the label of the selected segment, or `None` if there are
+    /// no segments.
+    pub fn selected_option(&self) -> Option<&str> {
+        self.options.get(self.selected).map(String::as_str)
+    }
+
+    /// Sets the selected segment, clamped to the last one.
+    pub fn set_selected(&mut self, index: usize) {
+        self.selected = index.min(self.options.len().saturating_sub(1));
+    }
+
+    /// Replaces the options, clamping the selection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::SegmentedControlState;
+    ///
+    /// let mut state = SegmentedControlState::new(vec!["A", "B", "C"]).with_selected(2);
+    /// state.set_options(vec!["X", "Y"]);
+    /// assert_eq!(state.selected_option(), Some("Y"));
+    /// ```
+    pub fn set_options<S: Into<String>>(&mut self, options: impl IntoIterator<Item = S>) {
+        self.options = options.into_iter().map(Into::into).collect();
+        self.set_selected(self.selected);
+    }
+
+    /// Updates the state with a message, returning any output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{
+    ///     SegmentedControlMessage, SegmentedControlOutput, SegmentedControlState,
+    /// };
+    ///
+    /// let mut state = SegmentedControlState::new(vec!["A", "B", "C"]);
+    /// assert_eq!(
+    ///     state.update(SegmentedControlMessage::Select(2)),
+    ///     Some(SegmentedControlOutput::Changed(2))
+    /// );
+    /// ```
+    pub fn update(&mut self, msg: SegmentedControlMessage) -> Option<SegmentedControlOutput> {
+        SegmentedControl::update(self, msg)
+    }
+
+    /// Selects `index` and reports the change, if it is a change.
+    fn select(&mut self, index: usize) -> Option<SegmentedControlOutput> {
+        let previous = self.selected;
+        self.set_selected(index);
+        (self.selected != previous).then_some(SegmentedControlOutput::Changed(self.selected))
+    }
+}
+
+/// A horizontal group of mutually exclusive segments.
+///
+/// Renders as `[ Day │ Week │ Month ]` on the first row of its area, with
+/// the selected segment highlighted. Segments that do not fit are cut
+/// off at the right edge.
+///
+/// # Key Bindings
+///
+/// - `Left` / `h` -- Previous segment
+/// - `Right` / `l` -- Next segment
+/// - `Home` -- First segment
+/// - `End` -- Last segment
+///
+/// # Example
+///
+/// ```rust
+/// use envision::component::{Component, RenderContext, SegmentedControl, SegmentedControlState};
+/// use envision::theme::Theme;
+/// use ratatui::{Terminal, backend::TestBackend};
+///
+/// let state = SegmentedControlState::new(vec!["Day", "Week", "Month"]);
+/// let theme = Theme::default();
+/// let mut terminal = Terminal::new(TestBackend::new(30, 1)).unwrap();
+/// terminal
+///     .draw(|frame| {
+///         let area = frame.area();
+///         SegmentedControl::view(&state, &mut RenderContext::new(frame, area, &theme));
+///     })
+///     .unwrap();
+/// ```
+pub struct SegmentedControl;
+
+impl Component for SegmentedControl {
+    type State = SegmentedControlState;
+    type Message = SegmentedControlMessage;
+    type Output = SegmentedControlOutput;
+
+    fn init() -> Self::State {
+        SegmentedControlState::default()
+    }
+
+    fn handle_event(
+        state: &Self::State,
+        event: &Event,
+        ctx: &EventContext,
+    ) -> Option<Self::Message> {
+        if !ctx.focused || ctx.disabled {
+            return None;
+        }
+
+        let key = event.as_key_press()?;
+
+        match key.code {
+            Key::Left | Key::Char('h') => Some(SegmentedControlMessage::Prev),
+            Key::Right | Key::Char('l') => Some(SegmentedControlMessage::Next),
+            Key::Home => Some(SegmentedControlMessage::Select(0)),
+            Key::End => Some(SegmentedControlMessage::Select(
+                state.options.len().saturating_sub(1),
+            )),
+            _ => None,
+        }
+    }
+
+    fn update(state: &mut Self::State, msg: Self::Message) -> Option<Self::Output> {
+        match msg {
+            SegmentedControlMessage::Next => state.select(state.selected + 1),
+            SegmentedControlMessage::Prev => state.select(state.selected.saturating_sub(1)),
+            SegmentedControlMessage::Select(index) => state.select(index),
+        }
+    }
+
+    fn view(state: &Self::State, ctx: &mut RenderContext<'_, '_>) {
+        crate::annotation::with_registry(|reg| {
+            reg.register(
+                ctx.area,
+                crate::annotation::Annotation::custom("SegmentedControl", "segmented_control")
+                    .with_focus(ctx.focused)
+                    .with_disabled(ctx.disabled)
+                    .with_value(state.selected_option().unwrap_or_default()),
+            );
+        });
+
+        if state.options.is_empty() || ctx.area.height == 0 {
+            return;
+        }
+
+        let (selected_style, other_style, frame_style) = if ctx.disabled {
+            let style = ctx.theme.disabled_style();
+            (style, style, style)
+        } else {
+            (
+                ctx.theme.selected_highlight_style(ctx.focused),
+                ctx.theme.normal_style(),
+                ctx.theme.border_style(),
+            )
+        };
+
+        let mut spans = Vec::with_capacity(state.options.len() * 2 + 1);
+        spans.push(Span::styled("[", frame_style));
+        for (index, option) in state.options.iter().enumerate() {
+            if index > 0 {
+                spans.push(Span::styled("│", frame_style));
+            }
+            let style = if index == state.selected {
+                selected_style
+            } else {
+                other_style
+            };
+            spans.push(Span::styled(format!(" {option} "), style));
+        }
+        spans.push(Span::styled("]", frame_style));
+
+        let area = Rect {
+            height: 1,
+            ..ctx.area
+        };
+        ctx.frame
+            .render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
+
+#[cfg(test)]
+mod tests;
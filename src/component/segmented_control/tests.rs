@@ -0,0 +1,199 @@
+use super::*;
+use crate::component::test_utils;
+
+fn state() -> SegmentedControlState {
+    SegmentedControlState::new(vec!["Day", "Week", "Month"])
+}
+
+fn render(state: &SegmentedControlState, width: u16, focused: bool, disabled: bool) -> String {
+    let (mut terminal, theme) = test_utils::setup_render(width, 1);
+    terminal
+        .draw(|frame| {
+            SegmentedControl::view(
+                state,
+                &mut RenderContext::new(frame, frame.area(), &theme)
+                    .focused(focused)
+                    .disabled(disabled),
+            );
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+// =============================================================================
+// State
+// =============================================================================
+
+#[test]
+fn test_new_and_default() {
+    let state = state();
+    assert_eq!(state.len(), 3);
+    assert_eq!(state.selected(), 0);
+    assert_eq!(state.selected_option(), Some("Day"));
+
+    let empty = SegmentedControl::init();
+    assert!(empty.is_empty());
+    assert_eq!(empty.selected_option(), None);
+}
+
+#[test]
+fn test_set_options_clamps_selection() {
+    let mut state = state().with_selected(2);
+    state.set_options(vec!["Yes", "No"]);
+    assert_eq!(state.selected(), 1);
+
+    state.set_options(Vec::<String>::new());
+    assert_eq!(state.selected(), 0);
+    assert_eq!(state.selected_option(), None);
+}
+
+// =============================================================================
+// Navigation
+// =============================================================================
+
+#[test]
+fn test_next_and_prev_stop_at_ends() {
+    let mut state = state();
+    assert_eq!(
+        SegmentedControl::update(&mut state, SegmentedControlMessage::Prev),
+        None
+    );
+    assert_eq!(
+        SegmentedControl::update(&mut state, SegmentedControlMessage::Next),
+        Some(SegmentedControlOutput::Changed(1))
+    );
+    assert_eq!(
+        SegmentedControl::update(&mut state, SegmentedControlMessage::Next),
+        Some(SegmentedControlOutput::Changed(2))
+    );
+    assert_eq!(
+        SegmentedControl::update(&mut state, SegmentedControlMessage::Next),
+        None
+    );
+    assert_eq!(
+        SegmentedControl::update(&mut state, SegmentedControlMessage::Prev),
+        Some(SegmentedControlOutput::Changed(1))
+    );
+}
+
+#[test]
+fn test_select_clamps_and_ignores_current() {
+    let mut state = state();
+    assert_eq!(
+        state.update(SegmentedControlMessage::Select(9)),
+        Some(SegmentedControlOutput::Changed(2))
+    );
+    assert_eq!(state.update(SegmentedControlMessage::Select(2)), None);
+}
+
+#[test]
+fn test_navigation_with_no_options() {
+    let mut state = SegmentedControlState::default();
+    assert_eq!(state.update(SegmentedControlMessage::Next), None);
+    assert_eq!(state.update(SegmentedControlMessage::Select(3)), None);
+}
+
+// =============================================================================
+// Events
+// =============================================================================
+
+#[test]
+fn test_handle_event_keys() {
+    let state = state();
+    let ctx = EventContext::new().focused(true);
+    let cases = [
+        (Event::key(Key::Left), SegmentedControlMessage::Prev),
+        (Event::char('h'), SegmentedControlMessage::Prev),
+        (Event::key(Key::Right), SegmentedControlMessage::Next),
+        (Event::char('l'), SegmentedControlMessage::Next),
+        (Event::key(Key::Home), SegmentedControlMessage::Select(0)),
+        (Event::key(Key::End), SegmentedControlMessage::Select(2)),
+    ];
+    for (event, expected) in cases {
+        assert_eq!(
+            SegmentedControl::handle_event(&state, &event, &ctx),
+            Some(expected)
+        );
+    }
+    assert_eq!(
+        SegmentedControl::handle_event(&state, &Event::key(Key::Down), &ctx),
+        None
+    );
+}
+
+#[test]
+fn test_handle_event_requires_focus_and_enabled() {
+    let state = state();
+    let event = Event::key(Key::Right);
+    assert_eq!(
+        SegmentedControl::handle_event(&state, &event, &EventContext::default()),
+        None
+    );
+    assert_eq!(
+        SegmentedControl::handle_event(
+            &state,
+            &event,
+            &EventContext::new().focused(true).disabled(true)
+        ),
+        None
+    );
+}
+
+// =============================================================================
+// Rendering
+// =============================================================================
+
+#[test]
+fn test_view() {
+    insta::assert_snapshot!(render(&state().with_selected(1), 24, true, false));
+}
+
+#[test]
+fn test_view_highlights_selected_segment() {
+    let (mut terminal, theme) = test_utils::setup_render(24, 1);
+    let state = state().with_selected(1);
+    terminal
+        .draw(|frame| {
+            SegmentedControl::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &theme).focused(true),
+            );
+        })
+        .unwrap();
+    let backend = terminal.backend();
+    let selected = theme.selected_highlight_style(true);
+    // "[ Day │ Week │ Month ]": " Week " spans columns 7..13.
+    for x in 7..13 {
+        assert_eq!(backend.cell(x, 0).unwrap().style(), selected, "column {x}");
+    }
+    assert_ne!(backend.cell(2, 0).unwrap().style(), selected);
+}
+
+#[test]
+fn test_view_truncates_and_handles_empty() {
+    assert_eq!(render(&state(), 10, false, false), "[ Day │ We");
+    assert_eq!(
+        render(&SegmentedControlState::default(), 10, false, true).trim(),
+        ""
+    );
+}
+
+#[test]
+fn test_view_annotation() {
+    use crate::annotation::{WidgetType, with_annotations};
+    let state = state().with_selected(2);
+    let (mut terminal, theme) = test_utils::setup_render(24, 1);
+    let registry = with_annotations(|| {
+        terminal
+            .draw(|frame| {
+                SegmentedControl::view(
+                    &state,
+                    &mut RenderContext::new(frame, frame.area(), &theme),
+                );
+            })
+            .unwrap();
+    });
+    let regions = registry.find_by_type(&WidgetType::Custom("SegmentedControl".into()));
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].annotation.value.as_deref(), Some("Month"));
+}
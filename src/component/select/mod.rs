@@ -496,7 +496,7 @@ impl Component for Select {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             if state.is_open {
                 match key.code {
                     Key::Enter => Some(SelectMessage::Confirm),
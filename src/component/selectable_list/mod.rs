@@ -578,7 +578,7 @@ impl<T: Clone + std::fmt::Display + 'static> Component for SelectableList<T> {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Up | Key::Char('k') => Some(SelectableListMessage::Up),
                 Key::Down | Key::Char('j') => Some(SelectableListMessage::Down),
@@ -1,5 +1,5 @@
 use super::*;
-use crate::input::{Event, Key};
+use crate::input::{Event, Key, KeyEvent, KeyEventKind};
 
 #[test]
 fn test_init_empty() {
@@ -355,6 +355,28 @@ fn test_handle_event_down() {
     assert_eq!(msg, Some(SelectableListMessage::Down));
 }
 
+#[test]
+fn test_handle_event_ignores_key_release() {
+    let state = SelectableListState::new(vec!["one".to_string(), "two".to_string()]);
+    let ctx = EventContext::new().focused(true);
+    let release = Event::Key(KeyEvent {
+        kind: KeyEventKind::Release,
+        ..KeyEvent::new(Key::Down)
+    });
+    let repeat = Event::Key(KeyEvent {
+        kind: KeyEventKind::Repeat,
+        ..KeyEvent::new(Key::Down)
+    });
+    assert_eq!(
+        SelectableList::<String>::handle_event(&state, &release, &ctx),
+        None
+    );
+    assert_eq!(
+        SelectableList::<String>::handle_event(&state, &repeat, &ctx),
+        Some(SelectableListMessage::Down)
+    );
+}
+
 #[test]
 fn test_handle_event_home() {
     let state = SelectableListState::new(vec!["one".to_string(), "two".to_string()]);
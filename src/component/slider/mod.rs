@@ -35,7 +35,7 @@ use ratatui::widgets::Paragraph;
 
 use super::{Component, EventContext, RenderContext};
 use crate::input::{Event, Key};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Orientation of the slider.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -507,7 +507,7 @@ impl Component for Slider {
             return None;
         }
 
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match state.orientation {
                 SliderOrientation::Horizontal => match key.code {
                     Key::Right | Key::Char('l') => Some(SliderMessage::Increment),
@@ -653,7 +653,7 @@ fn view_vertical(state: &SliderState, ctx: &mut RenderContext<'_, '_>) {
 }
 
 /// Computes the styles for label, filled, and empty portions.
-fn compute_styles(theme: &Theme, ctx: &EventContext) -> (Style, Style, Style) {
+fn compute_styles(theme: &dyn ThemeProvider, ctx: &EventContext) -> (Style, Style, Style) {
     if ctx.disabled {
         let disabled = theme.disabled_style();
         (disabled, disabled, disabled)
@@ -819,7 +819,7 @@ impl Component for SpanTree {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             let has_shift = key.modifiers.shift();
             match key.code {
                 Key::Up | Key::Char('k') if !has_shift => Some(SpanTreeMessage::SelectUp),
@@ -5,7 +5,7 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 
 use super::{FlatSpan, SpanTreeState};
 use crate::scroll::ScrollState;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the full span tree including border, time axis header, and rows.
 ///
@@ -16,7 +16,7 @@ pub(super) fn render_span_tree(
     state: &SpanTreeState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -144,7 +144,7 @@ fn render_header(
     inner: Rect,
     label_width: u16,
     bar_width: u16,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     let header_area = Rect::new(inner.x, inner.y, inner.width, 1);
@@ -286,7 +286,7 @@ struct RowContext<'a> {
     state: &'a SpanTreeState,
     label_width: u16,
     bar_width: u16,
-    theme: &'a Theme,
+    theme: &'a dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 }
@@ -5,6 +5,10 @@
 //! receive keyboard focus. State is stored in [`SpinnerState`] and updated via
 //! [`SpinnerMessage`].
 //!
+//! [`SpinnerStyle`] is the single definition of spinner frames: the loading
+//! indicators in `LoadingList`, `Dropdown` and the spinner overlay animate
+//! with [`SpinnerStyle::Dots`].
+//!
 //! See also [`ProgressBar`](super::ProgressBar) for determinate progress,
 //! and [`MultiProgress`](super::MultiProgress) for tracking multiple tasks.
 //!
@@ -80,6 +84,10 @@ pub enum SpinnerStyle {
     ///
     /// 4 frames, rotating circle segments.
     Circle,
+    /// Sweeping arc animation (◜◠◝◞◡◟).
+    ///
+    /// 6 frames, an arc travelling around a circle.
+    Arc,
     /// Bouncing dot animation (⠁⠂⠄⠂).
     ///
     /// 4 frames, vertical bouncing effect.
@@ -108,6 +116,7 @@ impl SpinnerStyle {
         const DOTS: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
         const LINE: &[char] = &['|', '/', '-', '\\'];
         const CIRCLE: &[char] = &['◐', '◓', '◑', '◒'];
+        const ARC: &[char] = &['◜', '◠', '◝', '◞', '◡', '◟'];
         const BOUNCE: &[char] = &['⠁', '⠂', '⠄', '⠂'];
         const EMPTY: &[char] = &[' '];
 
@@ -115,6 +124,7 @@ impl SpinnerStyle {
             SpinnerStyle::Dots => DOTS,
             SpinnerStyle::Line => LINE,
             SpinnerStyle::Circle => CIRCLE,
+            SpinnerStyle::Arc => ARC,
             SpinnerStyle::Bounce => BOUNCE,
             SpinnerStyle::Custom(frames) => {
                 if frames.is_empty() {
@@ -139,6 +149,52 @@ impl SpinnerStyle {
     pub fn frame_count(&self) -> usize {
         self.frames().len()
     }
+
+    /// Returns the frame at `index`, wrapping around past the last frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::SpinnerStyle;
+    ///
+    /// assert_eq!(SpinnerStyle::Line.frame(1), '/');
+    /// assert_eq!(SpinnerStyle::Line.frame(5), '/');
+    /// ```
+    pub fn frame(&self, index: usize) -> char {
+        let frames = self.frames();
+        frames[index % frames.len()]
+    }
+
+    /// Returns the frames of a built-in style as static strings, or `None`
+    /// for `Custom` styles.
+    ///
+    /// Useful where a frame must be borrowed for `'static`, such as a
+    /// symbol returned from a method on a component's item type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::SpinnerStyle;
+    ///
+    /// assert_eq!(SpinnerStyle::Line.static_frames(), Some(&["|", "/", "-", "\\"][..]));
+    /// assert_eq!(SpinnerStyle::Custom(vec!['x']).static_frames(), None);
+    /// ```
+    pub fn static_frames(&self) -> Option<&'static [&'static str]> {
+        const DOTS: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+        const LINE: &[&str] = &["|", "/", "-", "\\"];
+        const CIRCLE: &[&str] = &["◐", "◓", "◑", "◒"];
+        const ARC: &[&str] = &["◜", "◠", "◝", "◞", "◡", "◟"];
+        const BOUNCE: &[&str] = &["⠁", "⠂", "⠄", "⠂"];
+
+        match self {
+            SpinnerStyle::Dots => Some(DOTS),
+            SpinnerStyle::Line => Some(LINE),
+            SpinnerStyle::Circle => Some(CIRCLE),
+            SpinnerStyle::Arc => Some(ARC),
+            SpinnerStyle::Bounce => Some(BOUNCE),
+            SpinnerStyle::Custom(_) => None,
+        }
+    }
 }
 
 /// Messages that can be sent to a Spinner.
@@ -251,8 +307,7 @@ impl SpinnerState {
     /// assert_ne!(first, second);
     /// ```
     pub fn current_frame(&self) -> char {
-        let frames = self.style.frames();
-        frames[self.frame % frames.len()]
+        self.style.frame(self.frame)
     }
 
     /// Returns true if the spinner is currently spinning.
@@ -113,6 +113,47 @@ fn test_style_frames_circle() {
     assert_eq!(style.frames(), &['◐', '◓', '◑', '◒']);
 }
 
+#[test]
+fn test_style_frames_arc() {
+    let style = SpinnerStyle::Arc;
+    assert_eq!(style.frame_count(), 6);
+    assert_eq!(style.frames(), &['◜', '◠', '◝', '◞', '◡', '◟']);
+}
+
+#[test]
+fn test_style_frame_wraps() {
+    assert_eq!(SpinnerStyle::Dots.frame(0), '⠋');
+    assert_eq!(SpinnerStyle::Dots.frame(10), '⠋');
+    assert_eq!(SpinnerStyle::Arc.frame(7), '◠');
+    assert_eq!(SpinnerStyle::Custom(vec![]).frame(3), ' ');
+}
+
+#[test]
+fn test_static_frames_match_frames() {
+    for style in [
+        SpinnerStyle::Dots,
+        SpinnerStyle::Line,
+        SpinnerStyle::Circle,
+        SpinnerStyle::Arc,
+        SpinnerStyle::Bounce,
+    ] {
+        let chars: Vec<String> = style.frames().iter().map(char::to_string).collect();
+        assert_eq!(style.static_frames().unwrap(), chars.as_slice());
+    }
+    assert_eq!(SpinnerStyle::Custom(vec!['a']).static_frames(), None);
+}
+
+#[test]
+fn test_arc_spinner_advances() {
+    let mut state = SpinnerState::with_style(SpinnerStyle::Arc);
+    for _ in 0..6 {
+        Spinner::update(&mut state, SpinnerMessage::Tick);
+    }
+    assert_eq!(state.current_frame(), '◜');
+    Spinner::update(&mut state, SpinnerMessage::Tick);
+    assert_eq!(state.current_frame(), '◠');
+}
+
 #[test]
 fn test_style_frames_bounce() {
     let style = SpinnerStyle::Bounce;
@@ -2,7 +2,9 @@
 //!
 //! [`SplitPanel`] divides an area into two panes (horizontal or vertical)
 //! with a draggable split ratio. The parent controls what to render in
-//! each pane — this component only manages the layout and focus. State is
+//! each pane — this component only manages the layout and focus. The
+//! divider can be dragged with the mouse once the panel's screen area is
+//! recorded with [`SplitPanelState::set_area`]. State is
 //! stored in [`SplitPanelState`], updated via [`SplitPanelMessage`], and
 //! produces [`SplitPanelOutput`].
 //!
@@ -35,7 +37,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders};
 
 use super::{Component, EventContext, RenderContext};
-use crate::input::{Event, Key};
+use crate::input::{Event, Key, MouseButton, MouseEventKind};
 
 /// The orientation of a split panel.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -67,6 +69,17 @@ pub enum SplitPanelMessage {
     SetRatio(f32),
     /// Reset the split to 50/50.
     ResetRatio,
+    /// Start dragging the divider.
+    DragStart,
+    /// Move the divider to the given screen position while dragging.
+    DragTo {
+        /// The pointer column.
+        column: u16,
+        /// The pointer row.
+        row: u16,
+    },
+    /// Stop dragging the divider.
+    DragEnd,
 }
 
 /// Output messages from a SplitPanel.
@@ -113,6 +126,12 @@ pub struct SplitPanelState {
     min_ratio: f32,
     /// Maximum ratio (prevents collapsing second pane).
     max_ratio: f32,
+    /// Screen area of the panel, used to hit-test the divider.
+    #[cfg_attr(feature = "serialization", serde(skip))]
+    area: Rect,
+    /// Whether the divider is being dragged with the mouse.
+    #[cfg_attr(feature = "serialization", serde(skip))]
+    dragging: bool,
 }
 
 impl PartialEq for SplitPanelState {
@@ -135,6 +154,8 @@ impl Default for SplitPanelState {
             resize_step: 0.1,
             min_ratio: 0.1,
             max_ratio: 0.9,
+            area: Rect::default(),
+            dragging: false,
         }
     }
 }
@@ -313,6 +334,88 @@ impl SplitPanelState {
         self
     }
 
+    /// Records the screen area the panel is rendered into.
+    ///
+    /// Mouse events are hit-tested against this area, so set it to the
+    /// same area passed to [`SplitPanel::view`] before handling events.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{SplitPanelState, SplitOrientation};
+    /// use ratatui::layout::Rect;
+    ///
+    /// let mut state = SplitPanelState::new(SplitOrientation::Vertical);
+    /// state.set_area(Rect::new(0, 0, 80, 24));
+    /// assert_eq!(state.area(), Rect::new(0, 0, 80, 24));
+    /// ```
+    pub fn set_area(&mut self, area: Rect) {
+        self.area = area;
+    }
+
+    /// Returns the area set with [`set_area`](Self::set_area).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{SplitPanelState, SplitOrientation};
+    /// use ratatui::layout::Rect;
+    ///
+    /// let state = SplitPanelState::new(SplitOrientation::Vertical);
+    /// assert_eq!(state.area(), Rect::default());
+    /// ```
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Returns true while the divider is being dragged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{SplitPanelState, SplitPanelMessage, SplitOrientation};
+    ///
+    /// let mut state = SplitPanelState::new(SplitOrientation::Vertical);
+    /// state.update(SplitPanelMessage::DragStart);
+    /// assert!(state.is_dragging());
+    /// state.update(SplitPanelMessage::DragEnd);
+    /// assert!(!state.is_dragging());
+    /// ```
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Returns true if the given screen position lies on the divider.
+    ///
+    /// The divider is the pair of adjacent border lines where the two
+    /// panes meet within the area set by [`set_area`](Self::set_area).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{SplitPanelState, SplitOrientation};
+    /// use ratatui::layout::Rect;
+    ///
+    /// let mut state = SplitPanelState::new(SplitOrientation::Vertical);
+    /// state.set_area(Rect::new(0, 0, 80, 24));
+    /// assert!(state.is_on_divider(39, 5));
+    /// assert!(state.is_on_divider(40, 5));
+    /// assert!(!state.is_on_divider(10, 5));
+    /// ```
+    pub fn is_on_divider(&self, column: u16, row: u16) -> bool {
+        let area = self.area;
+        if row < area.y || row >= area.bottom() || column < area.x || column >= area.right() {
+            return false;
+        }
+
+        let (first, second) = self.layout(area);
+        let (position, first_end, second_start) = match self.orientation {
+            SplitOrientation::Vertical => (column, first.right(), second.x),
+            SplitOrientation::Horizontal => (row, first.bottom(), second.y),
+        };
+        position + 1 == first_end || position == second_start
+    }
+
     /// Updates the state with a message, returning any output.
     ///
     /// # Example
@@ -378,6 +481,8 @@ impl SplitPanelState {
 /// - `Ctrl+Left/Up` — Grow first pane (shrink second)
 /// - `Ctrl+Right/Down` — Shrink first pane (grow second)
 /// - `Ctrl+0` — Reset to 50/50 split
+/// - Mouse drag on the divider — Resize (requires
+///   [`SplitPanelState::set_area`]; works without focus)
 ///
 /// # Rendering
 ///
@@ -413,15 +518,39 @@ impl Component for SplitPanel {
     }
 
     fn handle_event(
-        _state: &Self::State,
+        state: &Self::State,
         event: &Event,
         ctx: &EventContext,
     ) -> Option<Self::Message> {
-        if !ctx.focused || ctx.disabled {
+        if ctx.disabled {
             return None;
         }
 
-        if let Some(key) = event.as_key() {
+        if let Some(mouse) = event.as_mouse() {
+            return match mouse.kind {
+                MouseEventKind::Down(MouseButton::Left)
+                    if state.is_on_divider(mouse.column, mouse.row) =>
+                {
+                    Some(SplitPanelMessage::DragStart)
+                }
+                MouseEventKind::Drag(MouseButton::Left) if state.dragging => {
+                    Some(SplitPanelMessage::DragTo {
+                        column: mouse.column,
+                        row: mouse.row,
+                    })
+                }
+                MouseEventKind::Up(MouseButton::Left) if state.dragging => {
+                    Some(SplitPanelMessage::DragEnd)
+                }
+                _ => None,
+            };
+        }
+
+        if !ctx.focused {
+            return None;
+        }
+
+        if let Some(key) = event.as_key_press() {
             // Tab toggles pane focus
             if key.code == Key::Tab {
                 return Some(SplitPanelMessage::FocusOther);
@@ -507,6 +636,35 @@ impl Component for SplitPanel {
                     None
                 }
             }
+            SplitPanelMessage::DragStart => {
+                state.dragging = true;
+                None
+            }
+            SplitPanelMessage::DragTo { column, row } => {
+                let area = state.area;
+                let (position, start, total) = match state.orientation {
+                    SplitOrientation::Vertical => (column, area.x, area.width),
+                    SplitOrientation::Horizontal => (row, area.y, area.height),
+                };
+                if !state.dragging || total == 0 {
+                    return None;
+                }
+
+                // The second pane starts at the pointer
+                let first_size = position.saturating_sub(start).min(total);
+                let ratio =
+                    (first_size as f32 / total as f32).clamp(state.min_ratio, state.max_ratio);
+                if (ratio - state.ratio).abs() > f32::EPSILON {
+                    state.ratio = ratio;
+                    Some(SplitPanelOutput::RatioChanged(ratio))
+                } else {
+                    None
+                }
+            }
+            SplitPanelMessage::DragEnd => {
+                state.dragging = false;
+                None
+            }
         }
     }
 
@@ -1,6 +1,6 @@
 use super::*;
 use crate::component::test_utils;
-use crate::input::Modifiers;
+use crate::input::{Modifiers, MouseButton};
 
 fn vertical_state() -> SplitPanelState {
     SplitPanelState::new(SplitOrientation::Vertical)
@@ -356,6 +356,131 @@ fn test_arrow_without_ctrl_ignored() {
     assert_eq!(msg, None);
 }
 
+// =============================================================================
+// Mouse drag
+// =============================================================================
+
+fn placed_state(orientation: SplitOrientation) -> SplitPanelState {
+    let mut state = SplitPanelState::new(orientation);
+    state.set_area(Rect::new(0, 0, 80, 20));
+    state
+}
+
+#[test]
+fn test_is_on_divider_vertical() {
+    let state = placed_state(SplitOrientation::Vertical);
+    assert!(state.is_on_divider(39, 0));
+    assert!(state.is_on_divider(40, 19));
+    assert!(!state.is_on_divider(38, 5));
+    assert!(!state.is_on_divider(41, 5));
+    assert!(!state.is_on_divider(40, 20));
+}
+
+#[test]
+fn test_is_on_divider_horizontal() {
+    let state = placed_state(SplitOrientation::Horizontal);
+    assert!(state.is_on_divider(5, 9));
+    assert!(state.is_on_divider(5, 10));
+    assert!(!state.is_on_divider(5, 11));
+}
+
+#[test]
+fn test_is_on_divider_without_area() {
+    let state = vertical_state();
+    assert!(!state.is_on_divider(0, 0));
+}
+
+#[test]
+fn test_click_on_divider_starts_drag() {
+    let state = placed_state(SplitOrientation::Vertical);
+    let msg = SplitPanel::handle_event(&state, &Event::click(40, 5), &EventContext::default());
+    assert_eq!(msg, Some(SplitPanelMessage::DragStart));
+}
+
+#[test]
+fn test_click_off_divider_ignored() {
+    let state = placed_state(SplitOrientation::Vertical);
+    let msg = SplitPanel::handle_event(&state, &Event::click(10, 5), &EventContext::default());
+    assert_eq!(msg, None);
+}
+
+#[test]
+fn test_drag_ignored_when_not_dragging() {
+    let state = placed_state(SplitOrientation::Vertical);
+    let msg = SplitPanel::handle_event(
+        &state,
+        &Event::mouse_drag(60, 5, MouseButton::Left),
+        &EventContext::default(),
+    );
+    assert_eq!(msg, None);
+}
+
+#[test]
+fn test_mouse_ignored_when_disabled() {
+    let state = placed_state(SplitOrientation::Vertical);
+    let msg = SplitPanel::handle_event(
+        &state,
+        &Event::click(40, 5),
+        &EventContext::new().disabled(true),
+    );
+    assert_eq!(msg, None);
+}
+
+#[test]
+fn test_drag_divider_vertical() {
+    let mut state = placed_state(SplitOrientation::Vertical);
+    let ctx = EventContext::default();
+
+    SplitPanel::dispatch_event(&mut state, &Event::click(40, 5), &ctx);
+    assert!(state.is_dragging());
+
+    let output = SplitPanel::dispatch_event(
+        &mut state,
+        &Event::mouse_drag(60, 5, MouseButton::Left),
+        &ctx,
+    );
+    assert_eq!(output, Some(SplitPanelOutput::RatioChanged(0.75)));
+    let (first, second) = state.layout(state.area());
+    assert_eq!(first.width, 60);
+    assert_eq!(second.x, 60);
+
+    SplitPanel::dispatch_event(&mut state, &Event::mouse_up(60, 5), &ctx);
+    assert!(!state.is_dragging());
+
+    let output = SplitPanel::dispatch_event(
+        &mut state,
+        &Event::mouse_drag(20, 5, MouseButton::Left),
+        &ctx,
+    );
+    assert_eq!(output, None);
+}
+
+#[test]
+fn test_drag_divider_horizontal() {
+    let mut state = placed_state(SplitOrientation::Horizontal);
+    state.update(SplitPanelMessage::DragStart);
+    let output = state.update(SplitPanelMessage::DragTo { column: 0, row: 5 });
+    assert_eq!(output, Some(SplitPanelOutput::RatioChanged(0.25)));
+}
+
+#[test]
+fn test_drag_divider_clamped_to_bounds() {
+    let mut state = placed_state(SplitOrientation::Vertical).with_bounds(0.2, 0.8);
+    state.update(SplitPanelMessage::DragStart);
+    state.update(SplitPanelMessage::DragTo { column: 0, row: 0 });
+    assert!((state.ratio() - 0.2).abs() < f32::EPSILON);
+    state.update(SplitPanelMessage::DragTo { column: 79, row: 0 });
+    assert!((state.ratio() - 0.8).abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_drag_to_without_drag_start_ignored() {
+    let mut state = placed_state(SplitOrientation::Vertical);
+    let output = state.update(SplitPanelMessage::DragTo { column: 60, row: 0 });
+    assert_eq!(output, None);
+    assert!((state.ratio() - 0.5).abs() < f32::EPSILON);
+}
+
 // =============================================================================
 // Layout
 // =============================================================================
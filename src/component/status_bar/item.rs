@@ -3,8 +3,9 @@
 //! This module contains the types for individual status bar items,
 //! their content variants, and visual styles.
 
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 use ratatui::prelude::*;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Content type for status bar items.
 ///
@@ -47,8 +48,22 @@ pub enum StatusBarItemContent {
         /// Current animation frame.
         frame: usize,
     },
+    /// Horizontally scrolling text.
+    ///
+    /// When the text is wider than the space the status bar gives it, a
+    /// window of the text is shown starting at `offset`, wrapping around
+    /// with a gap. The window advances one column on every tick.
+    Marquee {
+        /// The full text.
+        text: String,
+        /// Current scroll offset in characters.
+        offset: usize,
+    },
 }
 
+/// Gap shown between the end of marquee text and its wrapped-around start.
+const MARQUEE_GAP: &str = "   ";
+
 impl StatusBarItemContent {
     /// Creates static text content.
     ///
@@ -116,6 +131,47 @@ impl StatusBarItemContent {
         }
     }
 
+    /// Creates scrolling marquee content.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::StatusBarItemContent;
+    ///
+    /// let content = StatusBarItemContent::marquee("a/very/long/path/to/file.rs");
+    /// assert!(matches!(content, StatusBarItemContent::Marquee { offset: 0, .. }));
+    /// ```
+    pub fn marquee(text: impl Into<String>) -> Self {
+        Self::Marquee {
+            text: text.into(),
+            offset: 0,
+        }
+    }
+
+    /// Returns the display text for this content, limited to `width`
+    /// display columns for marquee content.
+    ///
+    /// Marquee text that fits within `width` is returned unchanged.
+    /// Other content is never limited.
+    pub(super) fn display_text_within(&self, width: usize) -> String {
+        match self {
+            Self::Marquee { text, offset } if text.width() > width => {
+                let cycle: Vec<char> = text.chars().chain(MARQUEE_GAP.chars()).collect();
+                let mut used = 0;
+                cycle
+                    .iter()
+                    .cycle()
+                    .skip(offset % cycle.len())
+                    .take_while(|ch| {
+                        used += ch.width().unwrap_or(0);
+                        used <= width
+                    })
+                    .collect()
+            }
+            _ => self.display_text(),
+        }
+    }
+
     /// Returns the display text for this content.
     pub(super) fn display_text(&self) -> String {
         match self {
@@ -151,6 +207,7 @@ impl StatusBarItemContent {
                     "♡".to_string()
                 }
             }
+            Self::Marquee { text, .. } => text.clone(),
         }
     }
 
@@ -160,6 +217,34 @@ impl StatusBarItemContent {
     }
 }
 
+/// Returns the width each marquee item in `items` may use when the whole
+/// section is limited to `max_width`.
+///
+/// Marquee items share whatever the other items and separators leave over.
+pub(super) fn marquee_width(items: &[StatusBarItem], separator: &str, max_width: usize) -> usize {
+    let is_marquee =
+        |item: &StatusBarItem| matches!(item.content, StatusBarItemContent::Marquee { .. });
+    let marquees = items.iter().filter(|item| is_marquee(item)).count().max(1);
+    let fixed: usize = items
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| {
+            let text = if is_marquee(item) {
+                0
+            } else {
+                item.text().width()
+            };
+            let sep = if idx < items.len() - 1 && item.has_separator() {
+                separator.width()
+            } else {
+                0
+            };
+            text + sep
+        })
+        .sum();
+    max_width.saturating_sub(fixed) / marquees
+}
+
 /// Style variants for status bar items.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(
@@ -184,7 +269,7 @@ pub enum StatusBarStyle {
 
 impl StatusBarStyle {
     /// Returns the ratatui style for this status bar style variant.
-    pub(super) fn style(self, theme: &Theme) -> Style {
+    pub(super) fn style(self, theme: &dyn ThemeProvider) -> Style {
         match self {
             Self::Default => theme.normal_style(),
             Self::Info => theme.info_style(),
@@ -303,6 +388,30 @@ impl StatusBarItem {
         }
     }
 
+    /// Creates a new status bar item with scrolling marquee text.
+    ///
+    /// The text scrolls on each tick when it doesn't fit in the center
+    /// section.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::StatusBarItem;
+    ///
+    /// let item = StatusBarItem::marquee("Indexing /home/user/projects/envision");
+    /// assert_eq!(item.text(), "Indexing /home/user/projects/envision");
+    /// assert!(item.is_dynamic());
+    /// ```
+    pub fn marquee(text: impl Into<String>) -> Self {
+        Self {
+            content: StatusBarItemContent::marquee(text),
+            style: StatusBarStyle::Default,
+            color: None,
+            style_override: None,
+            separator: true,
+        }
+    }
+
     /// Creates a new status bar item with a heartbeat indicator.
     ///
     /// # Example
@@ -659,6 +768,11 @@ impl StatusBarItem {
                 *frame = (*frame + 1) % 4;
                 true
             }
+            StatusBarItemContent::Marquee { text, offset } => {
+                let cycle_len = text.chars().count() + MARQUEE_GAP.chars().count();
+                *offset = (*offset + 1) % cycle_len;
+                true
+            }
             _ => false,
         }
     }
@@ -58,11 +58,14 @@ mod item;
 mod per_side_separators;
 pub use item::*;
 
+use std::time::Instant;
+
 use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
+use unicode_width::UnicodeWidthChar;
 
 use super::{Component, RenderContext};
-use crate::theme::Theme;
+use crate::theme::{Theme, ThemeProvider};
 
 /// Section of the status bar for addressing items.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -99,6 +102,14 @@ pub enum StatusBarMessage {
     /// The parameter is the time delta in milliseconds since the last tick.
     Tick(u64),
 
+    /// Update elapsed time for all running timers from a clock reading.
+    ///
+    /// The delta is the time since the previous `TickAt`; the first
+    /// `TickAt` only records the instant. Pair this with
+    /// [`Clock::now`](crate::app::Clock::now) so elapsed-time items follow
+    /// the runtime's (possibly virtual) clock.
+    TickAt(Instant),
+
     /// Start an elapsed time timer.
     StartTimer {
         /// Which section contains the timer.
@@ -205,6 +216,9 @@ pub struct StatusBarState {
     background: Color,
     /// Whether the component is disabled.
     disabled: bool,
+    /// Clock reading from the previous `TickAt` message.
+    #[cfg_attr(feature = "serialization", serde(skip))]
+    last_tick_at: Option<Instant>,
 }
 
 impl Default for StatusBarState {
@@ -233,6 +247,7 @@ impl Default for StatusBarState {
             right_separator: None,
             background: Color::DarkGray,
             disabled: false,
+            last_tick_at: None,
         }
     }
 }
@@ -667,13 +682,79 @@ impl StatusBarState {
 /// ```
 pub struct StatusBar;
 
+/// A rendered span and the item it shows, if any.
+type ItemSpan = (Option<(Section, usize)>, Span<'static>);
+
 impl StatusBar {
+    /// Returns the item under the cell at (`x`, `y`) when the bar is
+    /// rendered into `area`.
+    ///
+    /// Uses the same layout as [`view`](Component::view), so a mouse click
+    /// can be mapped back to the item that was drawn there. Returns `None`
+    /// for coordinates outside `area` and for separators and padding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Section, StatusBar, StatusBarItem, StatusBarState};
+    /// use ratatui::layout::Rect;
+    ///
+    /// let mut state = StatusBarState::new();
+    /// state.push_left(StatusBarItem::new("NORMAL"));
+    /// state.push_left(StatusBarItem::new("main.rs"));
+    /// state.push_right(StatusBarItem::new("Ln 1"));
+    ///
+    /// let area = Rect::new(0, 23, 40, 1);
+    /// assert_eq!(StatusBar::item_at(&state, area, 2, 23), Some((Section::Left, 0)));
+    /// assert_eq!(StatusBar::item_at(&state, area, 10, 23), Some((Section::Left, 1)));
+    /// assert_eq!(StatusBar::item_at(&state, area, 38, 23), Some((Section::Right, 0)));
+    /// assert_eq!(StatusBar::item_at(&state, area, 7, 23), None); // separator
+    /// assert_eq!(StatusBar::item_at(&state, area, 2, 0), None);
+    /// ```
+    pub fn item_at(state: &StatusBarState, area: Rect, x: u16, y: u16) -> Option<(Section, usize)> {
+        if !area.contains(Position::new(x, y)) {
+            return None;
+        }
+
+        let target = usize::from(x - area.x);
+        let mut start = 0;
+        for (owner, span) in Self::layout(state, &Theme::default(), usize::from(area.width)) {
+            let end = start + span.width();
+            if target < end {
+                return owner;
+            }
+            start = end;
+        }
+        None
+    }
+
     /// Renders a section of items to a span list.
+    #[cfg(test)]
     fn render_section(
         items: &[StatusBarItem],
         separator: &str,
-        theme: &Theme,
+        theme: &dyn ThemeProvider,
+        max_width: Option<usize>,
     ) -> Vec<Span<'static>> {
+        Self::render_section_items(Section::Left, items, separator, theme, max_width)
+            .into_iter()
+            .map(|(_, span)| span)
+            .collect()
+    }
+
+    /// Renders a section of items, tagging each span with the item it shows.
+    ///
+    /// When `max_width` is set, marquee items share whatever width the
+    /// other items and separators leave over.
+    fn render_section_items(
+        section: Section,
+        items: &[StatusBarItem],
+        separator: &str,
+        theme: &dyn ThemeProvider,
+        max_width: Option<usize>,
+    ) -> Vec<ItemSpan> {
+        let marquee_width = max_width.map(|max| item::marquee_width(items, separator, max));
+
         let mut spans = Vec::new();
 
         for (idx, item) in items.iter().enumerate() {
@@ -684,25 +765,32 @@ impl StatusBar {
             } else {
                 item.style.style(theme)
             };
-            spans.push(Span::styled(item.text(), style));
+            let text = match marquee_width {
+                Some(width) => item.content.display_text_within(width),
+                None => item.text(),
+            };
+            spans.push((Some((section, idx)), Span::styled(text, style)));
 
             // Add separator if not last item and item has separator enabled
             if idx < items.len() - 1 && item.has_separator() {
-                spans.push(Span::styled(separator.to_string(), theme.disabled_style()));
+                spans.push((
+                    None,
+                    Span::styled(separator.to_string(), theme.disabled_style()),
+                ));
             }
         }
 
         spans
     }
 
-    /// Truncates a list of spans to fit within `max_width` characters.
+    /// Truncates a list of spans to fit within `max_width` columns.
     /// Appends an ellipsis if truncation occurs.
-    fn truncate_spans(spans: Vec<Span<'static>>, max_width: usize) -> Vec<Span<'static>> {
+    fn truncate_spans(spans: Vec<ItemSpan>, max_width: usize) -> Vec<ItemSpan> {
         if max_width == 0 {
             return Vec::new();
         }
 
-        let total: usize = spans.iter().map(|s| s.content.len()).sum();
+        let total: usize = spans.iter().map(|(_, span)| span.width()).sum();
         if total <= max_width {
             return spans;
         }
@@ -710,28 +798,118 @@ impl StatusBar {
         let mut result = Vec::new();
         let mut remaining = max_width.saturating_sub(1); // Reserve 1 for ellipsis
 
-        for span in spans {
-            let len = span.content.len();
+        for (owner, span) in spans {
+            let width = span.width();
             if remaining == 0 {
                 break;
             }
-            if len <= remaining {
-                remaining -= len;
-                result.push(span);
+            if width <= remaining {
+                remaining -= width;
+                result.push((owner, span));
             } else {
                 // Truncate this span
-                let truncated: String = span.content.chars().take(remaining).collect();
-                result.push(Span::styled(truncated, span.style));
+                let mut truncated = String::new();
+                for ch in span.content.chars() {
+                    let ch_width = ch.width().unwrap_or(0);
+                    if ch_width > remaining {
+                        break;
+                    }
+                    remaining -= ch_width;
+                    truncated.push(ch);
+                }
+                result.push((owner, Span::styled(truncated, span.style)));
                 remaining = 0;
             }
         }
 
-        // Add ellipsis with the style of the last span
-        let ellipsis_style = result.last().map(|s| s.style).unwrap_or_default();
-        result.push(Span::styled("…", ellipsis_style));
+        // Add ellipsis with the owner and style of the last span
+        let (owner, style) = result
+            .last()
+            .map(|(owner, span)| (*owner, span.style))
+            .unwrap_or_default();
+        result.push((owner, Span::styled("…", style)));
 
         result
     }
+
+    /// Lays out the whole bar for a line `total_width` columns wide.
+    ///
+    /// Shared by `view` and [`item_at`](Self::item_at) so hit-testing always
+    /// matches what is drawn. Padding and separators have no owner.
+    fn layout(
+        state: &StatusBarState,
+        theme: &dyn ThemeProvider,
+        total_width: usize,
+    ) -> Vec<ItemSpan> {
+        // Calculate section widths
+        let left_sep = state.left_separator.as_deref().unwrap_or(&state.separator);
+        let center_sep = state
+            .center_separator
+            .as_deref()
+            .unwrap_or(&state.separator);
+        let right_sep = state.right_separator.as_deref().unwrap_or(&state.separator);
+        let left_spans =
+            Self::render_section_items(Section::Left, &state.left, left_sep, theme, None);
+        let right_spans =
+            Self::render_section_items(Section::Right, &state.right, right_sep, theme, None);
+
+        // Calculate the width of each side section
+        let left_width: usize = left_spans.iter().map(|(_, span)| span.width()).sum();
+        let right_width: usize = right_spans.iter().map(|(_, span)| span.width()).sum();
+
+        // Determine how much space is available for center after left and right.
+        // Priority: left (full), right (full), center (gets remainder).
+        let available_for_center = total_width
+            .saturating_sub(left_width)
+            .saturating_sub(right_width);
+
+        // Marquee items in the center scroll within the remaining space
+        let center_spans = Self::render_section_items(
+            Section::Center,
+            &state.center,
+            center_sep,
+            theme,
+            Some(available_for_center),
+        );
+
+        // Truncate center spans if they exceed available space
+        let center_spans = Self::truncate_spans(center_spans, available_for_center);
+        let center_width: usize = center_spans.iter().map(|(_, span)| span.width()).sum();
+
+        // Build the line with proper spacing
+        let mut line_spans = Vec::new();
+
+        // Add left section
+        line_spans.extend(left_spans);
+
+        // Calculate padding for center
+        let left_padding = if center_width > 0 {
+            let center_start = (total_width.saturating_sub(center_width)) / 2;
+            center_start.saturating_sub(left_width)
+        } else {
+            0
+        };
+
+        if left_padding > 0 {
+            line_spans.push((None, Span::raw(" ".repeat(left_padding))));
+        }
+
+        // Add center section
+        line_spans.extend(center_spans);
+
+        // Calculate padding for right
+        let current_width = left_width + left_padding + center_width;
+        let right_padding = total_width.saturating_sub(current_width + right_width);
+
+        if right_padding > 0 {
+            line_spans.push((None, Span::raw(" ".repeat(right_padding))));
+        }
+
+        // Add right section
+        line_spans.extend(right_spans);
+
+        line_spans
+    }
 }
 
 impl Component for StatusBar {
@@ -772,6 +950,13 @@ impl Component for StatusBar {
                 state.tick_all(delta_ms);
             }
 
+            StatusBarMessage::TickAt(now) => {
+                if let Some(last) = state.last_tick_at.replace(now) {
+                    let delta = now.saturating_duration_since(last);
+                    state.tick_all(u64::try_from(delta.as_millis()).unwrap_or(u64::MAX));
+                }
+            }
+
             StatusBarMessage::StartTimer { section, index } => {
                 if let Some(item) = state.get_item_mut(section, index) {
                     if let StatusBarItemContent::ElapsedTime { running, .. } = &mut item.content {
@@ -862,65 +1047,10 @@ impl Component for StatusBar {
         // Render background
         let bg_style = Style::default().bg(state.background);
 
-        // Calculate section widths
-        let left_sep = state.left_separator.as_deref().unwrap_or(&state.separator);
-        let center_sep = state
-            .center_separator
-            .as_deref()
-            .unwrap_or(&state.separator);
-        let right_sep = state.right_separator.as_deref().unwrap_or(&state.separator);
-        let left_spans = Self::render_section(&state.left, left_sep, ctx.theme);
-        let center_spans = Self::render_section(&state.center, center_sep, ctx.theme);
-        let right_spans = Self::render_section(&state.right, right_sep, ctx.theme);
-
-        // Calculate the width of each section
-        let left_width: usize = left_spans.iter().map(|s| s.content.len()).sum();
-        let center_width: usize = center_spans.iter().map(|s| s.content.len()).sum();
-        let right_width: usize = right_spans.iter().map(|s| s.content.len()).sum();
-
-        let total_width = ctx.area.width as usize;
-
-        // Determine how much space is available for center after left and right.
-        // Priority: left (full), right (full), center (gets remainder).
-        let available_for_center = total_width
-            .saturating_sub(left_width)
-            .saturating_sub(right_width);
-        let effective_center_width = center_width.min(available_for_center);
-
-        // Truncate center spans if they exceed available space
-        let center_spans = Self::truncate_spans(center_spans, effective_center_width);
-
-        // Build the line with proper spacing
-        let mut line_spans: Vec<Span> = Vec::new();
-
-        // Add left section
-        line_spans.extend(left_spans);
-
-        // Calculate padding for center
-        let left_padding = if effective_center_width > 0 {
-            let center_start = (total_width.saturating_sub(effective_center_width)) / 2;
-            center_start.saturating_sub(left_width)
-        } else {
-            0
-        };
-
-        if left_padding > 0 {
-            line_spans.push(Span::raw(" ".repeat(left_padding)));
-        }
-
-        // Add center section
-        line_spans.extend(center_spans);
-
-        // Calculate padding for right
-        let current_width = left_width + left_padding + effective_center_width;
-        let right_padding = total_width.saturating_sub(current_width + right_width);
-
-        if right_padding > 0 {
-            line_spans.push(Span::raw(" ".repeat(right_padding)));
-        }
-
-        // Add right section
-        line_spans.extend(right_spans);
+        let line_spans: Vec<Span> = Self::layout(state, ctx.theme, usize::from(ctx.area.width))
+            .into_iter()
+            .map(|(_, span)| span)
+            .collect();
 
         let line = Line::from(line_spans);
         let paragraph = Paragraph::new(line).style(bg_style);
@@ -188,7 +188,7 @@ fn snapshot_status_bar_four_stop_severity_ramp() {
 fn snapshot_status_bar_per_side_separators() {
     // RENDER-PATH PIN: distinct per-side separators visible in the rendered
     // output. Pins that the per-side fallback resolution at mod.rs:850-852
-    // correctly routes each section's separator through render_section.
+    // correctly routes each section's separator through render_section_items.
     //
     // leadline's actual transformation (the load-bearing motivation):
     // global " · " separator on left items, but " " (space) between
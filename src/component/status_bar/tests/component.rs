@@ -89,7 +89,7 @@ fn test_update_returns_none() {
 #[test]
 fn test_render_section_empty() {
     let theme = Theme::default();
-    let spans = StatusBar::render_section(&[], " | ", &theme);
+    let spans = StatusBar::render_section(&[], " | ", &theme, None);
     assert!(spans.is_empty());
 }
 
@@ -97,7 +97,7 @@ fn test_render_section_empty() {
 fn test_render_section_single_item() {
     let theme = Theme::default();
     let items = vec![StatusBarItem::new("Test")];
-    let spans = StatusBar::render_section(&items, " | ", &theme);
+    let spans = StatusBar::render_section(&items, " | ", &theme, None);
     assert_eq!(spans.len(), 1);
 }
 
@@ -105,13 +105,36 @@ fn test_render_section_single_item() {
 fn test_render_section_multiple_items() {
     let theme = Theme::default();
     let items = vec![StatusBarItem::new("A"), StatusBarItem::new("B")];
-    let spans = StatusBar::render_section(&items, " | ", &theme);
+    let spans = StatusBar::render_section(&items, " | ", &theme, None);
     // A + separator + B = 3 spans
     assert_eq!(spans.len(), 3);
 }
 
 // View tests
 
+#[test]
+fn test_view_center_marquee_scrolls_on_tick() {
+    let mut state = StatusBarState::new();
+    state.push_left(StatusBarItem::new("L"));
+    state.push_center(StatusBarItem::marquee("0123456789"));
+    state.push_right(StatusBarItem::new("R"));
+
+    let render = |state: &StatusBarState| {
+        let (mut terminal, theme) = crate::component::test_utils::setup_render(8, 1);
+        terminal
+            .draw(|frame| {
+                StatusBar::view(state, &mut RenderContext::new(frame, frame.area(), &theme));
+            })
+            .unwrap();
+        terminal.backend().to_string()
+    };
+
+    assert_eq!(render(&state).trim_end(), "L012345R");
+    StatusBar::update(&mut state, StatusBarMessage::Tick(100));
+    StatusBar::update(&mut state, StatusBarMessage::Tick(100));
+    assert_eq!(render(&state).trim_end(), "L234567R");
+}
+
 #[test]
 fn test_view_empty() {
     let state = StatusBarState::new();
@@ -467,7 +490,7 @@ fn test_render_section_with_separator_disabled() {
         StatusBarItem::new("A").with_separator(false),
         StatusBarItem::new("B"),
     ];
-    let spans = StatusBar::render_section(&items, " | ", &theme);
+    let spans = StatusBar::render_section(&items, " | ", &theme, None);
     // A (no sep) + B = 2 spans
     assert_eq!(spans.len(), 2);
 }
@@ -480,7 +503,7 @@ fn test_render_section_all_separators_disabled() {
         StatusBarItem::new("B").with_separator(false),
         StatusBarItem::new("C"),
     ];
-    let spans = StatusBar::render_section(&items, " | ", &theme);
+    let spans = StatusBar::render_section(&items, " | ", &theme, None);
     // A (no sep) + B (no sep) + C = 3 spans (no separators added)
     assert_eq!(spans.len(), 3);
 }
@@ -489,7 +512,7 @@ fn test_render_section_all_separators_disabled() {
 fn test_render_section_span_content() {
     let theme = Theme::default();
     let items = vec![StatusBarItem::new("Hello"), StatusBarItem::new("World")];
-    let spans = StatusBar::render_section(&items, " | ", &theme);
+    let spans = StatusBar::render_section(&items, " | ", &theme, None);
     assert_eq!(spans.len(), 3);
     assert_eq!(spans[0].content.as_ref(), "Hello");
     assert_eq!(spans[1].content.as_ref(), " | ");
@@ -0,0 +1,136 @@
+use super::*;
+
+fn editor_bar() -> StatusBarState {
+    let mut state = StatusBarState::new();
+    state.push_left(StatusBarItem::new("NORMAL"));
+    state.push_left(StatusBarItem::new("main.rs"));
+    state.push_center(StatusBarItem::new("ok"));
+    state.push_right(StatusBarItem::new("UTF-8"));
+    state.push_right(StatusBarItem::new("Ln 1"));
+    state
+}
+
+#[test]
+fn test_item_at_maps_each_section() {
+    let state = editor_bar();
+    let area = Rect::new(0, 0, 40, 1);
+
+    // "NORMAL | main.rs" on the left
+    assert_eq!(
+        StatusBar::item_at(&state, area, 0, 0),
+        Some((Section::Left, 0))
+    );
+    assert_eq!(
+        StatusBar::item_at(&state, area, 5, 0),
+        Some((Section::Left, 0))
+    );
+    assert_eq!(
+        StatusBar::item_at(&state, area, 9, 0),
+        Some((Section::Left, 1))
+    );
+    assert_eq!(
+        StatusBar::item_at(&state, area, 15, 0),
+        Some((Section::Left, 1))
+    );
+
+    // "ok" centered at columns 19-20
+    assert_eq!(
+        StatusBar::item_at(&state, area, 19, 0),
+        Some((Section::Center, 0))
+    );
+    assert_eq!(
+        StatusBar::item_at(&state, area, 20, 0),
+        Some((Section::Center, 0))
+    );
+
+    // "UTF-8 | Ln 1" on the right
+    assert_eq!(
+        StatusBar::item_at(&state, area, 28, 0),
+        Some((Section::Right, 0))
+    );
+    assert_eq!(
+        StatusBar::item_at(&state, area, 39, 0),
+        Some((Section::Right, 1))
+    );
+}
+
+#[test]
+fn test_item_at_separators_and_padding_are_empty() {
+    let state = editor_bar();
+    let area = Rect::new(0, 0, 40, 1);
+
+    assert_eq!(StatusBar::item_at(&state, area, 7, 0), None);
+    assert_eq!(StatusBar::item_at(&state, area, 17, 0), None);
+    assert_eq!(StatusBar::item_at(&state, area, 25, 0), None);
+}
+
+#[test]
+fn test_item_at_respects_area_offset() {
+    let state = editor_bar();
+    let area = Rect::new(5, 10, 40, 1);
+
+    assert_eq!(
+        StatusBar::item_at(&state, area, 5, 10),
+        Some((Section::Left, 0))
+    );
+    assert_eq!(StatusBar::item_at(&state, area, 4, 10), None);
+    assert_eq!(StatusBar::item_at(&state, area, 5, 9), None);
+    assert_eq!(StatusBar::item_at(&state, area, 5, 11), None);
+    assert_eq!(StatusBar::item_at(&state, area, 45, 10), None);
+}
+
+#[test]
+fn test_item_at_matches_rendered_cells() {
+    let state = editor_bar();
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(40, 1);
+    terminal
+        .draw(|frame| {
+            StatusBar::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    let area = Rect::new(0, 0, 40, 1);
+    let backend = terminal.backend();
+    for x in 0..40 {
+        let symbol = backend.cell(x, 0).unwrap().symbol();
+        if let Some((section, index)) = StatusBar::item_at(&state, area, x, 0) {
+            let text = state.section(section)[index].text();
+            assert!(text.contains(symbol), "{symbol:?} not in {text:?} at {x}");
+        }
+    }
+}
+
+#[test]
+fn test_item_at_uses_display_width() {
+    let mut state = StatusBarState::new();
+    state.push_left(StatusBarItem::new("✓ 日本"));
+    state.push_left(StatusBarItem::new("next"));
+    let area = Rect::new(0, 0, 30, 1);
+
+    // "✓ 日本" is 6 columns wide, then " | " and "next"
+    assert_eq!(
+        StatusBar::item_at(&state, area, 5, 0),
+        Some((Section::Left, 0))
+    );
+    assert_eq!(StatusBar::item_at(&state, area, 7, 0), None);
+    assert_eq!(
+        StatusBar::item_at(&state, area, 9, 0),
+        Some((Section::Left, 1))
+    );
+}
+
+#[test]
+fn test_view_pads_wide_characters_by_display_width() {
+    let mut state = StatusBarState::new();
+    state.push_left(StatusBarItem::new("日本"));
+    state.push_right(StatusBarItem::new("R"));
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(10, 1);
+    terminal
+        .draw(|frame| {
+            StatusBar::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    // The right item stays flush with the right edge
+    assert_eq!(terminal.backend().cell(9, 0).unwrap().symbol(), "R");
+}
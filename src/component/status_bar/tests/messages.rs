@@ -25,6 +25,35 @@ fn test_tick_message() {
     assert_eq!(state.left()[0].text(), "01:10");
 }
 
+#[test]
+fn test_tick_at_message_uses_clock_deltas() {
+    use crate::app::Clock;
+    use std::time::Duration;
+
+    let clock = Clock::new_virtual();
+    let mut state = StatusBarState::new();
+    state.push_left(StatusBarItem::elapsed_time());
+    StatusBar::update(
+        &mut state,
+        StatusBarMessage::StartTimer {
+            section: Section::Left,
+            index: 0,
+        },
+    );
+
+    // First reading only records the instant
+    StatusBar::update(&mut state, StatusBarMessage::TickAt(clock.now()));
+    assert_eq!(state.left()[0].text(), "00:00");
+
+    clock.advance(Duration::from_secs(5));
+    StatusBar::update(&mut state, StatusBarMessage::TickAt(clock.now()));
+    assert_eq!(state.left()[0].text(), "00:05");
+
+    clock.advance(Duration::from_secs(65));
+    StatusBar::update(&mut state, StatusBarMessage::TickAt(clock.now()));
+    assert_eq!(state.left()[0].text(), "01:10");
+}
+
 #[test]
 fn test_start_timer() {
     let mut state = StatusBarState::new();
@@ -1,6 +1,7 @@
 use super::*;
 
 mod component;
+mod item_at;
 mod messages;
 mod state;
 mod style_item;
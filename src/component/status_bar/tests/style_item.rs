@@ -146,6 +146,71 @@ fn test_content_heartbeat_active_frames() {
     assert_eq!(content3.display_text(), "♡");
 }
 
+#[test]
+fn test_content_marquee_fits_without_scrolling() {
+    let content = StatusBarItemContent::Marquee {
+        text: "short".to_string(),
+        offset: 3,
+    };
+    assert_eq!(content.display_text(), "short");
+    assert_eq!(content.display_text_within(10), "short");
+}
+
+#[test]
+fn test_content_marquee_window_wraps_with_gap() {
+    let content = StatusBarItemContent::Marquee {
+        text: "abcdef".to_string(),
+        offset: 4,
+    };
+    assert_eq!(content.display_text_within(5), "ef   ");
+
+    let content = StatusBarItemContent::Marquee {
+        text: "abcdef".to_string(),
+        offset: 7,
+    };
+    assert_eq!(content.display_text_within(5), "  abc");
+}
+
+#[test]
+fn test_content_marquee_window_counts_display_columns() {
+    let content = StatusBarItemContent::Marquee {
+        text: "日本語の道".to_string(),
+        offset: 1,
+    };
+    // A wide glyph that would straddle the edge is left out.
+    assert_eq!(content.display_text_within(5), "本語");
+    assert_eq!(content.display_text_within(6), "本語の");
+}
+
+#[test]
+fn test_marquee_width_measures_display_columns() {
+    let items = vec![
+        StatusBarItem::new("日本"),
+        StatusBarItem::marquee("a/long/path"),
+    ];
+    // "日本" is four columns and the "│" separator three.
+    assert_eq!(item::marquee_width(&items, " │ ", 20), 13);
+}
+
+#[test]
+fn test_marquee_item_tick_advances_and_wraps() {
+    let mut item = StatusBarItem::marquee("abc");
+    assert!(item.is_dynamic());
+    for _ in 0..5 {
+        assert!(item.tick(100));
+    }
+    assert!(matches!(
+        item.content(),
+        StatusBarItemContent::Marquee { offset: 5, .. }
+    ));
+    // "abc" plus the three-column gap is a six-column cycle
+    item.tick(100);
+    assert!(matches!(
+        item.content(),
+        StatusBarItemContent::Marquee { offset: 0, .. }
+    ));
+}
+
 // StatusBarItem factory method tests
 
 #[test]
@@ -0,0 +1,406 @@
+//! A compact grid of named health indicators.
+//!
+//! [`StatusGrid`] shows many services (or hosts, jobs, …) at a glance, each
+//! as a colored `●` followed by its label, in columns that wrap to the
+//! available width. Colors come from the theme's status palette, see
+//! [`Theme::status_colors`](crate::theme::Theme::status_colors). State is
+//! stored in [`StatusGridState`], updated via [`StatusGridMessage`], and
+//! produces [`StatusGridOutput`].
+//!
+//! See also [`Table`](super::Table) for statuses with more detail per row.
+//!
+//! # Example
+//!
+//! ```rust
+//! use envision::component::{Component, Health, StatusGrid, StatusGridMessage, StatusGridState};
+//!
+//! let mut state = StatusGridState::new(vec![("api", Health::Ok), ("db", Health::Unknown)]);
+//! StatusGrid::update(
+//!     &mut state,
+//!     StatusGridMessage::SetStatus("db".into(), Health::Down),
+//! );
+//! assert_eq!(state.health("db"), Some(Health::Down));
+//! assert_eq!(state.count(Health::Ok), 1);
+//! ```
+
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+use unicode_width::UnicodeWidthStr;
+
+use super::{Component, EventContext, RenderContext};
+use crate::input::{Event, MouseButton, MouseEventKind};
+use crate::theme::StatusLevel;
+
+/// Blank columns between grid columns.
+const COLUMN_GAP: u16 = 2;
+
+/// The health of one entry in a [`StatusGrid`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum Health {
+    /// Working normally.
+    Ok,
+    /// Working, but impaired.
+    Degraded,
+    /// Not working.
+    Down,
+    /// Not known yet, e.g. before the first check.
+    #[default]
+    Unknown,
+}
+
+impl Health {
+    /// Returns the status level whose theme color marks this health.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::Health;
+    /// use envision::theme::StatusLevel;
+    ///
+    /// assert_eq!(Health::Down.status_level(), StatusLevel::Error);
+    /// ```
+    pub fn status_level(self) -> StatusLevel {
+        match self {
+            Health::Ok => StatusLevel::Success,
+            Health::Degraded => StatusLevel::Warning,
+            Health::Down => StatusLevel::Error,
+            Health::Unknown => StatusLevel::Neutral,
+        }
+    }
+}
+
+/// Messages that can be sent to a StatusGrid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StatusGridMessage {
+    /// Set the health of a label, adding it at the end if it is new.
+    SetStatus(String, Health),
+    /// Replace every entry, in the given order.
+    SetAll(Vec<(String, Health)>),
+    /// Report a click on the entry at this index.
+    Click(usize),
+}
+
+/// Output messages from a StatusGrid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StatusGridOutput {
+    /// An entry was clicked. Contains its label.
+    StatusClicked(String),
+}
+
+/// State for a StatusGrid component.
+///
+/// Entries keep the order they were added in.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::component::{Health, StatusGridState};
+///
+/// let mut state = StatusGridState::default();
+/// state.set_status("cache", Health::Degraded);
+/// assert_eq!(state.len(), 1);
+/// assert_eq!(state.health("cache"), Some(Health::Degraded));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct StatusGridState {
+    /// Labels and their health, in display order.
+    entries: Vec<(String, Health)>,
+    /// The area the grid was last laid out in, used for mouse clicks.
+    #[cfg_attr(feature = "serialization", serde(skip))]
+    area: Rect,
+}
+
+impl StatusGridState {
+    /// Creates a grid with the given entries, in order.
+    ///
+    /// A label listed more than once keeps its first position and its
+    /// last health.
+    pub fn new<S: Into<String>>(entries: impl IntoIterator<Item = (S, Health)>) -> Self {
+        let mut state = Self::default();
+        state.set_all(entries);
+        state
+    }
+
+    /// Returns the entries in display order.
+    pub fn entries(&self) -> &[(String, Health)] {
+        &self.entries
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the health of `label`, or `None` if it is not in the grid.
+    pub fn health(&self, label: &str) -> Option<Health> {
+        self.entries
+            .iter()
+            .find(|(l, _)| l == label)
+            .map(|&(_, health)| health)
+    }
+
+    /// Returns how many entries have the given health.
+    pub fn count(&self, health: Health) -> usize {
+        self.entries.iter().filter(|(_, h)| *h == health).count()
+    }
+
+    /// Sets the health of `label`, adding it at the end if it is new.
+    pub fn set_status(&mut self, label: impl Into<String>, health: Health) {
+        let label = label.into();
+        match self.entries.iter_mut().find(|(l, _)| *l == label) {
+            Some(entry) => entry.1 = health,
+            None => self.entries.push((label, health)),
+        }
+    }
+
+    /// Replaces every entry, in the given order.
+    pub fn set_all<S: Into<String>>(&mut self, entries: impl IntoIterator<Item = (S, Health)>) {
+        self.entries.clear();
+        for (label, health) in entries {
+            self.set_status(label, health);
+        }
+    }
+
+    /// Removes `label`, returning its health if it was present.
+    pub fn remove(&mut self, label: &str) -> Option<Health> {
+        let index = self.entries.iter().position(|(l, _)| l == label)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    /// Sets the area the grid is rendered in.
+    ///
+    /// Mouse clicks are mapped to entries using this area, so call it with
+    /// the same area passed to [`StatusGrid::view`] before handling events.
+    pub fn set_area(&mut self, area: Rect) {
+        self.area = area;
+    }
+
+    /// Returns the area set with [`set_area`](Self::set_area).
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Returns the number of columns the grid uses in `width` columns of
+    /// screen.
+    ///
+    /// Every column is as wide as the widest entry, with a gap of two
+    /// between columns. There is always at least one column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Health, StatusGridState};
+    ///
+    /// // Entries are "● api" and "● auth", 6 wide, so 3 fit in 22.
+    /// let state = StatusGridState::new(vec![("api", Health::Ok), ("auth", Health::Ok)]);
+    /// assert_eq!(state.column_count(22), 3);
+    /// assert_eq!(state.column_count(4), 1);
+    /// ```
+    pub fn column_count(&self, width: u16) -> usize {
+        let pitch = usize::from(self.cell_width() + COLUMN_GAP);
+        ((usize::from(width) + usize::from(COLUMN_GAP)) / pitch).max(1)
+    }
+
+    /// Returns the index of the entry rendered at the given screen
+    /// position, if any.
+    ///
+    /// Uses the area set with [`set_area`](Self::set_area). The gaps
+    /// between columns do not belong to any entry.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Health, StatusGridState};
+    /// use ratatui::layout::Rect;
+    ///
+    /// let mut state = StatusGridState::new(vec![("api", Health::Ok), ("db", Health::Ok)]);
+    /// state.set_area(Rect::new(0, 0, 20, 2));
+    /// assert_eq!(state.entry_at(0, 0), Some(0));
+    /// assert_eq!(state.entry_at(7, 0), Some(1));
+    /// assert_eq!(state.entry_at(5, 0), None);
+    /// assert_eq!(state.entry_at(0, 1), None);
+    /// ```
+    pub fn entry_at(&self, column: u16, row: u16) -> Option<usize> {
+        let area = self.area;
+        if !area.contains(Position::new(column, row)) {
+            return None;
+        }
+        let pitch = self.cell_width() + COLUMN_GAP;
+        let x = column - area.x;
+        if x % pitch >= self.cell_width() {
+            return None;
+        }
+        let grid_column = usize::from(x / pitch);
+        let columns = self.column_count(area.width);
+        if grid_column >= columns {
+            return None;
+        }
+        let index = usize::from(row - area.y) * columns + grid_column;
+        (index < self.entries.len()).then_some(index)
+    }
+
+    /// Updates the state with a message, returning any output.
+    pub fn update(&mut self, msg: StatusGridMessage) -> Option<StatusGridOutput> {
+        StatusGrid::update(self, msg)
+    }
+
+    /// Width of one grid cell: the dot, a space and the widest label.
+    fn cell_width(&self) -> u16 {
+        let label = self
+            .entries
+            .iter()
+            .map(|(label, _)| label.width())
+            .max()
+            .unwrap_or(0);
+        u16::try_from(label + 2).unwrap_or(u16::MAX - COLUMN_GAP)
+    }
+}
+
+/// A compact grid of named health indicators.
+///
+/// Entries are laid out left to right, top to bottom, in as many equal
+/// columns as fit the area. Rows past the bottom of the area are not
+/// shown. The `●` of each entry is colored with the status palette color
+/// for its [`Health`]; the label uses the normal text style.
+///
+/// # Mouse
+///
+/// Left-clicking an entry sends `Click` for it, which produces
+/// [`StatusGridOutput::StatusClicked`]. Clicks are mapped using the area
+/// given to [`StatusGridState::set_area`].
+///
+/// # Example
+///
+/// ```rust
+/// use envision::component::{Component, Health, RenderContext, StatusGrid, StatusGridState};
+/// use envision::theme::Theme;
+/// use ratatui::{Terminal, backend::TestBackend};
+///
+/// let mut state = StatusGridState::new(vec![
+///     ("api", Health::Ok),
+///     ("db", Health::Degraded),
+///     ("queue", Health::Down),
+/// ]);
+/// let theme = Theme::default();
+/// let mut terminal = Terminal::new(TestBackend::new(30, 2)).unwrap();
+/// terminal
+///     .draw(|frame| {
+///         let area = frame.area();
+///         state.set_area(area);
+///         StatusGrid::view(&state, &mut RenderContext::new(frame, area, &theme));
+///     })
+///     .unwrap();
+/// ```
+pub struct StatusGrid;
+
+impl Component for StatusGrid {
+    type State = StatusGridState;
+    type Message = StatusGridMessage;
+    type Output = StatusGridOutput;
+
+    fn init() -> Self::State {
+        StatusGridState::default()
+    }
+
+    fn handle_event(
+        state: &Self::State,
+        event: &Event,
+        ctx: &EventContext,
+    ) -> Option<Self::Message> {
+        if ctx.disabled {
+            return None;
+        }
+        let mouse = event.as_mouse()?;
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return None;
+        }
+        state
+            .entry_at(mouse.column, mouse.row)
+            .map(StatusGridMessage::Click)
+    }
+
+    fn update(state: &mut Self::State, msg: Self::Message) -> Option<Self::Output> {
+        match msg {
+            StatusGridMessage::SetStatus(label, health) => {
+                state.set_status(label, health);
+                None
+            }
+            StatusGridMessage::SetAll(entries) => {
+                state.set_all(entries);
+                None
+            }
+            StatusGridMessage::Click(index) => state
+                .entries
+                .get(index)
+                .map(|(label, _)| StatusGridOutput::StatusClicked(label.clone())),
+        }
+    }
+
+    fn view(state: &Self::State, ctx: &mut RenderContext<'_, '_>) {
+        crate::annotation::with_registry(|reg| {
+            reg.register(
+                ctx.area,
+                crate::annotation::Annotation::custom("StatusGrid", "status_grid")
+                    .with_focus(ctx.focused)
+                    .with_disabled(ctx.disabled)
+                    .with_value(format!("{}/{} ok", state.count(Health::Ok), state.len())),
+            );
+        });
+
+        if state.entries.is_empty() || ctx.area.width == 0 {
+            return;
+        }
+
+        let columns = state.column_count(ctx.area.width);
+        let pitch = usize::from(state.cell_width() + COLUMN_GAP);
+        let label_style = if ctx.disabled {
+            ctx.theme.disabled_style()
+        } else {
+            ctx.theme.normal_style()
+        };
+
+        let lines: Vec<Line> = state
+            .entries
+            .chunks(columns)
+            .take(usize::from(ctx.area.height))
+            .map(|row| {
+                let mut spans = Vec::with_capacity(row.len() * 3);
+                for (i, (label, health)) in row.iter().enumerate() {
+                    let dot_style = if ctx.disabled {
+                        ctx.theme.disabled_style()
+                    } else {
+                        let (_, color) = ctx.theme.status_colors(health.status_level());
+                        Style::default().fg(color)
+                    };
+                    spans.push(Span::styled("●", dot_style));
+                    let padding = if i + 1 < row.len() {
+                        pitch - 2 - label.width()
+                    } else {
+                        0
+                    };
+                    spans.push(Span::styled(format!(" {label}"), label_style));
+                    spans.push(Span::raw(" ".repeat(padding)));
+                }
+                Line::from(spans)
+            })
+            .collect();
+
+        ctx.frame.render_widget(Paragraph::new(lines), ctx.area);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,212 @@
+use super::*;
+use crate::component::test_utils;
+
+fn state() -> StatusGridState {
+    StatusGridState::new(vec![
+        ("api", Health::Ok),
+        ("auth", Health::Degraded),
+        ("db", Health::Down),
+        ("queue", Health::Unknown),
+    ])
+}
+
+fn render(state: &StatusGridState, width: u16, height: u16) -> String {
+    let (mut terminal, theme) = test_utils::setup_render(width, height);
+    terminal
+        .draw(|frame| {
+            StatusGrid::view(state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+// =============================================================================
+// State
+// =============================================================================
+
+#[test]
+fn test_new_keeps_order_and_merges_duplicates() {
+    let state = StatusGridState::new(vec![
+        ("b", Health::Ok),
+        ("a", Health::Down),
+        ("b", Health::Degraded),
+    ]);
+    assert_eq!(
+        state.entries(),
+        [
+            ("b".to_string(), Health::Degraded),
+            ("a".to_string(), Health::Down)
+        ]
+    );
+    assert!(StatusGrid::init().is_empty());
+}
+
+#[test]
+fn test_set_status_updates_or_appends() {
+    let mut state = state();
+    StatusGrid::update(
+        &mut state,
+        StatusGridMessage::SetStatus("api".into(), Health::Down),
+    );
+    StatusGrid::update(
+        &mut state,
+        StatusGridMessage::SetStatus("mail".into(), Health::Ok),
+    );
+    assert_eq!(state.len(), 5);
+    assert_eq!(state.health("api"), Some(Health::Down));
+    assert_eq!(state.entries()[4], ("mail".to_string(), Health::Ok));
+    assert_eq!(state.count(Health::Down), 2);
+}
+
+#[test]
+fn test_set_all_replaces_entries() {
+    let mut state = state();
+    let output = state.update(StatusGridMessage::SetAll(vec![("web".into(), Health::Ok)]));
+    assert_eq!(output, None);
+    assert_eq!(state.len(), 1);
+    assert_eq!(state.health("api"), None);
+}
+
+#[test]
+fn test_remove() {
+    let mut state = state();
+    assert_eq!(state.remove("db"), Some(Health::Down));
+    assert_eq!(state.remove("db"), None);
+    assert_eq!(state.len(), 3);
+}
+
+#[test]
+fn test_health_status_levels() {
+    assert_eq!(Health::default(), Health::Unknown);
+    assert_eq!(Health::Ok.status_level(), StatusLevel::Success);
+    assert_eq!(Health::Degraded.status_level(), StatusLevel::Warning);
+    assert_eq!(Health::Unknown.status_level(), StatusLevel::Neutral);
+}
+
+// =============================================================================
+// Layout and mouse
+// =============================================================================
+
+#[test]
+fn test_column_count_wraps_to_width() {
+    // Cells are "● queue": 7 wide, pitch 9.
+    let state = state();
+    assert_eq!(state.column_count(7), 1);
+    assert_eq!(state.column_count(15), 1);
+    assert_eq!(state.column_count(16), 2);
+    assert_eq!(state.column_count(u16::MAX), 7281);
+    assert_eq!(StatusGridState::default().column_count(10), 3);
+}
+
+#[test]
+fn test_entry_at_maps_grid_cells() {
+    let mut state = state();
+    state.set_area(Rect::new(2, 1, 16, 3));
+    assert_eq!(state.entry_at(2, 1), Some(0));
+    assert_eq!(state.entry_at(8, 1), Some(0));
+    assert_eq!(state.entry_at(9, 1), None);
+    assert_eq!(state.entry_at(11, 1), Some(1));
+    assert_eq!(state.entry_at(11, 2), Some(3));
+    assert_eq!(state.entry_at(2, 3), None);
+    assert_eq!(state.entry_at(0, 1), None);
+}
+
+#[test]
+fn test_click_emits_status_clicked() {
+    let mut state = state();
+    state.set_area(Rect::new(0, 0, 16, 2));
+    let msg = StatusGrid::handle_event(&state, &Event::click(10, 1), &EventContext::default());
+    assert_eq!(msg, Some(StatusGridMessage::Click(3)));
+    assert_eq!(
+        StatusGrid::update(&mut state, msg.unwrap()),
+        Some(StatusGridOutput::StatusClicked("queue".into()))
+    );
+    assert_eq!(
+        StatusGrid::update(&mut state, StatusGridMessage::Click(9)),
+        None
+    );
+}
+
+#[test]
+fn test_click_ignored_when_disabled_or_not_left_down() {
+    let mut state = state();
+    state.set_area(Rect::new(0, 0, 16, 2));
+    assert_eq!(
+        StatusGrid::handle_event(
+            &state,
+            &Event::click(0, 0),
+            &EventContext::new().disabled(true)
+        ),
+        None
+    );
+    assert_eq!(
+        StatusGrid::handle_event(
+            &state,
+            &Event::click_button(0, 0, MouseButton::Right),
+            &EventContext::default()
+        ),
+        None
+    );
+    assert_eq!(
+        StatusGrid::handle_event(&state, &Event::char('x'), &EventContext::default()),
+        None
+    );
+}
+
+// =============================================================================
+// Rendering
+// =============================================================================
+
+#[test]
+fn test_view() {
+    insta::assert_snapshot!(render(&state(), 20, 3));
+}
+
+#[test]
+fn test_view_clips_rows_to_height() {
+    let output = render(&state(), 7, 2);
+    assert_eq!(output, "● api  \n● auth ");
+}
+
+#[test]
+fn test_view_colors_dots_by_health() {
+    let (mut terminal, theme) = test_utils::setup_render(20, 2);
+    let state = state();
+    terminal
+        .draw(|frame| {
+            StatusGrid::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+    let backend = terminal.backend();
+    let dot = |x, y| backend.cell(x, y).unwrap().fg;
+    assert_eq!(
+        dot(0, 0),
+        theme.status_colors(StatusLevel::Success).1.into()
+    );
+    assert_eq!(
+        dot(9, 0),
+        theme.status_colors(StatusLevel::Warning).1.into()
+    );
+    assert_eq!(dot(0, 1), theme.status_colors(StatusLevel::Error).1.into());
+    assert_eq!(
+        dot(9, 1),
+        theme.status_colors(StatusLevel::Neutral).1.into()
+    );
+}
+
+#[test]
+fn test_view_annotation() {
+    use crate::annotation::{WidgetType, with_annotations};
+    let state = state();
+    let (mut terminal, theme) = test_utils::setup_render(20, 2);
+    let registry = with_annotations(|| {
+        terminal
+            .draw(|frame| {
+                StatusGrid::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+            })
+            .unwrap();
+    });
+    let regions = registry.find_by_type(&WidgetType::Custom("StatusGrid".into()));
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].annotation.value.as_deref(), Some("1/4 ok"));
+}
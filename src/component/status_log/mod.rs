@@ -713,7 +713,7 @@ impl Component for StatusLog {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Up | Key::Char('k') => Some(StatusLogMessage::ScrollUp),
                 Key::Down | Key::Char('j') => Some(StatusLogMessage::ScrollDown),
@@ -37,7 +37,7 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 
 use super::{Component, EventContext, RenderContext};
 use crate::input::{Event, Key};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// The status of a single step in a workflow.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -452,7 +452,7 @@ impl Component for StepIndicator {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Left | Key::Char('h') => Some(StepIndicatorMessage::FocusPrev),
                 Key::Right | Key::Char('l') => Some(StepIndicatorMessage::FocusNext),
@@ -515,7 +515,7 @@ fn step_style(
     index: usize,
     status: &StepStatus,
     is_focused_step: bool,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     step_overrides: &HashMap<usize, Style>,
     status_overrides: &HashMap<StepStatus, Style>,
 ) -> Style {
@@ -542,7 +542,7 @@ fn render_horizontal(
     state: &StepIndicatorState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
 ) {
     let mut spans = Vec::new();
@@ -580,7 +580,7 @@ fn render_vertical(
     state: &StepIndicatorState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
 ) {
     let mut lines = Vec::new();
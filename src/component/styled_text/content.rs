@@ -6,7 +6,7 @@
 use ratatui::prelude::*;
 use ratatui::text::{Line as RatLine, Span as RatSpan};
 
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// A block-level element in styled text content.
 #[derive(Clone, Debug, PartialEq)]
@@ -623,7 +623,11 @@ impl StyledContent {
     }
 
     /// Renders this content into ratatui `Line` objects for display.
-    pub(crate) fn render_lines(&self, width: u16, theme: &Theme) -> Vec<RatLine<'static>> {
+    pub(crate) fn render_lines(
+        &self,
+        width: u16,
+        theme: &dyn ThemeProvider,
+    ) -> Vec<RatLine<'static>> {
         self.render_lines_styled(width, theme, theme.normal_style())
     }
 
@@ -636,7 +640,7 @@ impl StyledContent {
     pub(crate) fn render_lines_styled(
         &self,
         width: u16,
-        theme: &Theme,
+        theme: &dyn ThemeProvider,
         base_style: Style,
     ) -> Vec<RatLine<'static>> {
         let mut lines = Vec::new();
@@ -650,7 +654,7 @@ impl StyledContent {
 fn render_block(
     block: &StyledBlock,
     width: u16,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     base_style: Style,
     lines: &mut Vec<RatLine<'static>>,
 ) {
@@ -723,7 +727,7 @@ fn render_block(
 
 fn render_line(
     inlines: &[StyledInline],
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     base_style: Style,
     lines: &mut Vec<RatLine<'static>>,
 ) {
@@ -734,7 +738,11 @@ fn render_line(
     lines.push(RatLine::from(spans));
 }
 
-fn render_inline(inline: &StyledInline, theme: &Theme, base_style: Style) -> RatSpan<'static> {
+fn render_inline(
+    inline: &StyledInline,
+    theme: &dyn ThemeProvider,
+    base_style: Style,
+) -> RatSpan<'static> {
     match inline {
         StyledInline::Code(text) => RatSpan::styled(
             text.clone(),
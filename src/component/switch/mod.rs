@@ -330,7 +330,7 @@ impl Component for Switch {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Enter | Key::Char(' ') => Some(SwitchMessage::Toggle),
                 _ => None,
@@ -33,7 +33,6 @@
 
 use super::{Component, EventContext, RenderContext};
 use crate::input::{Event, Key};
-use crate::theme::Theme;
 
 mod render;
 #[cfg(test)]
@@ -750,7 +749,7 @@ impl Component for TabBar {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Left | Key::Char('h') => Some(TabBarMessage::PrevTab),
                 Key::Right | Key::Char('l') => Some(TabBarMessage::NextTab),
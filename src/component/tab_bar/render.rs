@@ -7,13 +7,14 @@ use ratatui::widgets::Paragraph;
 use unicode_width::UnicodeWidthStr;
 
 use super::*;
+use crate::theme::ThemeProvider;
 
 /// Renders the tab bar into the given frame area.
 pub(super) fn render_tab_bar(
     state: &TabBarState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 ) {
@@ -0,0 +1,207 @@
+//! Content-based sizing for [`Column::auto`] columns.
+
+use ratatui::layout::Constraint;
+use unicode_width::UnicodeWidthStr;
+
+use super::render::{COLUMN_SPACING, HIGHLIGHT_SYMBOL_WIDTH, STATUS_COLUMN_WIDTH};
+use super::{Column, TableRow, TableState};
+
+/// Cells taken by the left and right border of the table block.
+const BORDER_WIDTH: u16 = 2;
+
+/// Display width of a sort arrow and the space before it.
+const SORT_ARROW_WIDTH: usize = 2;
+
+impl<T: TableRow> TableState<T> {
+    /// Sizes the [`Column::auto`] columns to fit their content.
+    ///
+    /// Each auto column is measured as the widest of its header (plus room
+    /// for a sort indicator if the column is sortable) and its cells across
+    /// all rows, then kept within its
+    /// [`width_bounds`](Column::width_bounds). `available_width` is the
+    /// width of the area the table renders into, including its border.
+    ///
+    /// Space left over after the on-screen columns is shared among the auto
+    /// columns in proportion to their width, unless a `Min` or `Fill`
+    /// column is on screen to take it. When the columns do not fit, auto
+    /// columns shrink in proportion to their width above the minimum.
+    ///
+    /// The result is stored as each column's [`Constraint::Length`] width,
+    /// so call this again after the rows or the area change.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Cell, Column, TableRow, TableState};
+    /// use ratatui::layout::Constraint;
+    ///
+    /// #[derive(Clone)]
+    /// struct Item { name: String }
+    /// impl TableRow for Item {
+    ///     fn cells(&self) -> Vec<Cell> { vec![Cell::new(&self.name)] }
+    /// }
+    ///
+    /// let mut state = TableState::new(
+    ///     vec![Item { name: "Alice".into() }, Item { name: "Bartholomew".into() }],
+    ///     vec![Column::auto("Name"), Column::min("Notes", 10)],
+    /// );
+    /// state.autosize_columns(80);
+    /// assert_eq!(state.columns()[0].width(), Constraint::Length(11));
+    /// ```
+    pub fn autosize_columns(&mut self, available_width: u16) {
+        if !self.columns.iter().any(Column::is_auto) {
+            return;
+        }
+
+        let natural: Vec<u16> = (0..self.columns.len())
+            .map(|i| self.natural_width(i))
+            .collect();
+
+        let rendered = self.rendered_columns();
+        let mut inner = available_width.saturating_sub(BORDER_WIDTH);
+        if self.selected.is_some() {
+            inner = inner.saturating_sub(HIGHLIGHT_SYMBOL_WIDTH);
+        }
+        let mut budget = inner;
+        if self.has_status_column() {
+            budget = budget.saturating_sub(STATUS_COLUMN_WIDTH + COLUMN_SPACING);
+        }
+        let gaps = u16::try_from(rendered.len().saturating_sub(1)).unwrap_or(u16::MAX);
+        budget = budget.saturating_sub(gaps.saturating_mul(COLUMN_SPACING));
+        let mut flexible = false;
+        for &i in rendered.iter().filter(|&&i| !self.columns[i].is_auto()) {
+            let width = self.columns[i].width();
+            flexible |= matches!(width, Constraint::Min(_) | Constraint::Fill(_));
+            budget = budget.saturating_sub(reserved_width(width, inner));
+        }
+
+        let on_screen: Vec<usize> = rendered
+            .into_iter()
+            .filter(|&i| self.columns[i].is_auto())
+            .collect();
+        let mut widths: Vec<u16> = on_screen.iter().map(|&i| natural[i]).collect();
+        let bounds: Vec<(u16, u16)> = on_screen
+            .iter()
+            .filter_map(|&i| self.columns[i].width_bounds())
+            .collect();
+        let total: usize = widths.iter().map(|&w| usize::from(w)).sum();
+        let budget = usize::from(budget);
+        if total < budget && !flexible {
+            let maxes: Vec<u16> = bounds.iter().map(|&(_, max)| max).collect();
+            grow(&mut widths, &maxes, budget - total);
+        } else if total > budget {
+            let mins: Vec<u16> = bounds.iter().map(|&(min, _)| min).collect();
+            shrink(&mut widths, &mins, total - budget);
+        }
+
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            if column.is_auto() {
+                let width = on_screen
+                    .iter()
+                    .position(|&j| j == i)
+                    .map_or(natural[i], |pos| widths[pos]);
+                column.set_width(Constraint::Length(width));
+            }
+        }
+    }
+
+    /// Returns the content width of an auto column within its bounds, or
+    /// zero for other columns.
+    fn natural_width(&self, col: usize) -> u16 {
+        let column = &self.columns[col];
+        let Some((min, max)) = column.width_bounds() else {
+            return 0;
+        };
+
+        let mut header = column.header().width();
+        if column.is_sortable() {
+            header += SORT_ARROW_WIDTH;
+            // Multi-sort adds the column's priority after the arrow
+            if self.sort_columns.len() > 1 && self.sort_columns.iter().any(|&(c, _)| c == col) {
+                header += self.sort_columns.len().to_string().len();
+            }
+        }
+        let cells = self
+            .rows
+            .iter()
+            .filter_map(|row| row.cells().get(col).map(|cell| cell.text().width()))
+            .max()
+            .unwrap_or(0);
+
+        u16::try_from(header.max(cells))
+            .unwrap_or(u16::MAX)
+            .clamp(min, max)
+    }
+}
+
+/// Returns the cells a non-auto column takes from the space left for auto
+/// columns.
+fn reserved_width(width: Constraint, inner: u16) -> u16 {
+    let share = |num: u64, den: u64| {
+        let cells = u64::from(inner) * num / den.max(1);
+        u16::try_from(cells).unwrap_or(u16::MAX).min(inner)
+    };
+    match width {
+        Constraint::Length(n) | Constraint::Min(n) => n,
+        Constraint::Percentage(p) => share(u64::from(p), 100),
+        Constraint::Ratio(num, den) => share(u64::from(num), u64::from(den)),
+        Constraint::Max(_) | Constraint::Fill(_) => 0,
+    }
+}
+
+/// Adds `amount` cells across `widths` in proportion to each width, without
+/// exceeding `maxes`.
+fn grow(widths: &mut [u16], maxes: &[u16], mut amount: usize) {
+    while amount > 0 {
+        let open: Vec<usize> = (0..widths.len())
+            .filter(|&i| widths[i] < maxes[i])
+            .collect();
+        if open.is_empty() {
+            break;
+        }
+        let total: usize = open.iter().map(|&i| usize::from(widths[i].max(1))).sum();
+        let mut given = 0;
+        for &i in &open {
+            let room = usize::from(maxes[i] - widths[i]);
+            let share = (amount * usize::from(widths[i].max(1)) / total)
+                .max(1)
+                .min(room)
+                .min(amount - given);
+            // `share` is at most `room`, which fits in a u16
+            widths[i] += share as u16;
+            given += share;
+            if given == amount {
+                break;
+            }
+        }
+        amount -= given;
+    }
+}
+
+/// Removes `amount` cells across `widths` in proportion to each width above
+/// its minimum, without going below `mins`.
+fn shrink(widths: &mut [u16], mins: &[u16], mut amount: usize) {
+    while amount > 0 {
+        let slack: usize = (0..widths.len())
+            .map(|i| usize::from(widths[i] - mins[i]))
+            .sum();
+        if slack == 0 {
+            break;
+        }
+        let mut taken = 0;
+        for i in 0..widths.len() {
+            let room = usize::from(widths[i] - mins[i]);
+            if room == 0 {
+                continue;
+            }
+            let share = (amount * room / slack).max(1).min(room).min(amount - taken);
+            // `share` is at most `room`, which fits in a u16
+            widths[i] -= share as u16;
+            taken += share;
+            if taken == amount {
+                break;
+            }
+        }
+        amount -= taken;
+    }
+}
@@ -0,0 +1,279 @@
+use super::*;
+use crate::component::cell::Cell;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Item {
+    name: String,
+    city: String,
+}
+
+impl Item {
+    fn new(name: &str, city: &str) -> Self {
+        Self {
+            name: name.into(),
+            city: city.into(),
+        }
+    }
+}
+
+impl TableRow for Item {
+    fn cells(&self) -> Vec<Cell> {
+        vec![Cell::new(&self.name), Cell::new(&self.city)]
+    }
+}
+
+fn items() -> Vec<Item> {
+    vec![
+        Item::new("Alice", "Oslo"),
+        Item::new("Bartholomew", "Rio"),
+        Item::new("Cy", "Kathmandu"),
+    ]
+}
+
+fn widths(state: &TableState<Item>) -> Vec<Constraint> {
+    state.columns().iter().map(Column::width).collect()
+}
+
+#[test]
+fn test_auto_column_defaults() {
+    let col = Column::auto("Name");
+    assert!(col.is_auto());
+    assert_eq!(col.width(), Constraint::Min(4));
+    assert_eq!(col.width_bounds(), Some((MIN_COLUMN_WIDTH, u16::MAX)));
+
+    // Narrow headers still get the minimum column width
+    assert_eq!(Column::auto("#").width(), Constraint::Min(MIN_COLUMN_WIDTH));
+}
+
+#[test]
+fn test_width_bounds_raise_max_to_min() {
+    let col = Column::fixed("Name", 10).with_width_bounds(8, 4);
+    assert!(col.is_auto());
+    assert_eq!(col.width_bounds(), Some((8, 8)));
+}
+
+#[test]
+fn test_autosize_fits_content() {
+    let mut state = TableState::new(
+        items(),
+        vec![
+            Column::auto("Name"),
+            Column::auto("City"),
+            Column::min("Notes", 5),
+        ],
+    );
+    state.autosize_columns(80);
+    assert_eq!(
+        widths(&state),
+        vec![
+            Constraint::Length(11),
+            Constraint::Length(9),
+            Constraint::Min(5),
+        ]
+    );
+}
+
+#[test]
+fn test_autosize_measures_header() {
+    let mut state = TableState::new(
+        vec![Item::new("Al", "X")],
+        vec![Column::auto("Full name"), Column::min("City", 5)],
+    );
+    state.autosize_columns(80);
+    assert_eq!(state.columns()[0].width(), Constraint::Length(9));
+}
+
+#[test]
+fn test_autosize_reserves_sort_indicator() {
+    let mut state = TableState::new(
+        vec![Item::new("Al", "X")],
+        vec![Column::auto("Full name").sortable(), Column::min("City", 5)],
+    );
+    state.autosize_columns(80);
+    assert_eq!(state.columns()[0].width(), Constraint::Length(11));
+}
+
+#[test]
+fn test_autosize_reserves_multi_sort_priority() {
+    let mut state = TableState::new(
+        vec![Item::new("Al", "X")],
+        vec![
+            Column::auto("Name").sortable(),
+            Column::auto("City").sortable(),
+            Column::min("Notes", 5),
+        ],
+    );
+    Table::<Item>::update(&mut state, TableMessage::SortAsc(0));
+    Table::<Item>::update(&mut state, TableMessage::AddSortAsc(1));
+    state.autosize_columns(80);
+    // "Name ↑1" and "City ↑2"
+    assert_eq!(state.columns()[0].width(), Constraint::Length(7));
+    assert_eq!(state.columns()[1].width(), Constraint::Length(7));
+}
+
+#[test]
+fn test_autosize_measures_wide_characters() {
+    let mut state = TableState::new(
+        vec![Item::new("日本語", "X")],
+        vec![Column::auto("N"), Column::min("City", 5)],
+    );
+    state.autosize_columns(80);
+    assert_eq!(state.columns()[0].width(), Constraint::Length(6));
+}
+
+#[test]
+fn test_autosize_respects_bounds() {
+    let mut state = TableState::new(
+        items(),
+        vec![
+            Column::auto("Name").with_width_bounds(3, 6),
+            Column::auto("City").with_width_bounds(12, 20),
+            Column::min("Notes", 5),
+        ],
+    );
+    state.autosize_columns(80);
+    assert_eq!(state.columns()[0].width(), Constraint::Length(6));
+    assert_eq!(state.columns()[1].width(), Constraint::Length(12));
+}
+
+#[test]
+fn test_autosize_distributes_leftover_proportionally() {
+    let mut state = TableState::new(items(), vec![Column::auto("Name"), Column::auto("City")]);
+    // 40 - 2 border - 2 highlight - 1 spacing = 35 cells for 11 + 9; the
+    // rounding remainder goes to the first column
+    state.autosize_columns(40);
+    assert_eq!(
+        widths(&state),
+        vec![Constraint::Length(20), Constraint::Length(15)]
+    );
+}
+
+#[test]
+fn test_autosize_leftover_stops_at_max() {
+    let mut state = TableState::new(
+        items(),
+        vec![
+            Column::auto("Name").with_width_bounds(3, 14),
+            Column::auto("City"),
+        ],
+    );
+    state.autosize_columns(40);
+    assert_eq!(
+        widths(&state),
+        vec![Constraint::Length(14), Constraint::Length(21)]
+    );
+}
+
+#[test]
+fn test_autosize_leaves_leftover_to_flexible_column() {
+    let mut state = TableState::new(
+        items(),
+        vec![
+            Column::auto("Name"),
+            Column::auto("City"),
+            Column::new("Notes", Constraint::Fill(1)),
+        ],
+    );
+    state.autosize_columns(80);
+    assert_eq!(state.columns()[0].width(), Constraint::Length(11));
+    assert_eq!(state.columns()[1].width(), Constraint::Length(9));
+}
+
+#[test]
+fn test_autosize_shrinks_to_fit() {
+    let mut state = TableState::new(items(), vec![Column::auto("Name"), Column::auto("City")]);
+    // 20 - 2 border - 2 highlight - 1 spacing = 15 cells for 11 + 9
+    state.autosize_columns(20);
+    let total: u16 = state
+        .columns()
+        .iter()
+        .map(|c| match c.width() {
+            Constraint::Length(w) => w,
+            other => panic!("expected a length, got {other:?}"),
+        })
+        .sum();
+    assert_eq!(total, 15);
+    assert_eq!(
+        widths(&state),
+        vec![Constraint::Length(8), Constraint::Length(7)]
+    );
+}
+
+#[test]
+fn test_autosize_never_shrinks_below_min() {
+    let mut state = TableState::new(
+        items(),
+        vec![
+            Column::auto("Name").with_width_bounds(10, 20),
+            Column::auto("City"),
+        ],
+    );
+    state.autosize_columns(10);
+    assert_eq!(
+        widths(&state),
+        vec![Constraint::Length(10), Constraint::Length(3)]
+    );
+}
+
+#[test]
+fn test_autosize_ignores_fixed_columns() {
+    let mut state = TableState::new(
+        items(),
+        vec![Column::fixed("Name", 4), Column::percent("City", 50)],
+    );
+    state.autosize_columns(80);
+    assert_eq!(
+        widths(&state),
+        vec![Constraint::Length(4), Constraint::Percentage(50)]
+    );
+}
+
+#[test]
+fn test_autosize_accounts_for_fixed_columns() {
+    let mut state = TableState::new(
+        items(),
+        vec![Column::auto("Name"), Column::fixed("City", 20)],
+    );
+    // 40 - 2 border - 2 highlight - 1 spacing - 20 = 15 cells for Name
+    state.autosize_columns(40);
+    assert_eq!(state.columns()[0].width(), Constraint::Length(15));
+}
+
+#[test]
+fn test_autosize_empty_table_uses_header() {
+    let mut state: TableState<Item> =
+        TableState::new(vec![], vec![Column::auto("Name"), Column::min("City", 5)]);
+    state.autosize_columns(80);
+    assert_eq!(state.columns()[0].width(), Constraint::Length(4));
+}
+
+#[test]
+fn test_autosized_column_can_be_resized() {
+    let mut state = TableState::new(items(), vec![Column::auto("Name"), Column::min("City", 5)]);
+    state.autosize_columns(80);
+    let output = Table::<Item>::update(&mut state, TableMessage::IncreaseColumnWidth(0));
+    assert_eq!(
+        output,
+        Some(TableOutput::ColumnResized {
+            column: 0,
+            width: 12
+        })
+    );
+}
+
+#[test]
+fn test_view_uses_autosized_widths() {
+    let mut state = TableState::new(items(), vec![Column::auto("Name"), Column::auto("City")]);
+    state.autosize_columns(30);
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 7);
+
+    terminal
+        .draw(|frame| {
+            Table::<Item>::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    let output = terminal.backend().to_string();
+    assert!(output.contains("Bartholomew"));
+    assert!(output.contains("Kathmandu"));
+}
@@ -0,0 +1,174 @@
+//! Inline cell editing for the Table component.
+//!
+//! Extracted from the main table module to keep file sizes manageable.
+
+use ratatui::layout::{Constraint, Layout, Position, Rect};
+use unicode_width::UnicodeWidthStr;
+
+use super::{TableMessage, TableOutput, TableRow, TableState};
+
+/// Width of the `"> "` highlight symbol reserved when a row is selected.
+const HIGHLIGHT_SYMBOL_WIDTH: u16 = 2;
+
+/// An in-progress cell edit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct TableEdit {
+    /// Index into the table's rows (not the display order).
+    pub(super) row: usize,
+    /// Column index of the cell being edited.
+    pub(super) column: usize,
+    /// The edit buffer. The cursor is always at the end.
+    pub(super) buffer: String,
+}
+
+impl<T: TableRow> TableState<T> {
+    /// Returns true if a cell is currently being edited.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Cell, Column, TableMessage, TableRow, TableState};
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Item { name: String }
+    /// impl TableRow for Item {
+    ///     fn cells(&self) -> Vec<Cell> { vec![Cell::new(&self.name)] }
+    /// }
+    ///
+    /// let mut state = TableState::new(
+    ///     vec![Item { name: "A".into() }],
+    ///     vec![Column::fixed("Name", 10)],
+    /// );
+    /// assert!(!state.is_editing());
+    /// state.update(TableMessage::BeginEdit(0));
+    /// assert!(state.is_editing());
+    /// ```
+    pub fn is_editing(&self) -> bool {
+        self.edit.is_some()
+    }
+
+    /// Returns the `(row, column)` of the cell being edited.
+    ///
+    /// The row is an index into [`rows()`](Self::rows), not the display order.
+    pub fn editing_cell(&self) -> Option<(usize, usize)> {
+        self.edit.as_ref().map(|edit| (edit.row, edit.column))
+    }
+
+    /// Returns the current contents of the edit buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Cell, Column, TableMessage, TableRow, TableState};
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Item { name: String }
+    /// impl TableRow for Item {
+    ///     fn cells(&self) -> Vec<Cell> { vec![Cell::new(&self.name)] }
+    /// }
+    ///
+    /// let mut state = TableState::new(
+    ///     vec![Item { name: "Bob".into() }],
+    ///     vec![Column::fixed("Name", 10)],
+    /// );
+    /// assert_eq!(state.edit_buffer(), None);
+    /// state.update(TableMessage::BeginEdit(0));
+    /// state.update(TableMessage::EditInsert('!'));
+    /// assert_eq!(state.edit_buffer(), Some("Bob!"));
+    /// ```
+    pub fn edit_buffer(&self) -> Option<&str> {
+        self.edit.as_ref().map(|edit| edit.buffer.as_str())
+    }
+
+    /// Handles a message while editing, or a `BeginEdit` message.
+    pub(super) fn update_edit(&mut self, msg: TableMessage) -> Option<TableOutput<T>> {
+        match msg {
+            TableMessage::BeginEdit(column) => {
+                if self.edit.is_some() {
+                    return None;
+                }
+                let row = self
+                    .selected
+                    .and_then(|i| self.display_order.get(i).copied())?;
+                if !self.columns.get(column)?.is_editable() {
+                    return None;
+                }
+                let buffer = self.rows[row]
+                    .cells()
+                    .get(column)
+                    .map(|cell| cell.text().to_string())
+                    .unwrap_or_default();
+                self.edit = Some(TableEdit {
+                    row,
+                    column,
+                    buffer,
+                });
+                None
+            }
+            TableMessage::EditInsert(c) => {
+                if let Some(edit) = &mut self.edit {
+                    edit.buffer.push(c);
+                }
+                None
+            }
+            TableMessage::EditBackspace => {
+                if let Some(edit) = &mut self.edit {
+                    edit.buffer.pop();
+                }
+                None
+            }
+            TableMessage::CommitEdit => self.edit.take().map(|edit| TableOutput::CellEdited {
+                row: edit.row,
+                col: edit.column,
+                value: edit.buffer,
+            }),
+            TableMessage::CancelEdit => self.edit.take().map(|_| TableOutput::EditCancelled),
+            // Other messages are ignored while a cell is being edited.
+            _ => None,
+        }
+    }
+
+    /// Returns the screen position of the edit cursor, if the edited cell
+    /// is visible.
+    ///
+    /// `area` is the data area (inside any border), `widths` and `rendered`
+    /// describe the rendered columns in order, and `offset` is the first
+    /// visible display row.
+    pub(super) fn edit_cursor_position(
+        &self,
+        area: Rect,
+        widths: &[Constraint],
+        rendered: &[usize],
+        offset: usize,
+    ) -> Option<Position> {
+        let edit = self.edit.as_ref()?;
+        let rendered_index = rendered.iter().position(|&col| col == edit.column)?;
+        let display_index = self.display_order.iter().position(|&i| i == edit.row)?;
+        let visible_row = display_index.checked_sub(offset)?;
+
+        let mut columns_area = area;
+        if self.selected.is_some() {
+            columns_area.x = columns_area.x.saturating_add(HIGHLIGHT_SYMBOL_WIDTH);
+            columns_area.width = columns_area.width.saturating_sub(HIGHLIGHT_SYMBOL_WIDTH);
+        }
+        let rects = Layout::horizontal(widths.iter().copied())
+            .spacing(1)
+            .split(columns_area);
+        let cell_rect = rects.get(rendered_index + self.has_status_column() as usize)?;
+        if cell_rect.width == 0 {
+            return None;
+        }
+
+        // Header row plus its bottom margin precede the data rows.
+        let y = area.y + 2 + u16::try_from(visible_row).ok()?;
+        if y >= area.bottom() {
+            return None;
+        }
+        let text_width = u16::try_from(edit.buffer.width()).unwrap_or(u16::MAX);
+        let x = cell_rect
+            .x
+            .saturating_add(text_width)
+            .min(cell_rect.right() - 1);
+        Some(Position::new(x, y))
+    }
+}
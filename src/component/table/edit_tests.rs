@@ -0,0 +1,211 @@
+use super::*;
+use crate::input::Event;
+
+// Test row type
+#[derive(Clone, Debug, PartialEq)]
+struct TestRow {
+    name: String,
+    value: String,
+}
+
+impl TestRow {
+    fn new(name: &str, value: &str) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl TableRow for TestRow {
+    fn cells(&self) -> Vec<crate::component::cell::Cell> {
+        use crate::component::cell::Cell;
+        vec![Cell::new(&self.name), Cell::new(&self.value)]
+    }
+}
+
+fn test_columns() -> Vec<Column> {
+    vec![
+        Column::new("Name", Constraint::Length(10)).sortable(),
+        Column::new("Value", Constraint::Length(10)),
+    ]
+}
+
+fn test_rows() -> Vec<TestRow> {
+    vec![
+        TestRow::new("Charlie", "30"),
+        TestRow::new("Alice", "10"),
+        TestRow::new("Bob", "20"),
+    ]
+}
+
+// ========== Editing Tests ==========
+
+#[test]
+fn test_begin_edit_prefills_buffer() {
+    let mut state = TableState::new(test_rows(), test_columns());
+    state.set_selected(Some(1));
+    let output = Table::<TestRow>::update(&mut state, TableMessage::BeginEdit(1));
+    assert_eq!(output, None);
+    assert!(state.is_editing());
+    assert_eq!(state.editing_cell(), Some((1, 1)));
+    assert_eq!(state.edit_buffer(), Some("10"));
+}
+
+#[test]
+fn test_begin_edit_requires_editable_column() {
+    let columns = vec![
+        Column::new("Name", Constraint::Length(10)).with_editable(false),
+        Column::new("Value", Constraint::Length(10)),
+    ];
+    let mut state = TableState::new(test_rows(), columns);
+    Table::<TestRow>::update(&mut state, TableMessage::BeginEdit(0));
+    assert!(!state.is_editing());
+    Table::<TestRow>::update(&mut state, TableMessage::BeginEdit(5));
+    assert!(!state.is_editing());
+}
+
+#[test]
+fn test_begin_edit_requires_selection() {
+    let mut state: TableState<TestRow> = TableState::new(vec![], test_columns());
+    Table::<TestRow>::update(&mut state, TableMessage::BeginEdit(0));
+    assert!(!state.is_editing());
+}
+
+#[test]
+fn test_commit_edit_emits_cell_edited() {
+    let mut state = TableState::new(test_rows(), test_columns());
+    Table::<TestRow>::update(&mut state, TableMessage::BeginEdit(1));
+    Table::<TestRow>::update(&mut state, TableMessage::EditBackspace);
+    Table::<TestRow>::update(&mut state, TableMessage::EditInsert('5'));
+    assert_eq!(state.edit_buffer(), Some("35"));
+
+    let output = Table::<TestRow>::update(&mut state, TableMessage::CommitEdit);
+    assert_eq!(
+        output,
+        Some(TableOutput::CellEdited {
+            row: 0,
+            col: 1,
+            value: "35".into(),
+        })
+    );
+    assert!(!state.is_editing());
+    // Rows are untouched; the app applies the edit.
+    assert_eq!(state.rows()[0].value, "30");
+}
+
+#[test]
+fn test_commit_edit_reports_original_row_index_when_sorted() {
+    let mut state = TableState::new(test_rows(), test_columns());
+    Table::<TestRow>::update(&mut state, TableMessage::SortAsc(0));
+    // Display order is Alice, Bob, Charlie; Alice is row 1.
+    state.set_selected(Some(0));
+    Table::<TestRow>::update(&mut state, TableMessage::BeginEdit(0));
+    assert_eq!(state.edit_buffer(), Some("Alice"));
+    let output = Table::<TestRow>::update(&mut state, TableMessage::CommitEdit);
+    assert_eq!(
+        output,
+        Some(TableOutput::CellEdited {
+            row: 1,
+            col: 0,
+            value: "Alice".into(),
+        })
+    );
+}
+
+#[test]
+fn test_cancel_edit() {
+    let mut state = TableState::new(test_rows(), test_columns());
+    Table::<TestRow>::update(&mut state, TableMessage::BeginEdit(0));
+    Table::<TestRow>::update(&mut state, TableMessage::EditInsert('x'));
+    let output = Table::<TestRow>::update(&mut state, TableMessage::CancelEdit);
+    assert_eq!(output, Some(TableOutput::EditCancelled));
+    assert!(!state.is_editing());
+    assert_eq!(state.edit_buffer(), None);
+}
+
+#[test]
+fn test_edit_messages_without_edit_are_noops() {
+    let mut state = TableState::new(test_rows(), test_columns());
+    assert_eq!(
+        Table::<TestRow>::update(&mut state, TableMessage::EditInsert('x')),
+        None
+    );
+    assert_eq!(
+        Table::<TestRow>::update(&mut state, TableMessage::CommitEdit),
+        None
+    );
+    assert_eq!(
+        Table::<TestRow>::update(&mut state, TableMessage::CancelEdit),
+        None
+    );
+}
+
+#[test]
+fn test_navigation_ignored_while_editing() {
+    let mut state = TableState::new(test_rows(), test_columns());
+    Table::<TestRow>::update(&mut state, TableMessage::BeginEdit(0));
+    let output = Table::<TestRow>::update(&mut state, TableMessage::Down);
+    assert_eq!(output, None);
+    assert_eq!(state.selected_index(), Some(0));
+    assert!(state.is_editing());
+}
+
+#[test]
+fn test_set_rows_discards_edit() {
+    let mut state = TableState::new(test_rows(), test_columns());
+    Table::<TestRow>::update(&mut state, TableMessage::BeginEdit(0));
+    state.set_rows(test_rows());
+    assert!(!state.is_editing());
+}
+
+#[test]
+fn test_handle_event_while_editing() {
+    let mut state = TableState::new(test_rows(), test_columns());
+    Table::<TestRow>::update(&mut state, TableMessage::BeginEdit(0));
+    let ctx = EventContext::new().focused(true);
+
+    assert_eq!(
+        Table::<TestRow>::handle_event(&state, &Event::char('j'), &ctx),
+        Some(TableMessage::EditInsert('j'))
+    );
+    assert_eq!(
+        Table::<TestRow>::handle_event(&state, &Event::key(Key::Backspace), &ctx),
+        Some(TableMessage::EditBackspace)
+    );
+    assert_eq!(
+        Table::<TestRow>::handle_event(&state, &Event::key(Key::Enter), &ctx),
+        Some(TableMessage::CommitEdit)
+    );
+    assert_eq!(
+        Table::<TestRow>::handle_event(&state, &Event::key(Key::Esc), &ctx),
+        Some(TableMessage::CancelEdit)
+    );
+}
+
+#[test]
+fn test_view_renders_edit_buffer_and_cursor() {
+    let mut state = TableState::new(test_rows(), test_columns());
+    state.set_selected(Some(1));
+    Table::<TestRow>::update(&mut state, TableMessage::BeginEdit(0));
+    Table::<TestRow>::update(&mut state, TableMessage::EditInsert('!'));
+
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(40, 10);
+    terminal
+        .draw(|frame| {
+            Table::<TestRow>::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &theme).focused(true),
+            );
+        })
+        .unwrap();
+
+    let output = terminal.backend().to_string();
+    assert!(output.contains("Alice!"));
+
+    // Border (1) + highlight symbol (2) + "Alice!" (6); header + margin above.
+    let cursor = terminal.get_cursor_position().unwrap();
+    assert_eq!((cursor.x, cursor.y), (9, 4));
+    let cell = terminal.backend().cell(3, 4).unwrap();
+    assert!(cell.style().add_modifier.contains(Modifier::UNDERLINED));
+}
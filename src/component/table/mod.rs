@@ -5,7 +5,8 @@
 //! [`TableState<T>`], updated via [`TableMessage`], and produces [`TableOutput`].
 //!
 //!
-//! See also [`DataGrid`](super::DataGrid) for a table with inline cell editing.
+//! See also [`DataGrid`](super::DataGrid) for a grid with a column cursor and a
+//! full inline cell editor.
 //!
 //! # Example
 //!
@@ -54,7 +55,9 @@
 //! }));
 //! ```
 
+mod autosize;
 mod clip_warn;
+mod edit;
 mod render;
 mod state;
 mod types;
@@ -64,23 +67,27 @@ pub use types::{Column, InitialSort, SortDirection, TableMessage, TableOutput, T
 use std::cell::RefCell;
 use std::collections::HashSet;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use clip_warn::ClipWarnState;
+use edit::TableEdit;
 
 use ratatui::prelude::*;
 
 use super::{Component, EventContext, RenderContext};
 use crate::input::{Event, Key};
 use crate::scroll::ScrollState;
-use crate::theme::Theme;
 
 /// Minimum column width in characters for column resizing.
 const MIN_COLUMN_WIDTH: u16 = 3;
 
+/// A row style function that takes a row and returns a style override, or
+/// `None` to keep the default row style.
+type RowStyleFn<T> = dyn Fn(&T) -> Option<Style> + Send + Sync;
+
 /// State for a Table component.
 ///
 /// Holds the rows, columns, selection state, and sort configuration.
-#[derive(Clone, Debug)]
 #[cfg_attr(
     feature = "serialization",
     derive(serde::Serialize, serde::Deserialize)
@@ -112,6 +119,58 @@ pub struct TableState<T: TableRow> {
     /// serialized.
     #[cfg_attr(feature = "serialization", serde(skip))]
     clip_warn_state: RefCell<ClipWarnState>,
+    /// The in-progress cell edit, if any.
+    #[cfg_attr(feature = "serialization", serde(skip))]
+    edit: Option<TableEdit>,
+    /// How many unpinned columns are scrolled out of view on the left.
+    column_offset: usize,
+    /// Whether alternate rows get a subtle background.
+    zebra: bool,
+    /// Per-row style override computed from the row data.
+    /// Wrapped in `Arc` so that `TableState` stays `Clone`. The explicit
+    /// `default` keeps serde from requiring `T: Default`.
+    #[cfg_attr(feature = "serialization", serde(skip, default = "Option::default"))]
+    row_style_fn: Option<Arc<RowStyleFn<T>>>,
+}
+
+impl<T: TableRow> Clone for TableState<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows.clone(),
+            columns: self.columns.clone(),
+            selected: self.selected,
+            sort_columns: self.sort_columns.clone(),
+            display_order: self.display_order.clone(),
+            filter_text: self.filter_text.clone(),
+            scroll: self.scroll.clone(),
+            cross_variant_warned_cols: self.cross_variant_warned_cols.clone(),
+            clip_warn_state: self.clip_warn_state.clone(),
+            edit: self.edit.clone(),
+            column_offset: self.column_offset,
+            zebra: self.zebra,
+            row_style_fn: self.row_style_fn.clone(),
+        }
+    }
+}
+
+impl<T: TableRow + std::fmt::Debug> std::fmt::Debug for TableState<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableState")
+            .field("rows", &self.rows)
+            .field("columns", &self.columns)
+            .field("selected", &self.selected)
+            .field("sort_columns", &self.sort_columns)
+            .field("display_order", &self.display_order)
+            .field("filter_text", &self.filter_text)
+            .field("scroll", &self.scroll)
+            .field("cross_variant_warned_cols", &self.cross_variant_warned_cols)
+            .field("clip_warn_state", &self.clip_warn_state)
+            .field("edit", &self.edit)
+            .field("column_offset", &self.column_offset)
+            .field("zebra", &self.zebra)
+            .field("row_style_fn", &self.row_style_fn.as_ref().map(|_| "..."))
+            .finish()
+    }
 }
 
 impl<T: TableRow + PartialEq> PartialEq for TableState<T> {
@@ -119,13 +178,17 @@ impl<T: TableRow + PartialEq> PartialEq for TableState<T> {
         // `cross_variant_warned_cols` and `clip_warn_state` are
         // intentionally excluded — both are transient diagnostics state
         // (sort-pass and render-pass respectively), not part of the
-        // logical equality of the table.
+        // logical equality of the table. `row_style_fn` is excluded
+        // because closures cannot be compared.
         self.rows == other.rows
             && self.columns == other.columns
             && self.selected == other.selected
             && self.sort_columns == other.sort_columns
             && self.display_order == other.display_order
             && self.filter_text == other.filter_text
+            && self.edit == other.edit
+            && self.column_offset == other.column_offset
+            && self.zebra == other.zebra
     }
 }
 
@@ -141,6 +204,10 @@ impl<T: TableRow> Default for TableState<T> {
             scroll: ScrollState::default(),
             cross_variant_warned_cols: HashSet::new(),
             clip_warn_state: RefCell::new(ClipWarnState::default()),
+            edit: None,
+            column_offset: 0,
+            zebra: false,
+            row_style_fn: None,
         }
     }
 }
@@ -161,9 +228,29 @@ impl<T: TableRow> Default for TableState<T> {
 /// - `First` / `Last` - Jump to beginning/end
 /// - `PageUp` / `PageDown` - Move by page size
 /// - `Select` - Confirm the current selection
+/// - `ScrollLeft` / `ScrollRight` - Scroll the unpinned columns horizontally
 /// - `SortAsc(column)` / `SortDesc(column)` / `SortToggle(column)` - Sort by the given column
 /// - `SortClear` - Clear the current sort
 ///
+/// # Editing
+///
+/// Send `BeginEdit(column)` to edit a cell of the selected row in place.
+/// While editing, typed characters go to the edit buffer, `Enter` commits
+/// the edit with [`TableOutput::CellEdited`], and `Esc` cancels it. Other
+/// messages are ignored until the edit ends.
+///
+/// # Pinned columns
+///
+/// Columns marked with [`Column::pinned`] are rendered first and never
+/// scroll; the remaining columns scroll horizontally in the space to their
+/// right. The selected row is highlighted across both regions.
+///
+/// # Row styling
+///
+/// [`TableState::with_zebra`] stripes alternate rows with a subtle
+/// background, and [`TableState::with_row_style_fn`] styles individual rows
+/// from their data. The selection highlight takes precedence over both.
+///
 /// # Sorting
 ///
 /// `SortToggle` flips Ascending <-> Descending without clearing.
@@ -218,6 +305,10 @@ impl<T: TableRow + 'static> Component for Table<T> {
     }
 
     fn update(state: &mut Self::State, msg: Self::Message) -> Option<Self::Output> {
+        if state.is_editing() || matches!(msg, TableMessage::BeginEdit(_)) {
+            return state.update_edit(msg);
+        }
+
         match msg {
             TableMessage::SetFilter(ref text) => {
                 state.set_filter_text(text);
@@ -284,6 +375,12 @@ impl<T: TableRow + 'static> Component for Table<T> {
                     return Some(TableOutput::Selected(row));
                 }
             }
+            TableMessage::ScrollLeft => {
+                state.set_column_offset(state.column_offset.saturating_sub(1));
+            }
+            TableMessage::ScrollRight => {
+                state.set_column_offset(state.column_offset + 1);
+            }
             TableMessage::IncreaseColumnWidth(col) => {
                 if let Some(column) = state.columns.get_mut(col) {
                     if let Constraint::Length(w) = column.width() {
@@ -313,6 +410,13 @@ impl<T: TableRow + 'static> Component for Table<T> {
             TableMessage::SetFilter(_) | TableMessage::ClearFilter => {
                 unreachable!("handled above")
             }
+            TableMessage::BeginEdit(_)
+            | TableMessage::EditInsert(_)
+            | TableMessage::EditBackspace
+            | TableMessage::CommitEdit
+            | TableMessage::CancelEdit => {
+                // Edit messages without an active edit are no-ops.
+            }
             TableMessage::SortAsc(col) => {
                 if let Some(column) = state.columns.get(col) {
                     if !column.is_sortable() {
@@ -473,11 +577,22 @@ impl<T: TableRow + 'static> Component for Table<T> {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
+            if state.is_editing() {
+                return match key.code {
+                    Key::Enter => Some(TableMessage::CommitEdit),
+                    Key::Esc => Some(TableMessage::CancelEdit),
+                    Key::Backspace => Some(TableMessage::EditBackspace),
+                    Key::Char(_) => key.raw_char.map(TableMessage::EditInsert),
+                    _ => None,
+                };
+            }
             let has_shift = key.modifiers.shift();
             match key.code {
                 Key::Up | Key::Char('k') => Some(TableMessage::Up),
                 Key::Down | Key::Char('j') => Some(TableMessage::Down),
+                Key::Left | Key::Char('h') => Some(TableMessage::ScrollLeft),
+                Key::Right | Key::Char('l') => Some(TableMessage::ScrollRight),
                 Key::Home => Some(TableMessage::First),
                 Key::End => Some(TableMessage::Last),
                 Key::Enter if has_shift => {
@@ -517,6 +632,10 @@ impl<T: TableRow + 'static> Component for Table<T> {
     }
 }
 
+#[cfg(test)]
+mod autosize_tests;
+#[cfg(test)]
+mod edit_tests;
 #[cfg(test)]
 mod filter_tests;
 #[cfg(test)]
@@ -524,6 +643,8 @@ mod multi_sort_tests;
 #[cfg(test)]
 mod resize_tests;
 #[cfg(test)]
+mod scroll_tests;
+#[cfg(test)]
 mod snapshot_tests;
 #[cfg(test)]
 mod sort_proptests;
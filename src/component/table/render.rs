@@ -4,10 +4,21 @@
 
 use ratatui::prelude::*;
 use ratatui::style::Color;
-use ratatui::widgets::{Block, Borders, Cell as RatatuiCell, Row};
+use ratatui::widgets::{Cell as RatatuiCell, Row};
 
 use super::*;
 use crate::component::cell::CellStyle;
+use crate::theme::{NamedColor, ThemeProvider};
+
+/// Display width of the `"> "` highlight symbol, reserved by ratatui while a
+/// row is selected.
+pub(super) const HIGHLIGHT_SYMBOL_WIDTH: u16 = 2;
+
+/// Cells between columns; ratatui's `Table` default, which is not overridden.
+pub(super) const COLUMN_SPACING: u16 = 1;
+
+/// Width of the row-status indicator column.
+pub(super) const STATUS_COLUMN_WIDTH: u16 = 2;
 
 /// Identifies columns whose declared lower-bound width constraint was
 /// violated by the resolved layout.
@@ -76,7 +87,7 @@ pub(crate) fn detect_clipped_columns(
 /// rendering maps the semantic variants to the theme's success/warning/
 /// error styles where available, with a hardcoded dark-gray for `Muted`
 /// (the theme does not expose a dedicated muted accessor at present).
-fn cell_style_to_ratatui(style: &CellStyle, theme: &Theme, disabled: bool) -> Style {
+fn cell_style_to_ratatui(style: &CellStyle, theme: &dyn ThemeProvider, disabled: bool) -> Style {
     if disabled {
         return Style::default().fg(Color::DarkGray);
     }
@@ -101,7 +112,7 @@ pub(super) fn render_table<T: TableRow>(
     state: &TableState<T>,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -117,6 +128,7 @@ pub(super) fn render_table<T: TableRow>(
     });
 
     let has_status = state.has_status_column();
+    let rendered = state.rendered_columns();
 
     let header_style = if disabled {
         theme.disabled_style()
@@ -130,7 +142,8 @@ pub(super) fn render_table<T: TableRow>(
     if has_status {
         header_cells.push(RatatuiCell::from(""));
     }
-    for (i, col) in state.columns.iter().enumerate() {
+    for &i in &rendered {
+        let col = &state.columns[i];
         let mut text = col.header().to_string();
         if let Some(pos) = state.sort_columns.iter().position(|&(c, _)| c == i) {
             let (_, dir) = state.sort_columns[pos];
@@ -154,12 +167,20 @@ pub(super) fn render_table<T: TableRow>(
 
     let header = Row::new(header_cells).style(header_style).bottom_margin(1);
 
+    let edit_style = if disabled {
+        theme.disabled_style()
+    } else {
+        theme.focused_style().add_modifier(Modifier::UNDERLINED)
+    };
+
     // Build data rows using display_order, applying per-cell styling and
     // optionally prepending the row-status indicator cell.
+    let zebra_style = Style::default().bg(theme.color(NamedColor::Surface0));
     let rows: Vec<Row> = state
         .display_order
         .iter()
-        .map(|&idx| {
+        .enumerate()
+        .map(|(position, &idx)| {
             let row = &state.rows[idx];
             let row_cells = row.cells();
             let mut cells: Vec<RatatuiCell> = Vec::with_capacity(row_cells.len() + 1);
@@ -178,21 +199,45 @@ pub(super) fn render_table<T: TableRow>(
                 }
             }
 
-            for cell in row_cells {
-                let style = cell_style_to_ratatui(cell.style(), theme, disabled);
-                cells.push(RatatuiCell::from(cell.text().to_string()).style(style));
+            let editing = state
+                .edit
+                .as_ref()
+                .filter(|edit| edit.row == idx)
+                .map(|edit| (edit.column, edit.buffer.as_str()));
+            for &col in &rendered {
+                match (editing, row_cells.get(col)) {
+                    (Some((edit_col, buffer)), _) if edit_col == col => {
+                        cells.push(RatatuiCell::from(buffer.to_string()).style(edit_style));
+                    }
+                    (_, Some(cell)) => {
+                        let style = cell_style_to_ratatui(cell.style(), theme, disabled);
+                        cells.push(RatatuiCell::from(cell.text().to_string()).style(style));
+                    }
+                    (_, None) => cells.push(RatatuiCell::from("")),
+                }
             }
 
-            Row::new(cells)
+            // The selected row is left unstyled so the highlight fully wins.
+            let row_style = if disabled || state.selected == Some(position) {
+                None
+            } else {
+                state
+                    .row_style(idx)
+                    .or_else(|| (state.zebra && position % 2 == 1).then_some(zebra_style))
+            };
+            match row_style {
+                Some(style) => Row::new(cells).style(style),
+                None => Row::new(cells),
+            }
         })
         .collect();
 
     let mut widths: Vec<Constraint> = Vec::new();
     if has_status {
-        widths.push(Constraint::Length(2));
+        widths.push(Constraint::Length(STATUS_COLUMN_WIDTH));
     }
-    for col in state.columns.iter() {
-        widths.push(col.width());
+    for &i in &rendered {
+        widths.push(state.columns[i].width());
     }
 
     // Best-effort clip-warning diagnostic: compute resolved column
@@ -204,8 +249,8 @@ pub(super) fn render_table<T: TableRow>(
     // per (column index, area width) across the TableState's
     // lifetime, and emits a tracing warning on first detection.
     //
-    // Skipped entirely when the table has no user columns.
-    if !state.columns.is_empty() {
+    // Skipped entirely when the table has no rendered user columns.
+    if !rendered.is_empty() {
         // Mirror the full ratatui 0.29 Table width formula so detection
         // matches what the renderer actually distributes columns over.
         // Every term below corresponds to a row in the spec's "Canonical
@@ -231,8 +276,6 @@ pub(super) fn render_table<T: TableRow>(
         //
         // Flex::Start is the default for both Table and Layout::horizontal
         // — no explicit .flex(...) call needed.
-        const HIGHLIGHT_SYMBOL_WIDTH: u16 = 2; // matches "> " set at render.rs:153
-        const COLUMN_SPACING: u16 = 1; // matches ratatui Table default; render.rs:150-153 sets no override
         let mut col_dist_area = if chrome_owned {
             area
         } else {
@@ -248,13 +291,21 @@ pub(super) fn render_table<T: TableRow>(
             .spacing(COLUMN_SPACING)
             .split(col_dist_area);
         // Skip the status reservation when mapping back to user columns.
-        // resolved_rects[has_status as usize..] aligns 1:1 with state.columns.
+        // resolved_rects[has_status as usize..] aligns 1:1 with `rendered`.
         let user_resolved: Vec<u16> = resolved_rects
             .iter()
             .skip(has_status as usize)
             .map(|r| r.width)
             .collect();
-        let clipped = detect_clipped_columns(state.columns.as_slice(), &user_resolved);
+        let rendered_columns: Vec<Column> =
+            rendered.iter().map(|&i| state.columns[i].clone()).collect();
+        let clipped: Vec<ClippedColumn> = detect_clipped_columns(&rendered_columns, &user_resolved)
+            .into_iter()
+            .map(|clip| ClippedColumn {
+                idx: rendered[clip.idx],
+                ..clip
+            })
+            .collect();
 
         // Always track area width so resize re-arms detection, even
         // when the current render has no clipped columns.
@@ -286,19 +337,13 @@ pub(super) fn render_table<T: TableRow>(
         }
     }
 
-    let border_style = if focused && !disabled {
-        theme.focused_border_style()
-    } else {
-        theme.border_style()
-    };
-
     let row_highlight_style = if disabled {
         theme.disabled_style()
     } else {
         theme.selected_highlight_style(focused)
     };
 
-    let table_widget = ratatui::widgets::Table::new(rows, widths)
+    let table_widget = ratatui::widgets::Table::new(rows, widths.clone())
         .header(header)
         .row_highlight_style(row_highlight_style)
         .highlight_symbol("> ");
@@ -306,11 +351,7 @@ pub(super) fn render_table<T: TableRow>(
     let table_widget = if chrome_owned {
         table_widget
     } else {
-        table_widget.block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(border_style),
-        )
+        table_widget.block(theme.focused_block(focused && !disabled))
     };
 
     // Use TableState for stateful rendering
@@ -326,6 +367,15 @@ pub(super) fn render_table<T: TableRow>(
     } else {
         (area.inner(Margin::new(1, 1)), 2) // header row + bottom margin
     };
+
+    if focused && !disabled {
+        if let Some(position) =
+            state.edit_cursor_position(inner, &widths, &rendered, table_state.offset())
+        {
+            frame.set_cursor_position(position);
+        }
+    }
+
     let data_viewport = (inner.height as usize).saturating_sub(viewport_offset);
     if data_viewport > 0 && state.display_order.len() > data_viewport {
         let mut bar_scroll = ScrollState::new(state.display_order.len());
@@ -0,0 +1,161 @@
+use super::*;
+use crate::component::cell::Cell;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Host {
+    id: u32,
+    name: &'static str,
+    region: &'static str,
+    owner: &'static str,
+}
+
+impl TableRow for Host {
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            Cell::uint(self.id as u64),
+            Cell::new(self.name),
+            Cell::new(self.region),
+            Cell::new(self.owner),
+        ]
+    }
+}
+
+fn hosts() -> Vec<Host> {
+    vec![
+        Host {
+            id: 1,
+            name: "alpha",
+            region: "us-east",
+            owner: "ops",
+        },
+        Host {
+            id: 2,
+            name: "beta",
+            region: "eu-west",
+            owner: "data",
+        },
+    ]
+}
+
+fn columns() -> Vec<Column> {
+    vec![
+        Column::fixed("ID", 4).pinned(),
+        Column::fixed("Name", 8),
+        Column::fixed("Region", 9),
+        Column::fixed("Owner", 6),
+    ]
+}
+
+fn render(state: &TableState<Host>, width: u16) -> String {
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(width, 6);
+    terminal
+        .draw(|frame| {
+            Table::<Host>::view(
+                state,
+                &mut RenderContext::new(frame, frame.area(), &theme).focused(true),
+            );
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn test_pinned_defaults_to_false() {
+    assert!(!Column::fixed("Name", 8).is_pinned());
+    assert!(Column::fixed("ID", 4).pinned().is_pinned());
+}
+
+#[test]
+fn test_scroll_right_and_left() {
+    let mut state = TableState::new(hosts(), columns());
+    assert_eq!(state.column_offset(), 0);
+
+    assert_eq!(
+        Table::<Host>::update(&mut state, TableMessage::ScrollRight),
+        None
+    );
+    assert_eq!(state.column_offset(), 1);
+
+    Table::<Host>::update(&mut state, TableMessage::ScrollLeft);
+    Table::<Host>::update(&mut state, TableMessage::ScrollLeft);
+    assert_eq!(state.column_offset(), 0);
+}
+
+#[test]
+fn test_scroll_right_keeps_last_unpinned_column() {
+    let mut state = TableState::new(hosts(), columns());
+    for _ in 0..10 {
+        Table::<Host>::update(&mut state, TableMessage::ScrollRight);
+    }
+    assert_eq!(state.column_offset(), 2);
+}
+
+#[test]
+fn test_pinned_column_stays_while_scrolling() {
+    let mut state = TableState::new(hosts(), columns());
+    let before = render(&state, 28);
+    assert!(before.contains("ID"));
+    assert!(before.contains("Name"));
+    assert!(before.contains("alpha"));
+
+    Table::<Host>::update(&mut state, TableMessage::ScrollRight);
+    Table::<Host>::update(&mut state, TableMessage::ScrollRight);
+    let after = render(&state, 28);
+    assert!(after.contains("ID"));
+    assert!(after.contains("1"));
+    assert!(!after.contains("Name"));
+    assert!(!after.contains("alpha"));
+    assert!(!after.contains("Region"));
+    assert!(after.contains("Owner"));
+    assert!(after.contains("ops"));
+}
+
+#[test]
+fn test_pinned_columns_render_first() {
+    let columns = vec![
+        Column::fixed("Name", 8),
+        Column::fixed("ID", 4).pinned(),
+        Column::fixed("Region", 9),
+    ];
+    let state = TableState::new(hosts(), columns);
+    let output = render(&state, 40);
+    let header = output.lines().nth(1).unwrap();
+    assert!(header.find("ID").unwrap() < header.find("Name").unwrap());
+}
+
+#[test]
+fn test_selection_highlight_spans_pinned_and_scrolled_columns() {
+    let mut state = TableState::new(hosts(), columns());
+    Table::<Host>::update(&mut state, TableMessage::ScrollRight);
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(28, 6);
+    terminal
+        .draw(|frame| {
+            Table::<Host>::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &theme).focused(true),
+            );
+        })
+        .unwrap();
+
+    let highlight = theme.selected_highlight_style(true);
+    let backend = terminal.backend();
+    // Row 3 is the first data row: border, header, header margin.
+    let pinned = backend.cell(3, 3).unwrap();
+    let scrolled = backend.cell(10, 3).unwrap();
+    assert_eq!(pinned.bg, highlight.bg.unwrap().into());
+    assert_eq!(scrolled.bg, highlight.bg.unwrap().into());
+}
+
+#[test]
+fn test_handle_event_left_right_scroll() {
+    let state = TableState::new(hosts(), columns());
+    let ctx = EventContext::new().focused(true);
+    assert_eq!(
+        Table::<Host>::handle_event(&state, &Event::key(Key::Right), &ctx),
+        Some(TableMessage::ScrollRight)
+    );
+    assert_eq!(
+        Table::<Host>::handle_event(&state, &Event::char('h'), &ctx),
+        Some(TableMessage::ScrollLeft)
+    );
+}
@@ -4,6 +4,9 @@
 
 use std::cell::RefCell;
 use std::collections::HashSet;
+use std::sync::Arc;
+
+use ratatui::style::Style;
 
 use super::{
     Column, InitialSort, SortDirection, Table, TableMessage, TableOutput, TableRow, TableState,
@@ -54,6 +57,10 @@ impl<T: TableRow> TableState<T> {
             scroll,
             cross_variant_warned_cols: HashSet::new(),
             clip_warn_state: RefCell::new(ClipWarnState::default()),
+            edit: None,
+            column_offset: 0,
+            zebra: false,
+            row_style_fn: None,
         }
     }
 
@@ -98,6 +105,10 @@ impl<T: TableRow> TableState<T> {
             scroll,
             cross_variant_warned_cols: HashSet::new(),
             clip_warn_state: RefCell::new(ClipWarnState::default()),
+            edit: None,
+            column_offset: 0,
+            zebra: false,
+            row_style_fn: None,
         }
     }
 
@@ -174,6 +185,144 @@ impl<T: TableRow> TableState<T> {
         self
     }
 
+    /// Enables alternating row (zebra) striping (builder pattern).
+    ///
+    /// Every other displayed row gets a subtle background so wide rows are
+    /// easier to follow. The selection highlight still takes precedence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Cell, Column, TableRow, TableState};
+    /// use ratatui::layout::Constraint;
+    ///
+    /// #[derive(Clone)]
+    /// struct Item { name: String }
+    /// impl TableRow for Item {
+    ///     fn cells(&self) -> Vec<Cell> { vec![Cell::new(&self.name)] }
+    /// }
+    ///
+    /// let state = TableState::new(
+    ///     vec![Item { name: "A".into() }],
+    ///     vec![Column::new("Name", Constraint::Length(10))],
+    /// )
+    /// .with_zebra(true);
+    /// assert!(state.is_zebra());
+    /// ```
+    pub fn with_zebra(mut self, zebra: bool) -> Self {
+        self.zebra = zebra;
+        self
+    }
+
+    /// Returns whether alternating row striping is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Cell, Column, TableRow, TableState};
+    /// use ratatui::layout::Constraint;
+    ///
+    /// #[derive(Clone)]
+    /// struct Item { name: String }
+    /// impl TableRow for Item {
+    ///     fn cells(&self) -> Vec<Cell> { vec![Cell::new(&self.name)] }
+    /// }
+    ///
+    /// let state: TableState<Item> =
+    ///     TableState::new(vec![], vec![Column::new("Name", Constraint::Length(10))]);
+    /// assert!(!state.is_zebra());
+    /// ```
+    pub fn is_zebra(&self) -> bool {
+        self.zebra
+    }
+
+    /// Sets whether alternating row striping is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Cell, Column, TableRow, TableState};
+    /// use ratatui::layout::Constraint;
+    ///
+    /// #[derive(Clone)]
+    /// struct Item { name: String }
+    /// impl TableRow for Item {
+    ///     fn cells(&self) -> Vec<Cell> { vec![Cell::new(&self.name)] }
+    /// }
+    ///
+    /// let mut state: TableState<Item> =
+    ///     TableState::new(vec![], vec![Column::new("Name", Constraint::Length(10))]);
+    /// state.set_zebra(true);
+    /// assert!(state.is_zebra());
+    /// ```
+    pub fn set_zebra(&mut self, zebra: bool) {
+        self.zebra = zebra;
+    }
+
+    /// Sets a function that styles rows based on their data (builder pattern).
+    ///
+    /// The function returns `Some(style)` to override a row's style (for
+    /// example, coloring overdue rows red) or `None` to keep the default.
+    /// The override replaces zebra striping for that row, and the selection
+    /// highlight still takes precedence over both.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Cell, Column, TableRow, TableState};
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::style::{Color, Style};
+    ///
+    /// #[derive(Clone)]
+    /// struct Task { name: String, overdue: bool }
+    /// impl TableRow for Task {
+    ///     fn cells(&self) -> Vec<Cell> { vec![Cell::new(&self.name)] }
+    /// }
+    ///
+    /// let state = TableState::new(
+    ///     vec![Task { name: "Report".into(), overdue: true }],
+    ///     vec![Column::new("Task", Constraint::Length(10))],
+    /// )
+    /// .with_row_style_fn(|task: &Task| task.overdue.then(|| Style::default().fg(Color::Red)));
+    /// assert_eq!(state.row_style(0), Some(Style::default().fg(Color::Red)));
+    /// ```
+    pub fn with_row_style_fn(
+        mut self,
+        row_style_fn: impl Fn(&T) -> Option<Style> + Send + Sync + 'static,
+    ) -> Self {
+        self.row_style_fn = Some(Arc::new(row_style_fn));
+        self
+    }
+
+    /// Returns the style override for the row at `index` in the underlying
+    /// rows, as computed by the row style function.
+    ///
+    /// Returns `None` when no row style function is set, the function
+    /// returns `None`, or the index is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Cell, Column, TableRow, TableState};
+    /// use ratatui::layout::Constraint;
+    ///
+    /// #[derive(Clone)]
+    /// struct Item { name: String }
+    /// impl TableRow for Item {
+    ///     fn cells(&self) -> Vec<Cell> { vec![Cell::new(&self.name)] }
+    /// }
+    ///
+    /// let state = TableState::new(
+    ///     vec![Item { name: "A".into() }],
+    ///     vec![Column::new("Name", Constraint::Length(10))],
+    /// );
+    /// assert_eq!(state.row_style(0), None);
+    /// ```
+    pub fn row_style(&self, index: usize) -> Option<Style> {
+        let row_style_fn = self.row_style_fn.as_ref()?;
+        row_style_fn(self.rows.get(index)?)
+    }
+
     /// Returns a reference to the rows.
     ///
     /// # Examples
@@ -427,8 +576,10 @@ impl<T: TableRow> TableState<T> {
 
     /// Sets the rows, clearing filter and sort, and adjusting selection.
     ///
-    /// If there were rows selected, the selection is preserved if valid,
-    /// otherwise clamped to the last row.
+    /// If the previously selected row has a [`TableRow::key`] and a row with
+    /// the same key exists in `rows`, that row is selected. Otherwise the
+    /// selection is preserved if valid, or clamped to the last row. Any
+    /// in-progress cell edit is discarded.
     ///
     /// # Example
     ///
@@ -449,14 +600,24 @@ impl<T: TableRow> TableState<T> {
     /// assert_eq!(state.len(), 2);
     /// ```
     pub fn set_rows(&mut self, rows: Vec<T>) {
+        let selected_key = self.selected_row().and_then(TableRow::key);
         self.rows = rows;
+        self.edit = None;
         self.filter_text.clear();
         self.display_order = (0..self.rows.len()).collect();
         self.sort_columns.clear();
         self.scroll.set_content_length(self.display_order.len());
 
+        let keyed = selected_key.and_then(|key| {
+            self.rows
+                .iter()
+                .position(|row| row.key().as_deref() == Some(key.as_str()))
+        });
+
         if self.rows.is_empty() {
             self.selected = None;
+        } else if let Some(index) = keyed {
+            self.selected = Some(index);
         } else if let Some(sel) = self.selected {
             self.selected = Some(sel.min(self.rows.len() - 1));
         } else {
@@ -547,6 +708,70 @@ impl<T: TableRow> TableState<T> {
         self.display_order.len()
     }
 
+    /// Returns how many unpinned columns are scrolled out of view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Cell, Column, Component, Table, TableMessage, TableRow, TableState};
+    ///
+    /// #[derive(Clone)]
+    /// struct Item { id: u32, name: String }
+    /// impl TableRow for Item {
+    ///     fn cells(&self) -> Vec<Cell> { vec![Cell::uint(self.id as u64), Cell::new(&self.name)] }
+    /// }
+    ///
+    /// let mut state = TableState::new(
+    ///     vec![Item { id: 1, name: "Alice".into() }],
+    ///     vec![Column::fixed("ID", 4).pinned(), Column::fixed("Name", 10), Column::fixed("Email", 20)],
+    /// );
+    /// assert_eq!(state.column_offset(), 0);
+    /// Table::<Item>::update(&mut state, TableMessage::ScrollRight);
+    /// assert_eq!(state.column_offset(), 1);
+    /// ```
+    pub fn column_offset(&self) -> usize {
+        self.column_offset
+    }
+
+    /// Sets how many unpinned columns are scrolled out of view.
+    ///
+    /// The offset is clamped so that the last unpinned column stays
+    /// visible.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::{Cell, Column, TableRow, TableState};
+    ///
+    /// #[derive(Clone)]
+    /// struct Item { name: String }
+    /// impl TableRow for Item {
+    ///     fn cells(&self) -> Vec<Cell> { vec![Cell::new(&self.name)] }
+    /// }
+    ///
+    /// let mut state = TableState::new(
+    ///     vec![Item { name: "Alice".into() }],
+    ///     vec![Column::fixed("Name", 10), Column::fixed("Email", 20)],
+    /// );
+    /// state.set_column_offset(5);
+    /// assert_eq!(state.column_offset(), 1);
+    /// ```
+    pub fn set_column_offset(&mut self, offset: usize) {
+        let scrollable = self.columns.iter().filter(|c| !c.is_pinned()).count();
+        self.column_offset = offset.min(scrollable.saturating_sub(1));
+    }
+
+    /// Returns the indices of the columns to render, in render order:
+    /// pinned columns first, then the unpinned columns past the scroll
+    /// offset.
+    pub(super) fn rendered_columns(&self) -> Vec<usize> {
+        let pinned = (0..self.columns.len()).filter(|&i| self.columns[i].is_pinned());
+        let scrolled = (0..self.columns.len())
+            .filter(|&i| !self.columns[i].is_pinned())
+            .skip(self.column_offset);
+        pinned.chain(scrolled).collect()
+    }
+
     /// Returns `true` if any row in the table has a non-`RowStatus::None`
     /// status. When `true`, the renderer prepends a 2-cell-wide status
     /// column to the table; when `false`, no status column is rendered
@@ -219,6 +219,82 @@ fn test_set_rows_clamps_selection() {
     assert_eq!(state.selected_index(), Some(0)); // Clamped
 }
 
+#[derive(Clone, Debug, PartialEq)]
+struct KeyedRow {
+    id: u32,
+    name: String,
+}
+
+impl KeyedRow {
+    fn new(id: u32, name: &str) -> Self {
+        Self {
+            id,
+            name: name.into(),
+        }
+    }
+}
+
+impl TableRow for KeyedRow {
+    fn cells(&self) -> Vec<crate::component::cell::Cell> {
+        use crate::component::cell::Cell;
+        vec![Cell::new(&self.name)]
+    }
+
+    fn key(&self) -> Option<String> {
+        Some(self.id.to_string())
+    }
+}
+
+#[test]
+fn test_set_rows_follows_selected_key() {
+    let rows = vec![
+        KeyedRow::new(1, "A"),
+        KeyedRow::new(2, "B"),
+        KeyedRow::new(3, "C"),
+    ];
+    let mut state = TableState::with_selected(rows, vec![Column::fixed("Name", 10)], 1);
+
+    state.set_rows(vec![
+        KeyedRow::new(3, "C"),
+        KeyedRow::new(4, "D"),
+        KeyedRow::new(1, "A"),
+        KeyedRow::new(2, "B updated"),
+    ]);
+    assert_eq!(state.selected_index(), Some(3));
+    assert_eq!(state.selected_row().map(|r| r.id), Some(2));
+}
+
+#[test]
+fn test_set_rows_missing_key_falls_back_to_clamp() {
+    let rows = vec![
+        KeyedRow::new(1, "A"),
+        KeyedRow::new(2, "B"),
+        KeyedRow::new(3, "C"),
+    ];
+    let mut state = TableState::with_selected(rows, vec![Column::fixed("Name", 10)], 2);
+
+    state.set_rows(vec![KeyedRow::new(4, "D"), KeyedRow::new(5, "E")]);
+    assert_eq!(state.selected_index(), Some(1));
+}
+
+#[test]
+fn test_set_rows_key_uses_display_order_of_selection() {
+    let rows = vec![
+        KeyedRow::new(1, "C"),
+        KeyedRow::new(2, "A"),
+        KeyedRow::new(3, "B"),
+    ];
+    let mut state = TableState::new(rows, vec![Column::fixed("Name", 10).sortable()]);
+    Table::<KeyedRow>::update(&mut state, TableMessage::SortAsc(0));
+    // Sorted ascending: A(2), B(3), C(1); select "C"
+    state.set_selected(Some(2));
+    assert_eq!(state.selected_row().map(|r| r.id), Some(1));
+
+    state.set_rows(vec![KeyedRow::new(2, "A"), KeyedRow::new(1, "C")]);
+    assert_eq!(state.selected_index(), Some(1));
+    assert_eq!(state.selected_row().map(|r| r.id), Some(1));
+}
+
 #[test]
 fn test_set_selected() {
     let mut state = TableState::new(test_rows(), test_columns());
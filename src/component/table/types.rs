@@ -1,7 +1,9 @@
 //! Types for the table component.
 
 use ratatui::layout::Constraint;
+use unicode_width::UnicodeWidthStr;
 
+use super::MIN_COLUMN_WIDTH;
 use crate::component::cell::{Cell, RowStatus};
 
 /// Trait for types that can be displayed as table rows.
@@ -44,6 +46,16 @@ pub trait TableRow: Clone {
     fn status(&self) -> RowStatus {
         RowStatus::None
     }
+
+    /// Optional stable identity for this row. Default: `None`.
+    ///
+    /// When implemented, [`TableState::set_rows`](super::TableState::set_rows)
+    /// re-selects the row whose key matches the previously selected row, so
+    /// the highlight follows the same logical row across data refreshes that
+    /// reorder it. Rows without a key fall back to index clamping.
+    fn key(&self) -> Option<String> {
+        None
+    }
 }
 
 /// Column definition for a table.
@@ -73,7 +85,21 @@ pub struct Column {
     sortable: bool,
     editable: bool,
     visible: bool,
+    pinned: bool,
     default_sort: SortDirection,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    auto: Option<AutoWidth>,
+}
+
+/// Width bounds of an auto-sized column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+struct AutoWidth {
+    min: u16,
+    max: u16,
 }
 
 impl Column {
@@ -135,10 +161,94 @@ impl Column {
             sortable: false,
             editable: true,
             visible: true,
+            pinned: false,
             default_sort: SortDirection::Ascending,
+            auto: None,
         }
     }
 
+    /// Creates a column sized to fit its content.
+    ///
+    /// Until [`TableState::autosize_columns`](super::TableState::autosize_columns)
+    /// runs, the column is at least as wide as its header. Autosizing then
+    /// measures the header and every cell and sets a fixed width, kept
+    /// within the bounds from [`with_width_bounds`](Self::with_width_bounds)
+    /// (by default, at least 3 cells).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::Column;
+    /// use ratatui::layout::Constraint;
+    ///
+    /// let col = Column::auto("Name");
+    /// assert!(col.is_auto());
+    /// assert_eq!(col.width(), Constraint::Min(4));
+    /// ```
+    pub fn auto(header: impl Into<String>) -> Self {
+        let header = header.into();
+        let width = u16::try_from(header.width())
+            .unwrap_or(u16::MAX)
+            .max(MIN_COLUMN_WIDTH);
+        let mut column = Self::new(header, Constraint::Min(width));
+        column.auto = Some(AutoWidth {
+            min: MIN_COLUMN_WIDTH,
+            max: u16::MAX,
+        });
+        column
+    }
+
+    /// Returns whether this column is sized to fit its content.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::Column;
+    ///
+    /// assert!(Column::auto("Name").is_auto());
+    /// assert!(!Column::fixed("Name", 10).is_auto());
+    /// ```
+    pub fn is_auto(&self) -> bool {
+        self.auto.is_some()
+    }
+
+    /// Sets the narrowest and widest an auto-sized column may be (builder
+    /// pattern).
+    ///
+    /// Makes the column auto-sized if it was not already. `max` is raised
+    /// to `min` if it is smaller.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::Column;
+    ///
+    /// let col = Column::auto("Description").with_width_bounds(10, 40);
+    /// assert_eq!(col.width_bounds(), Some((10, 40)));
+    /// ```
+    pub fn with_width_bounds(mut self, min: u16, max: u16) -> Self {
+        self.auto = Some(AutoWidth {
+            min,
+            max: max.max(min),
+        });
+        self
+    }
+
+    /// Returns the `(min, max)` width bounds of an auto-sized column, or
+    /// `None` for a column with a declared width.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::Column;
+    ///
+    /// assert_eq!(Column::auto("Name").width_bounds(), Some((3, u16::MAX)));
+    /// assert_eq!(Column::fixed("Name", 10).width_bounds(), None);
+    /// ```
+    pub fn width_bounds(&self) -> Option<(u16, u16)> {
+        self.auto.map(|auto| (auto.min, auto.max))
+    }
+
     /// Makes this column sortable.
     ///
     /// Sortable columns can be sorted by clicking/selecting the header
@@ -252,6 +362,40 @@ impl Column {
         self.sortable
     }
 
+    /// Pins this column to the left edge of the table (builder pattern).
+    ///
+    /// Pinned columns are rendered first, in declaration order, and stay
+    /// in place while the remaining columns scroll horizontally with
+    /// [`TableMessage::ScrollRight`] and [`TableMessage::ScrollLeft`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::Column;
+    ///
+    /// let col = Column::fixed("ID", 8).pinned();
+    /// assert!(col.is_pinned());
+    /// ```
+    pub fn pinned(mut self) -> Self {
+        self.pinned = true;
+        self
+    }
+
+    /// Returns whether this column is pinned.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::Column;
+    ///
+    /// assert!(!Column::fixed("Name", 10).is_pinned());
+    /// ```
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
     /// Sets whether this column is editable (builder pattern).
     ///
     /// Columns are editable by default. Set to `false` to make a column
@@ -504,6 +648,12 @@ pub enum TableMessage {
     PageDown(usize),
     /// Confirm the current selection.
     Select,
+    /// Scroll the unpinned columns left by one column.
+    ScrollLeft,
+    /// Scroll the unpinned columns right by one column.
+    ///
+    /// The last unpinned column always stays visible.
+    ScrollRight,
 
     /// Set the primary sort to this column, ascending. Replaces the entire
     /// sort stack with just this entry.
@@ -561,6 +711,19 @@ pub enum TableMessage {
     SetFilter(String),
     /// Clear the filter text.
     ClearFilter,
+    /// Start editing the cell in the given column of the selected row.
+    ///
+    /// The edit buffer is prefilled with the cell's text. Ignored if the
+    /// column is not editable or a cell is already being edited.
+    BeginEdit(usize),
+    /// Append a character to the edit buffer.
+    EditInsert(char),
+    /// Delete the last character of the edit buffer.
+    EditBackspace,
+    /// Finish editing, emitting [`TableOutput::CellEdited`].
+    CommitEdit,
+    /// Abandon the current edit.
+    CancelEdit,
 }
 
 /// Output messages from a Table component.
@@ -588,4 +751,19 @@ pub enum TableOutput<T: Clone> {
         /// The new width of the column.
         width: u16,
     },
+    /// A cell edit was committed.
+    ///
+    /// Rows are read-only to the table, so the app applies the new value
+    /// to its own data (for example, followed by
+    /// [`TableState::set_rows`](super::TableState::set_rows)).
+    CellEdited {
+        /// The edited row, as an index into [`TableState::rows`](super::TableState::rows).
+        row: usize,
+        /// The edited column.
+        col: usize,
+        /// The contents of the edit buffer.
+        value: String,
+    },
+    /// A cell edit was cancelled.
+    EditCancelled,
 }
@@ -676,3 +676,98 @@ fn snapshot_table_cells_severity_disabled_renders_dark_gray_no_bold() {
 
     insta::assert_snapshot!(plain);
 }
+
+#[test]
+fn test_view_zebra_stripes_odd_rows() {
+    use crate::theme::NamedColor;
+
+    let mut state = TableState::new(test_rows(), test_columns()).with_zebra(true);
+    state.set_selected(None);
+
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(40, 10);
+    terminal
+        .draw(|frame| {
+            Table::<TestRow>::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    // Border + header + margin put the data rows at y = 3, 4, 5.
+    let stripe = Some(theme.color(NamedColor::Surface0));
+    assert_ne!(terminal.backend().cell(1, 3).unwrap().style().bg, stripe);
+    assert_eq!(terminal.backend().cell(1, 4).unwrap().style().bg, stripe);
+    assert_ne!(terminal.backend().cell(1, 5).unwrap().style().bg, stripe);
+}
+
+#[test]
+fn test_view_row_style_fn_overrides_row_style() {
+    use crate::theme::NamedColor;
+
+    let state = TableState::new(test_rows(), test_columns())
+        .with_zebra(true)
+        .with_row_style_fn(|row: &TestRow| {
+            (row.name == "Alice").then(|| Style::default().fg(Color::Red))
+        });
+
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(40, 10);
+    terminal
+        .draw(|frame| {
+            Table::<TestRow>::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    // "Alice" is the second displayed row; the override replaces the stripe.
+    let cell = terminal.backend().cell(3, 4).unwrap();
+    assert_eq!(cell.symbol(), "A");
+    assert_eq!(cell.style().fg, Some(Color::Red));
+    assert_ne!(cell.style().bg, Some(theme.color(NamedColor::Surface0)));
+}
+
+#[test]
+fn test_view_selection_wins_over_row_style() {
+    let state = TableState::with_selected(test_rows(), test_columns(), 1)
+        .with_zebra(true)
+        .with_row_style_fn(|_: &TestRow| Some(Style::default().fg(Color::Red)));
+
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(40, 10);
+    terminal
+        .draw(|frame| {
+            Table::<TestRow>::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &theme).focused(true),
+            );
+        })
+        .unwrap();
+
+    let highlight = theme.selected_highlight_style(true);
+    let cell = terminal.backend().cell(3, 4).unwrap();
+    assert_eq!(cell.style().fg, highlight.fg);
+    assert_eq!(cell.style().bg, highlight.bg);
+    // Unselected rows keep the override.
+    let other = terminal.backend().cell(3, 3).unwrap();
+    assert_eq!(other.style().fg, Some(Color::Red));
+}
+
+#[test]
+fn test_view_disabled_ignores_row_styles() {
+    let mut state = TableState::new(test_rows(), test_columns())
+        .with_zebra(true)
+        .with_row_style_fn(|_: &TestRow| Some(Style::default().bg(Color::Red)));
+    state.set_selected(None);
+
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(40, 10);
+    terminal
+        .draw(|frame| {
+            Table::<TestRow>::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &theme).disabled(true),
+            );
+        })
+        .unwrap();
+
+    for y in 3..6 {
+        assert_ne!(
+            terminal.backend().cell(1, y).unwrap().style().bg,
+            Some(Color::Red)
+        );
+    }
+}
@@ -448,7 +448,7 @@ impl<T: Clone + Display + 'static> Component for Tabs<T> {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Left | Key::Char('h') => Some(TabsMessage::Left),
                 Key::Right | Key::Char('l') => Some(TabsMessage::Right),
@@ -12,7 +12,8 @@
 //! # Features
 //!
 //! - ANSI SGR color rendering (standard, bright, 256-color palette)
-//! - Auto-scroll to follow new output
+//! - Auto-scroll to follow new output, with a "new output" indicator while
+//!   scrolled back
 //! - Optional line numbers
 //! - Status bar showing running state, exit code, line count
 //! - Scrollbar for long output
@@ -139,6 +140,9 @@ pub struct TerminalOutputState {
     exit_code: Option<i32>,
     /// Whether the process is currently running.
     running: bool,
+    /// Lines pushed since auto-scroll was paused and not yet scrolled to.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    new_lines: usize,
 }
 
 impl Default for TerminalOutputState {
@@ -152,6 +156,7 @@ impl Default for TerminalOutputState {
             title: None,
             exit_code: None,
             running: false,
+            new_lines: 0,
         }
     }
 }
@@ -292,11 +297,7 @@ impl TerminalOutputState {
     /// ```
     pub fn push_line(&mut self, line: impl Into<String>) {
         self.lines.push(line.into());
-        self.enforce_max_lines();
-        self.scroll.set_content_length(self.lines.len());
-        if self.auto_scroll {
-            self.scroll.scroll_to_end();
-        }
+        self.after_push(1);
     }
 
     /// Pushes multiple lines of output at once.
@@ -314,12 +315,9 @@ impl TerminalOutputState {
     /// # }
     /// ```
     pub fn push_lines(&mut self, lines: Vec<String>) {
+        let count = lines.len();
         self.lines.extend(lines);
-        self.enforce_max_lines();
-        self.scroll.set_content_length(self.lines.len());
-        if self.auto_scroll {
-            self.scroll.scroll_to_end();
-        }
+        self.after_push(count);
     }
 
     /// Clears all output lines and resets scroll.
@@ -340,6 +338,7 @@ impl TerminalOutputState {
     pub fn clear(&mut self) {
         self.lines.clear();
         self.scroll = ScrollState::new(0);
+        self.new_lines = 0;
     }
 
     /// Returns all output lines.
@@ -491,6 +490,40 @@ impl TerminalOutputState {
     /// ```
     pub fn set_auto_scroll(&mut self, auto_scroll: bool) {
         self.auto_scroll = auto_scroll;
+        if auto_scroll {
+            self.new_lines = 0;
+        }
+    }
+
+    /// Returns the number of lines pushed while auto-scroll was off that
+    /// have not been scrolled to yet.
+    ///
+    /// Scrolling back through the output pauses auto-scroll so new lines
+    /// do not move the view. They are counted here, and the status bar
+    /// shows how many of them are below the view. The count resets when
+    /// the view reaches the end or auto-scroll is turned back on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "display-components")]
+    /// # {
+    /// use envision::component::{TerminalOutputMessage, TerminalOutputState};
+    ///
+    /// let mut state = TerminalOutputState::new();
+    /// state.push_lines((1..=20).map(|i| format!("line {i}")).collect());
+    /// state.update(TerminalOutputMessage::ScrollUp);
+    ///
+    /// state.push_line("line 21");
+    /// state.push_line("line 22");
+    /// assert_eq!(state.new_lines(), 2);
+    ///
+    /// state.update(TerminalOutputMessage::End);
+    /// assert_eq!(state.new_lines(), 0);
+    /// # }
+    /// ```
+    pub fn new_lines(&self) -> usize {
+        self.new_lines
     }
 
     /// Returns whether line numbers are shown.
@@ -672,6 +705,24 @@ impl TerminalOutputState {
 
     // ---- Internal ----
 
+    /// Updates scroll state after `count` lines were appended.
+    fn after_push(&mut self, count: usize) {
+        self.enforce_max_lines();
+        self.scroll.set_content_length(self.lines.len());
+        if self.auto_scroll {
+            self.scroll.scroll_to_end();
+        } else {
+            self.new_lines = (self.new_lines + count).min(self.lines.len());
+        }
+    }
+
+    /// Forgets the new lines once the view reaches the end.
+    fn note_scrolled(&mut self) {
+        if self.scroll.at_end() {
+            self.new_lines = 0;
+        }
+    }
+
     /// Removes oldest lines when the count exceeds max_lines.
     fn enforce_max_lines(&mut self) {
         if self.lines.len() > self.max_lines {
@@ -717,7 +768,7 @@ impl Component for TerminalOutput {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
         let ctrl = key.modifiers.ctrl();
 
         match key.code {
@@ -771,6 +822,7 @@ impl Component for TerminalOutput {
             }
             TerminalOutputMessage::ScrollDown => {
                 if state.scroll.scroll_down() {
+                    state.note_scrolled();
                     Some(TerminalOutputOutput::ScrollChanged(state.scroll.offset()))
                 } else {
                     None
@@ -788,6 +840,7 @@ impl Component for TerminalOutput {
             }
             TerminalOutputMessage::PageDown(n) => {
                 if state.scroll.page_down(n) {
+                    state.note_scrolled();
                     Some(TerminalOutputOutput::ScrollChanged(state.scroll.offset()))
                 } else {
                     None
@@ -804,6 +857,7 @@ impl Component for TerminalOutput {
                 }
             }
             TerminalOutputMessage::End => {
+                state.new_lines = 0;
                 if state.scroll.scroll_to_end() {
                     Some(TerminalOutputOutput::ScrollChanged(state.scroll.offset()))
                 } else {
@@ -813,6 +867,7 @@ impl Component for TerminalOutput {
             TerminalOutputMessage::ToggleAutoScroll => {
                 state.auto_scroll = !state.auto_scroll;
                 if state.auto_scroll {
+                    state.new_lines = 0;
                     state.scroll.set_content_length(state.lines.len());
                     state.scroll.scroll_to_end();
                 }
@@ -5,7 +5,7 @@ use ratatui::widgets::{Block, Borders};
 
 use super::TerminalOutputState;
 use super::ansi::parse_ansi;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the terminal output component.
 ///
@@ -18,7 +18,7 @@ pub(super) fn render(
     state: &TerminalOutputState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
     chrome_owned: bool,
@@ -81,6 +81,7 @@ pub(super) fn render(
 
     render_content(state, frame, content_area, theme, disabled);
     render_status_bar(state, frame, status_area, theme, disabled);
+    render_new_output_indicator(state, frame, status_area, content_height, theme, disabled);
 
     // Render scrollbar when content exceeds viewport. In chrome-owned mode
     // the data already occupies the full `area` (no border inset), so the
@@ -104,7 +105,7 @@ fn render_content(
     state: &TerminalOutputState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     let visible_lines = area.height as usize;
@@ -188,12 +189,43 @@ fn render_content(
     }
 }
 
+/// Renders a "new output" marker at the right of the status bar for new
+/// lines below the view.
+fn render_new_output_indicator(
+    state: &TerminalOutputState,
+    frame: &mut Frame,
+    area: Rect,
+    content_height: u16,
+    theme: &dyn ThemeProvider,
+    disabled: bool,
+) {
+    let view_end = state.scroll.offset() + content_height as usize;
+    let below = state
+        .new_lines
+        .min(state.lines.len().saturating_sub(view_end));
+    if below == 0 {
+        return;
+    }
+
+    let text = format!(" ↓ {below} new ");
+    let width = (text.chars().count() as u16).min(area.width);
+    let style = if disabled {
+        theme.disabled_style()
+    } else {
+        theme.info_style().add_modifier(Modifier::BOLD)
+    };
+    frame.render_widget(
+        Span::styled(text, style),
+        Rect::new(area.right() - width, area.y, width, 1),
+    );
+}
+
 /// Renders the status bar at the bottom of the component.
 fn render_status_bar(
     state: &TerminalOutputState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     if area.width == 0 || area.height == 0 {
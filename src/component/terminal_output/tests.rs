@@ -189,6 +189,80 @@ fn test_home_disables_auto_scroll() {
     assert!(!state.auto_scroll());
 }
 
+#[test]
+fn test_new_lines_counted_while_scrolled_back() {
+    let mut state = content_state();
+    assert_eq!(state.new_lines(), 0);
+    TerminalOutput::update(&mut state, TerminalOutputMessage::ScrollUp);
+    let offset = state.scroll_offset();
+
+    state.push_line("Line 21");
+    state.push_lines(vec!["Line 22".to_string(), "Line 23".to_string()]);
+    assert_eq!(state.new_lines(), 3);
+    assert_eq!(state.scroll_offset(), offset);
+}
+
+#[test]
+fn test_new_lines_not_counted_while_following() {
+    let mut state = content_state();
+    state.push_line("Line 21");
+    assert_eq!(state.new_lines(), 0);
+}
+
+#[test]
+fn test_end_clears_new_lines() {
+    let mut state = content_state();
+    TerminalOutput::update(&mut state, TerminalOutputMessage::Home);
+    state.push_line("Line 21");
+    TerminalOutput::update(&mut state, TerminalOutputMessage::End);
+    assert_eq!(state.new_lines(), 0);
+}
+
+#[test]
+fn test_scrolling_down_to_end_clears_new_lines() {
+    let mut state = content_state();
+    TerminalOutput::update(&mut state, TerminalOutputMessage::ScrollUp);
+    state.push_line("Line 21");
+    TerminalOutput::update(&mut state, TerminalOutputMessage::ScrollDown);
+    assert_eq!(state.new_lines(), 1);
+    TerminalOutput::update(&mut state, TerminalOutputMessage::ScrollDown);
+    assert_eq!(state.new_lines(), 0);
+}
+
+#[test]
+fn test_resuming_auto_scroll_clears_new_lines() {
+    let mut state = content_state();
+    TerminalOutput::update(&mut state, TerminalOutputMessage::ScrollUp);
+    state.push_line("Line 21");
+    TerminalOutput::update(&mut state, TerminalOutputMessage::ToggleAutoScroll);
+    assert_eq!(state.new_lines(), 0);
+
+    state.set_auto_scroll(false);
+    state.push_line("Line 22");
+    state.set_auto_scroll(true);
+    assert_eq!(state.new_lines(), 0);
+}
+
+#[test]
+fn test_clear_resets_new_lines() {
+    let mut state = content_state();
+    TerminalOutput::update(&mut state, TerminalOutputMessage::ScrollUp);
+    state.push_line("Line 21");
+    state.clear();
+    assert_eq!(state.new_lines(), 0);
+}
+
+#[test]
+fn test_new_lines_capped_by_max_lines() {
+    let mut state = TerminalOutputState::new()
+        .with_auto_scroll(false)
+        .with_max_lines(3);
+    for i in 1..=5 {
+        state.push_line(format!("Line {i}"));
+    }
+    assert_eq!(state.new_lines(), 3);
+}
+
 // =============================================================================
 // Scroll operations (via update)
 // =============================================================================
@@ -713,6 +787,37 @@ fn test_view_focused() {
         .unwrap();
     insta::assert_snapshot!(terminal.backend().to_string());
 }
+
+#[test]
+fn test_view_new_output_indicator() {
+    let mut state = content_state();
+    TerminalOutput::update(&mut state, TerminalOutputMessage::Home);
+    state.push_line("Line 21");
+    state.push_line("Line 22");
+    let (mut terminal, theme) = test_utils::setup_render(50, 10);
+    terminal
+        .draw(|frame| {
+            TerminalOutput::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+    insta::assert_snapshot!(terminal.backend().to_string());
+}
+
+#[test]
+fn test_view_hides_indicator_when_new_lines_visible() {
+    let mut state = TerminalOutputState::new().with_auto_scroll(false);
+    state.push_line("Line 1");
+    state.push_line("Line 2");
+    assert_eq!(state.new_lines(), 2);
+    let (mut terminal, theme) = test_utils::setup_render(50, 10);
+    terminal
+        .draw(|frame| {
+            TerminalOutput::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+    assert!(!terminal.backend().contains_text("new"));
+}
+
 // =============================================================================
 // Annotation tests
 // =============================================================================
@@ -0,0 +1,175 @@
+/// Length and line-count limits for TextAreaState.
+///
+/// These are implementation details extracted to keep
+/// the main module under the 1000-line limit.
+use super::TextAreaState;
+
+impl TextAreaState {
+    /// Sets the maximum number of characters (builder pattern).
+    ///
+    /// Newlines count as one character each. Once the limit is reached,
+    /// insertions are rejected with
+    /// [`TextAreaOutput::LimitReached`](super::TextAreaOutput::LimitReached),
+    /// pastes are cut off at the remaining capacity, and `set_value`
+    /// truncates. Existing content longer than the limit is truncated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::TextAreaState;
+    ///
+    /// let state = TextAreaState::new().with_value("hello world").with_max_length(5);
+    /// assert_eq!(state.max_length(), Some(5));
+    /// assert_eq!(state.value(), "hello");
+    /// ```
+    pub fn with_max_length(mut self, max: usize) -> Self {
+        self.set_max_length(Some(max));
+        self
+    }
+
+    /// Sets the maximum number of lines (builder pattern).
+    ///
+    /// Once the limit is reached, new lines are rejected with
+    /// [`TextAreaOutput::LimitReached`](super::TextAreaOutput::LimitReached)
+    /// and `set_value` drops the extra lines. Existing content with more
+    /// lines than the limit is truncated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::TextAreaState;
+    ///
+    /// let state = TextAreaState::new().with_value("a\nb\nc").with_max_lines(2);
+    /// assert_eq!(state.max_lines(), Some(2));
+    /// assert_eq!(state.value(), "a\nb");
+    /// ```
+    pub fn with_max_lines(mut self, max: usize) -> Self {
+        self.set_max_lines(Some(max));
+        self
+    }
+
+    /// Returns the maximum number of characters, if limited.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::TextAreaState;
+    ///
+    /// assert_eq!(TextAreaState::new().max_length(), None);
+    /// ```
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Sets the maximum number of characters. `None` means unlimited.
+    ///
+    /// Existing content longer than the limit is truncated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::TextAreaState;
+    ///
+    /// let mut state = TextAreaState::new();
+    /// state.set_max_length(Some(72));
+    /// assert_eq!(state.max_length(), Some(72));
+    /// ```
+    pub fn set_max_length(&mut self, max: Option<usize>) {
+        self.max_length = max;
+        self.enforce_limits();
+    }
+
+    /// Returns the maximum number of lines, if limited.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::TextAreaState;
+    ///
+    /// assert_eq!(TextAreaState::new().max_lines(), None);
+    /// ```
+    pub fn max_lines(&self) -> Option<usize> {
+        self.max_lines
+    }
+
+    /// Sets the maximum number of lines. `None` means unlimited.
+    ///
+    /// Existing content with more lines than the limit is truncated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::component::TextAreaState;
+    ///
+    /// let mut state = TextAreaState::new();
+    /// state.set_max_lines(Some(10));
+    /// assert_eq!(state.max_lines(), Some(10));
+    /// ```
+    pub fn set_max_lines(&mut self, max: Option<usize>) {
+        self.max_lines = max;
+        self.enforce_limits();
+    }
+
+    /// Returns the number of characters in the value, counting newlines.
+    pub(super) fn char_count(&self) -> usize {
+        let chars: usize = self.lines.iter().map(|l| l.chars().count()).sum();
+        chars + self.lines.len() - 1
+    }
+
+    /// Returns how many more characters fit, treating any selection as
+    /// already deleted. `None` when the length is unlimited.
+    pub(super) fn remaining_length(&self) -> Option<usize> {
+        let max = self.max_length?;
+        let selected = self.selected_text().map_or(0, |t| t.chars().count());
+        Some(max.saturating_sub(self.char_count() - selected))
+    }
+
+    /// Returns how many more line breaks fit, treating any selection as
+    /// already deleted. `None` when the line count is unlimited.
+    pub(super) fn remaining_lines(&self) -> Option<usize> {
+        let max = self.max_lines?;
+        let selected = self.selected_text().map_or(0, |t| t.matches('\n').count());
+        Some(max.max(1).saturating_sub(self.lines.len() - selected))
+    }
+
+    /// Truncates the content to the configured limits, moving the cursor
+    /// to the end if anything was removed.
+    pub(super) fn enforce_limits(&mut self) {
+        let mut truncated = false;
+
+        if let Some(max) = self.max_lines {
+            let max = max.max(1);
+            if self.lines.len() > max {
+                self.lines.truncate(max);
+                truncated = true;
+            }
+        }
+
+        if let Some(max) = self.max_length {
+            if self.char_count() > max {
+                let mut budget = max;
+                let mut keep = 0;
+                for line in &mut self.lines {
+                    keep += 1;
+                    let chars = line.chars().count();
+                    if chars >= budget {
+                        if let Some((byte, _)) = line.char_indices().nth(budget) {
+                            line.truncate(byte);
+                        }
+                        break;
+                    }
+                    // The line plus its trailing newline
+                    budget -= chars + 1;
+                }
+                self.lines.truncate(keep);
+                truncated = true;
+            }
+        }
+
+        if truncated {
+            self.cursor_row = self.lines.len() - 1;
+            self.cursor_col = self.lines[self.cursor_row].len();
+            self.selection_anchor = None;
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use super::*;
+
+fn insert_str(state: &mut TextAreaState, s: &str) {
+    for c in s.chars() {
+        TextArea::update(state, TextAreaMessage::Insert(c));
+    }
+}
+
+#[test]
+fn test_limits_default_none() {
+    let state = TextAreaState::new();
+    assert_eq!(state.max_length(), None);
+    assert_eq!(state.max_lines(), None);
+}
+
+#[test]
+fn test_insert_stops_at_max_length() {
+    let mut state = TextAreaState::new().with_max_length(3);
+    insert_str(&mut state, "abc");
+    assert_eq!(state.value(), "abc");
+
+    let output = TextArea::update(&mut state, TextAreaMessage::Insert('d'));
+    assert_eq!(
+        output,
+        Some(TextAreaOutput::LimitReached(LimitKind::Length))
+    );
+    assert_eq!(state.value(), "abc");
+    assert_eq!(state.cursor_position(), (0, 3));
+}
+
+#[test]
+fn test_insert_replaces_selection_at_limit() {
+    let mut state = TextAreaState::new().with_value("abc").with_max_length(3);
+    TextArea::update(&mut state, TextAreaMessage::SelectLeft);
+    let output = TextArea::update(&mut state, TextAreaMessage::Insert('x'));
+    assert_eq!(output, Some(TextAreaOutput::Changed("abx".into())));
+}
+
+#[test]
+fn test_newline_counts_toward_length() {
+    let mut state = TextAreaState::new().with_value("ab").with_max_length(3);
+    assert_eq!(
+        TextArea::update(&mut state, TextAreaMessage::NewLine),
+        Some(TextAreaOutput::Changed("ab\n".into()))
+    );
+    assert_eq!(
+        TextArea::update(&mut state, TextAreaMessage::NewLine),
+        Some(TextAreaOutput::LimitReached(LimitKind::Length))
+    );
+}
+
+#[test]
+fn test_newline_stops_at_max_lines() {
+    let mut state = TextAreaState::new().with_max_lines(2);
+    TextArea::update(&mut state, TextAreaMessage::NewLine);
+    assert_eq!(state.line_count(), 2);
+
+    let output = TextArea::update(&mut state, TextAreaMessage::NewLine);
+    assert_eq!(output, Some(TextAreaOutput::LimitReached(LimitKind::Lines)));
+    assert_eq!(state.line_count(), 2);
+
+    // Characters can still be typed on the last line
+    let output = TextArea::update(&mut state, TextAreaMessage::Insert('x'));
+    assert_eq!(output, Some(TextAreaOutput::Changed("\nx".into())));
+}
+
+#[test]
+fn test_rejected_edit_is_not_undoable() {
+    let mut state = TextAreaState::new().with_max_length(1);
+    TextArea::update(&mut state, TextAreaMessage::Insert('a'));
+    TextArea::update(&mut state, TextAreaMessage::Insert('b'));
+    TextArea::update(&mut state, TextAreaMessage::Undo);
+    assert_eq!(state.value(), "");
+}
+
+#[test]
+fn test_paste_truncated_to_remaining_length() {
+    let mut state = TextAreaState::new().with_value("ab").with_max_length(5);
+    let output = TextArea::update(&mut state, TextAreaMessage::Paste("cdefg".into()));
+    assert_eq!(output, Some(TextAreaOutput::Changed("abcde".into())));
+
+    let output = TextArea::update(&mut state, TextAreaMessage::Paste("x".into()));
+    assert_eq!(
+        output,
+        Some(TextAreaOutput::LimitReached(LimitKind::Length))
+    );
+    assert_eq!(state.value(), "abcde");
+}
+
+#[test]
+fn test_paste_truncated_to_remaining_lines() {
+    let mut state = TextAreaState::new().with_max_lines(2);
+    let output = TextArea::update(&mut state, TextAreaMessage::Paste("one\ntwo\nthree".into()));
+    assert_eq!(output, Some(TextAreaOutput::Changed("one\ntwo".into())));
+    assert_eq!(state.cursor_position(), (1, 3));
+}
+
+#[test]
+fn test_paste_into_selection_reuses_its_capacity() {
+    let mut state = TextAreaState::new().with_value("a\nb").with_max_lines(2);
+    TextArea::update(&mut state, TextAreaMessage::SelectAll);
+    let output = TextArea::update(&mut state, TextAreaMessage::Paste("x\ny\nz".into()));
+    assert_eq!(output, Some(TextAreaOutput::Changed("x\ny".into())));
+}
+
+#[test]
+fn test_set_value_truncates() {
+    let mut state = TextAreaState::new().with_max_lines(2).with_max_length(6);
+    state.set_value("first\nsecond\nthird");
+    assert_eq!(state.value(), "first\n");
+    assert_eq!(state.cursor_position(), (1, 0));
+}
+
+#[test]
+fn test_set_value_message_truncates() {
+    let mut state = TextAreaState::new().with_max_length(4);
+    let output = TextArea::update(&mut state, TextAreaMessage::SetValue("héllo".into()));
+    assert_eq!(output, Some(TextAreaOutput::Changed("héll".into())));
+
+    let output = TextArea::update(&mut state, TextAreaMessage::SetValue("héllo wörld".into()));
+    assert_eq!(output, None);
+}
+
+#[test]
+fn test_setting_limit_truncates_existing_content() {
+    let mut state = TextAreaState::new().with_value("a\nb\nc");
+    state.set_max_lines(Some(1));
+    assert_eq!(state.value(), "a");
+    assert_eq!(state.cursor_position(), (0, 1));
+
+    state.set_max_lines(None);
+    TextArea::update(&mut state, TextAreaMessage::NewLine);
+    assert_eq!(state.line_count(), 2);
+}
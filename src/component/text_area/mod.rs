@@ -27,7 +27,8 @@
 //! assert_eq!(state.line_count(), 2);
 //! ```
 
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::text::Text;
+use ratatui::widgets::Paragraph;
 use unicode_width::UnicodeWidthStr;
 
 use super::{Component, EventContext, RenderContext};
@@ -38,6 +39,7 @@ use crate::undo::UndoStack;
 use crate::clipboard::system_clipboard_get;
 
 mod cursor;
+mod limits;
 mod search;
 mod selection;
 mod update;
@@ -159,6 +161,29 @@ pub enum TextAreaOutput {
     Changed(String),
     /// Text was copied to the internal clipboard.
     Copied(String),
+    /// The search query or current match changed.
+    ///
+    /// `current` is the 1-based position of the current match (0 when there
+    /// are no matches) and `total` is the number of matches, suitable for a
+    /// "3/12" status display.
+    SearchResult {
+        /// 1-based position of the current match, or 0 if there are none.
+        current: usize,
+        /// Total number of matches.
+        total: usize,
+    },
+    /// An edit was rejected because the textarea is at a limit set with
+    /// [`TextAreaState::with_max_length`] or [`TextAreaState::with_max_lines`].
+    LimitReached(LimitKind),
+}
+
+/// Which TextArea limit rejected an edit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LimitKind {
+    /// The maximum number of characters.
+    Length,
+    /// The maximum number of lines.
+    Lines,
 }
 
 /// State for a TextArea component.
@@ -194,6 +219,10 @@ pub struct TextAreaState {
     search_matches: Vec<(usize, usize)>,
     /// Index of the current match within search_matches.
     current_match: usize,
+    /// Maximum number of characters, counting newlines.
+    max_length: Option<usize>,
+    /// Maximum number of lines.
+    max_lines: Option<usize>,
 }
 
 impl Default for TextAreaState {
@@ -211,6 +240,8 @@ impl Default for TextAreaState {
             search_query: None,
             search_matches: Vec::new(),
             current_match: 0,
+            max_length: None,
+            max_lines: None,
         }
     }
 }
@@ -254,6 +285,7 @@ impl TextAreaState {
 
         self.cursor_row = self.lines.len().saturating_sub(1);
         self.cursor_col = self.lines.last().map(|l| l.len()).unwrap_or(0);
+        self.enforce_limits();
         self
     }
 
@@ -289,6 +321,9 @@ impl TextAreaState {
 
     /// Sets the content from a string (splits on \n). Cursor moves to end.
     ///
+    /// Content beyond [`max_lines`](Self::max_lines) or
+    /// [`max_length`](Self::max_length) is dropped.
+    ///
     /// # Examples
     ///
     /// ```
@@ -311,6 +346,7 @@ impl TextAreaState {
         self.cursor_col = self.lines[self.cursor_row].len();
         self.scroll_offset = 0;
         self.selection_anchor = None;
+        self.enforce_limits();
     }
 
     /// Returns the cursor position as (row, char_column).
@@ -700,7 +736,7 @@ impl Component for TextArea {
         if let Event::Paste(text) = event {
             return Some(TextAreaMessage::Paste(text.clone()));
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             let ctrl = key.modifiers.ctrl();
             let shift = key.modifiers.shift();
             match key.code {
@@ -759,7 +795,11 @@ impl Component for TextArea {
     }
 
     fn update(state: &mut Self::State, msg: Self::Message) -> Option<Self::Output> {
-        state.apply_update(msg)
+        let output = state.apply_update(msg);
+        if matches!(output, Some(TextAreaOutput::Changed(_))) && state.is_searching() {
+            state.refresh_matches();
+        }
+        output
     }
 
     fn view(state: &Self::State, ctx: &mut RenderContext<'_, '_>) {
@@ -789,16 +829,18 @@ impl Component for TextArea {
 
         // Build display text
         let display_text = if state.is_empty() && !state.placeholder.is_empty() {
-            state.placeholder.clone()
+            Text::from(state.placeholder.clone())
         } else {
-            state
-                .lines
-                .iter()
-                .skip(scroll)
-                .take(inner_height.max(1))
-                .cloned()
-                .collect::<Vec<_>>()
-                .join("\n")
+            Text::from(
+                state
+                    .lines
+                    .iter()
+                    .enumerate()
+                    .skip(scroll)
+                    .take(inner_height.max(1))
+                    .map(|(row, line)| state.highlighted_line(row, line, ctx.theme))
+                    .collect::<Vec<_>>(),
+            )
         };
 
         let style = if ctx.disabled {
@@ -811,17 +853,9 @@ impl Component for TextArea {
             ctx.theme.normal_style()
         };
 
-        let border_style = if ctx.focused && !ctx.disabled {
-            ctx.theme.focused_border_style()
-        } else {
-            ctx.theme.border_style()
-        };
-
-        let paragraph = Paragraph::new(display_text).style(style).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(border_style),
-        );
+        let paragraph = Paragraph::new(display_text)
+            .style(style)
+            .block(ctx.theme.focused_block(ctx.focused && !ctx.disabled));
 
         ctx.frame.render_widget(paragraph, ctx.area);
 
@@ -844,6 +878,8 @@ impl Component for TextArea {
     }
 }
 
+#[cfg(test)]
+mod limits_tests;
 #[cfg(test)]
 mod tests;
 #[cfg(test)]
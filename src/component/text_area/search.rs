@@ -3,7 +3,11 @@
 /// Provides text search with match highlighting and navigation.
 /// Extracted to a submodule to keep the main module under the
 /// 1000-line limit.
-use super::TextAreaState;
+use ratatui::style::Modifier;
+use ratatui::text::{Line, Span};
+
+use super::{TextAreaOutput, TextAreaState};
+use crate::theme::ThemeProvider;
 
 impl TextAreaState {
     /// Returns the current search query, if any.
@@ -146,8 +150,9 @@ impl TextAreaState {
             while let Some(pos) = line[start..].find(&query) {
                 let byte_col = start + pos;
                 self.search_matches.push((line_idx, byte_col));
-                // Advance past this match to find overlapping/subsequent matches
-                start = byte_col + 1;
+                // Advance one character past this match start to find
+                // overlapping/subsequent matches
+                start = byte_col + line[byte_col..].chars().next().map_or(1, char::len_utf8);
                 if start >= line.len() {
                     break;
                 }
@@ -183,4 +188,70 @@ impl TextAreaState {
         self.current_match = 0;
         self.jump_cursor_to_current_match();
     }
+
+    /// Recomputes matches after an edit without moving the cursor.
+    ///
+    /// The current match becomes the first match at or after the cursor.
+    pub(super) fn refresh_matches(&mut self) {
+        self.recompute_matches();
+        let cursor = (self.cursor_row, self.cursor_col);
+        self.current_match = self
+            .search_matches
+            .iter()
+            .position(|&pos| pos >= cursor)
+            .unwrap_or(0);
+    }
+
+    /// Returns a [`TextAreaOutput::SearchResult`] for the current search.
+    pub(super) fn search_result(&self) -> TextAreaOutput {
+        let total = self.search_matches.len();
+        let current = if total == 0 {
+            0
+        } else {
+            self.current_match + 1
+        };
+        TextAreaOutput::SearchResult { current, total }
+    }
+
+    /// Builds the display line for `row`, highlighting search matches.
+    ///
+    /// All matches use the theme's warning style; the current match is
+    /// additionally reversed and bold.
+    pub(super) fn highlighted_line<'a>(
+        &self,
+        row: usize,
+        line: &'a str,
+        theme: &dyn ThemeProvider,
+    ) -> Line<'a> {
+        let query_len = match &self.search_query {
+            Some(q) if !q.is_empty() => q.len(),
+            _ => return Line::from(line),
+        };
+
+        let mut spans = Vec::new();
+        let mut pos = 0;
+        for (index, &(match_row, col)) in self.search_matches.iter().enumerate() {
+            // Overlapping matches are merged into the preceding highlight
+            if match_row != row || col < pos {
+                continue;
+            }
+            if col > pos {
+                spans.push(Span::raw(&line[pos..col]));
+            }
+            let end = col + query_len;
+            let style = if index == self.current_match {
+                theme
+                    .warning_style()
+                    .add_modifier(Modifier::REVERSED | Modifier::BOLD)
+            } else {
+                theme.warning_style()
+            };
+            spans.push(Span::styled(&line[col..end], style));
+            pos = end;
+        }
+        if pos < line.len() {
+            spans.push(Span::raw(&line[pos..]));
+        }
+        Line::from(spans)
+    }
 }
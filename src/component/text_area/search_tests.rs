@@ -0,0 +1,141 @@
+use super::*;
+use ratatui::style::Modifier;
+
+// =============================================================================
+// Search tests
+// =============================================================================
+
+#[test]
+fn test_set_search_query_emits_search_result() {
+    let mut state = TextAreaState::new().with_value("foo bar\nfoo baz foo");
+    let output = TextArea::update(&mut state, TextAreaMessage::SetSearchQuery("foo".into()));
+    assert_eq!(
+        output,
+        Some(TextAreaOutput::SearchResult {
+            current: 1,
+            total: 3
+        })
+    );
+}
+
+#[test]
+fn test_search_no_matches_result() {
+    let mut state = TextAreaState::new().with_value("hello");
+    let output = TextArea::update(&mut state, TextAreaMessage::SetSearchQuery("xyz".into()));
+    assert_eq!(
+        output,
+        Some(TextAreaOutput::SearchResult {
+            current: 0,
+            total: 0
+        })
+    );
+    assert_eq!(
+        TextArea::update(&mut state, TextAreaMessage::NextMatch),
+        None
+    );
+    assert_eq!(
+        TextArea::update(&mut state, TextAreaMessage::PrevMatch),
+        None
+    );
+}
+
+#[test]
+fn test_next_match_wraps_and_reports_position() {
+    let mut state = TextAreaState::new().with_value("a a a");
+    state.set_cursor_position(0, 0);
+    TextArea::update(&mut state, TextAreaMessage::SetSearchQuery("a".into()));
+
+    let output = TextArea::update(&mut state, TextAreaMessage::NextMatch);
+    assert_eq!(
+        output,
+        Some(TextAreaOutput::SearchResult {
+            current: 2,
+            total: 3
+        })
+    );
+    TextArea::update(&mut state, TextAreaMessage::NextMatch);
+    let output = TextArea::update(&mut state, TextAreaMessage::NextMatch);
+    assert_eq!(
+        output,
+        Some(TextAreaOutput::SearchResult {
+            current: 1,
+            total: 3
+        })
+    );
+    assert_eq!(state.cursor_position(), (0, 0));
+
+    let output = TextArea::update(&mut state, TextAreaMessage::PrevMatch);
+    assert_eq!(
+        output,
+        Some(TextAreaOutput::SearchResult {
+            current: 3,
+            total: 3
+        })
+    );
+}
+
+#[test]
+fn test_search_multibyte_does_not_panic() {
+    let mut state = TextAreaState::new().with_value("ééé");
+    TextArea::update(&mut state, TextAreaMessage::SetSearchQuery("é".into()));
+    assert_eq!(state.search_matches(), &[(0, 0), (0, 2), (0, 4)]);
+}
+
+#[test]
+fn test_edit_refreshes_matches() {
+    let mut state = TextAreaState::new().with_value("foo");
+    TextArea::update(&mut state, TextAreaMessage::SetSearchQuery("foo".into()));
+    assert_eq!(state.search_matches().len(), 1);
+
+    TextArea::update(&mut state, TextAreaMessage::TextEnd);
+    for c in " foo".chars() {
+        TextArea::update(&mut state, TextAreaMessage::Insert(c));
+    }
+    assert_eq!(state.search_matches(), &[(0, 0), (0, 4)]);
+}
+
+#[test]
+fn test_view_highlights_matches() {
+    let mut state = TextAreaState::new().with_value("foo bar foo");
+    state.set_cursor_position(0, 0);
+    TextArea::update(&mut state, TextAreaMessage::SetSearchQuery("foo".into()));
+
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(20, 3);
+    terminal
+        .draw(|frame| {
+            TextArea::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+
+    let backend = terminal.backend();
+    let current = backend.cell(1, 1).unwrap().style();
+    let other = backend.cell(9, 1).unwrap().style();
+    let plain = backend.cell(5, 1).unwrap().style();
+    assert_eq!(current.fg, theme.warning_style().fg);
+    assert!(current.add_modifier.contains(Modifier::REVERSED));
+    assert_eq!(other.fg, theme.warning_style().fg);
+    assert!(!other.add_modifier.contains(Modifier::REVERSED));
+    assert_ne!(plain.fg, theme.warning_style().fg);
+}
+
+#[test]
+fn test_view_scrolls_current_match_into_view() {
+    let text = (0..20)
+        .map(|i| format!("line {}", i))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut state = TextAreaState::new().with_value(text);
+    state.set_cursor_position(0, 0);
+    TextArea::update(
+        &mut state,
+        TextAreaMessage::SetSearchQuery("line 17".into()),
+    );
+
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(20, 5);
+    terminal
+        .draw(|frame| {
+            TextArea::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+    assert!(terminal.backend().to_string().contains("line 17"));
+}
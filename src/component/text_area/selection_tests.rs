@@ -269,6 +269,14 @@ fn test_paste_multiline() {
     assert_eq!(state.line_count(), 3);
 }
 
+#[test]
+fn test_paste_normalizes_carriage_returns() {
+    let mut state = TextAreaState::new();
+    TextArea::update(&mut state, TextAreaMessage::Paste("a\r\nb\rc".into()));
+    assert_eq!(state.value(), "a\nb\nc");
+    assert_eq!(state.line_count(), 3);
+}
+
 #[test]
 fn test_paste_empty() {
     let mut state = TextAreaState::new().with_value("hello");
@@ -1,6 +1,8 @@
 use super::*;
 use crate::input::{Event, Key, Modifiers};
 
+#[path = "search_tests.rs"]
+mod search_tests;
 #[path = "selection_tests.rs"]
 mod selection_tests;
 
@@ -493,6 +495,32 @@ fn test_view_focused() {
     insta::assert_snapshot!(terminal.backend().to_string());
 }
 
+#[test]
+fn test_view_focused_draws_focus_ring() {
+    let state = TextAreaState::new().with_value("Hello");
+    let (mut terminal, _) = crate::component::test_utils::setup_render(20, 3);
+    let theme = crate::theme::Theme {
+        focus_ring: Some(ratatui::widgets::BorderType::Thick),
+        ..crate::theme::Theme::default()
+    };
+
+    terminal
+        .draw(|frame| {
+            TextArea::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &theme).focused(true),
+            );
+        })
+        .unwrap();
+
+    let output = terminal.backend().to_string();
+    assert!(output.starts_with("┏━━"), "{output}");
+    assert_eq!(
+        terminal.backend().cell(0, 0).unwrap().style(),
+        theme.focus_ring_style()
+    );
+}
+
 #[test]
 fn test_view_unfocused() {
     let state = TextAreaState::new().with_value("Hello");
@@ -995,3 +1023,28 @@ fn test_annotation_emitted() {
     assert!(!regions[0].annotation.focused);
     assert!(!regions[0].annotation.disabled);
 }
+
+#[test]
+fn test_cursor_hidden_when_unfocused() {
+    let state = TextAreaState::new().with_value("Hello");
+    let (mut terminal, theme) = crate::component::test_utils::setup_render(40, 10);
+
+    terminal
+        .draw(|frame| {
+            TextArea::view(&state, &mut RenderContext::new(frame, frame.area(), &theme));
+        })
+        .unwrap();
+    assert!(!terminal.backend().is_cursor_visible());
+    assert_eq!(terminal.backend().visible_cursor(), None);
+
+    terminal
+        .draw(|frame| {
+            TextArea::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &theme).focused(true),
+            );
+        })
+        .unwrap();
+    assert!(terminal.backend().is_cursor_visible());
+    assert!(terminal.backend().visible_cursor().is_some());
+}
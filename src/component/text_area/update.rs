@@ -4,7 +4,7 @@
 /// the main module under the 1000-line limit.
 use crate::undo::EditKind;
 
-use super::{TextAreaMessage, TextAreaOutput, TextAreaState};
+use super::{LimitKind, TextAreaMessage, TextAreaOutput, TextAreaState};
 
 #[cfg(feature = "clipboard")]
 use crate::clipboard::system_clipboard_set;
@@ -18,6 +18,9 @@ impl TextAreaState {
         match msg {
             // Editing (replaces selection if active)
             TextAreaMessage::Insert(c) => {
+                if self.remaining_length() == Some(0) {
+                    return Some(TextAreaOutput::LimitReached(LimitKind::Length));
+                }
                 if c.is_whitespace() {
                     self.undo_stack.break_group();
                 }
@@ -31,6 +34,12 @@ impl TextAreaState {
                 Some(TextAreaOutput::Changed(self.value()))
             }
             TextAreaMessage::NewLine => {
+                if self.remaining_lines() == Some(0) {
+                    return Some(TextAreaOutput::LimitReached(LimitKind::Lines));
+                }
+                if self.remaining_length() == Some(0) {
+                    return Some(TextAreaOutput::LimitReached(LimitKind::Length));
+                }
                 let snapshot = self.snapshot();
                 self.undo_stack.save(snapshot, EditKind::Other);
                 self.delete_selection();
@@ -209,10 +218,37 @@ impl TextAreaState {
                 if text.is_empty() {
                     return None;
                 }
+                // Terminals may send CR or CRLF line endings in bracketed paste
+                let text = text.replace("\r\n", "\n").replace('\r', "\n");
+
+                // Only paste what fits within the length and line limits
+                let mut length_left = self.remaining_length().unwrap_or(usize::MAX);
+                let mut lines_left = self.remaining_lines().unwrap_or(usize::MAX);
+                let mut fitting = 0;
+                let mut limit = None;
+                for c in text.chars() {
+                    if length_left == 0 {
+                        limit = Some(LimitKind::Length);
+                        break;
+                    }
+                    if c == '\n' {
+                        if lines_left == 0 {
+                            limit = Some(LimitKind::Lines);
+                            break;
+                        }
+                        lines_left -= 1;
+                    }
+                    length_left -= 1;
+                    fitting += 1;
+                }
+                if fitting == 0 {
+                    return limit.map(TextAreaOutput::LimitReached);
+                }
+
                 let snapshot = self.snapshot();
                 self.undo_stack.save(snapshot, EditKind::Other);
                 self.delete_selection();
-                for c in text.chars() {
+                for c in text.chars().take(fitting) {
                     if c == '\n' {
                         self.new_line();
                     } else {
@@ -273,11 +309,11 @@ impl TextAreaState {
                 }
             }
             TextAreaMessage::SetValue(value) => {
+                let snapshot = self.snapshot();
                 let old_value = self.value();
-                if old_value != value {
-                    let snapshot = self.snapshot();
+                self.set_value(value);
+                if self.value() != old_value {
                     self.undo_stack.save(snapshot, EditKind::Other);
-                    self.set_value(value);
                     Some(TextAreaOutput::Changed(self.value()))
                 } else {
                     None
@@ -308,15 +344,23 @@ impl TextAreaState {
             }
             TextAreaMessage::SetSearchQuery(query) => {
                 self.set_search_query(query);
-                None
+                Some(self.search_result())
             }
             TextAreaMessage::NextMatch => {
-                self.next_match();
-                None
+                if self.search_matches.is_empty() {
+                    None
+                } else {
+                    self.next_match();
+                    Some(self.search_result())
+                }
             }
             TextAreaMessage::PrevMatch => {
-                self.prev_match();
-                None
+                if self.search_matches.is_empty() {
+                    None
+                } else {
+                    self.prev_match();
+                    Some(self.search_result())
+                }
             }
             TextAreaMessage::ClearSearch => {
                 self.clear_search();
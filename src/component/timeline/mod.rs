@@ -693,7 +693,7 @@ impl Component for Timeline {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
 
         match key.code {
             Key::Left | Key::Char('h') => Some(TimelineMessage::PanLeft),
@@ -8,14 +8,14 @@ use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
 
 use super::{SelectedType, TimelineState};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the complete timeline inside the block's inner area.
 pub(super) fn render_timeline(
     state: &TimelineState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     _focused: bool,
     disabled: bool,
 ) {
@@ -129,7 +129,7 @@ fn render_time_axis(
     state: &TimelineState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     if area.width == 0 {
@@ -179,7 +179,7 @@ fn render_time_axis(
 }
 
 /// Renders a horizontal separator line.
-fn render_separator(frame: &mut Frame, area: Rect, theme: &Theme, disabled: bool) {
+fn render_separator(frame: &mut Frame, area: Rect, theme: &dyn ThemeProvider, disabled: bool) {
     let style = if disabled {
         theme.disabled_style()
     } else {
@@ -197,7 +197,7 @@ fn render_span_lane(
     frame: &mut Frame,
     area: Rect,
     lane_idx: usize,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 
     disabled: bool,
 ) {
@@ -296,7 +296,7 @@ fn render_events(
     state: &TimelineState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     if area.width == 0 {
@@ -356,7 +356,7 @@ fn render_detail_bar(
     state: &TimelineState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     let detail = match state.selected_type {
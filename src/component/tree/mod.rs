@@ -940,7 +940,7 @@ impl<T: Clone + 'static> Component for Tree<T> {
         if !ctx.focused || ctx.disabled {
             return None;
         }
-        if let Some(key) = event.as_key() {
+        if let Some(key) = event.as_key_press() {
             match key.code {
                 Key::Up | Key::Char('k') => Some(TreeMessage::Up),
                 Key::Down | Key::Char('j') => Some(TreeMessage::Down),
@@ -8,7 +8,7 @@ use ratatui::widgets::Paragraph;
 
 use crate::component::{EventContext, RenderContext};
 use crate::scroll::ScrollState;
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 use super::{Tree, TreeState};
 
@@ -17,7 +17,7 @@ impl<T: Clone + 'static> Tree<T> {
     pub(super) fn render_lines(
         state: &TreeState<T>,
         width: u16,
-        theme: &Theme,
+        theme: &dyn ThemeProvider,
         ctx: &EventContext,
     ) -> Vec<Line<'static>> {
         let flat = state.flatten();
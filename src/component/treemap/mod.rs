@@ -707,7 +707,7 @@ impl Component for Treemap {
             return None;
         }
 
-        let key = event.as_key()?;
+        let key = event.as_key_press()?;
 
         match key.code {
             Key::Left | Key::Char('h') => Some(TreemapMessage::SelectPrev),
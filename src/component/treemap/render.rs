@@ -5,14 +5,14 @@ use ratatui::widgets::Paragraph;
 
 use super::TreemapState;
 use super::layout::{LayoutRect, squarified_layout};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Renders the treemap inside the border area.
 pub(super) fn render_treemap(
     state: &TreemapState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     focused: bool,
     disabled: bool,
 ) {
@@ -181,7 +181,7 @@ fn render_detail_bar(
     state: &TreemapState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
     disabled: bool,
 ) {
     let style = if disabled {
@@ -36,7 +36,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 use super::{Component, RenderContext};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Layout style for usage metrics display.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -399,7 +399,7 @@ pub struct UsageDisplay;
 
 impl UsageDisplay {
     /// Renders a single metric as a sequence of spans.
-    fn metric_spans(metric: &UsageMetric, theme: &Theme) -> Vec<Span<'static>> {
+    fn metric_spans(metric: &UsageMetric, theme: &dyn ThemeProvider) -> Vec<Span<'static>> {
         let mut spans = Vec::new();
 
         if let Some(icon) = &metric.icon {
@@ -422,7 +422,12 @@ impl UsageDisplay {
     }
 
     /// Renders horizontal layout.
-    fn view_horizontal(state: &UsageDisplayState, frame: &mut Frame, area: Rect, theme: &Theme) {
+    fn view_horizontal(
+        state: &UsageDisplayState,
+        frame: &mut Frame,
+        area: Rect,
+        theme: &dyn ThemeProvider,
+    ) {
         let mut spans = Vec::new();
         for (i, metric) in state.metrics.iter().enumerate() {
             if i > 0 {
@@ -452,7 +457,7 @@ impl UsageDisplay {
         state: &UsageDisplayState,
         frame: &mut Frame,
         area: Rect,
-        theme: &Theme,
+        theme: &dyn ThemeProvider,
         chrome_owned: bool,
     ) {
         let inner = if chrome_owned {
@@ -490,7 +495,7 @@ impl UsageDisplay {
         state: &UsageDisplayState,
         frame: &mut Frame,
         area: Rect,
-        theme: &Theme,
+        theme: &dyn ThemeProvider,
         columns: usize,
         chrome_owned: bool,
     ) {
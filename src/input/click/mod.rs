@@ -0,0 +1,187 @@
+//! Multi-click recognition.
+//!
+//! Terminals report every mouse press separately, so a double-click arrives
+//! as two ordinary presses. [`ClickTracker`] counts presses of the same
+//! button at the same cell that follow each other within an interval, which
+//! lets an app tell a double-click from two single clicks.
+//!
+//! The interval is measured with a [`Clock`], so a virtual clock makes
+//! timing deterministic in tests.
+
+use std::time::{Duration, Instant};
+
+use super::events::Event;
+use super::mouse::{MouseButton, MouseEventKind};
+use crate::app::Clock;
+
+/// The default maximum delay between the presses of a multi-click.
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A stateful counter of consecutive mouse clicks.
+///
+/// Feed it every event. Each button press reports how many presses in a
+/// row it completes: 1 for a single click, 2 for a double-click, and so on.
+/// The count restarts when a different button is pressed, the press lands
+/// on another cell, or the interval has elapsed since the previous press.
+/// Releases, moves, scrolls and keys leave the count untouched.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::input::{ClickTracker, Event, EventQueue};
+///
+/// let mut queue = EventQueue::new();
+/// queue.click(3, 1);
+/// queue.double_click(10, 4);
+///
+/// let mut clicks = ClickTracker::new();
+/// let doubles: Vec<bool> = queue
+///     .drain()
+///     .map(|event| clicks.is_double_click(&event))
+///     .collect();
+/// // The click, then the double-click's down, up, down, up
+/// assert_eq!(doubles, [false, false, false, true, false]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClickTracker {
+    last: Option<LastClick>,
+    interval: Duration,
+    clock: Clock,
+}
+
+/// The press that the next click is compared against.
+#[derive(Clone, Copy, Debug)]
+struct LastClick {
+    button: MouseButton,
+    column: u16,
+    row: u16,
+    at: Instant,
+    count: u32,
+}
+
+impl Default for ClickTracker {
+    fn default() -> Self {
+        Self {
+            last: None,
+            interval: DEFAULT_INTERVAL,
+            clock: Clock::system(),
+        }
+    }
+}
+
+impl ClickTracker {
+    /// Creates a tracker with a 500 millisecond interval.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use envision::input::ClickTracker;
+    ///
+    /// let clicks = ClickTracker::new();
+    /// assert_eq!(clicks.interval(), Duration::from_millis(500));
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum delay between the presses of a multi-click
+    /// (builder pattern).
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the clock used to measure the interval (builder pattern).
+    ///
+    /// Pass the runtime's virtual clock to control timing in tests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use envision::app::Clock;
+    /// use envision::input::{ClickTracker, Event};
+    ///
+    /// let clock = Clock::new_virtual();
+    /// let mut clicks = ClickTracker::new().with_clock(clock.clone());
+    ///
+    /// assert_eq!(clicks.feed(&Event::click(0, 0)), 1);
+    /// clock.advance(Duration::from_secs(1));
+    /// // Too slow: this press starts a new click
+    /// assert_eq!(clicks.feed(&Event::click(0, 0)), 1);
+    /// ```
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the maximum delay between the presses of a multi-click.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Forgets the previous press, so the next one counts as a single
+    /// click.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+
+    /// Feeds an event to the tracker.
+    ///
+    /// Returns the click count for a button press, or 0 for any other
+    /// event.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{ClickTracker, Event};
+    ///
+    /// let mut clicks = ClickTracker::new();
+    /// assert_eq!(clicks.feed(&Event::click(5, 5)), 1);
+    /// assert_eq!(clicks.feed(&Event::mouse_up(5, 5)), 0);
+    /// assert_eq!(clicks.feed(&Event::click(5, 5)), 2);
+    /// assert_eq!(clicks.feed(&Event::click(5, 5)), 3);
+    /// assert_eq!(clicks.feed(&Event::click(6, 5)), 1);
+    /// ```
+    pub fn feed(&mut self, event: &Event) -> u32 {
+        let Some(mouse) = event.as_mouse() else {
+            return 0;
+        };
+        let MouseEventKind::Down(button) = mouse.kind else {
+            return 0;
+        };
+
+        let now = self.clock.now();
+        let count = match self.last {
+            Some(last)
+                if last.button == button
+                    && last.column == mouse.column
+                    && last.row == mouse.row
+                    && now.saturating_duration_since(last.at) <= self.interval =>
+            {
+                last.count.saturating_add(1)
+            }
+            _ => 1,
+        };
+        self.last = Some(LastClick {
+            button,
+            column: mouse.column,
+            row: mouse.row,
+            at: now,
+            count,
+        });
+        count
+    }
+
+    /// Feeds an event to the tracker and returns true if it is the second
+    /// press of a double-click.
+    ///
+    /// A third quick press is a triple-click and returns false.
+    pub fn is_double_click(&mut self, event: &Event) -> bool {
+        self.feed(event) == 2
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,97 @@
+use super::*;
+use crate::input::Key;
+
+fn virtual_tracker() -> (ClickTracker, Clock) {
+    let clock = Clock::new_virtual();
+    (ClickTracker::new().with_clock(clock.clone()), clock)
+}
+
+#[test]
+fn test_single_click() {
+    let (mut clicks, _) = virtual_tracker();
+    assert_eq!(clicks.feed(&Event::click(1, 1)), 1);
+}
+
+#[test]
+fn test_double_click_within_interval() {
+    let (mut clicks, clock) = virtual_tracker();
+    assert!(!clicks.is_double_click(&Event::click(1, 1)));
+    clock.advance(Duration::from_millis(500));
+    assert!(clicks.is_double_click(&Event::click(1, 1)));
+}
+
+#[test]
+fn test_slow_clicks_restart_count() {
+    let (mut clicks, clock) = virtual_tracker();
+    clicks.feed(&Event::click(1, 1));
+    clock.advance(Duration::from_millis(501));
+    assert_eq!(clicks.feed(&Event::click(1, 1)), 1);
+}
+
+#[test]
+fn test_interval_measured_from_previous_press() {
+    let (mut clicks, clock) = virtual_tracker();
+    clicks.feed(&Event::click(1, 1));
+    clock.advance(Duration::from_millis(400));
+    clicks.feed(&Event::click(1, 1));
+    clock.advance(Duration::from_millis(400));
+    assert_eq!(clicks.feed(&Event::click(1, 1)), 3);
+}
+
+#[test]
+fn test_custom_interval() {
+    let (clicks, clock) = virtual_tracker();
+    let mut clicks = clicks.with_interval(Duration::from_millis(100));
+    assert_eq!(clicks.interval(), Duration::from_millis(100));
+
+    clicks.feed(&Event::click(1, 1));
+    clock.advance(Duration::from_millis(200));
+    assert!(!clicks.is_double_click(&Event::click(1, 1)));
+}
+
+#[test]
+fn test_other_cell_restarts_count() {
+    let (mut clicks, _) = virtual_tracker();
+    clicks.feed(&Event::click(1, 1));
+    assert_eq!(clicks.feed(&Event::click(1, 2)), 1);
+    assert_eq!(clicks.feed(&Event::click(1, 2)), 2);
+}
+
+#[test]
+fn test_other_button_restarts_count() {
+    let (mut clicks, _) = virtual_tracker();
+    clicks.feed(&Event::click(1, 1));
+    assert_eq!(
+        clicks.feed(&Event::click_button(1, 1, MouseButton::Right)),
+        1
+    );
+}
+
+#[test]
+fn test_non_press_events_are_ignored() {
+    let (mut clicks, _) = virtual_tracker();
+    clicks.feed(&Event::click(1, 1));
+    assert_eq!(clicks.feed(&Event::mouse_up(1, 1)), 0);
+    assert_eq!(clicks.feed(&Event::mouse_move(4, 4)), 0);
+    assert_eq!(clicks.feed(&Event::scroll_down(1, 1)), 0);
+    assert_eq!(clicks.feed(&Event::key(Key::Enter)), 0);
+    assert_eq!(clicks.feed(&Event::click(1, 1)), 2);
+}
+
+#[test]
+fn test_reset() {
+    let (mut clicks, _) = virtual_tracker();
+    clicks.feed(&Event::click(1, 1));
+    clicks.reset();
+    assert_eq!(clicks.feed(&Event::click(1, 1)), 1);
+}
+
+#[test]
+fn test_queue_double_click_is_recognized() {
+    let mut queue = crate::input::EventQueue::new();
+    queue.double_click(2, 3);
+
+    let (mut clicks, _) = virtual_tracker();
+    let counts: Vec<u32> = queue.drain().map(|event| clicks.feed(&event)).collect();
+    assert_eq!(counts, [1, 0, 2, 0]);
+}
@@ -4,7 +4,7 @@
 //! can call them, but they are not part of envision's public API.
 
 use super::events::Event;
-use super::key::{Key, KeyEvent, KeyEventKind, Modifiers};
+use super::key::{Key, KeyEvent, KeyEventKind, MediaKey, Modifiers};
 use super::mouse::{MouseButton, MouseEvent, MouseEventKind};
 
 /// Converts a crossterm event to an envision event.
@@ -12,10 +12,18 @@ use super::mouse::{MouseButton, MouseEvent, MouseEventKind};
 /// Returns `None` for key events that envision doesn't model (e.g. CapsLock)
 /// and for key release/repeat events (only presses are forwarded).
 pub(crate) fn from_crossterm_event(event: crossterm::event::Event) -> Option<Event> {
+    from_crossterm_event_with_kinds(event, false)
+}
+
+/// Converts a crossterm event to an envision event, keeping key release
+/// and repeat events when `forward_key_kinds` is true.
+pub(crate) fn from_crossterm_event_with_kinds(
+    event: crossterm::event::Event,
+    forward_key_kinds: bool,
+) -> Option<Event> {
     match event {
         crossterm::event::Event::Key(key) => {
-            // Only handle key press events, not release or repeat
-            if key.kind == crossterm::event::KeyEventKind::Press {
+            if forward_key_kinds || key.kind == crossterm::event::KeyEventKind::Press {
                 from_crossterm_key(key).map(Event::Key)
             } else {
                 None
@@ -37,22 +45,9 @@ pub(crate) fn from_crossterm_key(key: crossterm::event::KeyEvent) -> Option<KeyE
     let kind = from_crossterm_key_kind(key.kind);
 
     let (envision_key, raw_char) = match key.code {
-        crossterm::event::KeyCode::Char(c) => {
-            // Normalize control characters (Ctrl+letter sends 0x01-0x1A)
-            if c.is_ascii_control() && c != '\t' && c != '\r' && c != '\n' {
-                let letter = (c as u8 + b'a' - 1) as char;
-                modifiers |= Modifiers::CONTROL;
-                (Key::Char(letter), Some(c))
-            }
-            // Normalize uppercase ASCII letters to lowercase
-            else if c.is_ascii_uppercase() {
-                (Key::Char(c.to_ascii_lowercase()), Some(c))
-            }
-            // Everything else passes through
-            else {
-                (Key::Char(c), Some(c))
-            }
-        }
+        // Control characters and uppercase letters are canonicalized by
+        // `KeyEvent::normalized` below
+        crossterm::event::KeyCode::Char(c) => (Key::Char(c), Some(c)),
         crossterm::event::KeyCode::F(n) => (Key::F(n), None),
         crossterm::event::KeyCode::Backspace => (Key::Backspace, None),
         crossterm::event::KeyCode::Enter => (Key::Enter, None),
@@ -72,17 +67,40 @@ pub(crate) fn from_crossterm_key(key: crossterm::event::KeyEvent) -> Option<KeyE
         crossterm::event::KeyCode::Delete => (Key::Delete, None),
         crossterm::event::KeyCode::Insert => (Key::Insert, None),
         crossterm::event::KeyCode::Esc => (Key::Esc, None),
+        crossterm::event::KeyCode::Media(media) => (Key::Media(from_crossterm_media(media)), None),
         // Dropped variants: Null, CapsLock, ScrollLock, NumLock,
-        // PrintScreen, Pause, Menu, KeypadBegin, Media, Modifier
+        // PrintScreen, Pause, Menu, KeypadBegin, Modifier
         _ => return None,
     };
 
-    Some(KeyEvent {
-        code: envision_key,
-        modifiers,
-        kind,
-        raw_char,
-    })
+    Some(
+        KeyEvent {
+            code: envision_key,
+            modifiers,
+            kind,
+            raw_char,
+        }
+        .normalized(),
+    )
+}
+
+/// Converts a crossterm media key code to an envision media key.
+pub(crate) fn from_crossterm_media(media: crossterm::event::MediaKeyCode) -> MediaKey {
+    match media {
+        crossterm::event::MediaKeyCode::Play => MediaKey::Play,
+        crossterm::event::MediaKeyCode::Pause => MediaKey::Pause,
+        crossterm::event::MediaKeyCode::PlayPause => MediaKey::PlayPause,
+        crossterm::event::MediaKeyCode::Reverse => MediaKey::Reverse,
+        crossterm::event::MediaKeyCode::Stop => MediaKey::Stop,
+        crossterm::event::MediaKeyCode::FastForward => MediaKey::FastForward,
+        crossterm::event::MediaKeyCode::Rewind => MediaKey::Rewind,
+        crossterm::event::MediaKeyCode::TrackNext => MediaKey::TrackNext,
+        crossterm::event::MediaKeyCode::TrackPrevious => MediaKey::TrackPrevious,
+        crossterm::event::MediaKeyCode::Record => MediaKey::Record,
+        crossterm::event::MediaKeyCode::LowerVolume => MediaKey::LowerVolume,
+        crossterm::event::MediaKeyCode::RaiseVolume => MediaKey::RaiseVolume,
+        crossterm::event::MediaKeyCode::MuteVolume => MediaKey::MuteVolume,
+    }
 }
 
 /// Converts a crossterm mouse event to an envision mouse event.
@@ -328,6 +346,22 @@ mod tests {
         assert!(from_crossterm_key(ct_key(ct::KeyCode::KeypadBegin)).is_none());
     }
 
+    #[test]
+    fn test_media_keys() {
+        assert_eq!(
+            from_crossterm_key(ct_key(ct::KeyCode::Media(ct::MediaKeyCode::PlayPause)))
+                .unwrap()
+                .code,
+            Key::Media(MediaKey::PlayPause)
+        );
+        assert_eq!(
+            from_crossterm_key(ct_key(ct::KeyCode::Media(ct::MediaKeyCode::MuteVolume)))
+                .unwrap()
+                .code,
+            Key::Media(MediaKey::MuteVolume)
+        );
+    }
+
     #[test]
     fn test_key_event_kind_mapping() {
         let press = ct::KeyEvent {
@@ -355,6 +389,73 @@ mod tests {
         );
     }
 
+    // ========== Cross-platform normalization tests ==========
+
+    #[test]
+    fn test_char_carriage_return_becomes_enter() {
+        // Windows reports numpad Enter as a raw '\r' character
+        let result = from_crossterm_key(ct_key(ct::KeyCode::Char('\r'))).unwrap();
+        assert_eq!(result.code, Key::Enter);
+        assert!(result.modifiers.is_none());
+        assert!(result.raw_char.is_none());
+
+        let result = from_crossterm_key(ct_key(ct::KeyCode::Char('\n'))).unwrap();
+        assert_eq!(result.code, Key::Enter);
+    }
+
+    #[test]
+    fn test_char_tab_and_escape_become_keys() {
+        assert_eq!(
+            from_crossterm_key(ct_key(ct::KeyCode::Char('\t')))
+                .unwrap()
+                .code,
+            Key::Tab
+        );
+        assert_eq!(
+            from_crossterm_key(ct_key(ct::KeyCode::Char('\x1b')))
+                .unwrap()
+                .code,
+            Key::Esc
+        );
+    }
+
+    #[test]
+    fn test_ctrl_backspace_as_del_becomes_backspace() {
+        // Windows reports Ctrl+Backspace as DEL with CONTROL
+        let result = from_crossterm_key(ct_key_with_mods(
+            ct::KeyCode::Char('\x7f'),
+            ct::KeyModifiers::CONTROL,
+        ))
+        .unwrap();
+        assert_eq!(result.code, Key::Backspace);
+        assert!(result.modifiers.ctrl());
+        assert!(result.raw_char.is_none());
+
+        let expected = from_crossterm_key(ct_key_with_mods(
+            ct::KeyCode::Backspace,
+            ct::KeyModifiers::CONTROL,
+        ))
+        .unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ctrl_punctuation_from_raw_control_char() {
+        for (raw, key) in [('\x1c', '\\'), ('\x1d', ']'), ('\x1e', '^'), ('\x1f', '_')] {
+            let result = from_crossterm_key(ct_key(ct::KeyCode::Char(raw))).unwrap();
+            assert_eq!(result.code, Key::Char(key));
+            assert!(result.modifiers.ctrl());
+            assert_eq!(result.raw_char, Some(raw));
+        }
+    }
+
+    #[test]
+    fn test_nul_becomes_ctrl_space() {
+        let result = from_crossterm_key(ct_key(ct::KeyCode::Char('\0'))).unwrap();
+        assert_eq!(result.code, Key::Char(' '));
+        assert!(result.modifiers.ctrl());
+    }
+
     // ========== Modifier conversion tests ==========
 
     #[test]
@@ -456,6 +557,18 @@ mod tests {
         assert!(from_crossterm_event(ct_event).is_none());
     }
 
+    #[test]
+    fn test_event_key_release_and_repeat_forwarded() {
+        let mut key = ct_key(ct::KeyCode::Enter);
+        key.kind = ct::KeyEventKind::Release;
+        let result = from_crossterm_event_with_kinds(ct::Event::Key(key), true).unwrap();
+        assert!(matches!(result, Event::Key(ke) if ke.kind == KeyEventKind::Release));
+
+        key.kind = ct::KeyEventKind::Repeat;
+        let result = from_crossterm_event_with_kinds(ct::Event::Key(key), true).unwrap();
+        assert!(matches!(result, Event::Key(ke) if ke.kind == KeyEventKind::Repeat));
+    }
+
     #[test]
     fn test_event_dropped_key_filtered() {
         let ct_event = ct::Event::Key(ct_key(ct::KeyCode::Null));
@@ -1,6 +1,6 @@
 //! Event types for terminal input.
 
-use super::key::{Key, KeyEvent, Modifiers};
+use super::key::{Key, KeyEvent, MediaKey, Modifiers};
 use super::mouse::{MouseButton, MouseEvent, MouseEventKind};
 
 /// A terminal input event.
@@ -30,6 +30,26 @@ pub enum Event {
 }
 
 impl Event {
+    /// Returns this event with key encodings canonicalized across platforms.
+    ///
+    /// Key events are normalized with [`KeyEvent::normalized`]; other
+    /// events are returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{Event, Key};
+    ///
+    /// let event = Event::char('\r').normalized();
+    /// assert_eq!(event, Event::key(Key::Enter));
+    /// ```
+    pub fn normalized(self) -> Self {
+        match self {
+            Self::Key(key) => Self::Key(key.normalized()),
+            other => other,
+        }
+    }
+
     /// Creates a key press event for a character.
     ///
     /// # Example
@@ -81,6 +101,53 @@ impl Event {
         Self::Key(ev)
     }
 
+    /// Creates a function key press event (`F1`, `F2`, ...).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{Event, Key};
+    ///
+    /// let event = Event::function(5);
+    /// assert_eq!(event.as_key().unwrap().code, Key::F(5));
+    /// ```
+    pub fn function(n: u8) -> Self {
+        Self::key(Key::F(n))
+    }
+
+    /// Creates a numeric keypad digit press event, or `None` if `digit` is
+    /// greater than 9.
+    ///
+    /// Keypad keys are not distinguished from the main keyboard: terminals
+    /// report keypad digits as ordinary characters, so this returns the
+    /// same event as [`Event::char`] for the digit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::Event;
+    ///
+    /// assert_eq!(Event::keypad(7), Some(Event::char('7')));
+    /// assert_eq!(Event::keypad(10), None);
+    /// ```
+    pub fn keypad(digit: u8) -> Option<Self> {
+        char::from_digit(u32::from(digit), 10).map(Self::char)
+    }
+
+    /// Creates a media key press event.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{Event, Key, MediaKey};
+    ///
+    /// let event = Event::media(MediaKey::Stop);
+    /// assert_eq!(event.as_key().unwrap().code, Key::Media(MediaKey::Stop));
+    /// ```
+    pub fn media(key: MediaKey) -> Self {
+        Self::key(Key::Media(key))
+    }
+
     /// Creates a Ctrl+key event.
     ///
     /// # Example
@@ -153,6 +220,16 @@ impl Event {
         })
     }
 
+    /// Creates a mouse release event for a specific button.
+    pub fn mouse_up_button(x: u16, y: u16, button: MouseButton) -> Self {
+        Self::Mouse(MouseEvent {
+            kind: MouseEventKind::Up(button),
+            column: x,
+            row: y,
+            modifiers: Modifiers::NONE,
+        })
+    }
+
     /// Creates a mouse move event.
     pub fn mouse_move(x: u16, y: u16) -> Self {
         Self::Mouse(MouseEvent {
@@ -239,6 +316,30 @@ impl Event {
         }
     }
 
+    /// Returns the key event if this is a key press or auto-repeat.
+    ///
+    /// Key releases return `None`. Built-in components read keys through
+    /// this, so with
+    /// [`RuntimeConfig::forward_key_kinds`](crate::RuntimeConfig::forward_key_kinds)
+    /// enabled a key still acts once when it goes down, and again for each
+    /// repeat while it is held, as it does in terminals that report only
+    /// presses.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{Event, EventQueue, Key};
+    ///
+    /// assert!(Event::key(Key::Enter).as_key_press().is_some());
+    ///
+    /// let mut queue = EventQueue::new();
+    /// queue.release(Key::Enter);
+    /// assert!(queue.pop().unwrap().as_key_press().is_none());
+    /// ```
+    pub fn as_key_press(&self) -> Option<&KeyEvent> {
+        self.as_key().filter(|key| !key.is_release())
+    }
+
     /// Returns the mouse event if this is one.
     ///
     /// # Example
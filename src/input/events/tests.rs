@@ -1,5 +1,5 @@
 use super::*;
-use crate::input::key::{Key, KeyEvent, KeyEventKind, Modifiers};
+use crate::input::key::{Key, KeyEvent, KeyEventKind, MediaKey, Modifiers};
 use crate::input::mouse::{MouseButton, MouseEvent, MouseEventKind};
 
 // -------------------------------------------------------------------------
@@ -58,6 +58,34 @@ fn test_simulated_event_alt() {
     assert!(key.modifiers.alt());
 }
 
+#[test]
+fn test_simulated_event_function() {
+    let event = Event::function(12);
+    let key = event.as_key().unwrap();
+    assert_eq!(key.code, Key::F(12));
+    assert!(key.raw_char.is_none());
+}
+
+#[test]
+fn test_simulated_event_keypad() {
+    assert_eq!(Event::keypad(0), Some(Event::char('0')));
+    assert_eq!(Event::keypad(9), Some(Event::char('9')));
+}
+
+#[test]
+fn test_simulated_event_keypad_out_of_range() {
+    assert_eq!(Event::keypad(10), None);
+}
+
+#[test]
+fn test_simulated_event_media() {
+    let event = Event::media(MediaKey::TrackNext);
+    let key = event.as_key().unwrap();
+    assert_eq!(key.code, Key::Media(MediaKey::TrackNext));
+    assert!(key.modifiers.is_none());
+    assert!(key.raw_char.is_none());
+}
+
 #[test]
 fn test_simulated_event_click() {
     let event = Event::click(10, 20);
@@ -370,3 +398,35 @@ fn test_kind_name_focus() {
 fn test_kind_name_paste() {
     assert_eq!(Event::Paste("hello".to_string()).kind_name(), "Paste");
 }
+
+#[test]
+fn test_normalized_key_event() {
+    assert_eq!(Event::char('\r').normalized(), Event::key(Key::Enter));
+    assert_eq!(Event::char('\x1b').normalized(), Event::key(Key::Esc));
+    assert_eq!(Event::char('\x7f').normalized(), Event::key(Key::Backspace));
+    assert_eq!(
+        Event::char('\x01').normalized().as_key().unwrap().code,
+        Key::Char('a')
+    );
+    assert!(
+        Event::char('\x01')
+            .normalized()
+            .as_key()
+            .unwrap()
+            .modifiers
+            .ctrl()
+    );
+}
+
+#[test]
+fn test_normalized_is_idempotent() {
+    let event = Event::char_with('\x7f', Modifiers::CONTROL).normalized();
+    assert_eq!(event.clone().normalized(), event);
+    assert_eq!(Event::char('A').normalized(), Event::char('A'));
+}
+
+#[test]
+fn test_normalized_leaves_other_events() {
+    assert_eq!(Event::Resize(80, 24).normalized(), Event::Resize(80, 24));
+    assert_eq!(Event::click(1, 2).normalized(), Event::click(1, 2));
+}
@@ -56,6 +56,51 @@ pub enum Key {
     Insert,
     /// The escape key.
     Esc,
+    /// A media key (play, pause, volume, ...).
+    ///
+    /// Only reported by terminals that support the kitty keyboard protocol
+    /// with disambiguated escape codes.
+    Media(MediaKey),
+}
+
+/// A media key, as reported in [`Key::Media`].
+///
+/// # Example
+///
+/// ```rust
+/// use envision::input::{Event, Key, MediaKey};
+///
+/// let event = Event::media(MediaKey::PlayPause);
+/// assert_eq!(event.as_key().unwrap().code, Key::Media(MediaKey::PlayPause));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MediaKey {
+    /// Play.
+    Play,
+    /// Pause.
+    Pause,
+    /// Toggle between play and pause.
+    PlayPause,
+    /// Reverse playback.
+    Reverse,
+    /// Stop.
+    Stop,
+    /// Fast forward.
+    FastForward,
+    /// Rewind.
+    Rewind,
+    /// Skip to the next track.
+    TrackNext,
+    /// Skip to the previous track.
+    TrackPrevious,
+    /// Record.
+    Record,
+    /// Lower the volume.
+    LowerVolume,
+    /// Raise the volume.
+    RaiseVolume,
+    /// Mute the volume.
+    MuteVolume,
 }
 
 /// A keyboard event with normalization and raw character preservation.
@@ -201,6 +246,70 @@ impl KeyEvent {
         }
     }
 
+    /// Returns this event with platform-specific encodings canonicalized.
+    ///
+    /// Terminals and platforms report some keys differently. Normalizing
+    /// makes bindings written on one platform behave the same on another:
+    ///
+    /// - `Char('\r')` and `Char('\n')` (numpad Enter on Windows, raw line
+    ///   endings) become [`Key::Enter`].
+    /// - `Char('\t')` becomes [`Key::Tab`] and `Char('\x1b')` becomes
+    ///   [`Key::Esc`].
+    /// - `Char('\x7f')` (DEL, sent for Backspace by some terminals and for
+    ///   Ctrl+Backspace on Windows) becomes [`Key::Backspace`].
+    /// - Other ASCII control characters become their Ctrl+key form:
+    ///   `'\x01'`–`'\x1a'` are Ctrl+`a`–`z`, `'\x1c'`–`'\x1f'` are
+    ///   Ctrl+`\\`, `]`, `^` and `_`, and `'\0'` is Ctrl+Space.
+    /// - Uppercase ASCII letters become lowercase. SHIFT is left as
+    ///   reported, so Caps Lock input stays distinguishable.
+    ///
+    /// Modifiers and `raw_char` are otherwise preserved; `raw_char` is
+    /// cleared when the key is no longer a character. Events from the
+    /// terminal are already normalized; this is useful for events built
+    /// by hand or replayed from another platform.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::key::{Key, KeyEvent, Modifiers};
+    ///
+    /// let mut event = KeyEvent::ctrl('\x7f');
+    /// event = event.normalized();
+    /// assert_eq!(event.code, Key::Backspace);
+    /// assert!(event.modifiers.ctrl());
+    /// assert_eq!(event.raw_char, None);
+    ///
+    /// let event = KeyEvent::char('\x1c').normalized();
+    /// assert_eq!(event.code, Key::Char('\\'));
+    /// assert!(event.modifiers.ctrl());
+    /// ```
+    pub fn normalized(mut self) -> Self {
+        let Key::Char(c) = self.code else {
+            return self;
+        };
+        match c {
+            '\r' | '\n' => self.code = Key::Enter,
+            '\t' => self.code = Key::Tab,
+            '\x1b' => self.code = Key::Esc,
+            '\x7f' => self.code = Key::Backspace,
+            '\0' => {
+                self.code = Key::Char(' ');
+                self.modifiers |= Modifiers::CONTROL;
+            }
+            c if c.is_ascii_control() => {
+                let key = (c as u8 | 0x40).to_ascii_lowercase() as char;
+                self.code = Key::Char(key);
+                self.modifiers |= Modifiers::CONTROL;
+            }
+            c if c.is_ascii_uppercase() => self.code = Key::Char(c.to_ascii_lowercase()),
+            _ => {}
+        }
+        if !matches!(self.code, Key::Char(_)) {
+            self.raw_char = None;
+        }
+        self
+    }
+
     /// Returns true if this is a press event.
     pub fn is_press(&self) -> bool {
         self.kind == KeyEventKind::Press
@@ -0,0 +1,273 @@
+//! Declarative key bindings.
+//!
+//! [`KeyMap`] maps single key chords such as `q` or `Ctrl+S` to messages.
+//! Install one on the runtime with
+//! [`Runtime::set_keymap`](crate::app::Runtime::set_keymap) and it is
+//! consulted before the app's `handle_event`, so static bindings like quit
+//! and help live in one inspectable table while dynamic handling stays in
+//! `handle_event`.
+
+use std::fmt;
+use std::sync::Arc;
+
+use super::events::Event;
+use super::key::{Key, KeyEvent, Modifiers};
+
+/// A single entry of a [`KeyMap`].
+pub struct KeyMapBinding<M> {
+    key: KeyEvent,
+    description: Option<String>,
+    message: Arc<dyn Fn() -> M + Send + Sync>,
+}
+
+impl<M> KeyMapBinding<M> {
+    /// Returns the bound key chord, normalized.
+    pub fn key(&self) -> KeyEvent {
+        self.key
+    }
+
+    /// Returns the description given with
+    /// [`bind_described`](KeyMap::bind_described), if any.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Produces the bound message.
+    pub fn message(&self) -> M {
+        (self.message)()
+    }
+
+    /// Returns true if `key` is this binding's chord.
+    fn matches(&self, key: &KeyEvent) -> bool {
+        chord(&self.key) == chord(key)
+    }
+}
+
+impl<M> Clone for KeyMapBinding<M> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key,
+            description: self.description.clone(),
+            message: Arc::clone(&self.message),
+        }
+    }
+}
+
+impl<M> fmt::Debug for KeyMapBinding<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyMapBinding")
+            .field("key", &self.key)
+            .field("description", &self.description)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A table of key chords and the messages they produce.
+///
+/// Keys are compared by their normalized code and modifiers, so
+/// [`KeyEvent::char('G')`](KeyEvent::char) matches Shift+G and
+/// [`KeyEvent::ctrl('s')`](KeyEvent::ctrl) matches Ctrl+S however the
+/// terminal reports it. Key releases never match. When a chord is bound
+/// more than once, the first binding wins.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::input::{Event, Key, KeyEvent, KeyMap};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Msg { Quit, Save, Help }
+///
+/// let keymap = KeyMap::new()
+///     .bind_described(KeyEvent::char('q'), "Quit", Msg::Quit)
+///     .bind_described(KeyEvent::ctrl('s'), "Save", Msg::Save)
+///     .bind(KeyEvent::new(Key::F(1)), Msg::Help);
+///
+/// assert_eq!(keymap.message_for(&Event::char('q')), Some(Msg::Quit));
+/// assert_eq!(keymap.message_for(&Event::ctrl('s')), Some(Msg::Save));
+/// assert_eq!(keymap.message_for(&Event::char('s')), None);
+///
+/// // Describe the bindings, for example in a help bar
+/// let help: Vec<&str> = keymap.bindings().filter_map(|b| b.description()).collect();
+/// assert_eq!(help, ["Quit", "Save"]);
+/// ```
+pub struct KeyMap<M> {
+    bindings: Vec<KeyMapBinding<M>>,
+}
+
+impl<M> Default for KeyMap<M> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+}
+
+impl<M> Clone for KeyMap<M> {
+    fn clone(&self) -> Self {
+        Self {
+            bindings: self.bindings.clone(),
+        }
+    }
+}
+
+impl<M> fmt::Debug for KeyMap<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyMap")
+            .field("bindings", &self.bindings)
+            .finish()
+    }
+}
+
+impl<M> KeyMap<M> {
+    /// Creates an empty key map.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::KeyMap;
+    ///
+    /// let keymap: KeyMap<()> = KeyMap::new();
+    /// assert!(keymap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds a key chord to a message (builder pattern).
+    ///
+    /// The message is cloned each time the chord is pressed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{Event, KeyEvent, KeyMap};
+    ///
+    /// let keymap = KeyMap::new().bind(KeyEvent::char('q'), "quit");
+    /// assert_eq!(keymap.message_for(&Event::char('q')), Some("quit"));
+    /// ```
+    pub fn bind(self, key: KeyEvent, msg: M) -> Self
+    where
+        M: Clone + Send + Sync + 'static,
+    {
+        self.push(key, None, Arc::new(move || msg.clone()))
+    }
+
+    /// Binds a key chord to a message with a human-readable description
+    /// (builder pattern).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{KeyEvent, KeyMap};
+    ///
+    /// let keymap = KeyMap::new().bind_described(KeyEvent::char('?'), "Help", "help");
+    /// let binding = keymap.bindings().next().unwrap();
+    /// assert_eq!(binding.description(), Some("Help"));
+    /// ```
+    pub fn bind_described(self, key: KeyEvent, description: impl Into<String>, msg: M) -> Self
+    where
+        M: Clone + Send + Sync + 'static,
+    {
+        self.push(key, Some(description.into()), Arc::new(move || msg.clone()))
+    }
+
+    /// Binds a key chord to a function that builds the message (builder
+    /// pattern).
+    ///
+    /// Use this for message types that are not `Clone`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{Event, KeyEvent, KeyMap};
+    ///
+    /// let keymap = KeyMap::new().bind_with(KeyEvent::char('n'), || String::from("new"));
+    /// assert_eq!(keymap.message_for(&Event::char('n')), Some("new".to_string()));
+    /// ```
+    pub fn bind_with(
+        self,
+        key: KeyEvent,
+        message_fn: impl Fn() -> M + Send + Sync + 'static,
+    ) -> Self {
+        self.push(key, None, Arc::new(message_fn))
+    }
+
+    /// Removes every binding for a key chord. Returns true if any were
+    /// removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{KeyEvent, KeyMap};
+    ///
+    /// let mut keymap = KeyMap::new().bind(KeyEvent::char('q'), ());
+    /// assert!(keymap.unbind(KeyEvent::char('q')));
+    /// assert!(keymap.is_empty());
+    /// ```
+    pub fn unbind(&mut self, key: KeyEvent) -> bool {
+        let before = self.bindings.len();
+        self.bindings.retain(|binding| !binding.matches(&key));
+        self.bindings.len() != before
+    }
+
+    /// Returns the binding for the key pressed in `event`, if any.
+    pub fn lookup(&self, event: &Event) -> Option<&KeyMapBinding<M>> {
+        let key = event.as_key().filter(|key| !key.is_release())?;
+        self.bindings.iter().find(|binding| binding.matches(key))
+    }
+
+    /// Returns the message bound to the key pressed in `event`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{Event, KeyEvent, KeyMap};
+    ///
+    /// let keymap = KeyMap::new().bind(KeyEvent::char('G'), "bottom");
+    /// assert_eq!(keymap.message_for(&Event::char('G')), Some("bottom"));
+    /// assert_eq!(keymap.message_for(&Event::char('g')), None);
+    /// ```
+    pub fn message_for(&self, event: &Event) -> Option<M> {
+        self.lookup(event).map(KeyMapBinding::message)
+    }
+
+    /// Returns the bindings in the order they were added.
+    pub fn bindings(&self) -> impl Iterator<Item = &KeyMapBinding<M>> {
+        self.bindings.iter()
+    }
+
+    /// Returns the number of bindings.
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Returns true if there are no bindings.
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Appends a binding with a normalized key.
+    fn push(
+        mut self,
+        key: KeyEvent,
+        description: Option<String>,
+        message: Arc<dyn Fn() -> M + Send + Sync>,
+    ) -> Self {
+        self.bindings.push(KeyMapBinding {
+            key: key.normalized(),
+            description,
+            message,
+        });
+        self
+    }
+}
+
+/// Reduces a key event to the parts that identify it in a binding.
+fn chord(key: &KeyEvent) -> (Key, Modifiers) {
+    let key = key.normalized();
+    (key.code, key.modifiers)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,122 @@
+use super::*;
+use crate::input::KeyEventKind;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Msg {
+    Quit,
+    Save,
+    Help,
+    Bottom,
+}
+
+fn keymap() -> KeyMap<Msg> {
+    KeyMap::new()
+        .bind_described(KeyEvent::char('q'), "Quit", Msg::Quit)
+        .bind_described(KeyEvent::ctrl('s'), "Save", Msg::Save)
+        .bind(KeyEvent::new(Key::F(1)), Msg::Help)
+        .bind(KeyEvent::char('G'), Msg::Bottom)
+}
+
+#[test]
+fn test_new_is_empty() {
+    let keymap: KeyMap<Msg> = KeyMap::new();
+    assert!(keymap.is_empty());
+    assert_eq!(keymap.len(), 0);
+    assert_eq!(keymap.message_for(&Event::char('q')), None);
+}
+
+#[test]
+fn test_message_for_bound_keys() {
+    let keymap = keymap();
+    assert_eq!(keymap.len(), 4);
+    assert_eq!(keymap.message_for(&Event::char('q')), Some(Msg::Quit));
+    assert_eq!(keymap.message_for(&Event::ctrl('s')), Some(Msg::Save));
+    assert_eq!(keymap.message_for(&Event::key(Key::F(1))), Some(Msg::Help));
+}
+
+#[test]
+fn test_modifiers_must_match() {
+    let keymap = keymap();
+    assert_eq!(keymap.message_for(&Event::char('s')), None);
+    assert_eq!(keymap.message_for(&Event::ctrl('q')), None);
+    assert_eq!(keymap.message_for(&Event::char('g')), None);
+    assert_eq!(keymap.message_for(&Event::char('G')), Some(Msg::Bottom));
+}
+
+#[test]
+fn test_raw_control_character_matches_ctrl_binding() {
+    let keymap = keymap();
+    assert_eq!(
+        keymap.message_for(&Event::Key(KeyEvent::char('\x13'))),
+        Some(Msg::Save)
+    );
+}
+
+#[test]
+fn test_key_release_ignored() {
+    let keymap = keymap();
+    let mut release = KeyEvent::char('q');
+    release.kind = KeyEventKind::Release;
+    assert_eq!(keymap.message_for(&Event::Key(release)), None);
+}
+
+#[test]
+fn test_non_key_events_ignored() {
+    let keymap = keymap();
+    assert_eq!(keymap.message_for(&Event::Resize(80, 24)), None);
+    assert_eq!(keymap.message_for(&Event::click(0, 0)), None);
+}
+
+#[test]
+fn test_first_binding_wins() {
+    let keymap = KeyMap::new()
+        .bind(KeyEvent::char('q'), Msg::Quit)
+        .bind(KeyEvent::char('q'), Msg::Help);
+    assert_eq!(keymap.message_for(&Event::char('q')), Some(Msg::Quit));
+}
+
+#[test]
+fn test_unbind() {
+    let mut keymap = keymap();
+    assert!(keymap.unbind(KeyEvent::ctrl('s')));
+    assert!(!keymap.unbind(KeyEvent::ctrl('s')));
+    assert_eq!(keymap.len(), 3);
+    assert_eq!(keymap.message_for(&Event::ctrl('s')), None);
+}
+
+#[test]
+fn test_bind_with_non_clone_message() {
+    struct Token(u32);
+    let keymap = KeyMap::new().bind_with(KeyEvent::char('t'), || Token(7));
+    let Some(Token(n)) = keymap.message_for(&Event::char('t')) else {
+        panic!("expected a message");
+    };
+    assert_eq!(n, 7);
+}
+
+#[test]
+fn test_bindings_are_inspectable() {
+    let keymap = keymap();
+    let bindings: Vec<_> = keymap
+        .bindings()
+        .map(|b| (b.key().code, b.description()))
+        .collect();
+    assert_eq!(
+        bindings,
+        vec![
+            (Key::Char('q'), Some("Quit")),
+            (Key::Char('s'), Some("Save")),
+            (Key::F(1), None),
+            (Key::Char('g'), None),
+        ]
+    );
+}
+
+#[test]
+fn test_lookup_returns_binding() {
+    let keymap = keymap();
+    let binding = keymap.lookup(&Event::ctrl('s')).unwrap();
+    assert_eq!(binding.description(), Some("Save"));
+    assert!(binding.key().modifiers.ctrl());
+    assert_eq!(binding.message(), Msg::Save);
+}
@@ -21,13 +21,19 @@
 //! }
 //! ```
 
+mod click;
 pub(crate) mod convert;
 mod events;
 pub mod key;
+mod keymap;
 pub mod mouse;
 mod queue;
+mod sequence;
 
+pub use click::ClickTracker;
 pub use events::{Event, KeyEventBuilder, MouseEventBuilder};
-pub use key::{Key, KeyEvent, KeyEventKind, Modifiers};
+pub use key::{Key, KeyEvent, KeyEventKind, MediaKey, Modifiers};
+pub use keymap::{KeyMap, KeyMapBinding};
 pub use mouse::{MouseButton, MouseEvent, MouseEventKind};
 pub use queue::EventQueue;
+pub use sequence::{KeySequence, SequenceState};
@@ -4,7 +4,7 @@ use std::collections::VecDeque;
 use std::time::Duration;
 
 use super::events::Event;
-use super::key::Key;
+use super::key::{Key, KeyEvent, KeyEventKind, MediaKey};
 use super::mouse::MouseButton;
 
 /// A queue of simulated input events.
@@ -80,6 +80,29 @@ impl EventQueue {
         self.events.clear();
     }
 
+    /// Keeps only the events for which `keep` returns true, preserving
+    /// their order.
+    ///
+    /// Useful for dropping or coalescing noisy events before processing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::EventQueue;
+    ///
+    /// let mut queue = EventQueue::new();
+    /// queue.char('a');
+    /// queue.click(1, 1);
+    /// queue.char('b');
+    ///
+    /// // Discard all mouse events
+    /// queue.retain(|event| !event.is_mouse());
+    /// assert_eq!(queue.len(), 2);
+    /// ```
+    pub fn retain(&mut self, keep: impl FnMut(&Event) -> bool) {
+        self.events.retain(keep);
+    }
+
     /// Adds an event to the end of the queue.
     ///
     /// # Example
@@ -115,6 +138,51 @@ impl EventQueue {
         self.push(Event::key(key));
     }
 
+    /// Adds a press of `key`.
+    ///
+    /// Same as [`key`](Self::key); pairs with [`release`](Self::release)
+    /// and [`repeat`](Self::repeat) when simulating held keys.
+    pub fn press(&mut self, key: Key) {
+        self.key_with_kind(key, KeyEventKind::Press);
+    }
+
+    /// Adds a release of `key`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{EventQueue, Key, KeyEventKind};
+    ///
+    /// let mut queue = EventQueue::new();
+    /// queue.press(Key::Char('w'));
+    /// queue.repeat(Key::Char('w'));
+    /// queue.release(Key::Char('w'));
+    ///
+    /// let kinds: Vec<_> = queue
+    ///     .drain()
+    ///     .map(|event| event.as_key().unwrap().kind)
+    ///     .collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     vec![KeyEventKind::Press, KeyEventKind::Repeat, KeyEventKind::Release]
+    /// );
+    /// ```
+    pub fn release(&mut self, key: Key) {
+        self.key_with_kind(key, KeyEventKind::Release);
+    }
+
+    /// Adds an auto-repeat of `key`, as sent while the key is held down.
+    pub fn repeat(&mut self, key: Key) {
+        self.key_with_kind(key, KeyEventKind::Repeat);
+    }
+
+    fn key_with_kind(&mut self, key: Key, kind: KeyEventKind) {
+        self.push(Event::Key(KeyEvent {
+            kind,
+            ..KeyEvent::new(key)
+        }));
+    }
+
     /// Adds a character key event.
     pub fn char(&mut self, c: char) {
         self.push(Event::char(c));
@@ -214,7 +282,22 @@ impl EventQueue {
 
     /// Adds a function key event (F1-F12).
     pub fn function(&mut self, n: u8) {
-        self.key(Key::F(n));
+        self.push(Event::function(n));
+    }
+
+    /// Adds a numeric keypad digit event.
+    ///
+    /// The event is the same as a typed digit; see [`Event::keypad`]. Does
+    /// nothing if `digit` is greater than 9.
+    pub fn keypad(&mut self, digit: u8) {
+        if let Some(event) = Event::keypad(digit) {
+            self.push(event);
+        }
+    }
+
+    /// Adds a media key event.
+    pub fn media(&mut self, key: MediaKey) {
+        self.push(Event::media(key));
     }
 
     /// Adds a mouse click event.
@@ -222,7 +305,16 @@ impl EventQueue {
         self.push(Event::click(x, y));
     }
 
+    /// Adds a right-button click (press and release).
+    pub fn right_click(&mut self, x: u16, y: u16) {
+        self.push(Event::click_button(x, y, MouseButton::Right));
+        self.push(Event::mouse_up_button(x, y, MouseButton::Right));
+    }
+
     /// Adds a mouse double-click (two clicks at same position).
+    ///
+    /// The events carry no timing, so a [`ClickTracker`](super::ClickTracker)
+    /// fed them back to back recognizes the second press as a double-click.
     pub fn double_click(&mut self, x: u16, y: u16) {
         self.push(Event::click(x, y));
         self.push(Event::mouse_up(x, y));
@@ -247,6 +339,30 @@ impl EventQueue {
         self.push(Event::scroll_down(x, y));
     }
 
+    /// Adds `lines` scroll events at a position: down for positive values,
+    /// up for negative ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{Event, EventQueue};
+    ///
+    /// let mut queue = EventQueue::new();
+    /// queue.scroll_by(5, 5, -2);
+    /// assert_eq!(queue.len(), 2);
+    /// assert_eq!(queue.pop(), Some(Event::scroll_up(5, 5)));
+    /// ```
+    pub fn scroll_by(&mut self, x: u16, y: u16, lines: i16) {
+        let event = if lines < 0 {
+            Event::scroll_up(x, y)
+        } else {
+            Event::scroll_down(x, y)
+        };
+        for _ in 0..lines.unsigned_abs() {
+            self.push(event.clone());
+        }
+    }
+
     /// Adds a resize event.
     pub fn resize(&mut self, width: u16, height: u16) {
         self.push(Event::Resize(width, height));
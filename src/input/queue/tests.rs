@@ -1,5 +1,5 @@
 use super::*;
-use crate::input::key::Key;
+use crate::input::key::{Key, MediaKey};
 
 #[test]
 fn test_new_queue_is_empty() {
@@ -132,6 +132,26 @@ fn test_clear() {
     assert!(queue.is_empty());
 }
 
+#[test]
+fn test_retain() {
+    let mut queue = EventQueue::new();
+    queue.char('a');
+    queue.scroll_down(0, 0);
+    queue.char('b');
+    queue.resize(80, 24);
+    queue.scroll_up(0, 0);
+
+    queue.retain(|event| !event.is_mouse());
+    assert_eq!(queue.len(), 3);
+    assert_eq!(queue.pop(), Some(Event::char('a')));
+    assert_eq!(queue.pop(), Some(Event::char('b')));
+    assert_eq!(queue.pop(), Some(Event::Resize(80, 24)));
+
+    queue.type_str("xyz");
+    queue.retain(|_| false);
+    assert!(queue.is_empty());
+}
+
 #[test]
 fn test_push_front() {
     let mut queue = EventQueue::new();
@@ -150,6 +170,42 @@ fn test_double_click() {
     assert_eq!(queue.len(), 4); // down, up, down, up
 }
 
+#[test]
+fn test_right_click() {
+    let mut queue = EventQueue::new();
+    queue.right_click(4, 2);
+
+    assert_eq!(
+        queue.pop(),
+        Some(Event::click_button(4, 2, MouseButton::Right))
+    );
+    assert_eq!(
+        queue.pop(),
+        Some(Event::mouse_up_button(4, 2, MouseButton::Right))
+    );
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_scroll_by() {
+    let mut queue = EventQueue::new();
+    queue.scroll_by(1, 1, 3);
+    queue.scroll_by(1, 1, 0);
+    queue.scroll_by(1, 1, -1);
+
+    assert_eq!(queue.len(), 4);
+    let events: Vec<Event> = queue.drain().collect();
+    assert_eq!(
+        events,
+        vec![
+            Event::scroll_down(1, 1),
+            Event::scroll_down(1, 1),
+            Event::scroll_down(1, 1),
+            Event::scroll_up(1, 1),
+        ]
+    );
+}
+
 #[test]
 fn test_drag() {
     let mut queue = EventQueue::new();
@@ -200,6 +256,21 @@ fn test_function_keys() {
     assert_eq!(queue.pop(), Some(Event::key(Key::F(12))));
 }
 
+#[test]
+fn test_keypad_and_media_keys() {
+    let mut queue = EventQueue::new();
+    queue.keypad(5);
+    queue.keypad(10);
+    queue.media(MediaKey::PlayPause);
+
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue.pop(), Some(Event::char('5')));
+    assert_eq!(
+        queue.pop(),
+        Some(Event::key(Key::Media(MediaKey::PlayPause)))
+    );
+}
+
 #[test]
 fn test_scroll_down() {
     let mut queue = EventQueue::new();
@@ -323,3 +394,24 @@ fn test_key_method() {
 
     assert_eq!(queue.pop(), Some(Event::key(Key::Insert)));
 }
+
+#[test]
+fn test_press_release_repeat_set_kind() {
+    use crate::input::key::KeyEventKind;
+
+    let mut queue = EventQueue::new();
+    queue.press(Key::Up);
+    queue.repeat(Key::Up);
+    queue.release(Key::Char('W'));
+
+    assert_eq!(queue.pop(), Some(Event::key(Key::Up)));
+    let repeat = queue.pop().unwrap();
+    assert_eq!(repeat.as_key().unwrap().kind, KeyEventKind::Repeat);
+    assert_eq!(repeat.as_key().unwrap().code, Key::Up);
+    let release = queue.pop().unwrap();
+    let release = release.as_key().unwrap();
+    assert_eq!(release.kind, KeyEventKind::Release);
+    // Normalized like other key constructors
+    assert_eq!(release.code, Key::Char('w'));
+    assert!(release.modifiers.shift());
+}
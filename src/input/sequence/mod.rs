@@ -0,0 +1,321 @@
+//! Multi-key sequence recognition.
+//!
+//! [`KeySequence`] matches vim-style key sequences such as `gg` or `dd`.
+//! Register sequences with [`bind`](KeySequence::bind), then feed it every
+//! event. Each call reports whether the event started or continued a
+//! sequence, completed one, or is unrelated and should be handled normally.
+//!
+//! Keys must arrive within the configured timeout of each other; otherwise
+//! the pending keys are discarded. The timeout is measured with a
+//! [`Clock`], so a virtual clock makes timing deterministic in tests.
+
+use std::time::{Duration, Instant};
+
+use super::events::Event;
+use super::key::{Key, KeyEvent, Modifiers};
+use crate::app::Clock;
+
+/// The default maximum delay between keys of a sequence.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The result of feeding an event to a [`KeySequence`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SequenceState<M> {
+    /// The event is a prefix of at least one sequence; more keys are needed.
+    Partial,
+    /// The event completed a sequence, producing its message.
+    Matched(M),
+    /// The event is not part of any sequence and should be handled normally.
+    NoMatch,
+}
+
+/// A single key of a sequence: the normalized key and its modifiers.
+type Stroke = (Key, Modifiers);
+
+/// A stateful matcher for multi-key bindings like `gg`.
+///
+/// A sequence that is a prefix of a longer one matches as soon as it is
+/// typed, so the longer sequence is unreachable. Key releases and non-key
+/// events leave the pending keys untouched and report
+/// [`SequenceState::NoMatch`].
+///
+/// # Example
+///
+/// ```rust
+/// use envision::input::{Event, KeySequence, SequenceState};
+///
+/// let mut sequences = KeySequence::new()
+///     .bind_chars("gg", "top")
+///     .bind_chars("G", "bottom");
+///
+/// assert_eq!(sequences.feed(&Event::char('g')), SequenceState::Partial);
+/// assert_eq!(sequences.feed(&Event::char('g')), SequenceState::Matched("top"));
+/// assert_eq!(sequences.feed(&Event::char('G')), SequenceState::Matched("bottom"));
+/// assert_eq!(sequences.feed(&Event::char('x')), SequenceState::NoMatch);
+/// ```
+#[derive(Clone, Debug)]
+pub struct KeySequence<M> {
+    bindings: Vec<(Vec<Stroke>, M)>,
+    pending: Vec<Stroke>,
+    last_key_at: Option<Instant>,
+    timeout: Duration,
+    clock: Clock,
+}
+
+impl<M> Default for KeySequence<M> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+            pending: Vec::new(),
+            last_key_at: None,
+            timeout: DEFAULT_TIMEOUT,
+            clock: Clock::system(),
+        }
+    }
+}
+
+impl<M: Clone> KeySequence<M> {
+    /// Creates an empty matcher with a one second timeout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use envision::input::KeySequence;
+    ///
+    /// let sequences: KeySequence<()> = KeySequence::new();
+    /// assert_eq!(sequences.timeout(), Duration::from_secs(1));
+    /// assert!(!sequences.is_pending());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a sequence of key events that produces `msg` (builder
+    /// pattern).
+    ///
+    /// Keys are compared by their normalized code and modifiers. Empty
+    /// sequences are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{Event, Key, KeyEvent, KeySequence, SequenceState};
+    ///
+    /// let mut sequences = KeySequence::new()
+    ///     .bind([KeyEvent::ctrl('w'), KeyEvent::new(Key::Left)], "pane_left");
+    ///
+    /// assert_eq!(sequences.feed(&Event::ctrl('w')), SequenceState::Partial);
+    /// assert_eq!(
+    ///     sequences.feed(&Event::key(Key::Left)),
+    ///     SequenceState::Matched("pane_left"),
+    /// );
+    /// ```
+    pub fn bind(mut self, keys: impl IntoIterator<Item = KeyEvent>, msg: M) -> Self {
+        let strokes: Vec<Stroke> = keys.into_iter().map(stroke).collect();
+        if !strokes.is_empty() {
+            self.bindings.push((strokes, msg));
+        }
+        self
+    }
+
+    /// Registers a sequence of typed characters that produces `msg`
+    /// (builder pattern).
+    ///
+    /// Each character is converted with [`KeyEvent::char`], so `"G"` means
+    /// Shift+G.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{Event, KeySequence, SequenceState};
+    ///
+    /// let mut sequences = KeySequence::new().bind_chars("dd", "delete_line");
+    /// sequences.feed(&Event::char('d'));
+    /// assert_eq!(
+    ///     sequences.feed(&Event::char('d')),
+    ///     SequenceState::Matched("delete_line"),
+    /// );
+    /// ```
+    pub fn bind_chars(self, keys: &str, msg: M) -> Self {
+        self.bind(keys.chars().map(KeyEvent::char), msg)
+    }
+
+    /// Sets the maximum delay between keys of a sequence (builder pattern).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use envision::input::KeySequence;
+    ///
+    /// let sequences: KeySequence<()> =
+    ///     KeySequence::new().with_timeout(Duration::from_millis(500));
+    /// assert_eq!(sequences.timeout(), Duration::from_millis(500));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the clock used to measure the timeout (builder pattern).
+    ///
+    /// Pass the runtime's virtual clock to control timing in tests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use envision::app::Clock;
+    /// use envision::input::{Event, KeySequence, SequenceState};
+    ///
+    /// let clock = Clock::new_virtual();
+    /// let mut sequences = KeySequence::new()
+    ///     .bind_chars("gg", "top")
+    ///     .with_clock(clock.clone());
+    ///
+    /// sequences.feed(&Event::char('g'));
+    /// clock.advance(Duration::from_secs(2));
+    /// // Too slow: the first `g` expired and this one starts over
+    /// assert_eq!(sequences.feed(&Event::char('g')), SequenceState::Partial);
+    /// ```
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the maximum delay between keys of a sequence.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns true if a sequence has been started but not completed.
+    ///
+    /// Keys that have exceeded the timeout still count as pending until
+    /// the next key arrives; use [`expire`](Self::expire) to drop them
+    /// eagerly, for example from `on_tick`.
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Returns the keys typed so far in the current sequence.
+    ///
+    /// Useful for showing the pending keys in a status bar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::input::{Event, Key, KeySequence, Modifiers};
+    ///
+    /// let mut sequences = KeySequence::new().bind_chars("gg", ());
+    /// sequences.feed(&Event::char('g'));
+    /// assert_eq!(sequences.pending(), vec![(Key::Char('g'), Modifiers::NONE)]);
+    /// ```
+    pub fn pending(&self) -> &[(Key, Modifiers)] {
+        &self.pending
+    }
+
+    /// Discards any pending keys.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.last_key_at = None;
+    }
+
+    /// Discards the pending keys if the timeout has elapsed since the last
+    /// key. Returns true if keys were discarded.
+    pub fn expire(&mut self) -> bool {
+        let expired = self
+            .last_key_at
+            .is_some_and(|last| self.clock.now().saturating_duration_since(last) > self.timeout);
+        if expired && self.is_pending() {
+            self.reset();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Feeds an event to the matcher.
+    ///
+    /// When a pending sequence is broken by a key that does not continue
+    /// it, the pending keys are discarded and the key is matched on its
+    /// own, so it can start a new sequence.
+    pub fn feed(&mut self, event: &Event) -> SequenceState<M> {
+        let Some(key) = event.as_key() else {
+            return SequenceState::NoMatch;
+        };
+        if key.is_release() {
+            return SequenceState::NoMatch;
+        }
+
+        self.expire();
+        let stroke = stroke(*key);
+
+        let mut candidate = self.pending.clone();
+        candidate.push(stroke);
+        let state = self.advance(candidate);
+        if state != Advance::None || self.pending.is_empty() {
+            return self.finish(state);
+        }
+
+        // The pending sequence is broken; retry the key on its own
+        self.reset();
+        let state = self.advance(vec![stroke]);
+        self.finish(state)
+    }
+
+    /// Classifies a candidate sequence against the bindings.
+    fn advance(&self, candidate: Vec<Stroke>) -> Advance {
+        if let Some(index) = self
+            .bindings
+            .iter()
+            .position(|(keys, _)| *keys == candidate)
+        {
+            return Advance::Matched(index);
+        }
+        if self
+            .bindings
+            .iter()
+            .any(|(keys, _)| keys.starts_with(&candidate))
+        {
+            return Advance::Partial(candidate);
+        }
+        Advance::None
+    }
+
+    /// Applies a classification to the pending state.
+    fn finish(&mut self, state: Advance) -> SequenceState<M> {
+        match state {
+            Advance::Matched(index) => {
+                self.reset();
+                SequenceState::Matched(self.bindings[index].1.clone())
+            }
+            Advance::Partial(candidate) => {
+                self.pending = candidate;
+                self.last_key_at = Some(self.clock.now());
+                SequenceState::Partial
+            }
+            Advance::None => {
+                self.reset();
+                SequenceState::NoMatch
+            }
+        }
+    }
+}
+
+/// How a candidate sequence relates to the bindings.
+#[derive(PartialEq)]
+enum Advance {
+    Matched(usize),
+    Partial(Vec<Stroke>),
+    None,
+}
+
+/// Reduces a key event to the parts that identify it in a binding.
+fn stroke(key: KeyEvent) -> Stroke {
+    let key = key.normalized();
+    (key.code, key.modifiers)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,221 @@
+use super::*;
+use crate::input::KeyEventKind;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Msg {
+    Top,
+    Bottom,
+    DeleteLine,
+    DeleteWord,
+    PaneLeft,
+}
+
+fn vim() -> KeySequence<Msg> {
+    KeySequence::new()
+        .bind_chars("gg", Msg::Top)
+        .bind_chars("G", Msg::Bottom)
+        .bind_chars("dd", Msg::DeleteLine)
+        .bind_chars("dw", Msg::DeleteWord)
+}
+
+#[test]
+fn test_single_key_binding() {
+    let mut sequences = vim();
+    assert_eq!(
+        sequences.feed(&Event::char('G')),
+        SequenceState::Matched(Msg::Bottom)
+    );
+    assert!(!sequences.is_pending());
+}
+
+#[test]
+fn test_two_key_binding() {
+    let mut sequences = vim();
+    assert_eq!(sequences.feed(&Event::char('g')), SequenceState::Partial);
+    assert!(sequences.is_pending());
+    assert_eq!(
+        sequences.feed(&Event::char('g')),
+        SequenceState::Matched(Msg::Top)
+    );
+    assert!(!sequences.is_pending());
+}
+
+#[test]
+fn test_shared_prefix() {
+    let mut sequences = vim();
+    sequences.feed(&Event::char('d'));
+    assert_eq!(
+        sequences.feed(&Event::char('w')),
+        SequenceState::Matched(Msg::DeleteWord)
+    );
+    sequences.feed(&Event::char('d'));
+    assert_eq!(
+        sequences.feed(&Event::char('d')),
+        SequenceState::Matched(Msg::DeleteLine)
+    );
+}
+
+#[test]
+fn test_unbound_key_no_match() {
+    let mut sequences = vim();
+    assert_eq!(sequences.feed(&Event::char('x')), SequenceState::NoMatch);
+    assert!(!sequences.is_pending());
+}
+
+#[test]
+fn test_lowercase_does_not_match_shifted_binding() {
+    let mut sequences = vim();
+    // `g` starts `gg`, it is not `G`
+    assert_eq!(sequences.feed(&Event::char('g')), SequenceState::Partial);
+}
+
+#[test]
+fn test_broken_sequence_retries_key() {
+    let mut sequences = vim();
+    sequences.feed(&Event::char('d'));
+    // `dg` is not bound, but `g` starts `gg`
+    assert_eq!(sequences.feed(&Event::char('g')), SequenceState::Partial);
+    assert_eq!(sequences.pending(), [(Key::Char('g'), Modifiers::NONE)]);
+    assert_eq!(
+        sequences.feed(&Event::char('g')),
+        SequenceState::Matched(Msg::Top)
+    );
+}
+
+#[test]
+fn test_broken_sequence_matches_single_key() {
+    let mut sequences = vim();
+    sequences.feed(&Event::char('g'));
+    assert_eq!(
+        sequences.feed(&Event::char('G')),
+        SequenceState::Matched(Msg::Bottom)
+    );
+}
+
+#[test]
+fn test_broken_sequence_no_match_clears_pending() {
+    let mut sequences = vim();
+    sequences.feed(&Event::char('g'));
+    assert_eq!(sequences.feed(&Event::char('x')), SequenceState::NoMatch);
+    assert!(!sequences.is_pending());
+}
+
+#[test]
+fn test_modified_keys() {
+    let mut sequences = KeySequence::new().bind(
+        [KeyEvent::ctrl('w'), KeyEvent::new(Key::Left)],
+        Msg::PaneLeft,
+    );
+    assert_eq!(sequences.feed(&Event::char('w')), SequenceState::NoMatch);
+    assert_eq!(sequences.feed(&Event::ctrl('w')), SequenceState::Partial);
+    assert_eq!(
+        sequences.feed(&Event::key(Key::Left)),
+        SequenceState::Matched(Msg::PaneLeft)
+    );
+}
+
+#[test]
+fn test_raw_control_character_matches_ctrl_binding() {
+    let mut sequences = KeySequence::new().bind([KeyEvent::ctrl('w')], Msg::PaneLeft);
+    assert_eq!(
+        sequences.feed(&Event::Key(KeyEvent::char('\x17'))),
+        SequenceState::Matched(Msg::PaneLeft)
+    );
+}
+
+#[test]
+fn test_non_key_events_keep_pending() {
+    let mut sequences = vim();
+    sequences.feed(&Event::char('g'));
+    assert_eq!(
+        sequences.feed(&Event::Resize(80, 24)),
+        SequenceState::NoMatch
+    );
+    assert_eq!(sequences.feed(&Event::click(0, 0)), SequenceState::NoMatch);
+    assert_eq!(
+        sequences.feed(&Event::char('g')),
+        SequenceState::Matched(Msg::Top)
+    );
+}
+
+#[test]
+fn test_key_release_ignored() {
+    let mut sequences = vim();
+    sequences.feed(&Event::char('g'));
+    let mut release = KeyEvent::char('g');
+    release.kind = KeyEventKind::Release;
+    assert_eq!(sequences.feed(&Event::Key(release)), SequenceState::NoMatch);
+    assert_eq!(
+        sequences.feed(&Event::char('g')),
+        SequenceState::Matched(Msg::Top)
+    );
+}
+
+#[test]
+fn test_timeout_discards_pending() {
+    let clock = Clock::new_virtual();
+    let mut sequences = vim()
+        .with_timeout(Duration::from_millis(500))
+        .with_clock(clock.clone());
+
+    sequences.feed(&Event::char('d'));
+    clock.advance(Duration::from_millis(600));
+    // The stale `d` is dropped; `w` alone is unbound
+    assert_eq!(sequences.feed(&Event::char('w')), SequenceState::NoMatch);
+}
+
+#[test]
+fn test_within_timeout_matches() {
+    let clock = Clock::new_virtual();
+    let mut sequences = vim()
+        .with_timeout(Duration::from_millis(500))
+        .with_clock(clock.clone());
+
+    sequences.feed(&Event::char('g'));
+    clock.advance(Duration::from_millis(500));
+    assert_eq!(
+        sequences.feed(&Event::char('g')),
+        SequenceState::Matched(Msg::Top)
+    );
+}
+
+#[test]
+fn test_expire() {
+    let clock = Clock::new_virtual();
+    let mut sequences = vim().with_clock(clock.clone());
+
+    sequences.feed(&Event::char('g'));
+    assert!(!sequences.expire());
+    assert!(sequences.is_pending());
+
+    clock.advance(Duration::from_secs(2));
+    assert!(sequences.expire());
+    assert!(!sequences.is_pending());
+    assert!(!sequences.expire());
+}
+
+#[test]
+fn test_reset() {
+    let mut sequences = vim();
+    sequences.feed(&Event::char('g'));
+    sequences.reset();
+    assert!(!sequences.is_pending());
+    assert_eq!(sequences.feed(&Event::char('g')), SequenceState::Partial);
+}
+
+#[test]
+fn test_prefix_binding_matches_first() {
+    let mut sequences = KeySequence::new()
+        .bind_chars("g", Msg::Top)
+        .bind_chars("gg", Msg::Bottom);
+    assert_eq!(
+        sequences.feed(&Event::char('g')),
+        SequenceState::Matched(Msg::Top)
+    );
+}
+
+#[test]
+fn test_empty_binding_ignored() {
+    let mut sequences = KeySequence::new().bind_chars("", Msg::Top);
+    assert_eq!(sequences.feed(&Event::char('g')), SequenceState::NoMatch);
+}
@@ -178,15 +178,19 @@ pub use annotation::{
 #[cfg(feature = "serialization")]
 pub use app::load_state;
 pub use app::{
-    App, BatchSubscription, BoxedSubscription, ChannelSubscription, Command, CommandHandler,
-    ConfiguredRuntimeBuilder, DebounceSubscription, FilterSubscription, FnUpdate,
-    IntervalImmediateBuilder, IntervalImmediateSubscription, MappedSubscription, OptionalArgs,
-    Runtime, RuntimeBuilder, RuntimeConfig, StateExt, StreamSubscription, Subscription,
-    SubscriptionExt, TakeSubscription, TerminalEventSubscription, TerminalHook, TerminalRuntime,
-    ThrottleSubscription, TickSubscription, TickSubscriptionBuilder, TimerSubscription,
-    UnboundedChannelSubscription, Update, UpdateResult, VirtualRuntime, batch, interval_immediate,
-    terminal_events, tick,
+    AdaptiveIntervalSubscription, App, BatchSubscription, BoxedSubscription, ChannelSubscription,
+    Clock, CombineLatestSubscription, Command, CommandHandler, ConfiguredRuntimeBuilder,
+    DebounceSubscription, DynamicUpdate, FilterSubscription, FnUpdate, IntervalImmediateBuilder,
+    IntervalImmediateSubscription, MappedSubscription, OptionalArgs, Runtime, RuntimeBuilder,
+    RuntimeConfig, RuntimeDiagnostics, SkipWhileSubscription, StateExt, StepOutcome,
+    StopwatchSubscription, StreamSubscription, Subscription, SubscriptionExt, TakeSubscription,
+    TakeWhileSubscription, TerminalEventSubscription, TerminalHook, TerminalRuntime,
+    ThrottleSubscription, TickOutcome, TickSubscription, TickSubscriptionBuilder,
+    TimeoutSubscription, TimerSubscription, UnboundedChannelSubscription, UnfoldSubscription,
+    Update, UpdateResult, VirtualRuntime, batch, interval_immediate, terminal_events, tick,
 };
+#[cfg(feature = "cron")]
+pub use app::{CronSchedule, CronSubscription};
 pub use backend::{CaptureBackend, EnhancedCell, FrameSnapshot};
 // Core component traits and utilities (always available)
 pub use component::{Component, EventContext, FocusManager, RenderContext, Toggleable};
@@ -194,14 +198,16 @@ pub use component::{Component, EventContext, FocusManager, RenderContext, Toggle
 // Input components
 #[cfg(feature = "input-components")]
 pub use component::{
-    Button, ButtonMessage, ButtonOutput, ButtonState, Checkbox, CheckboxMessage, CheckboxOutput,
-    CheckboxState, Dropdown, DropdownMessage, DropdownOutput, DropdownState, InputField,
-    InputFieldMessage, InputFieldOutput, InputFieldState, LineInput, LineInputMessage,
-    LineInputOutput, LineInputState, NumberInput, NumberInputMessage, NumberInputOutput,
-    NumberInputState, RadioGroup, RadioGroupMessage, RadioGroupOutput, RadioGroupState, Select,
-    SelectMessage, SelectOutput, SelectState, Slider, SliderMessage, SliderOrientation,
-    SliderOutput, SliderState, Switch, SwitchMessage, SwitchOutput, SwitchState, TextArea,
-    TextAreaMessage, TextAreaOutput, TextAreaState,
+    Button, ButtonMessage, ButtonOutput, ButtonState, CheckList, CheckListMessage, CheckListOutput,
+    CheckListState, Checkbox, CheckboxMessage, CheckboxOutput, CheckboxState, Dropdown,
+    DropdownMessage, DropdownOutput, DropdownState, InputField, InputFieldMessage,
+    InputFieldOutput, InputFieldState, LimitKind, LineInput, LineInputMessage, LineInputOutput,
+    LineInputState, NumberInput, NumberInputMessage, NumberInputOutput, NumberInputState,
+    RadioGroup, RadioGroupMessage, RadioGroupOutput, RadioGroupState, SegmentedControl,
+    SegmentedControlMessage, SegmentedControlOutput, SegmentedControlState, Select, SelectMessage,
+    SelectOutput, SelectState, Slider, SliderMessage, SliderOrientation, SliderOutput, SliderState,
+    Switch, SwitchMessage, SwitchOutput, SwitchState, TextArea, TextAreaMessage, TextAreaOutput,
+    TextAreaState,
 };
 
 // Data components
@@ -217,11 +223,12 @@ pub use component::{
 // Display components
 #[cfg(feature = "display-components")]
 pub use component::{
-    BigText, BigTextMessage, BigTextState, Calendar, CalendarMessage, CalendarOutput,
-    CalendarState, Canvas, CanvasMarker, CanvasMessage, CanvasShape, CanvasState, CodeBlock,
+    Banner, BannerLevel, BannerMessage, BannerOutput, BannerState, BigText, BigTextMessage,
+    BigTextState, Calendar, CalendarMessage, CalendarOutput, CalendarState, Canvas, CanvasMarker,
+    CanvasMessage, CanvasShape, CanvasState, CardGrid, CardGridMessage, CardGridState, CodeBlock,
     CodeBlockMessage, CodeBlockState, Collapsible, CollapsibleMessage, CollapsibleOutput,
     CollapsibleState, Divider, DividerMessage, DividerOrientation, DividerState, Gauge,
-    GaugeMessage, GaugeOrientation, GaugeOutput, GaugeState, GaugeVariant, HelpPanel,
+    GaugeMessage, GaugeOrientation, GaugeOutput, GaugeState, GaugeVariant, Health, HelpPanel,
     HelpPanelMessage, HelpPanelState, KeyBinding, KeyBindingGroup, KeyHint, KeyHints,
     KeyHintsLayout, KeyHintsMessage, KeyHintsState, MultiProgress, MultiProgressMessage,
     MultiProgressOutput, MultiProgressState, Paginator, PaginatorMessage, PaginatorOutput,
@@ -231,24 +238,26 @@ pub use component::{
     ScrollableText, ScrollableTextMessage, ScrollableTextOutput, ScrollableTextState, Section,
     Sparkline, SparklineDirection, SparklineMessage, SparklineOutput, SparklineState, Spinner,
     SpinnerMessage, SpinnerState, SpinnerStyle, StatusBar, StatusBarItem, StatusBarItemContent,
-    StatusBarMessage, StatusBarState, StatusBarStyle, StatusLog, StatusLogEntry, StatusLogLevel,
-    StatusLogMessage, StatusLogOutput, StatusLogState, StyledText, StyledTextMessage,
-    StyledTextOutput, StyledTextState, TerminalOutput, TerminalOutputMessage, TerminalOutputOutput,
+    StatusBarMessage, StatusBarState, StatusBarStyle, StatusGrid, StatusGridMessage,
+    StatusGridOutput, StatusGridState, StatusLog, StatusLogEntry, StatusLogLevel, StatusLogMessage,
+    StatusLogOutput, StatusLogState, StyledText, StyledTextMessage, StyledTextOutput,
+    StyledTextState, TerminalOutput, TerminalOutputMessage, TerminalOutputOutput,
     TerminalOutputState, ThresholdZone, TitleCard, TitleCardMessage, TitleCardState, Toast,
     ToastItem, ToastLevel, ToastMessage, ToastOutput, ToastState, UsageDisplay,
-    UsageDisplayMessage, UsageDisplayState, UsageLayout, UsageMetric, big_char, big_char_width,
-    format_eta,
+    UsageDisplayMessage, UsageDisplayState, UsageLayout, UsageMetric, VisibleCard, big_char,
+    big_char_width, format_eta,
 };
 
 // Navigation components
 #[cfg(feature = "navigation-components")]
 pub use component::{
     Accordion, AccordionMessage, AccordionOutput, AccordionPanel, AccordionState, Breadcrumb,
-    BreadcrumbMessage, BreadcrumbOutput, BreadcrumbSegment, BreadcrumbState, CommandPalette,
-    CommandPaletteMessage, CommandPaletteOutput, CommandPaletteState, Menu, MenuItem, MenuMessage,
-    MenuOutput, MenuState, NavigationMode, PaletteItem, Router, RouterMessage, RouterOutput,
-    RouterState, StepIndicator, StepIndicatorMessage, StepIndicatorOutput, StepIndicatorState, Tab,
-    TabBar, TabBarMessage, TabBarOutput, TabBarState, Tabs, TabsMessage, TabsOutput, TabsState,
+    BreadcrumbMessage, BreadcrumbOutput, BreadcrumbSegment, BreadcrumbState, Carousel,
+    CarouselMessage, CarouselOutput, CarouselState, CommandPalette, CommandPaletteMessage,
+    CommandPaletteOutput, CommandPaletteState, Menu, MenuItem, MenuMessage, MenuOutput, MenuState,
+    NavigationMode, PaletteItem, Router, RouterMessage, RouterOutput, RouterState, StepIndicator,
+    StepIndicatorMessage, StepIndicatorOutput, StepIndicatorState, Tab, TabBar, TabBarMessage,
+    TabBarOutput, TabBarState, Tabs, TabsMessage, TabsOutput, TabsState,
 };
 
 // Compound components
@@ -407,14 +416,16 @@ pub use component::{MarkdownRenderer, MarkdownRendererMessage, MarkdownRendererS
 pub use error::{BoxedError, EnvisionError, Result};
 pub use harness::{AppHarness, Assertion, Snapshot, TestHarness};
 pub use input::{
-    Event, EventQueue, Key, KeyEvent, KeyEventKind, Modifiers, MouseButton, MouseEvent,
-    MouseEventKind,
+    ClickTracker, Event, EventQueue, Key, KeyEvent, KeyEventKind, KeyMap, KeyMapBinding,
+    KeySequence, MediaKey, Modifiers, MouseButton, MouseEvent, MouseEventKind, SequenceState,
 };
 pub use overlay::{Overlay, OverlayAction, OverlayStack};
 #[cfg(feature = "display-components")]
 pub use render::styled_line;
 pub use scroll::{ScrollState, render_scrollbar, render_scrollbar_inside_border};
-pub use theme::{NamedColor, Palette, Severity, Theme};
+pub use theme::{
+    ColorDepth, NamedColor, Palette, Severity, StatusLevel, Theme, ThemeMode, ThemeProvider,
+};
 
 /// Prelude module for convenient imports.
 ///
@@ -442,15 +453,15 @@ pub mod prelude {
 
     // Input
     pub use crate::input::{
-        Event, EventQueue, Key, KeyEvent, KeyEventKind, Modifiers, MouseButton, MouseEvent,
-        MouseEventKind,
+        ClickTracker, Event, EventQueue, Key, KeyEvent, KeyEventKind, KeyMap, KeySequence,
+        MediaKey, Modifiers, MouseButton, MouseEvent, MouseEventKind, SequenceState,
     };
 
     // Overlay
     pub use crate::overlay::{Overlay, OverlayAction, OverlayStack};
 
     // Theme
-    pub use crate::theme::{NamedColor, Severity, Theme};
+    pub use crate::theme::{NamedColor, Severity, StatusLevel, Theme, ThemeProvider};
 
     // Scroll infrastructure
     pub use crate::scroll::ScrollState;
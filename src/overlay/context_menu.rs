@@ -0,0 +1,386 @@
+//! Context menu overlay anchored to a screen position.
+
+use std::any::Any;
+use std::cell::Cell;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use unicode_width::UnicodeWidthStr;
+
+use crate::component::RenderContext;
+use crate::input::{Event, Key, MouseButton, MouseEventKind};
+
+use super::{Overlay, OverlayAction};
+
+/// A popup menu anchored to a screen position, typically opened on right-click.
+///
+/// The menu is a small bordered list whose top-left corner sits at the
+/// anchor. When that would push it past the right or bottom edge of the
+/// screen it opens to the left of or above the anchor instead, and it is
+/// clamped so it never leaves the screen.
+///
+/// Up/Down (or `k`/`j`) move the highlight, wrapping at either end.
+/// Enter, or a left-click on an item, dismisses the menu and dispatches
+/// that item's message. Esc or a click anywhere outside the menu
+/// dismisses it without a message. All other input is consumed while the
+/// menu is open, except terminal resizes.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::overlay::ContextMenuOverlay;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Msg { Copy, Paste }
+///
+/// let menu = ContextMenuOverlay::new(10, 4, vec![("Copy", Msg::Copy), ("Paste", Msg::Paste)]);
+/// assert_eq!(menu.anchor(), (10, 4));
+/// assert_eq!(menu.labels().collect::<Vec<_>>(), vec!["Copy", "Paste"]);
+/// assert_eq!(menu.selected(), 0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ContextMenuOverlay<M> {
+    anchor: (u16, u16),
+    items: Vec<(String, M)>,
+    selected: usize,
+    /// The screen area seen by the last render, used to hit-test clicks.
+    screen: Cell<Option<Rect>>,
+}
+
+impl<M> ContextMenuOverlay<M> {
+    /// Creates a context menu at column `x`, row `y` with the given
+    /// `(label, message)` items. The first item starts highlighted.
+    pub fn new<S: Into<String>>(x: u16, y: u16, items: impl IntoIterator<Item = (S, M)>) -> Self {
+        Self {
+            anchor: (x, y),
+            items: items
+                .into_iter()
+                .map(|(label, message)| (label.into(), message))
+                .collect(),
+            selected: 0,
+            screen: Cell::new(None),
+        }
+    }
+
+    /// Returns the `(x, y)` position the menu is anchored to.
+    pub fn anchor(&self) -> (u16, u16) {
+        self.anchor
+    }
+
+    /// Returns the item labels in order.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.items.iter().map(|(label, _)| label.as_str())
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the menu has no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the index of the highlighted item.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Returns the area the menu occupies on `screen`.
+    ///
+    /// The menu opens below and to the right of the anchor, flipping to
+    /// the other side of it on either axis that would overflow, and is
+    /// then clamped inside `screen`.
+    pub fn menu_area(&self, screen: Rect) -> Rect {
+        let label_width = self.labels().map(|l| l.width()).max().unwrap_or(0);
+        // Borders plus one column of padding on each side.
+        let width = u16::try_from(label_width + 4)
+            .unwrap_or(u16::MAX)
+            .min(screen.width);
+        let height = u16::try_from(self.items.len() + 2)
+            .unwrap_or(u16::MAX)
+            .min(screen.height);
+
+        let (x, y) = self.anchor;
+        let x = if x.saturating_add(width) > screen.right() {
+            x.saturating_add(1).saturating_sub(width)
+        } else {
+            x
+        };
+        let y = if y.saturating_add(height) > screen.bottom() {
+            y.saturating_add(1).saturating_sub(height)
+        } else {
+            y
+        };
+        let x = x.max(screen.x).min(screen.right().saturating_sub(width));
+        let y = y.max(screen.y).min(screen.bottom().saturating_sub(height));
+        Rect::new(x, y, width, height)
+    }
+
+    fn move_selection(&mut self, forward: bool) {
+        let len = self.items.len();
+        if len == 0 {
+            return;
+        }
+        self.selected = if forward {
+            (self.selected + 1) % len
+        } else {
+            (self.selected + len - 1) % len
+        };
+    }
+
+    fn activate(&mut self, index: usize) -> OverlayAction<M> {
+        if index >= self.items.len() {
+            return OverlayAction::Dismiss;
+        }
+        let (_, message) = self.items.swap_remove(index);
+        OverlayAction::DismissWithMessage(message)
+    }
+
+    /// Returns the index of the item at `(column, row)`, or `None` if the
+    /// point is on the border or outside the menu.
+    fn item_at(&self, area: Rect, column: u16, row: u16) -> Option<usize> {
+        let inner = area.inner(ratatui::layout::Margin::new(1, 1));
+        if !inner.contains(Position::new(column, row)) {
+            return None;
+        }
+        let index = usize::from(row - inner.y);
+        (index < self.items.len()).then_some(index)
+    }
+}
+
+impl<M: Send + 'static> Overlay<M> for ContextMenuOverlay<M> {
+    fn handle_event(&mut self, event: &Event) -> OverlayAction<M> {
+        if let Some(key) = event.as_key_press() {
+            return match key.code {
+                Key::Up | Key::Char('k') => {
+                    self.move_selection(false);
+                    OverlayAction::Consumed
+                }
+                Key::Down | Key::Char('j') => {
+                    self.move_selection(true);
+                    OverlayAction::Consumed
+                }
+                Key::Enter => self.activate(self.selected),
+                Key::Esc => OverlayAction::Dismiss,
+                _ => OverlayAction::Consumed,
+            };
+        }
+        match event {
+            Event::Resize(..) => OverlayAction::Propagate,
+            Event::Mouse(mouse) => {
+                let screen = self
+                    .screen
+                    .get()
+                    .unwrap_or(Rect::new(0, 0, u16::MAX, u16::MAX));
+                let area = self.menu_area(screen);
+                let inside = area.contains(Position::new(mouse.column, mouse.row));
+                match mouse.kind {
+                    MouseEventKind::Down(_) if !inside => OverlayAction::Dismiss,
+                    MouseEventKind::Down(MouseButton::Left) => {
+                        match self.item_at(area, mouse.column, mouse.row) {
+                            Some(index) => self.activate(index),
+                            None => OverlayAction::Consumed,
+                        }
+                    }
+                    MouseEventKind::Moved => {
+                        if let Some(index) = self.item_at(area, mouse.column, mouse.row) {
+                            self.selected = index;
+                        }
+                        OverlayAction::Consumed
+                    }
+                    _ => OverlayAction::Consumed,
+                }
+            }
+            _ => OverlayAction::Consumed,
+        }
+    }
+
+    fn view(&self, ctx: &mut RenderContext<'_, '_>) {
+        self.screen.set(Some(ctx.area));
+        let area = self.menu_area(ctx.area);
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        ctx.frame.render_widget(Clear, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(ctx.theme.focused_border_style());
+        let lines: Vec<Line> = self
+            .labels()
+            .enumerate()
+            .map(|(index, label)| {
+                let style = if index == self.selected {
+                    ctx.theme.selected_highlight_style(true)
+                } else {
+                    ctx.theme.normal_style()
+                };
+                Line::styled(format!(" {label} "), style)
+            })
+            .collect();
+        ctx.frame
+            .render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn menu() -> ContextMenuOverlay<&'static str> {
+        ContextMenuOverlay::new(
+            5,
+            2,
+            vec![("Cut", "cut"), ("Copy", "copy"), ("Paste", "paste")],
+        )
+    }
+
+    fn render<M: Send + 'static>(menu: &ContextMenuOverlay<M>, width: u16, height: u16) -> String {
+        let (mut terminal, theme) = crate::component::test_utils::setup_render(width, height);
+        terminal
+            .draw(|frame| {
+                menu.view(&mut RenderContext::new(frame, frame.area(), &theme));
+            })
+            .unwrap();
+        terminal.backend().to_string()
+    }
+
+    #[test]
+    fn test_context_menu_navigation_wraps() {
+        let mut menu = menu();
+        assert!(matches!(
+            menu.handle_event(&Event::key(Key::Up)),
+            OverlayAction::Consumed
+        ));
+        assert_eq!(menu.selected(), 2);
+        let _ = menu.handle_event(&Event::key(Key::Down));
+        assert_eq!(menu.selected(), 0);
+        let _ = menu.handle_event(&Event::char('j'));
+        assert_eq!(menu.selected(), 1);
+    }
+
+    #[test]
+    fn test_context_menu_enter_emits_selected_message() {
+        let mut menu = menu();
+        let _ = menu.handle_event(&Event::key(Key::Down));
+        assert!(matches!(
+            menu.handle_event(&Event::key(Key::Enter)),
+            OverlayAction::DismissWithMessage("copy")
+        ));
+    }
+
+    #[test]
+    fn test_context_menu_esc_and_outside_click_dismiss() {
+        let mut menu = menu();
+        assert!(matches!(
+            menu.handle_event(&Event::key(Key::Esc)),
+            OverlayAction::Dismiss
+        ));
+        let _ = render(&menu, 40, 10);
+        assert!(matches!(
+            menu.handle_event(&Event::click(0, 0)),
+            OverlayAction::Dismiss
+        ));
+        assert!(matches!(
+            menu.handle_event(&Event::click_button(30, 8, MouseButton::Right)),
+            OverlayAction::Dismiss
+        ));
+    }
+
+    #[test]
+    fn test_context_menu_click_on_item_activates_it() {
+        let mut menu = menu();
+        let _ = render(&menu, 40, 10);
+        // Border at row 2, items on rows 3..=5.
+        assert!(matches!(
+            menu.handle_event(&Event::click(7, 5)),
+            OverlayAction::DismissWithMessage("paste")
+        ));
+    }
+
+    #[test]
+    fn test_context_menu_hover_moves_highlight_and_border_click_is_consumed() {
+        let mut menu = menu();
+        let _ = render(&menu, 40, 10);
+        let _ = menu.handle_event(&Event::mouse_move(7, 4));
+        assert_eq!(menu.selected(), 1);
+        assert!(matches!(
+            menu.handle_event(&Event::click(5, 2)),
+            OverlayAction::Consumed
+        ));
+    }
+
+    #[test]
+    fn test_context_menu_ignores_key_release() {
+        use crate::input::{KeyEventBuilder, KeyEventKind};
+
+        let release = |key| {
+            KeyEventBuilder::new()
+                .code(key)
+                .kind(KeyEventKind::Release)
+                .into_event()
+        };
+        let mut menu = menu();
+        let _ = menu.handle_event(&Event::key(Key::Down));
+        assert!(matches!(
+            menu.handle_event(&release(Key::Down)),
+            OverlayAction::Consumed
+        ));
+        assert_eq!(menu.selected(), 1);
+        assert!(matches!(
+            menu.handle_event(&release(Key::Enter)),
+            OverlayAction::Consumed
+        ));
+    }
+
+    #[test]
+    fn test_context_menu_blocks_input_but_not_resize() {
+        let mut menu = menu();
+        assert!(matches!(
+            menu.handle_event(&Event::char('x')),
+            OverlayAction::Consumed
+        ));
+        assert!(matches!(
+            menu.handle_event(&Event::Resize(80, 24)),
+            OverlayAction::Propagate
+        ));
+    }
+
+    #[test]
+    fn test_context_menu_area_flips_at_screen_edges() {
+        let menu = menu();
+        let screen = Rect::new(0, 0, 40, 10);
+        // "Paste" is 5 wide: 9 columns, 5 rows.
+        assert_eq!(menu.menu_area(screen), Rect::new(5, 2, 9, 5));
+
+        let corner = ContextMenuOverlay::new(38, 8, vec![("Paste", ())]);
+        assert_eq!(corner.menu_area(screen), Rect::new(30, 6, 9, 3));
+
+        let tiny = Rect::new(0, 0, 6, 2);
+        assert_eq!(menu.menu_area(tiny), Rect::new(0, 0, 6, 2));
+    }
+
+    #[test]
+    fn test_context_menu_view() {
+        let output = render(&menu(), 20, 8);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[2].starts_with("     ┌───────┐"));
+        assert!(lines[3].contains("│ Cut   │"));
+        assert!(lines[5].contains("│ Paste │"));
+    }
+
+    #[test]
+    fn test_context_menu_view_flips_near_bottom_right() {
+        let menu = ContextMenuOverlay::new(19, 7, vec![("Open", ())]);
+        let output = render(&menu, 20, 8);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[5].ends_with("┌──────┐"));
+        assert!(lines[6].ends_with("│ Open │"));
+    }
+}
@@ -9,11 +9,24 @@
 //! - [`Overlay`]: Trait for overlay implementations (dialogs, search bars, etc.)
 //! - [`OverlayAction`]: Result of overlay event handling (consume, dismiss, propagate)
 //! - [`OverlayStack`]: Stack of active overlays managed by the runtime
+//! - [`ContextMenuOverlay`]: Popup menu anchored to a position, e.g. on right-click
+//! - [`ToastOverlay`]: Auto-dismissing toast notifications stacked in a corner
+//! - [`SpinnerOverlay`]: Modal loading spinner that blocks input
 
 mod action;
+mod context_menu;
+#[cfg(feature = "display-components")]
+mod spinner;
 mod stack;
+#[cfg(feature = "display-components")]
+mod toast;
 mod traits;
 
 pub use action::OverlayAction;
+pub use context_menu::ContextMenuOverlay;
+#[cfg(feature = "display-components")]
+pub use spinner::SpinnerOverlay;
 pub use stack::OverlayStack;
+#[cfg(feature = "display-components")]
+pub use toast::ToastOverlay;
 pub use traits::Overlay;
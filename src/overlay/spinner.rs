@@ -0,0 +1,202 @@
+//! Modal loading spinner overlay.
+
+use std::any::Any;
+use std::time::Duration;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use unicode_width::UnicodeWidthStr;
+
+use crate::component::{RenderContext, SpinnerStyle};
+use crate::input::Event;
+use crate::util::centered_rect;
+
+use super::{Overlay, OverlayAction};
+
+/// A modal overlay that shows an animated spinner with a caption.
+///
+/// The spinner is drawn in a bordered box centered on the screen and
+/// advances one frame per runtime tick. While it is on the stack it
+/// consumes every input event except terminal resizes, blocking the UI
+/// underneath until it is removed. Key releases, which the runtime never
+/// routes through overlays, are dropped while it is shown rather than
+/// reaching the app.
+///
+/// Use [`Command::show_spinner`](crate::app::Command::show_spinner) and
+/// [`Command::hide_spinner`](crate::app::Command::hide_spinner) to show and
+/// remove it from `update()`. Showing a spinner while one is already on
+/// the stack replaces its caption instead of stacking a second one.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::overlay::SpinnerOverlay;
+///
+/// let overlay = SpinnerOverlay::new("Loading…");
+/// assert_eq!(overlay.message(), "Loading…");
+/// assert_eq!(overlay.frame(), 0);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpinnerOverlay {
+    message: String,
+    frame: usize,
+}
+
+impl SpinnerOverlay {
+    /// Creates a spinner overlay with the given caption.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            frame: 0,
+        }
+    }
+
+    /// Returns the caption.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Sets the caption.
+    pub fn set_message(&mut self, message: impl Into<String>) {
+        self.message = message.into();
+    }
+
+    /// Returns the current animation frame index.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+}
+
+impl<M> Overlay<M> for SpinnerOverlay {
+    fn handle_event(&mut self, event: &Event) -> OverlayAction<M> {
+        match event {
+            Event::Resize(..) => OverlayAction::Propagate,
+            _ => OverlayAction::Consumed,
+        }
+    }
+
+    fn blocks_input(&self) -> bool {
+        true
+    }
+
+    fn view(&self, ctx: &mut RenderContext<'_, '_>) {
+        let symbol = SpinnerStyle::Dots.frame(self.frame).to_string();
+        let content_width = symbol.width() + 1 + self.message.width();
+        // Borders plus one column of padding on each side.
+        let width = u16::try_from(content_width + 4).unwrap_or(u16::MAX);
+        let area = centered_rect(width, 3, ctx.area);
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        ctx.frame.render_widget(Clear, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(ctx.theme.border_style());
+        let line = Line::from(vec![
+            Span::styled(symbol, ctx.theme.info_style()),
+            Span::raw(" "),
+            Span::raw(self.message.as_str()),
+        ]);
+        let paragraph = Paragraph::new(line)
+            .alignment(Alignment::Center)
+            .block(block);
+        ctx.frame.render_widget(paragraph, area);
+    }
+
+    fn on_tick(&mut self, _elapsed: Duration) -> OverlayAction<M> {
+        self.frame = (self.frame + 1) % SpinnerStyle::Dots.frame_count();
+        OverlayAction::Consumed
+    }
+
+    /// A second spinner only replaces this one's caption.
+    fn merge(&mut self, incoming: &mut dyn Overlay<M>) -> bool {
+        match incoming
+            .as_any_mut()
+            .and_then(|any| any.downcast_mut::<Self>())
+        {
+            Some(incoming) => {
+                self.message = std::mem::take(&mut incoming.message);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::overlay::OverlayStack;
+
+    #[test]
+    fn test_spinner_overlay_blocks_input_but_not_resize() {
+        let mut overlay = SpinnerOverlay::new("Loading");
+        let action: OverlayAction<i32> = overlay.handle_event(&Event::char('q'));
+        assert!(matches!(action, OverlayAction::Consumed));
+        let action: OverlayAction<i32> = overlay.handle_event(&Event::Resize(80, 24));
+        assert!(matches!(action, OverlayAction::Propagate));
+    }
+
+    #[test]
+    fn test_spinner_overlay_advances_on_tick() {
+        let mut overlay = SpinnerOverlay::new("Loading");
+        for _ in 0..SpinnerStyle::Dots.frame_count() - 1 {
+            let action: OverlayAction<i32> = overlay.on_tick(Duration::from_millis(100));
+            assert!(matches!(action, OverlayAction::Consumed));
+        }
+        assert_eq!(overlay.frame(), SpinnerStyle::Dots.frame_count() - 1);
+        let _: OverlayAction<i32> = overlay.on_tick(Duration::from_millis(100));
+        assert_eq!(overlay.frame(), 0);
+    }
+
+    #[test]
+    fn test_stack_replaces_spinner_caption() {
+        let mut stack: OverlayStack<i32> = OverlayStack::new();
+        stack.push(Box::new(SpinnerOverlay::new("Loading")));
+        stack.push(Box::new(SpinnerOverlay::new("Saving")));
+        assert_eq!(stack.len(), 1);
+        let overlay = stack.find_mut::<SpinnerOverlay>().unwrap();
+        assert_eq!(overlay.message(), "Saving");
+    }
+
+    #[test]
+    fn test_stack_removes_spinner_below_other_overlays() {
+        use crate::component::ToastLevel;
+        use crate::overlay::ToastOverlay;
+
+        let mut stack: OverlayStack<i32> = OverlayStack::new();
+        stack.push(Box::new(SpinnerOverlay::new("Loading")));
+        stack.push(Box::new(ToastOverlay::new().with_toast(
+            "Saved",
+            ToastLevel::Success,
+            Duration::from_secs(1),
+        )));
+        assert!(stack.remove::<SpinnerOverlay>().is_some());
+        assert_eq!(stack.len(), 1);
+        assert!(stack.find_mut::<ToastOverlay<i32>>().is_some());
+        assert!(stack.remove::<SpinnerOverlay>().is_none());
+    }
+
+    #[test]
+    fn test_spinner_overlay_view() {
+        let mut overlay = SpinnerOverlay::new("Loading");
+        let _: OverlayAction<i32> = overlay.on_tick(Duration::from_millis(100));
+        let (mut terminal, theme) = crate::component::test_utils::setup_render(30, 7);
+        terminal
+            .draw(|frame| {
+                Overlay::<i32>::view(
+                    &overlay,
+                    &mut RenderContext::new(frame, frame.area(), &theme),
+                );
+            })
+            .unwrap();
+        let output = terminal.backend().to_string();
+        assert!(output.contains("│ ⠙ Loading │"));
+        assert_eq!(output.lines().nth(2).unwrap().trim(), "┌───────────┐");
+    }
+}
@@ -1,5 +1,10 @@
 //! Overlay stack implementation.
 
+use std::any::Any;
+use std::time::Duration;
+
+use ratatui::style::{Modifier, Style};
+
 use crate::component::RenderContext;
 use crate::input::Event;
 
@@ -10,6 +15,11 @@ use super::traits::Overlay;
 ///
 /// The stack renders overlays bottom-up (so the topmost draws last) and
 /// processes events top-down (so the topmost gets first chance to handle).
+///
+/// Rendering starts at the topmost [opaque](Overlay::is_opaque) overlay, so
+/// layers it hides are never drawn. The topmost overlay that
+/// [dims its backdrop](Overlay::dims_backdrop) dims everything drawn before
+/// it; lower overlays that also ask for a backdrop don't dim again.
 pub struct OverlayStack<M> {
     layers: Vec<Box<dyn Overlay<M>>>,
 }
@@ -20,11 +30,6 @@ impl<M> OverlayStack<M> {
         Self { layers: Vec::new() }
     }
 
-    /// Pushes an overlay onto the top of the stack.
-    pub fn push(&mut self, overlay: Box<dyn Overlay<M>>) {
-        self.layers.push(overlay);
-    }
-
     /// Pops the topmost overlay from the stack.
     pub fn pop(&mut self) -> Option<Box<dyn Overlay<M>>> {
         self.layers.pop()
@@ -65,12 +70,115 @@ impl<M> OverlayStack<M> {
         OverlayAction::Propagate
     }
 
-    /// Renders all overlays bottom-up (so topmost draws last).
+    /// Advances every overlay by `elapsed` (bottom-up).
+    ///
+    /// Overlays that return [`OverlayAction::Dismiss`] or
+    /// [`OverlayAction::DismissWithMessage`] are removed. Returns the messages
+    /// produced by the overlays, in stack order, for the caller to dispatch.
+    pub(crate) fn tick(&mut self, elapsed: Duration) -> Vec<M> {
+        let mut messages = Vec::new();
+        let mut index = 0;
+        while index < self.layers.len() {
+            match self.layers[index].on_tick(elapsed) {
+                OverlayAction::Consumed | OverlayAction::Propagate => index += 1,
+                OverlayAction::KeepAndMessage(msg) => {
+                    messages.push(msg);
+                    index += 1;
+                }
+                OverlayAction::Dismiss => {
+                    self.layers.remove(index);
+                }
+                OverlayAction::DismissWithMessage(msg) => {
+                    messages.push(msg);
+                    self.layers.remove(index);
+                }
+            }
+        }
+        messages
+    }
+
+    /// Returns true if an [opaque](Overlay::is_opaque) overlay hides the
+    /// app view.
+    pub fn hides_view(&self) -> bool {
+        self.layers.iter().any(|overlay| overlay.is_opaque())
+    }
+
+    /// Returns true if an overlay on the stack
+    /// [blocks input](Overlay::blocks_input) to the app.
+    pub fn blocks_input(&self) -> bool {
+        self.layers.iter().any(|overlay| overlay.blocks_input())
+    }
+
+    /// Renders the visible overlays bottom-up (so topmost draws last).
+    ///
+    /// Overlays below the topmost opaque overlay are skipped, and the area
+    /// is dimmed just before the topmost overlay that asks for a backdrop.
     pub(crate) fn render(&self, ctx: &mut RenderContext<'_, '_>) {
-        for overlay in &self.layers {
+        let first = self
+            .layers
+            .iter()
+            .rposition(|overlay| overlay.is_opaque())
+            .unwrap_or(0);
+        let backdrop = self
+            .layers
+            .iter()
+            .rposition(|overlay| overlay.dims_backdrop());
+
+        for (index, overlay) in self.layers.iter().enumerate().skip(first) {
+            if backdrop == Some(index) {
+                ctx.frame
+                    .buffer_mut()
+                    .set_style(ctx.area, Style::default().add_modifier(Modifier::DIM));
+            }
             overlay.view(&mut ctx.with_area(ctx.area));
         }
     }
+
+    /// Pushes an overlay onto the top of the stack.
+    ///
+    /// The overlays already on the stack are first offered the new one,
+    /// topmost first, through [`Overlay::merge`]. If one of them takes it
+    /// in, nothing is pushed: this is how toasts share a single stacked
+    /// corner and a second spinner only replaces the caption.
+    pub fn push(&mut self, mut overlay: Box<dyn Overlay<M>>) {
+        for existing in self.layers.iter_mut().rev() {
+            if existing.merge(overlay.as_mut()) {
+                return;
+            }
+        }
+        self.layers.push(overlay);
+    }
+
+    /// Removes the topmost overlay of concrete type `T`, returning it.
+    ///
+    /// Only overlays that override [`Overlay::as_any_mut`] can be removed
+    /// this way.
+    pub fn remove<T: 'static>(&mut self) -> Option<Box<dyn Overlay<M>>> {
+        self.remove_where(|any| any.is::<T>())
+    }
+
+    /// Removes the topmost overlay whose [`Overlay::as_any_mut`] matches
+    /// `predicate`, returning it.
+    pub(crate) fn remove_where(
+        &mut self,
+        predicate: fn(&dyn Any) -> bool,
+    ) -> Option<Box<dyn Overlay<M>>> {
+        let index = self
+            .layers
+            .iter_mut()
+            .rposition(|overlay| overlay.as_any_mut().is_some_and(|any| predicate(any)))?;
+        Some(self.layers.remove(index))
+    }
+
+    /// Returns the topmost overlay of concrete type `T`, if any.
+    ///
+    /// Only overlays that override [`Overlay::as_any_mut`] can be found.
+    pub fn find_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.layers
+            .iter_mut()
+            .rev()
+            .find_map(|overlay| overlay.as_any_mut()?.downcast_mut::<T>())
+    }
 }
 
 impl<M> Default for OverlayStack<M> {
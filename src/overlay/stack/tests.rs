@@ -221,3 +221,180 @@ fn test_stack_render_with_overlays() {
     assert_eq!(count1.load(Ordering::Relaxed), 1);
     assert_eq!(count2.load(Ordering::Relaxed), 1);
 }
+
+/// An overlay that clears one row (the top row by default) and writes its
+/// label there.
+struct LabelOverlay {
+    label: &'static str,
+    opaque: bool,
+    backdrop: bool,
+    area: Option<ratatui::layout::Rect>,
+}
+
+impl LabelOverlay {
+    fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            opaque: false,
+            backdrop: false,
+            area: None,
+        }
+    }
+}
+
+impl Overlay<i32> for LabelOverlay {
+    fn handle_event(&mut self, _event: &Event) -> OverlayAction<i32> {
+        OverlayAction::Consumed
+    }
+
+    fn view(&self, ctx: &mut RenderContext<'_, '_>) {
+        let area = self.area.unwrap_or(ratatui::layout::Rect {
+            height: 1,
+            ..ctx.area
+        });
+        ctx.frame.render_widget(ratatui::widgets::Clear, area);
+        ctx.frame.buffer_mut().set_string(
+            area.x,
+            area.y,
+            self.label,
+            ratatui::style::Style::default(),
+        );
+    }
+
+    fn is_opaque(&self) -> bool {
+        self.opaque
+    }
+
+    fn dims_backdrop(&self) -> bool {
+        self.backdrop
+    }
+}
+
+fn render_stack(stack: &OverlayStack<i32>) -> ratatui::buffer::Buffer {
+    let backend = ratatui::backend::TestBackend::new(20, 3);
+    let mut terminal = ratatui::Terminal::new(backend).unwrap();
+    let theme = crate::theme::Theme::default();
+    terminal
+        .draw(|frame| {
+            frame
+                .buffer_mut()
+                .set_string(0, 2, "app", ratatui::style::Style::default());
+            let area = frame.area();
+            let mut ctx = RenderContext::new(frame, area, &theme);
+            stack.render(&mut ctx);
+        })
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn is_dim(buffer: &ratatui::buffer::Buffer, x: u16, y: u16) -> bool {
+    buffer[(x, y)]
+        .modifier
+        .contains(ratatui::style::Modifier::DIM)
+}
+
+#[test]
+fn test_stack_hides_view_with_opaque_overlay() {
+    let mut stack: OverlayStack<i32> = OverlayStack::new();
+    stack.push(Box::new(LabelOverlay::new("menu")));
+    assert!(!stack.hides_view());
+
+    stack.push(Box::new(LabelOverlay {
+        opaque: true,
+        ..LabelOverlay::new("full")
+    }));
+    assert!(stack.hides_view());
+}
+
+#[test]
+fn test_stack_render_skips_layers_below_opaque_overlay() {
+    let mut stack: OverlayStack<i32> = OverlayStack::new();
+    stack.push(Box::new(LabelOverlay::new("menu")));
+    stack.push(Box::new(LabelOverlay {
+        opaque: true,
+        area: Some(ratatui::layout::Rect::new(0, 1, 20, 1)),
+        ..LabelOverlay::new("full")
+    }));
+
+    let buffer = render_stack(&stack);
+    assert_eq!(buffer[(0, 0)].symbol(), " ");
+    assert_eq!(buffer[(0, 1)].symbol(), "f");
+}
+
+#[test]
+fn test_stack_render_dims_only_below_topmost_backdrop() {
+    let mut stack: OverlayStack<i32> = OverlayStack::new();
+    stack.push(Box::new(LabelOverlay {
+        backdrop: true,
+        ..LabelOverlay::new("menu")
+    }));
+    stack.push(Box::new(LabelOverlay {
+        backdrop: true,
+        area: Some(ratatui::layout::Rect::new(0, 1, 20, 1)),
+        ..LabelOverlay::new("dialog")
+    }));
+
+    let buffer = render_stack(&stack);
+    // App content and the menu sit below the dialog's backdrop
+    assert!(is_dim(&buffer, 0, 2));
+    assert!(is_dim(&buffer, 0, 0));
+    assert_eq!(buffer[(0, 0)].symbol(), "m");
+    // The dialog itself is drawn after the backdrop
+    assert!(!is_dim(&buffer, 0, 1));
+    assert_eq!(buffer[(0, 1)].symbol(), "d");
+}
+
+#[test]
+fn test_stack_render_without_backdrop_leaves_content_undimmed() {
+    let mut stack: OverlayStack<i32> = OverlayStack::new();
+    stack.push(Box::new(LabelOverlay::new("menu")));
+
+    let buffer = render_stack(&stack);
+    assert!(!is_dim(&buffer, 0, 2));
+    assert!(!is_dim(&buffer, 0, 0));
+}
+
+/// An overlay that absorbs any later `CounterOverlay`, adding up counts.
+struct CounterOverlay {
+    count: i32,
+}
+
+impl Overlay<i32> for CounterOverlay {
+    fn handle_event(&mut self, _event: &Event) -> OverlayAction<i32> {
+        OverlayAction::KeepAndMessage(self.count)
+    }
+
+    fn view(&self, _ctx: &mut RenderContext<'_, '_>) {}
+
+    fn merge(&mut self, incoming: &mut dyn Overlay<i32>) -> bool {
+        match incoming
+            .as_any_mut()
+            .and_then(|any| any.downcast_mut::<Self>())
+        {
+            Some(incoming) => {
+                self.count += incoming.count;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+#[test]
+fn test_stack_push_merges_into_overlay_below() {
+    let mut stack: OverlayStack<i32> = OverlayStack::new();
+    stack.push(Box::new(CounterOverlay { count: 1 }));
+    stack.push(Box::new(PropagateOverlay));
+    stack.push(Box::new(CounterOverlay { count: 2 }));
+    assert_eq!(stack.len(), 2);
+
+    let _ = stack.pop();
+    assert!(matches!(
+        stack.handle_event(&Event::char('x')),
+        OverlayAction::KeepAndMessage(3)
+    ));
+}
@@ -0,0 +1,246 @@
+//! Toast notification overlay.
+
+use std::any::Any;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use crate::component::{
+    Component, RenderContext, Toast, ToastLevel, ToastMessage, ToastOutput, ToastState,
+};
+use crate::input::Event;
+
+use super::{Overlay, OverlayAction};
+
+/// An overlay that shows auto-dismissing toast notifications.
+///
+/// Toasts are stacked in the bottom-right corner of the screen and count
+/// down on every runtime tick. Once the last toast expires the overlay
+/// dismisses itself. Toasts never intercept input, so the app underneath
+/// keeps receiving events.
+///
+/// Use [`Command::toast`](crate::app::Command::toast) to show a toast from
+/// `update()`. Pushing a toast overlay while one is already on the stack
+/// adds its toasts to the existing overlay instead of stacking a second one.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use envision::component::ToastLevel;
+/// use envision::overlay::ToastOverlay;
+///
+/// let mut overlay: ToastOverlay<()> = ToastOverlay::new();
+/// overlay.push("Saved", ToastLevel::Success, Duration::from_secs(3));
+/// assert_eq!(overlay.state().len(), 1);
+/// ```
+pub struct ToastOverlay<M> {
+    state: ToastState,
+    _message: PhantomData<fn() -> M>,
+}
+
+impl<M> ToastOverlay<M> {
+    /// Creates an empty toast overlay.
+    pub fn new() -> Self {
+        Self::with_state(ToastState::new())
+    }
+
+    /// Creates a toast overlay backed by the given toast state.
+    ///
+    /// Use this to configure limits such as
+    /// [`ToastState::set_max_visible`] before pushing the overlay.
+    pub fn with_state(state: ToastState) -> Self {
+        Self {
+            state,
+            _message: PhantomData,
+        }
+    }
+
+    /// Adds a toast that expires after `duration`, returning its ID.
+    pub fn push(&mut self, text: impl Into<String>, level: ToastLevel, duration: Duration) -> u64 {
+        self.push_message(text.into(), level, Some(duration_ms(duration)))
+    }
+
+    /// Adds a toast through the toast component's `Push` message.
+    fn push_message(
+        &mut self,
+        message: String,
+        level: ToastLevel,
+        duration_ms: Option<u64>,
+    ) -> u64 {
+        match Toast::update(
+            &mut self.state,
+            ToastMessage::Push {
+                message,
+                level,
+                duration_ms,
+            },
+        ) {
+            Some(ToastOutput::Added(id)) => id,
+            _ => unreachable!("ToastMessage::Push always produces ToastOutput::Added"),
+        }
+    }
+
+    /// Adds a toast that expires after `duration` (builder pattern).
+    pub fn with_toast(
+        mut self,
+        text: impl Into<String>,
+        level: ToastLevel,
+        duration: Duration,
+    ) -> Self {
+        self.push(text, level, duration);
+        self
+    }
+
+    /// Returns the underlying toast state.
+    pub fn state(&self) -> &ToastState {
+        &self.state
+    }
+
+    /// Returns the underlying toast state mutably.
+    pub fn state_mut(&mut self) -> &mut ToastState {
+        &mut self.state
+    }
+
+    /// Moves all toasts from `other` into this overlay.
+    fn absorb(&mut self, other: &mut Self) {
+        let toasts: Vec<_> = other.state.toasts().to_vec();
+        for toast in toasts {
+            self.push_message(
+                toast.message().to_string(),
+                toast.level(),
+                toast.remaining_ms(),
+            );
+        }
+        Toast::update(&mut other.state, ToastMessage::Clear);
+    }
+}
+
+/// Converts a duration to whole milliseconds, saturating at `u64::MAX`.
+fn duration_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+impl<M> Default for ToastOverlay<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: 'static> Overlay<M> for ToastOverlay<M> {
+    fn handle_event(&mut self, _event: &Event) -> OverlayAction<M> {
+        OverlayAction::Propagate
+    }
+
+    fn view(&self, ctx: &mut RenderContext<'_, '_>) {
+        Toast::view(&self.state, ctx);
+    }
+
+    fn on_tick(&mut self, elapsed: Duration) -> OverlayAction<M> {
+        Toast::update(&mut self.state, ToastMessage::Tick(duration_ms(elapsed)));
+        if self.state.is_empty() {
+            OverlayAction::Dismiss
+        } else {
+            OverlayAction::Consumed
+        }
+    }
+
+    /// Toasts pushed while this overlay is up join its stack.
+    fn merge(&mut self, incoming: &mut dyn Overlay<M>) -> bool {
+        match incoming
+            .as_any_mut()
+            .and_then(|any| any.downcast_mut::<Self>())
+        {
+            Some(incoming) => {
+                self.absorb(incoming);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::overlay::OverlayStack;
+
+    #[test]
+    fn test_toast_overlay_expires_on_tick() {
+        let mut overlay: ToastOverlay<i32> = ToastOverlay::new().with_toast(
+            "Saved",
+            ToastLevel::Success,
+            Duration::from_millis(200),
+        );
+
+        let action = overlay.on_tick(Duration::from_millis(100));
+        assert!(matches!(action, OverlayAction::Consumed));
+        assert_eq!(overlay.state().len(), 1);
+
+        let action = overlay.on_tick(Duration::from_millis(100));
+        assert!(matches!(action, OverlayAction::Dismiss));
+        assert!(overlay.state().is_empty());
+    }
+
+    #[test]
+    fn test_toast_overlay_propagates_events() {
+        let mut overlay: ToastOverlay<i32> =
+            ToastOverlay::new().with_toast("Hi", ToastLevel::Info, Duration::from_secs(1));
+        let action = overlay.handle_event(&Event::char('a'));
+        assert!(matches!(action, OverlayAction::Propagate));
+    }
+
+    #[test]
+    fn test_stack_merges_toast_overlays() {
+        let mut stack: OverlayStack<i32> = OverlayStack::new();
+        stack.push(Box::new(ToastOverlay::new().with_toast(
+            "One",
+            ToastLevel::Info,
+            Duration::from_secs(1),
+        )));
+        stack.push(Box::new(ToastOverlay::new().with_toast(
+            "Two",
+            ToastLevel::Error,
+            Duration::from_secs(2),
+        )));
+        assert_eq!(stack.len(), 1);
+
+        let overlay = stack.find_mut::<ToastOverlay<i32>>().unwrap();
+        let messages: Vec<&str> = overlay
+            .state()
+            .toasts()
+            .iter()
+            .map(|t| t.message())
+            .collect();
+        assert_eq!(messages, vec!["One", "Two"]);
+    }
+
+    #[test]
+    fn test_stack_tick_removes_expired_toast_overlay() {
+        let mut stack: OverlayStack<i32> = OverlayStack::new();
+        stack.push(Box::new(ToastOverlay::new().with_toast(
+            "One",
+            ToastLevel::Info,
+            Duration::from_millis(50),
+        )));
+        let messages = stack.tick(Duration::from_millis(50));
+        assert!(messages.is_empty());
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_toast_overlay_view() {
+        let overlay: ToastOverlay<i32> =
+            ToastOverlay::new().with_toast("Saved", ToastLevel::Success, Duration::from_secs(1));
+        let (mut terminal, theme) = crate::component::test_utils::setup_render(50, 10);
+        terminal
+            .draw(|frame| {
+                overlay.view(&mut RenderContext::new(frame, frame.area(), &theme));
+            })
+            .unwrap();
+        assert!(terminal.backend().to_string().contains("[+] Saved"));
+    }
+}
@@ -1,5 +1,8 @@
 //! Overlay trait definition.
 
+use std::any::Any;
+use std::time::Duration;
+
 use crate::component::RenderContext;
 use crate::input::Event;
 
@@ -24,7 +27,7 @@ use super::OverlayAction;
 ///
 /// impl Overlay<String> for ConfirmDialog {
 ///     fn handle_event(&mut self, event: &Event) -> OverlayAction<String> {
-///         if let Some(key) = event.as_key() {
+///         if let Some(key) = event.as_key_press() {
 ///             match key.code {
 ///                 Key::Char('y') => OverlayAction::DismissWithMessage("confirmed".into()),
 ///                 Key::Char('n') | Key::Esc => OverlayAction::Dismiss,
@@ -48,6 +51,68 @@ pub trait Overlay<M>: Send {
 
     /// Render the overlay on top of the main view.
     fn view(&self, ctx: &mut RenderContext<'_, '_>);
+
+    /// Advance time-based overlay state.
+    ///
+    /// Called by the runtime once per tick with the tick duration. Return
+    /// [`OverlayAction::Dismiss`] or [`OverlayAction::DismissWithMessage`] to
+    /// remove the overlay, or [`OverlayAction::KeepAndMessage`] to dispatch a
+    /// message. [`OverlayAction::Consumed`] and [`OverlayAction::Propagate`]
+    /// both keep the overlay unchanged.
+    ///
+    /// The default implementation does nothing.
+    fn on_tick(&mut self, _elapsed: Duration) -> OverlayAction<M> {
+        OverlayAction::Propagate
+    }
+
+    /// Returns true if the overlay covers the whole screen.
+    ///
+    /// When an opaque overlay is on the stack, the app view and every
+    /// overlay beneath it are hidden, so
+    /// [`OverlayStack`](super::OverlayStack) skips rendering them. The
+    /// default implementation returns `false`.
+    fn is_opaque(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the content beneath this overlay should be dimmed.
+    ///
+    /// Only the topmost overlay that requests a backdrop gets one: the app
+    /// view and the overlays below it are dimmed, while overlays above it
+    /// render normally. The default implementation returns `false`.
+    fn dims_backdrop(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the overlay blocks all input to the app while shown.
+    ///
+    /// Key releases skip the overlay stack and go straight to the app, so a
+    /// blocking overlay never sees them. While an overlay that blocks input
+    /// is on the stack, the runtime drops releases instead. The default
+    /// implementation returns `false`.
+    fn blocks_input(&self) -> bool {
+        false
+    }
+
+    /// Offers this overlay a newly pushed one to take in.
+    ///
+    /// [`OverlayStack::push`](super::OverlayStack::push) calls this on the
+    /// overlays already on the stack, topmost first. Return `true` to absorb
+    /// `incoming` into this overlay instead of stacking it, for overlays
+    /// that should exist only once, such as a toast corner. The default
+    /// implementation returns `false`.
+    fn merge(&mut self, _incoming: &mut dyn Overlay<M>) -> bool {
+        false
+    }
+
+    /// Returns this overlay as [`Any`] so it can be found by concrete type.
+    ///
+    /// Overlays that return `Some(self)` can be located on the stack with
+    /// [`OverlayStack::find_mut`](super::OverlayStack::find_mut). The default
+    /// implementation returns `None`.
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -109,6 +174,24 @@ mod tests {
         assert!(matches!(action, OverlayAction::DismissWithMessage(ref s) if s == "confirmed"));
     }
 
+    #[test]
+    fn test_overlay_default_on_tick_propagates() {
+        let mut overlay = TestOverlay { consumed_count: 0 };
+        let action = overlay.on_tick(Duration::from_millis(100));
+        assert!(matches!(action, OverlayAction::Propagate));
+        assert!(overlay.as_any_mut().is_none());
+        let mut other = TestOverlay { consumed_count: 0 };
+        assert!(!overlay.merge(&mut other));
+    }
+
+    #[test]
+    fn test_overlay_default_is_transparent_without_backdrop() {
+        let overlay = TestOverlay { consumed_count: 0 };
+        assert!(!overlay.is_opaque());
+        assert!(!overlay.dims_backdrop());
+        assert!(!overlay.blocks_input());
+    }
+
     #[test]
     fn test_overlay_handle_event_propagate() {
         let mut overlay = TestOverlay { consumed_count: 0 };
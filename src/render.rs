@@ -32,7 +32,7 @@ use ratatui::text::Text;
 use ratatui::widgets::Paragraph;
 
 use crate::component::styled_text::{StyledContent, StyledInline};
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Render a sequence of styled inline elements as a single line into `area`.
 ///
@@ -59,7 +59,12 @@ use crate::theme::Theme;
 ///     styled_line(frame, area, &inlines, theme);
 /// }
 /// ```
-pub fn styled_line(frame: &mut Frame, area: Rect, inlines: &[StyledInline], theme: &Theme) {
+pub fn styled_line(
+    frame: &mut Frame,
+    area: Rect,
+    inlines: &[StyledInline],
+    theme: &dyn ThemeProvider,
+) {
     if area.width == 0 || area.height == 0 {
         return;
     }
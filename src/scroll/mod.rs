@@ -55,7 +55,7 @@ use ratatui::Frame;
 use ratatui::layout::Rect;
 use ratatui::widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState};
 
-use crate::theme::Theme;
+use crate::theme::ThemeProvider;
 
 /// Tracks scroll position for virtual scrolling in TUI components.
 ///
@@ -365,7 +365,12 @@ impl ScrollState {
 /// render_scrollbar(&scroll, frame, area, &theme);
 /// # }).unwrap();
 /// ```
-pub fn render_scrollbar(scroll: &ScrollState, frame: &mut Frame, area: Rect, theme: &Theme) {
+pub fn render_scrollbar(
+    scroll: &ScrollState,
+    frame: &mut Frame,
+    area: Rect,
+    theme: &dyn ThemeProvider,
+) {
     if !scroll.can_scroll() {
         return;
     }
@@ -388,7 +393,7 @@ pub fn render_scrollbar_inside_border(
     scroll: &ScrollState,
     frame: &mut Frame,
     area: Rect,
-    theme: &Theme,
+    theme: &dyn ThemeProvider,
 ) {
     if !scroll.can_scroll() || area.height < 3 {
         return;
@@ -2,6 +2,7 @@ use ratatui::Terminal;
 
 use super::*;
 use crate::backend::CaptureBackend;
+use crate::theme::Theme;
 
 fn setup_render(width: u16, height: u16) -> Terminal<CaptureBackend> {
     let backend = CaptureBackend::new(width, height);
@@ -0,0 +1,172 @@
+//! Terminal background detection for choosing a light or dark theme.
+//!
+//! The terminal is asked for its background color with the OSC 11 query
+//! (`ESC ] 11 ; ? ESC \`). Terminals that support it answer with an
+//! `rgb:RRRR/GGGG/BBBB` color, whose brightness decides the [`ThemeMode`].
+
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+
+/// How long to wait for the terminal to answer the background query.
+pub(crate) const DETECT_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Whether the terminal has a light or dark background.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::theme::ThemeMode;
+///
+/// assert_eq!(ThemeMode::default(), ThemeMode::Dark);
+/// assert!(ThemeMode::Light.is_light());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum ThemeMode {
+    /// A light background with dark text.
+    Light,
+    /// A dark background with light text.
+    #[default]
+    Dark,
+}
+
+impl ThemeMode {
+    /// Returns true for [`ThemeMode::Light`].
+    pub fn is_light(self) -> bool {
+        self == Self::Light
+    }
+
+    /// Returns true for [`ThemeMode::Dark`].
+    pub fn is_dark(self) -> bool {
+        self == Self::Dark
+    }
+
+    /// Classifies an OSC 11 background color response.
+    ///
+    /// Accepts the `rgb:R/G/B` color with or without the surrounding escape
+    /// sequence, where each component has one to four hex digits. Returns
+    /// `None` if the response can't be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::theme::ThemeMode;
+    ///
+    /// let mode = ThemeMode::from_osc11_response("\x1b]11;rgb:ffff/ffff/ffff\x1b\\");
+    /// assert_eq!(mode, Some(ThemeMode::Light));
+    /// assert_eq!(ThemeMode::from_osc11_response("rgb:1e/1e/2e"), Some(ThemeMode::Dark));
+    /// assert_eq!(ThemeMode::from_osc11_response("garbage"), None);
+    /// ```
+    pub fn from_osc11_response(response: &str) -> Option<Self> {
+        let start = response.find("rgb:")? + "rgb:".len();
+        let color = response[start..].trim_end_matches(['\x07', '\x1b', '\\']);
+
+        let mut components = color.split('/').map(parse_component);
+        let r = components.next()??;
+        let g = components.next()??;
+        let b = components.next()??;
+        if components.next().is_some() {
+            return None;
+        }
+
+        let luminance = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        Some(if luminance > 0.5 {
+            Self::Light
+        } else {
+            Self::Dark
+        })
+    }
+}
+
+/// Parses a 1-4 digit hex color component into the range `0.0..=1.0`.
+fn parse_component(hex: &str) -> Option<f64> {
+    if hex.is_empty() || hex.len() > 4 {
+        return None;
+    }
+    let value = u16::from_str_radix(hex, 16).ok()?;
+    let max = (1u32 << (4 * hex.len())) - 1;
+    Some(f64::from(value) / f64::from(max))
+}
+
+/// Queries the terminal background, falling back to dark.
+///
+/// Temporarily enables raw mode so the response isn't echoed. If stdin or
+/// stdout isn't a terminal, or no answer arrives within `timeout`, the
+/// terminal is assumed to be dark.
+pub(crate) fn detect_terminal_mode(timeout: Duration) -> ThemeMode {
+    query_background(timeout)
+        .and_then(|response| ThemeMode::from_osc11_response(&response))
+        .unwrap_or_default()
+}
+
+fn query_background(timeout: Duration) -> Option<String> {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return None;
+    }
+
+    let was_raw = crossterm::terminal::is_raw_mode_enabled().unwrap_or(false);
+    if !was_raw {
+        crossterm::terminal::enable_raw_mode().ok()?;
+    }
+
+    let response = send_query().ok().and_then(|()| read_response(timeout));
+
+    if !was_raw {
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+    response
+}
+
+fn send_query() -> io::Result<()> {
+    let mut stdout = io::stdout();
+    stdout.write_all(b"\x1b]11;?\x1b\\")?;
+    stdout.flush()
+}
+
+/// Reads the OSC response through crossterm's event reader, so nothing is
+/// left reading stdin once `timeout` passes.
+///
+/// crossterm has no OSC event, so the response arrives as key events: the
+/// introducer as `Alt+]`, the payload as plain characters and the
+/// terminator as `Alt+\` (ST) or `Ctrl+G` (BEL). The payload characters
+/// are collected back into a string.
+fn read_response(timeout: Duration) -> Option<String> {
+    let deadline = Instant::now() + timeout;
+    let mut response: Option<String> = None;
+    while response.as_ref().is_none_or(|text| text.len() < 64) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if !event::poll(remaining).ok()? {
+            return None;
+        }
+        let Event::Key(key) = event::read().ok()? else {
+            continue;
+        };
+        if collect_response_key(&mut response, &key) {
+            break;
+        }
+    }
+    response
+}
+
+/// Feeds one key event into the OSC response being reassembled.
+///
+/// Characters only count once the `Alt+]` introducer has been read, so
+/// keys typed before the answer arrives aren't taken for its payload.
+/// Returns true when the terminator is read.
+pub(crate) fn collect_response_key(response: &mut Option<String>, key: &KeyEvent) -> bool {
+    let alt = key.modifiers.contains(KeyModifiers::ALT);
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match (response.as_mut(), key.code) {
+        (_, KeyCode::Char(']')) if alt => *response = Some(String::new()),
+        (Some(_), KeyCode::Char('\\')) if alt => return true,
+        (Some(_), KeyCode::Char('g')) if ctrl => return true,
+        (Some(text), KeyCode::Char(c)) => text.push(c),
+        _ => {}
+    }
+    false
+}
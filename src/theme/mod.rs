@@ -24,6 +24,23 @@
 //! // Component::view(&state, &mut ctx);
 //! ```
 //!
+//! # Matching the Terminal Background
+//!
+//! [`Theme::detect`] asks the terminal for its background color and reports
+//! a [`ThemeMode`]; [`Theme::auto`] picks a built-in theme for it.
+//!
+//! # Limited Color Terminals
+//!
+//! [`Theme::downsample`] maps an RGB theme onto the 256 or 16 colors a
+//! terminal supports; [`ColorDepth::detect`] reads the depth from
+//! `$COLORTERM` and `$TERM`.
+//!
+//! # Color Ramps
+//!
+//! [`Theme::gradient`] and [`Theme::sequential_ramp`] build color ramps for
+//! heatmaps and charts, interpolated perceptually; [`ColorRamp::color_at`]
+//! maps a single normalized value.
+//!
 //! # Creating Custom Themes
 //!
 //! You can create custom themes by constructing a `Theme` directly:
@@ -43,6 +60,9 @@ pub mod catppuccin;
 #[allow(deprecated)]
 pub use catppuccin::*;
 
+mod detect;
+pub use detect::ThemeMode;
+
 pub mod dracula;
 #[allow(deprecated)]
 pub use dracula::*;
@@ -56,13 +76,25 @@ pub mod nord;
 pub use nord::*;
 
 pub mod palette;
-pub use palette::{NamedColor, Palette, Severity};
+pub use palette::{NamedColor, Palette, Severity, StatusLevel};
+
+mod provider;
+pub use provider::ThemeProvider;
+
+pub mod ramp;
+pub use ramp::ColorRamp;
 
 pub mod solarized;
 #[allow(deprecated)]
 pub use solarized::*;
 
+mod terminal_palette;
+
+pub use crate::backend::ColorDepth;
+
 use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, BorderType};
 
 // =============================================================================
 // Theme Struct
@@ -79,6 +111,7 @@ use ratatui::style::{Color, Modifier, Style};
 /// - **Interactive states**: `focused`, `selected`, `disabled`, `placeholder`
 /// - **Semantic colors**: `primary`, `success`, `warning`, `error`, `info`
 /// - **Progress bar**: `progress_filled`, `progress_empty`
+/// - **Borders**: `border_type`, `focus_ring`
 ///
 /// # Example
 ///
@@ -127,6 +160,15 @@ pub struct Theme {
     /// Empty portion of progress bars.
     pub progress_empty: Color,
 
+    // Borders
+    /// Line style of the borders drawn by [`Theme::block`], e.g. plain or
+    /// rounded.
+    pub border_type: BorderType,
+    /// Line style of the focus ring drawn by [`Theme::focused_block`]
+    /// around the focused element, e.g. double or thick. `None` marks
+    /// focus by border color alone.
+    pub focus_ring: Option<BorderType>,
+
     // Named-color palette (26 entries; populated per-theme)
     /// Theme-specific palette of named colors. Use [`Theme::color`] for theme-aware
     /// lookup; this field is exposed primarily for users constructing custom themes.
@@ -182,6 +224,9 @@ impl Default for Theme {
             progress_filled: Color::Cyan,
             progress_empty: Color::Black,
 
+            border_type: BorderType::Plain,
+            focus_ring: None,
+
             palette: Palette {
                 rosewater: Color::Red,
                 flamingo: Color::Red,
@@ -276,6 +321,9 @@ impl Theme {
             progress_filled: NORD8,
             progress_empty: NORD1,
 
+            border_type: BorderType::Plain,
+            focus_ring: None,
+
             palette: Palette {
                 rosewater: NORD4,
                 flamingo: NORD4,
@@ -364,6 +412,9 @@ impl Theme {
             progress_filled: DRACULA_PURPLE,
             progress_empty: DRACULA_CURRENT,
 
+            border_type: BorderType::Plain,
+            focus_ring: None,
+
             palette: Palette {
                 rosewater: DRACULA_PINK,
                 flamingo: DRACULA_PINK,
@@ -453,6 +504,9 @@ impl Theme {
             progress_filled: SOLARIZED_BLUE,
             progress_empty: SOLARIZED_BASE02,
 
+            border_type: BorderType::Plain,
+            focus_ring: None,
+
             palette: Palette {
                 rosewater: SOLARIZED_MAGENTA,
                 flamingo: SOLARIZED_MAGENTA,
@@ -541,6 +595,9 @@ impl Theme {
             progress_filled: GRUVBOX_YELLOW,
             progress_empty: GRUVBOX_BG1,
 
+            border_type: BorderType::Plain,
+            focus_ring: None,
+
             palette: Palette {
                 rosewater: GRUVBOX_PURPLE,
                 flamingo: GRUVBOX_PURPLE,
@@ -616,6 +673,9 @@ impl Theme {
             progress_filled: CATPPUCCIN_LAVENDER,
             progress_empty: CATPPUCCIN_SURFACE0,
 
+            border_type: BorderType::Plain,
+            focus_ring: None,
+
             palette: Palette {
                 rosewater: CATPPUCCIN_ROSEWATER,
                 flamingo: CATPPUCCIN_FLAMINGO,
@@ -647,6 +707,113 @@ impl Theme {
         }
     }
 
+    /// Detects whether the terminal has a light or dark background.
+    ///
+    /// Queries the terminal's background color with the OSC 11 escape
+    /// sequence and classifies its brightness. Falls back to
+    /// [`ThemeMode::Dark`] when stdin or stdout isn't a terminal, or when the
+    /// terminal doesn't answer within 100ms.
+    ///
+    /// This reads the response directly from stdin, so call it before
+    /// starting the terminal runtime, which takes over input. Keys typed
+    /// while it waits are discarded. A reply that arrives after the timeout
+    /// is left in the input stream, where it shows up as an `Alt+]` key
+    /// followed by the color characters and the terminator.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use envision::theme::{Theme, ThemeMode};
+    ///
+    /// let theme = match Theme::detect() {
+    ///     ThemeMode::Light => Theme::default(),
+    ///     ThemeMode::Dark => Theme::nord(),
+    /// };
+    /// # let _ = theme;
+    /// ```
+    pub fn detect() -> ThemeMode {
+        detect::detect_terminal_mode(detect::DETECT_TIMEOUT)
+    }
+
+    /// Returns a built-in theme suited to the detected terminal background.
+    ///
+    /// See [`Theme::detect`] for how the background is detected. Light
+    /// terminals get [`Theme::default`], whose base colors follow the
+    /// terminal's own; dark terminals get [`Theme::catppuccin_mocha`].
+    /// Use [`Theme::for_mode`] to pick a theme for a known mode.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use envision::theme::Theme;
+    ///
+    /// let theme = Theme::auto();
+    /// # let _ = theme;
+    /// ```
+    pub fn auto() -> Self {
+        Self::for_mode(Self::detect())
+    }
+
+    /// Returns the built-in theme [`Theme::auto`] chooses for `mode`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::theme::{Theme, ThemeMode};
+    ///
+    /// assert_eq!(Theme::for_mode(ThemeMode::Light), Theme::default());
+    /// assert_eq!(Theme::for_mode(ThemeMode::Dark), Theme::catppuccin_mocha());
+    /// ```
+    pub fn for_mode(mode: ThemeMode) -> Self {
+        match mode {
+            ThemeMode::Light => Self::default(),
+            ThemeMode::Dark => Self::catppuccin_mocha(),
+        }
+    }
+
+    /// Returns a copy of this theme with every color mapped to the nearest
+    /// color available at `depth`.
+    ///
+    /// RGB themes such as [`Theme::nord`] rely on 24-bit color. On a terminal
+    /// limited to 256 or 16 colors, downsampling picks the perceptually
+    /// closest palette entry instead of leaving the choice to the terminal
+    /// emulator. Use [`ColorDepth::detect`] to find the terminal's depth.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::theme::{ColorDepth, Theme};
+    /// use ratatui::style::Color;
+    ///
+    /// let theme = Theme::nord().downsample(ColorDepth::Ansi16);
+    /// assert!(!matches!(theme.focused, Color::Rgb(..) | Color::Indexed(_)));
+    ///
+    /// let theme = Theme::nord().downsample(ColorDepth::Ansi256);
+    /// assert!(matches!(theme.focused, Color::Indexed(_)));
+    /// ```
+    pub fn downsample(&self, depth: ColorDepth) -> Self {
+        let map = |color| depth.downsample(color);
+        Self {
+            background: map(self.background),
+            foreground: map(self.foreground),
+            border: map(self.border),
+            focused: map(self.focused),
+            selected: map(self.selected),
+            disabled: map(self.disabled),
+            placeholder: map(self.placeholder),
+            primary: map(self.primary),
+            success: map(self.success),
+            warning: map(self.warning),
+            error: map(self.error),
+            info: map(self.info),
+            progress_filled: map(self.progress_filled),
+            progress_empty: map(self.progress_empty),
+            border_type: self.border_type,
+            focus_ring: self.focus_ring,
+            palette: self.palette.map(map),
+        }
+    }
+
     // =========================================================================
     // Style Helper Methods
     // =========================================================================
@@ -682,6 +849,15 @@ impl Theme {
         Style::default().fg(self.focused).bg(self.background)
     }
 
+    /// Returns a style for the focus ring around the focused element.
+    ///
+    /// Like [`focused_border_style`](Theme::focused_border_style), but
+    /// bold, so the ring stands out from ordinary borders on terminals
+    /// that draw bold box characters heavier.
+    pub fn focus_ring_style(&self) -> Style {
+        self.focused_border_style().add_modifier(Modifier::BOLD)
+    }
+
     /// Returns a style for selected items.
     ///
     /// Uses bold modifier. In focused context, also uses focused color.
@@ -786,6 +962,74 @@ impl Theme {
             .fg(self.progress_filled)
             .bg(self.progress_empty)
     }
+
+    /// Returns a fully bordered block styled for the focus state.
+    ///
+    /// The border uses [`focused_border_style`](Theme::focused_border_style)
+    /// when `focused` and [`border_style`](Theme::border_style) otherwise,
+    /// drawn with the theme's [`border_type`](Theme::border_type).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::theme::Theme;
+    /// use ratatui::widgets::{BorderType, Paragraph};
+    ///
+    /// let theme = Theme {
+    ///     border_type: BorderType::Rounded,
+    ///     ..Theme::nord()
+    /// };
+    /// let paragraph = Paragraph::new("text").block(theme.block(true));
+    /// # let _ = paragraph;
+    /// ```
+    pub fn block(&self, focused: bool) -> Block<'static> {
+        ThemeProvider::block(self, focused)
+    }
+
+    /// Returns a fully bordered block with a title, styled like
+    /// [`block`](Theme::block).
+    ///
+    /// The title takes the same style as the border.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::theme::Theme;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let theme = Theme::default();
+    /// let paragraph = Paragraph::new("text").block(theme.titled_block("Notes", false));
+    /// # let _ = paragraph;
+    /// ```
+    pub fn titled_block<'a>(&self, title: impl Into<Line<'a>>, focused: bool) -> Block<'a> {
+        ThemeProvider::titled_block(self, title.into(), focused)
+    }
+
+    /// Returns a fully bordered block that draws a focus ring when
+    /// `focused`.
+    ///
+    /// With a [`focus_ring`](Theme::focus_ring) set, the focused block
+    /// switches to that line style in
+    /// [`focus_ring_style`](Theme::focus_ring_style), so keyboard focus
+    /// is unmistakable rather than a change of color. Without one, or
+    /// when not focused, this is the same as [`block`](Theme::block).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::theme::Theme;
+    /// use ratatui::widgets::{BorderType, Paragraph};
+    ///
+    /// let theme = Theme {
+    ///     focus_ring: Some(BorderType::Double),
+    ///     ..Theme::default()
+    /// };
+    /// let paragraph = Paragraph::new("text").block(theme.focused_block(true));
+    /// # let _ = paragraph;
+    /// ```
+    pub fn focused_block(&self, focused: bool) -> Block<'static> {
+        ThemeProvider::focused_block(self, focused)
+    }
 }
 
 // =============================================================================
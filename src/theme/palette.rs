@@ -1,10 +1,11 @@
-//! Named-color palette and severity helper for `Theme`.
+//! Named-color palette, severity, and status helpers for `Theme`.
 //!
-//! This module adds three new public types — [`NamedColor`], [`Palette`],
-//! and [`Severity`] — plus three new methods on [`Theme`] (`color`,
-//! `severity_color`, `severity_style`). Together they let consumers access
-//! palette colors by name and bucket numeric values into a four-band
-//! severity gradient without reaching for raw color constants.
+//! This module adds four public types — [`NamedColor`], [`Palette`],
+//! [`Severity`], and [`StatusLevel`] — plus four methods on [`Theme`]
+//! (`color`, `severity_color`, `severity_style`, `status_colors`). Together
+//! they let consumers access palette colors by name, bucket numeric values
+//! into a four-band severity gradient, and fill status badges without
+//! reaching for raw color constants.
 //!
 //! See the [theme module documentation](super) for an overview.
 
@@ -92,6 +93,44 @@ impl Severity {
     }
 }
 
+// =============================================================================
+// StatusLevel
+// =============================================================================
+
+/// A status level for background-filled badges, banners, and toasts.
+///
+/// Pass to [`Theme::status_colors`] for a foreground/background pair with
+/// readable contrast.
+///
+/// `#[non_exhaustive]` so envision can add levels later without breaking
+/// downstream `match` arms.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::theme::{StatusLevel, Theme};
+///
+/// let theme = Theme::nord();
+/// let (fg, bg) = theme.status_colors(StatusLevel::Warning);
+/// assert_eq!(bg, theme.warning);
+/// assert_ne!(fg, bg);
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StatusLevel {
+    /// No particular status — a muted surface.
+    #[default]
+    Neutral,
+    /// Informational — typically blue or cyan.
+    Info,
+    /// Success — typically green.
+    Success,
+    /// Warning — typically yellow.
+    Warning,
+    /// Error — typically red.
+    Error,
+}
+
 // =============================================================================
 // Named Palette Colors
 // =============================================================================
@@ -282,8 +321,42 @@ pub struct Palette {
     pub crust: Color,
 }
 
+impl Palette {
+    /// Returns a palette with `f` applied to every color.
+    pub(crate) fn map(self, f: impl Fn(Color) -> Color) -> Self {
+        Self {
+            rosewater: f(self.rosewater),
+            flamingo: f(self.flamingo),
+            pink: f(self.pink),
+            mauve: f(self.mauve),
+            red: f(self.red),
+            maroon: f(self.maroon),
+            peach: f(self.peach),
+            yellow: f(self.yellow),
+            green: f(self.green),
+            teal: f(self.teal),
+            sky: f(self.sky),
+            sapphire: f(self.sapphire),
+            blue: f(self.blue),
+            lavender: f(self.lavender),
+            text: f(self.text),
+            subtext1: f(self.subtext1),
+            subtext0: f(self.subtext0),
+            overlay2: f(self.overlay2),
+            overlay1: f(self.overlay1),
+            overlay0: f(self.overlay0),
+            surface2: f(self.surface2),
+            surface1: f(self.surface1),
+            surface0: f(self.surface0),
+            base: f(self.base),
+            mantle: f(self.mantle),
+            crust: f(self.crust),
+        }
+    }
+}
+
 // =============================================================================
-// Theme accessors (color, severity_color, severity_style)
+// Theme accessors (color, severity_color, severity_style, status_colors)
 // =============================================================================
 
 impl Theme {
@@ -393,4 +466,49 @@ impl Theme {
             style
         }
     }
+
+    /// Returns the `(foreground, background)` color pair for a filled
+    /// [`StatusLevel`] badge.
+    ///
+    /// The background is the foreground color of the matching semantic style
+    /// ([`info_style`](Theme::info_style), [`success_style`](Theme::success_style),
+    /// [`warning_style`](Theme::warning_style), [`error_style`](Theme::error_style)),
+    /// and the text uses the theme background so it stays readable on the
+    /// bright status color. Themes with a `Reset` background (such as
+    /// [`Default`](Theme::default)) use black text instead. `Neutral` pairs
+    /// [`NamedColor::Text`] with [`NamedColor::Surface2`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::theme::{StatusLevel, Theme};
+    /// use ratatui::style::{Color, Style};
+    ///
+    /// let theme = Theme::default();
+    /// let (fg, bg) = theme.status_colors(StatusLevel::Error);
+    /// assert_eq!((fg, bg), (Color::Black, Color::Red));
+    ///
+    /// let badge = Style::default().fg(fg).bg(bg);
+    /// # let _ = badge;
+    /// ```
+    pub fn status_colors(&self, level: StatusLevel) -> (Color, Color) {
+        let style = match level {
+            StatusLevel::Neutral => {
+                return (
+                    self.color(NamedColor::Text),
+                    self.color(NamedColor::Surface2),
+                );
+            }
+            StatusLevel::Info => self.info_style(),
+            StatusLevel::Success => self.success_style(),
+            StatusLevel::Warning => self.warning_style(),
+            StatusLevel::Error => self.error_style(),
+        };
+        let text = if self.background == Color::Reset {
+            Color::Black
+        } else {
+            self.background
+        };
+        (text, style.fg.unwrap_or(Color::Reset))
+    }
 }
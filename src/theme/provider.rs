@@ -0,0 +1,210 @@
+//! The [`ThemeProvider`] trait used by component `view` functions.
+//!
+//! Components style themselves through [`RenderContext::theme`](crate::component::RenderContext::theme),
+//! which is a `&dyn ThemeProvider`. [`Theme`] implements the trait directly, so
+//! passing `&theme` works as before. A custom provider wraps a base [`Theme`]
+//! and overrides only the getters it cares about, e.g. to compute styles from
+//! application state.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders};
+
+use super::{NamedColor, Severity, StatusLevel, Theme};
+
+/// Supplies the styles components render with.
+///
+/// Every getter defaults to the matching method on [`base_theme`](Self::base_theme),
+/// so an implementation only needs to provide the base theme plus the
+/// getters it wants to change.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::theme::{Theme, ThemeProvider};
+/// use ratatui::style::{Color, Style};
+///
+/// /// Highlights focus in red while an alarm is active.
+/// struct AlarmTheme {
+///     base: Theme,
+///     alarm: bool,
+/// }
+///
+/// impl ThemeProvider for AlarmTheme {
+///     fn base_theme(&self) -> &Theme {
+///         &self.base
+///     }
+///
+///     fn focused_border_style(&self) -> Style {
+///         if self.alarm {
+///             Style::default().fg(Color::Red)
+///         } else {
+///             self.base.focused_border_style()
+///         }
+///     }
+/// }
+///
+/// let provider = AlarmTheme { base: Theme::nord(), alarm: true };
+/// assert_eq!(provider.focused_border_style().fg, Some(Color::Red));
+/// assert_eq!(provider.normal_style(), Theme::nord().normal_style());
+/// ```
+pub trait ThemeProvider {
+    /// Returns the theme that supplies colors and any styles not overridden.
+    fn base_theme(&self) -> &Theme;
+
+    /// Returns a style for focused elements.
+    fn focused_style(&self) -> Style {
+        self.base_theme().focused_style()
+    }
+
+    /// Returns a style for focused elements with bold modifier.
+    fn focused_bold_style(&self) -> Style {
+        self.base_theme().focused_bold_style()
+    }
+
+    /// Returns a style for focused borders.
+    fn focused_border_style(&self) -> Style {
+        self.base_theme().focused_border_style()
+    }
+
+    /// Returns a style for the focus ring around the focused element.
+    ///
+    /// Derived from [`focused_border_style`](Self::focused_border_style),
+    /// so a provider that overrides it gets a matching ring.
+    fn focus_ring_style(&self) -> Style {
+        self.focused_border_style().add_modifier(Modifier::BOLD)
+    }
+
+    /// Returns a style for selected items.
+    fn selected_style(&self, focused: bool) -> Style {
+        self.base_theme().selected_style(focused)
+    }
+
+    /// Returns a style for selected items with background highlight.
+    fn selected_highlight_style(&self, focused: bool) -> Style {
+        self.base_theme().selected_highlight_style(focused)
+    }
+
+    /// Returns a style for text selection.
+    fn selection_style(&self) -> Style {
+        self.base_theme().selection_style()
+    }
+
+    /// Returns a style for disabled elements.
+    fn disabled_style(&self) -> Style {
+        self.base_theme().disabled_style()
+    }
+
+    /// Returns a style for placeholder text.
+    fn placeholder_style(&self) -> Style {
+        self.base_theme().placeholder_style()
+    }
+
+    /// Returns a style for default/normal elements.
+    fn normal_style(&self) -> Style {
+        self.base_theme().normal_style()
+    }
+
+    /// Returns a style for primary accent elements.
+    fn primary_style(&self) -> Style {
+        self.base_theme().primary_style()
+    }
+
+    /// Returns a style for borders (non-focused).
+    fn border_style(&self) -> Style {
+        self.base_theme().border_style()
+    }
+
+    /// Returns a style for success messages/indicators.
+    fn success_style(&self) -> Style {
+        self.base_theme().success_style()
+    }
+
+    /// Returns a style for warning messages/indicators.
+    fn warning_style(&self) -> Style {
+        self.base_theme().warning_style()
+    }
+
+    /// Returns a style for error messages/indicators.
+    fn error_style(&self) -> Style {
+        self.base_theme().error_style()
+    }
+
+    /// Returns a style for informational messages/indicators.
+    fn info_style(&self) -> Style {
+        self.base_theme().info_style()
+    }
+
+    /// Returns a style for the filled portion of progress bars.
+    fn progress_filled_style(&self) -> Style {
+        self.base_theme().progress_filled_style()
+    }
+
+    /// Returns a fully bordered block styled for the focus state.
+    ///
+    /// Built from [`focused_border_style`](Self::focused_border_style) or
+    /// [`border_style`](Self::border_style), so overriding those restyles
+    /// every block as well.
+    fn block(&self, focused: bool) -> Block<'static> {
+        let style = if focused {
+            self.focused_border_style()
+        } else {
+            self.border_style()
+        };
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(self.base_theme().border_type)
+            .border_style(style)
+    }
+
+    /// Returns a fully bordered block with a title in the border style.
+    fn titled_block<'a>(&self, title: Line<'a>, focused: bool) -> Block<'a> {
+        let style = if focused {
+            self.focused_border_style()
+        } else {
+            self.border_style()
+        };
+        self.block(focused).title(title).title_style(style)
+    }
+
+    /// Returns a fully bordered block that draws the theme's
+    /// [`focus_ring`](Theme::focus_ring) when `focused`.
+    ///
+    /// Falls back to [`block`](Self::block) when the theme has no focus
+    /// ring or the element is not focused.
+    fn focused_block(&self, focused: bool) -> Block<'static> {
+        match self.base_theme().focus_ring {
+            Some(ring) if focused => Block::default()
+                .borders(Borders::ALL)
+                .border_type(ring)
+                .border_style(self.focus_ring_style()),
+            _ => self.block(focused),
+        }
+    }
+
+    /// Returns the color for a [`NamedColor`] palette name.
+    fn color(&self, named: NamedColor) -> Color {
+        self.base_theme().color(named)
+    }
+
+    /// Returns the color for a [`Severity`] level.
+    fn severity_color(&self, sev: Severity) -> Color {
+        self.base_theme().severity_color(sev)
+    }
+
+    /// Returns a foreground style for a [`Severity`] level.
+    fn severity_style(&self, sev: Severity) -> Style {
+        self.base_theme().severity_style(sev)
+    }
+
+    /// Returns the `(foreground, background)` pair for a [`StatusLevel`].
+    fn status_colors(&self, level: StatusLevel) -> (Color, Color) {
+        self.base_theme().status_colors(level)
+    }
+}
+
+impl ThemeProvider for Theme {
+    fn base_theme(&self) -> &Theme {
+        self
+    }
+}
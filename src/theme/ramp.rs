@@ -0,0 +1,299 @@
+//! Color gradients and ramps for data visualization.
+//!
+//! This module adds [`ColorRamp`] plus two methods on [`Theme`]
+//! (`gradient` and `sequential_ramp`). Colors are interpolated in the
+//! Oklab color space, where equal steps look equally far apart, so a
+//! gradient between two saturated colors does not pass through a muddy
+//! midpoint the way a plain RGB blend does.
+//!
+//! See the [theme module documentation](super) for an overview.
+
+use ratatui::style::Color;
+
+use super::Theme;
+use crate::backend::to_rgb;
+
+/// A named sequential color ramp, from low to high values.
+///
+/// The ramps are matplotlib's perceptually uniform colormaps: lightness
+/// rises steadily from one end to the other, so they read correctly for
+/// color-blind users and in grayscale.
+///
+/// # Example
+///
+/// ```rust
+/// use envision::theme::ColorRamp;
+/// use ratatui::style::Color;
+///
+/// assert_eq!(ColorRamp::Viridis.color_at(0.0), Color::Rgb(68, 1, 84));
+/// assert_eq!(ColorRamp::Viridis.color_at(1.0), Color::Rgb(253, 231, 37));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum ColorRamp {
+    /// Dark purple through teal to yellow.
+    #[default]
+    Viridis,
+    /// Black through purple and coral to pale yellow.
+    Magma,
+    /// Black through purple and orange to pale yellow.
+    Inferno,
+    /// Dark blue through magenta and orange to yellow.
+    Plasma,
+}
+
+impl ColorRamp {
+    /// Returns the ramp's color for a normalized value.
+    ///
+    /// `t` is clamped to [0.0, 1.0]; 0.0 is the low end of the ramp.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::theme::ColorRamp;
+    /// use ratatui::style::Color;
+    ///
+    /// assert_eq!(ColorRamp::Magma.color_at(-1.0), Color::Rgb(0, 0, 4));
+    /// assert_eq!(ColorRamp::Magma.color_at(2.0), Color::Rgb(252, 253, 191));
+    /// ```
+    pub fn color_at(self, t: f64) -> Color {
+        let samples = self.samples();
+        let position = t.clamp(0.0, 1.0) * (samples.len() - 1) as f64;
+        let lo = position.floor() as usize;
+        let hi = (lo + 1).min(samples.len() - 1);
+        let (r, g, b) = mix(samples[lo], samples[hi], position - lo as f64);
+        Color::Rgb(r, g, b)
+    }
+
+    /// Returns the evenly spaced samples the ramp interpolates between.
+    fn samples(self) -> &'static [(u8, u8, u8)] {
+        match self {
+            ColorRamp::Viridis => &VIRIDIS,
+            ColorRamp::Magma => &MAGMA,
+            ColorRamp::Inferno => &INFERNO,
+            ColorRamp::Plasma => &PLASMA,
+        }
+    }
+}
+
+impl Theme {
+    /// Returns `steps` colors blending evenly from `from` to `to`.
+    ///
+    /// The first color is `from` and the last is `to`. Named and indexed
+    /// colors use their xterm RGB values. [`Color::Reset`] has no RGB
+    /// value, so a gradient involving it switches from `from` to `to`
+    /// halfway instead of blending.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::theme::Theme;
+    /// use ratatui::style::Color;
+    ///
+    /// let ramp = Theme::gradient(Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255), 3);
+    /// assert_eq!(ramp.len(), 3);
+    /// assert_eq!(ramp[0], Color::Rgb(0, 0, 0));
+    /// assert_eq!(ramp[2], Color::Rgb(255, 255, 255));
+    /// // The midpoint is perceptually halfway, darker than RGB's (128, 128, 128)
+    /// assert_eq!(ramp[1], Color::Rgb(99, 99, 99));
+    /// ```
+    pub fn gradient(from: Color, to: Color, steps: usize) -> Vec<Color> {
+        (0..steps)
+            .map(|i| {
+                let t = if steps > 1 {
+                    i as f64 / (steps - 1) as f64
+                } else {
+                    0.0
+                };
+                match (to_rgb(from), to_rgb(to)) {
+                    _ if t == 0.0 => from,
+                    _ if t == 1.0 => to,
+                    (Some(a), Some(b)) => {
+                        let (r, g, b) = mix(a, b, t);
+                        Color::Rgb(r, g, b)
+                    }
+                    _ if t < 0.5 => from,
+                    _ => to,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns `steps` colors evenly spaced along a named ramp.
+    ///
+    /// Use [`ColorRamp::color_at`] instead to map individual values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::theme::{ColorRamp, Theme};
+    /// use ratatui::style::Color;
+    ///
+    /// let ramp = Theme::sequential_ramp(ColorRamp::Viridis, 5);
+    /// assert_eq!(ramp.len(), 5);
+    /// assert_eq!(ramp[0], Color::Rgb(68, 1, 84));
+    /// assert_eq!(ramp[4], Color::Rgb(253, 231, 37));
+    /// ```
+    pub fn sequential_ramp(ramp: ColorRamp, steps: usize) -> Vec<Color> {
+        (0..steps)
+            .map(|i| {
+                let t = if steps > 1 {
+                    i as f64 / (steps - 1) as f64
+                } else {
+                    0.0
+                };
+                ramp.color_at(t)
+            })
+            .collect()
+    }
+}
+
+// =============================================================================
+// Oklab interpolation
+// =============================================================================
+
+/// Blends two sRGB colors in Oklab. `t` is the fraction of the way to `b`.
+fn mix(a: (u8, u8, u8), b: (u8, u8, u8), t: f64) -> (u8, u8, u8) {
+    if t <= 0.0 {
+        return a;
+    }
+    if t >= 1.0 {
+        return b;
+    }
+    let a = to_oklab(a);
+    let b = to_oklab(b);
+    from_oklab([
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+    ])
+}
+
+/// Converts an sRGB color to Oklab `[L, a, b]`.
+fn to_oklab((r, g, b): (u8, u8, u8)) -> [f64; 3] {
+    let (r, g, b) = (to_linear(r), to_linear(g), to_linear(b));
+    let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+    let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+    let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+    [
+        0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+        1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+        0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+    ]
+}
+
+/// Converts an Oklab `[L, a, b]` color to sRGB, clamping out-of-gamut
+/// values.
+fn from_oklab([lightness, a, b]: [f64; 3]) -> (u8, u8, u8) {
+    let l = (lightness + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+    let m = (lightness - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+    let s = (lightness - 0.089_484_177_5 * a - 1.291_485_548_0 * b).powi(3);
+    (
+        from_linear(4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s),
+        from_linear(-1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s),
+        from_linear(-0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s),
+    )
+}
+
+/// Converts an sRGB channel to linear light in [0.0, 1.0].
+fn to_linear(channel: u8) -> f64 {
+    let c = f64::from(channel) / 255.0;
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear light value to an sRGB channel.
+fn from_linear(value: f64) -> u8 {
+    let v = value.clamp(0.0, 1.0);
+    let c = if v <= 0.003_130_8 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+// =============================================================================
+// Ramp samples
+// =============================================================================
+
+/// Viridis colormap: 16 evenly spaced samples from the matplotlib Viridis scale.
+pub(crate) const VIRIDIS: [(u8, u8, u8); 16] = [
+    (68, 1, 84),
+    (72, 26, 108),
+    (71, 47, 126),
+    (65, 68, 135),
+    (57, 86, 140),
+    (48, 103, 141),
+    (39, 119, 142),
+    (31, 135, 141),
+    (30, 150, 138),
+    (44, 166, 130),
+    (73, 181, 117),
+    (110, 196, 98),
+    (155, 208, 72),
+    (199, 217, 46),
+    (238, 224, 29),
+    (253, 231, 37),
+];
+
+/// Magma colormap: 11 evenly spaced samples from the matplotlib Magma scale.
+const MAGMA: [(u8, u8, u8); 11] = [
+    (0, 0, 4),
+    (20, 14, 54),
+    (59, 15, 112),
+    (100, 26, 128),
+    (140, 41, 129),
+    (183, 55, 121),
+    (222, 73, 104),
+    (247, 112, 92),
+    (254, 159, 109),
+    (254, 207, 146),
+    (252, 253, 191),
+];
+
+/// Inferno colormap: 16 evenly spaced samples from the matplotlib Inferno scale.
+pub(crate) const INFERNO: [(u8, u8, u8); 16] = [
+    (0, 0, 4),
+    (11, 7, 36),
+    (32, 12, 74),
+    (59, 15, 99),
+    (87, 16, 110),
+    (114, 17, 112),
+    (140, 25, 101),
+    (165, 44, 81),
+    (187, 65, 58),
+    (205, 92, 35),
+    (219, 122, 12),
+    (230, 155, 0),
+    (237, 189, 12),
+    (239, 222, 52),
+    (237, 249, 121),
+    (252, 255, 164),
+];
+
+/// Plasma colormap: 16 evenly spaced samples from the matplotlib Plasma scale.
+pub(crate) const PLASMA: [(u8, u8, u8); 16] = [
+    (13, 8, 135),
+    (49, 4, 150),
+    (80, 2, 162),
+    (108, 1, 168),
+    (134, 2, 166),
+    (156, 23, 158),
+    (177, 42, 144),
+    (195, 63, 126),
+    (210, 84, 107),
+    (222, 107, 87),
+    (231, 131, 67),
+    (238, 157, 46),
+    (242, 183, 28),
+    (243, 210, 22),
+    (238, 236, 38),
+    (240, 249, 33),
+];
@@ -0,0 +1,164 @@
+//! Mapping a `Theme` onto the terminal's 16-color ANSI palette.
+//!
+//! Programs that only use the 16 standard colors, such as a shell or an
+//! editor started while the app is suspended, take their colors from the
+//! terminal's palette. [`Theme::to_palette`] picks a theme color for each
+//! of the 16 slots, and [`Theme::palette_osc_sequence`] writes them into
+//! the terminal so those programs match the app.
+
+use std::fmt::Write;
+
+use ratatui::style::Color;
+
+use super::Theme;
+use crate::backend::{ColorDepth, NAMED_COLORS, to_rgb};
+
+/// Resets every palette entry to the terminal's default (OSC 104).
+const RESET_PALETTE: &str = "\x1b]104\x07";
+
+impl Theme {
+    /// Returns the theme's colors for the 16 standard ANSI palette slots,
+    /// in palette order (black, red, ..., white, then the bright variants).
+    ///
+    /// Black is the theme's background and white its foreground. Every
+    /// other slot gets the first theme color whose nearest
+    /// [16-color equivalent](ColorDepth::Ansi16) is that slot, trying the
+    /// semantic colors first (`error` for red, `success` for green, and so
+    /// on), then the rest of the [`palette`](Theme::palette). A slot with no
+    /// match borrows the RGB color of its normal or bright sibling, and
+    /// keeps the standard color if there is none either. Themes built from the standard colors, such as the
+    /// default theme, therefore map to the standard palette unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::theme::Theme;
+    /// use ratatui::style::Color;
+    ///
+    /// let theme = Theme::catppuccin_mocha();
+    /// let palette = theme.to_palette();
+    /// assert_eq!(palette[0], theme.background);
+    /// assert_eq!(palette[1], theme.error);
+    /// assert_eq!(palette[7], theme.foreground);
+    ///
+    /// assert_eq!(Theme::default().to_palette()[4], Color::Blue);
+    /// ```
+    pub fn to_palette(&self) -> [Color; 16] {
+        let candidates = self.palette_candidates();
+        let slot_of = |color: Color| {
+            let standard = ColorDepth::Ansi16.downsample(color);
+            NAMED_COLORS
+                .iter()
+                .position(|&(named, _)| named == standard)
+        };
+        let find = |slot: usize| {
+            candidates
+                .iter()
+                .copied()
+                .find(|&color| slot_of(color) == Some(slot))
+        };
+
+        // Standard colors already are what the sibling slot shows.
+        let custom = |color: &Color| matches!(color, Color::Rgb(..) | Color::Indexed(16..));
+
+        std::array::from_fn(|slot| {
+            let fixed = match slot {
+                0 => Some(self.background),
+                7 => Some(self.foreground),
+                _ => None,
+            };
+            fixed
+                .filter(|&color| color != Color::Reset)
+                .or_else(|| find(slot))
+                .or_else(|| find(slot ^ 8).filter(custom))
+                .unwrap_or(NAMED_COLORS[slot].0)
+        })
+    }
+
+    /// Returns the escape sequence that sets the terminal's 16-color
+    /// palette to [`to_palette`](Self::to_palette).
+    ///
+    /// Each slot that differs from the standard color is set with an OSC 4
+    /// sequence; standard and [`Color::Reset`] slots are left as they are.
+    /// Write the result to the terminal before handing it to a child
+    /// process, and [`palette_reset_sequence`](Self::palette_reset_sequence)
+    /// afterwards to restore the user's palette. Terminals that don't
+    /// support OSC 4 ignore it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use envision::theme::Theme;
+    ///
+    /// assert_eq!(Theme::default().palette_osc_sequence(), "");
+    ///
+    /// // Catppuccin Mocha's red is #f38ba8
+    /// let osc = Theme::catppuccin_mocha().palette_osc_sequence();
+    /// assert!(osc.contains("\x1b]4;1;rgb:f3/8b/a8\x07"));
+    /// ```
+    pub fn palette_osc_sequence(&self) -> String {
+        let mut output = String::new();
+        for (slot, color) in self.to_palette().into_iter().enumerate() {
+            if color == NAMED_COLORS[slot].0 {
+                continue;
+            }
+            if let Some((r, g, b)) = to_rgb(color) {
+                let _ = write!(output, "\x1b]4;{slot};rgb:{r:02x}/{g:02x}/{b:02x}\x07");
+            }
+        }
+        output
+    }
+
+    /// Returns the escape sequence that restores the terminal's default
+    /// palette after [`palette_osc_sequence`](Self::palette_osc_sequence).
+    pub fn palette_reset_sequence() -> &'static str {
+        RESET_PALETTE
+    }
+
+    /// Returns the theme's colors in the order slots are matched against.
+    fn palette_candidates(&self) -> [Color; 40] {
+        let p = &self.palette;
+        [
+            self.background,
+            self.foreground,
+            self.error,
+            self.success,
+            self.warning,
+            self.primary,
+            self.info,
+            self.focused,
+            self.selected,
+            p.red,
+            p.green,
+            p.yellow,
+            p.blue,
+            p.mauve,
+            p.pink,
+            p.teal,
+            p.sky,
+            p.sapphire,
+            p.lavender,
+            p.peach,
+            p.maroon,
+            p.flamingo,
+            p.rosewater,
+            p.text,
+            p.subtext1,
+            p.subtext0,
+            p.overlay2,
+            p.overlay1,
+            p.overlay0,
+            p.surface2,
+            p.surface1,
+            p.surface0,
+            p.base,
+            p.mantle,
+            p.crust,
+            self.border,
+            self.disabled,
+            self.placeholder,
+            self.progress_filled,
+            self.progress_empty,
+        ]
+    }
+}
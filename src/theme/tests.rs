@@ -827,6 +827,82 @@ fn test_severity_style_fg_matches_severity_color() {
     }
 }
 
+#[test]
+fn test_status_colors_default_theme() {
+    let theme = Theme::default();
+    assert_eq!(
+        theme.status_colors(StatusLevel::Info),
+        (Color::Black, Color::Cyan)
+    );
+    assert_eq!(
+        theme.status_colors(StatusLevel::Success),
+        (Color::Black, Color::Green)
+    );
+    assert_eq!(
+        theme.status_colors(StatusLevel::Warning),
+        (Color::Black, Color::Yellow)
+    );
+    assert_eq!(
+        theme.status_colors(StatusLevel::Error),
+        (Color::Black, Color::Red)
+    );
+    assert_eq!(
+        theme.status_colors(StatusLevel::Neutral),
+        (Color::White, Color::DarkGray)
+    );
+}
+
+#[test]
+fn test_status_colors_derive_from_semantic_styles() {
+    for theme in [
+        Theme::default(),
+        Theme::nord(),
+        Theme::dracula(),
+        Theme::solarized_dark(),
+        Theme::gruvbox_dark(),
+        Theme::catppuccin_mocha(),
+    ] {
+        let pairs = [
+            (StatusLevel::Info, theme.info_style()),
+            (StatusLevel::Success, theme.success_style()),
+            (StatusLevel::Warning, theme.warning_style()),
+            (StatusLevel::Error, theme.error_style()),
+        ];
+        for (level, style) in pairs {
+            let (fg, bg) = theme.status_colors(level);
+            assert_eq!(Some(bg), style.fg, "{:?} background", level);
+            assert_ne!(fg, bg, "{:?} must contrast", level);
+            assert_ne!(fg, Color::Reset, "{:?} text must be explicit", level);
+        }
+    }
+}
+
+#[test]
+fn test_status_colors_use_theme_background_for_text() {
+    let theme = Theme::catppuccin_mocha();
+    let (fg, bg) = theme.status_colors(StatusLevel::Success);
+    assert_eq!(fg, theme.background);
+    assert_eq!(bg, theme.success);
+
+    let (fg, bg) = theme.status_colors(StatusLevel::Neutral);
+    assert_eq!(fg, theme.color(NamedColor::Text));
+    assert_eq!(bg, theme.color(NamedColor::Surface2));
+}
+
+#[test]
+fn test_status_level_default_is_neutral() {
+    assert_eq!(StatusLevel::default(), StatusLevel::Neutral);
+}
+
+#[test]
+fn test_theme_provider_status_colors() {
+    let theme = Theme::nord();
+    assert_eq!(
+        ThemeProvider::status_colors(&theme, StatusLevel::Error),
+        theme.status_colors(StatusLevel::Error)
+    );
+}
+
 #[test]
 fn test_palette_completeness_per_theme() {
     // For each shipped theme, every NamedColor variant returns *some* color.
@@ -904,3 +980,427 @@ fn test_crate_root_reexports() {
     // Palette construction is verified in test_palette_struct_construction.
     let _ = std::mem::size_of::<Palette>();
 }
+
+// =============================================================================
+// Terminal background detection
+// =============================================================================
+
+#[test]
+fn test_from_osc11_response_terminators() {
+    assert_eq!(
+        ThemeMode::from_osc11_response("\x1b]11;rgb:ffff/ffff/ffff\x07"),
+        Some(ThemeMode::Light)
+    );
+    assert_eq!(
+        ThemeMode::from_osc11_response("\x1b]11;rgb:0000/0000/0000\x1b\\"),
+        Some(ThemeMode::Dark)
+    );
+}
+
+#[test]
+fn test_from_osc11_response_component_widths() {
+    assert_eq!(
+        ThemeMode::from_osc11_response("rgb:f/f/e"),
+        Some(ThemeMode::Light)
+    );
+    assert_eq!(
+        ThemeMode::from_osc11_response("rgb:fdf6/e3e3/fdfd"),
+        Some(ThemeMode::Light)
+    );
+    assert_eq!(
+        ThemeMode::from_osc11_response("rgb:00/2b/36"),
+        Some(ThemeMode::Dark)
+    );
+}
+
+#[test]
+fn test_from_osc11_response_weights_green_most() {
+    // Pure green is perceived brighter than pure blue
+    assert_eq!(
+        ThemeMode::from_osc11_response("rgb:00/ff/00"),
+        Some(ThemeMode::Light)
+    );
+    assert_eq!(
+        ThemeMode::from_osc11_response("rgb:00/00/ff"),
+        Some(ThemeMode::Dark)
+    );
+}
+
+#[test]
+fn test_from_osc11_response_rejects_malformed() {
+    assert_eq!(ThemeMode::from_osc11_response(""), None);
+    assert_eq!(ThemeMode::from_osc11_response("rgb:ff/ff"), None);
+    assert_eq!(ThemeMode::from_osc11_response("rgb:ff/ff/ff/ff"), None);
+    assert_eq!(ThemeMode::from_osc11_response("rgb:fffff/0/0"), None);
+    assert_eq!(ThemeMode::from_osc11_response("rgb:zz/00/00"), None);
+}
+
+#[test]
+fn test_collect_response_key_ignores_keys_before_introducer() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let keys = [
+        KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
+        KeyEvent::new(KeyCode::Char(']'), KeyModifiers::ALT),
+        KeyEvent::new(KeyCode::Char('1'), KeyModifiers::NONE),
+        KeyEvent::new(KeyCode::Char('1'), KeyModifiers::NONE),
+        KeyEvent::new(KeyCode::Char('\\'), KeyModifiers::ALT),
+    ];
+    let mut response = None;
+    let done: Vec<bool> = keys
+        .iter()
+        .map(|key| detect::collect_response_key(&mut response, key))
+        .collect();
+    assert_eq!(done, [false, false, false, false, true]);
+    assert_eq!(response.as_deref(), Some("11"));
+
+    // A terminator before the introducer is an ordinary key
+    let mut response = None;
+    let ctrl_g = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::CONTROL);
+    assert!(!detect::collect_response_key(&mut response, &ctrl_g));
+    assert_eq!(response, None);
+}
+
+#[test]
+fn test_detect_without_terminal_falls_back_to_dark() {
+    // Test harnesses don't attach a terminal to stdin
+    if !std::io::IsTerminal::is_terminal(&std::io::stdin()) {
+        assert_eq!(
+            detect::detect_terminal_mode(detect::DETECT_TIMEOUT),
+            ThemeMode::Dark
+        );
+    }
+}
+
+#[test]
+fn test_for_mode_picks_builtin_theme() {
+    assert_eq!(Theme::for_mode(ThemeMode::Light), Theme::default());
+    assert_eq!(Theme::for_mode(ThemeMode::Dark), Theme::catppuccin_mocha());
+}
+
+#[test]
+fn test_downsample_truecolor_is_identity() {
+    let theme = Theme::nord();
+    assert_eq!(theme.downsample(ColorDepth::TrueColor), theme);
+}
+
+#[test]
+fn test_downsample_ansi256_maps_every_rgb_color() {
+    let theme = Theme::catppuccin_mocha().downsample(ColorDepth::Ansi256);
+    assert!(matches!(theme.focused, Color::Indexed(16..=255)));
+    assert!(matches!(theme.palette.crust, Color::Indexed(16..=255)));
+    assert!(matches!(theme.palette.lavender, Color::Indexed(16..=255)));
+}
+
+#[test]
+fn test_downsample_ansi16_uses_named_colors() {
+    let theme = Theme::nord().downsample(ColorDepth::Ansi16);
+    let colors = [
+        theme.background,
+        theme.foreground,
+        theme.focused,
+        theme.success,
+        theme.warning,
+        theme.error,
+        theme.palette.base,
+        theme.palette.text,
+    ];
+    for color in colors {
+        assert!(
+            !matches!(color, Color::Rgb(..) | Color::Indexed(_)),
+            "{color:?} is not a named color"
+        );
+    }
+    assert_eq!(theme.error, Color::Red);
+}
+
+#[test]
+fn test_downsample_keeps_named_colors() {
+    let theme = Theme::default();
+    assert_eq!(theme.downsample(ColorDepth::Ansi16), theme);
+    assert_eq!(theme.downsample(ColorDepth::Ansi256), theme);
+}
+
+struct RedBorders(Theme);
+
+impl ThemeProvider for RedBorders {
+    fn base_theme(&self) -> &Theme {
+        &self.0
+    }
+
+    fn border_style(&self) -> Style {
+        Style::default().fg(Color::Red)
+    }
+}
+
+#[test]
+fn test_theme_provider_defaults_to_base_theme() {
+    let provider = RedBorders(Theme::nord());
+    assert_eq!(provider.border_style().fg, Some(Color::Red));
+    assert_eq!(provider.normal_style(), Theme::nord().normal_style());
+    assert_eq!(
+        ThemeProvider::focused_style(&Theme::nord()),
+        Theme::nord().focused_style()
+    );
+}
+
+#[test]
+fn test_theme_provider_focus_ring_follows_focused_border_override() {
+    struct AlarmFocus(Theme);
+
+    impl ThemeProvider for AlarmFocus {
+        fn base_theme(&self) -> &Theme {
+            &self.0
+        }
+
+        fn focused_border_style(&self) -> Style {
+            Style::default().fg(Color::Red)
+        }
+    }
+
+    let provider = AlarmFocus(Theme::nord());
+    assert_eq!(
+        provider.focus_ring_style(),
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    );
+    assert_eq!(
+        ThemeProvider::focus_ring_style(&Theme::nord()),
+        Theme::nord().focus_ring_style()
+    );
+}
+
+#[cfg(feature = "display-components")]
+#[test]
+fn test_theme_provider_overrides_reach_components() {
+    use crate::component::{Component, RenderContext, ScrollView, ScrollViewState};
+
+    let provider = RedBorders(Theme::default());
+    let state = ScrollViewState::new();
+    let backend = crate::backend::CaptureBackend::new(10, 3);
+    let mut terminal = ratatui::Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| {
+            ScrollView::view(
+                &state,
+                &mut RenderContext::new(frame, frame.area(), &provider),
+            );
+        })
+        .unwrap();
+
+    let corner = terminal.backend().cell(0, 0).unwrap();
+    assert_eq!(Color::from(corner.fg), Color::Red);
+}
+
+/// Renders `block` into a 10x3 buffer.
+fn render_block(block: Block<'_>) -> ratatui::buffer::Buffer {
+    use ratatui::widgets::Widget;
+
+    let area = ratatui::layout::Rect::new(0, 0, 10, 3);
+    let mut buf = ratatui::buffer::Buffer::empty(area);
+    block.render(area, &mut buf);
+    buf
+}
+
+#[test]
+fn test_block_uses_focus_border_style() {
+    let theme = Theme::nord();
+    let focused = render_block(theme.block(true));
+    let unfocused = render_block(theme.block(false));
+    assert_eq!(focused[(0, 0)].fg, theme.focused);
+    assert_eq!(unfocused[(0, 0)].fg, theme.border);
+    assert_eq!(focused[(0, 0)].symbol(), "┌");
+}
+
+#[test]
+fn test_block_uses_border_type() {
+    let theme = Theme {
+        border_type: BorderType::Rounded,
+        ..Theme::default()
+    };
+    let buf = render_block(theme.block(false));
+    assert_eq!(buf[(0, 0)].symbol(), "╭");
+    assert_eq!(buf[(9, 2)].symbol(), "╯");
+    assert_eq!(
+        theme.downsample(ColorDepth::Ansi16).border_type,
+        BorderType::Rounded
+    );
+}
+
+#[test]
+fn test_titled_block_styles_title() {
+    let theme = Theme::nord();
+    let buf = render_block(theme.titled_block("Files", true));
+    assert_eq!(buf[(1, 0)].symbol(), "F");
+    assert_eq!(buf[(1, 0)].fg, theme.focused);
+}
+
+#[test]
+fn test_focused_block_draws_focus_ring() {
+    let theme = Theme {
+        focus_ring: Some(BorderType::Double),
+        ..Theme::nord()
+    };
+    let focused = render_block(theme.focused_block(true));
+    assert_eq!(focused[(0, 0)].symbol(), "╔");
+    assert_eq!(focused[(0, 0)].fg, theme.focused);
+    assert!(focused[(0, 0)].modifier.contains(Modifier::BOLD));
+
+    let unfocused = render_block(theme.focused_block(false));
+    assert_eq!(unfocused, render_block(theme.block(false)));
+    assert_eq!(
+        theme.downsample(ColorDepth::Ansi16).focus_ring,
+        Some(BorderType::Double)
+    );
+}
+
+#[test]
+fn test_focused_block_without_ring_matches_block() {
+    let theme = Theme::dracula();
+    assert_eq!(theme.focus_ring, None);
+    assert_eq!(
+        render_block(theme.focused_block(true)),
+        render_block(theme.block(true))
+    );
+}
+
+#[test]
+fn test_theme_provider_block_uses_overrides() {
+    let provider = RedBorders(Theme {
+        border_type: BorderType::Double,
+        ..Theme::default()
+    });
+    let buf = render_block(provider.titled_block(Line::from("Log"), false));
+    assert_eq!(buf[(0, 0)].symbol(), "╔");
+    assert_eq!(buf[(0, 0)].fg, Color::Red);
+    assert_eq!(buf[(1, 0)].fg, Color::Red);
+}
+
+// =============================================================================
+// Color ramps
+// =============================================================================
+
+#[test]
+fn test_gradient_endpoints_and_length() {
+    let ramp = Theme::gradient(Color::Rgb(10, 20, 30), Color::Rgb(200, 100, 50), 5);
+    assert_eq!(ramp.len(), 5);
+    assert_eq!(ramp[0], Color::Rgb(10, 20, 30));
+    assert_eq!(ramp[4], Color::Rgb(200, 100, 50));
+}
+
+#[test]
+fn test_gradient_degenerate_steps() {
+    assert!(Theme::gradient(Color::Red, Color::Blue, 0).is_empty());
+    assert_eq!(
+        Theme::gradient(Color::Red, Color::Blue, 1),
+        vec![Color::Red]
+    );
+    assert_eq!(
+        Theme::gradient(Color::Red, Color::Blue, 2),
+        vec![Color::Red, Color::Blue]
+    );
+}
+
+#[test]
+fn test_gradient_is_perceptual() {
+    // An RGB blend of red and green gives a muddy (128, 128, 0); Oklab
+    // keeps the midpoint as bright as its ends
+    let ramp = Theme::gradient(Color::Rgb(255, 0, 0), Color::Rgb(0, 255, 0), 3);
+    let Color::Rgb(r, g, b) = ramp[1] else {
+        panic!("expected an RGB color, got {:?}", ramp[1]);
+    };
+    assert!(r > 150 && g > 150 && b < 40, "got ({r}, {g}, {b})");
+}
+
+#[test]
+fn test_gradient_resolves_named_colors() {
+    let ramp = Theme::gradient(Color::Black, Color::White, 3);
+    assert_eq!(ramp[0], Color::Black);
+    assert_eq!(ramp[2], Color::White);
+    assert!(matches!(ramp[1], Color::Rgb(r, g, b) if r == g && g == b));
+}
+
+#[test]
+fn test_gradient_with_reset_switches_halfway() {
+    let ramp = Theme::gradient(Color::Reset, Color::Blue, 4);
+    assert_eq!(
+        ramp,
+        vec![Color::Reset, Color::Reset, Color::Blue, Color::Blue]
+    );
+}
+
+#[test]
+fn test_sequential_ramp_endpoints() {
+    let cases = [
+        (ColorRamp::Viridis, (68, 1, 84), (253, 231, 37)),
+        (ColorRamp::Magma, (0, 0, 4), (252, 253, 191)),
+        (ColorRamp::Inferno, (0, 0, 4), (252, 255, 164)),
+        (ColorRamp::Plasma, (13, 8, 135), (240, 249, 33)),
+    ];
+    for (ramp, (r1, g1, b1), (r2, g2, b2)) in cases {
+        let colors = Theme::sequential_ramp(ramp, 8);
+        assert_eq!(colors.len(), 8);
+        assert_eq!(colors[0], Color::Rgb(r1, g1, b1), "{ramp:?}");
+        assert_eq!(colors[7], Color::Rgb(r2, g2, b2), "{ramp:?}");
+    }
+}
+
+#[test]
+fn test_sequential_ramp_lightness_increases() {
+    let luma = |color: Color| match color {
+        Color::Rgb(r, g, b) => 2126 * u32::from(r) + 7152 * u32::from(g) + 722 * u32::from(b),
+        other => panic!("expected an RGB color, got {other:?}"),
+    };
+    for ramp in [ColorRamp::Viridis, ColorRamp::Magma, ColorRamp::Inferno] {
+        let colors = Theme::sequential_ramp(ramp, 10);
+        for pair in colors.windows(2) {
+            assert!(luma(pair[0]) < luma(pair[1]), "{ramp:?}: {pair:?}");
+        }
+    }
+}
+
+#[test]
+fn test_color_ramp_clamps_and_hits_samples() {
+    assert_eq!(ColorRamp::Viridis.color_at(-0.5), Color::Rgb(68, 1, 84));
+    assert_eq!(ColorRamp::Viridis.color_at(1.5), Color::Rgb(253, 231, 37));
+    // 0.5 of Magma's 11 samples is exactly the middle one
+    assert_eq!(ColorRamp::Magma.color_at(0.5), Color::Rgb(183, 55, 121));
+    assert_eq!(ColorRamp::default(), ColorRamp::Viridis);
+}
+
+#[test]
+fn test_to_palette_default_theme_is_standard_palette() {
+    let standard: Vec<Color> = crate::backend::NAMED_COLORS
+        .iter()
+        .map(|&(color, _)| color)
+        .collect();
+    assert_eq!(Theme::default().to_palette().to_vec(), standard);
+    assert_eq!(Theme::default().palette_osc_sequence(), "");
+}
+
+#[test]
+fn test_to_palette_matches_slots_by_hue() {
+    let theme = Theme::nord();
+    let palette = theme.to_palette();
+    assert_eq!(palette[0], theme.background);
+    assert_eq!(palette[2], theme.success);
+    assert_eq!(palette[3], theme.warning);
+    assert_eq!(palette[7], theme.foreground);
+    // No nord color is nearest to magenta, so the standard colors remain
+    // and are not written.
+    assert_eq!(palette[5], Color::Magenta);
+    let osc = theme.palette_osc_sequence();
+    assert!(!osc.contains("\x1b]4;5;"));
+    assert!(osc.starts_with("\x1b]4;0;rgb:2e/34/40\x07"));
+}
+
+#[test]
+fn test_to_palette_bright_slot_borrows_sibling() {
+    let theme = Theme {
+        error: Color::Rgb(150, 0, 0),
+        ..Theme::default()
+    };
+    let palette = theme.to_palette();
+    assert_eq!(palette[1], Color::Rgb(150, 0, 0));
+    assert_eq!(palette[9], Color::Rgb(150, 0, 0));
+    assert_eq!(Theme::palette_reset_sequence(), "\x1b]104\x07");
+}
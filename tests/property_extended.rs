@@ -174,7 +174,7 @@ fn loading_list_message_strategy() -> impl Strategy<Value = LoadingListMessage<S
         Just(LoadingListMessage::First),
         Just(LoadingListMessage::Last),
         Just(LoadingListMessage::Select),
-        Just(LoadingListMessage::Tick),
+        Just(LoadingListMessage::Tick(100)),
     ]
 }
 